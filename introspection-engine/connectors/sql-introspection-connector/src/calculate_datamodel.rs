@@ -97,10 +97,15 @@ mod tests {
                         .to_string(),
                 ),
                 is_embedded: false,
+                is_view: false,
+                schema: None,
+                check_constraints: vec![],
                 is_generated: false,
-                is_commented_out: true,
+                is_commented_out: false,
+                is_ignored: true,
                 indices: vec![],
                 id_fields: vec![],
+                id_constraint_name: None,
                 fields: col_types
                     .iter()
                     .map(|col_type| {
@@ -122,21 +127,29 @@ mod tests {
                             database_name: None,
                             default_value: None,
                             is_unique: false,
+                            unique_constraint_name: None,
                             is_id: false,
+                            id_constraint_name: None,
                             documentation,
                             is_generated: false,
                             is_updated_at: false,
+                            is_version: false,
+                            validations: Vec::new(),
+                            computed: None,
                             is_commented_out,
+                            is_ignored: false,
                         })
                     })
                     .collect(),
             }],
             enums: vec![],
+            composite_types: vec![],
         };
 
         let schema = SqlSchema {
             tables: vec![Table {
                 name: "Table1".to_string(),
+                schema: None,
                 columns: col_types
                     .iter()
                     .map(|family| Column {
@@ -148,14 +161,19 @@ mod tests {
                         ),
                         default: None,
                         auto_increment: false,
+                        generated: None,
+                        comment: None,
                     })
                     .collect(),
                 indices: vec![],
                 primary_key: None,
                 foreign_keys: vec![],
+                check_constraints: vec![],
+                comment: None,
             }],
             enums: vec![],
             sequences: vec![],
+            views: vec![],
         };
         let introspection_result =
             calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
@@ -171,7 +189,11 @@ mod tests {
                 name: "Table1".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
+                schema: None,
+                check_constraints: vec![],
                 is_commented_out: false,
+                is_ignored: false,
                 fields: vec![
                     Field::ScalarField(ScalarField::new(
                         "optional",
@@ -185,11 +207,17 @@ mod tests {
                         database_name: None,
                         default_value: Some(DMLDefault::Expression(ValueGenerator::new_autoincrement())),
                         is_unique: false,
+                        unique_constraint_name: None,
                         is_id: true,
+                        id_constraint_name: None,
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_version: false,
+                        validations: Vec::new(),
+                        computed: None,
                         is_commented_out: false,
+                        is_ignored: false,
                     }),
                     Field::ScalarField(ScalarField::new(
                         "list",
@@ -200,31 +228,40 @@ mod tests {
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                id_constraint_name: None,
             }],
             enums: vec![],
+            composite_types: vec![],
         };
 
         let schema = SqlSchema {
             tables: vec![Table {
                 name: "Table1".to_string(),
+                schema: None,
                 columns: vec![
                     Column {
                         name: "optional".to_string(),
                         tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Nullable),
                         default: None,
                         auto_increment: false,
+                        generated: None,
+                        comment: None,
                     },
                     Column {
                         name: "required".to_string(),
                         tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
                         default: None,
                         auto_increment: true,
+                        generated: None,
+                        comment: None,
                     },
                     Column {
                         name: "list".to_string(),
                         tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::List),
                         default: None,
                         auto_increment: false,
+                        generated: None,
+                        comment: None,
                     },
                 ],
                 indices: vec![],
@@ -234,9 +271,12 @@ mod tests {
                     constraint_name: None,
                 }),
                 foreign_keys: vec![],
+                check_constraints: vec![],
+                comment: None,
             }],
             enums: vec![],
             sequences: vec![],
+            views: vec![],
         };
         let introspection_result =
             calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
@@ -252,7 +292,11 @@ mod tests {
                 name: "Table1".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
+                schema: None,
+                check_constraints: vec![],
                 is_commented_out: false,
+                is_ignored: false,
                 fields: vec![
                     Field::ScalarField(ScalarField::new(
                         "no_default",
@@ -266,11 +310,17 @@ mod tests {
                         database_name: None,
                         default_value: Some(dml::DefaultValue::Single(PrismaValue::Int(1))),
                         is_unique: false,
+                        unique_constraint_name: None,
                         is_id: false,
+                        id_constraint_name: None,
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_version: false,
+                        validations: Vec::new(),
+                        computed: None,
                         is_commented_out: false,
+                        is_ignored: false,
                     }),
                     Field::ScalarField(ScalarField {
                         name: "bool_default".to_string(),
@@ -279,11 +329,17 @@ mod tests {
                         database_name: None,
                         default_value: Some(dml::DefaultValue::Single(PrismaValue::Boolean(true))),
                         is_unique: false,
+                        unique_constraint_name: None,
                         is_id: false,
+                        id_constraint_name: None,
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_version: false,
+                        validations: Vec::new(),
+                        computed: None,
                         is_commented_out: false,
+                        is_ignored: false,
                     }),
                     Field::ScalarField(ScalarField {
                         name: "float_default".to_string(),
@@ -292,11 +348,17 @@ mod tests {
                         database_name: None,
                         default_value: Some(dml::DefaultValue::Single(PrismaValue::Float(1.into()))),
                         is_unique: false,
+                        unique_constraint_name: None,
                         is_id: false,
+                        id_constraint_name: None,
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_version: false,
+                        validations: Vec::new(),
+                        computed: None,
                         is_commented_out: false,
+                        is_ignored: false,
                     }),
                     Field::ScalarField(ScalarField {
                         name: "string_default".to_string(),
@@ -305,11 +367,17 @@ mod tests {
                         database_name: None,
                         default_value: Some(dml::DefaultValue::Single(PrismaValue::String("default".to_string()))),
                         is_unique: false,
+                        unique_constraint_name: None,
                         is_id: false,
+                        id_constraint_name: None,
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_version: false,
+                        validations: Vec::new(),
+                        computed: None,
                         is_commented_out: false,
+                        is_ignored: false,
                     }),
                 ],
                 is_generated: false,
@@ -317,57 +385,80 @@ mod tests {
                     name: Some("unique".into()),
                     fields: vec!["no_default".into(), "int_default".into()],
                     tpe: dml::IndexType::Unique,
+                    field_options: vec![],
+                    algorithm: None,
+                    predicate: None,
+                    constraint_name: None,
                 }],
                 id_fields: vec![],
+                id_constraint_name: None,
             }],
             enums: vec![],
+            composite_types: vec![],
         };
 
         let schema = SqlSchema {
             tables: vec![Table {
                 name: "Table1".to_string(),
+                schema: None,
                 columns: vec![
                     Column {
                         name: "no_default".to_string(),
                         tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
                         default: None,
                         auto_increment: false,
+                        generated: None,
+                        comment: None,
                     },
                     Column {
                         name: "int_default".to_string(),
                         tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
                         default: Some(DefaultValue::value(PrismaValue::Int(1))),
                         auto_increment: false,
+                        generated: None,
+                        comment: None,
                     },
                     Column {
                         name: "bool_default".to_string(),
                         tpe: ColumnType::pure(ColumnTypeFamily::Boolean, ColumnArity::Nullable),
                         default: Some(DefaultValue::value(PrismaValue::Boolean(true))),
                         auto_increment: false,
+                        generated: None,
+                        comment: None,
                     },
                     Column {
                         name: "float_default".to_string(),
                         tpe: ColumnType::pure(ColumnTypeFamily::Float, ColumnArity::Nullable),
                         default: Some(DefaultValue::value(PrismaValue::new_float(1.0))),
                         auto_increment: false,
+                        generated: None,
+                        comment: None,
                     },
                     Column {
                         name: "string_default".to_string(),
                         tpe: ColumnType::pure(ColumnTypeFamily::String, ColumnArity::Nullable),
                         default: Some(DefaultValue::value(PrismaValue::String("default".to_string()))),
                         auto_increment: false,
+                        generated: None,
+                        comment: None,
                     },
                 ],
                 indices: vec![Index {
                     name: "unique".to_string(),
                     columns: vec!["no_default".into(), "int_default".into()],
                     tpe: IndexType::Unique,
+                    column_options: vec![],
+                    algorithm: None,
+                    predicate: None,
                 }],
                 primary_key: None,
                 foreign_keys: vec![],
+                check_constraints: vec![],
+                comment: None,
             }],
             enums: vec![],
             sequences: vec![],
+            views: vec![],
         };
         let introspection_result =
             calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
@@ -385,7 +476,11 @@ mod tests {
                     name: "Table1".to_string(),
                     documentation: None,
                     is_embedded: false,
+                    is_view: false,
+                    schema: None,
+                    check_constraints: vec![],
                     is_commented_out: false,
+                    is_ignored: false,
                     fields: vec![Field::ScalarField(ScalarField {
                         name: "primary".to_string(),
                         arity: FieldArity::Required,
@@ -393,15 +488,22 @@ mod tests {
                         database_name: None,
                         default_value: Some(DMLDefault::Expression(ValueGenerator::new_autoincrement())),
                         is_unique: false,
+                        unique_constraint_name: None,
                         is_id: true,
+                        id_constraint_name: None,
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_version: false,
+                        validations: Vec::new(),
+                        computed: None,
                         is_commented_out: false,
+                        is_ignored: false,
                     })],
                     is_generated: false,
                     indices: vec![],
                     id_fields: vec![],
+                    id_constraint_name: None,
                 },
                 // Model with non-auto-incrementing primary key
                 Model {
@@ -409,7 +511,11 @@ mod tests {
                     name: "Table2".to_string(),
                     documentation: None,
                     is_embedded: false,
+                    is_view: false,
+                    schema: None,
+                    check_constraints: vec![],
                     is_commented_out: false,
+                    is_ignored: false,
                     fields: vec![Field::ScalarField(ScalarField {
                         name: "primary".to_string(),
                         arity: FieldArity::Required,
@@ -417,15 +523,22 @@ mod tests {
                         database_name: None,
                         default_value: None,
                         is_unique: false,
+                        unique_constraint_name: None,
                         is_id: true,
+                        id_constraint_name: None,
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_version: false,
+                        validations: Vec::new(),
+                        computed: None,
                         is_commented_out: false,
+                        is_ignored: false,
                     })],
                     is_generated: false,
                     indices: vec![],
                     id_fields: vec![],
+                    id_constraint_name: None,
                 },
                 // Model with primary key seeded by sequence
                 Model {
@@ -433,7 +546,11 @@ mod tests {
                     name: "Table3".to_string(),
                     documentation: None,
                     is_embedded: false,
+                    is_view: false,
+                    schema: None,
+                    check_constraints: vec![],
                     is_commented_out: false,
+                    is_ignored: false,
                     fields: vec![Field::ScalarField(ScalarField {
                         name: "primary".to_string(),
                         arity: FieldArity::Required,
@@ -441,24 +558,33 @@ mod tests {
                         database_name: None,
                         default_value: Some(DMLDefault::Expression(ValueGenerator::new_autoincrement())),
                         is_unique: false,
+                        unique_constraint_name: None,
                         is_id: true,
+                        id_constraint_name: None,
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_version: false,
+                        validations: Vec::new(),
+                        computed: None,
                         is_commented_out: false,
+                        is_ignored: false,
                     })],
                     is_generated: false,
                     indices: vec![],
                     id_fields: vec![],
+                    id_constraint_name: None,
                 },
             ],
             enums: vec![],
+            composite_types: vec![],
         };
 
         let schema = SqlSchema {
             tables: vec![
                 Table {
                     name: "Table1".to_string(),
+                    schema: None,
                     columns: vec![Column {
                         name: "primary".to_string(),
                         tpe: ColumnType {
@@ -471,6 +597,8 @@ mod tests {
                         },
                         default: None,
                         auto_increment: true,
+                        generated: None,
+                        comment: None,
                     }],
                     indices: vec![],
                     primary_key: Some(PrimaryKey {
@@ -479,9 +607,12 @@ mod tests {
                         constraint_name: None,
                     }),
                     foreign_keys: vec![],
+                    check_constraints: vec![],
+                    comment: None,
                 },
                 Table {
                     name: "Table2".to_string(),
+                    schema: None,
                     columns: vec![Column {
                         name: "primary".to_string(),
                         tpe: ColumnType {
@@ -494,6 +625,8 @@ mod tests {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: None,
+                        comment: None,
                     }],
                     indices: vec![],
                     primary_key: Some(PrimaryKey {
@@ -502,9 +635,12 @@ mod tests {
                         constraint_name: None,
                     }),
                     foreign_keys: vec![],
+                    check_constraints: vec![],
+                    comment: None,
                 },
                 Table {
                     name: "Table3".to_string(),
+                    schema: None,
                     columns: vec![Column {
                         name: "primary".to_string(),
                         tpe: ColumnType {
@@ -517,6 +653,8 @@ mod tests {
                         },
                         default: None,
                         auto_increment: true,
+                        generated: None,
+                        comment: None,
                     }],
                     indices: vec![],
                     primary_key: Some(PrimaryKey {
@@ -527,10 +665,13 @@ mod tests {
                         constraint_name: None,
                     }),
                     foreign_keys: vec![],
+                    check_constraints: vec![],
+                    comment: None,
                 },
             ],
             enums: vec![],
             sequences: vec![],
+            views: vec![],
         };
         let introspection_result =
             calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
@@ -546,7 +687,11 @@ mod tests {
                 name: "Table1".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
+                schema: None,
+                check_constraints: vec![],
                 is_commented_out: false,
+                is_ignored: false,
                 fields: vec![
                     Field::ScalarField(ScalarField::new(
                         "non_unique",
@@ -560,47 +705,66 @@ mod tests {
                         database_name: None,
                         default_value: None,
                         is_unique: true,
+                        unique_constraint_name: None,
                         is_id: false,
+                        id_constraint_name: None,
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_version: false,
+                        validations: Vec::new(),
+                        computed: None,
                         is_commented_out: false,
+                        is_ignored: false,
                     }),
                 ],
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                id_constraint_name: None,
             }],
             enums: vec![],
+            composite_types: vec![],
         };
 
         let schema = SqlSchema {
             tables: vec![Table {
                 name: "Table1".to_string(),
+                schema: None,
                 columns: vec![
                     Column {
                         name: "non_unique".to_string(),
                         tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Nullable),
                         default: None,
                         auto_increment: false,
+                        generated: None,
+                        comment: None,
                     },
                     Column {
                         name: "unique".to_string(),
                         tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
                         default: None,
                         auto_increment: false,
+                        generated: None,
+                        comment: None,
                     },
                 ],
                 indices: vec![Index {
                     name: "unique".to_string(),
                     columns: vec!["unique".to_string()],
                     tpe: IndexType::Unique,
+                    column_options: vec![],
+                    algorithm: None,
+                    predicate: None,
                 }],
                 primary_key: None,
                 foreign_keys: vec![],
+                check_constraints: vec![],
+                comment: None,
             }],
             enums: vec![],
             sequences: vec![],
+            views: vec![],
         };
         let introspection_result =
             calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
@@ -617,7 +781,11 @@ mod tests {
                     name: "City".to_string(),
                     documentation: None,
                     is_embedded: false,
+                    is_view: false,
+                    schema: None,
+                    check_constraints: vec![],
                     is_commented_out: false,
+                    is_ignored: false,
                     fields: vec![
                         Field::ScalarField(ScalarField {
                             name: "id".to_string(),
@@ -626,11 +794,17 @@ mod tests {
                             database_name: None,
                             default_value: Some(DMLDefault::Expression(ValueGenerator::new_autoincrement())),
                             is_unique: false,
+                            unique_constraint_name: None,
                             is_id: true,
+                            id_constraint_name: None,
                             documentation: None,
                             is_generated: false,
                             is_updated_at: false,
+                            is_version: false,
+                            validations: Vec::new(),
+                            computed: None,
                             is_commented_out: false,
+                            is_ignored: false,
                         }),
                         Field::ScalarField(ScalarField::new(
                             "name",
@@ -646,19 +820,25 @@ mod tests {
                                 references: vec![],
                                 name: "CityToUser".to_string(),
                                 on_delete: OnDeleteStrategy::None,
+                                constraint_name: None,
                             },
                         )),
                     ],
                     is_generated: false,
                     indices: vec![],
                     id_fields: vec![],
+                    id_constraint_name: None,
                 },
                 Model {
                     database_name: None,
                     name: "User".to_string(),
                     documentation: None,
                     is_embedded: false,
+                    is_view: false,
+                    schema: None,
+                    check_constraints: vec![],
                     is_commented_out: false,
+                    is_ignored: false,
                     fields: vec![
                         Field::ScalarField(ScalarField {
                             name: "id".to_string(),
@@ -667,11 +847,17 @@ mod tests {
                             database_name: None,
                             default_value: Some(DMLDefault::Expression(ValueGenerator::new_autoincrement())),
                             is_unique: false,
+                            unique_constraint_name: None,
                             is_id: true,
+                            id_constraint_name: None,
                             documentation: None,
                             is_generated: false,
                             is_updated_at: false,
+                            is_version: false,
+                            validations: Vec::new(),
+                            computed: None,
                             is_commented_out: false,
+                            is_ignored: false,
                         }),
                         Field::ScalarField(ScalarField {
                             name: "city_id".to_string(),
@@ -680,11 +866,17 @@ mod tests {
                             database_name: Some("city-id".to_string()),
                             default_value: None,
                             is_unique: false,
+                            unique_constraint_name: None,
                             is_id: false,
+                            id_constraint_name: None,
                             documentation: None,
                             is_generated: false,
                             is_updated_at: false,
+                            is_version: false,
+                            validations: Vec::new(),
+                            computed: None,
                             is_commented_out: false,
+                            is_ignored: false,
                         }),
                         Field::ScalarField(ScalarField {
                             name: "city_name".to_string(),
@@ -693,11 +885,17 @@ mod tests {
                             database_name: Some("city-name".to_string()),
                             default_value: None,
                             is_unique: false,
+                            unique_constraint_name: None,
                             is_id: false,
+                            id_constraint_name: None,
                             documentation: None,
                             is_generated: false,
                             is_updated_at: false,
+                            is_version: false,
+                            validations: Vec::new(),
+                            computed: None,
                             is_commented_out: false,
+                            is_ignored: false,
                         }),
                         Field::RelationField(RelationField::new(
                             "City",
@@ -708,21 +906,25 @@ mod tests {
                                 fields: vec!["city_id".to_string(), "city_name".to_string()],
                                 references: vec!["id".to_string(), "name".to_string()],
                                 on_delete: OnDeleteStrategy::None,
+                                constraint_name: None,
                             },
                         )),
                     ],
                     is_generated: false,
                     indices: vec![],
                     id_fields: vec![],
+                    id_constraint_name: None,
                 },
             ],
             enums: vec![],
+            composite_types: vec![],
         };
 
         let schema = SqlSchema {
             tables: vec![
                 Table {
                     name: "City".to_string(),
+                    schema: None,
                     columns: vec![
                         Column {
                             name: "id".to_string(),
@@ -737,6 +939,8 @@ mod tests {
                             },
                             default: None,
                             auto_increment: true,
+                            generated: None,
+                            comment: None,
                         },
                         Column {
                             name: "name".to_string(),
@@ -751,6 +955,8 @@ mod tests {
                             },
                             default: None,
                             auto_increment: false,
+                            generated: None,
+                            comment: None,
                         },
                     ],
                     indices: vec![],
@@ -760,9 +966,12 @@ mod tests {
                         constraint_name: None,
                     }),
                     foreign_keys: vec![],
+                    check_constraints: vec![],
+                    comment: None,
                 },
                 Table {
                     name: "User".to_string(),
+                    schema: None,
                     columns: vec![
                         Column {
                             name: "id".to_string(),
@@ -777,6 +986,8 @@ mod tests {
                             },
                             default: None,
                             auto_increment: true,
+                            generated: None,
+                            comment: None,
                         },
                         Column {
                             name: "city-id".to_string(),
@@ -791,6 +1002,8 @@ mod tests {
                             },
                             default: None,
                             auto_increment: false,
+                            generated: None,
+                            comment: None,
                         },
                         Column {
                             name: "city-name".to_string(),
@@ -805,6 +1018,8 @@ mod tests {
                             },
                             default: None,
                             auto_increment: false,
+                            generated: None,
+                            comment: None,
                         },
                     ],
                     indices: vec![],
@@ -818,14 +1033,18 @@ mod tests {
                         constraint_name: None,
                         columns: vec!["city-id".to_string(), "city-name".to_string()],
                         referenced_table: "City".to_string(),
+                        referenced_schema: None,
                         on_delete_action: ForeignKeyAction::NoAction,
                         on_update_action: ForeignKeyAction::NoAction,
                         referenced_columns: vec!["id".to_string(), "name".to_string()],
                     }],
+                    check_constraints: vec![],
+                    comment: None,
                 },
             ],
             enums: vec![],
             sequences: vec![],
+            views: vec![],
         };
         let introspection_result =
             calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
@@ -841,7 +1060,11 @@ mod tests {
                 name: "User".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
+                schema: None,
+                check_constraints: vec![],
                 is_commented_out: false,
+                is_ignored: false,
                 fields: vec![
                     Field::ScalarField(ScalarField {
                         name: "id".to_string(),
@@ -850,11 +1073,17 @@ mod tests {
                         database_name: None,
                         default_value: Some(DMLDefault::Expression(ValueGenerator::new_autoincrement())),
                         is_unique: false,
+                        unique_constraint_name: None,
                         is_id: true,
+                        id_constraint_name: None,
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
+                        is_version: false,
+                        validations: Vec::new(),
+                        computed: None,
                         is_commented_out: false,
+                        is_ignored: false,
                     }),
                     Field::ScalarField(ScalarField::new(
                         "name",
@@ -872,15 +1101,22 @@ mod tests {
                     name: Some("name_last_name_unique".to_string()),
                     fields: vec!["name".to_string(), "lastname".to_string()],
                     tpe: datamodel::dml::IndexType::Unique,
+                    field_options: vec![],
+                    algorithm: None,
+                    predicate: None,
+                    constraint_name: None,
                 }],
                 id_fields: vec![],
+                id_constraint_name: None,
             }],
             enums: vec![],
+            composite_types: vec![],
         };
 
         let schema = SqlSchema {
             tables: vec![Table {
                 name: "User".to_string(),
+                schema: None,
                 columns: vec![
                     Column {
                         name: "id".to_string(),
@@ -895,6 +1131,8 @@ mod tests {
                         },
                         default: None,
                         auto_increment: true,
+                        generated: None,
+                        comment: None,
                     },
                     Column {
                         name: "name".to_string(),
@@ -909,6 +1147,8 @@ mod tests {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: None,
+                        comment: None,
                     },
                     Column {
                         name: "lastname".to_string(),
@@ -923,12 +1163,17 @@ mod tests {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: None,
+                        comment: None,
                     },
                 ],
                 indices: vec![Index {
                     name: "name_last_name_unique".to_string(),
                     columns: vec!["name".to_string(), "lastname".to_string()],
                     tpe: IndexType::Unique,
+                    column_options: vec![],
+                    algorithm: None,
+                    predicate: None,
                 }],
                 primary_key: Some(PrimaryKey {
                     columns: vec!["id".to_string()],
@@ -936,9 +1181,12 @@ mod tests {
                     constraint_name: None,
                 }),
                 foreign_keys: vec![],
+                check_constraints: vec![],
+                comment: None,
             }],
             enums: vec![],
             sequences: vec![],
+            views: vec![],
         };
         let introspection_result =
             calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
@@ -955,7 +1203,11 @@ mod tests {
                     name: "City".to_string(),
                     documentation: None,
                     is_embedded: false,
+                    is_view: false,
+                    schema: None,
+                    check_constraints: vec![],
                     is_commented_out: false,
+                    is_ignored: false,
                     fields: vec![
                         Field::ScalarField(ScalarField {
                             name: "id".to_string(),
@@ -964,11 +1216,17 @@ mod tests {
                             database_name: None,
                             default_value: Some(DMLDefault::Expression(ValueGenerator::new_autoincrement())),
                             is_unique: false,
+                            unique_constraint_name: None,
                             is_id: true,
+                            id_constraint_name: None,
                             documentation: None,
                             is_generated: false,
                             is_updated_at: false,
+                            is_version: false,
+                            validations: Vec::new(),
+                            computed: None,
                             is_commented_out: false,
+                            is_ignored: false,
                         }),
                         Field::ScalarField(ScalarField::new(
                             "name",
@@ -984,19 +1242,25 @@ mod tests {
                                 references: vec![],
                                 name: "CityToUser".to_string(),
                                 on_delete: OnDeleteStrategy::None,
+                                constraint_name: None,
                             },
                         )),
                     ],
                     is_generated: false,
                     indices: vec![],
                     id_fields: vec![],
+                    id_constraint_name: None,
                 },
                 Model {
                     database_name: None,
                     name: "User".to_string(),
                     documentation: None,
                     is_embedded: false,
+                    is_view: false,
+                    schema: None,
+                    check_constraints: vec![],
                     is_commented_out: false,
+                    is_ignored: false,
                     fields: vec![
                         Field::ScalarField(ScalarField {
                             name: "id".to_string(),
@@ -1005,11 +1269,17 @@ mod tests {
                             database_name: None,
                             default_value: Some(DMLDefault::Expression(ValueGenerator::new_autoincrement())),
                             is_unique: false,
+                            unique_constraint_name: None,
                             is_id: true,
+                            id_constraint_name: None,
                             documentation: None,
                             is_generated: false,
                             is_updated_at: false,
+                            is_version: false,
+                            validations: Vec::new(),
+                            computed: None,
                             is_commented_out: false,
+                            is_ignored: false,
                         }),
                         Field::ScalarField(ScalarField::new(
                             "city_id",
@@ -1025,21 +1295,25 @@ mod tests {
                                 fields: vec!["city_id".to_string()],
                                 references: vec!["id".to_string()],
                                 on_delete: OnDeleteStrategy::None,
+                                constraint_name: None,
                             },
                         )),
                     ],
                     is_generated: false,
                     indices: vec![],
                     id_fields: vec![],
+                    id_constraint_name: None,
                 },
             ],
             enums: vec![],
+            composite_types: vec![],
         };
 
         let schema = SqlSchema {
             tables: vec![
                 Table {
                     name: "City".to_string(),
+                    schema: None,
                     columns: vec![
                         Column {
                             name: "id".to_string(),
@@ -1054,6 +1328,8 @@ mod tests {
                             },
                             default: None,
                             auto_increment: true,
+                            generated: None,
+                            comment: None,
                         },
                         Column {
                             name: "name".to_string(),
@@ -1068,6 +1344,8 @@ mod tests {
                             },
                             default: None,
                             auto_increment: false,
+                            generated: None,
+                            comment: None,
                         },
                     ],
                     indices: vec![],
@@ -1077,9 +1355,12 @@ mod tests {
                         constraint_name: None,
                     }),
                     foreign_keys: vec![],
+                    check_constraints: vec![],
+                    comment: None,
                 },
                 Table {
                     name: "User".to_string(),
+                    schema: None,
                     columns: vec![
                         Column {
                             name: "id".to_string(),
@@ -1094,6 +1375,8 @@ mod tests {
                             },
                             default: None,
                             auto_increment: true,
+                            generated: None,
+                            comment: None,
                         },
                         Column {
                             name: "city_id".to_string(),
@@ -1108,6 +1391,8 @@ mod tests {
                             },
                             default: None,
                             auto_increment: false,
+                            generated: None,
+                            comment: None,
                         },
                     ],
                     indices: vec![],
@@ -1120,14 +1405,18 @@ mod tests {
                         constraint_name: None,
                         columns: vec!["city_id".to_string()],
                         referenced_table: "City".to_string(),
+                        referenced_schema: None,
                         on_delete_action: ForeignKeyAction::NoAction,
                         on_update_action: ForeignKeyAction::NoAction,
                         referenced_columns: vec!["id".to_string()],
                     }],
+                    check_constraints: vec![],
+                    comment: None,
                 },
             ],
             enums: vec![],
             sequences: vec![],
+            views: vec![],
         };
         let introspection_result =
             calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
@@ -1158,7 +1447,9 @@ mod tests {
                         commented_out: false,
                     },
                 ],
+                schema: None,
             }],
+            composite_types: vec![],
         };
 
         let enum_values = vec!["a".to_string(), "b".to_string()];
@@ -1166,9 +1457,11 @@ mod tests {
             tables: vec![],
             enums: vec![Enum {
                 name: "Enum".to_string(),
+                schema: None,
                 values: enum_values,
             }],
             sequences: vec![],
+            views: vec![],
         };
         let introspection_result =
             calculate_datamodel(&schema, &SqlFamily::Postgres, &Datamodel::new(), false).expect("calculate data model");
@@ -97,29 +97,43 @@ pub fn commenting_out_guardrails(datamodel: &mut Datamodel, family: &SqlFamily)
         }
     }
 
-    // models without uniques / ids
+    // models without uniques / ids are kept, but ignored by the client
     for model in datamodel
         .models_mut()
         .filter(|model| !models_without_columns.iter().any(|m| m.model == model.name))
     {
         if model.strict_unique_criterias().is_empty() {
-            model.is_commented_out = true;
-            model.documentation = Some(
+            model.is_ignored = true;
+
+            let comment = if model.is_view {
+                "The underlying view does not contain a valid unique identifier and can therefore currently not be handled. Please add an @id or @unique attribute to a field that uniquely identifies its rows."
+            } else {
                 "The underlying table does not contain a valid unique identifier and can therefore currently not be handled."
-                    .to_string(),
-            );
+            };
+
+            // Keep the comment of the table.
+            model.documentation = Some(match model.documentation.take() {
+                Some(docs) => format!("{}\n{}", docs, comment),
+                None => comment.to_string(),
+            });
             models_without_identifiers.push(Model {
                 model: model.name.clone(),
             })
         }
     }
 
-    // remove their backrelations
-    for model_without_identifier in &models_without_identifiers {
-        for model in datamodel.models_mut() {
+    // ignore the backrelations of ignored models, including the ones ignored in the previous datamodel
+    let ignored_models: Vec<String> = datamodel
+        .models()
+        .filter(|model| model.is_ignored)
+        .map(|model| model.name.clone())
+        .collect();
+
+    for ignored_model in &ignored_models {
+        for model in datamodel.models_mut().filter(|model| !model.is_ignored) {
             for field in model.relation_fields_mut() {
-                if field.points_to_model(&model_without_identifier.model) {
-                    field.is_commented_out = true;
+                if field.points_to_model(ignored_model) {
+                    field.is_ignored = true;
                 }
             }
         }
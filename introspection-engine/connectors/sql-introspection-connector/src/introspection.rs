@@ -1,7 +1,7 @@
 use crate::introspection_helpers::{
-    calculate_backrelation_field, calculate_index, calculate_many_to_many_field, calculate_relation_field,
-    calculate_scalar_field, is_new_migration_table, is_old_migration_table, is_prisma_1_point_0_join_table,
-    is_prisma_1_point_1_or_2_join_table, is_relay_table,
+    calculate_backrelation_field, calculate_index, calculate_many_to_many_field, calculate_primary_key_constraint_name,
+    calculate_relation_field, calculate_scalar_field, is_new_migration_table, is_old_migration_table,
+    is_prisma_1_point_0_join_table, is_prisma_1_point_1_or_2_join_table, is_relay_table,
 };
 use crate::version_checker::VersionChecker;
 use crate::Dedup;
@@ -29,6 +29,8 @@ pub fn introspect(
     {
         debug!("Calculating model: {}", table.name);
         let mut model = Model::new(table.name.clone(), None);
+        model.schema = table.schema.clone();
+        model.documentation = table.comment.clone();
 
         for column in &table.columns {
             version_check.check_column_for_type_and_default_value(&column);
@@ -50,20 +52,30 @@ pub fn introspect(
         }) {
             version_check.has_inline_relations(table);
             version_check.uses_on_delete(foreign_key, table);
-            let relation_field = calculate_relation_field(schema, table, foreign_key)?;
+            let relation_field = calculate_relation_field(schema, table, foreign_key, &sql_family)?;
             model.add_field(Field::RelationField(relation_field));
         }
 
         for index in table
             .indices
             .iter()
-            .filter(|i| !(i.columns.len() == 1 && i.is_unique()))
+            .filter(|i| !(i.columns.len() == 1 && i.is_unique() && i.has_default_options()))
         {
             model.add_index(calculate_index(index));
         }
 
+        model.check_constraints = table
+            .check_constraints
+            .iter()
+            .map(|check_constraint| dml::CheckConstraint {
+                name: check_constraint.name.clone(),
+                expression: check_constraint.expression.clone(),
+            })
+            .collect();
+
         if table.primary_key_columns().len() > 1 {
             model.id_fields = table.primary_key_columns();
+            model.id_constraint_name = calculate_primary_key_constraint_name(table, &sql_family);
         }
 
         version_check.always_has_created_at_updated_at(table, &model);
@@ -72,9 +84,36 @@ pub fn introspect(
         data_model.add_model(model);
     }
 
+    for view in schema.views.iter() {
+        debug!("Calculating view: {}", view.name);
+        let mut model = Model::new(view.name.clone(), None);
+        model.is_view = true;
+
+        // Views have no keys of their own, identifiers can only be restored from a previous data model.
+        let table = Table {
+            name: view.name.clone(),
+            schema: None,
+            columns: view.columns.clone(),
+            indices: vec![],
+            primary_key: None,
+            foreign_keys: vec![],
+            check_constraints: vec![],
+            comment: None,
+        };
+
+        for column in &table.columns {
+            let field = calculate_scalar_field(&table, &column, &sql_family, native_types);
+            model.add_field(Field::ScalarField(field));
+        }
+
+        data_model.add_model(model);
+    }
+
     for e in schema.enums.iter() {
         let values = e.values.iter().map(|v| dml::EnumValue::new(v)).collect();
-        data_model.add_enum(dml::Enum::new(&e.name, values));
+        let mut r#enum = dml::Enum::new(&e.name, values);
+        r#enum.schema = e.schema.clone();
+        data_model.add_enum(r#enum);
     }
 
     let mut fields_to_be_added = Vec::new();
@@ -117,7 +156,8 @@ fn calculate_fields_for_prisma_join_table(
     data_model: &mut Datamodel,
 ) {
     if let (Some(fk_a), Some(fk_b)) = (join_table.foreign_keys.get(0), join_table.foreign_keys.get(1)) {
-        let is_self_relation = fk_a.referenced_table == fk_b.referenced_table;
+        let is_self_relation =
+            fk_a.referenced_table == fk_b.referenced_table && fk_a.referenced_schema == fk_b.referenced_schema;
 
         for (fk, opposite_fk) in &[(fk_a, fk_b), (fk_b, fk_a)] {
             let referenced_model = find_model_by_db_name(&data_model, &fk.referenced_table)
@@ -1,14 +1,17 @@
 use crate::Dedup;
 use crate::SqlError;
 use datamodel::{
-    common::RelationNames, Datamodel, DefaultValue as DMLDef, FieldArity, FieldType, IndexDefinition, Model,
-    OnDeleteStrategy, RelationField, RelationInfo, ScalarField, ScalarType, ValueGenerator as VG,
+    common::RelationNames, ComputedValue, Datamodel, DefaultValue as DMLDef, FieldArity, FieldType, IndexDefinition,
+    IndexFieldOptions, Model, OnDeleteStrategy, RelationField, RelationInfo, ScalarField, ScalarType,
+    ValueGenerator as VG,
 };
 use datamodel_connector::Connector;
 use quaint::connector::SqlFamily;
 use sql_datamodel_connector::SqlDatamodelConnectors;
 use sql_schema_describer::DefaultKind;
-use sql_schema_describer::{Column, ColumnArity, ColumnTypeFamily, ForeignKey, Index, IndexType, SqlSchema, Table};
+use sql_schema_describer::{
+    Column, ColumnArity, ColumnTypeFamily, ForeignKey, Index, IndexAlgorithm, IndexType, SortOrder, SqlSchema, Table,
+};
 use tracing::debug;
 
 //checks
@@ -108,6 +111,7 @@ pub fn calculate_many_to_many_field(
         to: opposite_foreign_key.referenced_table.clone(),
         references: opposite_foreign_key.referenced_columns.clone(),
         on_delete: OnDeleteStrategy::None,
+        constraint_name: None,
     };
 
     let basename = opposite_foreign_key.referenced_table.clone();
@@ -125,12 +129,36 @@ pub(crate) fn calculate_index(index: &Index) -> IndexDefinition {
     let tpe = match index.tpe {
         IndexType::Unique => datamodel::dml::IndexType::Unique,
         IndexType::Normal => datamodel::dml::IndexType::Normal,
+        IndexType::Fulltext => datamodel::dml::IndexType::Fulltext,
     };
 
+    let field_options = index
+        .column_options
+        .iter()
+        .map(|options| IndexFieldOptions {
+            sort_order: match options.sort_order {
+                SortOrder::Asc => datamodel::SortOrder::Asc,
+                SortOrder::Desc => datamodel::SortOrder::Desc,
+            },
+            length: options.length,
+        })
+        .collect();
+
+    let algorithm = index.algorithm.map(|algorithm| match algorithm {
+        IndexAlgorithm::Hash => datamodel::IndexAlgorithm::Hash,
+        IndexAlgorithm::Gin => datamodel::IndexAlgorithm::Gin,
+        IndexAlgorithm::Gist => datamodel::IndexAlgorithm::Gist,
+        IndexAlgorithm::Brin => datamodel::IndexAlgorithm::Brin,
+    });
+
     IndexDefinition {
         name: Some(index.name.clone()),
         fields: index.columns.clone(),
         tpe,
+        field_options,
+        algorithm,
+        predicate: index.predicate.clone(),
+        constraint_name: None,
     }
 }
 
@@ -155,32 +183,39 @@ pub(crate) fn calculate_scalar_field(
         ColumnArity::List => FieldArity::List,
     };
 
-    let (default_value, dbgenerated_string) = calculate_default(table, &column, &arity);
-
-    let (is_commented_out, documentation) = match (&field_type, dbgenerated_string) {
-        (FieldType::Unsupported(_), None) => (true, Some("This type is currently not supported.".to_string())),
-        (FieldType::Unsupported(_), Some(default)) => (
-            true,
-            Some(format!(
-                "This type is currently not supported.\nThe value of this field is generated by the database as: `{}`.",
-                default
-            )),
-        ),
-        (_, Some(default)) => (
-            false,
-            Some(
-                format!(
-                    "The value of this field is generated by the database as: `{}`.",
-                    default
-                )
-                .to_string(),
-            ),
-        ),
+    let default_value = calculate_default(table, &column);
+
+    let (is_commented_out, documentation) = match &field_type {
+        FieldType::Unsupported(_) => (true, Some("This type is currently not supported.".to_string())),
         _ => (false, None),
     };
 
+    // The comment of the column comes first, then our notes about it.
+    let documentation = match (&column.comment, documentation) {
+        (Some(comment), Some(notes)) => Some(format!("{}\n{}", comment, notes)),
+        (comment, notes) => notes.or_else(|| comment.clone()),
+    };
+
     let is_unique = table.is_column_unique(&column.name) && !is_id;
 
+    let unique_constraint_name = if is_unique {
+        table
+            .indices
+            .iter()
+            .find(|index| index.is_unique() && index.columns == [column.name.as_str()] && index.has_default_options())
+            .map(|index| index.name.as_str())
+            .filter(|name| !is_default_unique_constraint_name(table, &column.name, name, family))
+            .map(String::from)
+    } else {
+        None
+    };
+
+    let id_constraint_name = if is_id {
+        calculate_primary_key_constraint_name(table, family)
+    } else {
+        None
+    };
+
     ScalarField {
         name: column.name.clone(),
         arity,
@@ -188,11 +223,20 @@ pub(crate) fn calculate_scalar_field(
         database_name: None,
         default_value,
         is_unique,
+        unique_constraint_name,
         is_id,
+        id_constraint_name,
         documentation,
         is_generated: false,
         is_updated_at: false,
+        is_version: false,
+        validations: Vec::new(),
+        computed: column.generated.as_ref().map(|generated| ComputedValue {
+            expression: generated.expression.clone(),
+            stored: generated.stored,
+        }),
         is_commented_out,
+        is_ignored: false,
     }
 }
 
@@ -200,15 +244,23 @@ pub(crate) fn calculate_relation_field(
     schema: &SqlSchema,
     table: &Table,
     foreign_key: &ForeignKey,
+    family: &SqlFamily,
 ) -> Result<RelationField, SqlError> {
     debug!("Handling foreign key  {:?}", foreign_key);
 
+    let constraint_name = foreign_key
+        .constraint_name
+        .as_ref()
+        .filter(|name| !is_default_foreign_key_name(table, foreign_key, name, family))
+        .cloned();
+
     let relation_info = RelationInfo {
         name: calculate_relation_name(schema, foreign_key, table)?,
         fields: foreign_key.columns.clone(),
         to: foreign_key.referenced_table.clone(),
         references: foreign_key.referenced_columns.clone(),
         on_delete: OnDeleteStrategy::None,
+        constraint_name,
     };
 
     let columns: Vec<&Column> = foreign_key
@@ -225,6 +277,60 @@ pub(crate) fn calculate_relation_field(
     Ok(RelationField::new(&foreign_key.referenced_table, arity, relation_info))
 }
 
+/// The name of the primary key of the table, if it differs from the name the migration engine
+/// or the database would pick.
+pub(crate) fn calculate_primary_key_constraint_name(table: &Table, family: &SqlFamily) -> Option<String> {
+    let name = table.primary_key.as_ref()?.constraint_name.as_ref()?;
+
+    let is_default = match family {
+        SqlFamily::Postgres => *name == format!("{}_pkey", table.name),
+        // Generated names look like `PK__User__3213E83F1DA1C6D3`.
+        SqlFamily::Mssql => name.starts_with("PK__"),
+        SqlFamily::Mysql | SqlFamily::Sqlite => true,
+    };
+
+    if is_default {
+        None
+    } else {
+        Some(name.clone())
+    }
+}
+
+/// Whether the unique constraint of a single column has the name the migration engine or the
+/// database would pick.
+fn is_default_unique_constraint_name(table: &Table, column_name: &str, name: &str, family: &SqlFamily) -> bool {
+    let migration_engine_name = match family {
+        SqlFamily::Mssql => format!("{}_{}_unique", table.name, column_name),
+        _ => format!("{}.{}_unique", table.name, column_name),
+    };
+
+    name == migration_engine_name
+        || match family {
+            SqlFamily::Postgres => name == format!("{}_{}_key", table.name, column_name),
+            // MySQL names the index after the column, with a numeric suffix on conflicts.
+            SqlFamily::Mysql => name == column_name || has_numeric_suffix(name, &format!("{}_", column_name)),
+            SqlFamily::Sqlite => has_numeric_suffix(name, &format!("sqlite_autoindex_{}_", table.name)),
+            SqlFamily::Mssql => name.starts_with("UQ__"),
+        }
+}
+
+/// Whether the foreign key has the name the migration engine or the database would pick.
+fn is_default_foreign_key_name(table: &Table, foreign_key: &ForeignKey, name: &str, family: &SqlFamily) -> bool {
+    match family {
+        SqlFamily::Postgres => name == format!("{}_{}_fkey", table.name, foreign_key.columns.join("_")),
+        // MySQL numbers the foreign keys of a table: `Post_ibfk_1`, `Post_ibfk_2`...
+        SqlFamily::Mysql => has_numeric_suffix(name, &format!("{}_ibfk_", table.name)),
+        SqlFamily::Mssql => name.starts_with("FK__"),
+        SqlFamily::Sqlite => true,
+    }
+}
+
+fn has_numeric_suffix(name: &str, prefix: &str) -> bool {
+    name.strip_prefix(prefix)
+        .map(|suffix| !suffix.is_empty() && suffix.chars().all(|c| c.is_ascii_digit()))
+        .unwrap_or(false)
+}
+
 pub(crate) fn calculate_backrelation_field(
     schema: &SqlSchema,
     model: &Model,
@@ -243,6 +349,7 @@ pub(crate) fn calculate_backrelation_field(
                 fields: vec![],
                 references: vec![],
                 on_delete: OnDeleteStrategy::None,
+                constraint_name: None,
             };
 
             // unique or id
@@ -270,34 +377,63 @@ pub(crate) fn calculate_backrelation_field(
     }
 }
 
-pub(crate) fn calculate_default(
-    table: &Table,
-    column: &Column,
-    arity: &FieldArity,
-) -> (Option<DMLDef>, Option<String>) {
+pub(crate) fn calculate_default(table: &Table, column: &Column) -> Option<DMLDef> {
     match (column.default.as_ref().map(|d| d.kind()), &column.tpe.family) {
-        (_, _) if *arity == FieldArity::List => (None, None),
-        (_, ColumnTypeFamily::Int) if column.auto_increment => {
-            (Some(DMLDef::Expression(VG::new_autoincrement())), None)
-        }
-        (_, ColumnTypeFamily::BigInt) if column.auto_increment => {
-            (Some(DMLDef::Expression(VG::new_autoincrement())), None)
-        }
-        (_, ColumnTypeFamily::Int) if is_sequence(column, table) => {
-            (Some(DMLDef::Expression(VG::new_autoincrement())), None)
+        (Some(DefaultKind::SEQUENCE(sequence_name)), _) if !is_serial_sequence(table, column, sequence_name) => {
+            Some(DMLDef::Expression(VG::new_sequence(sequence_name.clone())))
         }
+        (_, ColumnTypeFamily::Int) if column.auto_increment => Some(DMLDef::Expression(VG::new_autoincrement())),
+        (_, ColumnTypeFamily::BigInt) if column.auto_increment => Some(DMLDef::Expression(VG::new_autoincrement())),
+        (_, ColumnTypeFamily::Int) if is_sequence(column, table) => Some(DMLDef::Expression(VG::new_autoincrement())),
         (_, ColumnTypeFamily::BigInt) if is_sequence(column, table) => {
-            (Some(DMLDef::Expression(VG::new_autoincrement())), None)
+            Some(DMLDef::Expression(VG::new_autoincrement()))
+        }
+        (Some(DefaultKind::SEQUENCE(_)), _) => Some(DMLDef::Expression(VG::new_autoincrement())),
+        (Some(DefaultKind::NOW), ColumnTypeFamily::DateTime) => Some(DMLDef::Expression(VG::new_now())),
+        (Some(DefaultKind::DBGENERATED(default_string)), _) => Some(DMLDef::Expression(
+            VG::new_dbgenerated_expression(default_string.clone()),
+        )),
+        (Some(DefaultKind::VALUE(val)), _) => Some(DMLDef::Single(val.clone())),
+        _ => None,
+    }
+}
+
+/// Postgres names the sequences of `SERIAL` columns `<table>_<column>_seq`, any other sequence
+/// was created on purpose.
+fn is_serial_sequence(table: &Table, column: &Column, sequence_name: &str) -> bool {
+    sequence_name == serial_sequence_name(&table.name, &column.name)
+}
+
+/// Names longer than 63 bytes are shortened the way Postgres does it: the longer of the table and
+/// column names is cut until the whole name fits, so the `_seq` suffix is kept.
+fn serial_sequence_name(table_name: &str, column_name: &str) -> String {
+    const MAX_IDENTIFIER_LENGTH: usize = 63;
+    const OVERHEAD: usize = "__seq".len();
+
+    let mut table_len = table_name.len();
+    let mut column_len = column_name.len();
+
+    while table_len + column_len > MAX_IDENTIFIER_LENGTH - OVERHEAD {
+        if table_len > column_len {
+            table_len -= 1;
+        } else {
+            column_len -= 1;
         }
-        (Some(DefaultKind::SEQUENCE(_)), _) => (Some(DMLDef::Expression(VG::new_autoincrement())), None),
-        (Some(DefaultKind::NOW), ColumnTypeFamily::DateTime) => (Some(DMLDef::Expression(VG::new_now())), None),
-        (Some(DefaultKind::DBGENERATED(default_string)), _) => (
-            Some(DMLDef::Expression(VG::new_dbgenerated())),
-            Some(default_string.clone()),
-        ),
-        (Some(DefaultKind::VALUE(val)), _) => (Some(DMLDef::Single(val.clone())), None),
-        _ => (None, None),
     }
+
+    format!(
+        "{}_{}_seq",
+        clip_to_char_boundary(table_name, table_len),
+        clip_to_char_boundary(column_name, column_len)
+    )
+}
+
+fn clip_to_char_boundary(name: &str, mut len: usize) -> &str {
+    while !name.is_char_boundary(len) {
+        len -= 1;
+    }
+
+    &name[..len]
 }
 
 pub(crate) fn is_id(column: &Column, table: &Table) -> bool {
@@ -326,20 +462,22 @@ pub(crate) fn calculate_relation_name(schema: &SqlSchema, fk: &ForeignKey, table
         .foreign_keys
         .clone()
         .into_iter()
-        .filter(|fk| &fk.referenced_table == referenced_model)
+        .filter(|other_fk| {
+            other_fk.referenced_table == fk.referenced_table && other_fk.referenced_schema == fk.referenced_schema
+        })
         .collect();
 
     fk_to_same_model.clear_duplicates();
 
-    match schema.table(referenced_model) {
-        Err(table_name) => Err(SqlError::SchemaInconsistent {
-            explanation: format!("Table {} not found.", table_name),
+    match schema.referenced_table(fk) {
+        None => Err(SqlError::SchemaInconsistent {
+            explanation: format!("Table {} not found.", referenced_model),
         }),
-        Ok(other_table) => {
+        Some(other_table) => {
             let fk_from_other_model_to_this: Vec<&ForeignKey> = other_table
                 .foreign_keys
                 .iter()
-                .filter(|fk| &fk.referenced_table == model_with_fk)
+                .filter(|fk| fk.references(table))
                 .collect();
 
             let name = if fk_to_same_model.len() < 2 && fk_from_other_model_to_this.is_empty() {
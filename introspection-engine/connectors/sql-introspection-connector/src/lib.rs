@@ -25,6 +25,8 @@ pub type SqlIntrospectionResult<T> = core::result::Result<T, SqlError>;
 pub struct SqlIntrospectionConnector {
     connection_info: ConnectionInfo,
     describer: Box<dyn SqlSchemaDescriberBackend>,
+    /// The schemas of the `schemas` datasource property, see `@@schema`.
+    schemas: Vec<String>,
 }
 
 impl fmt::Debug for SqlIntrospectionConnector {
@@ -32,12 +34,19 @@ impl fmt::Debug for SqlIntrospectionConnector {
         f.debug_struct("SqlIntrospectionConnector")
             .field("connection_info", &self.connection_info)
             .field("describer", &"Box<dyn SqlSchemaDescriberBackend>")
+            .field("schemas", &self.schemas)
             .finish()
     }
 }
 
 impl SqlIntrospectionConnector {
     pub async fn new(url: &str) -> ConnectorResult<SqlIntrospectionConnector> {
+        Self::new_with_schemas(url, Vec::new()).await
+    }
+
+    /// Construct a connector that also introspects the given database schemas, as listed in the
+    /// `schemas` property of the datasource.
+    pub async fn new_with_schemas(url: &str, schemas: Vec<String>) -> ConnectorResult<SqlIntrospectionConnector> {
         let (describer, connection_info) = schema_describer_loading::load_describer(&url)
             .instrument(tracing::debug_span!("Loading describer"))
             .await
@@ -52,6 +61,7 @@ impl SqlIntrospectionConnector {
         Ok(SqlIntrospectionConnector {
             describer,
             connection_info,
+            schemas,
         })
     }
 
@@ -74,7 +84,15 @@ impl SqlIntrospectionConnector {
     }
 
     async fn describe(&self) -> SqlIntrospectionResult<SqlSchema> {
-        Ok(self.describer.describe(self.connection_info.schema_name()).await?)
+        let schema_name = self.connection_info.schema_name();
+        let other_schemas: Vec<String> = self
+            .schemas
+            .iter()
+            .filter(|schema| schema.as_str() != schema_name)
+            .cloned()
+            .collect();
+
+        Ok(self.describer.describe_schemas(schema_name, &other_schemas).await?)
     }
 
     async fn version(&self) -> SqlIntrospectionResult<String> {
@@ -1,6 +1,6 @@
 use crate::introspection_helpers::replace_field_names;
 use crate::warnings::*;
-use datamodel::{Datamodel, DefaultValue, FieldType, ScalarType, ValueGenerator};
+use datamodel::{Datamodel, DefaultValue, Field, FieldType, ScalarType, ValueGenerator};
 use introspection_connector::Warning;
 use prisma_value::PrismaValue;
 use quaint::connector::SqlFamily;
@@ -337,6 +337,165 @@ pub fn enrich(old_data_model: &Datamodel, new_data_model: &mut Datamodel, family
         }
     }
 
+    // Prisma Level Only identifiers on views
+    // @id / @unique / @@id / @@unique
+    let mut re_introspected_view_identifiers = vec![];
+    {
+        for model in new_data_model.models().filter(|model| model.is_view) {
+            if let Some(old_model) = old_data_model.find_model(&model.name).filter(|m| m.is_view) {
+                let all_fields_exist =
+                    |fields: &Vec<String>| fields.iter().all(|f| model.find_scalar_field(f).is_some());
+
+                let id_fields =
+                    Some(old_model.id_fields.clone()).filter(|fields| !fields.is_empty() && all_fields_exist(fields));
+                let indices: Vec<_> = old_model
+                    .indices
+                    .iter()
+                    .filter(|index| index.is_unique() && all_fields_exist(&index.fields))
+                    .cloned()
+                    .collect();
+                // Postgres and SQLite report all columns of views as nullable, so the fields of
+                // the identifiers keep their arity from the previous data model.
+                let identifier_fields: Vec<&String> = id_fields
+                    .iter()
+                    .flatten()
+                    .chain(indices.iter().flat_map(|index| index.fields.iter()))
+                    .collect();
+                let fields: Vec<_> = old_model
+                    .scalar_fields()
+                    .filter(|f| f.is_id || f.is_unique || identifier_fields.contains(&&f.name))
+                    .filter(|f| model.find_scalar_field(&f.name).is_some())
+                    .map(|f| (f.name.clone(), f.is_id, f.is_unique, f.arity))
+                    .collect();
+
+                if id_fields.is_some() || !indices.is_empty() || !fields.is_empty() {
+                    re_introspected_view_identifiers.push((Model::new(&model.name), id_fields, indices, fields));
+                }
+            }
+        }
+
+        for (view, id_fields, indices, fields) in &re_introspected_view_identifiers {
+            let model = new_data_model.find_model_mut(&view.model);
+
+            if let Some(id_fields) = id_fields {
+                model.id_fields = id_fields.clone();
+            }
+
+            model.indices.extend(indices.iter().cloned());
+
+            for (field_name, is_id, is_unique, arity) in fields {
+                let field = model.find_scalar_field_mut(field_name);
+                field.is_id = *is_id;
+                field.is_unique = *is_unique;
+                field.arity = *arity;
+            }
+        }
+    }
+
+    // @@index / @@unique - keep the predicates of partial indexes as they were written, since the database
+    // normalizes them
+    {
+        let mut re_introspected_predicates = vec![];
+        {
+            for model in new_data_model.models() {
+                if let Some(old_model) = old_data_model.find_model(&model.name) {
+                    for (idx, index) in model.indices.iter().enumerate().filter(|(_, i)| i.predicate.is_some()) {
+                        let old_predicate = old_model
+                            .indices
+                            .iter()
+                            .filter(|old_index| old_index.tpe == index.tpe && old_index.fields == index.fields)
+                            .filter(|old_index| old_index.name.is_none() || old_index.name == index.name)
+                            .find_map(|old_index| old_index.predicate.clone());
+
+                        if let Some(old_predicate) = old_predicate {
+                            re_introspected_predicates.push((Model::new(&model.name), idx, old_predicate));
+                        }
+                    }
+                }
+            }
+        }
+
+        for (model, idx, predicate) in re_introspected_predicates {
+            new_data_model.find_model_mut(&model.model).indices[idx].predicate = Some(predicate);
+        }
+    }
+
+    // @@check - keep the expressions as they were written and the names that were left to the default, since
+    // the database normalizes expressions
+    {
+        let mut re_introspected_check_constraints = vec![];
+        {
+            for model in new_data_model.models() {
+                if let Some(old_model) = old_data_model.find_model(&model.name) {
+                    let table_name = old_model.database_name.as_ref().unwrap_or(&old_model.name);
+
+                    for (idx, check_constraint) in model.check_constraints.iter().enumerate() {
+                        // Unnamed check constraints get these names from the migration engine.
+                        let old_check_constraint =
+                            old_model
+                                .check_constraints
+                                .iter()
+                                .enumerate()
+                                .find(|(old_idx, old_check_constraint)| {
+                                    let old_name = old_check_constraint
+                                        .name
+                                        .clone()
+                                        .unwrap_or_else(|| format!("{}_chk_{}", table_name, old_idx + 1));
+
+                                    check_constraint.name.as_ref() == Some(&old_name)
+                                });
+
+                        if let Some((_, old_check_constraint)) = old_check_constraint {
+                            re_introspected_check_constraints.push((
+                                Model::new(&model.name),
+                                idx,
+                                old_check_constraint.clone(),
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+
+        for (model, idx, check_constraint) in re_introspected_check_constraints {
+            new_data_model.find_model_mut(&model.model).check_constraints[idx] = check_constraint;
+        }
+    }
+
+    // @@ignore / @ignore - keep the models and fields the user excluded from the client, we do NOT generate
+    // warnings for them
+    {
+        let mut re_introspected_ignored_models = vec![];
+        let mut re_introspected_ignored_fields = vec![];
+
+        for model in new_data_model.models() {
+            if let Some(old_model) = old_data_model.find_model(&model.name) {
+                if old_model.is_ignored {
+                    re_introspected_ignored_models.push(Model::new(&model.name));
+                }
+
+                for field in &model.fields {
+                    if let Some(old_field) = old_model.find_field(&field.name()) {
+                        if old_field.is_ignored() {
+                            re_introspected_ignored_fields.push(ModelAndField::new(&model.name, &field.name()));
+                        }
+                    }
+                }
+            }
+        }
+
+        for model in &re_introspected_ignored_models {
+            new_data_model.find_model_mut(&model.model).is_ignored = true;
+        }
+
+        for model_and_field in &re_introspected_ignored_fields {
+            match new_data_model.find_field_mut(&model_and_field.model, &model_and_field.field) {
+                Field::ScalarField(sf) => sf.is_ignored = true,
+                Field::RelationField(rf) => rf.is_ignored = true,
+            }
+        }
+    }
+
     // comments - we do NOT generate warnings for comments
     {
         let mut re_introspected_model_comments = vec![];
@@ -462,6 +621,11 @@ pub fn enrich(old_data_model: &Datamodel, new_data_model: &mut Datamodel, family
         warnings.push(warning_enriched_with_updated_at(&re_introspected_updated_at));
     }
 
+    if !re_introspected_view_identifiers.is_empty() {
+        let views = re_introspected_view_identifiers.iter().map(|c| c.0.clone()).collect();
+        warnings.push(warning_enriched_views_with_identifiers(&views));
+    }
+
     warnings
 }
 
@@ -65,7 +65,7 @@ impl EnumAndValue {
 pub fn warning_models_without_identifier(affected: &Vec<Model>) -> Warning {
     Warning {
         code: 1,
-        message: "The following models were commented out as they do not have a valid unique identifier or id. This is currently not supported by Prisma.".into(),
+        message: "The following models were marked with `@@ignore` as they do not have a valid unique identifier or id. This is currently not supported by the Prisma Client.".into(),
         affected: serde_json::to_value(&affected).unwrap(),
     }
 }
@@ -186,3 +186,13 @@ pub fn warning_models_without_columns(affected: &Vec<Model>) -> Warning {
         affected: serde_json::to_value(&affected).unwrap(),
     }
 }
+
+pub fn warning_enriched_views_with_identifiers(affected: &Vec<Model>) -> Warning {
+    Warning {
+        code: 15,
+        message:
+            "These views were enriched with `@id` and `@unique` information taken from the previous Prisma schema."
+                .into(),
+        affected: serde_json::to_value(&affected).unwrap(),
+    }
+}
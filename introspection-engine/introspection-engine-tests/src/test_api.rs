@@ -20,6 +20,7 @@ pub struct TestApi {
     sql_family: SqlFamily,
     database: Quaint,
     introspection_connector: SqlIntrospectionConnector,
+    connection_string: String,
     pub tags: BitFlags<Tags>,
 }
 
@@ -78,6 +79,17 @@ impl TestApi {
         Ok(datamodel::render_datamodel_to_string(&introspection_result.data_model))
     }
 
+    /// Introspect the schema of the test together with the given other schemas, as listed in the
+    /// `schemas` property of the datasource.
+    pub async fn introspect_schemas(&self, schemas: &[&str]) -> Result<String> {
+        let schemas = schemas.iter().map(|schema| schema.to_string()).collect();
+        let introspection_connector =
+            SqlIntrospectionConnector::new_with_schemas(&self.connection_string, schemas).await?;
+        let introspection_result = introspection_connector.introspect(&Datamodel::new(), false).await?;
+
+        Ok(datamodel::render_datamodel_to_string(&introspection_result.data_model))
+    }
+
     pub async fn re_introspect(&self, data_model_string: &str) -> Result<String> {
         let config = parse_configuration(data_model_string)?;
         let data_model = parse_datamodel(data_model_string, &config)?;
@@ -182,6 +194,7 @@ pub async fn mysql_test_api(args: TestAPIArgs) -> TestApi {
         database: conn,
         sql_family: SqlFamily::Mysql,
         introspection_connector,
+        connection_string: url,
         db_name,
         tags: args.test_tag,
     }
@@ -201,6 +214,7 @@ pub async fn mysql_8_test_api(args: TestAPIArgs) -> TestApi {
         database: conn,
         sql_family: SqlFamily::Mysql,
         introspection_connector,
+        connection_string: url,
     }
 }
 
@@ -218,6 +232,7 @@ pub async fn mysql_5_6_test_api(args: TestAPIArgs) -> TestApi {
         database: conn,
         sql_family: SqlFamily::Mysql,
         introspection_connector,
+        connection_string: url,
     }
 }
 
@@ -235,6 +250,7 @@ pub async fn mysql_mariadb_test_api(args: TestAPIArgs) -> TestApi {
         database: conn,
         sql_family: SqlFamily::Mysql,
         introspection_connector,
+        connection_string: url,
     }
 }
 
@@ -272,6 +288,7 @@ pub async fn test_api_helper_for_postgres(url: String, args: TestAPIArgs) -> Tes
         database,
         sql_family: SqlFamily::Postgres,
         introspection_connector,
+        connection_string: url,
     }
 }
 
@@ -289,6 +306,7 @@ pub async fn sqlite_test_api(args: TestAPIArgs) -> TestApi {
         database,
         sql_family: SqlFamily::Sqlite,
         introspection_connector,
+        connection_string,
     }
 }
 
@@ -318,5 +336,6 @@ pub async fn mssql_test_api(connection_string: String, args: TestAPIArgs) -> Tes
         database,
         sql_family: SqlFamily::Mssql,
         introspection_connector,
+        connection_string,
     }
 }
@@ -22,32 +22,35 @@ async fn a_table_without_uniques(api: &TestApi) -> crate::TestResult {
 
     let dm = if api.sql_family().is_mysql() {
         indoc! {r#"
-            // The underlying table does not contain a valid unique identifier and can therefore currently not be handled.
-            // model Post {
-              // id      Int
-              // user_id Int
-              // User    User @relation(fields: [user_id], references: [id])
-
-              // @@index([user_id], name: "user_id")
-            // }
+            /// The underlying table does not contain a valid unique identifier and can therefore currently not be handled.
+            model Post {
+              id      Int
+              user_id Int
+              User    User @relation(fields: [user_id], references: [id])
+
+              @@index([user_id], name: "user_id")
+              @@ignore
+            }
 
             model User {
-              id      Int    @id @default(autoincrement())
-              // Post Post[]
+              id   Int    @id @default(autoincrement())
+              Post Post[] @ignore
             }
         "#}
     } else {
         indoc! {r#"
-            // The underlying table does not contain a valid unique identifier and can therefore currently not be handled.
-            // model Post {
-              // id      Int
-              // user_id Int
-              // User    User @relation(fields: [user_id], references: [id])
-            // }
+            /// The underlying table does not contain a valid unique identifier and can therefore currently not be handled.
+            model Post {
+              id      Int
+              user_id Int
+              User    User @relation(fields: [user_id], references: [id])
+
+              @@ignore
+            }
 
             model User {
-              id      Int    @id @default(autoincrement())
-              // Post Post[]
+              id   Int    @id @default(autoincrement())
+              Post Post[] @ignore
             }
         "#}
     };
@@ -69,11 +72,13 @@ async fn a_table_without_required_uniques(api: &TestApi) -> crate::TestResult {
         .await?;
 
     let dm = indoc! {r#"
-        // The underlying table does not contain a valid unique identifier and can therefore currently not be handled.
-        // model Post {
-          // id         Int
-          // opt_unique Int? @unique
-        // }
+        /// The underlying table does not contain a valid unique identifier and can therefore currently not be handled.
+        model Post {
+          id         Int
+          opt_unique Int? @unique
+
+          @@ignore
+        }
     "#};
 
     assert_eq!(dm, &api.introspect().await?);
@@ -99,14 +104,15 @@ async fn a_table_without_fully_required_compound_unique(api: &TestApi) -> crate:
         .await?;
 
     let dm = indoc! {r#"
-        // The underlying table does not contain a valid unique identifier and can therefore currently not be handled.
-        // model Post {
-          // id         Int
-          // opt_unique Int?
-          // req_unique Int
-
-          // @@unique([opt_unique, req_unique], name: "sqlite_autoindex_Post_1")
-        // }
+        /// The underlying table does not contain a valid unique identifier and can therefore currently not be handled.
+        model Post {
+          id         Int
+          opt_unique Int?
+          req_unique Int
+
+          @@unique([opt_unique, req_unique], name: "sqlite_autoindex_Post_1")
+          @@ignore
+        }
     "#};
 
     assert_eq!(dm, &api.introspect().await?);
@@ -172,7 +178,7 @@ async fn a_table_with_only_an_unsupported_id(api: &TestApi) -> crate::TestResult
     let expected = json!([
         {
             "code": 1,
-            "message": "The following models were commented out as they do not have a valid unique identifier or id. This is currently not supported by Prisma.",
+            "message": "The following models were marked with `@@ignore` as they do not have a valid unique identifier or id. This is currently not supported by the Prisma Client.",
             "affected": [{
                 "model": "Test"
             }]
@@ -191,12 +197,14 @@ async fn a_table_with_only_an_unsupported_id(api: &TestApi) -> crate::TestResult
     assert_eq_json!(expected, api.introspection_warnings().await?);
 
     let dm = indoc! {r#"
-        // The underlying table does not contain a valid unique identifier and can therefore currently not be handled.
-        // model Test {
-          // dummy       Int
+        /// The underlying table does not contain a valid unique identifier and can therefore currently not be handled.
+        model Test {
+          dummy       Int
           // This type is currently not supported.
           // network_mac macaddr @id
-        // }
+
+          @@ignore
+        }
     "#};
 
     assert_eq!(dm, &api.introspect().await?);
@@ -229,7 +237,7 @@ async fn remapping_field_names_to_empty(api: &TestApi) -> crate::TestResult {
 }
 
 #[test_each_connector(tags("postgres"))]
-async fn db_genererated_values_should_add_comments(api: &TestApi) -> crate::TestResult {
+async fn db_generated_values_should_be_introspected_with_their_expressions(api: &TestApi) -> crate::TestResult {
     api.barrel()
         .execute_with_schema(
             |migration| {
@@ -248,11 +256,9 @@ async fn db_genererated_values_should_add_comments(api: &TestApi) -> crate::Test
         model Blog {
           id            Int    @id @default(autoincrement())
           number        Int?   @default(1)
-          /// The value of this field is generated by the database as: `sqrt((4)::double precision)`.
-          bigger_number Int?   @default(dbgenerated())
+          bigger_number Int?   @default(dbgenerated("sqrt((4)::double precision)"))
           // This type is currently not supported.
-          // The value of this field is generated by the database as: `point((0)::double precision, (0)::double precision)`.
-          // point      point? @default(dbgenerated())
+          // point      point? @default(dbgenerated("point((0)::double precision, (0)::double precision)"))
         }
     "##};
 
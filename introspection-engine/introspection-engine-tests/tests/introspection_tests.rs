@@ -5,6 +5,7 @@ mod errors;
 mod identify_version;
 mod lists;
 mod model_renames;
+mod multi_schema;
 mod native_types;
 mod postgres;
 mod re_introspection;
@@ -13,5 +14,6 @@ mod relations_with_compound_fk;
 mod remapping_database_names;
 mod rpc_calls;
 mod tables;
+mod views;
 
 pub type TestResult = eyre::Result<()>;
@@ -0,0 +1,117 @@
+use indoc::formatdoc;
+use introspection_engine_tests::{assert_eq_datamodels, test_api::*};
+use quaint::prelude::Queryable;
+use test_macros::test_each_connector;
+
+/// `@@schema` attributes are only valid with a datasource listing the schemas.
+fn with_datasource(provider: &str, url: &str, schema: &str, dm: &str) -> String {
+    formatdoc! {r#"
+        datasource db {{
+          provider = "{}"
+          url      = "{}"
+          schemas  = ["{}"]
+        }}
+
+        {}
+    "#, provider, url, schema, dm}
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn relations_across_schemas_are_introspected(api: &TestApi) -> crate::TestResult {
+    let other_schema = format!("{}_other", api.schema_name());
+
+    let sql = formatdoc! {r#"
+        DROP SCHEMA IF EXISTS "{1}" CASCADE;
+        CREATE SCHEMA "{1}";
+
+        CREATE TABLE "{1}"."User" (id INTEGER PRIMARY KEY);
+
+        CREATE TABLE "{0}"."Post" (
+            id INTEGER PRIMARY KEY,
+            author_id INTEGER NOT NULL REFERENCES "{1}"."User"(id)
+        );
+
+        CREATE TABLE "{1}"."Comment" (
+            id INTEGER PRIMARY KEY,
+            post_id INTEGER NOT NULL REFERENCES "{0}"."Post"(id)
+        );
+    "#, api.schema_name(), other_schema};
+
+    api.database().raw_cmd(&sql).await?;
+
+    let dm = formatdoc! {r#"
+        model Post {{
+          id        Int       @id
+          author_id Int
+          User      User      @relation(fields: [author_id], references: [id])
+          Comment   Comment[]
+        }}
+
+        model Comment {{
+          id      Int  @id
+          post_id Int
+          Post    Post @relation(fields: [post_id], references: [id])
+
+          @@schema("{0}")
+        }}
+
+        model User {{
+          id   Int    @id
+          Post Post[]
+
+          @@schema("{0}")
+        }}
+    "#, other_schema};
+
+    let result = api.introspect_schemas(&[other_schema.as_str()]).await?;
+
+    assert_eq_datamodels!(
+        &with_datasource("postgresql", "postgresql://localhost:5432", &other_schema, &dm),
+        &with_datasource("postgresql", "postgresql://localhost:5432", &other_schema, &result)
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mssql"))]
+async fn relations_across_schemas_are_introspected_on_sql_server(api: &TestApi) -> crate::TestResult {
+    use test_setup::connectors::mssql;
+
+    let other_schema = format!("{}_other", api.schema_name());
+    mssql::reset_schema(api.database(), &other_schema).await?;
+
+    let sql = formatdoc! {r#"
+        CREATE TABLE [{1}].[User] (id INT PRIMARY KEY);
+
+        CREATE TABLE [{0}].[Post] (
+            id INT PRIMARY KEY,
+            author_id INT NOT NULL REFERENCES [{1}].[User](id)
+        );
+    "#, api.schema_name(), other_schema};
+
+    api.database().raw_cmd(&sql).await?;
+
+    let dm = formatdoc! {r#"
+        model Post {{
+          id        Int  @id
+          author_id Int
+          User      User @relation(fields: [author_id], references: [id])
+        }}
+
+        model User {{
+          id   Int    @id
+          Post Post[]
+
+          @@schema("{0}")
+        }}
+    "#, other_schema};
+
+    let result = api.introspect_schemas(&[other_schema.as_str()]).await?;
+
+    assert_eq_datamodels!(
+        &with_datasource("sqlserver", "sqlserver://localhost:1433", &other_schema, &dm),
+        &with_datasource("sqlserver", "sqlserver://localhost:1433", &other_schema, &result)
+    );
+
+    Ok(())
+}
@@ -33,9 +33,9 @@ async fn sequences_should_work(api: &TestApi) -> crate::TestResult {
         model Test {
           id     Int  @id
           serial Int  @default(autoincrement())
-          first  Int  @default(autoincrement())
-          second Int? @default(autoincrement())
-          third  Int  @default(autoincrement())
+          first  Int  @default(sequence("first_Sequence"))
+          second Int? @default(sequence("second_sequence"))
+          third  Int  @default(sequence("third_Sequence"))
         }
     "#}
     .to_string();
@@ -48,3 +48,93 @@ async fn sequences_should_work(api: &TestApi) -> crate::TestResult {
     assert_eq_datamodels!(&result, &dm);
     Ok(())
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn serial_sequences_with_shortened_names_are_introspected_as_autoincrement(api: &TestApi) -> crate::TestResult {
+    // The sequence is named `a_table_with_a_name_long_enough_to_be_shorten_serial_number_seq`.
+    api.barrel()
+        .execute(move |migration| {
+            migration.create_table(
+                "a_table_with_a_name_long_enough_to_be_shortened_by_postgres",
+                move |t| {
+                    t.inject_custom("id Integer Primary Key");
+                    t.inject_custom("serial_number Serial");
+                },
+            );
+        })
+        .await?;
+
+    let dm = indoc! {r#"
+        model a_table_with_a_name_long_enough_to_be_shortened_by_postgres {
+          id            Int @id
+          serial_number Int @default(autoincrement())
+        }
+    "#};
+
+    let result = api.introspect().await?;
+
+    assert_eq_datamodels!(dm, &result);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn check_constraints_are_introspected(api: &TestApi) -> crate::TestResult {
+    api.barrel()
+        .execute(move |migration| {
+            migration.create_table("Product", move |t| {
+                t.inject_custom("id Integer Primary Key");
+                t.inject_custom("price Integer Not Null");
+                t.inject_custom("Constraint \"price_check\" Check (price > 0)");
+            });
+        })
+        .await?;
+
+    let dm = indoc! {r#"
+        model Product {
+          id    Int @id
+          price Int
+
+          @@check("(price > 0)", name: "price_check")
+        }
+    "#};
+
+    let result = api.introspect().await?;
+
+    assert_eq_datamodels!(dm, &result);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn re_introspection_keeps_the_expressions_of_check_constraints(api: &TestApi) -> crate::TestResult {
+    api.barrel()
+        .execute(move |migration| {
+            migration.create_table("Product", move |t| {
+                t.inject_custom("id Integer Primary Key");
+                t.inject_custom("price Integer Not Null");
+                t.inject_custom("status Text Not Null");
+                t.inject_custom("Constraint \"Product_chk_1\" Check (price > 0)");
+                t.inject_custom("Constraint \"status_check\" Check (status IN ('draft', 'published'))");
+            });
+        })
+        .await?;
+
+    // The database returns `(price > 0)` and `(status = ANY (ARRAY['draft'::text, 'published'::text]))`.
+    let dm = indoc! {r#"
+        model Product {
+          id     Int    @id
+          price  Int
+          status String
+
+          @@check("price > 0")
+          @@check("status IN ('draft', 'published')", name: "status_check")
+        }
+    "#};
+
+    let result = api.re_introspect(dm).await?;
+
+    assert_eq_datamodels!(dm, &result);
+
+    Ok(())
+}
@@ -1319,6 +1319,63 @@ async fn updated_at(api: &TestApi) -> crate::TestResult {
     Ok(())
 }
 
+#[test_each_connector]
+async fn ignore(api: &TestApi) -> crate::TestResult {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("User", move |t| {
+                t.add_column("id", types::primary());
+                t.add_column("name", types::text());
+            });
+
+            migration.create_table("Post", move |t| {
+                t.add_column("id", types::primary());
+                t.add_column("title", types::text().nullable(true));
+            });
+
+            migration.create_table("Unrelated", |t| {
+                t.add_column("id", types::primary());
+            });
+        })
+        .await?;
+
+    let input_dm = indoc! {r#"
+        model User {
+            id   Int    @id @default(autoincrement())
+            name String
+
+            @@ignore
+        }
+
+        model Post {
+            id    Int     @id @default(autoincrement())
+            title String? @ignore
+        }
+    "#};
+
+    let final_dm = indoc! {r#"
+        model User {
+            id   Int    @id @default(autoincrement())
+            name String
+
+            @@ignore
+        }
+
+        model Post {
+            id    Int     @id @default(autoincrement())
+            title String? @ignore
+        }
+
+        model Unrelated {
+            id               Int @id @default(autoincrement())
+        }
+    "#};
+
+    assert_eq_datamodels!(final_dm, &api.re_introspect(input_dm).await?);
+
+    Ok(())
+}
+
 #[test_each_connector]
 async fn multiple_many_to_many_on_same_model(api: &TestApi) -> crate::TestResult {
     api.barrel()
@@ -1552,7 +1609,7 @@ async fn schemas_with_required_virtual_relation_fields_must_be_handlded_graceful
     let final_dm = indoc! {r#"
         model User {
             id            Int         @id @default(autoincrement())
-            accountInfoId Int         @unique
+            accountInfoId Int         @unique(map: "uniqueIndex")
             accountInfo   AccountInfo @relation(fields: accountInfoId, references: id)
         }
         
@@ -78,6 +78,13 @@ async fn remapping_tables_with_invalid_characters(api: &TestApi) -> crate::TestR
 
 #[test_each_connector]
 async fn remapping_models_in_relations(api: &TestApi) -> crate::TestResult {
+    // SQLite does not keep the names of unique constraints.
+    let unique = if api.sql_family().is_sqlite() {
+        "@unique"
+    } else {
+        r#"@unique(map: "post_user_unique")"#
+    };
+
     api.barrel()
         .execute(|migration| {
             migration.create_table("User with Space", |t| {
@@ -98,31 +105,39 @@ async fn remapping_models_in_relations(api: &TestApi) -> crate::TestResult {
         })
         .await?;
 
-    let dm = {
+    let dm = format!(
         r#"
-        model Post {
+        model Post {{
             id              Int             @id @default(autoincrement())
-            user_id         Int             @unique
+            user_id         Int             {}
             User_with_Space User_with_Space @relation(fields: [user_id], references: [id])
-        }
+        }}
 
-        model User_with_Space {
+        model User_with_Space {{
             id   Int    @id @default(autoincrement())
             name String
             Post Post?
 
             @@map("User with Space")
-        }
-    "#
-    };
+        }}
+    "#,
+        unique
+    );
 
-    assert_eq_datamodels!(dm, &api.introspect().await?);
+    assert_eq_datamodels!(&dm, &api.introspect().await?);
 
     Ok(())
 }
 
 #[test_each_connector]
 async fn remapping_models_in_relations_should_not_map_virtual_fields(api: &TestApi) -> crate::TestResult {
+    // SQLite does not keep the names of unique constraints.
+    let unique = if api.sql_family().is_sqlite() {
+        "@unique"
+    } else {
+        r#"@unique(map: "post_user_unique")"#
+    };
+
     api.barrel()
         .execute(|migration| {
             migration.create_table("User", |t| {
@@ -140,23 +155,26 @@ async fn remapping_models_in_relations_should_not_map_virtual_fields(api: &TestA
         })
         .await?;
 
-    let dm = indoc! {r#"
-        model Post_With_Space {
+    let dm = format!(
+        r#"
+        model Post_With_Space {{
             id      Int  @id @default(autoincrement())
-            user_id Int  @unique
+            user_id Int  {}
             User    User @relation(fields: [user_id], references: [id])
 
             @@map("Post With Space")
-        }
+        }}
 
-        model User {
+        model User {{
             id              Int              @id @default(autoincrement())
             name            String
             Post_With_Space Post_With_Space?
-        }
-    "#};
+        }}
+    "#,
+        unique
+    );
 
-    assert_eq_datamodels!(dm, &api.introspect().await?);
+    assert_eq_datamodels!(&dm, &api.introspect().await?);
 
     Ok(())
 }
@@ -332,6 +332,8 @@ async fn database_description_for_mssql_should_work(api: &TestApi) -> crate::Tes
                     },
                     default: None,
                     auto_increment: true,
+                    generated: None,
+                    comment: None,
                 },
                 Column {
                     name: "string".into(),
@@ -345,6 +347,8 @@ async fn database_description_for_mssql_should_work(api: &TestApi) -> crate::Tes
                     },
                     default: None,
                     auto_increment: false,
+                    generated: None,
+                    comment: None,
                 },
             ],
             primary_key: PrimaryKey {
@@ -1,5 +1,5 @@
 use barrel::{functions, types};
-use indoc::indoc;
+use indoc::{formatdoc, indoc};
 use introspection_engine_tests::{assert_eq_datamodels, test_api::*};
 use quaint::prelude::Queryable;
 use test_macros::test_each_connector;
@@ -138,7 +138,7 @@ async fn a_table_with_unique_index(api: &TestApi) -> crate::TestResult {
     let dm = indoc! {r##"
         model Blog {
             id      Int @id @default(autoincrement())
-            authorId String @unique
+            authorId String @unique(map: "test")
         }
     "##};
 
@@ -398,12 +398,10 @@ async fn pg_default_value_as_dbgenerated(api: &TestApi) -> crate::TestResult {
     let dm = indoc! {r#"
         model Test {
           id              Int       @id @default(autoincrement())
-          /// The value of this field is generated by the database as: `(('  '::text || '>'::text) || ' '::text)`.
-          string_function String?   @default(dbgenerated())
+          string_function String?   @default(dbgenerated("(('  '::text || '>'::text) || ' '::text)"))
           int_serial      Int       @default(autoincrement())
-          /// The value of this field is generated by the database as: `date_part('year'::text, '2001-02-16 20:38:40'::timestamp without time zone)`.
-          int_function    Int?      @default(dbgenerated())
-          int_sequence    Int?      @default(autoincrement())
+          int_function    Int?      @default(dbgenerated("date_part('year'::text, '2001-02-16 20:38:40'::timestamp without time zone)"))
+          int_sequence    Int?      @default(sequence("test_seq"))
           datetime_now    DateTime? @default(now())
           datetime_now_lc DateTime? @default(now())
           }
@@ -471,8 +469,67 @@ async fn a_table_with_an_index_that_contains_expressions_should_be_ignored(api:
     Ok(())
 }
 
+#[test_each_connector(tags("mysql_8"))]
+async fn generated_columns_should_be_introspected_as_computed_fields(api: &TestApi) -> crate::TestResult {
+    api.barrel()
+        .execute_with_schema(
+            |migration| {
+                migration.create_table("OrderItem", |t| {
+                    t.add_column("id", types::integer().primary(true));
+                    t.add_column("price", types::integer());
+                    t.add_column("quantity", types::integer());
+                    t.inject_custom("`total` INTEGER GENERATED ALWAYS AS (`price` * `quantity`) STORED");
+                });
+            },
+            api.schema_name(),
+        )
+        .await?;
+
+    let dm = indoc! {r#"
+        model OrderItem {
+            id       Int  @id
+            price    Int
+            quantity Int
+            total    Int? @computed("(`price` * `quantity`)")
+        }
+    "#};
+
+    assert_eq_datamodels!(dm, &api.introspect().await?);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn table_and_column_comments_should_be_introspected_as_documentation(api: &TestApi) -> crate::TestResult {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("Cat", |t| {
+                t.add_column("id", types::integer().primary(true));
+                t.inject_custom("`name` VARCHAR(191) NOT NULL COMMENT 'The name of the cat.'");
+            });
+        })
+        .await?;
+
+    api.database()
+        .raw_cmd("ALTER TABLE `Cat` COMMENT = 'The cats of the shelter.'")
+        .await?;
+
+    let dm = indoc! {r#"
+        /// The cats of the shelter.
+        model Cat {
+            id   Int    @id
+            /// The name of the cat.
+            name String
+        }
+    "#};
+
+    assert_eq_datamodels!(dm, &api.introspect().await?);
+
+    Ok(())
+}
+
 #[test_each_connector(tags("postgres"))]
-async fn default_values_on_lists_should_be_ignored(api: &TestApi) -> crate::TestResult {
+async fn default_values_on_lists_should_be_introspected(api: &TestApi) -> crate::TestResult {
     api.barrel()
         .execute(|migration| {
             migration.create_table("User", |t| {
@@ -490,9 +547,9 @@ async fn default_values_on_lists_should_be_ignored(api: &TestApi) -> crate::Test
         }
 
         model User {
-            id      Int @id @default(autoincrement())
-            ints    Int[]
-            ints2   Int[]
+            id      Int   @id @default(autoincrement())
+            ints    Int[] @default(dbgenerated("ARRAY[]::integer[]"))
+            ints2   Int[] @default([])
         }
     "#};
 
@@ -515,7 +572,7 @@ async fn default_values_on_lists_should_be_ignored(api: &TestApi) -> crate::Test
 
 // MySQL doesn't have partial indices.
 #[test_each_connector(ignore("mysql"))]
-async fn a_table_with_partial_indexes_should_ignore_them(api: &TestApi) -> crate::TestResult {
+async fn a_table_with_partial_indexes_must_work(api: &TestApi) -> crate::TestResult {
     api.barrel()
         .execute(move |migration| {
             migration.create_table("pages", move |t| {
@@ -529,12 +586,61 @@ async fn a_table_with_partial_indexes_should_ignore_them(api: &TestApi) -> crate
         })
         .await?;
 
-    let dm = indoc! {r#"
-        model pages {
+    // The databases normalize the predicate.
+    let predicate = if api.sql_family().is_postgres() {
+        "(latest = 1)"
+    } else if api.sql_family().is_mssql() {
+        "([latest]=(1))"
+    } else {
+        "latest = 1"
+    };
+
+    let dm = format!(
+        r#"
+        model pages {{
             id       Int     @id @default(autoincrement())
             staticId Int
             latest   Int
-            other    Int     @unique
+            other    Int     @unique(map: "full")
+
+            @@unique([staticId], name: "partial", where: "{}")
+        }}
+    "#,
+        predicate
+    );
+
+    assert_eq_datamodels!(&dm, &api.introspect().await?);
+
+    Ok(())
+}
+
+// Full-text indexes on SQLite are FTS5 tables reading from the indexed table, kept up to date by
+// triggers, as the migration engine creates them.
+#[test_each_connector(tags("sqlite"))]
+async fn a_table_with_a_fulltext_index_must_work(api: &TestApi) -> crate::TestResult {
+    let sql = formatdoc! {r#"
+        CREATE TABLE "{0}"."Post" (
+            id INTEGER PRIMARY KEY,
+            title TEXT NOT NULL,
+            body TEXT NOT NULL
+        );
+
+        CREATE VIRTUAL TABLE "{0}"."Post_search" USING fts5("title", "body", content='Post');
+
+        CREATE TRIGGER "{0}"."Post_search_insert" AFTER INSERT ON "Post" BEGIN
+            INSERT INTO "Post_search"(rowid, "title", "body") VALUES (new.rowid, new."title", new."body");
+        END;
+    "#, api.schema_name()};
+
+    api.database().raw_cmd(&sql).await?;
+
+    let dm = indoc! {r#"
+        model Post {
+            id    Int    @id @default(autoincrement())
+            title String
+            body  String
+
+            @@fulltext([title, body], name: "Post_search")
         }
     "#};
 
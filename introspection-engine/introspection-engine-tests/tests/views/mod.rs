@@ -0,0 +1,89 @@
+use indoc::{formatdoc, indoc};
+use introspection_engine_tests::{assert_eq_datamodels, assert_eq_json, test_api::*};
+use quaint::prelude::Queryable;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+async fn create_cat_names_view(api: &TestApi) -> crate::TestResult {
+    let sql = formatdoc! {r#"
+        CREATE TABLE "{0}"."Cat" (
+            id INTEGER PRIMARY KEY,
+            name TEXT NOT NULL
+        );
+
+        CREATE VIEW "{0}"."CatNames" AS SELECT id, name FROM "{0}"."Cat";
+    "#, api.schema_name()};
+
+    api.database().raw_cmd(&sql).await?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn views_are_rendered_as_view_blocks(api: &TestApi) -> crate::TestResult {
+    create_cat_names_view(api).await?;
+
+    // Views have no keys, so without a previous data model there's nothing to identify their rows by.
+    let dm = indoc! {r#"
+        model Cat {
+          id   Int    @id
+          name String
+        }
+
+        /// The underlying view does not contain a valid unique identifier and can therefore currently not be handled. Please add an @id or @unique attribute to a field that uniquely identifies its rows.
+        view CatNames {
+          id   Int?
+          name String?
+
+          @@ignore
+        }
+    "#};
+
+    assert_eq_datamodels!(dm, &api.introspect().await?);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn re_introspection_keeps_the_identifiers_of_views(api: &TestApi) -> crate::TestResult {
+    create_cat_names_view(api).await?;
+
+    let input_dm = indoc! {r#"
+        model Cat {
+          id   Int    @id
+          name String
+        }
+
+        view CatNames {
+          id   Int    @id
+          name String @unique
+        }
+    "#};
+
+    // Postgres reports all view columns as nullable, the identifier fields stay required.
+    let final_dm = indoc! {r#"
+        model Cat {
+          id   Int    @id
+          name String
+        }
+
+        view CatNames {
+          id   Int    @id
+          name String @unique
+        }
+    "#};
+
+    assert_eq_datamodels!(final_dm, &api.re_introspect(input_dm).await?);
+
+    let expected = json!([{
+        "code": 15,
+        "message": "These views were enriched with `@id` and `@unique` information taken from the previous Prisma schema.",
+        "affected": [{
+            "model": "CatNames"
+        }]
+    }]);
+
+    assert_eq_json!(expected, api.re_introspect_warnings(input_dm).await?);
+
+    Ok(())
+}
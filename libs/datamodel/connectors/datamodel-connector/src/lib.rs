@@ -71,6 +71,46 @@ pub trait Connector: Send + Sync {
         self.has_capability(ConnectorCapability::AutoIncrementNonIndexedAllowed)
     }
 
+    fn supports_full_text_index(&self) -> bool {
+        self.has_capability(ConnectorCapability::FullTextIndex)
+    }
+
+    fn supports_index_prefix_length(&self) -> bool {
+        self.has_capability(ConnectorCapability::IndexPrefixLength)
+    }
+
+    fn supports_index_algorithms(&self) -> bool {
+        self.has_capability(ConnectorCapability::IndexAlgorithms)
+    }
+
+    fn supports_partial_indexes(&self) -> bool {
+        self.has_capability(ConnectorCapability::PartialIndexes)
+    }
+
+    fn supports_named_primary_keys(&self) -> bool {
+        self.has_capability(ConnectorCapability::NamedPrimaryKeys)
+    }
+
+    fn supports_named_foreign_keys(&self) -> bool {
+        self.has_capability(ConnectorCapability::NamedForeignKeys)
+    }
+
+    fn supports_multi_schema(&self) -> bool {
+        self.has_capability(ConnectorCapability::MultiSchema)
+    }
+
+    fn supports_computed_fields(&self) -> bool {
+        self.has_capability(ConnectorCapability::ComputedFields)
+    }
+
+    fn supports_virtual_computed_fields(&self) -> bool {
+        self.has_capability(ConnectorCapability::VirtualComputedFields)
+    }
+
+    fn supports_named_sequences(&self) -> bool {
+        self.has_capability(ConnectorCapability::NamedSequences)
+    }
+
     fn allows_relation_fields_in_arbitrary_order(&self) -> bool {
         self.has_capability(ConnectorCapability::RelationFieldsInArbitraryOrder)
     }
@@ -108,8 +148,19 @@ pub enum ConnectorCapability {
     AutoIncrementMultipleAllowed,
     AutoIncrementNonIndexedAllowed,
     RelationFieldsInArbitraryOrder,
+    FullTextIndex,
+    IndexPrefixLength,
+    IndexAlgorithms,
+    PartialIndexes,
+    NamedPrimaryKeys,
+    NamedForeignKeys,
+    MultiSchema,
+    ComputedFields,
+    VirtualComputedFields,
+    NamedSequences,
     // start of Query Engine Capabilities
     InsensitiveFilters,
+    FullTextSearch,
 }
 
 /// Contains all capabilities that the connector is able to serve.
@@ -38,6 +38,7 @@ pub struct ValueGenerator {
 impl ValueGenerator {
     pub fn new(name: String, args: Vec<PrismaValue>) -> std::result::Result<Self, String> {
         let generator = ValueGeneratorFn::new(name.as_ref())?;
+        generator.check_arguments(&name, &args)?;
 
         Ok(ValueGenerator { name, args, generator })
     }
@@ -50,6 +51,16 @@ impl ValueGenerator {
         ValueGenerator::new("dbgenerated".to_owned(), vec![]).unwrap()
     }
 
+    /// `dbgenerated("expression")`, the expression being used verbatim by the database.
+    pub fn new_dbgenerated_expression(expression: String) -> Self {
+        ValueGenerator::new("dbgenerated".to_owned(), vec![PrismaValue::String(expression)]).unwrap()
+    }
+
+    /// `sequence("name")`, drawing the values from the named database sequence.
+    pub fn new_sequence(name: String) -> Self {
+        ValueGenerator::new("sequence".to_owned(), vec![PrismaValue::String(name)]).unwrap()
+    }
+
     pub fn new_now() -> Self {
         ValueGenerator::new("now".to_owned(), vec![]).unwrap()
     }
@@ -62,6 +73,10 @@ impl ValueGenerator {
         ValueGenerator::new("uuid".to_owned(), vec![]).unwrap()
     }
 
+    pub fn new_nanoid() -> Self {
+        ValueGenerator::new("nanoid".to_owned(), vec![]).unwrap()
+    }
+
     fn name(&self) -> &str {
         &self.name
     }
@@ -70,8 +85,16 @@ impl ValueGenerator {
         &self.args
     }
 
+    /// The single string argument of the function, e.g. the expression of `dbgenerated("now()")`.
+    pub fn string_argument(&self) -> Option<&str> {
+        match self.args() {
+            [PrismaValue::String(argument)] => Some(argument),
+            _ => None,
+        }
+    }
+
     pub fn generate(&self) -> Option<PrismaValue> {
-        self.generator.invoke()
+        self.generator.invoke(self.args())
     }
 
     pub fn check_compatibility_with_scalar_type(&self, scalar_type: ScalarType) -> std::result::Result<(), String> {
@@ -94,6 +117,8 @@ pub enum ValueGeneratorFn {
     Now,
     Autoincrement,
     DbGenerated,
+    Sequence,
+    Nanoid,
 }
 
 impl ValueGeneratorFn {
@@ -104,17 +129,44 @@ impl ValueGeneratorFn {
             "now" => Ok(Self::Now),
             "autoincrement" => Ok(Self::Autoincrement),
             "dbgenerated" => Ok(Self::DbGenerated),
+            "sequence" => Ok(Self::Sequence),
+            "nanoid" => Ok(Self::Nanoid),
             _ => Err(format!("The function {} is not a known function.", name)),
         }
     }
 
-    fn invoke(&self) -> Option<PrismaValue> {
+    fn check_arguments(&self, name: &str, args: &[PrismaValue]) -> std::result::Result<(), String> {
+        let valid = match (self, args) {
+            (_, []) => !matches!(self, Self::Sequence),
+            (Self::DbGenerated, [PrismaValue::String(_)]) => true,
+            (Self::Sequence, [PrismaValue::String(name)]) => !name.is_empty(),
+            (Self::Nanoid, [PrismaValue::Int(length)]) => (2..=255).contains(length),
+            _ => false,
+        };
+
+        if valid {
+            return Ok(());
+        }
+
+        let expected = match self {
+            Self::DbGenerated => "no argument or a single string",
+            Self::Sequence => "the name of the sequence as a single, non-empty string",
+            Self::Nanoid => "no argument or a length between 2 and 255",
+            _ => "no arguments",
+        };
+
+        Err(format!("The function `{}()` takes {}.", name, expected))
+    }
+
+    fn invoke(&self, args: &[PrismaValue]) -> Option<PrismaValue> {
         match self {
             Self::UUID => Self::generate_uuid(),
             Self::CUID => Self::generate_cuid(),
             Self::Now => Self::generate_now(),
+            Self::Nanoid => Self::generate_nanoid(args),
             Self::Autoincrement => None,
             Self::DbGenerated => None,
+            Self::Sequence => None,
         }
     }
 
@@ -126,6 +178,9 @@ impl ValueGeneratorFn {
             (Self::Autoincrement, ScalarType::Int) => true,
             (Self::Autoincrement, ScalarType::BigInt) => true,
             (Self::DbGenerated, _) => true,
+            (Self::Sequence, ScalarType::Int) => true,
+            (Self::Sequence, ScalarType::BigInt) => true,
+            (Self::Nanoid, ScalarType::String) => true,
             _ => false,
         }
     }
@@ -141,6 +196,16 @@ impl ValueGeneratorFn {
     fn generate_now() -> Option<PrismaValue> {
         Some(PrismaValue::DateTime(Utc::now().into()))
     }
+
+    /// `nanoid()` generates 21 characters by default, `nanoid(n)` generates `n`.
+    fn generate_nanoid(args: &[PrismaValue]) -> Option<PrismaValue> {
+        let length = match args {
+            [PrismaValue::Int(length)] => *length as usize,
+            _ => 21,
+        };
+
+        Some(PrismaValue::String(nanoid::nanoid!(length)))
+    }
 }
 
 impl PartialEq for ValueGenerator {
@@ -1,5 +1,6 @@
 use super::*;
 use crate::default_value::{DefaultValue, ValueGenerator};
+use crate::field_validation::FieldValidation;
 use crate::native_type_instance::NativeTypeInstance;
 use crate::scalars::ScalarType;
 use crate::traits::{WithDatabaseName, WithName};
@@ -35,6 +36,8 @@ pub enum FieldType {
     Enum(String),
     /// This is a relation field.
     Relation(RelationInfo),
+    /// This is a field embedding the composite type of the given name.
+    CompositeType(String),
     /// native field type.
     NativeType(ScalarType, NativeTypeInstance),
     /// This is a field with an unsupported datatype - used by introspection only.
@@ -59,6 +62,13 @@ impl FieldType {
         }
     }
 
+    pub fn composite_type(&self) -> Option<&str> {
+        match self {
+            FieldType::CompositeType(name) => Some(name),
+            _ => None,
+        }
+    }
+
     pub fn native_type(&self) -> Option<&NativeTypeInstance> {
         match self {
             FieldType::NativeType(_, nt) => Some(nt),
@@ -110,6 +120,13 @@ impl Field {
         }
     }
 
+    pub fn is_ignored(&self) -> bool {
+        match self {
+            Field::ScalarField(sf) => sf.is_ignored,
+            Field::RelationField(rf) => rf.is_ignored,
+        }
+    }
+
     pub fn arity(&self) -> &FieldArity {
         match &self {
             Field::ScalarField(sf) => &sf.arity,
@@ -138,6 +155,27 @@ impl Field {
         }
     }
 
+    pub fn is_version(&self) -> bool {
+        match &self {
+            Field::ScalarField(sf) => sf.is_version,
+            Field::RelationField(_) => false,
+        }
+    }
+
+    pub fn is_computed(&self) -> bool {
+        match &self {
+            Field::ScalarField(sf) => sf.computed.is_some(),
+            Field::RelationField(_) => false,
+        }
+    }
+
+    pub fn validations(&self) -> &[FieldValidation] {
+        match &self {
+            Field::ScalarField(sf) => &sf.validations,
+            Field::RelationField(_) => &[],
+        }
+    }
+
     pub fn is_unique(&self) -> bool {
         match &self {
             Field::ScalarField(sf) => sf.is_unique,
@@ -211,6 +249,9 @@ pub struct RelationField {
 
     /// Indicates if this field has to be commented out.
     pub is_commented_out: bool,
+
+    /// Indicates if this field is marked with @ignore and excluded from the client.
+    pub is_ignored: bool,
 }
 
 impl RelationField {
@@ -223,6 +264,7 @@ impl RelationField {
             documentation: None,
             is_generated: false,
             is_commented_out: false,
+            is_ignored: false,
         }
     }
     /// Creates a new field with the given name and type, marked as generated and optional.
@@ -260,6 +302,15 @@ impl RelationField {
     }
 }
 
+/// The value of a field computed by the database from the other fields of its row, declared with `@computed`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ComputedValue {
+    /// The SQL expression computing the value.
+    pub expression: String,
+    /// Whether the value is computed on write and stored, or computed on read.
+    pub stored: bool,
+}
+
 /// Represents a scalar field in a model.
 #[derive(Debug, PartialEq, Clone)]
 pub struct ScalarField {
@@ -281,9 +332,15 @@ pub struct ScalarField {
     /// Indicates if the field is unique.
     pub is_unique: bool,
 
+    /// The database name of the unique constraint, from the `map` argument of `@unique`.
+    pub unique_constraint_name: Option<String>,
+
     /// true if this field marked with @id.
     pub is_id: bool,
 
+    /// The database name of the primary key constraint, from the `map` argument of `@id`.
+    pub id_constraint_name: Option<String>,
+
     /// Comments associated with this field.
     pub documentation: Option<String>,
 
@@ -294,8 +351,21 @@ pub struct ScalarField {
     /// automatically.
     pub is_updated_at: bool,
 
+    /// If set, signals that this field is the @version of its model, checked and
+    /// incremented on every update for optimistic concurrency control.
+    pub is_version: bool,
+
+    /// Constraints on the values of this field, e.g. `@length` or `@range`.
+    pub validations: Vec<FieldValidation>,
+
+    /// Set if the database computes the value of this field, which can then not be written.
+    pub computed: Option<ComputedValue>,
+
     /// Indicates if this field has to be commented out.
     pub is_commented_out: bool,
+
+    /// Indicates if this field is marked with @ignore and excluded from the client.
+    pub is_ignored: bool,
 }
 
 impl ScalarField {
@@ -308,11 +378,17 @@ impl ScalarField {
             database_name: None,
             default_value: None,
             is_unique: false,
+            unique_constraint_name: None,
             is_id: false,
+            id_constraint_name: None,
             documentation: None,
             is_generated: false,
             is_updated_at: false,
+            is_version: false,
+            validations: Vec::new(),
+            computed: None,
             is_commented_out: false,
+            is_ignored: false,
         }
     }
     /// Creates a new field with the given name and type, marked as generated and optional.
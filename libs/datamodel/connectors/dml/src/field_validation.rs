@@ -0,0 +1,100 @@
+use regex::Regex;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+/// A constraint on the values a scalar field accepts, e.g. `@length(min: 1, max: 64)`.
+/// Checked by the query engine before any value is written.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum FieldValidation {
+    /// Bounds on the number of characters of a string, set with `@length`.
+    Length { min: Option<usize>, max: Option<usize> },
+    /// Bounds on a numeric value, set with `@range`.
+    Range { min: Option<f64>, max: Option<f64> },
+    /// A regular expression a string has to match, set with `@regex`.
+    Regex { pattern: ValidationPattern },
+    /// The string has to be an email address, set with `@email`.
+    Email,
+}
+
+impl FieldValidation {
+    /// The name of the attribute defining the validation.
+    pub fn attribute_name(&self) -> &'static str {
+        match self {
+            Self::Length { .. } => "length",
+            Self::Range { .. } => "range",
+            Self::Regex { .. } => "regex",
+            Self::Email => "email",
+        }
+    }
+}
+
+impl fmt::Display for FieldValidation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn bounds<T: fmt::Display>(f: &mut fmt::Formatter<'_>, min: &Option<T>, max: &Option<T>) -> fmt::Result {
+            match (min, max) {
+                (Some(min), Some(max)) => write!(f, "between {} and {}", min, max),
+                (Some(min), None) => write!(f, "at least {}", min),
+                (None, Some(max)) => write!(f, "at most {}", max),
+                (None, None) => write!(f, "unbounded"),
+            }
+        }
+
+        match self {
+            Self::Length { min, max } => {
+                write!(f, "a length ")?;
+                bounds(f, min, max)
+            }
+            Self::Range { min, max } => {
+                write!(f, "a value ")?;
+                bounds(f, min, max)
+            }
+            Self::Regex { pattern } => write!(f, "a value matching `{}`", pattern),
+            Self::Email => write!(f, "a valid email address"),
+        }
+    }
+}
+
+/// The regular expression of a `@regex` validation, compiled once when the datamodel is
+/// validated. Compared, printed and serialized as its pattern.
+#[derive(Debug, Clone)]
+pub struct ValidationPattern(Regex);
+
+impl ValidationPattern {
+    pub fn new(pattern: &str) -> Result<Self, regex::Error> {
+        Ok(Self(Regex::new(pattern)?))
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    pub fn is_match(&self, value: &str) -> bool {
+        self.0.is_match(value)
+    }
+}
+
+impl PartialEq for ValidationPattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl fmt::Display for ValidationPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for ValidationPattern {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ValidationPattern {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let pattern = String::deserialize(deserializer)?;
+        Self::new(&pattern).map_err(de::Error::custom)
+    }
+}
@@ -1,10 +1,12 @@
 //! This module contains the models representing the Datamodel part of a Prisma schema.
 //! It contains the main data structures that the engines can build upon.
 
+pub mod composite_type;
 pub mod datamodel;
 pub mod default_value;
 pub mod r#enum;
 pub mod field;
+pub mod field_validation;
 pub mod model;
 pub mod native_type_constructor;
 pub mod native_type_instance;
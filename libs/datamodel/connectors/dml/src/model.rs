@@ -17,32 +17,112 @@ pub struct Model {
     pub is_embedded: bool,
     /// Describes Composite Indexes
     pub indices: Vec<IndexDefinition>,
+    /// Describes check constraints defined via `@@check`.
+    pub check_constraints: Vec<CheckConstraint>,
     /// Describes Composite Primary Keys
     pub id_fields: Vec<String>,
+    /// The database name of the primary key constraint, from the `map` argument of `@@id`.
+    pub id_constraint_name: Option<String>,
     /// Indicates if this model is generated.
     pub is_generated: bool,
     /// Indicates if this model has to be commented out.
     pub is_commented_out: bool,
+    /// Indicates if this model is marked with @@ignore and excluded from the client.
+    pub is_ignored: bool,
+    /// Indicates if this model is a database view, which can only be read.
+    pub is_view: bool,
+    /// The database schema this model lives in, if set via `@@schema`.
+    pub schema: Option<String>,
 }
 
-/// Represents an index defined via `@@index` or `@@unique`.
+/// Represents an index defined via `@@index`, `@@unique` or `@@fulltext`.
 #[derive(Debug, PartialEq, Clone)]
 pub struct IndexDefinition {
     pub name: Option<String>,
     pub fields: Vec<String>,
     pub tpe: IndexType,
+    /// The sort order and prefix length of each field, in the order of `fields`. Empty if all fields use the defaults.
+    pub field_options: Vec<IndexFieldOptions>,
+    /// The index access method given with `type:`, if any.
+    pub algorithm: Option<IndexAlgorithm>,
+    /// The `WHERE` predicate of a partial index, as written in the `where:` argument.
+    pub predicate: Option<String>,
+    /// The database name of a unique constraint, from the `map` argument of `@@unique`. Defaults to `name`.
+    pub constraint_name: Option<String>,
 }
 
 impl IndexDefinition {
     pub fn is_unique(&self) -> bool {
         matches!(self.tpe, IndexType::Unique)
     }
+
+    /// The options of the field at the given position in the index.
+    pub fn field_options(&self, idx: usize) -> IndexFieldOptions {
+        self.field_options.get(idx).cloned().unwrap_or_default()
+    }
+}
+
+/// The options of a single field in an index, e.g. `title(Desc, 10)`.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct IndexFieldOptions {
+    pub sort_order: SortOrder,
+    /// The number of leading characters that are indexed (MySQL only).
+    pub length: Option<u32>,
+}
+
+impl IndexFieldOptions {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+impl Default for SortOrder {
+    fn default() -> Self {
+        SortOrder::Asc
+    }
+}
+
+/// The index access methods that can be chosen with `@@index(..., type: ...)` (Postgres only).
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum IndexAlgorithm {
+    BTree,
+    Hash,
+    Gin,
+    Gist,
+    Brin,
+}
+
+impl IndexAlgorithm {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IndexAlgorithm::BTree => "BTree",
+            IndexAlgorithm::Hash => "Hash",
+            IndexAlgorithm::Gin => "Gin",
+            IndexAlgorithm::Gist => "Gist",
+            IndexAlgorithm::Brin => "Brin",
+        }
+    }
+}
+
+/// Represents a check constraint defined via `@@check`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct CheckConstraint {
+    pub name: Option<String>,
+    /// The SQL expression, as written in the attribute.
+    pub expression: String,
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum IndexType {
     Unique,
     Normal,
+    Fulltext,
 }
 
 /// A unique criteria is a set of fields through which a record can be uniquely identified.
@@ -64,12 +144,17 @@ impl Model {
             name,
             fields: vec![],
             indices: vec![],
+            check_constraints: vec![],
             id_fields: vec![],
+            id_constraint_name: None,
             documentation: None,
             database_name,
             is_embedded: false,
             is_generated: false,
             is_commented_out: false,
+            is_ignored: false,
+            is_view: false,
+            schema: None,
         }
     }
 
@@ -150,6 +235,32 @@ impl Model {
         self.find_field(name).is_some()
     }
 
+    /// The name of an index of this model in the database: the `map` or `name` argument of the
+    /// attribute, or a name derived from the table and the columns of the index.
+    pub fn index_database_name(&self, index: &IndexDefinition) -> String {
+        if let Some(name) = index.constraint_name.as_ref().or_else(|| index.name.as_ref()) {
+            return name.clone();
+        }
+
+        let columns: Vec<&str> = index
+            .fields
+            .iter()
+            .map(|field| {
+                self.find_scalar_field(field)
+                    .map(|field| field.final_database_name())
+                    .unwrap_or(field)
+            })
+            .collect();
+
+        let qualifier = match index.tpe {
+            IndexType::Unique => "unique",
+            IndexType::Normal => "index",
+            IndexType::Fulltext => "fulltext",
+        };
+
+        format!("{}.{}_{}", self.final_database_name(), columns.join("_"), qualifier)
+    }
+
     /// Finds a field by name and returns a mutable reference.
     pub fn find_scalar_field_mut(&mut self, name: &str) -> &mut ScalarField {
         self.scalar_fields_mut()
@@ -199,7 +310,7 @@ impl Model {
         // first candidate: the singular id field
         {
             if let Some(x) = self.singular_id_fields().next() {
-                if !x.is_commented_out {
+                if !x.is_commented_out && !x.is_ignored {
                     result.push(UniqueCriteria::new(vec![x]))
                 }
             }
@@ -213,7 +324,7 @@ impl Model {
                 .map(|f| self.find_scalar_field(&f).unwrap())
                 .collect();
 
-            if !id_fields.is_empty() && !id_fields.iter().any(|f| f.is_commented_out) {
+            if !id_fields.is_empty() && !id_fields.iter().any(|f| f.is_commented_out || f.is_ignored) {
                 result.push(UniqueCriteria::new(id_fields));
             }
         }
@@ -222,7 +333,8 @@ impl Model {
         {
             let mut unique_required_fields: Vec<_> = self
                 .scalar_fields()
-                .filter(|field| field.is_unique && (field.is_required() || allow_optional) && !field.is_commented_out)
+                .filter(|field| field.is_unique && (field.is_required() || allow_optional))
+                .filter(|field| !field.is_commented_out && !field.is_ignored)
                 .map(|f| UniqueCriteria::new(vec![f]))
                 .collect();
 
@@ -230,14 +342,15 @@ impl Model {
         }
 
         // fourth candidate: any multi-field unique constraint where all fields are required
+        // (partial unique indexes only enforce uniqueness on some of the rows)
         {
             let mut unique_field_combi = self
                 .indices
                 .iter()
-                .filter(|id| id.tpe == IndexType::Unique)
+                .filter(|id| id.tpe == IndexType::Unique && id.predicate.is_none())
                 .filter_map(|id| {
                     let fields: Vec<_> = id.fields.iter().map(|f| self.find_scalar_field(&f).unwrap()).collect();
-                    let no_fields_are_commented_out = !fields.iter().any(|f| f.is_commented_out);
+                    let no_fields_are_commented_out = !fields.iter().any(|f| f.is_commented_out || f.is_ignored);
                     let all_fields_are_required = fields.iter().all(|f| f.is_required());
                     if (all_fields_are_required || allow_optional) && no_fields_are_commented_out {
                         Some(UniqueCriteria::new(fields))
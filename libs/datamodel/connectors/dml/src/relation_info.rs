@@ -12,10 +12,12 @@ pub struct RelationInfo {
     /// A strategy indicating what happens when
     /// a related node is deleted.
     pub on_delete: OnDeleteStrategy,
+    /// The database name of the foreign key constraint, from the `map` argument of `@relation`.
+    pub constraint_name: Option<String>,
 }
 
 impl PartialEq for RelationInfo {
-    //ignores the relation name for reintrospection
+    //ignores the relation name and the constraint name for reintrospection
     fn eq(&self, other: &Self) -> bool {
         self.to == other.to
             && self.fields == other.fields
@@ -34,6 +36,7 @@ impl RelationInfo {
             references: Vec::new(),
             name: String::new(),
             on_delete: OnDeleteStrategy::None,
+            constraint_name: None,
         }
     }
 }
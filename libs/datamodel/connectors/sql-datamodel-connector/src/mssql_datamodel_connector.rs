@@ -51,6 +51,10 @@ impl MsSqlDatamodelConnector {
             ConnectorCapability::AutoIncrementAllowedOnNonId,
             ConnectorCapability::AutoIncrementMultipleAllowed,
             ConnectorCapability::AutoIncrementNonIndexedAllowed,
+            ConnectorCapability::PartialIndexes,
+            ConnectorCapability::NamedPrimaryKeys,
+            ConnectorCapability::NamedForeignKeys,
+            ConnectorCapability::MultiSchema,
         ];
 
         let constructors: Vec<NativeTypeConstructor> = vec![
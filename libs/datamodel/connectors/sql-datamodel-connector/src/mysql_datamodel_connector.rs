@@ -67,6 +67,12 @@ impl MySqlDatamodelConnector {
             ConnectorCapability::MultipleIndexesWithSameName,
             ConnectorCapability::AutoIncrementAllowedOnNonId,
             ConnectorCapability::RelationFieldsInArbitraryOrder,
+            ConnectorCapability::FullTextIndex,
+            ConnectorCapability::IndexPrefixLength,
+            ConnectorCapability::NamedForeignKeys,
+            ConnectorCapability::ComputedFields,
+            ConnectorCapability::VirtualComputedFields,
+            ConnectorCapability::FullTextSearch,
         ];
 
         let int = NativeTypeConstructor::without_args(INT_TYPE_NAME, vec![ScalarType::Int]);
@@ -231,7 +237,8 @@ impl Connector for MySqlDatamodelConnector {
     }
 
     fn validate_model(&self, model: &Model) -> Result<(), ConnectorError> {
-        for index_definition in model.indices.iter() {
+        // Full-text indexes are the one kind of index that can be put on TEXT and BLOB columns.
+        for index_definition in model.indices.iter().filter(|i| i.tpe != IndexType::Fulltext) {
             let fields = index_definition.fields.iter().map(|f| model.find_field(f).unwrap());
             for f in fields {
                 if let FieldType::NativeType(_, native_type) = f.field_type() {
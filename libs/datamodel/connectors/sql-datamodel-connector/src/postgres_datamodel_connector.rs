@@ -52,6 +52,15 @@ impl PostgresDatamodelConnector {
             ConnectorCapability::AutoIncrementNonIndexedAllowed,
             ConnectorCapability::InsensitiveFilters,
             ConnectorCapability::RelationFieldsInArbitraryOrder,
+            ConnectorCapability::FullTextIndex,
+            ConnectorCapability::IndexAlgorithms,
+            ConnectorCapability::PartialIndexes,
+            ConnectorCapability::NamedPrimaryKeys,
+            ConnectorCapability::NamedForeignKeys,
+            ConnectorCapability::MultiSchema,
+            ConnectorCapability::ComputedFields,
+            ConnectorCapability::NamedSequences,
+            ConnectorCapability::FullTextSearch,
         ];
 
         let small_int = NativeTypeConstructor::without_args(SMALL_INT_TYPE_NAME, vec![ScalarType::Int]);
@@ -0,0 +1,44 @@
+use super::*;
+
+/// A composite type declaration.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompositeType {
+    /// The name of the composite type.
+    pub name: Identifier,
+    /// The fields of the composite type.
+    pub fields: Vec<Field>,
+    /// The attributes of this composite type.
+    pub attributes: Vec<Attribute>,
+    /// The documentation for this composite type.
+    pub documentation: Option<Comment>,
+    /// The location of this composite type in the text representation.
+    pub span: Span,
+}
+
+impl WithIdentifier for CompositeType {
+    fn identifier(&self) -> &Identifier {
+        &self.name
+    }
+}
+
+impl WithSpan for CompositeType {
+    fn span(&self) -> &Span {
+        &self.span
+    }
+}
+
+impl WithAttributes for CompositeType {
+    fn attributes(&self) -> &Vec<Attribute> {
+        &self.attributes
+    }
+}
+
+impl WithDocumentation for CompositeType {
+    fn documentation(&self) -> &Option<Comment> {
+        &self.documentation
+    }
+
+    fn is_commented_out(&self) -> bool {
+        false
+    }
+}
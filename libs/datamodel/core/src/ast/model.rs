@@ -15,6 +15,8 @@ pub struct Model {
     pub span: Span,
     /// Should this be commented out.
     pub commented_out: bool,
+    /// Is this a database view, declared with the `view` keyword.
+    pub is_view: bool,
 }
 
 impl Model {
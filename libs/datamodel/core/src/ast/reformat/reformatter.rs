@@ -165,6 +165,7 @@ impl<'a> Reformatter<'a> {
                     }
                 }
                 Rule::model_declaration => self.reformat_model(target, &current),
+                Rule::composite_type_declaration => self.reformat_composite_type(target, &current),
                 Rule::enum_declaration => self.reformat_enum(target, &current),
                 Rule::source_block => self.reformat_datasource(target, &current),
                 Rule::generator_block => self.reformat_generator(target, &current),
@@ -235,12 +236,18 @@ impl<'a> Reformatter<'a> {
     }
 
     fn reformat_model(&self, target: &mut Renderer, token: &Token) {
+        let is_view = token
+            .clone()
+            .into_inner()
+            .any(|current| current.as_rule() == Rule::VIEW_KEYWORD);
+
         self.reformat_block_element_internal(
-            "model",
+            if is_view { "view" } else { "model" },
             target,
             &token,
             Box::new(|table, renderer, token, model_name| {
                 match token.as_rule() {
+                    Rule::VIEW_KEYWORD => {}
                     Rule::block_level_attribute => {
                         // model level attributes reset the table. -> .render() does that
                         table.render(renderer);
@@ -263,6 +270,23 @@ impl<'a> Reformatter<'a> {
         );
     }
 
+    fn reformat_composite_type(&self, target: &mut Renderer, token: &Token) {
+        self.reformat_block_element(
+            "type",
+            target,
+            &token,
+            Box::new(|table, renderer, token, type_name| match token.as_rule() {
+                Rule::TYPE_KEYWORD => {}
+                Rule::block_level_attribute => {
+                    table.render(renderer);
+                    Self::reformat_attribute(renderer, &token, "@@");
+                }
+                Rule::field_declaration => self.reformat_field(table, &token, type_name),
+                _ => Self::reformat_generic_token(table, &token),
+            }),
+        );
+    }
+
     fn reformat_block_element(
         &self,
         block_type: &'static str,
@@ -10,6 +10,12 @@ pub struct Datasource {
     /// the provider that was selected as active from all specified providers
     pub active_provider: String,
     pub url: StringFromEnvVar,
+    /// urls of read replicas of the database behind `url`
+    pub read_replicas: Vec<StringFromEnvVar>,
+    /// connection pool settings, applied to the primary and all read replicas
+    pub pool: PoolSettings,
+    /// database schemas the models of this datasource may live in, see `@@schema`
+    pub schemas: Vec<String>,
     pub documentation: Option<String>,
     /// a connector representing the intersection of all providers specified
     pub combined_connector: Box<dyn Connector>,
@@ -25,6 +31,9 @@ impl std::fmt::Debug for Datasource {
             .field("provider", &self.provider)
             .field("active_provider", &self.active_provider)
             .field("url", &self.url)
+            .field("read_replicas", &self.read_replicas)
+            .field("pool", &self.pool)
+            .field("schemas", &self.schemas)
             .field("documentation", &self.documentation)
             .field("active_connector", &&"...")
             .finish()
@@ -50,6 +59,28 @@ pub struct StringFromEnvVar {
     pub value: String,
 }
 
+/// Connection pool settings of a `datasource`. Unset values fall back to the connector defaults.
+#[serde(rename_all = "camelCase")]
+#[derive(Clone, Debug, Default, Serialize, PartialEq)]
+pub struct PoolSettings {
+    /// maximum number of open connections
+    pub connection_limit: Option<usize>,
+    /// number of connections opened eagerly when the pool is created
+    pub min_idle_connections: Option<usize>,
+    /// seconds to wait for a free connection before giving up
+    pub pool_timeout: Option<u64>,
+    /// seconds after which a connection is closed, regardless of its usage
+    pub max_connection_lifetime: Option<u64>,
+    /// seconds after which an unused connection is closed
+    pub idle_timeout: Option<u64>,
+}
+
+impl PoolSettings {
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
 impl PreviewFeatures for Datasource {
     fn preview_features(&self) -> &Vec<String> {
         &self.preview_features
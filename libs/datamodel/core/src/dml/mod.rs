@@ -1,6 +1,8 @@
+pub use dml::composite_type::*;
 pub use dml::datamodel::*;
 pub use dml::default_value::*;
 pub use dml::field::*;
+pub use dml::field_validation::*;
 pub use dml::model::*;
 pub use dml::native_type_constructor::*;
 pub use dml::native_type_instance::*;
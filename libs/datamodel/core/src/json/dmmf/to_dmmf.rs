@@ -1,4 +1,4 @@
-use super::{Datamodel, Enum, EnumValue, Field, Function, Model, UniqueIndex};
+use super::{CompositeType, Datamodel, Enum, EnumValue, Field, Function, Model, UniqueIndex};
 use crate::{dml, IndexType, ScalarType};
 use bigdecimal::ToPrimitive;
 use prisma_value::PrismaValue;
@@ -17,16 +17,22 @@ fn schema_to_dmmf(schema: &dml::Datamodel) -> Datamodel {
     let mut datamodel = Datamodel {
         models: vec![],
         enums: vec![],
+        types: vec![],
     };
 
     for enum_model in schema.enums() {
         datamodel.enums.push(enum_to_dmmf(&enum_model));
     }
 
-    for model in schema.models() {
+    // Ignored models and fields are not part of the client.
+    for model in schema.models().filter(|model| !model.is_ignored) {
         datamodel.models.push(model_to_dmmf(&model));
     }
 
+    for composite_type in schema.composite_types() {
+        datamodel.types.push(composite_type_to_dmmf(&composite_type));
+    }
+
     datamodel
 }
 
@@ -57,15 +63,20 @@ fn model_to_dmmf(model: &dml::Model) -> Model {
         name: model.name.clone(),
         db_name: model.database_name.clone(),
         is_embedded: model.is_embedded,
-        fields: model.fields().map(|f| field_to_dmmf(model, f)).collect(),
+        fields: model
+            .fields()
+            .filter(|field| !field.is_ignored())
+            .map(|f| field_to_dmmf(model, f))
+            .collect(),
         is_generated: Some(model.is_generated),
+        is_view: model.is_view,
         documentation: model.documentation.clone(),
         id_fields: model.id_fields.clone(),
         unique_fields: model
             .indices
             .iter()
             .filter_map(|i| {
-                if i.tpe == IndexType::Unique {
+                if i.tpe == IndexType::Unique && i.predicate.is_none() {
                     Some(i.fields.clone())
                 } else {
                     None
@@ -76,7 +87,7 @@ fn model_to_dmmf(model: &dml::Model) -> Model {
             .indices
             .iter()
             .filter_map(|i| {
-                if i.tpe == IndexType::Unique {
+                if i.tpe == IndexType::Unique && i.predicate.is_none() {
                     Some(UniqueIndex {
                         name: i.name.clone(),
                         fields: i.fields.clone(),
@@ -89,6 +100,38 @@ fn model_to_dmmf(model: &dml::Model) -> Model {
     }
 }
 
+fn composite_type_to_dmmf(composite_type: &dml::CompositeType) -> CompositeType {
+    CompositeType {
+        name: composite_type.name.clone(),
+        fields: composite_type.fields().map(composite_type_field_to_dmmf).collect(),
+        documentation: composite_type.documentation.clone(),
+    }
+}
+
+fn composite_type_field_to_dmmf(field: &dml::CompositeTypeField) -> Field {
+    Field {
+        name: field.name.clone(),
+        kind: get_field_type_kind(&field.field_type),
+        is_required: field.arity == dml::FieldArity::Required,
+        is_list: field.arity == dml::FieldArity::List,
+        is_id: false,
+        is_read_only: false,
+        has_default_value: false,
+        default: None,
+        is_unique: false,
+        relation_name: None,
+        relation_from_fields: None,
+        relation_to_fields: None,
+        relation_on_delete: None,
+        field_type: get_field_type_name(&field.field_type),
+        is_generated: None,
+        is_updated_at: None,
+        is_version: None,
+        validations: None,
+        documentation: field.documentation.clone(),
+    }
+}
+
 fn field_to_dmmf(model: &dml::Model, field: &dml::Field) -> Field {
     let a_relation_field_is_based_on_this_field: bool = model
         .relation_fields()
@@ -100,7 +143,7 @@ fn field_to_dmmf(model: &dml::Model, field: &dml::Field) -> Field {
         is_required: *field.arity() == dml::FieldArity::Required,
         is_list: *field.arity() == dml::FieldArity::List,
         is_id: field.is_id(),
-        is_read_only: a_relation_field_is_based_on_this_field,
+        is_read_only: a_relation_field_is_based_on_this_field || field.is_computed(),
         has_default_value: field.default_value().is_some(),
         default: default_value_to_serde(&field.default_value().cloned()),
         is_unique: field.is_unique(),
@@ -111,13 +154,24 @@ fn field_to_dmmf(model: &dml::Model, field: &dml::Field) -> Field {
         field_type: get_field_type(field),
         is_generated: Some(field.is_generated()),
         is_updated_at: Some(field.is_updated_at()),
+        is_version: if field.is_version() { Some(true) } else { None },
+        validations: if field.validations().is_empty() {
+            None
+        } else {
+            Some(field.validations().to_vec())
+        },
         documentation: field.documentation().map(|v| v.to_owned()),
     }
 }
 
 fn get_field_kind(field: &dml::Field) -> String {
-    match field.field_type() {
+    get_field_type_kind(&field.field_type())
+}
+
+fn get_field_type_kind(field_type: &dml::FieldType) -> String {
+    match field_type {
         dml::FieldType::Relation(_) => String::from("object"),
+        dml::FieldType::CompositeType(_) => String::from("object"),
         dml::FieldType::Enum(_) => String::from("enum"),
         dml::FieldType::Base(_, _) => String::from("scalar"),
         dml::FieldType::NativeType(_, _) => String::from("scalar"),
@@ -164,8 +218,13 @@ fn function_to_serde(name: &str, args: &[PrismaValue]) -> serde_json::Value {
 }
 
 fn get_field_type(field: &dml::Field) -> String {
-    match &field.field_type() {
+    get_field_type_name(&field.field_type())
+}
+
+fn get_field_type_name(field_type: &dml::FieldType) -> String {
+    match field_type {
         dml::FieldType::Relation(relation_info) => relation_info.to.clone(),
+        dml::FieldType::CompositeType(name) => name.clone(),
         dml::FieldType::Enum(t) => t.clone(),
         dml::FieldType::Unsupported(t) => t.clone(),
         dml::FieldType::Base(t, _) => type_to_string(t),
@@ -1,4 +1,4 @@
-use crate::{configuration, StringFromEnvVar};
+use crate::{configuration, PoolSettings, StringFromEnvVar};
 
 #[serde(rename_all = "camelCase")]
 #[derive(Debug, serde::Serialize)]
@@ -7,6 +7,12 @@ pub struct SourceConfig {
     pub provider: Vec<String>,
     pub active_provider: String,
     pub url: StringFromEnvVar,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub read_replicas: Vec<StringFromEnvVar>,
+    #[serde(skip_serializing_if = "PoolSettings::is_empty")]
+    pub pool: PoolSettings,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub schemas: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub documentation: Option<String>,
 }
@@ -37,6 +43,9 @@ fn source_to_json_struct(source: &configuration::Datasource) -> SourceConfig {
         provider: source.provider.clone(),
         active_provider: source.active_provider.to_string(),
         url: source.url().clone(),
+        read_replicas: source.read_replicas.clone(),
+        pool: source.pool.clone(),
+        schemas: source.schemas.clone(),
         documentation: source.documentation.clone(),
     }
 }
@@ -5,12 +5,19 @@ use super::{
     datasource_provider::DatasourceProvider,
 };
 use crate::ast::Span;
-use crate::configuration::StringFromEnvVar;
+use crate::configuration::{PoolSettings, StringFromEnvVar};
 use crate::diagnostics::{DatamodelError, DatamodelWarning, Diagnostics, ValidatedDatasource, ValidatedDatasources};
 use crate::{ast, Datasource};
 use datamodel_connector::{CombinedConnector, Connector};
 
 const PREVIEW_FEATURES_KEY: &str = "previewFeatures";
+const READ_REPLICAS_KEY: &str = "readReplicas";
+const SCHEMAS_KEY: &str = "schemas";
+const CONNECTION_LIMIT_KEY: &str = "connectionLimit";
+const MIN_IDLE_CONNECTIONS_KEY: &str = "minIdleConnections";
+const POOL_TIMEOUT_KEY: &str = "poolTimeout";
+const MAX_CONNECTION_LIFETIME_KEY: &str = "maxConnectionLifetime";
+const IDLE_TIMEOUT_KEY: &str = "idleTimeout";
 
 /// Is responsible for loading and validating Datasources defined in an AST.
 pub struct DatasourceLoader {
@@ -161,6 +168,44 @@ impl DatasourceLoader {
             )));
         }
 
+        let read_replicas = match args.optional_arg(READ_REPLICAS_KEY) {
+            Some(_) if ignore_datasource_urls => Vec::new(),
+            Some(replicas_arg) => {
+                let mut read_replicas = Vec::new();
+
+                for replica in replicas_arg.as_array() {
+                    let (from_env_var, value) =
+                        replica.as_str_from_env().map_err(|err| diagnostics.merge_error(err))?;
+                    let value = value.trim().to_owned();
+
+                    if value.is_empty() {
+                        let msg = format!(
+                            "You must provide a nonempty URL for each read replica of the datasource `{}`.",
+                            source_name
+                        );
+
+                        return Err(diagnostics.merge_error(DatamodelError::new_source_validation_error(
+                            &msg,
+                            source_name,
+                            replica.span(),
+                        )));
+                    }
+
+                    read_replicas.push((StringFromEnvVar { from_env_var, value }, replica.span()));
+                }
+
+                read_replicas
+            }
+            None => Vec::new(),
+        };
+
+        let (schemas, schemas_span) = match args.optional_arg(SCHEMAS_KEY) {
+            Some(schemas_arg) => (schemas_arg.as_array().to_str_vec()?, schemas_arg.span()),
+            None => (Vec::new(), Span::empty()),
+        };
+
+        let pool = lift_pool_settings(&mut args, source_name).map_err(|err| diagnostics.merge_error(err))?;
+
         let preview_features_arg = args.arg(PREVIEW_FEATURES_KEY);
         let (preview_features, span) = match preview_features_arg.ok() {
             Some(x) => (x.as_array().to_str_vec()?, x.span()),
@@ -211,12 +256,41 @@ impl DatasourceLoader {
         let (successes, errors): (Vec<_>, Vec<_>) = validated_providers.into_iter().partition(|result| result.is_ok());
         if !successes.is_empty() {
             let first_successful_provider = successes.into_iter().next().unwrap()?;
+
+            // Replicas mirror the primary database, so they must be reachable through the same provider.
+            for (replica_url, span) in read_replicas.iter() {
+                if let Err(err_msg) = first_successful_provider.can_handle_url(source_name, replica_url) {
+                    return Err(diagnostics.merge_error(DatamodelError::new_source_validation_error(
+                        &err_msg,
+                        source_name,
+                        *span,
+                    )));
+                }
+            }
+
+            if !schemas.is_empty() && !first_successful_provider.connector().supports_multi_schema() {
+                let msg = format!(
+                    "The `{}` argument is not supported by the provider `{}`.",
+                    SCHEMAS_KEY,
+                    first_successful_provider.canonical_name()
+                );
+
+                return Err(diagnostics.merge_error(DatamodelError::new_source_validation_error(
+                    &msg,
+                    source_name,
+                    schemas_span,
+                )));
+            }
+
             Ok(ValidatedDatasource {
                 subject: Datasource {
                     name: source_name.to_string(),
                     provider: providers,
                     active_provider: first_successful_provider.canonical_name().to_string(),
                     url,
+                    read_replicas: read_replicas.into_iter().map(|(url, _)| url).collect(),
+                    pool,
+                    schemas,
                     documentation,
                     combined_connector,
                     active_connector: first_successful_provider.connector(),
@@ -234,6 +308,57 @@ impl DatasourceLoader {
     }
 }
 
+fn lift_pool_settings(args: &mut Arguments, source_name: &str) -> Result<PoolSettings, DatamodelError> {
+    let connection_limit = non_negative_int_arg(args, CONNECTION_LIMIT_KEY, source_name)?;
+    let min_idle_connections = non_negative_int_arg(args, MIN_IDLE_CONNECTIONS_KEY, source_name)?;
+    let pool_timeout = non_negative_int_arg(args, POOL_TIMEOUT_KEY, source_name)?;
+    let max_connection_lifetime = non_negative_int_arg(args, MAX_CONNECTION_LIFETIME_KEY, source_name)?;
+    let idle_timeout = non_negative_int_arg(args, IDLE_TIMEOUT_KEY, source_name)?;
+
+    if let (Some(limit), Some(min_idle)) = (connection_limit, min_idle_connections) {
+        if min_idle > limit {
+            let msg = format!(
+                "The `{}` argument must not be greater than the `{}` argument.",
+                MIN_IDLE_CONNECTIONS_KEY, CONNECTION_LIMIT_KEY
+            );
+
+            return Err(DatamodelError::new_source_validation_error(
+                &msg,
+                source_name,
+                args.span(),
+            ));
+        }
+    }
+
+    Ok(PoolSettings {
+        connection_limit: connection_limit.map(|limit| limit as usize),
+        min_idle_connections: min_idle_connections.map(|min_idle| min_idle as usize),
+        pool_timeout,
+        max_connection_lifetime,
+        idle_timeout,
+    })
+}
+
+fn non_negative_int_arg(args: &mut Arguments, key: &str, source_name: &str) -> Result<Option<u64>, DatamodelError> {
+    match args.optional_arg(key) {
+        Some(arg) => {
+            let value = arg.as_int()?;
+
+            if value < 0 {
+                let msg = format!("The `{}` argument must not be negative.", key);
+                return Err(DatamodelError::new_source_validation_error(
+                    &msg,
+                    source_name,
+                    arg.span(),
+                ));
+            }
+
+            Ok(Some(value as u64))
+        }
+        None => Ok(None),
+    }
+}
+
 fn get_builtin_datasource_providers() -> Vec<Box<dyn DatasourceProvider>> {
     vec![
         Box::new(MySqlDatasourceProvider::new()),
@@ -50,6 +50,10 @@ impl<'a, 'b> LiftAstToDml<'a, 'b> {
                     Ok(md) => schema.add_model(md),
                     Err(mut err) => errors.append(&mut err),
                 },
+                ast::Top::CompositeType(ct) => match self.lift_composite_type(&ct, ast_schema) {
+                    Ok(ct) => schema.add_composite_type(ct),
+                    Err(mut err) => errors.append(&mut err),
+                },
                 ast::Top::Source(_) => { /* Source blocks are explicitly ignored by the validator */ }
                 ast::Top::Generator(_) => { /* Generator blocks are explicitly ignored by the validator */ }
                 // TODO: For now, type blocks are never checked on their own.
@@ -68,6 +72,7 @@ impl<'a, 'b> LiftAstToDml<'a, 'b> {
     fn lift_model(&self, ast_model: &ast::Model, ast_schema: &ast::SchemaAst) -> Result<dml::Model, Diagnostics> {
         let mut model = dml::Model::new(ast_model.name.name.clone(), None);
         model.documentation = ast_model.documentation.clone().map(|comment| comment.text);
+        model.is_view = ast_model.is_view;
 
         let mut errors = Diagnostics::new();
 
@@ -89,6 +94,60 @@ impl<'a, 'b> LiftAstToDml<'a, 'b> {
         Ok(model)
     }
 
+    /// Internal: Validates a composite type AST node and lifts it to a DML composite type.
+    fn lift_composite_type(
+        &self,
+        ast_composite_type: &ast::CompositeType,
+        ast_schema: &ast::SchemaAst,
+    ) -> Result<dml::CompositeType, Diagnostics> {
+        let mut composite_type = dml::CompositeType::new(&ast_composite_type.name.name);
+        composite_type.documentation = ast_composite_type.documentation.clone().map(|comment| comment.text);
+
+        let mut errors = Diagnostics::new();
+
+        for attribute in &ast_composite_type.attributes {
+            errors.push_error(DatamodelError::new_attribute_validation_error(
+                "Composite types can not have block attributes.",
+                &attribute.name.name,
+                attribute.span,
+            ));
+        }
+
+        for ast_field in &ast_composite_type.fields {
+            if let Some(attribute) = ast_field.attributes.first() {
+                errors.push_error(DatamodelError::new_attribute_validation_error(
+                    "Fields of composite types can not have attributes.",
+                    &attribute.name.name,
+                    attribute.span,
+                ));
+                continue;
+            }
+
+            match self.lift_field_type(ast_field, None, ast_schema, &mut Vec::new()) {
+                Ok((FieldType::Relation(_), _)) => errors.push_error(DatamodelError::new_validation_error(
+                    &format!(
+                        "The field `{}` of the composite type `{}` refers to a model. Composite types can only contain scalar, enum and composite type fields.",
+                        ast_field.name.name, ast_composite_type.name.name
+                    ),
+                    ast_field.field_type.span,
+                )),
+                Ok((field_type, _)) => {
+                    let arity = self.lift_field_arity(&ast_field.arity);
+                    let mut field = dml::CompositeTypeField::new(&ast_field.name.name, arity, field_type);
+                    field.documentation = ast_field.documentation.clone().map(|comment| comment.text);
+                    composite_type.add_field(field);
+                }
+                Err(err) => errors.push_error(err),
+            }
+        }
+
+        if errors.has_errors() {
+            return Err(errors);
+        }
+
+        Ok(composite_type)
+    }
+
     /// Internal: Validates an enum AST node.
     fn lift_enum(&self, ast_enum: &ast::Enum) -> Result<dml::Enum, Diagnostics> {
         let mut errors = Diagnostics::new();
@@ -362,6 +421,8 @@ impl<'a, 'b> LiftAstToDml<'a, 'b> {
             Ok((dml::FieldType::Relation(dml::RelationInfo::new(type_name)), vec![]))
         } else if ast_schema.find_enum(type_name).is_some() {
             Ok((dml::FieldType::Enum(type_name.clone()), vec![]))
+        } else if ast_schema.find_composite_type(type_name).is_some() {
+            Ok((dml::FieldType::CompositeType(type_name.clone()), vec![]))
         } else {
             self.resolve_custom_type(ast_field, ast_schema, checked_types)
         }
@@ -392,7 +453,7 @@ impl<'a, 'b> LiftAstToDml<'a, 'b> {
             let (field_type, mut attrs) =
                 self.lift_field_type(custom_type, Some(type_name.to_owned()), ast_schema, checked_types)?;
 
-            if let dml::FieldType::Relation(_) = field_type {
+            if let dml::FieldType::Relation(_) | dml::FieldType::CompositeType(_) = field_type {
                 return Err(DatamodelError::new_validation_error(
                     "Only scalar types can be used for defining custom types.",
                     custom_type.field_type.span,
@@ -54,6 +54,12 @@ impl Standardiser {
             for field in model.fields_mut() {
                 if let Field::RelationField(field) = field {
                     let related_model = schema_copy.find_model(&field.relation_info.to).expect(STATE_ERROR);
+
+                    // Ignored models can lack a unique criteria, there is nothing to infer from them then.
+                    if related_model.loose_unique_criterias().is_empty() {
+                        continue;
+                    }
+
                     let unique_criteria = self.unique_criteria(&related_model);
                     let related_field = schema_copy.find_related_field_bang(field);
                     let related_model_name = &related_model.name;
@@ -227,9 +233,11 @@ impl Standardiser {
                         references: vec![],
                         name: rel_info.name.clone(),
                         on_delete: OnDeleteStrategy::None,
+                        constraint_name: None,
                     };
                     let mut back_relation_field = dml::RelationField::new_generated(&model.name, relation_info);
                     back_relation_field.arity = dml::FieldArity::List;
+                    back_relation_field.is_ignored = model.is_ignored;
 
                     result.push(AddMissingBackRelationField {
                         model: rel_info.to.clone(),
@@ -238,6 +246,16 @@ impl Standardiser {
                         related_field: field.name.to_string(),
                         underlying_fields: vec![],
                     });
+                } else if model.loose_unique_criterias().is_empty() {
+                    // Only ignored models can lack a unique criteria, and nothing could reference them.
+                    errors.push_error(DatamodelError::new_model_validation_error(
+                        &format!(
+                            "The back relation field of `{}` in `{}` cannot be generated, because the model `{}` does not have a unique criteria that could be referenced.",
+                            &field.name, &model.name, &model.name,
+                        ),
+                        &model.name,
+                        schema_ast.find_field(&model.name, &field.name).expect(ERROR_GEN_STATE_ERROR).span,
+                    ));
                 } else {
                     let unique_criteria = self.unique_criteria(&model);
                     let unique_criteria_field_names =
@@ -298,9 +316,12 @@ impl Standardiser {
                         references: unique_criteria_field_names,
                         name: rel_info.name.clone(),
                         on_delete: OnDeleteStrategy::None,
+                        constraint_name: None,
                     };
 
-                    let back_relation_field = dml::RelationField::new_generated(&model.name, relation_info);
+                    let mut back_relation_field = dml::RelationField::new_generated(&model.name, relation_info);
+                    back_relation_field.is_ignored = model.is_ignored;
+
                     result.push(AddMissingBackRelationField {
                         model: rel_info.to.clone(),
                         field: back_relation_field,
@@ -2,7 +2,7 @@ use crate::ast::WithAttributes;
 use crate::{
     ast, configuration,
     diagnostics::{DatamodelError, Diagnostics},
-    dml, DefaultValue, FieldType,
+    dml, DefaultValue, FieldType, ValueGeneratorFn,
 };
 use prisma_value::PrismaValue;
 use std::collections::HashSet;
@@ -42,10 +42,14 @@ impl<'a> Validator<'a> {
             // Having a separate error collection allows checking whether any error has occurred for a model.
             let mut errors_for_model = Diagnostics::new();
 
-            if let Err(err) = self.validate_model_has_strict_unique_criteria(
-                ast_schema.find_model(&model.name).expect(STATE_ERROR),
-                model,
-            ) {
+            let ast_model = ast_schema.find_model(&model.name).expect(STATE_ERROR);
+
+            // An ignored identifier also leaves the model without a unique criteria, only report the cause.
+            if let Err(err) = self
+                .validate_identifier_fields_are_not_ignored(ast_model, model)
+                .and_then(|()| self.validate_ignored_fields_can_be_left_out(ast_model, model))
+                .and_then(|()| self.validate_model_has_strict_unique_criteria(ast_model, model))
+            {
                 errors_for_model.push_error(err);
             }
             if let Err(err) = self.validate_model_name(ast_schema.find_model(&model.name).expect(STATE_ERROR), model) {
@@ -60,6 +64,14 @@ impl<'a> Validator<'a> {
                 errors_for_model.push_error(err);
             }
 
+            if let Err(err) = self.validate_views_have_no_relations(ast_schema, schema, model) {
+                errors_for_model.push_error(err);
+            }
+
+            if let Err(err) = self.validate_relations_to_ignored_models(ast_schema, schema, model) {
+                errors_for_model.push_error(err);
+            }
+
             if let Err(ref mut the_errors) =
                 self.validate_field_arities(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
             {
@@ -72,6 +84,12 @@ impl<'a> Validator<'a> {
                 errors_for_model.append(the_errors);
             }
 
+            if let Err(ref mut the_errors) =
+                self.validate_composite_type_fields(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors_for_model.append(the_errors);
+            }
+
             if let Err(ref mut the_errors) =
                 self.validate_field_connector_specific(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
             {
@@ -96,6 +114,42 @@ impl<'a> Validator<'a> {
                 errors_for_model.append(the_errors);
             }
 
+            if let Err(err) =
+                self.validate_version_fields(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors_for_model.push_error(err);
+            }
+
+            if let Err(err) =
+                self.validate_computed_fields(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors_for_model.push_error(err);
+            }
+
+            if let Err(err) =
+                self.validate_fulltext_indexes(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors_for_model.push_error(err);
+            }
+
+            if let Err(err) = self.validate_index_options(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors_for_model.push_error(err);
+            }
+
+            if let Err(err) =
+                self.validate_constraint_names(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors_for_model.push_error(err);
+            }
+
+            if let Err(err) = self.validate_schema_attribute(
+                &ast_schema.find_model(&model.name).expect(STATE_ERROR).attributes,
+                model.schema.as_deref(),
+            ) {
+                errors_for_model.push_error(err);
+            }
+
             if let Err(ref mut the_errors) = self.validate_base_fields_for_relation(
                 schema,
                 ast_schema.find_model(&model.name).expect(STATE_ERROR),
@@ -124,6 +178,17 @@ impl<'a> Validator<'a> {
             all_errors.append(&mut errors_for_model);
         }
 
+        // Composite type level validations.
+        for composite_type in schema.composite_types() {
+            if let Err(err) = self.validate_composite_type_is_not_recursive(
+                ast_schema.find_composite_type(&composite_type.name).expect(STATE_ERROR),
+                schema,
+                composite_type,
+            ) {
+                all_errors.push_error(err);
+            }
+        }
+
         // Enum level validations.
         for declared_enum in schema.enums() {
             let mut errors_for_enum = Diagnostics::new();
@@ -134,6 +199,13 @@ impl<'a> Validator<'a> {
                 errors_for_enum.push_error(err);
             }
 
+            if let Err(err) = self.validate_schema_attribute(
+                &ast_schema.find_enum(&declared_enum.name).expect(STATE_ERROR).attributes,
+                declared_enum.schema.as_deref(),
+            ) {
+                errors_for_enum.push_error(err);
+            }
+
             all_errors.append(&mut errors_for_enum);
         }
 
@@ -178,6 +250,14 @@ impl<'a> Validator<'a> {
     fn validate_names(&self, ast_schema: &ast::SchemaAst) -> Result<(), Diagnostics> {
         let mut errors = Diagnostics::new();
 
+        for composite_type in ast_schema.composite_types() {
+            errors.push_opt_error(composite_type.name.validate("Composite type").err());
+
+            for field in composite_type.fields.iter() {
+                errors.push_opt_error(field.name.validate("Field").err());
+            }
+        }
+
         for model in ast_schema.models() {
             errors.push_opt_error(model.name.validate("Model").err());
             errors.append(&mut model.validate_attributes());
@@ -247,7 +327,11 @@ impl<'a> Validator<'a> {
             None => false,
         };
 
-        for field in model.scalar_fields() {
+        // Lists of composite types are stored as a single JSON array.
+        for field in model
+            .scalar_fields()
+            .filter(|f| f.field_type.composite_type().is_none())
+        {
             if field.is_list() && !scalar_lists_are_supported {
                 errors.push_error(DatamodelError::new_scalar_list_fields_are_not_supported(
                     &model.name,
@@ -292,6 +376,105 @@ impl<'a> Validator<'a> {
         }
     }
 
+    fn validate_composite_type_fields(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), Diagnostics> {
+        let mut errors = Diagnostics::new();
+
+        let supports_json_type = match self.source {
+            Some(source) => source.combined_connector.supports_json(),
+            None => false,
+        };
+
+        for field in model.scalar_fields() {
+            let composite_type = match field.field_type.composite_type() {
+                Some(composite_type) => composite_type,
+                None => continue,
+            };
+
+            let span = ast_model.find_field(&field.name).span;
+            let mut push_error = |message: String| {
+                errors.push_error(DatamodelError::new_field_validation_error(
+                    &message,
+                    &model.name,
+                    &field.name,
+                    span,
+                ))
+            };
+
+            if !supports_json_type {
+                push_error(format!(
+                    "Field `{}` in model `{}` can't be of the composite type `{}`. Composite types are stored as Json, which the current connector does not support.",
+                    &field.name, &model.name, composite_type
+                ));
+            }
+
+            let in_unique_criteria = field.is_id
+                || field.is_unique
+                || model.id_fields.contains(&field.name)
+                || model.indices.iter().any(|index| index.fields.contains(&field.name));
+
+            if in_unique_criteria {
+                push_error(format!(
+                    "Field `{}` in model `{}` is of the composite type `{}` and can not be part of an id, unique constraint or index.",
+                    &field.name, &model.name, composite_type
+                ));
+            }
+
+            if field.default_value.is_some() || field.is_updated_at || field.is_version || !field.validations.is_empty()
+            {
+                push_error(format!(
+                    "Field `{}` in model `{}` is of the composite type `{}` and can not have a default value, `@updatedAt`, `@version` or validation attributes.",
+                    &field.name, &model.name, composite_type
+                ));
+            }
+        }
+
+        errors.to_result()
+    }
+
+    /// Rejects composite types that embed themselves through required fields only, as no
+    /// finite value could ever be stored for them.
+    fn validate_composite_type_is_not_recursive(
+        &self,
+        ast_composite_type: &ast::CompositeType,
+        schema: &dml::Datamodel,
+        composite_type: &dml::CompositeType,
+    ) -> Result<(), DatamodelError> {
+        fn find_cycle<'b>(schema: &'b dml::Datamodel, path: &mut Vec<&'b str>) -> bool {
+            let current = schema.find_composite_type(path.last().unwrap()).expect(STATE_ERROR);
+
+            for next in current
+                .fields()
+                .filter(|f| f.arity.is_required())
+                .filter_map(|f| f.field_type.composite_type())
+            {
+                let seen = path.contains(&next);
+                path.push(next);
+
+                if seen || find_cycle(schema, path) {
+                    return true;
+                }
+
+                path.pop();
+            }
+
+            false
+        }
+
+        let mut path = vec![composite_type.name.as_str()];
+
+        if find_cycle(schema, &mut path) {
+            return Err(DatamodelError::new_validation_error(
+                &format!(
+                    "Composite types can not embed themselves through required fields. Recursive path was: {}. Make one of the fields optional or a list.",
+                    path.join(" -> ")
+                ),
+                ast_composite_type.span,
+            ));
+        }
+
+        Ok(())
+    }
+
     fn validate_enum_default_values(
         &self,
         data_model: &dml::Datamodel,
@@ -301,17 +484,29 @@ impl<'a> Validator<'a> {
         let mut errors = Diagnostics::new();
 
         for field in model.scalar_fields() {
-            if let Some(DefaultValue::Single(PrismaValue::Enum(enum_value))) = &field.default_value {
-                if let FieldType::Enum(enum_name) = &field.field_type {
-                    if let Some(dml_enum) = data_model.find_enum(&enum_name) {
-                        if !dml_enum.values.iter().any(|value| &value.name == enum_value) {
-                            errors.push_error(DatamodelError::new_attribute_validation_error(
-                                &"The defined default value is not a valid value of the enum specified for the field."
-                                    .to_string(),
-                                "default",
-                                ast_model.find_field(&field.name).span,
-                            ))
-                        }
+            let enum_values = match &field.default_value {
+                Some(DefaultValue::Single(PrismaValue::Enum(enum_value))) => vec![enum_value],
+                Some(DefaultValue::Single(PrismaValue::List(values))) => values
+                    .iter()
+                    .filter_map(|value| match value {
+                        PrismaValue::Enum(enum_value) => Some(enum_value),
+                        _ => None,
+                    })
+                    .collect(),
+                _ => continue,
+            };
+
+            if let FieldType::Enum(enum_name) = &field.field_type {
+                if let Some(dml_enum) = data_model.find_enum(&enum_name) {
+                    let is_valid = |enum_value: &String| dml_enum.values.iter().any(|value| &value.name == enum_value);
+
+                    if !enum_values.into_iter().all(is_valid) {
+                        errors.push_error(DatamodelError::new_attribute_validation_error(
+                            &"The defined default value is not a valid value of the enum specified for the field."
+                                .to_string(),
+                            "default",
+                            ast_model.find_field(&field.name).span,
+                        ))
                     }
                 }
             }
@@ -363,6 +558,19 @@ impl<'a> Validator<'a> {
                     ast_field.span,
                 ))
                 }
+
+                let uses_sequence = matches!(
+                    &field.default_value,
+                    Some(DefaultValue::Expression(generator)) if generator.generator == ValueGeneratorFn::Sequence
+                );
+
+                if uses_sequence && !data_source.combined_connector.supports_named_sequences() {
+                    errors.push_error(DatamodelError::new_attribute_validation_error(
+                        "The `sequence()` default value is not supported by the current connector.",
+                        "default",
+                        ast_field.span,
+                    ))
+                }
             }
         }
 
@@ -373,6 +581,258 @@ impl<'a> Validator<'a> {
         }
     }
 
+    fn validate_version_fields(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), DatamodelError> {
+        if model.scalar_fields().filter(|field| field.is_version).count() > 1 {
+            return Err(DatamodelError::new_attribute_validation_error(
+                "The `@version` attribute can only be used on one field per model.",
+                "version",
+                ast_model.span,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Computed fields are written by the database only, and need a connector that supports them.
+    fn validate_computed_fields(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), DatamodelError> {
+        for field in model.scalar_fields() {
+            let computed = match &field.computed {
+                Some(computed) => computed,
+                None => continue,
+            };
+
+            let ast_field = ast_model.find_field(&field.name);
+            let span = ast_field
+                .attributes
+                .iter()
+                .find(|attr| attr.name.name == "computed")
+                .map(|attr| attr.span)
+                .unwrap_or(ast_field.span);
+
+            let conflicting_attribute = if field.is_id {
+                Some("@id")
+            } else if field.default_value.is_some() {
+                Some("@default")
+            } else if field.is_updated_at {
+                Some("@updatedAt")
+            } else if field.is_version {
+                Some("@version")
+            } else {
+                None
+            };
+
+            if let Some(conflicting_attribute) = conflicting_attribute {
+                return Err(DatamodelError::new_attribute_validation_error(
+                    &format!(
+                        "Fields that are marked with @computed can not use {}.",
+                        conflicting_attribute
+                    ),
+                    "computed",
+                    span,
+                ));
+            }
+
+            let connector = match self.source {
+                Some(source) => &source.combined_connector,
+                None => continue,
+            };
+
+            if !connector.supports_computed_fields() {
+                return Err(DatamodelError::new_attribute_validation_error(
+                    "The current connector does not support computed fields.",
+                    "computed",
+                    span,
+                ));
+            }
+
+            if !computed.stored && !connector.supports_virtual_computed_fields() {
+                return Err(DatamodelError::new_attribute_validation_error(
+                    "The current connector does not support computed fields that are not stored.",
+                    "computed",
+                    span,
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn validate_fulltext_indexes(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), DatamodelError> {
+        let (supports_full_text_index, provider) = match self.source {
+            Some(source) => (
+                source.combined_connector.supports_full_text_index(),
+                source.active_provider.as_str(),
+            ),
+            None => (false, "current"),
+        };
+
+        if !supports_full_text_index && model.indices.iter().any(|i| i.tpe == dml::IndexType::Fulltext) {
+            let span = ast_model
+                .attributes
+                .iter()
+                .find(|attr| attr.name.name == "fulltext")
+                .map(|attr| attr.span)
+                .unwrap_or(ast_model.span);
+
+            return Err(DatamodelError::new_attribute_validation_error(
+                &format!(
+                    "Full-text indexes are not supported by the `{}` connector. They are only supported on PostgreSQL, MySQL and SQLite.",
+                    provider
+                ),
+                "fulltext",
+                span,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Prefix lengths, index types and partial indexes are only available on some databases.
+    fn validate_index_options(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), DatamodelError> {
+        let connector = match self.source {
+            Some(source) => &source.combined_connector,
+            None => return Ok(()),
+        };
+
+        for (idx, index) in model.indices.iter().enumerate() {
+            let unsupported = if !connector.supports_index_prefix_length()
+                && index.field_options.iter().any(|options| options.length.is_some())
+            {
+                "prefix lengths on index fields"
+            } else if !connector.supports_index_algorithms() && index.algorithm.is_some() {
+                "index types"
+            } else if !connector.supports_partial_indexes() && index.predicate.is_some() {
+                "partial indexes"
+            } else {
+                continue;
+            };
+
+            let attribute_name = match index.tpe {
+                dml::IndexType::Unique => "unique",
+                dml::IndexType::Normal => "index",
+                dml::IndexType::Fulltext => "fulltext",
+            };
+
+            // The n-th index of a type was defined by the n-th attribute of that name.
+            let nth = model.indices[..idx]
+                .iter()
+                .filter(|other| other.tpe == index.tpe)
+                .count();
+            let span = ast_model
+                .attributes
+                .iter()
+                .filter(|attr| attr.name.name == attribute_name)
+                .nth(nth)
+                .map(|attr| attr.span)
+                .unwrap_or(ast_model.span);
+
+            return Err(DatamodelError::new_attribute_validation_error(
+                &format!("The current connector does not support {}.", unsupported),
+                attribute_name,
+                span,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Primary key and foreign key names given with `map` can only be set on some databases.
+    fn validate_constraint_names(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), DatamodelError> {
+        let connector = match self.source {
+            Some(source) => &source.combined_connector,
+            None => return Ok(()),
+        };
+
+        let attribute_span = |attributes: &[ast::Attribute], attribute_name: &str, fallback: ast::Span| {
+            attributes
+                .iter()
+                .find(|attr| attr.name.name == attribute_name)
+                .map(|attr| attr.span)
+                .unwrap_or(fallback)
+        };
+
+        if !connector.supports_named_primary_keys() {
+            if model.id_constraint_name.is_some() {
+                return Err(DatamodelError::new_attribute_validation_error(
+                    "The current connector does not support named primary keys.",
+                    "id",
+                    attribute_span(&ast_model.attributes, "id", ast_model.span),
+                ));
+            }
+
+            if let Some(field) = model.scalar_fields().find(|field| field.id_constraint_name.is_some()) {
+                let ast_field = ast_model.find_field(&field.name);
+
+                return Err(DatamodelError::new_attribute_validation_error(
+                    "The current connector does not support named primary keys.",
+                    "id",
+                    attribute_span(&ast_field.attributes, "id", ast_field.span),
+                ));
+            }
+        }
+
+        if !connector.supports_named_foreign_keys() {
+            if let Some(field) = model
+                .relation_fields()
+                .find(|field| field.relation_info.constraint_name.is_some())
+            {
+                let ast_field = ast_model.find_field(&field.name);
+
+                return Err(DatamodelError::new_attribute_validation_error(
+                    "The current connector does not support named foreign keys.",
+                    "relation",
+                    attribute_span(&ast_field.attributes, "relation", ast_field.span),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// A `@@schema` must name one of the schemas listed in the `schemas` property of the datasource.
+    fn validate_schema_attribute(
+        &self,
+        ast_attributes: &[ast::Attribute],
+        schema: Option<&str>,
+    ) -> Result<(), DatamodelError> {
+        let schema = match schema {
+            Some(schema) => schema,
+            None => return Ok(()),
+        };
+
+        let span = ast_attributes
+            .iter()
+            .find(|attr| attr.name.name == "schema")
+            .map(|attr| attr.span)
+            .unwrap_or_else(ast::Span::empty);
+
+        let schemas: &[String] = match self.source {
+            Some(source) => &source.schemas,
+            None => &[],
+        };
+
+        if schemas.is_empty() {
+            return Err(DatamodelError::new_attribute_validation_error(
+                "The `@@schema` attribute can only be used if the datasource defines a `schemas` property.",
+                "schema",
+                span,
+            ));
+        }
+
+        if !schemas.iter().any(|s| s == schema) {
+            return Err(DatamodelError::new_attribute_validation_error(
+                &format!(
+                    "The schema `{}` is not defined in the `schemas` property of the datasource.",
+                    schema
+                ),
+                "schema",
+                span,
+            ));
+        }
+
+        Ok(())
+    }
+
     fn validate_model_has_strict_unique_criteria(
         &self,
         ast_model: &ast::Model,
@@ -426,7 +886,8 @@ impl<'a> Validator<'a> {
             ast_model.span,
         ));
 
-        if model.strict_unique_criterias().is_empty() {
+        // Ignored models are not part of the client, so they do not need to be identifiable.
+        if model.strict_unique_criterias().is_empty() && !model.is_ignored {
             return missing_id_criteria_error;
         }
 
@@ -536,6 +997,130 @@ impl<'a> Validator<'a> {
         Ok(())
     }
 
+    fn validate_views_have_no_relations(
+        &self,
+        ast_schema: &ast::SchemaAst,
+        datamodel: &dml::Datamodel,
+        model: &dml::Model,
+    ) -> Result<(), DatamodelError> {
+        for field in model.relation_fields().filter(|f| !f.is_generated) {
+            let related_model = datamodel.find_model(&field.relation_info.to);
+            let points_to_view = related_model.map(|m| m.is_view).unwrap_or(false);
+
+            if model.is_view || points_to_view {
+                return Err(DatamodelError::new_model_validation_error(
+                    "Views cannot have relation fields, and relation fields cannot point to views.",
+                    &model.name,
+                    ast_schema.find_field(&model.name, &field.name).expect(STATE_ERROR).span,
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The fields identifying the records of a model, i.e. its id or else its first unique criteria, can only be
+    /// ignored together with the model.
+    fn validate_identifier_fields_are_not_ignored(
+        &self,
+        ast_model: &ast::Model,
+        model: &dml::Model,
+    ) -> Result<(), DatamodelError> {
+        if model.is_ignored {
+            return Ok(());
+        }
+
+        let find_fields = |names: &[String]| -> Vec<&dml::ScalarField> {
+            names.iter().filter_map(|name| model.find_scalar_field(name)).collect()
+        };
+
+        let identifier_fields = if let Some(field) = model.singular_id_fields().next() {
+            vec![field]
+        } else if !model.id_fields.is_empty() {
+            find_fields(&model.id_fields)
+        } else if let Some(field) = model.scalar_fields().find(|f| f.is_unique && f.is_required()) {
+            vec![field]
+        } else {
+            model
+                .indices
+                .iter()
+                .filter(|index| index.is_unique() && index.predicate.is_none())
+                .map(|index| find_fields(&index.fields))
+                .find(|fields| fields.iter().all(|f| f.is_required()))
+                .unwrap_or_default()
+        };
+
+        match identifier_fields.into_iter().find(|field| field.is_ignored) {
+            Some(field) => Err(DatamodelError::new_attribute_validation_error(
+                &format!(
+                    "The field `{}` on Model `{}` identifies its records and can not be ignored. Ignore the whole model with `@@ignore` instead.",
+                    field.name, model.name
+                ),
+                "ignore",
+                ast_model.find_field(&field.name).span,
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Ignored fields are left out of the records the client creates, so the database has to be able to fill them.
+    fn validate_ignored_fields_can_be_left_out(
+        &self,
+        ast_model: &ast::Model,
+        model: &dml::Model,
+    ) -> Result<(), DatamodelError> {
+        if model.is_ignored {
+            return Ok(());
+        }
+
+        let required_field = model
+            .scalar_fields()
+            .find(|field| field.is_ignored && field.is_required() && field.default_value.is_none());
+
+        match required_field {
+            Some(field) => Err(DatamodelError::new_attribute_validation_error(
+                &format!(
+                    "The field `{}` on Model `{}` is required and has no default value, so records could not be created once it is ignored. Make the field optional or give it a default value.",
+                    field.name, model.name
+                ),
+                "ignore",
+                ast_model.find_field(&field.name).span,
+            )),
+            None => Ok(()),
+        }
+    }
+
+    fn validate_relations_to_ignored_models(
+        &self,
+        ast_schema: &ast::SchemaAst,
+        datamodel: &dml::Datamodel,
+        model: &dml::Model,
+    ) -> Result<(), DatamodelError> {
+        if model.is_ignored {
+            return Ok(());
+        }
+
+        for field in model.relation_fields().filter(|f| !f.is_ignored) {
+            let points_to_ignored_model = datamodel
+                .find_model(&field.relation_info.to)
+                .map(|m| m.is_ignored)
+                .unwrap_or(false);
+
+            if points_to_ignored_model {
+                return Err(DatamodelError::new_attribute_validation_error(
+                    &format!(
+                        "The relation field `{}` on Model `{}` must specify the `@ignore` attribute, because the model {} it is pointing to is marked ignored.",
+                        field.name, model.name, field.relation_info.to
+                    ),
+                    "ignore",
+                    ast_schema.find_field(&model.name, &field.name).expect(STATE_ERROR).span,
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
     fn validate_base_fields_for_relation(
         &self,
         _datamodel: &dml::Datamodel,
@@ -21,6 +21,33 @@ pub trait AttributeValidator<T> {
     /// Serializes the given attribute's arguments for rendering.
     fn serialize(&self, obj: &T, datamodel: &dml::Datamodel) -> Vec<ast::Attribute>;
 
+    /// Parses the optional `map` argument, which holds the database name of a constraint.
+    fn parse_constraint_name(&self, args: &mut Arguments) -> Result<Option<String>, DatamodelError> {
+        let map_arg = match args.optional_arg("map") {
+            Some(map_arg) => map_arg,
+            None => return Ok(None),
+        };
+
+        let name = map_arg.as_str()?;
+
+        if name.is_empty() {
+            return Err(DatamodelError::new_attribute_validation_error(
+                "The constraint name given with `map` must not be empty.",
+                self.attribute_name(),
+                map_arg.span(),
+            ));
+        }
+
+        Ok(Some(name))
+    }
+
+    /// Serializes the database name of a constraint as a `map` argument, if there is one.
+    fn serialize_constraint_name(&self, name: Option<&str>) -> Vec<ast::Argument> {
+        name.map(|name| ast::Argument::new_string("map", name))
+            .into_iter()
+            .collect()
+    }
+
     /// Shorthand to construct an attribute validation error.
     fn new_attribute_validation_error(&self, msg: &str, span: ast::Span) -> Result<(), DatamodelError> {
         Err(DatamodelError::new_attribute_validation_error(
@@ -0,0 +1,50 @@
+use super::{super::helpers::*, AttributeValidator};
+use crate::diagnostics::DatamodelError;
+use crate::{ast, dml, Datamodel};
+
+/// Prismas builtin `@@check` attribute.
+pub struct CheckAttributeValidator {}
+
+impl AttributeValidator<dml::Model> for CheckAttributeValidator {
+    fn attribute_name(&self) -> &str {
+        "check"
+    }
+
+    fn is_duplicate_definition_allowed(&self) -> bool {
+        true
+    }
+
+    fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Model) -> Result<(), DatamodelError> {
+        let expression = args.default_arg("expression")?.as_str()?;
+
+        if expression.trim().is_empty() {
+            return self
+                .new_attribute_validation_error("The check constraint expression must not be empty.", args.span());
+        }
+
+        let name = match args.optional_arg("name") {
+            Some(name_arg) => Some(name_arg.as_str()?),
+            None => None,
+        };
+
+        obj.check_constraints.push(dml::CheckConstraint { name, expression });
+
+        Ok(())
+    }
+
+    fn serialize(&self, model: &dml::Model, _datamodel: &Datamodel) -> Vec<ast::Attribute> {
+        model
+            .check_constraints
+            .iter()
+            .map(|check_constraint| {
+                let mut args = vec![ast::Argument::new_string("", &check_constraint.expression)];
+
+                if let Some(name) = &check_constraint.name {
+                    args.push(ast::Argument::new_string("name", name));
+                }
+
+                ast::Attribute::new(self.attribute_name(), args)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,66 @@
+use super::{super::helpers::*, AttributeValidator};
+use crate::diagnostics::DatamodelError;
+use crate::{ast, dml};
+
+/// Prismas builtin `@computed` attribute.
+pub struct ComputedAttributeValidator {}
+
+impl AttributeValidator<dml::Field> for ComputedAttributeValidator {
+    fn attribute_name(&self) -> &'static str {
+        &"computed"
+    }
+
+    fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Field) -> Result<(), DatamodelError> {
+        let sf = match obj {
+            dml::Field::ScalarField(sf) => sf,
+            dml::Field::RelationField(_) => {
+                return self.new_attribute_validation_error(
+                    "The attribute @computed can only be used on scalar fields.",
+                    args.span(),
+                )
+            }
+        };
+
+        let expression = args.default_arg("expression")?.as_str()?;
+
+        if expression.trim().is_empty() {
+            return self.new_attribute_validation_error("The computed expression must not be empty.", args.span());
+        }
+
+        let stored = match args.optional_arg("stored") {
+            Some(stored_arg) => stored_arg.as_bool()?,
+            None => true,
+        };
+
+        if sf.arity == dml::FieldArity::List {
+            return self.new_attribute_validation_error(
+                "Fields that are marked with @computed can not be lists.",
+                args.span(),
+            );
+        }
+
+        sf.computed = Some(dml::ComputedValue { expression, stored });
+
+        Ok(())
+    }
+
+    fn serialize(&self, field: &dml::Field, _datamodel: &dml::Datamodel) -> Vec<ast::Attribute> {
+        let computed = match field {
+            dml::Field::ScalarField(sf) => sf.computed.as_ref(),
+            dml::Field::RelationField(_) => None,
+        };
+
+        match computed {
+            Some(computed) => {
+                let mut args = vec![ast::Argument::new_string("", &computed.expression)];
+
+                if !computed.stored {
+                    args.push(ast::Argument::new_constant("stored", "false"));
+                }
+
+                vec![ast::Attribute::new(self.attribute_name(), args)]
+            }
+            None => vec![],
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use super::{super::helpers::*, AttributeValidator};
 use crate::diagnostics::DatamodelError;
-use crate::{ast, dml, ValueGenerator};
+use crate::{ast, dml, ValueGeneratorFn};
 use prisma_value::PrismaValue;
 
 /// Prismas builtin `@default` attribute.
@@ -15,33 +15,28 @@ impl AttributeValidator<dml::Field> for DefaultAttributeValidator {
         if let dml::Field::RelationField(_) = field {
             return self.new_attribute_validation_error("Cannot set a default value on a relation field.", args.span());
         } else if let dml::Field::ScalarField(sf) = field {
-            // If we allow list default values, we need to adjust the types below properly for that case.
-            if sf.arity == dml::FieldArity::List {
-                return self.new_attribute_validation_error("Cannot set a default value on list field.", args.span());
-            }
+            let default_arg = args.default_arg("value")?;
 
-            if let dml::FieldType::Base(scalar_type, _) = sf.field_type {
-                let dv = args
-                    .default_arg("value")?
+            if sf.arity == dml::FieldArity::List {
+                sf.default_value = Some(self.list_default_value(&default_arg, &sf.field_type)?);
+            } else if let dml::FieldType::Base(scalar_type, _) = sf.field_type {
+                let dv = default_arg
                     .as_default_value_for_scalar_type(scalar_type)
                     .map_err(|e| self.wrap_in_attribute_validation_error(&e))?;
 
                 sf.default_value = Some(dv);
             } else if let dml::FieldType::NativeType(scalar_type, _) = sf.field_type {
-                let dv = args
-                    .default_arg("value")?
+                let dv = default_arg
                     .as_default_value_for_scalar_type(scalar_type)
                     .map_err(|e| self.wrap_in_attribute_validation_error(&e))?;
 
                 sf.default_value = Some(dv);
             } else if let dml::FieldType::Enum(_) = sf.field_type {
-                let default_arg = args.default_arg("value")?;
-
                 match default_arg.as_constant_literal() {
                     Ok(value) => sf.default_value = Some(dml::DefaultValue::Single(PrismaValue::Enum(value))),
                     Err(err) => {
                         let generator = default_arg.as_value_generator()?;
-                        if generator == ValueGenerator::new_dbgenerated() {
+                        if generator.generator == ValueGeneratorFn::DbGenerated {
                             sf.default_value = Some(dml::DefaultValue::Expression(generator));
                         } else {
                             return Err(self.wrap_in_attribute_validation_error(&err));
@@ -65,6 +60,56 @@ impl AttributeValidator<dml::Field> for DefaultAttributeValidator {
     }
 }
 
+impl DefaultAttributeValidator {
+    /// List fields take a list literal of values of the element type, e.g. `@default([1, 2, 3])`, or
+    /// an expression evaluated by the database.
+    fn list_default_value(
+        &self,
+        default_arg: &ValueValidator,
+        field_type: &dml::FieldType,
+    ) -> Result<dml::DefaultValue, DatamodelError> {
+        if default_arg.is_function() {
+            let generator = default_arg.as_value_generator()?;
+
+            if generator.generator != ValueGeneratorFn::DbGenerated {
+                return Err(DatamodelError::new_attribute_validation_error(
+                    "Only `dbgenerated()` can be used as a default value on list fields.",
+                    self.attribute_name(),
+                    default_arg.span(),
+                ));
+            }
+
+            return Ok(dml::DefaultValue::Expression(generator));
+        }
+
+        if !default_arg.is_array() {
+            return Err(DatamodelError::new_attribute_validation_error(
+                "The default value of a list field must be a list.",
+                self.attribute_name(),
+                default_arg.span(),
+            ));
+        }
+
+        let values = default_arg
+            .as_array()
+            .iter()
+            .map(|value| match field_type {
+                dml::FieldType::Base(scalar_type, _) | dml::FieldType::NativeType(scalar_type, _) => {
+                    value.as_type(*scalar_type)
+                }
+                dml::FieldType::Enum(_) => value.as_constant_literal().map(PrismaValue::Enum),
+                _ => Err(DatamodelError::new_validation_error(
+                    "Default values are not supported on lists of this type.",
+                    value.span(),
+                )),
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| self.wrap_in_attribute_validation_error(&e))?;
+
+        Ok(dml::DefaultValue::Single(PrismaValue::List(values)))
+    }
+}
+
 pub fn lower_default_value(dv: dml::DefaultValue) -> ast::Expression {
     match dv {
         dml::DefaultValue::Single(v) => lower_prisma_value(&v),
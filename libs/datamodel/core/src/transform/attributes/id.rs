@@ -14,6 +14,7 @@ impl AttributeValidator<dml::Field> for IdAttributeValidator {
         if let dml::Field::ScalarField(sf) = obj {
             if sf.arity == dml::FieldArity::Required {
                 sf.is_id = true;
+                sf.id_constraint_name = self.parse_constraint_name(args)?;
                 Ok(())
             } else {
                 self.new_attribute_validation_error("Fields that are marked as id must be required.", args.span())
@@ -33,7 +34,8 @@ impl AttributeValidator<dml::Field> for IdAttributeValidator {
     fn serialize(&self, field: &dml::Field, _datamodel: &dml::Datamodel) -> Vec<ast::Attribute> {
         if let dml::Field::ScalarField(sf) = field {
             if sf.is_id {
-                return vec![ast::Attribute::new(self.attribute_name(), Vec::new())];
+                let args = self.serialize_constraint_name(sf.id_constraint_name.as_deref());
+                return vec![ast::Attribute::new(self.attribute_name(), args)];
             }
         }
 
@@ -56,6 +58,7 @@ impl AttributeValidator<dml::Model> for ModelLevelIdAttributeValidator {
             .map(|f| f.as_constant_literal().unwrap())
             .collect();
         obj.id_fields = fields;
+        obj.id_constraint_name = self.parse_constraint_name(args)?;
 
         let undefined_fields: Vec<String> = obj
             .id_fields
@@ -135,6 +138,7 @@ impl AttributeValidator<dml::Model> for ModelLevelIdAttributeValidator {
                     .map(|f| ast::Expression::ConstantValue(f.to_string(), ast::Span::empty()))
                     .collect(),
             ));
+            args.extend(self.serialize_constraint_name(model.id_constraint_name.as_deref()));
 
             return vec![ast::Attribute::new(self.attribute_name(), args)];
         }
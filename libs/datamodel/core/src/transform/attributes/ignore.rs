@@ -0,0 +1,51 @@
+use super::{super::helpers::*, AttributeValidator};
+use crate::diagnostics::DatamodelError;
+use crate::{ast, dml, Datamodel};
+
+/// Prismas builtin `@@ignore` attribute.
+pub struct IgnoreAttributeValidator {}
+
+impl AttributeValidator<dml::Model> for IgnoreAttributeValidator {
+    fn attribute_name(&self) -> &'static str {
+        &"ignore"
+    }
+
+    fn validate_and_apply(&self, _args: &mut Arguments, obj: &mut dml::Model) -> Result<(), DatamodelError> {
+        obj.is_ignored = true;
+        Ok(())
+    }
+
+    fn serialize(&self, obj: &dml::Model, _datamodel: &Datamodel) -> Vec<ast::Attribute> {
+        if obj.is_ignored {
+            vec![ast::Attribute::new(self.attribute_name(), Vec::new())]
+        } else {
+            vec![]
+        }
+    }
+}
+
+/// Prismas builtin `@ignore` attribute.
+pub struct IgnoreAttributeValidatorForField {}
+
+impl AttributeValidator<dml::Field> for IgnoreAttributeValidatorForField {
+    fn attribute_name(&self) -> &'static str {
+        &"ignore"
+    }
+
+    fn validate_and_apply(&self, _args: &mut Arguments, obj: &mut dml::Field) -> Result<(), DatamodelError> {
+        match obj {
+            dml::Field::ScalarField(sf) => sf.is_ignored = true,
+            dml::Field::RelationField(rf) => rf.is_ignored = true,
+        }
+
+        Ok(())
+    }
+
+    fn serialize(&self, field: &dml::Field, _datamodel: &Datamodel) -> Vec<ast::Attribute> {
+        if field.is_ignored() {
+            vec![ast::Attribute::new(self.attribute_name(), Vec::new())]
+        } else {
+            vec![]
+        }
+    }
+}
@@ -1,11 +1,17 @@
 mod attribute_list_validator;
 mod attribute_validator;
+mod check;
+mod computed;
 mod default;
 mod id;
+mod ignore;
 mod map;
 mod relation;
+mod schema;
 mod unique_and_index;
 mod updated_at;
+mod validation;
+mod version;
 
 use crate::dml;
 use attribute_list_validator::AttributeListValidator;
@@ -38,8 +44,15 @@ fn new_builtin_field_attributes() -> AttributeListValidator<dml::Field> {
     validator.add(Box::new(unique_and_index::FieldLevelUniqueAttributeValidator {}));
     validator.add(Box::new(default::DefaultAttributeValidator {}));
     validator.add(Box::new(updated_at::UpdatedAtAttributeValidator {}));
+    validator.add(Box::new(version::VersionAttributeValidator {}));
+    validator.add(Box::new(computed::ComputedAttributeValidator {}));
+    validator.add(Box::new(validation::LengthAttributeValidator {}));
+    validator.add(Box::new(validation::RangeAttributeValidator {}));
+    validator.add(Box::new(validation::RegexAttributeValidator {}));
+    validator.add(Box::new(validation::EmailAttributeValidator {}));
     validator.add(Box::new(map::MapAttributeValidatorForField {}));
     validator.add(Box::new(relation::RelationAttributeValidator {}));
+    validator.add(Box::new(ignore::IgnoreAttributeValidatorForField {}));
 
     validator
 }
@@ -51,7 +64,11 @@ fn new_builtin_model_attributes() -> AttributeListValidator<dml::Model> {
     validator.add(Box::new(id::ModelLevelIdAttributeValidator {}));
     validator.add(Box::new(unique_and_index::ModelLevelUniqueAttributeValidator {}));
     validator.add(Box::new(unique_and_index::ModelLevelIndexAttributeValidator {}));
+    validator.add(Box::new(unique_and_index::ModelLevelFulltextAttributeValidator {}));
+    validator.add(Box::new(check::CheckAttributeValidator {}));
     validator.add(Box::new(map::MapAttributeValidator {}));
+    validator.add(Box::new(schema::SchemaAttributeValidator {}));
+    validator.add(Box::new(ignore::IgnoreAttributeValidator {}));
 
     validator
 }
@@ -60,6 +77,7 @@ fn new_builtin_enum_attributes() -> AttributeListValidator<dml::Enum> {
     let mut validator = AttributeListValidator::<dml::Enum>::new();
 
     validator.add(Box::new(map::MapAttributeValidator {}));
+    validator.add(Box::new(schema::SchemaAttributeValidator {}));
 
     validator
 }
@@ -32,6 +32,17 @@ impl AttributeValidator<dml::Field> for RelationAttributeValidator {
                 rf.relation_info.fields = base_fields.as_array().to_literal_vec()?;
             }
 
+            if let Some(constraint_name) = self.parse_constraint_name(args)? {
+                if rf.relation_info.fields.is_empty() {
+                    return self.new_attribute_validation_error(
+                        "The `map` argument can only be used on the side of the relation that defines `fields`.",
+                        args.span(),
+                    );
+                }
+
+                rf.relation_info.constraint_name = Some(constraint_name);
+            }
+
             // TODO: bring `onDelete` back once `prisma migrate` is a thing
             //            if let Ok(on_delete) = args.arg("onDelete") {
             //                relation_info.on_delete = on_delete.parse_literal::<dml::OnDeleteStrategy>()?;
@@ -100,6 +111,8 @@ impl AttributeValidator<dml::Field> for RelationAttributeValidator {
                 }
             }
 
+            args.extend(self.serialize_constraint_name(relation_info.constraint_name.as_deref()));
+
             if relation_info.on_delete != dml::OnDeleteStrategy::None {
                 args.push(ast::Argument::new_constant(
                     "onDelete",
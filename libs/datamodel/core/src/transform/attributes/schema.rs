@@ -0,0 +1,58 @@
+use super::{super::helpers::*, AttributeValidator};
+use crate::ast::Span;
+use crate::diagnostics::DatamodelError;
+use crate::{ast, dml, Datamodel};
+
+/// Prismas builtin `@@schema` attribute.
+pub struct SchemaAttributeValidator {}
+
+const ATTRIBUTE_NAME: &str = "schema";
+
+impl AttributeValidator<dml::Model> for SchemaAttributeValidator {
+    fn attribute_name(&self) -> &str {
+        ATTRIBUTE_NAME
+    }
+
+    fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Model) -> Result<(), DatamodelError> {
+        obj.schema = Some(internal_validate(args)?);
+        Ok(())
+    }
+
+    fn serialize(&self, obj: &dml::Model, _datamodel: &Datamodel) -> Vec<ast::Attribute> {
+        internal_serialize(obj.schema.as_deref())
+    }
+}
+
+impl AttributeValidator<dml::Enum> for SchemaAttributeValidator {
+    fn attribute_name(&self) -> &str {
+        ATTRIBUTE_NAME
+    }
+
+    fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Enum) -> Result<(), DatamodelError> {
+        obj.schema = Some(internal_validate(args)?);
+        Ok(())
+    }
+
+    fn serialize(&self, obj: &dml::Enum, _datamodel: &Datamodel) -> Vec<ast::Attribute> {
+        internal_serialize(obj.schema.as_deref())
+    }
+}
+
+fn internal_validate(args: &mut Arguments) -> Result<String, DatamodelError> {
+    args.default_arg("name")?
+        .as_str()
+        .map_err(|err| DatamodelError::new_attribute_validation_error(&format!("{}", err), ATTRIBUTE_NAME, err.span()))
+}
+
+fn internal_serialize(schema: Option<&str>) -> Vec<ast::Attribute> {
+    match schema {
+        Some(schema) => vec![ast::Attribute::new(
+            ATTRIBUTE_NAME,
+            vec![ast::Argument::new_unnamed(ast::Expression::StringValue(
+                String::from(schema),
+                Span::empty(),
+            ))],
+        )],
+        None => vec![],
+    }
+}
@@ -1,6 +1,6 @@
 use super::{super::helpers::*, AttributeValidator};
 use crate::diagnostics::DatamodelError;
-use crate::{ast, dml, IndexDefinition, IndexType};
+use crate::{ast, dml, IndexAlgorithm, IndexDefinition, IndexFieldOptions, IndexType, SortOrder};
 use std::cmp::Ordering;
 use std::collections::HashMap;
 
@@ -44,6 +44,7 @@ impl AttributeValidator<dml::Field> for FieldLevelUniqueAttributeValidator {
                 );
             } else {
                 sf.is_unique = true;
+                sf.unique_constraint_name = self.parse_constraint_name(args)?;
             }
         }
         Ok(())
@@ -52,7 +53,8 @@ impl AttributeValidator<dml::Field> for FieldLevelUniqueAttributeValidator {
     fn serialize(&self, field: &dml::Field, _datamodel: &dml::Datamodel) -> Vec<ast::Attribute> {
         if let dml::Field::ScalarField(sf) = field {
             if sf.is_unique {
-                return vec![ast::Attribute::new(self.attribute_name(), vec![])];
+                let args = self.serialize_constraint_name(sf.unique_constraint_name.as_deref());
+                return vec![ast::Attribute::new(self.attribute_name(), args)];
             }
         }
         vec![]
@@ -73,7 +75,8 @@ impl AttributeValidator<dml::Model> for ModelLevelUniqueAttributeValidator {
     }
 
     fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Model) -> Result<(), DatamodelError> {
-        let index_def = self.validate_index(args, obj, IndexType::Unique)?;
+        let mut index_def = self.validate_index(args, obj, IndexType::Unique)?;
+        index_def.constraint_name = self.parse_constraint_name(args)?;
         obj.indices.push(index_def);
 
         Ok(())
@@ -98,7 +101,28 @@ impl AttributeValidator<dml::Model> for ModelLevelIndexAttributeValidator {
     }
 
     fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Model) -> Result<(), DatamodelError> {
-        let index_def = self.validate_index(args, obj, IndexType::Normal)?;
+        let mut index_def = self.validate_index(args, obj, IndexType::Normal)?;
+
+        if let Some(algorithm_arg) = args.optional_arg("type") {
+            index_def.algorithm = Some(match algorithm_arg.as_constant_literal()?.as_str() {
+                "BTree" => IndexAlgorithm::BTree,
+                "Hash" => IndexAlgorithm::Hash,
+                "Gin" => IndexAlgorithm::Gin,
+                "Gist" => IndexAlgorithm::Gist,
+                "Brin" => IndexAlgorithm::Brin,
+                other => {
+                    return Err(DatamodelError::new_attribute_validation_error(
+                        &format!(
+                            "Unknown index type `{}`. Valid types are BTree, Hash, Gin, Gist and Brin.",
+                            other
+                        ),
+                        self.attribute_name(),
+                        algorithm_arg.span(),
+                    ))
+                }
+            });
+        }
+
         obj.indices.push(index_def);
 
         Ok(())
@@ -109,7 +133,56 @@ impl AttributeValidator<dml::Model> for ModelLevelIndexAttributeValidator {
     }
 }
 
-/// common logic for `@@unique` and `@@index`
+/// Prismas builtin `@@fulltext` attribute.
+pub struct ModelLevelFulltextAttributeValidator {}
+
+impl IndexAttributeBase<dml::Model> for ModelLevelFulltextAttributeValidator {}
+impl AttributeValidator<dml::Model> for ModelLevelFulltextAttributeValidator {
+    fn attribute_name(&self) -> &str {
+        "fulltext"
+    }
+
+    fn is_duplicate_definition_allowed(&self) -> bool {
+        true
+    }
+
+    fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Model) -> Result<(), DatamodelError> {
+        let index_def = self.validate_index(args, obj, IndexType::Fulltext)?;
+
+        let non_string_fields: Vec<&str> = index_def
+            .fields
+            .iter()
+            .filter(|field| {
+                obj.find_scalar_field(field)
+                    .and_then(|sf| sf.field_type.scalar_type())
+                    .map(|tpe| tpe != dml::ScalarType::String)
+                    .unwrap_or(true)
+            })
+            .map(|field| field.as_str())
+            .collect();
+
+        if !non_string_fields.is_empty() {
+            return Err(DatamodelError::new_model_validation_error(
+                &format!(
+                    "The fulltext index definition refers to the fields {}. Full-text indexes can only be defined on String fields.",
+                    non_string_fields.join(", ")
+                ),
+                &obj.name,
+                args.span(),
+            ));
+        }
+
+        obj.indices.push(index_def);
+
+        Ok(())
+    }
+
+    fn serialize(&self, model: &dml::Model, _datamodel: &dml::Datamodel) -> Vec<ast::Attribute> {
+        self.serialize_index_definitions(&model, IndexType::Fulltext)
+    }
+}
+
+/// common logic for `@@unique`, `@@index` and `@@fulltext`
 trait IndexAttributeBase<T>: AttributeValidator<T> {
     fn validate_index(
         &self,
@@ -121,6 +194,10 @@ trait IndexAttributeBase<T>: AttributeValidator<T> {
             name: None,
             fields: vec![],
             tpe: index_type,
+            field_options: vec![],
+            algorithm: None,
+            predicate: None,
+            constraint_name: None,
         };
         let name = match args.optional_arg("name") {
             Some(name_arg) => Some(name_arg.as_str()?),
@@ -128,20 +205,49 @@ trait IndexAttributeBase<T>: AttributeValidator<T> {
         };
         index_def.name = name;
 
-        let fields = args
-            .default_arg("fields")?
-            .as_array()
-            .iter()
-            .map(|f| f.as_constant_literal().unwrap())
-            .collect();
-        index_def.fields = fields;
+        let mut field_options = Vec::new();
+
+        for field in args.default_arg("fields")?.as_array() {
+            let (field_name, options) = self.parse_index_field(&field)?;
+
+            if index_type == IndexType::Fulltext && !options.is_default() {
+                return Err(DatamodelError::new_attribute_validation_error(
+                    "Fields of a fulltext index cannot have a sort order or prefix length.",
+                    self.attribute_name(),
+                    field.span(),
+                ));
+            }
+
+            index_def.fields.push(field_name);
+            field_options.push(options);
+        }
+
+        if field_options.iter().any(|options| !options.is_default()) {
+            index_def.field_options = field_options;
+        }
+
+        if index_type != IndexType::Fulltext {
+            if let Some(predicate_arg) = args.optional_arg("where") {
+                let predicate = predicate_arg.as_str()?;
+
+                if predicate.trim().is_empty() {
+                    return Err(DatamodelError::new_attribute_validation_error(
+                        "The predicate of a partial index must not be empty.",
+                        self.attribute_name(),
+                        predicate_arg.span(),
+                    ));
+                }
+
+                index_def.predicate = Some(predicate);
+            }
+        }
 
         let duplicated_fields = find_duplicates(&index_def.fields);
         if !duplicated_fields.is_empty() {
             return Err(DatamodelError::new_model_validation_error(
                 &format!(
                     "The {}index definition refers to the fields {} multiple times.",
-                    if index_type == IndexType::Unique { "unique " } else { "" },
+                    index_prefix(index_type),
                     duplicated_fields.join(", ")
                 ),
                 &obj.name,
@@ -172,7 +278,7 @@ trait IndexAttributeBase<T>: AttributeValidator<T> {
             return Err(DatamodelError::new_model_validation_error(
                 &format!(
                     "The {}index definition refers to the unknown fields {}.",
-                    if index_type == IndexType::Unique { "unique " } else { "" },
+                    index_prefix(index_type),
                     undefined_fields.join(", ")
                 ),
                 &obj.name,
@@ -210,7 +316,7 @@ trait IndexAttributeBase<T>: AttributeValidator<T> {
             return Err(DatamodelError::new_model_validation_error(
                 &format!(
                     "The {prefix}index definition refers to the relation fields {the_fields}. Index definitions must reference only scalar fields.{suggestion}",
-                    prefix = if index_type == IndexType::Unique { "unique " } else { "" },
+                    prefix = index_prefix(index_type),
                     the_fields = referenced_relation_fields.join(", "),
                     suggestion = suggestion
                 ),
@@ -222,6 +328,49 @@ trait IndexAttributeBase<T>: AttributeValidator<T> {
         Ok(index_def)
     }
 
+    /// Parses an entry of the fields list: either a plain field name, or a field name with
+    /// options, e.g. `title(Desc)`, `title(10)` or `title(Desc, 10)`.
+    fn parse_index_field(&self, field: &ValueValidator) -> Result<(String, IndexFieldOptions), DatamodelError> {
+        if !field.is_function() {
+            return Ok((field.as_constant_literal()?, IndexFieldOptions::default()));
+        }
+
+        let (name, args) = field.as_function()?;
+        let mut options = IndexFieldOptions::default();
+
+        for arg in args {
+            if let Ok(length) = arg.as_int() {
+                if length <= 0 {
+                    return Err(DatamodelError::new_attribute_validation_error(
+                        "The prefix length of an index field must be a positive number.",
+                        self.attribute_name(),
+                        arg.span(),
+                    ));
+                }
+
+                options.length = Some(length as u32);
+                continue;
+            }
+
+            options.sort_order = match arg.as_constant_literal()?.as_str() {
+                "Asc" => SortOrder::Asc,
+                "Desc" => SortOrder::Desc,
+                other => {
+                    return Err(DatamodelError::new_attribute_validation_error(
+                        &format!(
+                            "Unknown option `{}` on index field `{}`. Expected `Asc`, `Desc` or a prefix length.",
+                            other, name
+                        ),
+                        self.attribute_name(),
+                        arg.span(),
+                    ))
+                }
+            };
+        }
+
+        Ok((name, options))
+    }
+
     fn serialize_index_definitions(&self, model: &dml::Model, index_type: IndexType) -> Vec<ast::Attribute> {
         let attributes: Vec<ast::Attribute> = model
             .indices
@@ -235,12 +384,20 @@ trait IndexAttributeBase<T>: AttributeValidator<T> {
                     index_def
                         .fields
                         .iter()
-                        .map(|f| ast::Expression::ConstantValue(f.to_string(), ast::Span::empty()))
+                        .enumerate()
+                        .map(|(idx, f)| serialize_index_field(f, index_def.field_options(idx)))
                         .collect(),
                 ));
                 if let Some(name) = &index_def.name {
                     args.push(ast::Argument::new_string("name", &name));
                 }
+                if let Some(algorithm) = index_def.algorithm {
+                    args.push(ast::Argument::new_constant("type", algorithm.as_str()));
+                }
+                if let Some(predicate) = &index_def.predicate {
+                    args.push(ast::Argument::new_string("where", &predicate));
+                }
+                args.extend(self.serialize_constraint_name(index_def.constraint_name.as_deref()));
 
                 ast::Attribute::new(self.attribute_name(), args)
             })
@@ -250,11 +407,37 @@ trait IndexAttributeBase<T>: AttributeValidator<T> {
     }
 }
 
+fn serialize_index_field(field: &str, options: IndexFieldOptions) -> ast::Expression {
+    if options.is_default() {
+        return ast::Expression::ConstantValue(field.to_string(), ast::Span::empty());
+    }
+
+    let mut args = Vec::new();
+
+    if options.sort_order == SortOrder::Desc {
+        args.push(ast::Expression::ConstantValue("Desc".to_owned(), ast::Span::empty()));
+    }
+
+    if let Some(length) = options.length {
+        args.push(ast::Expression::NumericValue(length.to_string(), ast::Span::empty()));
+    }
+
+    ast::Expression::Function(field.to_string(), args, ast::Span::empty())
+}
+
 fn attribute_name(index_type: dml::IndexType) -> &'static str {
-    if index_type == dml::IndexType::Unique {
-        "unique"
-    } else {
-        "index"
+    match index_type {
+        dml::IndexType::Unique => "unique",
+        dml::IndexType::Normal => "index",
+        dml::IndexType::Fulltext => "fulltext",
+    }
+}
+
+fn index_prefix(index_type: dml::IndexType) -> &'static str {
+    match index_type {
+        dml::IndexType::Unique => "unique ",
+        dml::IndexType::Normal => "",
+        dml::IndexType::Fulltext => "fulltext ",
     }
 }
 
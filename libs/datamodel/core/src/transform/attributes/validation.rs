@@ -0,0 +1,246 @@
+use super::{super::helpers::*, AttributeValidator};
+use crate::diagnostics::DatamodelError;
+use crate::{ast, dml, FieldValidation, ScalarType, ValidationPattern};
+use bigdecimal::ToPrimitive;
+
+/// Prismas builtin `@length` attribute.
+pub struct LengthAttributeValidator {}
+
+impl AttributeValidator<dml::Field> for LengthAttributeValidator {
+    fn attribute_name(&self) -> &'static str {
+        &"length"
+    }
+
+    fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Field) -> Result<(), DatamodelError> {
+        let sf = validated_field(self.attribute_name(), args, obj, &[ScalarType::String], "String")?;
+
+        let min = length_bound(self.attribute_name(), args, "min")?;
+        let max = length_bound(self.attribute_name(), args, "max")?;
+        check_bounds(
+            self.attribute_name(),
+            args,
+            min.map(|m| m as f64),
+            max.map(|m| m as f64),
+        )?;
+
+        sf.validations.push(FieldValidation::Length { min, max });
+
+        Ok(())
+    }
+
+    fn serialize(&self, field: &dml::Field, _datamodel: &dml::Datamodel) -> Vec<ast::Attribute> {
+        serialize_validations(self.attribute_name(), field, |validation| match validation {
+            FieldValidation::Length { min, max } => {
+                Some(bound_arguments(min.map(|m| m.to_string()), max.map(|m| m.to_string())))
+            }
+            _ => None,
+        })
+    }
+}
+
+/// Prismas builtin `@range` attribute.
+pub struct RangeAttributeValidator {}
+
+impl AttributeValidator<dml::Field> for RangeAttributeValidator {
+    fn attribute_name(&self) -> &'static str {
+        &"range"
+    }
+
+    fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Field) -> Result<(), DatamodelError> {
+        let numeric_types = [
+            ScalarType::Int,
+            ScalarType::BigInt,
+            ScalarType::Float,
+            ScalarType::Decimal,
+        ];
+
+        let sf = validated_field(self.attribute_name(), args, obj, &numeric_types, "numeric")?;
+
+        let min = range_bound(args, "min")?;
+        let max = range_bound(args, "max")?;
+        check_bounds(self.attribute_name(), args, min, max)?;
+
+        sf.validations.push(FieldValidation::Range { min, max });
+
+        Ok(())
+    }
+
+    fn serialize(&self, field: &dml::Field, _datamodel: &dml::Datamodel) -> Vec<ast::Attribute> {
+        serialize_validations(self.attribute_name(), field, |validation| match validation {
+            FieldValidation::Range { min, max } => {
+                Some(bound_arguments(min.map(|m| m.to_string()), max.map(|m| m.to_string())))
+            }
+            _ => None,
+        })
+    }
+}
+
+/// Prismas builtin `@regex` attribute.
+pub struct RegexAttributeValidator {}
+
+impl AttributeValidator<dml::Field> for RegexAttributeValidator {
+    fn attribute_name(&self) -> &'static str {
+        &"regex"
+    }
+
+    fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Field) -> Result<(), DatamodelError> {
+        let sf = validated_field(self.attribute_name(), args, obj, &[ScalarType::String], "String")?;
+
+        let arg = args.default_arg("pattern")?;
+        let pattern = arg.as_str()?;
+
+        let pattern = match ValidationPattern::new(&pattern) {
+            Ok(pattern) => pattern,
+            Err(_) => {
+                return self.new_attribute_validation_error(
+                    &format!("The pattern `{}` is not a valid regular expression.", pattern),
+                    arg.span(),
+                )
+            }
+        };
+
+        sf.validations.push(FieldValidation::Regex { pattern });
+
+        Ok(())
+    }
+
+    fn serialize(&self, field: &dml::Field, _datamodel: &dml::Datamodel) -> Vec<ast::Attribute> {
+        serialize_validations(self.attribute_name(), field, |validation| match validation {
+            FieldValidation::Regex { pattern } => Some(vec![ast::Argument::new_string("", pattern.as_str())]),
+            _ => None,
+        })
+    }
+}
+
+/// Prismas builtin `@email` attribute.
+pub struct EmailAttributeValidator {}
+
+impl AttributeValidator<dml::Field> for EmailAttributeValidator {
+    fn attribute_name(&self) -> &'static str {
+        &"email"
+    }
+
+    fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Field) -> Result<(), DatamodelError> {
+        let sf = validated_field(self.attribute_name(), args, obj, &[ScalarType::String], "String")?;
+        sf.validations.push(FieldValidation::Email);
+
+        Ok(())
+    }
+
+    fn serialize(&self, field: &dml::Field, _datamodel: &dml::Datamodel) -> Vec<ast::Attribute> {
+        serialize_validations(self.attribute_name(), field, |validation| match validation {
+            FieldValidation::Email => Some(Vec::new()),
+            _ => None,
+        })
+    }
+}
+
+/// Returns the field if it is a non-list scalar field of one of the given types.
+fn validated_field<'a>(
+    attribute_name: &str,
+    args: &Arguments,
+    obj: &'a mut dml::Field,
+    types: &[ScalarType],
+    type_description: &str,
+) -> Result<&'a mut dml::ScalarField, DatamodelError> {
+    let sf = match obj {
+        dml::Field::ScalarField(sf) if sf.field_type.scalar_type().map_or(false, |st| types.contains(&st)) => sf,
+        _ => {
+            return Err(DatamodelError::new_attribute_validation_error(
+                &format!(
+                    "Fields that are marked with @{} must be of a {} type.",
+                    attribute_name, type_description
+                ),
+                attribute_name,
+                args.span(),
+            ))
+        }
+    };
+
+    if sf.arity == dml::FieldArity::List {
+        return Err(DatamodelError::new_attribute_validation_error(
+            &format!("Fields that are marked with @{} can not be lists.", attribute_name),
+            attribute_name,
+            args.span(),
+        ));
+    }
+
+    Ok(sf)
+}
+
+fn length_bound(attribute_name: &str, args: &mut Arguments, name: &str) -> Result<Option<usize>, DatamodelError> {
+    match args.optional_arg(name) {
+        Some(arg) => match arg.as_int()? {
+            bound if bound >= 0 => Ok(Some(bound as usize)),
+            _ => Err(DatamodelError::new_attribute_validation_error(
+                &format!("The `{}` argument must not be negative.", name),
+                attribute_name,
+                arg.span(),
+            )),
+        },
+        None => Ok(None),
+    }
+}
+
+fn range_bound(args: &mut Arguments, name: &str) -> Result<Option<f64>, DatamodelError> {
+    match args.optional_arg(name) {
+        Some(arg) => Ok(arg.as_float()?.to_f64()),
+        None => Ok(None),
+    }
+}
+
+fn check_bounds(
+    attribute_name: &str,
+    args: &Arguments,
+    min: Option<f64>,
+    max: Option<f64>,
+) -> Result<(), DatamodelError> {
+    match (min, max) {
+        (None, None) => Err(DatamodelError::new_attribute_validation_error(
+            "At least one of the `min` and `max` arguments must be given.",
+            attribute_name,
+            args.span(),
+        )),
+        (Some(min), Some(max)) if min > max => Err(DatamodelError::new_attribute_validation_error(
+            "The `min` argument must not be greater than the `max` argument.",
+            attribute_name,
+            args.span(),
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn bound_arguments(min: Option<String>, max: Option<String>) -> Vec<ast::Argument> {
+    let mut args = Vec::new();
+
+    if let Some(min) = min {
+        args.push(ast::Argument::new(
+            "min",
+            ast::Expression::NumericValue(min, ast::Span::empty()),
+        ));
+    }
+
+    if let Some(max) = max {
+        args.push(ast::Argument::new(
+            "max",
+            ast::Expression::NumericValue(max, ast::Span::empty()),
+        ));
+    }
+
+    args
+}
+
+fn serialize_validations<F>(attribute_name: &str, field: &dml::Field, to_arguments: F) -> Vec<ast::Attribute>
+where
+    F: Fn(&FieldValidation) -> Option<Vec<ast::Argument>>,
+{
+    match field {
+        dml::Field::ScalarField(sf) => sf
+            .validations
+            .iter()
+            .filter_map(|validation| to_arguments(validation))
+            .map(|args| ast::Attribute::new(attribute_name, args))
+            .collect(),
+        dml::Field::RelationField(_) => Vec::new(),
+    }
+}
@@ -31,6 +31,52 @@ impl DatasourceSerializer {
             }
         }
 
+        if !source.read_replicas.is_empty() {
+            let replicas: Vec<ast::Expression> = source
+                .read_replicas
+                .iter()
+                .map(|replica| match replica.from_env_var {
+                    Some(ref env_var) => ast::Expression::Function(
+                        "env".to_owned(),
+                        vec![ast::Expression::StringValue(env_var.to_string(), ast::Span::empty())],
+                        ast::Span::empty(),
+                    ),
+                    None => ast::Expression::StringValue(replica.value.to_owned(), ast::Span::empty()),
+                })
+                .collect();
+
+            arguments.push(ast::Argument::new_array("readReplicas", replicas));
+        }
+
+        if !source.schemas.is_empty() {
+            let schemas: Vec<ast::Expression> = source
+                .schemas
+                .iter()
+                .map(|schema| ast::Expression::StringValue(schema.to_owned(), ast::Span::empty()))
+                .collect();
+
+            arguments.push(ast::Argument::new_array("schemas", schemas));
+        }
+
+        let pool = &source.pool;
+        let pool_settings = vec![
+            ("connectionLimit", pool.connection_limit.map(|limit| limit as u64)),
+            (
+                "minIdleConnections",
+                pool.min_idle_connections.map(|min_idle| min_idle as u64),
+            ),
+            ("poolTimeout", pool.pool_timeout),
+            ("maxConnectionLifetime", pool.max_connection_lifetime),
+            ("idleTimeout", pool.idle_timeout),
+        ];
+
+        for (name, value) in pool_settings {
+            if let Some(value) = value {
+                let value = ast::Expression::NumericValue(value.to_string(), ast::Span::empty());
+                arguments.push(ast::Argument::new(name, value));
+            }
+        }
+
         if !&source.preview_features.is_empty() {
             let features: Vec<ast::Expression> = source
                 .preview_features
@@ -33,6 +33,10 @@ impl<'a> LowerDmlToAst<'a> {
             tops.push(ast::Top::Enum(self.lower_enum(enm, datamodel)))
         }
 
+        for composite_type in datamodel.composite_types() {
+            tops.push(ast::Top::CompositeType(self.lower_composite_type(composite_type)))
+        }
+
         ast::SchemaAst { tops }
     }
 
@@ -50,6 +54,28 @@ impl<'a> LowerDmlToAst<'a> {
             documentation: model.documentation.clone().map(|text| ast::Comment { text }),
             span: ast::Span::empty(),
             commented_out: model.is_commented_out,
+            is_view: model.is_view,
+        }
+    }
+
+    fn lower_composite_type(&self, composite_type: &dml::CompositeType) -> ast::CompositeType {
+        ast::CompositeType {
+            name: ast::Identifier::new(&composite_type.name),
+            fields: composite_type
+                .fields()
+                .map(|f| ast::Field {
+                    name: ast::Identifier::new(&f.name),
+                    arity: self.lower_field_arity(&f.arity),
+                    attributes: vec![],
+                    field_type: self.lower_type(&f.field_type),
+                    documentation: f.documentation.clone().map(|text| ast::Comment { text }),
+                    span: ast::Span::empty(),
+                    is_commented_out: false,
+                })
+                .collect(),
+            attributes: vec![],
+            documentation: composite_type.documentation.clone().map(|text| ast::Comment { text }),
+            span: ast::Span::empty(),
         }
     }
 
@@ -122,6 +148,7 @@ impl<'a> LowerDmlToAst<'a> {
             dml::FieldType::Enum(tpe) => ast::Identifier::new(&tpe.to_string()),
             dml::FieldType::Unsupported(tpe) => ast::Identifier::new(&tpe.to_string()),
             dml::FieldType::Relation(rel) => ast::Identifier::new(&rel.to),
+            dml::FieldType::CompositeType(name) => ast::Identifier::new(name),
             dml::FieldType::NativeType(prisma_tpe, _native_tpe) => ast::Identifier::new(&prisma_tpe.to_string()),
         }
     }
@@ -58,7 +58,15 @@ impl ValueValidator {
             ScalarType::Boolean => self.as_bool().map(PrismaValue::Boolean),
             ScalarType::DateTime => self.as_date_time().map(PrismaValue::DateTime),
             ScalarType::String => self.as_str().map(PrismaValue::String),
-            ScalarType::Json => self.as_str().map(PrismaValue::String),
+            ScalarType::Json => self
+                .as_str()
+                .and_then(|s| match serde_json::from_str::<serde_json::Value>(&s) {
+                    Ok(_) => Ok(PrismaValue::String(s)),
+                    Err(_) => Err(DatamodelError::new_validation_error(
+                        &format!("Invalid JSON string '{}'.", s),
+                        self.span(),
+                    )),
+                }),
             ScalarType::Bytes => self.as_str().and_then(|s| {
                 prisma_value::decode_bytes(&s).map(PrismaValue::Bytes).map_err(|_| {
                     DatamodelError::new_validation_error(&format!("Invalid base64 string '{}'.", s), self.span())
@@ -166,6 +174,21 @@ impl ValueValidator {
         }
     }
 
+    /// Unwraps the wrapped value as a function call, returning its name and arguments.
+    pub fn as_function(&self) -> Result<(String, Vec<ValueValidator>), DatamodelError> {
+        match &self.value {
+            ast::Expression::Function(name, args, _) => {
+                Ok((name.clone(), args.iter().map(ValueValidator::new).collect()))
+            }
+            _ => Err(self.construct_type_mismatch_error("function")),
+        }
+    }
+
+    /// Checks if the wrapped value is a function call.
+    pub fn is_function(&self) -> bool {
+        matches!(self.value, ast::Expression::Function(_, _, _))
+    }
+
     /// Unwraps the wrapped value as a constant literal..
     pub fn as_array(&self) -> Vec<ValueValidator> {
         match &self.value {
@@ -191,8 +214,8 @@ impl ValueValidator {
 
     pub fn as_default_value_for_scalar_type(&self, scalar_type: ScalarType) -> Result<DefaultValue, DatamodelError> {
         match &self.value {
-            ast::Expression::Function(name, _, _) => {
-                let generator = self.get_value_generator(&name)?;
+            ast::Expression::Function(name, args, _) => {
+                let generator = self.get_value_generator(&name, &args)?;
                 generator
                     .check_compatibility_with_scalar_type(scalar_type)
                     .map_err(|err_msg| DatamodelError::new_functional_evaluation_error(&err_msg, self.span()))?;
@@ -207,15 +230,28 @@ impl ValueValidator {
 
     pub fn as_value_generator(&self) -> Result<ValueGenerator, DatamodelError> {
         match &self.value {
-            ast::Expression::Function(name, _, _) => self.get_value_generator(&name),
+            ast::Expression::Function(name, args, _) => self.get_value_generator(&name, &args),
             _ => Err(self.construct_type_mismatch_error("function")),
         }
     }
 
-    fn get_value_generator(&self, name: &str) -> Result<ValueGenerator, DatamodelError> {
-        ValueGenerator::new(name.to_string(), vec![])
+    fn get_value_generator(&self, name: &str, args: &[ast::Expression]) -> Result<ValueGenerator, DatamodelError> {
+        let args = args
+            .iter()
+            .map(|arg| ValueValidator::new(arg).as_function_argument())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        ValueGenerator::new(name.to_string(), args)
             .map_err(|err_msg| DatamodelError::new_functional_evaluation_error(&err_msg, self.span()))
     }
+
+    /// The arguments of value generators are strings or integers, e.g. `dbgenerated("now()")` or `nanoid(8)`.
+    fn as_function_argument(&self) -> Result<PrismaValue, DatamodelError> {
+        match &self.value {
+            ast::Expression::NumericValue(_, _) => self.as_int().map(PrismaValue::Int),
+            _ => self.as_str().map(PrismaValue::String),
+        }
+    }
 }
 
 pub trait ValueListValidator {
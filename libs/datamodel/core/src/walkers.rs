@@ -2,7 +2,8 @@
 //! The most prominent functionality is the pain free navigation of relations.
 use crate::{
     dml::{
-        Datamodel, DefaultValue, Enum, FieldArity, FieldType, IndexDefinition, Model, ScalarField, WithDatabaseName,
+        CheckConstraint, CompositeType, ComputedValue, Datamodel, DefaultValue, Enum, FieldArity, FieldType,
+        IndexDefinition, Model, ScalarField, WithDatabaseName,
     },
     NativeTypeInstance, RelationField,
 };
@@ -68,6 +69,19 @@ impl<'a> ModelWalker<'a> {
         self.model.final_database_name()
     }
 
+    pub fn documentation(&self) -> Option<&'a str> {
+        self.model.documentation.as_deref()
+    }
+
+    pub fn is_view(&self) -> bool {
+        self.model.is_view
+    }
+
+    /// The database schema set via `@@schema`, if any.
+    pub fn schema(&self) -> Option<&'a str> {
+        self.model.schema.as_deref()
+    }
+
     pub fn into_relation_fields(self) -> impl Iterator<Item = RelationFieldWalker<'a>> + 'a {
         self.model.relation_fields().map(move |field| RelationFieldWalker {
             datamodel: self.datamodel,
@@ -104,6 +118,15 @@ impl<'a> ModelWalker<'a> {
         self.model.indices.iter()
     }
 
+    /// The name of the index in the database, see `Model::index_database_name`.
+    pub fn index_database_name(&self, index: &IndexDefinition) -> String {
+        self.model.index_database_name(index)
+    }
+
+    pub fn check_constraints(&self) -> &'a [CheckConstraint] {
+        &self.model.check_constraints
+    }
+
     pub fn name(&self) -> &'a str {
         &self.model.name
     }
@@ -127,6 +150,16 @@ impl<'a> ModelWalker<'a> {
             })
     }
 
+    /// The database name of the primary key constraint, from the `map` argument of `@id` or `@@id`.
+    pub fn id_constraint_name(&self) -> Option<&'a str> {
+        self.model.id_constraint_name.as_deref().or_else(|| {
+            self.model
+                .scalar_fields()
+                .find(|field| field.is_id)
+                .and_then(|field| field.id_constraint_name.as_deref())
+        })
+    }
+
     pub fn unique_indexes<'b>(&'b self) -> impl Iterator<Item = IndexWalker<'a>> + 'b {
         self.model
             .indices
@@ -152,6 +185,10 @@ impl<'a> ScalarFieldWalker<'a> {
         self.field.arity
     }
 
+    pub fn computed(&self) -> Option<&'a ComputedValue> {
+        self.field.computed.as_ref()
+    }
+
     pub fn db_name(&self) -> &'a str {
         self.field.final_database_name()
     }
@@ -160,6 +197,10 @@ impl<'a> ScalarFieldWalker<'a> {
         self.field.default_value.as_ref()
     }
 
+    pub fn documentation(&self) -> Option<&'a str> {
+        self.field.documentation.as_deref()
+    }
+
     pub fn field_type(&self) -> TypeWalker<'a> {
         match &self.field.field_type {
             FieldType::Enum(name) => TypeWalker::Enum(EnumWalker {
@@ -168,6 +209,9 @@ impl<'a> ScalarFieldWalker<'a> {
             }),
             FieldType::Base(scalar_type, _) => TypeWalker::Base(*scalar_type),
             FieldType::NativeType(scalar_type, native_type) => TypeWalker::NativeType(*scalar_type, native_type),
+            FieldType::CompositeType(name) => {
+                TypeWalker::CompositeType(self.datamodel.find_composite_type(name).unwrap())
+            }
             _ => TypeWalker::Other,
         }
     }
@@ -184,6 +228,10 @@ impl<'a> ScalarFieldWalker<'a> {
         self.field.is_unique
     }
 
+    pub fn unique_constraint_name(&self) -> Option<&'a str> {
+        self.field.unique_constraint_name.as_deref()
+    }
+
     pub fn model(&self) -> ModelWalker<'a> {
         ModelWalker {
             model: self.model,
@@ -201,6 +249,8 @@ pub enum TypeWalker<'a> {
     Enum(EnumWalker<'a>),
     Base(ScalarType),
     NativeType(ScalarType, &'a NativeTypeInstance),
+    /// A composite type, stored as a JSON column on SQL databases.
+    CompositeType(&'a CompositeType),
     Other,
 }
 
@@ -233,6 +283,10 @@ impl<'a> RelationFieldWalker<'a> {
         self.field.arity
     }
 
+    pub fn constraint_name(&self) -> Option<&'a str> {
+        self.field.relation_info.constraint_name.as_deref()
+    }
+
     pub fn is_one_to_one(&self) -> bool {
         self.field.is_singular() && self.opposite_side().field.is_singular()
     }
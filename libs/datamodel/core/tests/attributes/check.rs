@@ -0,0 +1,58 @@
+use crate::common::*;
+
+#[test]
+fn check_attribute_on_models() {
+    let dml = r#"
+    model Product {
+        id       Int @id
+        price    Int
+        discount Int
+
+        @@check("price > 0")
+        @@check("discount < price", name: "discount_below_price")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let check_constraints = &schema.assert_has_model("Product").check_constraints;
+
+    assert_eq!(check_constraints.len(), 2);
+    assert_eq!(check_constraints[0].name, None);
+    assert_eq!(check_constraints[0].expression, "price > 0");
+    assert_eq!(check_constraints[1].name.as_deref(), Some("discount_below_price"));
+    assert_eq!(check_constraints[1].expression, "discount < price");
+}
+
+#[test]
+fn check_attribute_is_rendered() {
+    let dml = r#"
+    model Product {
+        id     Int    @id
+        status String
+
+        @@check("status IN ('draft', 'published')", name: "status_check")
+    }
+    "#;
+
+    let rendered = datamodel::render_datamodel_to_string(&parse(dml));
+
+    assert!(
+        rendered.contains(r#"@@check("status IN ('draft', 'published')", name: "status_check")"#),
+        "{}",
+        rendered
+    );
+}
+
+#[test]
+fn check_attribute_must_not_be_empty() {
+    let dml = r#"
+    model Product {
+        id Int @id
+
+        @@check("  ")
+    }
+    "#;
+
+    parse_error(dml)
+        .assert_is_message("Error parsing attribute \"@check\": The check constraint expression must not be empty.");
+}
@@ -0,0 +1,176 @@
+use crate::common::*;
+use datamodel::dml;
+
+#[test]
+fn computed_fields_must_work() {
+    let dml = r#"
+    datasource db {
+        provider = "postgres"
+        url = "postgresql://localhost:5432"
+    }
+
+    model OrderItem {
+        id       Int @id
+        price    Int
+        quantity Int
+        total    Int @computed("price * quantity")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let order_item = schema.assert_has_model("OrderItem");
+
+    assert_eq!(
+        order_item.assert_has_scalar_field("total").computed,
+        Some(dml::ComputedValue {
+            expression: "price * quantity".to_owned(),
+            stored: true,
+        })
+    );
+    assert_eq!(order_item.assert_has_scalar_field("price").computed, None);
+}
+
+#[test]
+fn virtual_computed_fields_must_work_on_mysql() {
+    let dml = r#"
+    datasource db {
+        provider = "mysql"
+        url = "mysql://localhost:3306"
+    }
+
+    model User {
+        id        Int    @id
+        firstName String
+        lastName  String
+        fullName  String @computed("CONCAT(firstName, ' ', lastName)", stored: false)
+    }
+    "#;
+
+    let schema = parse(dml);
+    let full_name = schema.assert_has_model("User").assert_has_scalar_field("fullName");
+
+    assert_eq!(
+        full_name.computed,
+        Some(dml::ComputedValue {
+            expression: "CONCAT(firstName, ' ', lastName)".to_owned(),
+            stored: false,
+        })
+    );
+}
+
+#[test]
+fn computed_attributes_must_be_rendered() {
+    let dml = r#"
+    datasource db {
+        provider = "mysql"
+        url = "mysql://localhost:3306"
+    }
+
+    model OrderItem {
+        id       Int    @id
+        price    Int
+        quantity Int
+        total    Int    @computed("price * quantity")
+        label    String @computed("CONCAT('#', id)", stored: false)
+    }
+    "#;
+
+    let rendered = datamodel::render_datamodel_to_string(&parse(dml));
+
+    assert!(rendered.contains(r#"@computed("price * quantity")"#), "{}", rendered);
+    assert!(
+        rendered.contains(r#"@computed("CONCAT('#', id)", stored: false)"#),
+        "{}",
+        rendered
+    );
+}
+
+#[test]
+fn computed_fields_must_not_have_a_default() {
+    let dml = r#"
+    model OrderItem {
+        id       Int @id
+        price    Int
+        quantity Int
+        total    Int @default(0) @computed("price * quantity")
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@computed\": Fields that are marked with @computed can not use @default.",
+    );
+}
+
+#[test]
+fn computed_fields_must_not_be_ids() {
+    let dml = r#"
+    model OrderItem {
+        id Int @id @computed("1")
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@computed\": Fields that are marked with @computed can not use @id.",
+    );
+}
+
+#[test]
+fn computed_fields_must_not_be_lists() {
+    let dml = r#"
+    datasource db {
+        provider = "postgres"
+        url = "postgresql://localhost:5432"
+    }
+
+    model OrderItem {
+        id   Int   @id
+        tags Int[] @computed("ARRAY[id]")
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@computed\": Fields that are marked with @computed can not be lists.",
+    );
+}
+
+#[test]
+fn computed_fields_must_error_on_connectors_without_support() {
+    let dml = r#"
+    datasource db {
+        provider = "sqlite"
+        url = "file:dev.db"
+    }
+
+    model OrderItem {
+        id       Int @id
+        price    Int
+        quantity Int
+        total    Int @computed("price * quantity")
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@computed\": The current connector does not support computed fields.",
+    );
+}
+
+#[test]
+fn virtual_computed_fields_must_error_on_connectors_without_support() {
+    let dml = r#"
+    datasource db {
+        provider = "postgres"
+        url = "postgresql://localhost:5432"
+    }
+
+    model OrderItem {
+        id       Int @id
+        price    Int
+        quantity Int
+        total    Int @computed("price * quantity", stored: false)
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@computed\": The current connector does not support computed fields that are not stored.",
+    );
+}
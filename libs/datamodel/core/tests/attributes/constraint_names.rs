@@ -0,0 +1,203 @@
+use crate::common::*;
+
+#[test]
+fn map_on_id_and_unique_fields_must_work() {
+    let dml = r#"
+    datasource db {
+        provider = "postgres"
+        url = "postgresql://localhost:5432"
+    }
+
+    model User {
+        id    Int    @id(map: "users_pk")
+        email String @unique(map: "users_email_key")
+        name  String @unique
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user = schema.assert_has_model("User");
+
+    assert_eq!(
+        user.assert_has_scalar_field("id").id_constraint_name.as_deref(),
+        Some("users_pk")
+    );
+    assert_eq!(
+        user.assert_has_scalar_field("email").unique_constraint_name.as_deref(),
+        Some("users_email_key")
+    );
+    assert_eq!(user.assert_has_scalar_field("name").unique_constraint_name, None);
+}
+
+#[test]
+fn map_on_compound_id_and_unique_must_work() {
+    let dml = r#"
+    datasource db {
+        provider = "postgres"
+        url = "postgresql://localhost:5432"
+    }
+
+    model User {
+        firstName String
+        lastName  String
+        email     String
+        nickname  String
+
+        @@id([firstName, lastName], map: "users_pk")
+        @@unique([email, nickname], name: "email_nickname", map: "users_email_nickname_key")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user = schema.assert_has_model("User");
+
+    assert_eq!(user.id_constraint_name.as_deref(), Some("users_pk"));
+
+    let index = user
+        .indices
+        .iter()
+        .find(|index| index.name.as_deref() == Some("email_nickname"))
+        .unwrap();
+
+    assert_eq!(index.constraint_name.as_deref(), Some("users_email_nickname_key"));
+}
+
+#[test]
+fn map_on_relation_must_work() {
+    let dml = r#"
+    datasource db {
+        provider = "postgres"
+        url = "postgresql://localhost:5432"
+    }
+
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id       Int  @id
+        authorId Int
+        author   User @relation(fields: [authorId], references: [id], map: "posts_author_fk")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let author = schema.assert_has_model("Post").assert_has_relation_field("author");
+
+    assert_eq!(author.relation_info.constraint_name.as_deref(), Some("posts_author_fk"));
+}
+
+#[test]
+fn constraint_names_must_be_rendered() {
+    let dml = r#"
+    datasource db {
+        provider = "postgres"
+        url = "postgresql://localhost:5432"
+    }
+
+    model User {
+        id    Int    @id(map: "users_pk")
+        email String @unique(map: "users_email_key")
+        posts Post[]
+    }
+
+    model Post {
+        authorId Int
+        slug     String
+        author   User   @relation(fields: [authorId], references: [id], map: "posts_author_fk")
+
+        @@id([authorId, slug], map: "posts_pk")
+        @@unique([slug, authorId], name: "slug_author", map: "posts_slug_author_key")
+    }
+    "#;
+
+    let rendered = datamodel::render_datamodel_to_string(&parse(dml));
+
+    for expected in &[
+        r#"@id(map: "users_pk")"#,
+        r#"@unique(map: "users_email_key")"#,
+        r#"@relation(fields: [authorId], references: [id], map: "posts_author_fk")"#,
+        r#"@@id([authorId, slug], map: "posts_pk")"#,
+        r#"@@unique([slug, authorId], name: "slug_author", map: "posts_slug_author_key")"#,
+    ] {
+        assert!(rendered.contains(expected), "{}", rendered);
+    }
+}
+
+#[test]
+fn map_on_relation_without_fields_must_error() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[] @relation(map: "posts_author_fk")
+    }
+
+    model Post {
+        id       Int  @id
+        authorId Int
+        author   User @relation(fields: [authorId], references: [id])
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@relation\": The `map` argument can only be used on the side of the relation that defines `fields`.",
+    );
+}
+
+#[test]
+fn empty_constraint_names_must_error() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        email String @unique(map: "")
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@unique\": The constraint name given with `map` must not be empty.",
+    );
+}
+
+#[test]
+fn named_primary_keys_must_error_on_connectors_without_support() {
+    let dml = r#"
+    datasource db {
+        provider = "mysql"
+        url = "mysql://localhost:3306"
+    }
+
+    model User {
+        id Int @id(map: "users_pk")
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@id\": The current connector does not support named primary keys.",
+    );
+}
+
+#[test]
+fn named_foreign_keys_must_error_on_connectors_without_support() {
+    let dml = r#"
+    datasource db {
+        provider = "sqlite"
+        url = "file:dev.db"
+    }
+
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id       Int  @id
+        authorId Int
+        author   User @relation(fields: [authorId], references: [id], map: "posts_author_fk")
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@relation\": The current connector does not support named foreign keys.",
+    );
+}
@@ -24,20 +24,56 @@ fn must_error_if_default_value_for_relation_field() {
 }
 
 #[test]
-fn must_error_if_default_value_for_list() {
+fn must_error_if_default_value_for_list_is_not_a_list() {
     let dml = r#"
     model Model {
         id Int @id
-        rel String[] @default(["hello"])
+        rel String[] @default("hello")
     }
     "#;
 
     let errors = parse_error(dml);
 
     errors.assert_is(DatamodelError::new_attribute_validation_error(
-        "Cannot set a default value on list field.",
+        "The default value of a list field must be a list.",
         "default",
-        Span::new(60, 78),
+        Span::new(68, 75),
+    ));
+}
+
+#[test]
+fn must_error_if_default_value_for_list_has_elements_of_the_wrong_type() {
+    let dml = r#"
+    model Model {
+        id Int @id
+        rel Int[] @default([1, "two"])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_attribute_validation_error(
+        "Expected a numeric value, but received string value \"two\".",
+        "default",
+        Span::new(69, 74),
+    ));
+}
+
+#[test]
+fn must_error_if_a_function_other_than_dbgenerated_is_used_on_a_list() {
+    let dml = r#"
+    model Model {
+        id Int @id
+        rel Int[] @default(autoincrement())
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_attribute_validation_error(
+        "Only `dbgenerated()` can be used as a default value on list fields.",
+        "default",
+        Span::new(65, 80),
     ));
 }
 
@@ -131,6 +167,77 @@ fn must_error_if_autoincrement_function_is_used_for_fields_that_are_not_int() {
     ));
 }
 
+#[test]
+fn must_error_if_default_value_is_not_valid_json() {
+    let dml = r#"
+    model Model {
+        id  Int  @id
+        foo Json @default("{ \"a\" }")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_attribute_validation_error(
+        "Invalid JSON string '{ \"a\" }'.",
+        "default",
+        Span::new(66, 77),
+    ));
+}
+
+#[test]
+fn must_error_if_now_function_is_called_with_arguments() {
+    let dml = r#"
+    model Model {
+        id  Int      @id
+        foo DateTime @default(now("utc"))
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_attribute_validation_error(
+        "The function `now()` takes no arguments.",
+        "default",
+        Span::new(74, 84),
+    ));
+}
+
+#[test]
+fn must_error_if_nanoid_length_is_out_of_range() {
+    let dml = r#"
+    model Model {
+        id  Int    @id
+        foo String @default(nanoid(1))
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_attribute_validation_error(
+        "The function `nanoid()` takes no argument or a length between 2 and 255.",
+        "default",
+        Span::new(70, 79),
+    ));
+}
+
+#[test]
+fn must_error_if_sequence_has_no_name() {
+    let dml = r#"
+    model Model {
+        id Int @id @default(sequence())
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_attribute_validation_error(
+        "The function `sequence()` takes the name of the sequence as a single, non-empty string.",
+        "default",
+        Span::new(47, 57),
+    ));
+}
+
 #[test]
 fn must_error_if_default_value_for_enum_is_not_valid() {
     let dml = r#"
@@ -222,3 +329,48 @@ fn must_error_if_using_non_indexed_auto_increment_on_mysql() {
         Span::new(135, 173),
     ));
 }
+
+#[test]
+fn must_error_if_default_value_for_enum_list_is_not_valid() {
+    let dml = r#"
+    model Model {
+        id    Int @id
+        enums A[] @default([A, B])
+    }
+
+    enum A {
+        A
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_attribute_validation_error(
+        "The defined default value is not a valid value of the enum specified for the field.",
+        "default",
+        Span::new(49, 75),
+    ));
+}
+
+#[test]
+fn must_error_if_using_sequence_on_mysql() {
+    let dml = r#"
+    datasource db1 {
+        provider = "mysql"
+        url = "mysql://"
+    }
+
+    model Model {
+        id  Int @id
+        foo Int @default(sequence("foo_seq")) @unique
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_attribute_validation_error(
+        "The `sequence()` default value is not supported by the current connector.",
+        "default",
+        Span::new(127, 172),
+    ));
+}
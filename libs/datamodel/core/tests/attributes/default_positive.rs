@@ -117,3 +117,132 @@ fn db_generated_function_must_work_for_enum_fields() {
         .assert_enum_type("Role")
         .assert_default_value(DefaultValue::Expression(ValueGenerator::new_dbgenerated()));
 }
+
+#[test]
+fn should_set_default_on_list_fields() {
+    let dml = r#"
+    model Model {
+        id      Int     @id
+        ints    Int[]   @default([1, 2, 3])
+        strings String[] @default(["a", "b"])
+        empty   Int[]   @default([])
+        roles   Role[]  @default([ADMIN, MODERATOR])
+    }
+
+    enum Role {
+        ADMIN
+        MODERATOR
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let user_model = datamodel.assert_has_model("Model");
+    user_model
+        .assert_has_scalar_field("ints")
+        .assert_default_value(DefaultValue::Single(PrismaValue::List(vec![
+            PrismaValue::Int(1),
+            PrismaValue::Int(2),
+            PrismaValue::Int(3),
+        ])));
+    user_model
+        .assert_has_scalar_field("strings")
+        .assert_default_value(DefaultValue::Single(PrismaValue::List(vec![
+            PrismaValue::String(String::from("a")),
+            PrismaValue::String(String::from("b")),
+        ])));
+    user_model
+        .assert_has_scalar_field("empty")
+        .assert_default_value(DefaultValue::Single(PrismaValue::List(vec![])));
+    user_model
+        .assert_has_scalar_field("roles")
+        .assert_default_value(DefaultValue::Single(PrismaValue::List(vec![
+            PrismaValue::Enum(String::from("ADMIN")),
+            PrismaValue::Enum(String::from("MODERATOR")),
+        ])));
+}
+
+#[test]
+fn should_set_json_default() {
+    let dml = r#"
+    model Model {
+        id   Int  @id
+        json Json @default("{ \"a\": [1, 2] }")
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let user_model = datamodel.assert_has_model("Model");
+    user_model
+        .assert_has_scalar_field("json")
+        .assert_base_type(&ScalarType::Json)
+        .assert_default_value(DefaultValue::Single(PrismaValue::String(String::from(
+            "{ \"a\": [1, 2] }",
+        ))));
+}
+
+#[test]
+fn db_generated_function_must_take_an_expression() {
+    let dml = r#"
+    model Model {
+        id      Int      @id
+        created DateTime @default(dbgenerated("now()"))
+        tags    String[] @default(dbgenerated("ARRAY['a']"))
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let user_model = datamodel.assert_has_model("Model");
+    user_model
+        .assert_has_scalar_field("created")
+        .assert_default_value(DefaultValue::Expression(ValueGenerator::new_dbgenerated_expression(
+            String::from("now()"),
+        )));
+    user_model
+        .assert_has_scalar_field("tags")
+        .assert_default_value(DefaultValue::Expression(ValueGenerator::new_dbgenerated_expression(
+            String::from("ARRAY['a']"),
+        )));
+}
+
+#[test]
+fn nanoid_function_must_work_with_and_without_length() {
+    let dml = r#"
+    model Model {
+        id    String @id @default(nanoid())
+        short String @default(nanoid(8))
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let user_model = datamodel.assert_has_model("Model");
+    user_model
+        .assert_has_scalar_field("id")
+        .assert_default_value(DefaultValue::Expression(ValueGenerator::new_nanoid()));
+    user_model
+        .assert_has_scalar_field("short")
+        .assert_default_value(DefaultValue::Expression(
+            ValueGenerator::new("nanoid".to_owned(), vec![PrismaValue::Int(8)]).unwrap(),
+        ));
+}
+
+#[test]
+fn sequence_function_must_work_on_postgres() {
+    let dml = r#"
+    datasource db {
+        provider = "postgresql"
+        url = "postgresql://"
+    }
+
+    model Model {
+        id Int @id @default(sequence("model_ids"))
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let user_model = datamodel.assert_has_model("Model");
+    user_model
+        .assert_has_scalar_field("id")
+        .assert_default_value(DefaultValue::Expression(ValueGenerator::new_sequence(String::from(
+            "model_ids",
+        ))));
+}
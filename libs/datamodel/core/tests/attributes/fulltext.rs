@@ -0,0 +1,102 @@
+use datamodel::{ast::Span, diagnostics::*, render_datamodel_to_string, IndexDefinition, IndexType};
+
+use crate::common::*;
+
+#[test]
+fn basic_fulltext_index_must_work() {
+    let dml = r#"
+    datasource db {
+        provider = "postgres"
+        url = "postgresql://localhost:5432"
+    }
+
+    model Post {
+        id      Int    @id
+        title   String
+        content String
+
+        @@fulltext([title, content], name: "post_search")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let post_model = schema.assert_has_model("Post");
+    post_model.assert_has_index(IndexDefinition {
+        name: Some("post_search".to_string()),
+        fields: vec!["title".to_string(), "content".to_string()],
+        tpe: IndexType::Fulltext,
+        field_options: vec![],
+        algorithm: None,
+        predicate: None,
+        constraint_name: None,
+    });
+}
+
+#[test]
+fn fulltext_indexes_must_be_rendered() {
+    let dml = r#"datasource db {
+  provider = "mysql"
+  url      = "mysql://localhost:3306"
+}
+
+model Post {
+  id      Int    @id
+  title   String
+  content String
+
+  @@fulltext([title, content])
+}
+"#;
+
+    let rendered = render_datamodel_to_string(&parse(dml));
+    assert!(rendered.contains("@@fulltext([title, content])"), "{}", rendered);
+}
+
+#[test]
+fn fulltext_indexes_on_non_string_fields_must_error() {
+    let dml = r#"
+    datasource db {
+        provider = "postgres"
+        url = "postgresql://localhost:5432"
+    }
+
+    model Post {
+        id    Int    @id
+        title String
+        views Int
+
+        @@fulltext([title, views])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+    errors.assert_is(DatamodelError::new_model_validation_error(
+        "The fulltext index definition refers to the fields views. Full-text indexes can only be defined on String fields.",
+        "Post",
+        Span::new(194, 218),
+    ));
+}
+
+#[test]
+fn fulltext_indexes_must_error_on_connectors_without_support() {
+    let dml = r#"
+    datasource db {
+        provider = "sqlserver"
+        url = "sqlserver://"
+    }
+
+    model Post {
+        id    Int    @id
+        title String
+
+        @@fulltext([title])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+    errors.assert_is(DatamodelError::new_attribute_validation_error(
+        "Full-text indexes are not supported by the `sqlserver` connector. They are only supported on PostgreSQL, MySQL and SQLite.",
+        "fulltext",
+        Span::new(162, 179),
+    ));
+}
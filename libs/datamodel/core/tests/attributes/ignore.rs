@@ -0,0 +1,210 @@
+use crate::common::*;
+
+#[test]
+fn ignored_models_do_not_need_a_unique_criteria() {
+    let dml = r#"
+    model Log {
+        message String
+        level   Int
+
+        @@ignore
+    }
+    "#;
+
+    let schema = parse(dml);
+    let log = schema.assert_has_model("Log");
+
+    assert!(log.is_ignored);
+}
+
+#[test]
+fn ignore_on_fields_must_work() {
+    let dml = r#"
+    model User {
+        id     Int    @id
+        secret String @ignore
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user = schema.assert_has_model("User");
+
+    assert!(user.assert_has_scalar_field("secret").is_ignored);
+    assert!(!user.assert_has_scalar_field("id").is_ignored);
+}
+
+#[test]
+fn ignore_attributes_must_be_rendered() {
+    let dml = r#"
+    model User {
+        id     Int     @id
+        secret String? @ignore
+        logs   Log[]   @ignore
+    }
+
+    model Log {
+        message String
+        userId  Int
+        user    User   @relation(fields: [userId], references: [id])
+
+        @@ignore
+    }
+    "#;
+
+    let rendered = datamodel::render_datamodel_to_string(&parse(dml));
+
+    assert!(rendered.contains("secret String? @ignore"), "{}", rendered);
+    assert!(rendered.contains("logs   Log[]   @ignore"), "{}", rendered);
+    assert!(rendered.contains("@@ignore"), "{}", rendered);
+}
+
+#[test]
+fn relation_fields_pointing_to_ignored_models_must_be_ignored() {
+    let dml = r#"
+    model User {
+        id   Int   @id
+        logs Log[]
+    }
+
+    model Log {
+        message String
+        userId  Int
+        user    User   @relation(fields: [userId], references: [id])
+
+        @@ignore
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@ignore\": The relation field `logs` on Model `User` must specify the `@ignore` attribute, because the model Log it is pointing to is marked ignored.",
+    );
+}
+
+#[test]
+fn generated_back_relation_fields_of_ignored_models_must_be_ignored() {
+    let dml = r#"
+    model User {
+        id Int @id
+    }
+
+    model Log {
+        message String
+        userId  Int
+        user    User   @relation(fields: [userId], references: [id])
+
+        @@ignore
+    }
+    "#;
+
+    let schema = parse(dml);
+    let back_relation_field = schema.assert_has_model("User").assert_has_relation_field("Log");
+
+    assert!(back_relation_field.is_ignored);
+}
+
+#[test]
+fn id_fields_must_not_be_ignored() {
+    let dml = r#"
+    model User {
+        id   Int    @id @ignore
+        name String @unique
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@ignore\": The field `id` on Model `User` identifies its records and can not be ignored. Ignore the whole model with `@@ignore` instead.",
+    );
+}
+
+#[test]
+fn compound_id_fields_must_not_be_ignored() {
+    let dml = r#"
+    model User {
+        firstName String
+        lastName  String @ignore
+
+        @@id([firstName, lastName])
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@ignore\": The field `lastName` on Model `User` identifies its records and can not be ignored. Ignore the whole model with `@@ignore` instead.",
+    );
+}
+
+#[test]
+fn the_first_unique_field_of_models_without_id_must_not_be_ignored() {
+    let dml = r#"
+    model User {
+        email    String @unique @ignore
+        nickname String @unique
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@ignore\": The field `email` on Model `User` identifies its records and can not be ignored. Ignore the whole model with `@@ignore` instead.",
+    );
+}
+
+#[test]
+fn unique_fields_can_be_ignored_on_models_with_an_id() {
+    let dml = r#"
+    model User {
+        id    Int     @id
+        email String? @unique @ignore
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user = schema.assert_has_model("User");
+
+    assert!(user.assert_has_scalar_field("email").is_ignored);
+}
+
+#[test]
+fn id_fields_of_ignored_models_can_be_ignored() {
+    let dml = r#"
+    model User {
+        id   Int    @id @ignore
+        name String
+
+        @@ignore
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user = schema.assert_has_model("User");
+
+    assert!(user.assert_has_scalar_field("id").is_ignored);
+}
+
+#[test]
+fn required_fields_without_a_default_must_not_be_ignored() {
+    let dml = r#"
+    model User {
+        id     Int    @id
+        secret String @ignore
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@ignore\": The field `secret` on Model `User` is required and has no default value, so records could not be created once it is ignored. Make the field optional or give it a default value.",
+    );
+}
+
+#[test]
+fn required_fields_with_a_default_can_be_ignored() {
+    let dml = r#"
+    model User {
+        id        Int      @id
+        secret    String   @default("") @ignore
+        createdAt DateTime @default(now()) @ignore
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user = schema.assert_has_model("User");
+
+    assert!(user.assert_has_scalar_field("secret").is_ignored);
+    assert!(user.assert_has_scalar_field("createdAt").is_ignored);
+}
@@ -1,4 +1,7 @@
-use datamodel::{ast::Span, diagnostics::*, render_datamodel_to_string, IndexDefinition, IndexType};
+use datamodel::{
+    ast::Span, diagnostics::*, render_datamodel_to_string, IndexAlgorithm, IndexDefinition, IndexFieldOptions,
+    IndexType, SortOrder,
+};
 
 use crate::common::*;
 
@@ -20,6 +23,10 @@ fn basic_index_must_work() {
         name: None,
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Normal,
+        field_options: vec![],
+        algorithm: None,
+        predicate: None,
+        constraint_name: None,
     });
 }
 
@@ -45,6 +52,10 @@ fn indexes_on_enum_fields_must_work() {
         name: None,
         fields: vec!["role".to_string()],
         tpe: IndexType::Normal,
+        field_options: vec![],
+        algorithm: None,
+        predicate: None,
+        constraint_name: None,
     });
 }
 
@@ -91,6 +102,10 @@ fn the_name_argument_must_work() {
         name: Some("MyIndexName".to_string()),
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Normal,
+        field_options: vec![],
+        algorithm: None,
+        predicate: None,
+        constraint_name: None,
     });
 }
 
@@ -126,12 +141,20 @@ fn multiple_indexes_with_same_name_are_supported_by_mysql() {
         name: Some("MyIndexName".to_string()),
         fields: vec!["id".to_string()],
         tpe: IndexType::Normal,
+        field_options: vec![],
+        algorithm: None,
+        predicate: None,
+        constraint_name: None,
     });
 
     post_model.assert_has_index(IndexDefinition {
         name: Some("MyIndexName".to_string()),
         fields: vec!["id".to_string()],
         tpe: IndexType::Normal,
+        field_options: vec![],
+        algorithm: None,
+        predicate: None,
+        constraint_name: None,
     });
 }
 
@@ -222,12 +245,20 @@ fn multiple_index_must_work() {
         name: None,
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Normal,
+        field_options: vec![],
+        algorithm: None,
+        predicate: None,
+        constraint_name: None,
     });
 
     user_model.assert_has_index(IndexDefinition {
         name: Some("MyIndexName".to_string()),
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Normal,
+        field_options: vec![],
+        algorithm: None,
+        predicate: None,
+        constraint_name: None,
     });
 }
 
@@ -265,3 +296,186 @@ fn index_attributes_must_serialize_to_valid_dml() {
 
     assert!(datamodel::parse_datamodel(&render_datamodel_to_string(&schema)).is_ok());
 }
+
+#[test]
+fn index_field_sort_orders_and_prefix_lengths_must_work() {
+    let dml = r#"
+    datasource db {
+        provider = "mysql"
+        url = "mysql://localhost:3306"
+    }
+
+    model Post {
+        id        Int      @id
+        title     String
+        createdAt DateTime
+
+        @@index([title(100), createdAt(Desc)])
+    }
+    "#;
+
+    let schema = parse(dml);
+    let post_model = schema.assert_has_model("Post");
+    post_model.assert_has_index(IndexDefinition {
+        name: None,
+        fields: vec!["title".to_string(), "createdAt".to_string()],
+        tpe: IndexType::Normal,
+        field_options: vec![
+            IndexFieldOptions {
+                sort_order: SortOrder::Asc,
+                length: Some(100),
+            },
+            IndexFieldOptions {
+                sort_order: SortOrder::Desc,
+                length: None,
+            },
+        ],
+        algorithm: None,
+        predicate: None,
+        constraint_name: None,
+    });
+}
+
+#[test]
+fn index_types_and_partial_indexes_must_work() {
+    let dml = r#"
+    datasource db {
+        provider = "postgres"
+        url = "postgresql://localhost:5432"
+    }
+
+    model Post {
+        id        Int       @id
+        slug      String
+        tags      String
+        deletedAt DateTime?
+
+        @@index([tags], type: Gin)
+        @@unique([slug], where: "\"deletedAt\" IS NULL")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let post_model = schema.assert_has_model("Post");
+    post_model.assert_has_index(IndexDefinition {
+        name: None,
+        fields: vec!["tags".to_string()],
+        tpe: IndexType::Normal,
+        field_options: vec![],
+        algorithm: Some(IndexAlgorithm::Gin),
+        predicate: None,
+        constraint_name: None,
+    });
+    post_model.assert_has_index(IndexDefinition {
+        name: None,
+        fields: vec!["slug".to_string()],
+        tpe: IndexType::Unique,
+        field_options: vec![],
+        algorithm: None,
+        predicate: Some(r#""deletedAt" IS NULL"#.to_string()),
+        constraint_name: None,
+    });
+}
+
+#[test]
+fn index_options_must_be_rendered() {
+    let dml = r#"datasource db {
+  provider = "postgres"
+  url      = "postgresql://localhost:5432"
+}
+
+model Post {
+  id        Int       @id
+  title     String
+  deletedAt DateTime?
+
+  @@index([title(Desc), id], name: "title_idx", type: Hash, where: "deleted_at IS NULL")
+}
+"#;
+
+    let rendered = render_datamodel_to_string(&parse(dml));
+    assert!(
+        rendered.contains(r#"@@index([title(Desc), id], name: "title_idx", type: Hash, where: "deleted_at IS NULL")"#),
+        "{}",
+        rendered
+    );
+}
+
+#[test]
+fn unknown_index_types_must_error() {
+    let dml = r#"
+    datasource db {
+        provider = "postgres"
+        url = "postgresql://localhost:5432"
+    }
+
+    model Post {
+        id    Int    @id
+        title String
+
+        @@index([title], type: Bitmap)
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@index\": Unknown index type `Bitmap`. Valid types are BTree, Hash, Gin, Gist and Brin.",
+    );
+}
+
+#[test]
+fn unknown_index_field_options_must_error() {
+    let dml = r#"
+    model Post {
+        id    Int    @id
+        title String
+
+        @@index([title(Descending)])
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@index\": Unknown option `Descending` on index field `title`. Expected `Asc`, `Desc` or a prefix length.",
+    );
+}
+
+#[test]
+fn index_prefix_lengths_must_error_on_connectors_without_support() {
+    let dml = r#"
+    datasource db {
+        provider = "postgres"
+        url = "postgresql://localhost:5432"
+    }
+
+    model Post {
+        id    Int    @id
+        title String
+
+        @@index([title(10)])
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@index\": The current connector does not support prefix lengths on index fields.",
+    );
+}
+
+#[test]
+fn partial_indexes_must_error_on_connectors_without_support() {
+    let dml = r#"
+    datasource db {
+        provider = "mysql"
+        url = "mysql://localhost:3306"
+    }
+
+    model Post {
+        id    Int    @id
+        title String
+
+        @@unique([title], where: "title <> ''")
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@unique\": The current connector does not support partial indexes.",
+    );
+}
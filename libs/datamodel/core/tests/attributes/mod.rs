@@ -1,8 +1,13 @@
 pub mod builtin_attributes;
+pub mod check;
+pub mod computed;
+pub mod constraint_names;
 pub mod default_negative;
 pub mod default_positive;
+pub mod fulltext;
 pub mod id_negative;
 pub mod id_positive;
+pub mod ignore;
 pub mod index;
 pub mod map;
 pub mod relations_basic;
@@ -11,9 +16,14 @@ pub mod relations_legacy;
 pub mod relations_negative;
 pub mod relations_new;
 pub mod relations_positive;
+pub mod schema;
 pub mod unique;
 pub mod unique_criteria;
 pub mod updated_at_negative;
 pub mod updated_at_positive;
+pub mod validation_negative;
+pub mod validation_positive;
+pub mod version_negative;
+pub mod version_positive;
 
 pub mod arg_parsing;
@@ -0,0 +1,111 @@
+use crate::common::*;
+
+const DATASOURCE: &str = r#"
+    datasource db {
+        provider = "postgresql"
+        url = "postgresql://localhost:5432"
+        schemas = ["auth", "billing"]
+    }
+"#;
+
+#[test]
+fn schema_attribute_on_models_and_enums() {
+    let dml = format!(
+        r#"{}
+    model User {{
+        id Int @id
+
+        @@schema("auth")
+    }}
+
+    model Invoice {{
+        id     Int    @id
+        status Status
+
+        @@schema(name: "billing")
+    }}
+
+    model Note {{
+        id Int @id
+    }}
+
+    enum Status {{
+        PAID
+        OPEN
+
+        @@schema("billing")
+    }}
+    "#,
+        DATASOURCE
+    );
+
+    let schema = parse(&dml);
+
+    assert_eq!(schema.assert_has_model("User").schema.as_deref(), Some("auth"));
+    assert_eq!(schema.assert_has_model("Invoice").schema.as_deref(), Some("billing"));
+    assert_eq!(schema.assert_has_model("Note").schema, None);
+    assert_eq!(schema.assert_has_enum("Status").schema.as_deref(), Some("billing"));
+}
+
+#[test]
+fn schema_attribute_is_rendered() {
+    let dml = format!(
+        r#"{}
+    model User {{
+        id Int @id
+
+        @@schema("auth")
+    }}
+
+    enum Status {{
+        PAID
+
+        @@schema("billing")
+    }}
+    "#,
+        DATASOURCE
+    );
+
+    let rendered = datamodel::render_datamodel_to_string(&parse(&dml));
+
+    assert!(rendered.contains(r#"@@schema("auth")"#), "{}", rendered);
+    assert!(rendered.contains(r#"@@schema("billing")"#), "{}", rendered);
+}
+
+#[test]
+fn schema_attribute_must_reference_a_schema_of_the_datasource() {
+    let dml = format!(
+        r#"{}
+    model User {{
+        id Int @id
+
+        @@schema("accounting")
+    }}
+    "#,
+        DATASOURCE
+    );
+
+    parse_error(&dml).assert_is_message(
+        "Error parsing attribute \"@schema\": The schema `accounting` is not defined in the `schemas` property of the datasource.",
+    );
+}
+
+#[test]
+fn schema_attribute_requires_the_schemas_property() {
+    let dml = r#"
+    datasource db {
+        provider = "postgresql"
+        url = "postgresql://localhost:5432"
+    }
+
+    enum Status {
+        PAID
+
+        @@schema("billing")
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error parsing attribute \"@schema\": The `@@schema` attribute can only be used if the datasource defines a `schemas` property.",
+    );
+}
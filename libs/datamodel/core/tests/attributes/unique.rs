@@ -22,6 +22,10 @@ fn basic_unique_index_must_work() {
         name: None,
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Unique,
+        field_options: vec![],
+        algorithm: None,
+        predicate: None,
+        constraint_name: None,
     });
 }
 
@@ -163,6 +167,10 @@ fn the_name_argument_must_work() {
         name: Some("MyIndexName".to_string()),
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Unique,
+        field_options: vec![],
+        algorithm: None,
+        predicate: None,
+        constraint_name: None,
     });
 }
 
@@ -186,12 +194,20 @@ fn multiple_unique_must_work() {
         name: None,
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Unique,
+        field_options: vec![],
+        algorithm: None,
+        predicate: None,
+        constraint_name: None,
     });
 
     user_model.assert_has_index(IndexDefinition {
         name: Some("MyIndexName".to_string()),
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Unique,
+        field_options: vec![],
+        algorithm: None,
+        predicate: None,
+        constraint_name: None,
     });
 }
 
@@ -217,6 +233,10 @@ fn multi_field_unique_indexes_on_enum_fields_must_work() {
         name: None,
         fields: vec!["role".to_string()],
         tpe: IndexType::Unique,
+        field_options: vec![],
+        algorithm: None,
+        predicate: None,
+        constraint_name: None,
     });
 }
 
@@ -0,0 +1,110 @@
+use crate::common::*;
+use datamodel::{ast::Span, diagnostics::DatamodelError};
+
+#[test]
+fn length_should_fail_on_non_string_fields() {
+    let dml = r#"
+    model User {
+        id   Int @id
+        name Int @length(min: 1)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_attribute_validation_error(
+        "Fields that are marked with @length must be of a String type.",
+        "length",
+        Span::new(57, 71),
+    ));
+}
+
+#[test]
+fn length_should_fail_if_min_is_greater_than_max() {
+    let dml = r#"
+    model User {
+        id   Int    @id
+        name String @length(min: 5, max: 1)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_attribute_validation_error(
+        "The `min` argument must not be greater than the `max` argument.",
+        "length",
+        Span::new(63, 85),
+    ));
+}
+
+#[test]
+fn length_should_fail_without_bounds() {
+    let dml = r#"
+    model User {
+        id   Int    @id
+        name String @length
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_attribute_validation_error(
+        "At least one of the `min` and `max` arguments must be given.",
+        "length",
+        Span::new(63, 69),
+    ));
+}
+
+#[test]
+fn regex_should_fail_on_invalid_patterns() {
+    let dml = r#"
+    model User {
+        id   Int    @id
+        name String @regex("[a-z")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_attribute_validation_error(
+        "The pattern `[a-z` is not a valid regular expression.",
+        "regex",
+        Span::new(69, 75),
+    ));
+}
+
+#[test]
+fn email_should_fail_on_lists() {
+    let dml = r#"
+    model User {
+        id     Int      @id
+        emails String[] @email
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_attribute_validation_error(
+        "Fields that are marked with @email can not be lists.",
+        "email",
+        Span::new(71, 76),
+    ));
+}
+
+#[test]
+fn range_should_fail_on_non_numeric_fields() {
+    let dml = r#"
+    model User {
+        id   Int     @id
+        flag Boolean @range(min: 0)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_attribute_validation_error(
+        "Fields that are marked with @range must be of a numeric type.",
+        "range",
+        Span::new(65, 78),
+    ));
+}
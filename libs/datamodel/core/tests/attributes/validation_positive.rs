@@ -0,0 +1,80 @@
+use crate::common::*;
+use datamodel::{render_datamodel_to_string, FieldValidation, ValidationPattern};
+
+#[test]
+fn should_apply_validation_attributes() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        name  String @length(min: 1, max: 64) @regex("^[A-Z]")
+        email String @email
+        age   Int    @range(min: 0)
+        score Float? @range(max: 9.5)
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+
+    user_model.assert_has_scalar_field("name").assert_validations(&[
+        FieldValidation::Length {
+            min: Some(1),
+            max: Some(64),
+        },
+        FieldValidation::Regex {
+            pattern: ValidationPattern::new("^[A-Z]").unwrap(),
+        },
+    ]);
+
+    user_model
+        .assert_has_scalar_field("email")
+        .assert_validations(&[FieldValidation::Email]);
+
+    user_model
+        .assert_has_scalar_field("age")
+        .assert_validations(&[FieldValidation::Range {
+            min: Some(0.0),
+            max: None,
+        }]);
+
+    user_model
+        .assert_has_scalar_field("score")
+        .assert_validations(&[FieldValidation::Range {
+            min: None,
+            max: Some(9.5),
+        }]);
+
+    user_model.assert_has_scalar_field("id").assert_validations(&[]);
+}
+
+#[test]
+fn validation_attributes_must_survive_rendering() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        name  String @length(min: 1, max: 64)
+        email String @email
+        age   Int    @range(min: 0, max: 150)
+    }
+    "#;
+
+    let rendered = render_datamodel_to_string(&parse(dml));
+    let schema = parse(&rendered);
+    let user_model = schema.assert_has_model("User");
+
+    user_model
+        .assert_has_scalar_field("name")
+        .assert_validations(&[FieldValidation::Length {
+            min: Some(1),
+            max: Some(64),
+        }]);
+    user_model
+        .assert_has_scalar_field("email")
+        .assert_validations(&[FieldValidation::Email]);
+    user_model
+        .assert_has_scalar_field("age")
+        .assert_validations(&[FieldValidation::Range {
+            min: Some(0.0),
+            max: Some(150.0),
+        }]);
+}
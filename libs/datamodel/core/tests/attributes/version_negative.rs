@@ -0,0 +1,57 @@
+use crate::common::*;
+use datamodel::{ast::Span, diagnostics::DatamodelError};
+
+#[test]
+fn should_fail_if_field_type_is_string() {
+    let dml = r#"
+    model Post {
+        id Int @id
+        version String @version
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_attribute_validation_error(
+        "Fields that are marked with @version must be of type Int.",
+        "version",
+        Span::new(61, 68),
+    ));
+}
+
+#[test]
+fn should_fail_if_field_arity_is_list() {
+    let dml = r#"
+    model Post {
+        id Int @id
+        version Int[] @version
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_attribute_validation_error(
+        "Fields that are marked with @version can not be lists.",
+        "version",
+        Span::new(60, 67),
+    ));
+}
+
+#[test]
+fn should_fail_if_used_on_multiple_fields() {
+    let dml = r#"
+    model Post {
+        id       Int @id
+        version  Int @version
+        revision Int @version
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_attribute_validation_error(
+        "The `@version` attribute can only be used on one field per model.",
+        "version",
+        Span::new(5, 108),
+    ));
+}
@@ -0,0 +1,20 @@
+use crate::common::*;
+use datamodel::ScalarType;
+
+#[test]
+fn should_apply_version_attribute() {
+    let dml = r#"
+    model Post {
+        id      Int @id
+        version Int @version
+    }
+    "#;
+
+    let schema = parse(dml);
+    let post_model = schema.assert_has_model("Post");
+    post_model
+        .assert_has_scalar_field("version")
+        .assert_base_type(&ScalarType::Int)
+        .assert_is_version(true);
+    post_model.assert_has_scalar_field("id").assert_is_version(false);
+}
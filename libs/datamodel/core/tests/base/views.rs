@@ -0,0 +1,96 @@
+use crate::common::*;
+
+#[test]
+fn parse_basic_view() {
+    let dml = r#"
+    model User {
+        id   Int    @id
+        name String
+    }
+
+    view UserName {
+        id   Int    @unique
+        name String
+    }
+    "#;
+
+    let schema = parse(dml);
+
+    assert!(!schema.assert_has_model("User").is_view);
+
+    let view = schema.assert_has_model("UserName");
+    assert!(view.is_view);
+    view.assert_has_scalar_field("id").assert_is_unique(true);
+    view.assert_has_scalar_field("name");
+}
+
+#[test]
+fn views_must_have_a_unique_criteria() {
+    let dml = r#"
+    view UserName {
+        name String
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error validating model \"UserName\": Each model must have at least one unique criteria that has only required fields. Either mark a single field with `@id`, `@unique` or add a multi field criterion with `@@id([])` or `@@unique([])` to the model.",
+    );
+}
+
+#[test]
+fn views_cannot_have_relation_fields() {
+    let dml = r#"
+    model User {
+        id Int @id
+    }
+
+    view UserName {
+        id     Int  @unique
+        userId Int
+        user   User @relation(fields: [userId], references: [id])
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error validating model \"UserName\": Views cannot have relation fields, and relation fields cannot point to views.",
+    );
+}
+
+#[test]
+fn relation_fields_cannot_point_to_views() {
+    let dml = r#"
+    model Post {
+        id       Int      @id
+        authorId Int
+        author   UserName @relation(fields: [authorId], references: [id])
+    }
+
+    view UserName {
+        id Int @unique
+    }
+    "#;
+
+    parse_error(dml).assert_is_message(
+        "Error validating model \"Post\": Views cannot have relation fields, and relation fields cannot point to views.",
+    );
+}
+
+#[test]
+fn views_are_rendered_with_the_view_keyword() {
+    let input = r#"model User {
+  id   Int    @id
+  name String
+}
+
+view UserName {
+  id   Int    @unique
+  name String
+
+  @@map("user_names")
+}
+"#;
+
+    let rendered = datamodel::render_datamodel_to_string(&parse(input));
+
+    assert_eq!(rendered, input);
+}
@@ -24,6 +24,8 @@ pub trait ScalarFieldAsserts {
     fn assert_is_id(&self) -> &Self;
     fn assert_is_unique(&self, b: bool) -> &Self;
     fn assert_is_updated_at(&self, b: bool) -> &Self;
+    fn assert_is_version(&self, b: bool) -> &Self;
+    fn assert_validations(&self, validations: &[dml::FieldValidation]) -> &Self;
 }
 
 pub trait RelationFieldAsserts {
@@ -151,6 +153,16 @@ impl ScalarFieldAsserts for dml::ScalarField {
         assert_eq!(self.is_updated_at, b);
         self
     }
+
+    fn assert_is_version(&self, b: bool) -> &Self {
+        assert_eq!(self.is_version, b);
+        self
+    }
+
+    fn assert_validations(&self, validations: &[dml::FieldValidation]) -> &Self {
+        assert_eq!(self.validations, validations);
+        self
+    }
 }
 
 impl FieldAsserts for dml::RelationField {
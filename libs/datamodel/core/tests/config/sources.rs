@@ -1,5 +1,5 @@
 use crate::common::*;
-use datamodel::{ast::Span, diagnostics::DatamodelError, StringFromEnvVar};
+use datamodel::{ast::Span, diagnostics::DatamodelError, PoolSettings, StringFromEnvVar};
 use pretty_assertions::assert_eq;
 use serial_test::serial;
 
@@ -209,6 +209,159 @@ fn new_lines_in_source_must_work() {
     assert_eq_json(&rendered, expected);
 }
 
+#[test]
+#[serial]
+fn read_replicas_must_work() {
+    std::env::set_var("REPLICA_URL", "postgresql://replica2");
+
+    let schema = r#"
+        datasource ds {
+          provider = "postgresql"
+          url = "postgresql://localhost"
+          readReplicas = ["postgresql://replica1", env("REPLICA_URL")]
+        }
+    "#;
+
+    let config = parse_configuration(schema);
+    let rendered = datamodel::json::mcf::render_sources_to_json(&config.datasources);
+
+    let expected = r#"[
+        {
+          "name": "ds",
+          "provider": ["postgresql"],
+          "activeProvider": "postgresql",
+          "url": {
+              "fromEnvVar": null,
+              "value": "postgresql://localhost"
+          },
+          "readReplicas": [
+              {
+                  "fromEnvVar": null,
+                  "value": "postgresql://replica1"
+              },
+              {
+                  "fromEnvVar": "REPLICA_URL",
+                  "value": "postgresql://replica2"
+              }
+          ]
+        }
+    ]"#;
+
+    assert_eq_json(&rendered, expected);
+}
+
+#[test]
+fn must_error_if_wrong_protocol_is_used_for_read_replicas() {
+    let schema = r#"
+        datasource myds {
+            provider = "postgresql"
+            url = "postgresql://localhost"
+            readReplicas = ["mysql://replica"]
+        }
+    "#;
+
+    let diagnostics = parse_error(schema);
+    diagnostics.assert_is(DatamodelError::new_source_validation_error(
+        "The URL for datasource `myds` must start with the protocol `postgresql://`.",
+        "myds",
+        Span::new(134, 151),
+    ));
+}
+
+#[test]
+fn pool_settings_must_work() {
+    let schema = r#"
+        datasource ds {
+          provider = "postgresql"
+          url = "postgresql://localhost"
+          connectionLimit = 20
+          minIdleConnections = 2
+          poolTimeout = 5
+          maxConnectionLifetime = 1800
+          idleTimeout = 300
+        }
+    "#;
+
+    let config = parse_configuration(schema);
+    let data_source = config.datasources.first().unwrap();
+
+    assert_eq!(
+        data_source.pool,
+        PoolSettings {
+            connection_limit: Some(20),
+            min_idle_connections: Some(2),
+            pool_timeout: Some(5),
+            max_connection_lifetime: Some(1800),
+            idle_timeout: Some(300),
+        }
+    );
+}
+
+#[test]
+fn must_error_if_pool_settings_are_negative() {
+    let schema = r#"
+        datasource myds {
+            provider = "postgresql"
+            url = "postgresql://localhost"
+            poolTimeout = -1
+        }
+    "#;
+
+    let diagnostics = parse_error(schema);
+    diagnostics.assert_is(DatamodelError::new_source_validation_error(
+        "The `poolTimeout` argument must not be negative.",
+        "myds",
+        Span::new(132, 134),
+    ));
+}
+
+#[test]
+fn schemas_must_work() {
+    let schema = r#"
+        datasource ds {
+          provider = "postgresql"
+          url = "postgresql://localhost"
+          schemas = ["auth", "billing"]
+        }
+    "#;
+
+    let config = parse_configuration(schema);
+    let rendered = datamodel::json::mcf::render_sources_to_json(&config.datasources);
+
+    let expected = r#"[
+        {
+          "name": "ds",
+          "provider": ["postgresql"],
+          "activeProvider": "postgresql",
+          "url": {
+              "fromEnvVar": null,
+              "value": "postgresql://localhost"
+          },
+          "schemas": ["auth", "billing"]
+        }
+    ]"#;
+
+    assert_eq_json(&rendered, expected);
+}
+
+#[test]
+fn must_error_if_schemas_are_not_supported_by_the_provider() {
+    let schema = r#"
+        datasource myds {
+            provider = "mysql"
+            url = "mysql://localhost"
+            schemas = ["auth"]
+        }
+    "#;
+
+    let diagnostics = parse_error(schema);
+    diagnostics.assert_is(DatamodelError::new_source_validation_error(
+        "The `schemas` argument is not supported by the provider `mysql`.",
+        "myds",
+        Span::new(118, 126),
+    ));
+}
+
 #[test]
 #[serial]
 fn must_error_if_env_var_is_missing() {
@@ -0,0 +1,141 @@
+use crate::common::*;
+use datamodel::dml;
+
+#[test]
+fn should_parse_composite_types_embedded_in_models() {
+    let dml = r#"
+    datasource db {
+        provider = "postgres"
+        url      = "postgresql://"
+    }
+
+    model User {
+        id        Int       @id
+        address   Address
+        previous  Address[]
+    }
+
+    type Address {
+        street   String
+        zip      String?
+        tags     String[]
+        location Location?
+    }
+
+    type Location {
+        lat Float
+        lng Float
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let user = datamodel.assert_has_model("User");
+
+    let address = user.assert_has_scalar_field("address");
+    address.assert_arity(&dml::FieldArity::Required);
+    assert_eq!(address.field_type, dml::FieldType::CompositeType("Address".to_owned()));
+
+    let previous = user.assert_has_scalar_field("previous");
+    previous.assert_arity(&dml::FieldArity::List);
+    assert_eq!(previous.field_type, dml::FieldType::CompositeType("Address".to_owned()));
+
+    let address_type = datamodel.find_composite_type("Address").unwrap();
+    let field_names: Vec<_> = address_type.fields().map(|f| f.name.as_str()).collect();
+    assert_eq!(field_names, vec!["street", "zip", "tags", "location"]);
+
+    let zip = address_type.find_field("zip").unwrap();
+    assert_eq!(zip.arity, dml::FieldArity::Optional);
+    assert_eq!(zip.field_type, dml::FieldType::Base(dml::ScalarType::String, None));
+
+    let location = address_type.find_field("location").unwrap();
+    assert_eq!(
+        location.field_type,
+        dml::FieldType::CompositeType("Location".to_owned())
+    );
+}
+
+#[test]
+fn should_fail_on_composite_type_fields_without_json_support() {
+    let dml = r#"
+    datasource db {
+        provider = "sqlite"
+        url      = "file:dev.db"
+    }
+
+    model User {
+        id      Int     @id
+        address Address
+    }
+
+    type Address {
+        street String
+    }
+    "#;
+
+    parse_error(dml).assert_is_message("Error validating field `address` in model `User`: Field `address` in model `User` can't be of the composite type `Address`. Composite types are stored as Json, which the current connector does not support.");
+}
+
+#[test]
+fn should_fail_on_unique_composite_type_fields() {
+    let dml = r#"
+    datasource db {
+        provider = "postgres"
+        url      = "postgresql://"
+    }
+
+    model User {
+        id      Int     @id
+        address Address @unique
+    }
+
+    type Address {
+        street String
+    }
+    "#;
+
+    parse_error(dml).assert_is_message("Error validating field `address` in model `User`: Field `address` in model `User` is of the composite type `Address` and can not be part of an id, unique constraint or index.");
+}
+
+#[test]
+fn should_fail_on_relation_fields_in_composite_types() {
+    let dml = r#"
+    model User {
+        id Int @id
+    }
+
+    type Address {
+        owner User
+    }
+    "#;
+
+    parse_error(dml).assert_is_message("Error validating: The field `owner` of the composite type `Address` refers to a model. Composite types can only contain scalar, enum and composite type fields.");
+}
+
+#[test]
+fn should_fail_on_composite_types_embedding_themselves_through_required_fields() {
+    let dml = r#"
+    type Node {
+        value String
+        next  Node
+    }
+    "#;
+
+    parse_error(dml).assert_is_message("Error validating: Composite types can not embed themselves through required fields. Recursive path was: Node -> Node. Make one of the fields optional or a list.");
+}
+
+#[test]
+fn should_allow_composite_types_embedding_themselves_through_optional_fields() {
+    let dml = r#"
+    type Node {
+        value    String
+        next     Node?
+        children Node[]
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let node = datamodel.find_composite_type("Node").unwrap();
+
+    assert_eq!(node.find_field("next").unwrap().arity, dml::FieldArity::Optional);
+    assert_eq!(node.find_field("children").unwrap().arity, dml::FieldArity::List);
+}
@@ -29,6 +29,7 @@ impl<'a> DatamodelConverter<'a> {
             models: self.convert_models(),
             relations: self.convert_relations(),
             enums: self.convert_enums(),
+            composite_types: self.convert_composite_types(),
             version: Some("v2".to_string()),
         }
     }
@@ -43,6 +44,25 @@ impl<'a> DatamodelConverter<'a> {
             .collect()
     }
 
+    fn convert_composite_types(&self) -> Vec<InternalCompositeType> {
+        self.datamodel
+            .composite_types()
+            .map(|ct| InternalCompositeType {
+                name: ct.name.clone(),
+                fields: ct
+                    .fields()
+                    .map(|f| InternalCompositeTypeField {
+                        name: f.name.clone(),
+                        type_identifier: type_identifier(&f.field_type),
+                        arity: f.arity,
+                        internal_enum: internal_enum(&f.field_type, self.datamodel),
+                        composite_type: f.field_type.composite_type().map(String::from),
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+
     fn convert_enum_values(&self, enm: &dml::Enum) -> Vec<InternalEnumValue> {
         enm.values()
             .map(|enum_value| InternalEnumValue {
@@ -55,12 +75,14 @@ impl<'a> DatamodelConverter<'a> {
     fn convert_models(&self) -> Vec<ModelTemplate> {
         self.datamodel
             .models()
+            .filter(|model| !model.is_ignored)
             .map(|model| ModelTemplate {
                 name: model.name.clone(),
                 is_embedded: model.is_embedded,
+                is_view: model.is_view,
                 fields: self.convert_fields(&model),
                 manifestation: model.database_name().map(|s| s.to_owned()),
-                id_field_names: model.id_fields.clone(),
+                id_field_names: self.convert_id_field_names(&model),
                 indexes: self.convert_indexes(&model),
                 dml_model: model.clone(),
             })
@@ -70,6 +92,10 @@ impl<'a> DatamodelConverter<'a> {
     fn convert_fields(&self, model: &dml::Model) -> Vec<FieldTemplate> {
         model
             .fields()
+            .filter(|field| match field {
+                dml::Field::RelationField(rf) => !Self::relation_field_is_ignored(self.datamodel, model, rf),
+                dml::Field::ScalarField(sf) => !sf.is_ignored,
+            })
             .map(|field| match field {
                 dml::Field::RelationField(rf) => {
                     let relation = self
@@ -100,9 +126,11 @@ impl<'a> DatamodelConverter<'a> {
                     is_autoincrement: sf.is_auto_increment(),
                     behaviour: sf.behaviour(),
                     internal_enum: sf.internal_enum(self.datamodel),
+                    composite_type: sf.field_type.composite_type().map(String::from),
                     db_name: sf.database_name.clone(),
                     arity: sf.arity,
                     default_value: sf.default_value.clone(),
+                    validations: sf.validations.clone(),
                 }),
             })
             .collect()
@@ -122,17 +150,64 @@ impl<'a> DatamodelConverter<'a> {
             .collect()
     }
 
+    /// A compound id with an ignored field cannot be used by the client.
+    fn convert_id_field_names(&self, model: &dml::Model) -> Vec<String> {
+        if model.id_fields.iter().any(|f| Self::scalar_field_is_ignored(model, f)) {
+            Vec::new()
+        } else {
+            model.id_fields.clone()
+        }
+    }
+
+    fn scalar_field_is_ignored(model: &dml::Model, field_name: &str) -> bool {
+        model
+            .find_scalar_field(field_name)
+            .map(|field| field.is_ignored)
+            .unwrap_or(false)
+    }
+
+    /// Ignored models and fields are not part of the client. A relation is only kept if both of its sides and their
+    /// underlying fields are.
+    fn relation_field_is_ignored(datamodel: &dml::Datamodel, model: &dml::Model, field: &dml::RelationField) -> bool {
+        let side_is_ignored = |model: &dml::Model, field: &dml::RelationField| {
+            model.is_ignored
+                || field.is_ignored
+                || field
+                    .relation_info
+                    .fields
+                    .iter()
+                    .any(|f| Self::scalar_field_is_ignored(model, f))
+        };
+
+        let related_side_is_ignored = match (
+            datamodel.find_model(&field.relation_info.to),
+            datamodel.find_related_field(field),
+        ) {
+            (Some(related_model), Some(related_field)) => side_is_ignored(related_model, related_field),
+            _ => false,
+        };
+
+        side_is_ignored(model, field) || related_side_is_ignored
+    }
+
     fn convert_indexes(&self, model: &dml::Model) -> Vec<IndexTemplate> {
         model
             .indices
             .iter()
-            .filter(|i| i.fields.len() > 1) // @@unique for 1 field are transformed to is_unique instead
+            // @@unique for 1 field are transformed to is_unique instead. Full-text indexes are kept
+            // for any number of fields, searches go through them on SQLite.
+            .filter(|i| i.fields.len() > 1 || i.tpe == dml::IndexType::Fulltext)
+            .filter(|i| !i.fields.iter().any(|f| Self::scalar_field_is_ignored(model, f)))
             .map(|i| IndexTemplate {
                 name: i.name.clone(),
+                db_name: model.index_database_name(i),
                 fields: i.fields.clone(),
                 typ: match i.tpe {
+                    // A partial unique index does not make its fields a unique criteria.
+                    dml::IndexType::Unique if i.predicate.is_some() => IndexType::Normal,
                     dml::IndexType::Unique => IndexType::Unique,
                     dml::IndexType::Normal => IndexType::Normal,
+                    dml::IndexType::Fulltext => IndexType::Fulltext,
                 },
             })
             .collect()
@@ -141,7 +216,10 @@ impl<'a> DatamodelConverter<'a> {
     pub fn calculate_relations(datamodel: &dml::Datamodel) -> Vec<TempRelationHolder> {
         let mut result = Vec::new();
         for model in datamodel.models() {
-            for field in model.relation_fields() {
+            for field in model
+                .relation_fields()
+                .filter(|field| !Self::relation_field_is_ignored(datamodel, model, field))
+            {
                 let dml::RelationInfo {
                     to, references, name, ..
                 } = &field.relation_info;
@@ -334,28 +412,11 @@ trait DatamodelFieldExtensions {
     fn is_auto_generated_int_id(&self) -> bool;
     fn behaviour(&self) -> Option<FieldBehaviour>;
     fn internal_enum(&self, datamodel: &dml::Datamodel) -> Option<InternalEnum>;
-    fn internal_enum_value(&self, enum_value: &dml::EnumValue) -> InternalEnumValue;
 }
 
 impl DatamodelFieldExtensions for dml::ScalarField {
     fn type_identifier(&self) -> TypeIdentifier {
-        match &self.field_type {
-            dml::FieldType::Enum(x) => TypeIdentifier::Enum(x.clone()),
-            dml::FieldType::Relation(_) => TypeIdentifier::String, // Todo: Unused
-            dml::FieldType::Base(scalar, _) => match scalar {
-                dml::ScalarType::Boolean => TypeIdentifier::Boolean,
-                dml::ScalarType::DateTime => TypeIdentifier::DateTime,
-                dml::ScalarType::Float => TypeIdentifier::Float,
-                dml::ScalarType::Decimal => TypeIdentifier::Decimal,
-                dml::ScalarType::Int => TypeIdentifier::Int,
-                dml::ScalarType::String => TypeIdentifier::String,
-                dml::ScalarType::Json => TypeIdentifier::Json,
-                dml::ScalarType::Bytes => TypeIdentifier::Bytes,
-                dml::ScalarType::BigInt => TypeIdentifier::BigInt,
-            },
-            dml::FieldType::Unsupported(_) => panic!("These should always be commented out"),
-            dml::FieldType::NativeType(scalar_type, _) => (*scalar_type).into(),
-        }
+        type_identifier(&self.field_type)
     }
 
     fn is_unique(&self, model: &dml::Model) -> bool {
@@ -363,7 +424,7 @@ impl DatamodelFieldExtensions for dml::ScalarField {
         let is_declared_as_unique_through_multi_field_unique = model
             .indices
             .iter()
-            .any(|ixd| ixd.is_unique() && ixd.fields == vec![self.name.clone()]);
+            .any(|ixd| ixd.is_unique() && ixd.predicate.is_none() && ixd.fields == vec![self.name.clone()]);
 
         self.is_unique || is_declared_as_unique_through_multi_field_unique
     }
@@ -384,30 +445,57 @@ impl DatamodelFieldExtensions for dml::ScalarField {
     fn behaviour(&self) -> Option<FieldBehaviour> {
         if self.is_updated_at {
             Some(FieldBehaviour::UpdatedAt)
+        } else if self.is_version {
+            Some(FieldBehaviour::Version)
+        } else if self.computed.is_some() {
+            Some(FieldBehaviour::Computed)
         } else {
             None
         }
     }
 
     fn internal_enum(&self, datamodel: &dml::Datamodel) -> Option<InternalEnum> {
-        match self.field_type {
-            dml::FieldType::Enum(ref name) => {
-                datamodel
-                    .enums()
-                    .find(|e| e.name == name.clone())
-                    .map(|e| InternalEnum {
-                        name: e.name.clone(),
-                        values: e.values().map(|v| self.internal_enum_value(v)).collect(),
-                    })
-            }
-            _ => None,
-        }
+        internal_enum(&self.field_type, datamodel)
     }
+}
 
-    fn internal_enum_value(&self, enum_value: &dml::EnumValue) -> InternalEnumValue {
-        InternalEnumValue {
-            name: enum_value.name.clone(),
-            database_name: enum_value.database_name.clone(),
-        }
+fn type_identifier(field_type: &dml::FieldType) -> TypeIdentifier {
+    match field_type {
+        dml::FieldType::Enum(x) => TypeIdentifier::Enum(x.clone()),
+        dml::FieldType::Relation(_) => TypeIdentifier::String, // Todo: Unused
+        dml::FieldType::CompositeType(_) => TypeIdentifier::Json,
+        dml::FieldType::Base(scalar, _) => match scalar {
+            dml::ScalarType::Boolean => TypeIdentifier::Boolean,
+            dml::ScalarType::DateTime => TypeIdentifier::DateTime,
+            dml::ScalarType::Float => TypeIdentifier::Float,
+            dml::ScalarType::Decimal => TypeIdentifier::Decimal,
+            dml::ScalarType::Int => TypeIdentifier::Int,
+            dml::ScalarType::String => TypeIdentifier::String,
+            dml::ScalarType::Json => TypeIdentifier::Json,
+            dml::ScalarType::Bytes => TypeIdentifier::Bytes,
+            dml::ScalarType::BigInt => TypeIdentifier::BigInt,
+        },
+        dml::FieldType::Unsupported(_) => panic!("These should always be commented out"),
+        dml::FieldType::NativeType(scalar_type, _) => (*scalar_type).into(),
+    }
+}
+
+fn internal_enum(field_type: &dml::FieldType, datamodel: &dml::Datamodel) -> Option<InternalEnum> {
+    match field_type {
+        dml::FieldType::Enum(ref name) => datamodel
+            .enums()
+            .find(|e| e.name == name.clone())
+            .map(|e| InternalEnum {
+                name: e.name.clone(),
+                values: e.values().map(internal_enum_value).collect(),
+            }),
+        _ => None,
+    }
+}
+
+fn internal_enum_value(enum_value: &dml::EnumValue) -> InternalEnumValue {
+    InternalEnumValue {
+        name: enum_value.name.clone(),
+        database_name: enum_value.database_name.clone(),
     }
 }
@@ -1,5 +1,5 @@
 use crate::prelude::*;
-use datamodel::{DefaultValue, FieldArity};
+use datamodel::{DefaultValue, FieldArity, FieldValidation};
 use once_cell::sync::OnceCell;
 use std::{
     fmt::Debug,
@@ -27,9 +27,11 @@ pub struct ScalarFieldTemplate {
     pub is_autoincrement: bool,
     pub behaviour: Option<FieldBehaviour>,
     pub internal_enum: Option<InternalEnum>,
+    pub composite_type: Option<String>,
     pub arity: FieldArity,
     pub db_name: Option<String>,
     pub default_value: Option<DefaultValue>,
+    pub validations: Vec<FieldValidation>,
 }
 
 pub struct ScalarField {
@@ -41,10 +43,13 @@ pub struct ScalarField {
     pub is_auto_generated_int_id: bool,
     pub is_autoincrement: bool,
     pub internal_enum: Option<InternalEnum>,
+    /// The name of the embedded composite type, stored as JSON.
+    pub composite_type: Option<String>,
     pub behaviour: Option<FieldBehaviour>,
     pub arity: FieldArity,
     pub db_name: Option<String>,
     pub default_value: Option<DefaultValue>,
+    pub validations: Vec<FieldValidation>,
 
     pub model: ModelWeakRef,
     pub(crate) is_unique: bool,
@@ -62,10 +67,12 @@ impl Debug for ScalarField {
             .field("is_auto_generated_int_id", &self.is_auto_generated_int_id)
             .field("is_autoincrement", &self.is_autoincrement)
             .field("internal_enum", &self.internal_enum)
+            .field("composite_type", &self.composite_type)
             .field("behaviour", &self.behaviour)
             .field("arity", &self.arity)
             .field("db_name", &self.db_name)
             .field("default_value", &self.default_value)
+            .field("validations", &self.validations)
             .field("model", &"#ModelWeakRef#")
             .field("is_unique", &self.is_unique)
             .field("read_only", &self.read_only)
@@ -84,6 +91,7 @@ impl Hash for ScalarField {
         self.is_id.hash(state);
         self.is_auto_generated_int_id.hash(state);
         self.internal_enum.hash(state);
+        self.composite_type.hash(state);
         self.behaviour.hash(state);
         self.is_unique.hash(state);
         self.model().hash(state);
@@ -101,6 +109,7 @@ impl PartialEq for ScalarField {
             && self.is_id == other.is_id
             && self.is_auto_generated_int_id == other.is_auto_generated_int_id
             && self.internal_enum == other.internal_enum
+            && self.composite_type == other.composite_type
             && self.behaviour == other.behaviour
             && self.default_value == other.default_value
             && self.is_unique == other.is_unique
@@ -114,6 +123,8 @@ impl PartialEq for ScalarField {
 pub enum FieldBehaviour {
     CreatedAt,
     UpdatedAt,
+    Version,
+    Computed,
     ScalarList { strategy: ScalarListStrategy },
 }
 
@@ -136,10 +147,12 @@ impl ScalarFieldTemplate {
             read_only: OnceCell::new(),
             is_unique: self.is_unique,
             internal_enum: self.internal_enum,
+            composite_type: self.composite_type,
             behaviour: self.behaviour,
             arity: self.arity,
             db_name: self.db_name,
             default_value: self.default_value,
+            validations: self.validations,
             model,
         };
 
@@ -184,6 +197,16 @@ impl ScalarField {
         }
     }
 
+    /// True if the field is the `@version` of its model.
+    pub fn is_version(&self) -> bool {
+        matches!(self.behaviour, Some(FieldBehaviour::Version))
+    }
+
+    /// True if the database computes the value of the field, which can then only be read.
+    pub fn is_computed(&self) -> bool {
+        matches!(self.behaviour, Some(FieldBehaviour::Computed))
+    }
+
     pub fn unique(&self) -> bool {
         self.is_unique || self.is_id()
     }
@@ -192,8 +215,27 @@ impl ScalarField {
         &self.db_name.as_ref().unwrap_or(&self.name)
     }
 
+    /// Lists of composite types are stored as a single, nullable JSON array.
     pub fn type_identifier_with_arity(&self) -> (TypeIdentifier, FieldArity) {
-        (self.type_identifier.clone(), self.arity)
+        if self.is_composite() && self.is_list {
+            (self.type_identifier.clone(), FieldArity::Optional)
+        } else {
+            (self.type_identifier.clone(), self.arity)
+        }
+    }
+
+    pub fn is_composite(&self) -> bool {
+        self.composite_type.is_some()
+    }
+
+    /// The composite type embedded by this field, if any.
+    pub fn composite_type(&self) -> Option<InternalCompositeType> {
+        self.composite_type.as_ref().map(|name| {
+            self.internal_data_model()
+                .find_composite_type(name)
+                .expect("Composite type of a field must exist")
+                .clone()
+        })
     }
 
     pub fn is_read_only(&self) -> bool {
@@ -4,6 +4,7 @@ use std::sync::Arc;
 #[derive(Debug)]
 pub struct IndexTemplate {
     pub name: Option<String>,
+    pub db_name: String,
     pub fields: Vec<String>,
     pub typ: IndexType,
 }
@@ -13,10 +14,12 @@ impl IndexTemplate {
         let fields = match self.typ {
             IndexType::Unique => Self::map_fields(self.fields, fields),
             IndexType::Normal => Self::map_fields(self.fields, fields),
+            IndexType::Fulltext => Self::map_fields(self.fields, fields),
         };
 
         Index {
             name: self.name,
+            db_name: self.db_name,
             typ: self.typ,
             fields,
         }
@@ -40,6 +43,8 @@ impl IndexTemplate {
 #[derive(Debug)]
 pub struct Index {
     pub name: Option<String>,
+    /// The name of the index in the database.
+    pub db_name: String,
     pub fields: Vec<ScalarFieldWeak>,
     pub typ: IndexType,
 }
@@ -54,4 +59,5 @@ impl Index {
 pub enum IndexType {
     Unique,
     Normal,
+    Fulltext,
 }
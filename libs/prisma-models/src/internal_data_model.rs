@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use datamodel::FieldArity;
 use once_cell::sync::OnceCell;
 use std::sync::{Arc, Weak};
 
@@ -10,12 +11,14 @@ pub struct InternalDataModelTemplate {
     pub models: Vec<ModelTemplate>,
     pub relations: Vec<RelationTemplate>,
     pub enums: Vec<InternalEnum>,
+    pub composite_types: Vec<InternalCompositeType>,
     pub version: Option<String>,
 }
 
 #[derive(Debug)]
 pub struct InternalDataModel {
     pub enums: Vec<InternalEnum>,
+    pub composite_types: Vec<InternalCompositeType>,
     version: Option<String>,
 
     /// Todo clarify / rename.
@@ -74,12 +77,46 @@ impl InternalEnumValue {
     }
 }
 
+/// A composite type, embedded into models as a JSON value.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct InternalCompositeType {
+    pub name: String,
+    pub fields: Vec<InternalCompositeTypeField>,
+}
+
+impl InternalCompositeType {
+    pub fn find_field(&self, name: &str) -> Option<&InternalCompositeTypeField> {
+        self.fields.iter().find(|f| f.name == name)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct InternalCompositeTypeField {
+    pub name: String,
+    pub type_identifier: TypeIdentifier,
+    pub arity: FieldArity,
+    pub internal_enum: Option<InternalEnum>,
+    /// The name of the composite type if the field embeds another composite type.
+    pub composite_type: Option<String>,
+}
+
+impl InternalCompositeTypeField {
+    pub fn is_list(&self) -> bool {
+        self.arity.is_list()
+    }
+
+    pub fn is_required(&self) -> bool {
+        self.arity.is_required()
+    }
+}
+
 impl InternalDataModelTemplate {
     pub fn build(self, db_name: String) -> InternalDataModelRef {
         let internal_data_model = Arc::new(InternalDataModel {
             models: OnceCell::new(),
             relations: OnceCell::new(),
             enums: self.enums,
+            composite_types: self.composite_types,
             version: self.version,
             db_name,
             relation_fields: OnceCell::new(),
@@ -126,6 +163,13 @@ impl InternalDataModel {
             .ok_or_else(|| DomainError::ModelNotFound { name: name.to_string() })
     }
 
+    pub fn find_composite_type(&self, name: &str) -> crate::Result<&InternalCompositeType> {
+        self.composite_types
+            .iter()
+            .find(|composite_type| composite_type.name == name)
+            .ok_or_else(|| DomainError::CompositeTypeNotFound { name: name.to_string() })
+    }
+
     pub fn find_relation(&self, name: &str) -> crate::Result<RelationWeakRef> {
         self.relations
             .get()
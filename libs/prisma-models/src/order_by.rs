@@ -13,6 +13,14 @@ impl OrderBy {
     }
 }
 
+/// Orders records by how well the given fields match a full-text search query.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OrderByRelevance {
+    pub fields: Vec<ScalarFieldRef>,
+    pub search: String,
+    pub sort_order: SortOrder,
+}
+
 pub trait IntoOrderBy {
     fn into_order_by(self, model: ModelRef) -> OrderBy;
 }
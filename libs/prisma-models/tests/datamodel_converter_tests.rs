@@ -490,6 +490,62 @@ fn implicit_many_to_many_relation() {
     post.assert_relation_field("parents");
 }
 
+#[test]
+fn ignored_models_and_fields_must_be_excluded() {
+    let datamodel = convert(
+        r#"
+            model User {
+                id       Int      @id
+                secret   String?  @ignore
+                logs     Log[]    @ignore
+            }
+
+            model Log {
+                message  String
+                userId   Int
+                user     User     @relation(fields: [userId], references: [id])
+
+                @@ignore
+            }
+        "#,
+    );
+
+    assert!(datamodel.find_model("Log").is_err());
+    assert!(datamodel.relations().is_empty());
+
+    let user = datamodel.assert_model("User");
+    user.assert_scalar_field("id");
+    assert!(user.fields().find_from_all("secret").is_err());
+    assert!(user.fields().find_from_all("logs").is_err());
+}
+
+#[test]
+fn computed_fields_must_not_be_writable() {
+    let datamodel = convert(
+        r#"
+            model OrderItem {
+                id       Int @id
+                price    Int
+                quantity Int
+                total    Int @computed("price * quantity")
+            }
+        "#,
+    );
+
+    let order_item = datamodel.assert_model("OrderItem");
+    order_item
+        .assert_scalar_field("total")
+        .assert_behaviour(FieldBehaviour::Computed);
+    order_item.assert_scalar_field("price").assert_no_behaviour();
+
+    let writable: Vec<String> = order_item
+        .fields()
+        .scalar_writable()
+        .map(|field| field.name.clone())
+        .collect();
+    assert_eq!(writable, vec!["id", "price", "quantity"]);
+}
+
 fn convert(datamodel: &str) -> Arc<InternalDataModel> {
     let datamodel = datamodel::parse_datamodel(datamodel).unwrap().subject;
     let template = DatamodelConverter::convert(&datamodel);
@@ -38,6 +38,40 @@ pub trait SqlSchemaDescriberBackend: Send + Sync + 'static {
 
     /// Get the database version.
     async fn version(&self, schema: &str) -> DescriberResult<Option<String>>;
+
+    /// Describe the default schema of the connection together with further schemas. Tables and enums of the
+    /// further schemas are marked with the name of their schema.
+    async fn describe_schemas(&self, default_schema: &str, other_schemas: &[String]) -> DescriberResult<SqlSchema> {
+        let mut sql_schema = self.describe(default_schema).await?;
+
+        for schema_name in other_schemas {
+            let other = self.describe(schema_name).await?;
+
+            sql_schema.tables.extend(other.tables.into_iter().map(|mut table| {
+                // The foreign keys were described relative to the other schema.
+                for foreign_key in table.foreign_keys.iter_mut() {
+                    foreign_key.referenced_schema = match foreign_key.referenced_schema.take() {
+                        None => Some(schema_name.clone()),
+                        Some(referenced_schema) if referenced_schema == default_schema => None,
+                        referenced_schema => referenced_schema,
+                    };
+                }
+
+                Table {
+                    schema: Some(schema_name.clone()),
+                    ..table
+                }
+            }));
+            sql_schema.enums.extend(other.enums.into_iter().map(|r#enum| Enum {
+                schema: Some(schema_name.clone()),
+                ..r#enum
+            }));
+            sql_schema.sequences.extend(other.sequences);
+            sql_schema.views.extend(other.views);
+        }
+
+        Ok(sql_schema)
+    }
 }
 
 #[derive(Serialize, Deserialize)]
@@ -56,6 +90,9 @@ pub struct SqlSchema {
     pub enums: Vec<Enum>,
     /// The schema's sequences, unique to Postgres.
     pub sequences: Vec<Sequence>,
+    /// The schema's views.
+    #[serde(default)]
+    pub views: Vec<View>,
 }
 
 impl SqlSchema {
@@ -64,6 +101,11 @@ impl SqlSchema {
         self.tables.iter().find(|x| x.name == name)
     }
 
+    /// Get a view.
+    pub fn get_view(&self, name: &str) -> Option<&View> {
+        self.views.iter().find(|v| v.name == name)
+    }
+
     /// Get an enum.
     pub fn get_enum(&self, name: &str) -> Option<&Enum> {
         self.enums.iter().find(|x| x.name == name)
@@ -77,7 +119,8 @@ impl SqlSchema {
                 tables,
                 enums,
                 sequences,
-            } if tables.is_empty() && enums.is_empty() && sequences.is_empty()
+                views,
+            } if tables.is_empty() && enums.is_empty() && sequences.is_empty() && views.is_empty()
         )
     }
 
@@ -92,6 +135,11 @@ impl SqlSchema {
         self.table(&name).unwrap()
     }
 
+    /// Get the table referenced by a foreign key.
+    pub fn referenced_table(&self, foreign_key: &ForeignKey) -> Option<&Table> {
+        self.tables.iter().find(|table| foreign_key.references(table))
+    }
+
     /// Get a sequence.
     pub fn get_sequence(&self, name: &str) -> Option<&Sequence> {
         self.sequences.iter().find(|x| x.name == name)
@@ -102,6 +150,7 @@ impl SqlSchema {
             tables: Vec::new(),
             enums: Vec::new(),
             sequences: Vec::new(),
+            views: Vec::new(),
         }
     }
 
@@ -123,6 +172,9 @@ impl SqlSchema {
 pub struct Table {
     /// The table's name.
     pub name: String,
+    /// The database schema of the table, if it is not the default schema of the connection.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
     /// The table's columns.
     pub columns: Vec<Column>,
     /// The table's indices.
@@ -131,6 +183,12 @@ pub struct Table {
     pub primary_key: Option<PrimaryKey>,
     /// The table's foreign keys.
     pub foreign_keys: Vec<ForeignKey>,
+    /// The table's check constraints.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub check_constraints: Vec<CheckConstraint>,
+    /// The comment on the table.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
 }
 
 impl Table {
@@ -176,6 +234,7 @@ impl Table {
             index.tpe == IndexType::Unique
                 && index.columns.len() == 1
                 && index.columns.contains(&column_name.to_owned())
+                && index.has_default_options()
         })
     }
 
@@ -194,6 +253,8 @@ pub enum IndexType {
     Unique,
     /// Normal type.
     Normal,
+    /// Full-text search type.
+    Fulltext,
 }
 
 impl IndexType {
@@ -212,12 +273,91 @@ pub struct Index {
     pub columns: Vec<String>,
     /// Type of index.
     pub tpe: IndexType,
+    /// The sort order and prefix length of each column, in the order of `columns`. Empty if all
+    /// columns use the defaults.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub column_options: Vec<IndexColumnOptions>,
+    /// The index access method. `None` for B-tree indexes, the default on every database.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub algorithm: Option<IndexAlgorithm>,
+    /// The `WHERE` predicate of a partial index.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub predicate: Option<String>,
 }
 
 impl Index {
     pub fn is_unique(&self) -> bool {
         self.tpe == IndexType::Unique
     }
+
+    /// Whether the index covers all rows, with the default access method and column options.
+    pub fn has_default_options(&self) -> bool {
+        self.column_options.is_empty() && self.algorithm.is_none() && self.predicate.is_none()
+    }
+
+    /// The options of the column at the given position in the index.
+    pub fn column_options(&self, idx: usize) -> IndexColumnOptions {
+        self.column_options.get(idx).copied().unwrap_or_default()
+    }
+
+    /// Appends a column to the index, keeping `column_options` empty as long as all columns use
+    /// the defaults.
+    pub(crate) fn push_column(&mut self, column: String, options: IndexColumnOptions) {
+        if self.column_options.is_empty() && options != IndexColumnOptions::default() {
+            self.column_options = vec![IndexColumnOptions::default(); self.columns.len()];
+        }
+
+        if !self.column_options.is_empty() {
+            self.column_options.push(options);
+        }
+
+        self.columns.push(column);
+    }
+}
+
+/// The options of a single column in an index.
+#[derive(PartialEq, Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexColumnOptions {
+    /// Sort order of the column.
+    pub sort_order: SortOrder,
+    /// The number of leading characters that are indexed (MySQL prefix indexes).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub length: Option<u32>,
+}
+
+/// The sort order of an index column.
+#[derive(PartialEq, Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+impl Default for SortOrder {
+    fn default() -> Self {
+        SortOrder::Asc
+    }
+}
+
+/// The access method of a non B-tree index (Postgres only).
+#[derive(PartialEq, Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum IndexAlgorithm {
+    Hash,
+    Gin,
+    Gist,
+    Brin,
+}
+
+/// A check constraint of a table.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckConstraint {
+    /// The constraint name. Unnamed check constraints only exist on SQLite.
+    pub name: Option<String>,
+    /// The boolean SQL expression, without the surrounding `CHECK (...)`.
+    pub expression: String,
 }
 
 /// The primary key of a table.
@@ -250,6 +390,12 @@ pub struct Column {
     pub default: Option<DefaultValue>,
     /// Is the column auto-incrementing?
     pub auto_increment: bool,
+    /// How the database computes the values of the column, if it is a generated column.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub generated: Option<GeneratedColumn>,
+    /// The comment on the column.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
 }
 
 impl Column {
@@ -258,6 +404,17 @@ impl Column {
     }
 }
 
+/// The generation of a column whose values the database computes from the other columns of the
+/// row, e.g. `GENERATED ALWAYS AS (price * quantity) STORED`.
+#[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GeneratedColumn {
+    /// The generation expression, as normalized by the database.
+    pub expression: String,
+    /// Whether the values are computed on write and stored, or computed on read (virtual).
+    pub stored: bool,
+}
+
 /// The type of a column.
 #[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -429,6 +586,9 @@ pub struct ForeignKey {
     pub columns: Vec<String>,
     /// Referenced table.
     pub referenced_table: String,
+    /// The database schema of the referenced table, if it is not the default schema of the connection.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub referenced_schema: Option<String>,
     /// Referenced columns.
     pub referenced_columns: Vec<String>,
     /// Action on deletion.
@@ -441,20 +601,50 @@ impl PartialEq for ForeignKey {
     fn eq(&self, other: &Self) -> bool {
         self.columns == other.columns
             && self.referenced_table == other.referenced_table
+            && self.referenced_schema == other.referenced_schema
             && self.referenced_columns == other.referenced_columns
     }
 }
 
+impl ForeignKey {
+    /// Whether the foreign key references the given table, in the table's database schema.
+    pub fn references(&self, table: &Table) -> bool {
+        self.referenced_table == table.name && self.referenced_schema == table.schema
+    }
+}
+
 /// A SQL enum.
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Enum {
     /// Enum name.
     pub name: String,
+    /// The database schema of the enum, if it is not the default schema of the connection.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
     /// Possible enum values.
     pub values: Vec<String>,
 }
 
+/// A database view.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct View {
+    /// View name.
+    pub name: String,
+    /// The SQL query defining the view, if the database exposes it.
+    pub definition: Option<String>,
+    /// View columns.
+    pub columns: Vec<Column>,
+}
+
+impl View {
+    /// Get a column.
+    pub fn column(&self, name: &str) -> Option<&Column> {
+        self.columns.iter().find(|c| c.name == name)
+    }
+}
+
 /// A SQL sequence.
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
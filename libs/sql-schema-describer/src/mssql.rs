@@ -75,19 +75,31 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
         let mut columns = self.get_all_columns(schema).await?;
         let mut indexes = self.get_all_indices(schema).await?;
         let mut foreign_keys = self.get_foreign_keys(schema).await?;
+        let mut check_constraints = self.get_check_constraints(schema).await?;
+        let mut table_comments = self.get_table_comments(schema).await?;
 
         let table_names = self.get_table_names(schema).await?;
         let mut tables = Vec::with_capacity(table_names.len());
 
         for table_name in table_names {
-            let table = self.get_table(&table_name, &mut columns, &mut indexes, &mut foreign_keys);
+            let table = self.get_table(
+                &table_name,
+                &mut columns,
+                &mut indexes,
+                &mut foreign_keys,
+                &mut check_constraints,
+                &mut table_comments,
+            );
             tables.push(table);
         }
 
+        let views = self.get_views(schema, &mut columns).await?;
+
         Ok(SqlSchema {
             tables,
             enums: vec![],
             sequences: vec![],
+            views,
         })
     }
 
@@ -139,6 +151,36 @@ impl SqlSchemaDescriber {
         Ok(names)
     }
 
+    #[tracing::instrument(skip(columns))]
+    async fn get_views(&self, schema: &str, columns: &mut HashMap<String, Vec<Column>>) -> DescriberResult<Vec<View>> {
+        let select = r#"
+            SELECT v.name AS view_name, OBJECT_DEFINITION(v.object_id) AS view_definition
+            FROM sys.views v
+            WHERE SCHEMA_NAME(v.schema_id) = @P1
+            AND v.is_ms_shipped = 0
+            ORDER BY v.name asc;
+        "#;
+
+        let rows = self.conn.query_raw(select, &[schema.into()]).await?;
+
+        let views: Vec<View> = rows
+            .into_iter()
+            .map(|row| {
+                let name = row.get_expect_string("view_name");
+
+                View {
+                    columns: columns.remove(&name).unwrap_or_default(),
+                    definition: row.get_string("view_definition"),
+                    name,
+                }
+            })
+            .collect();
+
+        trace!("Found views: {:?}", views);
+
+        Ok(views)
+    }
+
     #[tracing::instrument]
     async fn get_size(&self, schema: &str) -> DescriberResult<usize> {
         let sql = indoc! {r#"
@@ -176,18 +218,24 @@ impl SqlSchemaDescriber {
         columns: &mut HashMap<String, Vec<Column>>,
         indexes: &mut HashMap<String, (BTreeMap<String, Index>, Option<PrimaryKey>)>,
         foreign_keys: &mut HashMap<String, Vec<ForeignKey>>,
+        check_constraints: &mut HashMap<String, Vec<CheckConstraint>>,
+        comments: &mut HashMap<String, String>,
     ) -> Table {
         let columns = columns.remove(name).unwrap_or_default();
         let (indices, primary_key) = indexes.remove(name).unwrap_or_else(|| (BTreeMap::new(), None));
 
         let foreign_keys = foreign_keys.remove(name).unwrap_or_default();
+        let check_constraints = check_constraints.remove(name).unwrap_or_default();
 
         Table {
             name: name.to_string(),
+            schema: None,
             columns,
             foreign_keys,
             indices: indices.into_iter().map(|(_k, v)| v).collect(),
             primary_key,
+            check_constraints,
+            comment: comments.remove(name),
         }
     }
 
@@ -206,11 +254,17 @@ impl SqlSchemaDescriber {
                     END)                                           AS numeric_precision,
                 convert(int, CASE
                     WHEN c.system_type_id IN (40, 41, 42, 43, 58, 61) THEN NULL
-                    ELSE ODBCSCALE(c.system_type_id, c.scale) END) AS numeric_scale
+                    ELSE ODBCSCALE(c.system_type_id, c.scale) END) AS numeric_scale,
+                CONVERT(nvarchar(max), ep.value)                   AS comment
             FROM sys.columns c
-                    INNER JOIN sys.tables t ON c.object_id = t.object_id
+                    INNER JOIN sys.objects t ON c.object_id = t.object_id
+                    LEFT JOIN sys.extended_properties ep ON ep.major_id = c.object_id
+                        AND ep.minor_id = c.column_id
+                        AND ep.class = 1
+                        AND ep.name = 'MS_Description'
             WHERE OBJECT_SCHEMA_NAME(c.object_id) = @P1
             AND t.is_ms_shipped = 0
+            AND t.type IN ('U', 'V')
 
             ORDER BY COLUMNPROPERTY(c.object_id, c.name, 'ordinal');
         "#};
@@ -312,6 +366,8 @@ impl SqlSchemaDescriber {
                 tpe,
                 default,
                 auto_increment,
+                generated: None,
+                comment: col.get_string("comment"),
             });
         }
 
@@ -332,6 +388,8 @@ impl SqlSchemaDescriber {
                 ind.is_primary_key AS is_primary_key,
                 col.name AS column_name,
                 ic.key_ordinal AS seq_in_index,
+                ic.is_descending_key AS is_descending,
+                ind.filter_definition AS filter_definition,
                 t.name AS table_name
             FROM
                 sys.indexes ind
@@ -343,7 +401,6 @@ impl SqlSchemaDescriber {
                 sys.tables t ON ind.object_id = t.object_id
             WHERE SCHEMA_NAME(t.schema_id) = @P1
                 AND t.is_ms_shipped = 0
-                AND ind.filter_definition IS NULL
 
             ORDER BY index_name, seq_in_index
         "#};
@@ -361,6 +418,13 @@ impl SqlSchemaDescriber {
                     let seq_in_index = row.get_expect_i64("seq_in_index");
                     let pos = seq_in_index - 1;
                     let is_unique = row.get_expect_bool("is_unique");
+                    let column_options = IndexColumnOptions {
+                        sort_order: match row.get_expect_bool("is_descending") {
+                            true => SortOrder::Desc,
+                            false => SortOrder::Asc,
+                        },
+                        length: None,
+                    };
 
                     // Multi-column indices will return more than one row (with different column_name values).
                     // We cannot assume that one row corresponds to one index.
@@ -398,20 +462,23 @@ impl SqlSchemaDescriber {
                         };
                     } else if indexes_map.contains_key(&index_name) {
                         if let Some(index) = indexes_map.get_mut(&index_name) {
-                            index.columns.push(column_name);
+                            index.push_column(column_name, column_options);
                         }
                     } else {
-                        indexes_map.insert(
-                            index_name.clone(),
-                            Index {
-                                name: index_name,
-                                columns: vec![column_name],
-                                tpe: match is_unique {
-                                    true => IndexType::Unique,
-                                    false => IndexType::Normal,
-                                },
+                        let mut index = Index {
+                            name: index_name.clone(),
+                            columns: vec![],
+                            tpe: match is_unique {
+                                true => IndexType::Unique,
+                                false => IndexType::Normal,
                             },
-                        );
+                            column_options: vec![],
+                            algorithm: None,
+                            predicate: row.get_string("filter_definition"),
+                        };
+
+                        index.push_column(column_name, column_options);
+                        indexes_map.insert(index_name, index);
                     }
                 }
                 None => {
@@ -431,6 +498,56 @@ impl SqlSchemaDescriber {
         Ok(map)
     }
 
+    /// SQL Server stores comments as `MS_Description` extended properties.
+    async fn get_table_comments(&self, schema: &str) -> DescriberResult<HashMap<String, String>> {
+        let sql = indoc! {r#"
+            SELECT OBJECT_NAME(ep.major_id)         AS table_name,
+                CONVERT(nvarchar(max), ep.value)    AS comment
+            FROM sys.extended_properties AS ep
+                    INNER JOIN sys.objects t ON ep.major_id = t.object_id
+            WHERE ep.class = 1
+            AND ep.minor_id = 0
+            AND ep.name = 'MS_Description'
+            AND t.type = 'U'
+            AND OBJECT_SCHEMA_NAME(ep.major_id) = @P1
+        "#};
+
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| (row.get_expect_string("table_name"), row.get_expect_string("comment")))
+            .collect())
+    }
+
+    async fn get_check_constraints(&self, schema: &str) -> DescriberResult<HashMap<String, Vec<CheckConstraint>>> {
+        let sql = indoc! {r#"
+            SELECT OBJECT_NAME(cc.parent_object_id) AS table_name,
+                cc.name                             AS constraint_name,
+                cc.definition                       AS expression
+            FROM sys.check_constraints AS cc
+            WHERE cc.is_ms_shipped = 0
+            AND OBJECT_SCHEMA_NAME(cc.parent_object_id) = @P1
+            ORDER BY table_name, constraint_name
+        "#};
+
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
+        let mut map: HashMap<String, Vec<CheckConstraint>> = HashMap::new();
+
+        for row in rows.into_iter() {
+            trace!("Got check constraint row {:?}", row);
+
+            map.entry(row.get_expect_string("table_name"))
+                .or_default()
+                .push(CheckConstraint {
+                    name: Some(row.get_expect_string("constraint_name")),
+                    expression: row.get_expect_string("expression"),
+                });
+        }
+
+        Ok(map)
+    }
+
     async fn get_foreign_keys(&self, schema: &str) -> DescriberResult<HashMap<String, Vec<ForeignKey>>> {
         // Foreign keys covering multiple columns will return multiple rows, which we need to
         // merge.
@@ -440,6 +557,7 @@ impl SqlSchemaDescriber {
             SELECT OBJECT_NAME(fkc.constraint_object_id) AS constraint_name,
                 parent_table.name                     AS table_name,
                 referenced_table.name                 AS referenced_table_name,
+                SCHEMA_NAME(referenced_table.schema_id) AS referenced_schema_name,
                 parent_column.name                    AS column_name,
                 referenced_column.name                AS referenced_column_name,
                 fk.delete_referential_action          AS delete_referential_action,
@@ -474,6 +592,8 @@ impl SqlSchemaDescriber {
             let constraint_name = row.get_expect_string("constraint_name");
             let column = row.get_expect_string("column_name");
             let referenced_table = row.get_expect_string("referenced_table_name");
+            // Tables in the described schema are referenced without a schema.
+            let referenced_schema = Some(row.get_expect_string("referenced_schema_name")).filter(|name| name != schema);
             let referenced_column = row.get_expect_string("referenced_column_name");
             let ord_pos = row.get_expect_i64("ordinal_position");
 
@@ -516,6 +636,7 @@ impl SqlSchemaDescriber {
                         constraint_name: Some(constraint_name.clone()),
                         columns: vec![column],
                         referenced_table,
+                        referenced_schema,
                         referenced_columns: vec![referenced_column],
                         on_delete_action,
                         on_update_action,
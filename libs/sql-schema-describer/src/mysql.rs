@@ -63,18 +63,30 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
         let mut columns = Self::get_all_columns(&self.conn, schema, &flavour).await?;
         let mut indexes = Self::get_all_indexes(&self.conn, schema).await?;
         let mut fks = Self::get_foreign_keys(&self.conn, schema).await?;
+        let mut check_constraints = Self::get_check_constraints(&self.conn, schema, &flavour).await?;
+        let mut table_comments = self.get_table_comments(schema).await?;
 
         let mut enums = vec![];
         for table_name in &table_names {
-            let (table, enms) = self.get_table(table_name, &mut columns, &mut indexes, &mut fks);
+            let (table, enms) = self.get_table(
+                table_name,
+                &mut columns,
+                &mut indexes,
+                &mut fks,
+                &mut check_constraints,
+                &mut table_comments,
+            );
             tables.push(table);
             enums.extend(enms.iter().cloned());
         }
 
+        let views = self.get_views(schema, &mut columns, &mut enums).await?;
+
         Ok(SqlSchema {
             tables,
             enums,
             sequences: vec![],
+            views,
         })
     }
 
@@ -124,6 +136,38 @@ impl SqlSchemaDescriber {
         Ok(names)
     }
 
+    #[tracing::instrument(skip(self, columns, enums))]
+    async fn get_views(
+        &self,
+        schema: &str,
+        columns: &mut HashMap<String, (Vec<Column>, Vec<Enum>)>,
+        enums: &mut Vec<Enum>,
+    ) -> DescriberResult<Vec<View>> {
+        let sql = "SELECT table_name view_name, view_definition view_definition
+            FROM information_schema.views
+            WHERE table_schema = ?
+            ORDER BY table_name";
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
+
+        let mut views = Vec::with_capacity(rows.len());
+
+        for row in rows {
+            let name = row.get_expect_string("view_name");
+            let (view_columns, view_enums) = columns.remove(&name).unwrap_or_default();
+            enums.extend(view_enums);
+
+            views.push(View {
+                columns: view_columns,
+                definition: row.get_string("view_definition"),
+                name,
+            });
+        }
+
+        trace!("Found views: {:?}", views);
+
+        Ok(views)
+    }
+
     #[tracing::instrument(skip(self))]
     async fn get_size(&self, schema: &str) -> DescriberResult<usize> {
         let sql = r#"
@@ -148,31 +192,56 @@ impl SqlSchemaDescriber {
         Ok(size as usize)
     }
 
-    #[tracing::instrument(skip(self, columns, indexes, foreign_keys))]
+    #[tracing::instrument(skip(self, columns, indexes, foreign_keys, check_constraints, comments))]
     fn get_table(
         &self,
         name: &str,
         columns: &mut HashMap<String, (Vec<Column>, Vec<Enum>)>,
         indexes: &mut HashMap<String, (BTreeMap<String, Index>, Option<PrimaryKey>)>,
         foreign_keys: &mut HashMap<String, Vec<ForeignKey>>,
+        check_constraints: &mut HashMap<String, Vec<CheckConstraint>>,
+        comments: &mut HashMap<String, String>,
     ) -> (Table, Vec<Enum>) {
         let (columns, enums) = columns.remove(name).unwrap_or((vec![], vec![]));
         let (indices, primary_key) = indexes.remove(name).unwrap_or_else(|| (BTreeMap::new(), None));
 
         let foreign_keys = foreign_keys.remove(name).unwrap_or_default();
+        let check_constraints = check_constraints.remove(name).unwrap_or_default();
 
         (
             Table {
                 name: name.to_string(),
+                schema: None,
                 columns,
                 foreign_keys,
                 indices: indices.into_iter().map(|(_k, v)| v).collect(),
                 primary_key,
+                check_constraints,
+                comment: comments.remove(name),
             },
             enums,
         )
     }
 
+    /// MySQL reports tables without comment with an empty comment.
+    #[tracing::instrument(skip(self))]
+    async fn get_table_comments(&self, schema: &str) -> DescriberResult<HashMap<String, String>> {
+        let sql = "SELECT table_name table_name, table_comment table_comment
+            FROM information_schema.tables
+            WHERE table_schema = ? AND table_type = 'BASE TABLE' AND table_comment <> ''";
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                (
+                    row.get_expect_string("table_name"),
+                    row.get_expect_string("table_comment"),
+                )
+            })
+            .collect())
+    }
+
     async fn get_all_columns(
         conn: &dyn Queryable,
         schema_name: &str,
@@ -193,6 +262,7 @@ impl SqlSchemaDescriber {
                 column_default column_default,
                 is_nullable is_nullable,
                 extra extra,
+                column_comment column_comment,
                 table_name table_name
             FROM information_schema.columns
             WHERE table_schema = ?
@@ -200,6 +270,7 @@ impl SqlSchemaDescriber {
         ";
 
         let mut map = HashMap::new();
+        let mut generated_columns = Self::get_generated_columns(conn, schema_name).await?;
 
         let rows = conn.query_raw(sql, &[schema_name.into()]).await?;
 
@@ -249,6 +320,8 @@ impl SqlSchemaDescriber {
             let extra = col.get_expect_string("extra").to_lowercase();
             let auto_increment = matches!(extra.as_str(), "auto_increment");
 
+            let generated = generated_columns.remove(&(table_name.clone(), name.clone()));
+
             let entry = map.entry(table_name).or_insert((Vec::new(), Vec::new()));
 
             if let Some(enm) = enum_option {
@@ -283,6 +356,10 @@ impl SqlSchemaDescriber {
                                 Some(PrismaValue::Int(0)) => DefaultValue::value(false),
                                 _ => DefaultValue::db_generated(default_string),
                             },
+                            // MySQL 8 marks expression defaults, e.g. `(uuid())`, as `DEFAULT_GENERATED`.
+                            ColumnTypeFamily::String if extra.contains("default_generated") => {
+                                DefaultValue::db_generated(default_string)
+                            }
                             ColumnTypeFamily::String => DefaultValue::value(PrismaValue::String(
                                 Self::unescape_and_unquote_default_string(default_string, flavour),
                             )),
@@ -308,6 +385,8 @@ impl SqlSchemaDescriber {
                 tpe,
                 default,
                 auto_increment,
+                generated,
+                comment: col.get_string("column_comment").filter(|comment| !comment.is_empty()),
             };
 
             entry.0.push(col);
@@ -316,6 +395,65 @@ impl SqlSchemaDescriber {
         Ok(map)
     }
 
+    /// The generated columns of the schema, keyed by table and column name.
+    async fn get_generated_columns(
+        conn: &dyn Queryable,
+        schema_name: &str,
+    ) -> DescriberResult<HashMap<(String, String), GeneratedColumn>> {
+        let mut map = HashMap::new();
+
+        // The `generation_expression` column only exists on MySQL 5.7+ and MariaDB 10.2+.
+        let sql = "
+            SELECT COUNT(*) AS count
+            FROM information_schema.columns
+            WHERE table_schema = 'information_schema' AND table_name = 'COLUMNS'
+                AND column_name = 'GENERATION_EXPRESSION'
+        ";
+
+        let rows = conn.query_raw(sql, &[]).await?;
+
+        if rows.first().and_then(|row| row.get_i64("count")).unwrap_or(0) == 0 {
+            return Ok(map);
+        }
+
+        let sql = "
+            SELECT
+                table_name table_name,
+                column_name column_name,
+                generation_expression generation_expression,
+                extra extra
+            FROM information_schema.columns
+            WHERE table_schema = ? AND generation_expression IS NOT NULL AND generation_expression <> ''
+        ";
+
+        let rows = conn.query_raw(sql, &[schema_name.into()]).await?;
+
+        for row in rows.into_iter() {
+            trace!("Got generated column row {:?}", row);
+
+            // `DEFAULT_GENERATED` in `extra` marks expression defaults, not generated columns.
+            let extra = row.get_expect_string("extra").to_lowercase();
+            let stored = match extra.as_str() {
+                "stored generated" | "persistent" => true,
+                "virtual generated" | "virtual" => false,
+                _ => continue,
+            };
+
+            let generated = GeneratedColumn {
+                expression: row.get_expect_string("generation_expression"),
+                stored,
+            };
+
+            let key = (
+                row.get_expect_string("table_name"),
+                row.get_expect_string("column_name"),
+            );
+            map.insert(key, generated);
+        }
+
+        Ok(map)
+    }
+
     async fn get_all_indexes(
         conn: &dyn Queryable,
         schema_name: &str,
@@ -330,8 +468,11 @@ impl SqlSchemaDescriber {
             SELECT DISTINCT
                 index_name AS index_name,
                 non_unique AS non_unique,
+                index_type AS index_type,
                 column_name AS column_name,
                 seq_in_index AS seq_in_index,
+                collation AS collation,
+                sub_part AS sub_part,
                 table_name AS table_name
             FROM INFORMATION_SCHEMA.STATISTICS
             WHERE table_schema = ?
@@ -348,6 +489,14 @@ impl SqlSchemaDescriber {
                     let seq_in_index = row.get_expect_i64("seq_in_index");
                     let pos = seq_in_index - 1;
                     let is_unique = !row.get_expect_bool("non_unique");
+                    let is_fulltext = row.get_expect_string("index_type") == "FULLTEXT";
+                    let column_options = IndexColumnOptions {
+                        sort_order: match row.get_string("collation").as_deref() {
+                            Some("D") => SortOrder::Desc,
+                            _ => SortOrder::Asc,
+                        },
+                        length: row.get_i64("sub_part").map(|sub_part| sub_part as u32),
+                    };
 
                     // Multi-column indices will return more than one row (with different column_name values).
                     // We cannot assume that one row corresponds to one index.
@@ -381,20 +530,24 @@ impl SqlSchemaDescriber {
                         };
                     } else if indexes_map.contains_key(&index_name) {
                         if let Some(index) = indexes_map.get_mut(&index_name) {
-                            index.columns.push(column_name);
+                            index.push_column(column_name, column_options);
                         }
                     } else {
-                        indexes_map.insert(
-                            index_name.clone(),
-                            Index {
-                                name: index_name,
-                                columns: vec![column_name],
-                                tpe: match is_unique {
-                                    true => IndexType::Unique,
-                                    false => IndexType::Normal,
-                                },
+                        let mut index = Index {
+                            name: index_name.clone(),
+                            columns: vec![],
+                            tpe: match (is_unique, is_fulltext) {
+                                (_, true) => IndexType::Fulltext,
+                                (true, false) => IndexType::Unique,
+                                (false, false) => IndexType::Normal,
                             },
-                        );
+                            column_options: vec![],
+                            algorithm: None,
+                            predicate: None,
+                        };
+
+                        index.push_column(column_name, column_options);
+                        indexes_map.insert(index_name, index);
                     }
                 }
                 None => {
@@ -414,6 +567,81 @@ impl SqlSchemaDescriber {
         Ok(map)
     }
 
+    async fn get_check_constraints(
+        conn: &dyn Queryable,
+        schema_name: &str,
+        flavour: &Flavour,
+    ) -> DescriberResult<HashMap<String, Vec<CheckConstraint>>> {
+        let mut map: HashMap<String, Vec<CheckConstraint>> = HashMap::new();
+
+        // `information_schema.check_constraints` only exists on MySQL 8.0.16+ and MariaDB 10.2+.
+        let sql = "
+            SELECT COUNT(*) AS count
+            FROM information_schema.tables
+            WHERE table_schema = 'information_schema' AND table_name = 'CHECK_CONSTRAINTS'
+        ";
+
+        let rows = conn.query_raw(sql, &[]).await?;
+
+        if rows.first().and_then(|row| row.get_i64("count")).unwrap_or(0) == 0 {
+            return Ok(map);
+        }
+
+        // On MySQL, check constraint names are unique per database and the table is only found in
+        // `table_constraints`. MariaDB scopes the names to the table and has a `table_name` column.
+        let sql = match flavour {
+            Flavour::Mysql => {
+                "
+                SELECT
+                    tc.table_name table_name,
+                    cc.constraint_name constraint_name,
+                    cc.check_clause check_clause
+                FROM information_schema.check_constraints AS cc
+                INNER JOIN information_schema.table_constraints AS tc ON
+                    tc.constraint_schema = cc.constraint_schema
+                    AND tc.constraint_name = cc.constraint_name
+                    AND tc.constraint_type = 'CHECK'
+                WHERE cc.constraint_schema = ?
+                ORDER BY table_name, constraint_name
+                "
+            }
+            Flavour::MariaDb => {
+                "
+                SELECT
+                    cc.table_name table_name,
+                    cc.constraint_name constraint_name,
+                    cc.check_clause check_clause
+                FROM information_schema.check_constraints AS cc
+                WHERE cc.constraint_schema = ?
+                ORDER BY table_name, constraint_name
+                "
+            }
+        };
+
+        let rows = conn.query_raw(sql, &[schema_name.into()]).await?;
+
+        for row in rows.into_iter() {
+            trace!("Got check constraint row {:?}", row);
+
+            let name = row.get_expect_string("constraint_name");
+            let expression = row.get_expect_string("check_clause");
+
+            // MariaDB implements `JSON` columns as `LONGTEXT` with an implicit check constraint.
+            if expression == format!("json_valid(`{}`)", name) {
+                continue;
+            }
+
+            map.entry(row.get_expect_string("table_name"))
+                .or_default()
+                .push(CheckConstraint {
+                    name: Some(name),
+                    expression,
+                });
+        }
+
+        Ok(map)
+    }
+
     async fn get_foreign_keys(
         conn: &dyn Queryable,
         schema_name: &str,
@@ -496,6 +724,7 @@ impl SqlSchemaDescriber {
                         constraint_name: Some(constraint_name.clone()),
                         columns: vec![column],
                         referenced_table,
+                        referenced_schema: None,
                         referenced_columns: vec![referenced_column],
                         on_delete_action,
                         on_update_action,
@@ -644,6 +873,7 @@ impl SqlSchemaDescriber {
                 tpe,
                 Some(Enum {
                     name: name.clone(),
+                    schema: None,
                     values: Self::extract_enum_values(&full_data_type),
                 }),
             ),
@@ -3,12 +3,13 @@
 use super::*;
 use crate::getters::Getter;
 use crate::parsers::Parser;
+use bigdecimal::BigDecimal;
 use native_types::{NativeType, PostgresType};
 use quaint::connector::ResultRow;
 use quaint::{prelude::Queryable, single::Quaint};
 use regex::Regex;
 use serde_json::from_str;
-use std::{borrow::Cow, collections::HashMap, convert::TryInto};
+use std::{borrow::Cow, collections::HashMap, convert::TryInto, str::FromStr};
 use tracing::trace;
 
 #[derive(Debug)]
@@ -39,18 +40,30 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
         let mut columns = self.get_columns(schema, &enums, &sequences).await?;
         let mut foreign_keys = self.get_foreign_keys(schema).await?;
         let mut indexes = self.get_indices(schema, &sequences).await?;
+        let mut check_constraints = self.get_check_constraints(schema).await?;
+        let mut table_comments = self.get_table_comments(schema).await?;
 
         let table_names = self.get_table_names(schema).await?;
         let mut tables = Vec::with_capacity(table_names.len());
 
         for table_name in &table_names {
-            tables.push(self.get_table(&table_name, &mut columns, &mut foreign_keys, &mut indexes));
+            tables.push(self.get_table(
+                &table_name,
+                &mut columns,
+                &mut foreign_keys,
+                &mut indexes,
+                &mut check_constraints,
+                &mut table_comments,
+            ));
         }
 
+        let views = self.get_views(schema, &mut columns).await?;
+
         Ok(SqlSchema {
             enums,
             sequences,
             tables,
+            views,
         })
     }
 
@@ -98,7 +111,7 @@ impl SqlSchemaDescriber {
         let sql = "
             SELECT table_name as table_name FROM information_schema.tables
             WHERE table_schema = $1
-            -- Views are not supported yet
+            -- Views are described separately, see `get_views`
             AND table_type = 'BASE TABLE'
             ORDER BY table_name";
         let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
@@ -112,6 +125,33 @@ impl SqlSchemaDescriber {
         Ok(names)
     }
 
+    #[tracing::instrument(skip(columns))]
+    async fn get_views(&self, schema: &str, columns: &mut HashMap<String, Vec<Column>>) -> DescriberResult<Vec<View>> {
+        let sql = "
+            SELECT table_name AS view_name, view_definition AS view_definition
+            FROM information_schema.views
+            WHERE table_schema = $1
+            ORDER BY table_name";
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
+
+        let views: Vec<View> = rows
+            .into_iter()
+            .map(|row| {
+                let name = row.get_expect_string("view_name");
+
+                View {
+                    columns: columns.remove(&name).unwrap_or_default(),
+                    definition: row.get_string("view_definition"),
+                    name,
+                }
+            })
+            .collect();
+
+        trace!("Found views: {:?}", views);
+
+        Ok(views)
+    }
+
     #[tracing::instrument]
     async fn get_size(&self, schema: &str) -> DescriberResult<usize> {
         let sql =
@@ -126,26 +166,85 @@ impl SqlSchemaDescriber {
         Ok(size.try_into().expect("size is not a valid usize"))
     }
 
-    #[tracing::instrument(skip(columns, foreign_keys, indices))]
+    #[tracing::instrument(skip(columns, foreign_keys, indices, check_constraints, comments))]
     fn get_table(
         &self,
         name: &str,
         columns: &mut HashMap<String, Vec<Column>>,
         foreign_keys: &mut HashMap<String, Vec<ForeignKey>>,
         indices: &mut HashMap<String, (Vec<Index>, Option<PrimaryKey>)>,
+        check_constraints: &mut HashMap<String, Vec<CheckConstraint>>,
+        comments: &mut HashMap<String, String>,
     ) -> Table {
         let (indices, primary_key) = indices.remove(name).unwrap_or_else(|| (Vec::new(), None));
         let foreign_keys = foreign_keys.remove(name).unwrap_or_else(Vec::new);
         let columns = columns.remove(name).unwrap_or_default();
+        let check_constraints = check_constraints.remove(name).unwrap_or_default();
+        let comment = comments.remove(name);
         Table {
             name: name.to_string(),
+            schema: None,
             columns,
             foreign_keys,
             indices,
             primary_key,
+            check_constraints,
+            comment,
         }
     }
 
+    #[tracing::instrument]
+    async fn get_table_comments(&self, schema: &str) -> DescriberResult<HashMap<String, String>> {
+        let sql = r#"
+            SELECT
+                cl.relname AS table_name,
+                obj_description(cl.oid, 'pg_class') AS comment
+            FROM pg_class cl
+            JOIN pg_namespace ns ON cl.relnamespace = ns.oid
+            WHERE ns.nspname = $1 AND cl.relkind = 'r' AND obj_description(cl.oid, 'pg_class') IS NOT NULL
+        "#;
+
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| (row.get_expect_string("table_name"), row.get_expect_string("comment")))
+            .collect())
+    }
+
+    #[tracing::instrument]
+    async fn get_check_constraints(&self, schema: &str) -> DescriberResult<HashMap<String, Vec<CheckConstraint>>> {
+        let sql = r#"
+            SELECT
+                cl.relname AS table_name,
+                con.conname AS constraint_name,
+                pg_get_expr(con.conbin, con.conrelid) AS expression
+            FROM pg_constraint con
+            JOIN pg_class cl ON con.conrelid = cl.oid
+            JOIN pg_namespace ns ON cl.relnamespace = ns.oid
+            WHERE ns.nspname = $1 AND con.contype = 'c'
+            ORDER BY table_name, constraint_name"#;
+
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await?;
+        let mut check_constraints: HashMap<String, Vec<CheckConstraint>> = HashMap::new();
+
+        for row in rows.into_iter() {
+            trace!("Got check constraint row {:?}", row);
+
+            let check_constraint = CheckConstraint {
+                name: Some(row.get_expect_string("constraint_name")),
+                expression: row.get_expect_string("expression"),
+            };
+
+            check_constraints
+                .entry(row.get_expect_string("table_name"))
+                .or_default()
+                .push(check_constraint);
+        }
+
+        Ok(check_constraints)
+    }
+
     async fn get_columns(
         &self,
         schema: &str,
@@ -154,6 +253,8 @@ impl SqlSchemaDescriber {
     ) -> DescriberResult<HashMap<String, Vec<Column>>> {
         let mut columns: HashMap<String, Vec<Column>> = HashMap::new();
 
+        // The `generation_expression` column only exists from Postgres 12 on, so it is read through
+        // `to_jsonb` to get NULL instead of an error on older versions.
         let sql = r#"
             SELECT
                info.table_name,
@@ -169,7 +270,9 @@ impl SqlSchemaDescriber {
                 info.is_nullable,
                 info.is_identity,
                 info.data_type,
-                info.character_maximum_length
+                info.character_maximum_length,
+                to_jsonb(info) ->> 'generation_expression' AS generation_expression,
+                col_description(att.attrelid, att.attnum) AS comment
             FROM information_schema.columns info
             JOIN pg_attribute  att on att.attname = info.column_name
             And att.attrelid = (
@@ -204,11 +307,21 @@ impl SqlSchemaDescriber {
             let auto_increment =
                 is_identity || matches!(default.as_ref().map(|d| d.kind()), Some(DefaultKind::SEQUENCE(_)));
 
+            // Postgres only has stored generated columns.
+            let generated = col
+                .get_string("generation_expression")
+                .map(|expression| GeneratedColumn {
+                    expression,
+                    stored: true,
+                });
+
             let col = Column {
                 name,
                 tpe,
                 default,
                 auto_increment,
+                generated,
+                comment: col.get_string("comment"),
             };
 
             columns.entry(table_name).or_default().push(col);
@@ -285,6 +398,7 @@ impl SqlSchemaDescriber {
                 con.oid as "con_id",
                 att2.attname as "child_column",
                 cl.relname as "parent_table",
+                parent_ns.nspname as "parent_schema",
                 att.attname as "parent_column",
                 con.confdeltype,
                 con.confupdtype,
@@ -317,6 +431,8 @@ impl SqlSchemaDescriber {
                 att.attrelid = con.confrelid and att.attnum = con.child
             JOIN pg_class cl on
                 cl.oid = con.confrelid
+            JOIN pg_namespace parent_ns on
+                parent_ns.oid = cl.relnamespace
             JOIN pg_attribute att2 on
                 att2.attrelid = con.conrelid and att2.attnum = con.parent
             ORDER BY con_id, con.colidx"#;
@@ -331,6 +447,8 @@ impl SqlSchemaDescriber {
             let id = row.get_expect_i64("con_id");
             let column = row.get_expect_string("child_column");
             let referenced_table = row.get_expect_string("parent_table");
+            // Tables in the described schema are referenced without a schema.
+            let referenced_schema = Some(row.get_expect_string("parent_schema")).filter(|parent| parent != schema);
             let referenced_column = row.get_expect_string("parent_column");
             let table_name = row.get_expect_string("table_name");
             let confdeltype = row.get_expect_char("confdeltype");
@@ -363,6 +481,7 @@ impl SqlSchemaDescriber {
                         constraint_name: Some(constraint_name),
                         columns: vec![column],
                         referenced_table,
+                        referenced_schema,
                         referenced_columns: vec![referenced_column],
                         on_delete_action,
                         on_update_action,
@@ -410,6 +529,10 @@ impl SqlSchemaDescriber {
             rawIndex.indisprimary AS is_primary_key,
             tableInfos.relname AS table_name,
             rawIndex.indkeyidx,
+            -- bit 0 of indoption is set for DESC columns
+            (rawIndex.indoption[rawIndex.indkeyidx] & 1) = 1 AS is_descending,
+            rawIndex.predicate,
+            accessMethod.amname AS algorithm,
             pg_get_serial_sequence('"' || $1 || '"."' || tableInfos.relname || '"', columnInfos.attname) AS sequence_name
         FROM
             -- pg_class stores infos about tables, indices etc: https://www.postgresql.org/docs/current/catalog-pg-class.html
@@ -423,15 +546,17 @@ impl SqlSchemaDescriber {
                     indisunique,
                     indisprimary,
                     pg_index.indkey AS indkey,
+                    pg_index.indoption AS indoption,
+                    pg_get_expr(pg_index.indpred, pg_index.indrelid) AS predicate,
                     generate_subscripts(pg_index.indkey, 1) AS indkeyidx
                 FROM pg_index
-                -- ignores partial indexes
-                Where indpred is Null
-                GROUP BY indrelid, indexrelid, indisunique, indisprimary, indkeyidx, indkey
+                GROUP BY indrelid, indexrelid, indisunique, indisprimary, indkeyidx, indkey, indoption, predicate
                 ORDER BY indrelid, indexrelid, indkeyidx
             ) rawIndex,
             -- pg_attribute stores infos about columns: https://www.postgresql.org/docs/current/catalog-pg-attribute.html
             pg_attribute columnInfos,
+            -- pg_am stores the index access methods: https://www.postgresql.org/docs/current/catalog-pg-am.html
+            pg_am accessMethod,
             -- pg_namespace stores info about the schema
             pg_namespace schemaInfo
         WHERE
@@ -439,6 +564,8 @@ impl SqlSchemaDescriber {
             tableInfos.oid = rawIndex.indrelid
             -- find index info
             AND indexInfos.oid = rawIndex.indexrelid
+            -- find the access method of the index
+            AND accessMethod.oid = indexInfos.relam
             -- find table columns
             AND columnInfos.attrelid = tableInfos.oid
             AND columnInfos.attnum = rawIndex.indkey[rawIndex.indkeyidx]
@@ -447,7 +574,7 @@ impl SqlSchemaDescriber {
             -- we only consider stuff out of one specific schema
             AND tableInfos.relnamespace = schemaInfo.oid
             AND schemaInfo.nspname = $1
-        GROUP BY tableInfos.relname, indexInfos.relname, rawIndex.indisunique, rawIndex.indisprimary, columnInfos.attname, rawIndex.indkeyidx
+        GROUP BY tableInfos.relname, indexInfos.relname, rawIndex.indisunique, rawIndex.indisprimary, columnInfos.attname, rawIndex.indkeyidx, rawIndex.indoption, rawIndex.predicate, accessMethod.amname
         ORDER BY rawIndex.indkeyidx
         "#;
 
@@ -459,11 +586,19 @@ impl SqlSchemaDescriber {
                 column_name,
                 is_primary_key,
                 is_unique,
+                is_descending,
+                predicate,
+                algorithm,
                 name,
                 sequence_name,
                 table_name,
             } = quaint::serde::from_row::<IndexRow>(index).unwrap();
 
+            let column_options = IndexColumnOptions {
+                sort_order: if is_descending { SortOrder::Desc } else { SortOrder::Asc },
+                length: None,
+            };
+
             if is_primary_key {
                 let entry: &mut (Vec<_>, Option<PrimaryKey>) =
                     indexes_map.entry(table_name).or_insert_with(|| (Vec::new(), None));
@@ -493,23 +628,88 @@ impl SqlSchemaDescriber {
                 let entry: &mut (Vec<Index>, _) = indexes_map.entry(table_name).or_insert_with(|| (Vec::new(), None));
 
                 if let Some(existing_index) = entry.0.iter_mut().find(|idx| idx.name == name) {
-                    existing_index.columns.push(column_name);
+                    existing_index.push_column(column_name, column_options);
                 } else {
-                    entry.0.push(Index {
+                    let mut index = Index {
                         name,
-                        columns: vec![column_name],
+                        columns: vec![],
                         tpe: match is_unique {
                             true => IndexType::Unique,
                             false => IndexType::Normal,
                         },
-                    })
+                        column_options: vec![],
+                        algorithm: match algorithm.as_str() {
+                            "hash" => Some(IndexAlgorithm::Hash),
+                            "gin" => Some(IndexAlgorithm::Gin),
+                            "gist" => Some(IndexAlgorithm::Gist),
+                            "brin" => Some(IndexAlgorithm::Brin),
+                            _ => None,
+                        },
+                        predicate,
+                    };
+
+                    index.push_column(column_name, column_options);
+                    entry.0.push(index);
                 }
             }
         }
 
+        self.get_fulltext_indices(schema, &mut indexes_map).await?;
+
         Ok(indexes_map)
     }
 
+    /// Full-text indexes are GIN indexes over `to_tsvector` expressions, which the column based
+    /// query in `get_indices` cannot see. We read them from their definition instead.
+    async fn get_fulltext_indices(
+        &self,
+        schema: &str,
+        indexes_map: &mut HashMap<String, (Vec<Index>, Option<PrimaryKey>)>,
+    ) -> DescriberResult<()> {
+        let sql = r#"
+        SELECT
+            tableInfos.relname AS table_name,
+            indexInfos.relname AS name,
+            pg_get_indexdef(rawIndex.indexrelid) AS definition
+        FROM pg_index rawIndex
+        INNER JOIN pg_class tableInfos ON tableInfos.oid = rawIndex.indrelid
+        INNER JOIN pg_class indexInfos ON indexInfos.oid = rawIndex.indexrelid
+        INNER JOIN pg_am accessMethod ON accessMethod.oid = indexInfos.relam
+        INNER JOIN pg_namespace schemaInfo ON schemaInfo.oid = tableInfos.relnamespace
+        WHERE schemaInfo.nspname = $1
+            AND accessMethod.amname = 'gin'
+            AND rawIndex.indexprs IS NOT NULL
+            AND rawIndex.indpred IS NULL
+        "#;
+
+        let rows = self.conn.query_raw(&sql, &[schema.into()]).await?;
+
+        for row in rows {
+            trace!("Got full-text index candidate: {:?}", row);
+            let table_name = row.get_expect_string("table_name");
+            let name = row.get_expect_string("name");
+            let definition = row.get_expect_string("definition");
+
+            let columns = match fulltext_index_columns(&definition) {
+                Some(columns) => columns,
+                None => continue,
+            };
+
+            let entry: &mut (Vec<Index>, _) = indexes_map.entry(table_name).or_insert_with(|| (Vec::new(), None));
+
+            entry.0.push(Index {
+                name,
+                columns,
+                tpe: IndexType::Fulltext,
+                column_options: vec![],
+                algorithm: None,
+                predicate: None,
+            });
+        }
+
+        Ok(())
+    }
+
     #[tracing::instrument]
     async fn get_sequences(&self, schema: &str) -> DescriberResult<Vec<Sequence>> {
         let sql = "SELECT sequence_name
@@ -554,7 +754,11 @@ impl SqlSchemaDescriber {
 
         let mut enums: Vec<Enum> = enum_values
             .into_iter()
-            .map(|(k, v)| Enum { name: k, values: v })
+            .map(|(k, v)| Enum {
+                name: k,
+                schema: None,
+                values: v,
+            })
             .collect();
 
         enums.sort_by(|a, b| Ord::cmp(&a.name, &b.name));
@@ -570,6 +774,12 @@ impl SqlSchemaDescriber {
             Some(param_value) => match param_value.to_string() {
                 None => None,
                 Some(x) if x.starts_with("NULL") => None,
+                // List defaults come in the '{...}'::type[] form.
+                Some(default_string) if tpe.arity.is_list() => Some(
+                    parse_list_default(&default_string, &tpe.family)
+                        .map(DefaultValue::value)
+                        .unwrap_or_else(|| DefaultValue::db_generated(default_string)),
+                ),
                 Some(default_string) => {
                     Some(match &tpe.family {
                         ColumnTypeFamily::Int => match Self::parse_int(&default_string) {
@@ -648,6 +858,9 @@ struct IndexRow {
     column_name: String,
     is_unique: bool,
     is_primary_key: bool,
+    is_descending: bool,
+    predicate: Option<String>,
+    algorithm: String,
     table_name: String,
     sequence_name: Option<String>,
 }
@@ -737,6 +950,45 @@ fn get_column_type(row: &ResultRow, enums: &[Enum]) -> ColumnType {
     }
 }
 
+static RE_TSVECTOR: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"to_tsvector\('simple'::regconfig, \(?("(?:[^"]|"")+"|[^\s(),:]+)\)?(?:::text)?\)"#)
+        .expect("compile regex")
+});
+
+/// Returns the columns of a full-text index definition, in the shape rendered by the migration
+/// engine: `USING gin (to_tsvector('simple', col_a), to_tsvector('simple', col_b))`. Any other
+/// GIN expression index is not a full-text index for our purposes.
+fn fulltext_index_columns(definition: &str) -> Option<Vec<String>> {
+    let start = definition.find("USING gin (")? + "USING gin (".len();
+    let expressions = definition[start..].strip_suffix(')')?;
+
+    let mut columns = Vec::new();
+    let mut cursor = 0;
+
+    for captures in RE_TSVECTOR.captures_iter(expressions) {
+        let whole = captures.get(0)?;
+
+        if !matches!(expressions[cursor..whole.start()].trim(), "" | ",") {
+            return None;
+        }
+
+        let column = captures.get(1)?.as_str();
+        let column = match column.strip_prefix('"').and_then(|c| c.strip_suffix('"')) {
+            Some(quoted) => quoted.replace("\"\"", "\""),
+            None => column.to_owned(),
+        };
+
+        columns.push(column);
+        cursor = whole.end();
+    }
+
+    if columns.is_empty() || !expressions[cursor..].trim().is_empty() {
+        return None;
+    }
+
+    Some(columns)
+}
+
 static RE_SEQ: Lazy<Regex> = Lazy::new(|| Regex::new("^(?:.+\\.)?\"?([^.\"]+)\"?").expect("compile regex"));
 
 static AUTOINCREMENT_REGEX: Lazy<Regex> = Lazy::new(|| {
@@ -774,6 +1026,85 @@ fn unsuffix_default_literal<'a>(literal: &'a str, data_type: &str, full_data_typ
     Some(first_capture.into())
 }
 
+/// Parses array literals like `'{1,2,3}'::integer[]` or `'{"a b",c}'::text[]` into a list value.
+/// `ARRAY[...]` expressions, nested arrays and NULL elements are not handled.
+fn parse_list_default(default: &str, family: &ColumnTypeFamily) -> Option<PrismaValue> {
+    static POSTGRES_ARRAY_LITERAL_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"(?ms)^'(.*)'::.+\[\]$"#).unwrap());
+
+    let literal = POSTGRES_ARRAY_LITERAL_RE.captures(default)?.get(1)?.as_str();
+    let literal = literal.replace("''", "'");
+    let elements = literal.strip_prefix('{')?.strip_suffix('}')?;
+
+    split_array_literal(elements)?
+        .into_iter()
+        .map(|(element, quoted)| match family {
+            ColumnTypeFamily::Int => element.parse().ok().map(PrismaValue::Int),
+            ColumnTypeFamily::BigInt => element.parse().ok().map(PrismaValue::BigInt),
+            ColumnTypeFamily::Float | ColumnTypeFamily::Decimal => {
+                BigDecimal::from_str(&element).ok().map(PrismaValue::Float)
+            }
+            ColumnTypeFamily::Boolean => match element.as_str() {
+                "t" | "true" => Some(PrismaValue::Boolean(true)),
+                "f" | "false" => Some(PrismaValue::Boolean(false)),
+                _ => None,
+            },
+            _ if !quoted && element.eq_ignore_ascii_case("null") => None,
+            ColumnTypeFamily::String => Some(PrismaValue::String(element)),
+            ColumnTypeFamily::Enum(_) => Some(PrismaValue::Enum(element)),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()
+        .map(PrismaValue::List)
+}
+
+/// Splits the elements of an array literal (without the braces), returning each element and
+/// whether it was quoted.
+fn split_array_literal(elements: &str) -> Option<Vec<(String, bool)>> {
+    let mut result = Vec::new();
+
+    if elements.is_empty() {
+        return Some(result);
+    }
+
+    let mut chars = elements.chars();
+
+    loop {
+        let mut element = String::new();
+        let mut quoted = false;
+
+        match chars.next()? {
+            '"' => {
+                quoted = true;
+
+                loop {
+                    match chars.next()? {
+                        '\\' => element.push(chars.next()?),
+                        '"' => break,
+                        c => element.push(c),
+                    }
+                }
+            }
+            '{' => return None,
+            c => element.push(c),
+        }
+
+        loop {
+            match chars.next() {
+                Some(',') => break,
+                None => {
+                    result.push((element, quoted));
+                    return Some(result);
+                }
+                Some(_) if quoted => return None,
+                Some('{') | Some('"') => return None,
+                Some(c) => element.push(c),
+            }
+        }
+
+        result.push((element, quoted));
+    }
+}
+
 // See https://www.postgresql.org/docs/9.3/sql-syntax-lexical.html
 fn process_string_literal(literal: &str) -> Cow<'_, str> {
     static POSTGRES_STRING_DEFAULT_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"(?ms)^B?'(.*)'$"#).unwrap());
@@ -847,4 +1178,25 @@ mod tests {
         let non_autoincrement = r#"string_default_named_seq"#;
         assert!(is_autoincrement(non_autoincrement, &sequences).is_none());
     }
+
+    #[test]
+    fn postgres_fulltext_index_columns_works() {
+        let single =
+            r#"CREATE INDEX "Post_title_idx" ON public."Post" USING gin (to_tsvector('simple'::regconfig, title))"#;
+        assert_eq!(fulltext_index_columns(single), Some(vec!["title".to_string()]));
+
+        let multiple = r#"CREATE INDEX idx ON public."Post" USING gin (to_tsvector('simple'::regconfig, "firstName"), to_tsvector('simple'::regconfig, (body)::text))"#;
+        assert_eq!(
+            fulltext_index_columns(multiple),
+            Some(vec!["firstName".to_string(), "body".to_string()])
+        );
+
+        let other_expression =
+            r#"CREATE INDEX idx ON public."Post" USING gin (to_tsvector('english'::regconfig, title))"#;
+        assert_eq!(fulltext_index_columns(other_expression), None);
+
+        let mixed =
+            r#"CREATE INDEX idx ON public."Post" USING gin (to_tsvector('simple'::regconfig, title), lower(body))"#;
+        assert_eq!(fulltext_index_columns(mixed), None);
+    }
 }
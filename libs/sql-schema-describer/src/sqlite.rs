@@ -29,13 +29,24 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
     #[tracing::instrument]
     async fn describe(&self, schema: &str) -> DescriberResult<SqlSchema> {
         let table_names: Vec<String> = self.get_table_names(schema).await?;
+        let fulltext_indexes = self.get_fulltext_indexes().await?;
 
         let mut tables = Vec::with_capacity(table_names.len());
 
-        for table_name in table_names.iter().filter(|table| !is_system_table(&table)) {
+        for table_name in table_names
+            .iter()
+            .filter(|table| !is_system_table(&table))
+            .filter(|table| !is_fulltext_index_table(&table, &fulltext_indexes))
+        {
             tables.push(self.get_table(schema, table_name).await?)
         }
 
+        for (table_name, index) in fulltext_indexes {
+            if let Some(table) = tables.iter_mut().find(|table| table.name == table_name) {
+                table.indices.push(index);
+            }
+        }
+
         //sqlite allows foreign key definitions without specifying the referenced columns, it then assumes the pk is used
         let mut foreign_keys_without_referenced_columns = vec![];
         for (table_index, table) in tables.iter().enumerate() {
@@ -52,12 +63,15 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
             tables[table_index].foreign_keys[fk_index].referenced_columns = columns
         }
 
+        let views = self.get_views().await?;
+
         Ok(SqlSchema {
             // There's no enum type in SQLite.
             enums: vec![],
             // There are no sequences in SQLite.
             sequences: vec![],
             tables,
+            views,
         })
     }
 
@@ -111,6 +125,30 @@ impl SqlSchemaDescriber {
         Ok(names)
     }
 
+    #[tracing::instrument]
+    async fn get_views(&self) -> DescriberResult<Vec<View>> {
+        let sql = r#"SELECT name, sql FROM sqlite_master WHERE type='view' ORDER BY name ASC"#;
+        let result_set = self.conn.query_raw(&sql, &[]).await?;
+
+        let mut views = Vec::with_capacity(result_set.len());
+
+        for row in result_set {
+            let name = row.get("name").and_then(|x| x.to_string()).unwrap();
+            let definition = row.get("sql").and_then(|x| x.to_string());
+            let (columns, _) = self.get_columns(&name).await?;
+
+            views.push(View {
+                name,
+                definition,
+                columns,
+            });
+        }
+
+        trace!("Found views: {:?}", views);
+
+        Ok(views)
+    }
+
     #[tracing::instrument]
     async fn get_size(&self) -> DescriberResult<usize> {
         let sql = r#"SELECT page_count * page_size as size FROM pragma_page_count(), pragma_page_size();"#;
@@ -128,16 +166,69 @@ impl SqlSchemaDescriber {
         let (columns, primary_key) = self.get_columns(name).await?;
         let foreign_keys = self.get_foreign_keys(name).await?;
         let indices = self.get_indices(name).await?;
+        let check_constraints = self.get_check_constraints(name).await?;
 
         Ok(Table {
             name: name.to_string(),
+            schema: None,
             columns,
             indices,
             primary_key,
             foreign_keys,
+            check_constraints,
+            comment: None,
         })
     }
 
+    /// SQLite has no catalog for check constraints, so they are read from the `CREATE TABLE` statement.
+    #[tracing::instrument]
+    async fn get_check_constraints(&self, table: &str) -> DescriberResult<Vec<CheckConstraint>> {
+        let sql = r#"SELECT sql FROM sqlite_master WHERE type='table' AND name = ?"#;
+        let result_set = self.conn.query_raw(sql, &[table.into()]).await?;
+
+        let check_constraints = result_set
+            .first()
+            .and_then(|row| row.get("sql").and_then(|x| x.to_string()))
+            .map(|create_table| parse_check_constraints(&create_table))
+            .unwrap_or_default();
+
+        trace!("Found check constraints: {:?}", check_constraints);
+
+        Ok(check_constraints)
+    }
+
+    /// Full-text indexes are FTS5 tables reading their values from the indexed table, see
+    /// `parse_fulltext_index`. Returns the name of the indexed table with each index.
+    #[tracing::instrument]
+    async fn get_fulltext_indexes(&self) -> DescriberResult<Vec<(String, Index)>> {
+        let sql = r#"SELECT name, sql FROM sqlite_master WHERE type='table' AND sql LIKE 'CREATE VIRTUAL TABLE%'"#;
+        let result_set = self.conn.query_raw(sql, &[]).await?;
+
+        let fulltext_indexes = result_set
+            .into_iter()
+            .filter_map(|row| {
+                let name = row.get("name").and_then(|x| x.to_string())?;
+                let create_table = row.get("sql").and_then(|x| x.to_string())?;
+
+                parse_fulltext_index(&name, &create_table)
+            })
+            .collect();
+
+        trace!("Found full-text indexes: {:?}", fulltext_indexes);
+
+        Ok(fulltext_indexes)
+    }
+
+    async fn get_index_predicate(&self, index: &str) -> DescriberResult<Option<String>> {
+        let sql = r#"SELECT sql FROM sqlite_master WHERE type='index' AND name = ?"#;
+        let result_set = self.conn.query_raw(sql, &[index.into()]).await?;
+
+        Ok(result_set
+            .first()
+            .and_then(|row| row.get("sql").and_then(|x| x.to_string()))
+            .and_then(|create_index| parse_index_predicate(&create_index)))
+    }
+
     #[tracing::instrument]
     async fn get_columns(&self, table: &str) -> DescriberResult<(Vec<Column>, Option<PrimaryKey>)> {
         let sql = format!(r#"PRAGMA table_info ("{}")"#, table);
@@ -190,6 +281,10 @@ impl SqlSchemaDescriber {
                                         None => DefaultValue::db_generated(default_string),
                                     },
                                 },
+                                // Expressions are wrapped in parentheses, e.g. `DEFAULT (lower('A'))`.
+                                ColumnTypeFamily::String if default_string.starts_with('(') => {
+                                    DefaultValue::db_generated(default_string)
+                                }
                                 ColumnTypeFamily::String => {
                                     DefaultValue::value(unquote_sqlite_string_default(&default_string).into_owned())
                                 }
@@ -216,6 +311,8 @@ impl SqlSchemaDescriber {
                     tpe,
                     default,
                     auto_increment: false,
+                    generated: None,
+                    comment: None,
                 };
                 if pk_col > 0 {
                     pk_cols.insert(pk_col, col.name.clone());
@@ -371,6 +468,7 @@ impl SqlSchemaDescriber {
                 let fk = ForeignKey {
                     columns,
                     referenced_table: intermediate_fk.referenced_table.to_owned(),
+                    referenced_schema: None,
                     referenced_columns,
                     on_delete_action: intermediate_fk.on_delete_action.to_owned(),
                     on_update_action: intermediate_fk.on_update_action.to_owned(),
@@ -400,12 +498,11 @@ impl SqlSchemaDescriber {
         let filtered_rows = result_set
             .into_iter()
             // Exclude primary keys, they are inferred separately.
-            .filter(|row| row.get("origin").and_then(|origin| origin.as_str()).unwrap() != "pk")
-            // Exclude partial indices
-            .filter(|row| !row.get("partial").and_then(|partial| partial.as_bool()).unwrap());
+            .filter(|row| row.get("origin").and_then(|origin| origin.as_str()).unwrap() != "pk");
 
         for row in filtered_rows {
             let is_unique = row.get("unique").and_then(|x| x.as_bool()).expect("get unique");
+            let is_partial = row.get("partial").and_then(|x| x.as_bool()).expect("get partial");
             let name = row.get("name").and_then(|x| x.to_string()).expect("get name");
             let mut index = Index {
                 name: name.clone(),
@@ -414,18 +511,43 @@ impl SqlSchemaDescriber {
                     false => IndexType::Normal,
                 },
                 columns: vec![],
+                column_options: vec![],
+                algorithm: None,
+                predicate: None,
             };
 
-            let sql = format!(r#"PRAGMA index_info("{}");"#, name);
+            if is_partial {
+                index.predicate = self.get_index_predicate(&name).await?;
+            }
+
+            // `index_xinfo` also lists the auxiliary columns of the index, they are not key columns.
+            let sql = format!(r#"PRAGMA index_xinfo("{}");"#, name);
             let result_set = self.conn.query_raw(&sql, &[]).await.expect("querying for index info");
             trace!("Got index description results: {:?}", result_set);
+
+            let mut columns = Vec::new();
+
             for row in result_set.into_iter() {
-                let pos = row.get("seqno").and_then(|x| x.as_i64()).expect("get seqno") as usize;
-                let col_name = row.get("name").and_then(|x| x.to_string()).expect("get name");
-                if index.columns.len() <= pos {
-                    index.columns.resize(pos + 1, "".to_string());
+                if !row.get("key").and_then(|x| x.as_bool()).expect("get key") {
+                    continue;
                 }
-                index.columns[pos] = col_name;
+
+                let pos = row.get("seqno").and_then(|x| x.as_i64()).expect("get seqno");
+                let col_name = row.get("name").and_then(|x| x.to_string()).expect("get name");
+                let is_descending = row.get("desc").and_then(|x| x.as_bool()).expect("get desc");
+
+                columns.push((pos, col_name, is_descending));
+            }
+
+            columns.sort_by_key(|(pos, _, _)| *pos);
+
+            for (_, col_name, is_descending) in columns {
+                let options = IndexColumnOptions {
+                    sort_order: if is_descending { SortOrder::Desc } else { SortOrder::Asc },
+                    length: None,
+                };
+
+                index.push_column(col_name, options);
             }
 
             indices.push(index)
@@ -493,6 +615,193 @@ fn unquote_sqlite_string_default(s: &str) -> Cow<'_, str> {
     }
 }
 
+/// Extracts the `[CONSTRAINT name] CHECK (expression)` clauses from a `CREATE TABLE` statement.
+/// Extracts the predicate of a partial index from its `CREATE INDEX ... WHERE <predicate>` statement.
+fn parse_index_predicate(create_index: &str) -> Option<String> {
+    let tokens = tokenize_sqlite(create_index);
+    let mut depth = 0;
+
+    let (where_start, where_token) = tokens.iter().find(|(_, token)| {
+        match *token {
+            "(" => depth += 1,
+            ")" => depth -= 1,
+            _ => (),
+        }
+
+        depth == 0 && token.eq_ignore_ascii_case("WHERE")
+    })?;
+
+    Some(create_index[where_start + where_token.len()..].trim().to_owned())
+}
+
+/// Parses the FTS5 table of a full-text index, `CREATE VIRTUAL TABLE <index> USING fts5(<columns>,
+/// content='<table>')`, into the index on the table. FTS5 tables without an external content
+/// table are not indexes of another table.
+fn parse_fulltext_index(name: &str, create_table: &str) -> Option<(String, Index)> {
+    let tokens = tokenize_sqlite(create_table);
+    let using = tokens
+        .iter()
+        .position(|(_, token)| token.eq_ignore_ascii_case("USING"))?;
+
+    match (tokens.get(using + 1), tokens.get(using + 2)) {
+        (Some((_, module)), Some((_, "("))) if module.eq_ignore_ascii_case("fts5") => (),
+        _ => return None,
+    }
+
+    let arguments_start = using + 3;
+    let arguments_end = arguments_start + tokens[arguments_start..].iter().position(|(_, token)| *token == ")")?;
+    let mut content_table = None;
+    let mut index = Index {
+        name: name.to_owned(),
+        tpe: IndexType::Fulltext,
+        columns: vec![],
+        column_options: vec![],
+        algorithm: None,
+        predicate: None,
+    };
+
+    for argument in tokens[arguments_start..arguments_end].split(|(_, token)| *token == ",") {
+        match argument {
+            [(_, option), (_, "="), (_, value)] if option.eq_ignore_ascii_case("content") => {
+                content_table = Some(unquote_sqlite_identifier(value).to_owned())
+            }
+            // Other options, e.g. `tokenize`.
+            [_, (_, "="), ..] => (),
+            [(_, column), ..] => {
+                let options = IndexColumnOptions {
+                    sort_order: SortOrder::Asc,
+                    length: None,
+                };
+
+                index.push_column(unquote_sqlite_identifier(column).to_owned(), options)
+            }
+            [] => (),
+        }
+    }
+
+    content_table
+        .filter(|table| !table.is_empty())
+        .map(|table| (table, index))
+}
+
+/// Whether the table is the FTS5 table of a full-text index, or one of the shadow tables FTS5
+/// stores the index in.
+fn is_fulltext_index_table(table_name: &str, fulltext_indexes: &[(String, Index)]) -> bool {
+    fulltext_indexes.iter().any(|(_, index)| {
+        table_name == index.name
+            || FTS5_SHADOW_TABLES
+                .iter()
+                .any(|suffix| table_name.strip_prefix(index.name.as_str()) == Some(*suffix))
+    })
+}
+
+/// See https://www.sqlite.org/fts5.html#fts5_table_creation_and_initialization
+const FTS5_SHADOW_TABLES: &[&str] = &["_data", "_idx", "_content", "_docsize", "_config"];
+
+fn parse_check_constraints(create_table: &str) -> Vec<CheckConstraint> {
+    let tokens = tokenize_sqlite(create_table);
+    let mut check_constraints = Vec::new();
+
+    for (idx, (_, token)) in tokens.iter().enumerate() {
+        if !token.eq_ignore_ascii_case("CHECK") || tokens.get(idx + 1).map(|(_, t)| *t) != Some("(") {
+            continue;
+        }
+
+        let mut depth = 0;
+        let closing_paren = tokens[idx + 1..].iter().find(|(_, t)| {
+            match *t {
+                "(" => depth += 1,
+                ")" => depth -= 1,
+                _ => (),
+            }
+
+            depth == 0
+        });
+
+        let (expression_start, expression_end) = match closing_paren {
+            Some((end, _)) => (tokens[idx + 1].0 + 1, *end),
+            None => continue,
+        };
+
+        let name = match idx
+            .checked_sub(2)
+            .map(|name_idx| (tokens[name_idx].1, tokens[name_idx + 1].1))
+        {
+            Some((constraint, name)) if constraint.eq_ignore_ascii_case("CONSTRAINT") => {
+                Some(unquote_sqlite_identifier(name).to_owned())
+            }
+            _ => None,
+        };
+
+        check_constraints.push(CheckConstraint {
+            name,
+            expression: create_table[expression_start..expression_end].trim().to_owned(),
+        });
+    }
+
+    check_constraints
+}
+
+/// A minimal tokenizer returning the start offset and text of each word, quoted identifier,
+/// string literal or punctuation character in a SQLite statement.
+fn tokenize_sqlite(sql: &str) -> Vec<(usize, &str)> {
+    let mut tokens = Vec::new();
+    let mut chars = sql.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        let end = match c {
+            c if c.is_whitespace() => continue,
+            '\'' | '"' | '`' | '[' => {
+                let closing = if c == '[' { ']' } else { c };
+                let mut end = sql.len();
+
+                while let Some((idx, c)) = chars.next() {
+                    if c != closing {
+                        continue;
+                    }
+
+                    // Quotes are escaped by doubling them.
+                    if closing != ']' && chars.peek().map(|(_, next)| *next) == Some(closing) {
+                        chars.next();
+                        continue;
+                    }
+
+                    end = idx + c.len_utf8();
+                    break;
+                }
+
+                end
+            }
+            c if c.is_alphanumeric() || c == '_' => {
+                let mut end = start + c.len_utf8();
+
+                while let Some((idx, c)) = chars.peek().copied() {
+                    if !(c.is_alphanumeric() || c == '_' || c == '$') {
+                        break;
+                    }
+
+                    end = idx + c.len_utf8();
+                    chars.next();
+                }
+
+                end
+            }
+            c => start + c.len_utf8(),
+        };
+
+        tokens.push((start, &sql[start..end]));
+    }
+
+    tokens
+}
+
+fn unquote_sqlite_identifier(identifier: &str) -> &str {
+    match identifier.chars().next() {
+        Some('"') | Some('`') | Some('[') | Some('\'') if identifier.len() >= 2 => &identifier[1..identifier.len() - 1],
+        _ => identifier,
+    }
+}
+
 /// Returns whether a table is one of the SQLite system tables.
 fn is_system_table(table_name: &str) -> bool {
     SQLITE_SYSTEM_TABLES
@@ -5,8 +5,9 @@
 use std::fmt;
 
 use crate::{
-    Column, ColumnArity, ColumnType, ColumnTypeFamily, DefaultValue, Enum, ForeignKey, ForeignKeyAction, Index,
-    IndexType, PrimaryKey, SqlSchema, Table,
+    CheckConstraint, Column, ColumnArity, ColumnType, ColumnTypeFamily, DefaultValue, Enum, ForeignKey,
+    ForeignKeyAction, GeneratedColumn, Index, IndexAlgorithm, IndexColumnOptions, IndexType, PrimaryKey, SqlSchema,
+    Table,
 };
 
 /// Traverse all the columns in the schema.
@@ -56,6 +57,11 @@ impl<'a> ColumnWalker<'a> {
         self.column_index
     }
 
+    /// The comment on the column.
+    pub fn comment(&self) -> Option<&'a str> {
+        self.column().comment.as_deref()
+    }
+
     /// The type family.
     pub fn column_type_family(&self) -> &'a ColumnTypeFamily {
         &self.column().tpe.family
@@ -81,6 +87,11 @@ impl<'a> ColumnWalker<'a> {
         self.column().default.as_ref()
     }
 
+    /// How the database computes the values of the column, if it is a generated column.
+    pub fn generated(&self) -> Option<&'a GeneratedColumn> {
+        self.column().generated.as_ref()
+    }
+
     /// The full column type.
     pub fn column_type(&self) -> &'a ColumnType {
         &self.column().tpe
@@ -177,6 +188,11 @@ impl<'a> TableWalker<'a> {
         })
     }
 
+    /// The comment on the table.
+    pub fn comment(&self) -> Option<&'a str> {
+        self.table().comment.as_deref()
+    }
+
     /// The number of foreign key constraints on the table.
     pub fn foreign_key_count(&self) -> usize {
         self.table().foreign_keys.len()
@@ -235,11 +251,26 @@ impl<'a> TableWalker<'a> {
         }
     }
 
+    /// The check constraints on the table.
+    pub fn check_constraints(&self) -> &'a [CheckConstraint] {
+        &self.table().check_constraints
+    }
+
+    /// Get a check constraint by index.
+    pub fn check_constraint_at(&self, index: usize) -> &'a CheckConstraint {
+        &self.table().check_constraints[index]
+    }
+
     /// The table name.
     pub fn name(&self) -> &'a str {
         &self.table().name
     }
 
+    /// The database schema of the table, if it is not the default schema of the connection.
+    pub fn schema_name(&self) -> Option<&'a str> {
+        self.table().schema.as_deref()
+    }
+
     /// Try to traverse a foreign key for a single column.
     pub fn foreign_key_for_column(&self, column: &str) -> Option<&'a ForeignKey> {
         self.table().foreign_key_for_column(column)
@@ -342,15 +373,23 @@ impl<'schema> ForeignKeyWalker<'schema> {
         self.foreign_key().referenced_columns.len()
     }
 
+    /// The database schema of the referenced table, if it is not the default schema of the connection.
+    pub fn referenced_schema_name(&self) -> Option<&'schema str> {
+        self.foreign_key().referenced_schema.as_deref()
+    }
+
     /// The table the foreign key "points to".
     pub fn referenced_table(&self) -> TableWalker<'schema> {
+        let foreign_key = self.foreign_key();
+
         TableWalker {
             schema: self.schema,
             table_index: self
                 .schema
-                .table_walker(&self.foreign_key().referenced_table)
-                .expect("foreign key references unknown table")
-                .table_index,
+                .tables
+                .iter()
+                .position(|table| foreign_key.references(table))
+                .expect("foreign key references unknown table"),
         }
     }
 
@@ -364,7 +403,7 @@ impl<'schema> ForeignKeyWalker<'schema> {
 
     /// True if relation is back to the same table.
     pub fn is_self_relation(&self) -> bool {
-        self.table().name() == self.referenced_table().name()
+        self.table().table_index() == self.referenced_table().table_index()
     }
 }
 
@@ -421,6 +460,21 @@ impl<'a> IndexWalker<'a> {
         &self.get().tpe
     }
 
+    /// The sort order and prefix length of the column at the given position in the index.
+    pub fn column_options(&self, position: usize) -> IndexColumnOptions {
+        self.get().column_options(position)
+    }
+
+    /// The access method of the index, `None` for B-tree indexes.
+    pub fn algorithm(&self) -> Option<IndexAlgorithm> {
+        self.get().algorithm
+    }
+
+    /// The `WHERE` predicate of a partial index.
+    pub fn predicate(&self) -> Option<&'a str> {
+        self.get().predicate.as_deref()
+    }
+
     /// The name of the index.
     pub fn name(&self) -> &str {
         &self.get().name
@@ -465,6 +519,11 @@ impl<'a> EnumWalker<'a> {
         &self.get().name
     }
 
+    /// The database schema of the enum, if it is not the default schema of the connection.
+    pub fn schema_name(&self) -> Option<&'a str> {
+        self.get().schema.as_deref()
+    }
+
     /// The values of the enum
     pub fn values(&self) -> &'a [String] {
         &self.get().values
@@ -101,6 +101,8 @@ async fn is_required_must_work(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "column2".to_string(),
@@ -115,6 +117,8 @@ async fn is_required_must_work(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
     ];
 
@@ -158,6 +162,8 @@ async fn foreign_keys_must_work(api: &TestApi) {
         },
         default: None,
         auto_increment: false,
+        generated: None,
+        comment: None,
     }];
 
     let on_delete_action = match api.sql_family() {
@@ -169,6 +175,9 @@ async fn foreign_keys_must_work(api: &TestApi) {
             name: "city".to_owned(),
             columns: vec!["city".to_owned()],
             tpe: IndexType::Normal,
+            column_options: vec![],
+            algorithm: None,
+            predicate: None,
         }]
     } else {
         vec![]
@@ -178,6 +187,7 @@ async fn foreign_keys_must_work(api: &TestApi) {
         user_table,
         &Table {
             name: "User".to_string(),
+            schema: None,
             columns: expected_columns,
             indices: expected_indexes,
             primary_key: None,
@@ -191,9 +201,12 @@ async fn foreign_keys_must_work(api: &TestApi) {
                 columns: vec!["city".to_string()],
                 referenced_columns: vec!["id".to_string()],
                 referenced_table: "City".to_string(),
+                referenced_schema: None,
                 on_delete_action,
                 on_update_action: ForeignKeyAction::NoAction,
             }],
+            check_constraints: vec![],
+            comment: None,
         }
     );
 }
@@ -253,6 +266,8 @@ async fn multi_column_foreign_keys_must_work(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "city_name".to_string(),
@@ -270,6 +285,8 @@ async fn multi_column_foreign_keys_must_work(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
     ];
 
@@ -278,6 +295,9 @@ async fn multi_column_foreign_keys_must_work(api: &TestApi) {
             name: "city_name".to_owned(),
             columns: vec!["city_name".to_owned(), "city".to_owned()],
             tpe: IndexType::Normal,
+            column_options: vec![],
+            algorithm: None,
+            predicate: None,
         }]
     } else {
         vec![]
@@ -292,6 +312,7 @@ async fn multi_column_foreign_keys_must_work(api: &TestApi) {
         user_table,
         &Table {
             name: "User".to_string(),
+            schema: None,
             columns: expected_columns,
             indices: expected_indexes,
             primary_key: None,
@@ -306,9 +327,12 @@ async fn multi_column_foreign_keys_must_work(api: &TestApi) {
                 columns: vec!["city_name".to_string(), "city".to_string()],
                 referenced_columns: vec!["name".to_string(), "id".to_string(),],
                 referenced_table: "City".to_string(),
+                referenced_schema: None,
                 on_delete_action,
                 on_update_action: ForeignKeyAction::NoAction,
             },],
+            check_constraints: vec![],
+            comment: None,
         }
     );
 }
@@ -337,6 +361,8 @@ async fn names_with_hyphens_must_work(api: &TestApi) {
         },
         default: None,
         auto_increment: false,
+        generated: None,
+        comment: None,
     }];
     assert_eq!(user_table.columns, expected_columns);
 }
@@ -388,6 +414,8 @@ async fn composite_primary_keys_must_work(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "name".to_string(),
@@ -405,6 +433,8 @@ async fn composite_primary_keys_must_work(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -413,6 +443,7 @@ async fn composite_primary_keys_must_work(api: &TestApi) {
         table,
         &Table {
             name: "User".to_string(),
+            schema: None,
             columns: expected_columns,
             indices: vec![],
             primary_key: Some(PrimaryKey {
@@ -425,6 +456,8 @@ async fn composite_primary_keys_must_work(api: &TestApi) {
                 }
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
+            comment: None,
         }
     );
 }
@@ -461,6 +494,8 @@ async fn indices_must_work(api: &TestApi) {
 
             default,
             auto_increment: true,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "count".to_string(),
@@ -475,6 +510,8 @@ async fn indices_must_work(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
     ];
     let pk_sequence = match api.sql_family() {
@@ -492,6 +529,9 @@ async fn indices_must_work(api: &TestApi) {
             name: "count".to_string(),
             columns: vec!["count".to_string()],
             tpe: IndexType::Normal,
+            column_options: vec![],
+            algorithm: None,
+            predicate: None,
         }],
         user_table.indices
     );
@@ -543,6 +583,8 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "uniq2".to_string(),
@@ -558,18 +600,26 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
     ];
     let mut expected_indices = vec![Index {
         name: "uniq".to_string(),
         columns: vec!["uniq2".to_string()],
         tpe: IndexType::Unique,
+        column_options: vec![],
+        algorithm: None,
+        predicate: None,
     }];
     match api.sql_family() {
         SqlFamily::Mysql => expected_indices.push(Index {
             name: "uniq1".to_string(),
             columns: vec!["uniq1".to_string()],
             tpe: IndexType::Unique,
+            column_options: vec![],
+            algorithm: None,
+            predicate: None,
         }),
         SqlFamily::Postgres => expected_indices.insert(
             0,
@@ -577,12 +627,18 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
                 name: "User_uniq1_key".to_string(),
                 columns: vec!["uniq1".to_string()],
                 tpe: IndexType::Unique,
+                column_options: vec![],
+                algorithm: None,
+                predicate: None,
             },
         ),
         SqlFamily::Sqlite => expected_indices.push(Index {
             name: "sqlite_autoindex_User_1".to_string(),
             columns: vec!["uniq1".to_string()],
             tpe: IndexType::Unique,
+            column_options: vec![],
+            algorithm: None,
+            predicate: None,
         }),
         SqlFamily::Mssql => expected_indices.insert(
             0,
@@ -590,6 +646,9 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
                 name: "UQ__User__CD572100A176666B".to_string(),
                 columns: vec!["uniq1".to_string()],
                 tpe: IndexType::Unique,
+                column_options: vec![],
+                algorithm: None,
+                predicate: None,
             },
         ),
     };
@@ -616,10 +675,13 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
                 user_table,
                 &Table {
                     name: "User".to_string(),
+                    schema: None,
                     columns: expected_columns,
                     indices: expected_indices,
                     primary_key: None,
                     foreign_keys: vec![],
+                    check_constraints: vec![],
+                    comment: None,
                 }
             );
         }
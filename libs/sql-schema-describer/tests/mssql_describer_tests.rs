@@ -6,6 +6,8 @@ use barrel::{types, Migration};
 use native_types::{MsSqlType, MsSqlTypeParameter::*, NativeType};
 use pretty_assertions::assert_eq;
 use sql_schema_describer::*;
+use test_api::*;
+use test_macros::test_each_connector;
 
 #[tokio::test]
 async fn all_mssql_column_types_must_work() {
@@ -64,6 +66,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: true,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "bit_col".to_string(),
@@ -78,6 +82,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "decimal_col".to_string(),
@@ -92,6 +98,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "int_col".to_string(),
@@ -105,6 +113,8 @@ async fn all_mssql_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "money_col".to_string(),
@@ -119,6 +129,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "numeric_col".to_string(),
@@ -133,6 +145,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "smallint_col".to_string(),
@@ -147,6 +161,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "smallmoney_col".to_string(),
@@ -161,6 +177,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "tinyint_col".to_string(),
@@ -175,6 +193,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "float_col".to_string(),
@@ -189,6 +209,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "double_col".to_string(),
@@ -203,6 +225,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "date_col".to_string(),
@@ -217,6 +241,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "datetime_col".to_string(),
@@ -230,6 +256,8 @@ async fn all_mssql_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "datetime2_col".to_string(),
@@ -244,6 +272,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "datetimeoffset_col".to_string(),
@@ -258,6 +288,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "smalldatetime_col".to_string(),
@@ -272,6 +304,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "time_col".to_string(),
@@ -286,6 +320,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "char_col".to_string(),
@@ -300,6 +336,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "varchar_col".to_string(),
@@ -314,6 +352,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "varchar_max_col".to_string(),
@@ -328,6 +368,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "text_col".to_string(),
@@ -342,6 +384,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "nvarchar_col".to_string(),
@@ -356,6 +400,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "nvarchar_max_col".to_string(),
@@ -370,6 +416,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "ntext_col".to_string(),
@@ -384,6 +432,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "binary_col".to_string(),
@@ -398,6 +448,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "varbinary_col".to_string(),
@@ -412,6 +464,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "varbinary_max_col".to_string(),
@@ -426,6 +480,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "image_col".to_string(),
@@ -440,6 +496,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "xml_col".to_string(),
@@ -454,6 +512,8 @@ async fn all_mssql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -503,6 +563,7 @@ async fn mssql_foreign_key_on_delete_must_be_handled() {
         table,
         Table {
             name: "User".to_string(),
+            schema: None,
             columns: vec![
                 Column {
                     name: "id".to_string(),
@@ -517,6 +578,8 @@ async fn mssql_foreign_key_on_delete_must_be_handled() {
 
                     default: None,
                     auto_increment: true,
+                    generated: None,
+                    comment: None,
                 },
                 Column {
                     name: "city".to_string(),
@@ -530,6 +593,8 @@ async fn mssql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generated: None,
+                    comment: None,
                 },
                 Column {
                     name: "city_cascade".to_string(),
@@ -543,6 +608,8 @@ async fn mssql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generated: None,
+                    comment: None,
                 },
             ],
             indices: vec![],
@@ -557,6 +624,7 @@ async fn mssql_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::NoAction,
                 },
@@ -565,10 +633,13 @@ async fn mssql_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_cascade".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_update_action: ForeignKeyAction::Cascade,
                     on_delete_action: ForeignKeyAction::Cascade,
                 },
             ],
+            check_constraints: vec![],
+            comment: None,
         }
     );
 }
@@ -595,7 +666,10 @@ async fn mssql_multi_field_indexes_must_be_inferred() {
         &[Index {
             name: "age_and_name_index".into(),
             columns: vec!["name".to_owned(), "age".to_owned()],
-            tpe: IndexType::Unique
+            tpe: IndexType::Unique,
+            column_options: vec![],
+            algorithm: None,
+            predicate: None,
         }]
     );
 }
@@ -634,6 +708,119 @@ async fn mssql_join_table_unique_indexes_must_be_inferred() {
             name: "cat_and_human_index".into(),
             columns: vec!["cat".to_owned(), "human".to_owned()],
             tpe: IndexType::Unique,
+            column_options: vec![],
+            algorithm: None,
+            predicate: None,
+        }]
+    );
+}
+
+#[test_each_connector(tags("mssql"))]
+async fn views_are_described(api: &TestApi) -> TestResult {
+    let create_table = format!(
+        "CREATE TABLE [{0}].[Cat] (id INT PRIMARY KEY, name NVARCHAR(1000) NOT NULL)",
+        api.schema_name()
+    );
+
+    // `CREATE VIEW` has to be the only statement of its batch.
+    let create_view = format!(
+        "CREATE VIEW [{0}].[CatNames] AS SELECT id, name FROM [{0}].[Cat]",
+        api.schema_name()
+    );
+
+    api.database().raw_cmd(&create_table).await?;
+    api.database().raw_cmd(&create_view).await?;
+
+    let schema = api.describe().await?;
+    let view = schema.get_view("CatNames").unwrap();
+
+    // The columns query reads the columns of tables (`U`) and views (`V`), which must end up
+    // in the right place.
+    assert!(schema.get_table("CatNames").is_none());
+    assert_eq!(schema.table_bang("Cat").columns.len(), 2);
+    assert_eq!(
+        view.columns.iter().map(|c| c.name.as_str()).collect::<Vec<_>>(),
+        &["id", "name"]
+    );
+    assert_eq!(view.column("id").unwrap().tpe.family, ColumnTypeFamily::Int);
+    assert_eq!(view.column("name").unwrap().tpe.family, ColumnTypeFamily::String);
+    assert!(view.definition.as_deref().unwrap().contains("CREATE VIEW"));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mssql"))]
+async fn foreign_keys_to_other_schemas_are_described_with_their_schema(api: &TestApi) -> TestResult {
+    use test_setup::connectors::mssql;
+
+    let other_schema = format!("{}_other", api.schema_name());
+    mssql::reset_schema(api.database(), &other_schema).await?;
+
+    let sql = format!(
+        r#"
+            CREATE TABLE [{1}].[User] (id INT PRIMARY KEY);
+            CREATE TABLE [{0}].[User] (id INT PRIMARY KEY);
+
+            CREATE TABLE [{0}].[Post] (
+                id INT PRIMARY KEY,
+                author_id INT NOT NULL REFERENCES [{1}].[User](id),
+                editor_id INT NOT NULL REFERENCES [{0}].[User](id)
+            );
+
+            CREATE TABLE [{1}].[Comment] (
+                id INT PRIMARY KEY,
+                user_id INT NOT NULL REFERENCES [{1}].[User](id)
+            );
+        "#,
+        api.schema_name(),
+        other_schema,
+    );
+
+    api.database().raw_cmd(&sql).await?;
+
+    let schema = api.describe_schemas(&[other_schema.clone()]).await?;
+    let post = schema.table_walkers().find(|table| table.name() == "Post").unwrap();
+    let comment = schema.table_walkers().find(|table| table.name() == "Comment").unwrap();
+
+    let fks: Vec<_> = post.foreign_keys().collect();
+    assert_eq!(fks[0].constrained_columns().next().unwrap().name(), "author_id");
+    assert_eq!(fks[0].referenced_schema_name(), Some(other_schema.as_str()));
+    assert_eq!(fks[0].referenced_table().schema_name(), Some(other_schema.as_str()));
+    assert_eq!(fks[1].referenced_schema_name(), None);
+    assert_eq!(fks[1].referenced_table().schema_name(), None);
+
+    let fks: Vec<_> = comment.foreign_keys().collect();
+    assert_eq!(fks[0].referenced_schema_name(), Some(other_schema.as_str()));
+    assert_eq!(fks[0].referenced_table().schema_name(), Some(other_schema.as_str()));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mssql"))]
+async fn check_constraints_are_described(api: &TestApi) -> TestResult {
+    let sql = format!(
+        r#"
+            CREATE TABLE [{0}].[products] (
+                id INT PRIMARY KEY,
+                price INT NOT NULL,
+                CONSTRAINT [price_check] CHECK (price > 0)
+            );
+        "#,
+        api.schema_name()
+    );
+
+    api.database().raw_cmd(&sql).await?;
+
+    let schema = api.describe().await?;
+    let table = schema.table_bang("products");
+
+    assert_eq!(
+        table.check_constraints,
+        &[CheckConstraint {
+            name: Some("price_check".into()),
+            expression: "([price]>(0))".into(),
         }]
     );
+
+    Ok(())
 }
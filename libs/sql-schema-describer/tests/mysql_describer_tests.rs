@@ -77,6 +77,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: true,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "int_col".to_string(),
@@ -91,6 +93,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "smallint_col".to_string(),
@@ -105,6 +109,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "tinyint4_col".to_string(),
@@ -118,6 +124,8 @@ async fn all_mysql_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "tinyint1_col".to_string(),
@@ -132,6 +140,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "mediumint_col".to_string(),
@@ -146,6 +156,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "bigint_col".to_string(),
@@ -160,6 +172,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "decimal_col".to_string(),
@@ -174,6 +188,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "numeric_col".to_string(),
@@ -188,6 +204,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "float_col".to_string(),
@@ -202,6 +220,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "double_col".to_string(),
@@ -216,6 +236,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "date_col".to_string(),
@@ -230,6 +252,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "time_col".to_string(),
@@ -244,6 +268,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "datetime_col".to_string(),
@@ -258,6 +284,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "timestamp_col".to_string(),
@@ -272,6 +300,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: Some(DefaultValue::now()),
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "year_col".to_string(),
@@ -286,6 +316,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "char_col".to_string(),
@@ -300,6 +332,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "varchar_col".to_string(),
@@ -314,6 +348,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "text_col".to_string(),
@@ -328,6 +364,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "tinytext_col".to_string(),
@@ -342,6 +380,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "mediumtext_col".to_string(),
@@ -356,6 +396,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "longtext_col".to_string(),
@@ -370,6 +412,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "enum_col".to_string(),
@@ -384,6 +428,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "set_col".to_string(),
@@ -398,6 +444,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "binary_col".to_string(),
@@ -412,6 +460,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "varbinary_col".to_string(),
@@ -426,6 +476,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "blob_col".to_string(),
@@ -440,6 +492,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "tinyblob_col".to_string(),
@@ -455,6 +509,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "mediumblob_col".to_string(),
@@ -469,6 +525,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "longblob_col".to_string(),
@@ -483,6 +541,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "geometry_col".to_string(),
@@ -497,6 +557,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "point_col".to_string(),
@@ -511,6 +573,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "linestring_col".to_string(),
@@ -525,6 +589,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "polygon_col".to_string(),
@@ -539,6 +605,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "multipoint_col".to_string(),
@@ -553,6 +621,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "multilinestring_col".to_string(),
@@ -567,6 +637,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "multipolygon_col".to_string(),
@@ -581,6 +653,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "geometrycollection_col".to_string(),
@@ -595,6 +669,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "json_col".to_string(),
@@ -609,6 +685,8 @@ async fn all_mysql_column_types_must_work() {
 
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -617,6 +695,7 @@ async fn all_mysql_column_types_must_work() {
         table,
         Table {
             name: "User".to_string(),
+            schema: None,
             columns: expected_columns,
             indices: vec![],
             primary_key: Some(PrimaryKey {
@@ -625,6 +704,8 @@ async fn all_mysql_column_types_must_work() {
                 constraint_name: None,
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
+            comment: None,
         }
     );
 }
@@ -656,6 +737,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
         table,
         Table {
             name: "User".to_string(),
+            schema: None,
             columns: vec![
                 Column {
                     name: "id".to_string(),
@@ -670,6 +752,8 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
 
                     default: None,
                     auto_increment: true,
+                    generated: None,
+                    comment: None,
                 },
                 Column {
                     name: "city".to_string(),
@@ -683,6 +767,8 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generated: None,
+                    comment: None,
                 },
                 Column {
                     name: "city_cascade".to_string(),
@@ -696,6 +782,8 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generated: None,
+                    comment: None,
                 },
                 Column {
                     name: "city_restrict".to_string(),
@@ -709,6 +797,8 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generated: None,
+                    comment: None,
                 },
                 Column {
                     name: "city_set_null".to_string(),
@@ -722,6 +812,8 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generated: None,
+                    comment: None,
                 },
             ],
             indices: vec![
@@ -729,21 +821,33 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     name: "city".to_owned(),
                     columns: vec!["city".to_owned(),],
                     tpe: IndexType::Normal,
+                    column_options: vec![],
+                    algorithm: None,
+                    predicate: None,
                 },
                 Index {
                     name: "city_cascade".to_owned(),
                     columns: vec!["city_cascade".to_owned(),],
                     tpe: IndexType::Normal,
+                    column_options: vec![],
+                    algorithm: None,
+                    predicate: None,
                 },
                 Index {
                     name: "city_restrict".to_owned(),
                     columns: vec!["city_restrict".to_owned(),],
                     tpe: IndexType::Normal,
+                    column_options: vec![],
+                    algorithm: None,
+                    predicate: None,
                 },
                 Index {
                     name: "city_set_null".to_owned(),
                     columns: vec!["city_set_null".to_owned(),],
                     tpe: IndexType::Normal,
+                    column_options: vec![],
+                    algorithm: None,
+                    predicate: None,
                 }
             ],
             primary_key: Some(PrimaryKey {
@@ -757,6 +861,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::NoAction,
                     on_update_action: ForeignKeyAction::NoAction,
                 },
@@ -765,6 +870,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_cascade".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::Cascade,
                     on_update_action: ForeignKeyAction::NoAction,
                 },
@@ -773,6 +879,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_restrict".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::Restrict,
                     on_update_action: ForeignKeyAction::NoAction,
                 },
@@ -781,10 +888,13 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_set_null".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::SetNull,
                     on_update_action: ForeignKeyAction::NoAction,
                 },
             ],
+            check_constraints: vec![],
+            comment: None,
         }
     );
 }
@@ -812,6 +922,9 @@ async fn mysql_multi_field_indexes_must_be_inferred() {
             name: "age_and_name_index".into(),
             columns: vec!["name".to_owned(), "age".to_owned()],
             tpe: IndexType::Unique,
+            column_options: vec![],
+            algorithm: None,
+            predicate: None,
         }]
     );
 }
@@ -850,6 +963,9 @@ async fn mysql_join_table_unique_indexes_must_be_inferred() {
             name: "cat_and_human_index".into(),
             columns: vec!["cat".to_owned(), "human".to_owned()],
             tpe: IndexType::Unique,
+            column_options: vec![],
+            algorithm: None,
+            predicate: None,
         }]
     );
 }
@@ -887,6 +1003,7 @@ async fn constraints_from_other_databases_should_not_be_introspected() {
             constraint_name: Some("Post_ibfk_1".into()),
             columns: vec!["user_id".into()],
             referenced_table: "User".into(),
+            referenced_schema: None,
             referenced_columns: vec!["id".into()],
             on_delete_action: ForeignKeyAction::Cascade,
             on_update_action: ForeignKeyAction::NoAction,
@@ -919,6 +1036,7 @@ async fn constraints_from_other_databases_should_not_be_introspected() {
             constraint_name: Some("Post_ibfk_1".into()),
             columns: vec!["user_id".into()],
             referenced_table: "User".into(),
+            referenced_schema: None,
             referenced_columns: vec!["id".into()],
             on_delete_action: ForeignKeyAction::Restrict,
             on_update_action: ForeignKeyAction::NoAction,
@@ -1035,3 +1153,158 @@ async fn escaped_backslashes_in_string_literals_must_be_unescaped(api: &TestApi)
 
     Ok(())
 }
+
+#[test_each_connector(tags("mysql_8"))]
+async fn generated_columns_are_described(api: &TestApi) -> TestResult {
+    let create_table = r#"
+        CREATE TABLE order_items (
+            id INTEGER PRIMARY KEY,
+            price INTEGER NOT NULL,
+            quantity INTEGER NOT NULL,
+            total INTEGER GENERATED ALWAYS AS (price * quantity) STORED,
+            label VARCHAR(191) AS (CONCAT('#', id)) VIRTUAL,
+            created_at DATETIME NOT NULL DEFAULT (CURRENT_TIMESTAMP)
+        )
+    "#;
+
+    api.database().query_raw(&create_table, &[]).await?;
+
+    let schema = api.describe().await?;
+    let table = schema.table_bang("order_items");
+
+    let total = table.column_bang("total").generated.as_ref().unwrap();
+    assert!(total.stored);
+    assert!(
+        total.expression.contains("`price` * `quantity`"),
+        "{}",
+        total.expression
+    );
+
+    let label = table.column_bang("label").generated.as_ref().unwrap();
+    assert!(!label.stored);
+
+    assert_eq!(table.column_bang("created_at").generated, None);
+    assert_eq!(table.column_bang("price").generated, None);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn table_and_column_comments_are_described(api: &TestApi) -> TestResult {
+    let create_table = r#"
+        CREATE TABLE Cat (
+            id INTEGER PRIMARY KEY,
+            name VARCHAR(191) NOT NULL COMMENT 'The name of the cat, if it''s known.'
+        ) COMMENT='The cats of the shelter.'
+    "#;
+
+    api.database().query_raw(&create_table, &[]).await?;
+
+    let schema = api.describe().await?;
+    let table = schema.table_bang("Cat");
+
+    assert_eq!(table.comment.as_deref(), Some("The cats of the shelter."));
+    assert_eq!(
+        table.column_bang("name").comment.as_deref(),
+        Some("The name of the cat, if it's known.")
+    );
+    assert_eq!(table.column_bang("id").comment, None);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn views_are_described(api: &TestApi) -> TestResult {
+    let create_table = r#"
+        CREATE TABLE Cat (
+            id INTEGER PRIMARY KEY,
+            name VARCHAR(191) NOT NULL,
+            mood ENUM('happy', 'grumpy') NOT NULL
+        )
+    "#;
+
+    api.database().query_raw(&create_table, &[]).await?;
+    api.database()
+        .query_raw("CREATE VIEW CatNames AS SELECT id, name, mood FROM Cat", &[])
+        .await?;
+
+    let schema = api.describe().await?;
+    let view = schema.get_view("CatNames").unwrap();
+
+    assert!(schema.get_table("CatNames").is_none());
+    assert_eq!(
+        view.columns.iter().map(|c| c.name.as_str()).collect::<Vec<_>>(),
+        &["id", "name", "mood"]
+    );
+    assert_eq!(view.column("id").unwrap().tpe.family, ColumnTypeFamily::Int);
+    assert_eq!(view.column("name").unwrap().tpe.family, ColumnTypeFamily::String);
+    assert!(view.definition.as_deref().unwrap().contains("`Cat`"));
+
+    // Enum columns of views get their enums, like the columns of tables.
+    assert!(matches!(
+        &view.column("mood").unwrap().tpe.family,
+        ColumnTypeFamily::Enum(name) if schema.get_enum(name).is_some()
+    ));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn check_constraints_are_described(api: &TestApi) -> TestResult {
+    let create_table = r#"
+        CREATE TABLE products (
+            id INTEGER PRIMARY KEY,
+            price INTEGER NOT NULL,
+            CONSTRAINT price_check CHECK (price > 0)
+        )
+    "#;
+
+    api.database().query_raw(&create_table, &[]).await?;
+
+    let schema = api.describe().await?;
+    let table = schema.table_bang("products");
+
+    let expected: &[CheckConstraint] = match api.connector_name() {
+        // MySQL before 8.0.16 parses check constraints but doesn't store them, and has no
+        // `information_schema.check_constraints` table.
+        "mysql" | "mysql_5_6" => &[],
+        "mysql_mariadb" => &[CheckConstraint {
+            name: Some("price_check".into()),
+            expression: "`price` > 0".into(),
+        }],
+        _ => &[CheckConstraint {
+            name: Some("price_check".into()),
+            expression: "(`price` > 0)".into(),
+        }],
+    };
+
+    assert_eq!(table.check_constraints, expected);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mariadb"))]
+async fn implicit_json_check_constraints_are_not_described_on_mariadb(api: &TestApi) -> TestResult {
+    let create_table = r#"
+        CREATE TABLE products (
+            id INTEGER PRIMARY KEY,
+            data JSON NOT NULL,
+            CONSTRAINT data_check CHECK (json_length(data) > 0)
+        )
+    "#;
+
+    api.database().query_raw(&create_table, &[]).await?;
+
+    let schema = api.describe().await?;
+    let table = schema.table_bang("products");
+
+    assert_eq!(
+        table.check_constraints,
+        &[CheckConstraint {
+            name: Some("data_check".into()),
+            expression: "json_length(`data`) > 0".into(),
+        }]
+    );
+
+    Ok(())
+}
@@ -6,6 +6,7 @@ use crate::{common::*, postgres::*};
 use barrel::{types, Migration};
 use native_types::{NativeType, PostgresType};
 use pretty_assertions::assert_eq;
+use prisma_value::PrismaValue;
 use quaint::prelude::Queryable;
 use sql_schema_describer::*;
 use test_api::*;
@@ -80,6 +81,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "array_bool_col".into(),
@@ -93,6 +96,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "array_date_col".into(),
@@ -106,6 +111,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "array_double_col".into(),
@@ -119,6 +126,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "array_float_col".into(),
@@ -132,6 +141,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "array_int_col".into(),
@@ -145,6 +156,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "array_text_col".into(),
@@ -158,6 +171,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "array_varchar_col".into(),
@@ -171,6 +186,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "binary_col".into(),
@@ -184,6 +201,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "boolean_col".into(),
@@ -197,6 +216,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "date_time_col".into(),
@@ -210,6 +231,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "double_col".into(),
@@ -223,6 +246,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "float_col".into(),
@@ -236,6 +261,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "int_col".into(),
@@ -249,6 +276,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "primary_col".into(),
@@ -262,6 +291,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: Some(DefaultValue::sequence("User_primary_col_seq".to_string())),
             auto_increment: true,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "string1_col".into(),
@@ -275,6 +306,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "string2_col".into(),
@@ -288,6 +321,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "bigint_col".into(),
@@ -301,6 +336,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "bigserial_col".into(),
@@ -314,6 +351,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: Some(DefaultValue::sequence("User_bigserial_col_seq".to_string())),
             auto_increment: true,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "bit_col".into(),
@@ -327,6 +366,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "bit_varying_col".into(),
@@ -340,6 +381,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "box_col".into(),
@@ -353,6 +396,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "char_col".into(),
@@ -366,6 +411,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "circle_col".into(),
@@ -379,6 +426,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "line_col".into(),
@@ -392,6 +441,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "time_col".into(),
@@ -405,6 +456,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "timetz_col".into(),
@@ -418,6 +471,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "timestamp_col".into(),
@@ -431,6 +486,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "timestamptz_col".into(),
@@ -444,6 +501,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "lseg_col".into(),
@@ -457,6 +516,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "numeric_col".into(),
@@ -470,6 +531,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "path_col".into(),
@@ -483,6 +546,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "pg_lsn_col".into(),
@@ -496,6 +561,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "polygon_col".into(),
@@ -509,6 +576,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "smallint_col".into(),
@@ -522,6 +591,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "smallserial_col".into(),
@@ -535,6 +606,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: Some(DefaultValue::sequence("User_smallserial_col_seq".to_string())),
             auto_increment: true,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "serial_col".into(),
@@ -548,6 +621,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: Some(DefaultValue::sequence("User_serial_col_seq".to_string())),
             auto_increment: true,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "tsquery_col".into(),
@@ -561,6 +636,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "tsvector_col".into(),
@@ -574,6 +651,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "txid_col".into(),
@@ -587,6 +666,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "json_col".into(),
@@ -600,6 +681,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "jsonb_col".into(),
@@ -613,6 +696,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "uuid_col".into(),
@@ -627,6 +712,8 @@ async fn all_postgres_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
     ];
     expected_columns.sort_unstable_by_key(|c| c.name.to_owned());
@@ -635,11 +722,15 @@ async fn all_postgres_column_types_must_work() {
         table,
         Table {
             name: "User".into(),
+            schema: None,
             columns: expected_columns,
             indices: vec![Index {
                 name: "User_uuid_col_key".into(),
                 columns: vec!["uuid_col".into(),],
                 tpe: IndexType::Unique,
+                column_options: vec![],
+                algorithm: None,
+                predicate: None,
             },],
             primary_key: Some(PrimaryKey {
                 columns: vec!["primary_col".into()],
@@ -649,6 +740,8 @@ async fn all_postgres_column_types_must_work() {
                 constraint_name: Some("User_pkey".into()),
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
+            comment: None,
         }
     );
 }
@@ -678,6 +771,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
         table,
         Table {
             name: "User".into(),
+            schema: None,
             columns: vec![
                 Column {
                     name: "id".into(),
@@ -693,6 +787,8 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
 
                     default: None,
                     auto_increment: false,
+                    generated: None,
+                    comment: None,
                 },
                 Column {
                     name: "city".into(),
@@ -707,6 +803,8 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generated: None,
+                    comment: None,
                 },
                 Column {
                     name: "city_cascade".into(),
@@ -721,6 +819,8 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generated: None,
+                    comment: None,
                 },
                 Column {
                     name: "city_restrict".into(),
@@ -735,6 +835,8 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generated: None,
+                    comment: None,
                 },
                 Column {
                     name: "city_set_null".into(),
@@ -749,6 +851,8 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generated: None,
+                    comment: None,
                 },
                 Column {
                     name: "city_set_default".into(),
@@ -763,6 +867,8 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generated: None,
+                    comment: None,
                 },
             ],
             indices: vec![],
@@ -777,6 +883,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city".into()],
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
+                    referenced_schema: None,
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::NoAction,
                 },
@@ -785,6 +892,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_cascade".into()],
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
+                    referenced_schema: None,
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::Cascade,
                 },
@@ -793,6 +901,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_restrict".into()],
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
+                    referenced_schema: None,
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::Restrict,
                 },
@@ -801,6 +910,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_set_default".into()],
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
+                    referenced_schema: None,
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::SetDefault,
                 },
@@ -809,10 +919,13 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_set_null".into()],
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
+                    referenced_schema: None,
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::SetNull,
                 },
             ],
+            check_constraints: vec![],
+            comment: None,
         }
     );
 }
@@ -833,6 +946,7 @@ async fn postgres_enums_must_work() {
         got_enum,
         &Enum {
             name: "mood".into(),
+            schema: None,
             values,
         }
     );
@@ -960,3 +1074,286 @@ async fn escaped_backslashes_in_string_literals_must_be_unescaped(api: &TestApi)
 
     Ok(())
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn check_constraints_are_described(api: &TestApi) -> TestResult {
+    let create_table = format!(
+        r#"
+            CREATE TABLE "{0}"."products" (
+                id INTEGER PRIMARY KEY,
+                price INTEGER NOT NULL,
+                CONSTRAINT "price_check" CHECK (price > 0)
+            );
+        "#,
+        api.schema_name()
+    );
+
+    api.database().query_raw(&create_table, &[]).await?;
+
+    let schema = api.describe().await?;
+    let table = schema.table_bang("products");
+
+    assert_eq!(
+        table.check_constraints,
+        &[CheckConstraint {
+            name: Some("price_check".into()),
+            expression: "(price > 0)".into(),
+        }]
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn index_options_are_described(api: &TestApi) -> TestResult {
+    let sql = format!(
+        r#"
+            CREATE TABLE "{0}"."posts" (
+                id INTEGER PRIMARY KEY,
+                slug TEXT NOT NULL,
+                created_at TIMESTAMP NOT NULL,
+                tags JSONB NOT NULL,
+                deleted_at TIMESTAMP
+            );
+
+            CREATE INDEX "posts_created_at_slug" ON "{0}"."posts" (created_at DESC, slug);
+            CREATE INDEX "posts_tags" ON "{0}"."posts" USING GIN (tags);
+            CREATE UNIQUE INDEX "posts_slug_live" ON "{0}"."posts" (slug) WHERE deleted_at IS NULL;
+        "#,
+        api.schema_name()
+    );
+
+    api.database().raw_cmd(&sql).await?;
+
+    let schema = api.describe().await?;
+    let table = schema.table_bang("posts");
+
+    let created_at_slug = table
+        .indices
+        .iter()
+        .find(|i| i.name == "posts_created_at_slug")
+        .unwrap();
+    assert_eq!(created_at_slug.column_options(0).sort_order, SortOrder::Desc);
+    assert_eq!(created_at_slug.column_options(1).sort_order, SortOrder::Asc);
+    assert_eq!(created_at_slug.algorithm, None);
+
+    let tags = table.indices.iter().find(|i| i.name == "posts_tags").unwrap();
+    assert_eq!(tags.algorithm, Some(IndexAlgorithm::Gin));
+    assert!(tags.column_options.is_empty());
+
+    let slug_live = table.indices.iter().find(|i| i.name == "posts_slug_live").unwrap();
+    assert!(slug_live.is_unique());
+    assert_eq!(slug_live.predicate.as_deref(), Some("(deleted_at IS NULL)"));
+    assert!(!table.is_column_unique("slug"));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn generated_columns_are_described(api: &TestApi) -> TestResult {
+    // Generated columns only exist from Postgres 12 on.
+    if matches!(api.connector_name(), "postgres9" | "postgres" | "postgres11") {
+        return Ok(());
+    }
+
+    let sql = format!(
+        r#"
+            CREATE TABLE "{0}"."order_items" (
+                id INTEGER PRIMARY KEY,
+                price INTEGER NOT NULL,
+                quantity INTEGER NOT NULL,
+                total INTEGER GENERATED ALWAYS AS (price * quantity) STORED
+            );
+        "#,
+        api.schema_name()
+    );
+
+    api.database().raw_cmd(&sql).await?;
+
+    let schema = api.describe().await?;
+    let table = schema.table_bang("order_items");
+
+    assert_eq!(
+        table.column_bang("total").generated,
+        Some(GeneratedColumn {
+            expression: "(price * quantity)".into(),
+            stored: true,
+        })
+    );
+    assert_eq!(table.column_bang("total").default, None);
+    assert_eq!(table.column_bang("price").generated, None);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn table_and_column_comments_are_described(api: &TestApi) -> TestResult {
+    let sql = format!(
+        r#"
+            CREATE TABLE "{0}"."Cat" (
+                id INTEGER PRIMARY KEY,
+                name TEXT NOT NULL
+            );
+
+            COMMENT ON TABLE "{0}"."Cat" IS 'The cats of the shelter.';
+            COMMENT ON COLUMN "{0}"."Cat"."name" IS 'The name of the cat, if it''s known.';
+        "#,
+        api.schema_name()
+    );
+
+    api.database().raw_cmd(&sql).await?;
+
+    let schema = api.describe().await?;
+    let table = schema.table_bang("Cat");
+
+    assert_eq!(table.comment.as_deref(), Some("The cats of the shelter."));
+    assert_eq!(
+        table.column_bang("name").comment.as_deref(),
+        Some("The name of the cat, if it's known.")
+    );
+    assert_eq!(table.column_bang("id").comment, None);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn array_literal_defaults_are_described_as_lists(api: &TestApi) -> TestResult {
+    let sql = format!(
+        r#"
+            CREATE TABLE "{0}"."Cat" (
+                id INTEGER PRIMARY KEY,
+                ages INTEGER[] NOT NULL DEFAULT '{{1,2,3}}',
+                names TEXT[] NOT NULL DEFAULT '{{"Fluffy the \"first\"",Garfield}}',
+                flags BOOLEAN[] NOT NULL DEFAULT '{{}}',
+                computed INTEGER[] NOT NULL DEFAULT ARRAY[1, 2]
+            );
+        "#,
+        api.schema_name()
+    );
+
+    api.database().raw_cmd(&sql).await?;
+
+    let schema = api.describe().await?;
+    let table = schema.table_bang("Cat");
+
+    assert_eq!(
+        table.column_bang("ages").default,
+        Some(DefaultValue::value(PrismaValue::List(vec![
+            PrismaValue::Int(1),
+            PrismaValue::Int(2),
+            PrismaValue::Int(3),
+        ])))
+    );
+    assert_eq!(
+        table.column_bang("names").default,
+        Some(DefaultValue::value(PrismaValue::List(vec![
+            PrismaValue::String("Fluffy the \"first\"".into()),
+            PrismaValue::String("Garfield".into()),
+        ])))
+    );
+    assert_eq!(
+        table.column_bang("flags").default,
+        Some(DefaultValue::value(PrismaValue::List(vec![])))
+    );
+    assert!(matches!(
+        table.column_bang("computed").default.as_ref().map(|d| d.kind()),
+        Some(DefaultKind::DBGENERATED(_))
+    ));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn views_are_described(api: &TestApi) -> TestResult {
+    let sql = format!(
+        r#"
+            CREATE TABLE "{0}"."Cat" (
+                id INTEGER PRIMARY KEY,
+                name TEXT NOT NULL
+            );
+
+            CREATE VIEW "{0}"."CatNames" AS SELECT id, name FROM "{0}"."Cat";
+        "#,
+        api.schema_name()
+    );
+
+    api.database().raw_cmd(&sql).await?;
+
+    let schema = api.describe().await?;
+    let view = schema.get_view("CatNames").unwrap();
+
+    assert!(schema.get_table("CatNames").is_none());
+    assert_eq!(
+        view.columns.iter().map(|c| c.name.as_str()).collect::<Vec<_>>(),
+        &["id", "name"]
+    );
+    assert_eq!(view.column("id").unwrap().tpe.family, ColumnTypeFamily::Int);
+    assert_eq!(view.column("name").unwrap().tpe.family, ColumnTypeFamily::String);
+    assert!(view.definition.as_deref().unwrap().contains("FROM"));
+
+    // The columns of the view are not mistaken for columns of the table.
+    assert_eq!(schema.table_bang("Cat").columns.len(), 2);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn foreign_keys_to_other_schemas_are_described_with_their_schema(api: &TestApi) -> TestResult {
+    let other_schema = format!("{}_other", api.schema_name());
+
+    let sql = format!(
+        r#"
+            DROP SCHEMA IF EXISTS "{1}" CASCADE;
+            CREATE SCHEMA "{1}";
+
+            CREATE TABLE "{1}"."User" (id INTEGER PRIMARY KEY);
+            CREATE TABLE "{0}"."User" (id INTEGER PRIMARY KEY);
+
+            CREATE TABLE "{0}"."Post" (
+                id INTEGER PRIMARY KEY,
+                author_id INTEGER NOT NULL REFERENCES "{1}"."User"(id),
+                editor_id INTEGER NOT NULL REFERENCES "{0}"."User"(id)
+            );
+
+            CREATE TABLE "{1}"."Comment" (
+                id INTEGER PRIMARY KEY,
+                post_id INTEGER NOT NULL REFERENCES "{0}"."Post"(id),
+                user_id INTEGER NOT NULL REFERENCES "{1}"."User"(id)
+            );
+        "#,
+        api.schema_name(),
+        other_schema,
+    );
+
+    api.database().raw_cmd(&sql).await?;
+
+    // Described on its own, the schema of the test references the other schema explicitly.
+    let schema = api.describe().await?;
+    let post = schema.table_bang("Post");
+
+    assert_eq!(
+        post.foreign_keys[0].referenced_schema.as_deref(),
+        Some(other_schema.as_str())
+    );
+    assert_eq!(post.foreign_keys[1].referenced_schema, None);
+
+    let schema = api.describe_schemas(&[other_schema.clone()]).await?;
+    let post = schema.table_walkers().find(|table| table.name() == "Post").unwrap();
+    let comment = schema.table_walkers().find(|table| table.name() == "Comment").unwrap();
+
+    let fks: Vec<_> = post.foreign_keys().collect();
+    assert_eq!(fks[0].referenced_schema_name(), Some(other_schema.as_str()));
+    assert_eq!(fks[0].referenced_table().schema_name(), Some(other_schema.as_str()));
+    assert_eq!(fks[1].referenced_schema_name(), None);
+    assert_eq!(fks[1].referenced_table().schema_name(), None);
+
+    // The foreign keys of the other schema are relative to the schema of the test.
+    let fks: Vec<_> = comment.foreign_keys().collect();
+    assert_eq!(comment.schema_name(), Some(other_schema.as_str()));
+    assert_eq!(fks[0].referenced_schema_name(), None);
+    assert_eq!(fks[0].referenced_table().name(), "Post");
+    assert_eq!(fks[1].referenced_schema_name(), Some(other_schema.as_str()));
+    assert_eq!(fks[1].referenced_table().schema_name(), Some(other_schema.as_str()));
+
+    Ok(())
+}
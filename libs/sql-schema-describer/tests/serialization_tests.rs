@@ -24,6 +24,7 @@ fn database_schema_is_serializable() {
         tables: vec![
             Table {
                 name: "table1".to_string(),
+                schema: None,
                 columns: vec![
                     Column {
                         name: "column1".to_string(),
@@ -38,6 +39,8 @@ fn database_schema_is_serializable() {
                         },
                         default: None,
                         auto_increment: true,
+                        generated: None,
+                        comment: None,
                     },
                     Column {
                         name: "column2".to_string(),
@@ -52,6 +55,8 @@ fn database_schema_is_serializable() {
                         },
                         default: Some(DefaultValue::value("default value".to_string())),
                         auto_increment: false,
+                        generated: None,
+                        comment: None,
                     },
                     Column {
                         name: "column3".to_string(),
@@ -66,12 +71,17 @@ fn database_schema_is_serializable() {
                         },
                         default: None,
                         auto_increment: false,
+                        generated: None,
+                        comment: None,
                     },
                 ],
                 indices: vec![Index {
                     name: "column2".to_string(),
                     columns: vec!["column2".to_string()],
                     tpe: IndexType::Normal,
+                    column_options: vec![],
+                    algorithm: None,
+                    predicate: None,
                 }],
                 primary_key: Some(PrimaryKey {
                     columns: vec!["column1".to_string()],
@@ -82,13 +92,17 @@ fn database_schema_is_serializable() {
                     constraint_name: None,
                     columns: vec!["column3".to_string()],
                     referenced_table: "table2".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::NoAction,
                 }],
+                check_constraints: vec![],
+                comment: None,
             },
             Table {
                 name: "table2".to_string(),
+                schema: None,
                 columns: vec![Column {
                     name: "id".to_string(),
                     tpe: ColumnType {
@@ -102,6 +116,8 @@ fn database_schema_is_serializable() {
                     },
                     default: None,
                     auto_increment: true,
+                    generated: None,
+                    comment: None,
                 }],
                 indices: vec![],
                 primary_key: Some(PrimaryKey {
@@ -110,15 +126,19 @@ fn database_schema_is_serializable() {
                     constraint_name: None,
                 }),
                 foreign_keys: vec![],
+                check_constraints: vec![],
+                comment: None,
             },
         ],
         enums: vec![Enum {
             name: "enum1".to_string(),
+            schema: None,
             values: enum_values,
         }],
         sequences: vec![Sequence {
             name: "sequence1".to_string(),
         }],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -137,6 +157,7 @@ fn database_schema_without_primary_key_is_serializable() {
     let schema = SqlSchema {
         tables: vec![Table {
             name: "table1".to_string(),
+            schema: None,
             columns: vec![Column {
                 name: "column1".to_string(),
                 tpe: ColumnType {
@@ -150,13 +171,18 @@ fn database_schema_without_primary_key_is_serializable() {
                 },
                 default: None,
                 auto_increment: false,
+                generated: None,
+                comment: None,
             }],
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            check_constraints: vec![],
+            comment: None,
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-without-primary-key.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -197,18 +223,24 @@ fn database_schema_is_serializable_for_every_column_type_family() {
         },
         default: None,
         auto_increment: false,
+        generated: None,
+        comment: None,
     })
     .collect();
     let schema = SqlSchema {
         tables: vec![Table {
             name: "table1".to_string(),
+            schema: None,
             columns,
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            check_constraints: vec![],
+            comment: None,
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-all-column-type-families.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -241,18 +273,24 @@ fn database_schema_is_serializable_for_every_column_arity() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         })
         .collect();
     let schema = SqlSchema {
         tables: vec![Table {
             name: "table1".to_string(),
+            schema: None,
             columns,
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            check_constraints: vec![],
+            comment: None,
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-all-column-arities.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -272,6 +310,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
     let schema = SqlSchema {
         tables: vec![Table {
             name: "table1".to_string(),
+            schema: None,
             columns: vec![
                 Column {
                     name: "column1".to_string(),
@@ -285,6 +324,8 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                         native_type: Some(PostgresType::Integer.to_json()),
                     },
                     auto_increment: false,
+                    generated: None,
+                    comment: None,
                     default: None,
                 },
                 Column {
@@ -299,6 +340,8 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                         native_type: Some(PostgresType::Integer.to_json()),
                     },
                     auto_increment: false,
+                    generated: None,
+                    comment: None,
                     default: None,
                 },
                 Column {
@@ -313,6 +356,8 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                         native_type: Some(PostgresType::Integer.to_json()),
                     },
                     auto_increment: false,
+                    generated: None,
+                    comment: None,
                     default: None,
                 },
                 Column {
@@ -327,6 +372,8 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                         native_type: Some(PostgresType::Integer.to_json()),
                     },
                     auto_increment: false,
+                    generated: None,
+                    comment: None,
                     default: None,
                 },
                 Column {
@@ -341,6 +388,8 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                         native_type: Some(PostgresType::Integer.to_json()),
                     },
                     auto_increment: false,
+                    generated: None,
+                    comment: None,
                     default: None,
                 },
             ],
@@ -351,6 +400,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     constraint_name: None,
                     columns: vec!["column1".to_string()],
                     referenced_table: "table2".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::NoAction,
@@ -359,6 +409,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     constraint_name: None,
                     columns: vec!["column2".to_string()],
                     referenced_table: "table2".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::Restrict,
@@ -367,6 +418,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     constraint_name: None,
                     columns: vec!["column3".to_string()],
                     referenced_table: "table2".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::Cascade,
@@ -375,6 +427,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     constraint_name: None,
                     columns: vec!["column4".to_string()],
                     referenced_table: "table2".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::SetNull,
@@ -383,14 +436,18 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     constraint_name: None,
                     columns: vec!["column5".to_string()],
                     referenced_table: "table2".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::SetDefault,
                 },
             ],
+            check_constraints: vec![],
+            comment: None,
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-all-foreign-key-actions.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -40,6 +40,8 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "int4_col".to_string(),
@@ -53,6 +55,8 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "text_col".to_string(),
@@ -66,6 +70,8 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "real_col".to_string(),
@@ -79,6 +85,8 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "primary_col".to_string(),
@@ -92,6 +100,8 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: true,
+            generated: None,
+            comment: None,
         },
         Column {
             name: "decimal_col".to_string(),
@@ -105,6 +115,8 @@ async fn sqlite_column_types_must_work() {
             },
             default: None,
             auto_increment: false,
+            generated: None,
+            comment: None,
         },
     ];
 
@@ -112,6 +124,7 @@ async fn sqlite_column_types_must_work() {
         table,
         &Table {
             name: "User".to_string(),
+            schema: None,
             columns: expected_columns,
             indices: vec![],
             primary_key: Some(PrimaryKey {
@@ -120,6 +133,8 @@ async fn sqlite_column_types_must_work() {
                 constraint_name: None,
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
+            comment: None,
         }
     );
 }
@@ -146,6 +161,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
         table,
         Table {
             name: "User".to_string(),
+            schema: None,
             columns: vec![
                 Column {
                     name: "id".to_string(),
@@ -159,6 +175,8 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: true,
+                    generated: None,
+                    comment: None,
                 },
                 Column {
                     name: "city".to_string(),
@@ -172,6 +190,8 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generated: None,
+                    comment: None,
                 },
                 Column {
                     name: "city_cascade".to_string(),
@@ -185,6 +205,8 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generated: None,
+                    comment: None,
                 },
                 Column {
                     name: "city_restrict".to_string(),
@@ -198,6 +220,8 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generated: None,
+                    comment: None,
                 },
                 Column {
                     name: "city_set_default".to_string(),
@@ -211,6 +235,8 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generated: None,
+                    comment: None,
                 },
                 Column {
                     name: "city_set_null".to_string(),
@@ -225,6 +251,8 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     },
                     default: None,
                     auto_increment: false,
+                    generated: None,
+                    comment: None,
                 },
             ],
             indices: vec![],
@@ -239,6 +267,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::NoAction,
                 },
@@ -247,6 +276,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_cascade".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::Cascade,
                 },
@@ -255,6 +285,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_restrict".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::Restrict,
                 },
@@ -263,6 +294,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_set_default".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::SetDefault,
                 },
@@ -271,10 +303,13 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_set_null".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_update_action: ForeignKeyAction::NoAction,
                     on_delete_action: ForeignKeyAction::SetNull,
                 },
             ],
+            check_constraints: vec![],
+            comment: None,
         }
     );
 }
@@ -395,3 +430,127 @@ async fn escaped_backslashes_in_string_literals_must_be_unescaped(api: &TestApi)
 
     Ok(())
 }
+
+#[test_each_connector(tags("sqlite"))]
+async fn check_constraints_are_described(api: &TestApi) -> TestResult {
+    let create_table = format!(
+        r#"
+            CREATE TABLE "{0}"."products" (
+                id INTEGER PRIMARY KEY,
+                price INTEGER NOT NULL CHECK (price > 0),
+                status TEXT NOT NULL,
+                CONSTRAINT "status_check" CHECK (status IN ('draft', 'published (live)'))
+            );
+        "#,
+        api.schema_name()
+    );
+
+    api.database().query_raw(&create_table, &[]).await?;
+
+    let schema = api.describe().await?;
+    let table = schema.table_bang("products");
+
+    assert_eq!(
+        table.check_constraints,
+        &[
+            CheckConstraint {
+                name: None,
+                expression: "price > 0".into(),
+            },
+            CheckConstraint {
+                name: Some("status_check".into()),
+                expression: "status IN ('draft', 'published (live)')".into(),
+            },
+        ]
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn index_options_are_described(api: &TestApi) -> TestResult {
+    let create_table = format!(
+        r#"
+            CREATE TABLE "{0}"."posts" (
+                id INTEGER PRIMARY KEY,
+                slug TEXT NOT NULL,
+                created_at DATETIME NOT NULL,
+                deleted_at DATETIME
+            );
+        "#,
+        api.schema_name()
+    );
+
+    let create_indexes = [
+        format!(
+            r#"CREATE INDEX "{0}"."posts_created_at_slug" ON "posts" (created_at DESC, slug);"#,
+            api.schema_name()
+        ),
+        format!(
+            r#"CREATE UNIQUE INDEX "{0}"."posts_slug_live" ON "posts" (slug) WHERE deleted_at IS NULL;"#,
+            api.schema_name()
+        ),
+    ];
+
+    api.database().query_raw(&create_table, &[]).await?;
+
+    for create_index in create_indexes.iter() {
+        api.database().query_raw(create_index, &[]).await?;
+    }
+
+    let schema = api.describe().await?;
+    let table = schema.table_bang("posts");
+
+    let created_at_slug = table
+        .indices
+        .iter()
+        .find(|i| i.name == "posts_created_at_slug")
+        .unwrap();
+    assert_eq!(created_at_slug.columns, &["created_at", "slug"]);
+    assert_eq!(created_at_slug.column_options(0).sort_order, SortOrder::Desc);
+    assert_eq!(created_at_slug.column_options(1).sort_order, SortOrder::Asc);
+
+    let slug_live = table.indices.iter().find(|i| i.name == "posts_slug_live").unwrap();
+    assert!(slug_live.is_unique());
+    assert_eq!(slug_live.predicate.as_deref(), Some("deleted_at IS NULL"));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn views_are_described(api: &TestApi) -> TestResult {
+    let create_table = format!(
+        r#"
+            CREATE TABLE "{0}"."Cat" (
+                id INTEGER PRIMARY KEY,
+                name TEXT NOT NULL
+            );
+        "#,
+        api.schema_name()
+    );
+
+    let create_view = format!(
+        r#"CREATE VIEW "{0}"."CatNames" AS SELECT id, name FROM "Cat";"#,
+        api.schema_name()
+    );
+
+    api.database().query_raw(&create_table, &[]).await?;
+    api.database().query_raw(&create_view, &[]).await?;
+
+    let schema = api.describe().await?;
+    let view = schema.get_view("CatNames").unwrap();
+
+    assert!(schema.get_table("CatNames").is_none());
+    assert_eq!(
+        view.columns.iter().map(|c| c.name.as_str()).collect::<Vec<_>>(),
+        &["id", "name"]
+    );
+    assert_eq!(view.column("id").unwrap().tpe.family, ColumnTypeFamily::Int);
+    assert!(view
+        .definition
+        .as_deref()
+        .unwrap()
+        .contains(r#"AS SELECT id, name FROM "Cat""#));
+
+    Ok(())
+}
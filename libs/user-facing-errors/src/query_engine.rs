@@ -241,3 +241,48 @@ pub struct TableDoesNotExist {
 pub struct ColumnDoesNotExist {
     pub column: String,
 }
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(
+    code = "P2023",
+    message = "Timed out fetching a new connection from the connection pool. (Connection limit: {connection_limit}, connections in use: {in_use}, pool timeout: {pool_timeout})"
+)]
+pub struct PoolTimeout {
+    /// The maximum number of open connections of the pool.
+    pub connection_limit: String,
+
+    /// The number of connections checked out of the pool when the timeout occurred.
+    pub in_use: String,
+
+    /// The time waited for a free connection.
+    pub pool_timeout: String,
+}
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(
+    code = "P2024",
+    message = "The record to update on model `{model_name}` was not found with the expected version `{expected_version}`. It was updated or deleted concurrently."
+)]
+pub struct VersionConflict {
+    /// The model of the record to update.
+    pub model_name: String,
+
+    /// The version the record was expected to have.
+    pub expected_version: String,
+}
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(
+    code = "P2025",
+    message = "The query exceeds the {limit} limit: {value} is more than the allowed maximum of {max}."
+)]
+pub struct QueryLimitExceeded {
+    /// The exceeded limit, e.g. `selection depth`.
+    pub limit: String,
+
+    /// The value of the query for the limit.
+    pub value: String,
+
+    /// The configured maximum.
+    pub max: String,
+}
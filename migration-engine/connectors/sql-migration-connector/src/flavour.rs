@@ -31,18 +31,27 @@ use std::fmt::Debug;
 /// reference: https://dev.mysql.com/doc/refman/5.7/en/identifier-length.html
 pub(crate) const MYSQL_IDENTIFIER_SIZE_LIMIT: usize = 64;
 
-pub(crate) fn from_connection_info(connection_info: &ConnectionInfo) -> Box<dyn SqlFlavour + Send + Sync + 'static> {
+pub(crate) fn from_connection_info(
+    connection_info: &ConnectionInfo,
+    schemas: &[String],
+) -> Box<dyn SqlFlavour + Send + Sync + 'static> {
     match connection_info {
         ConnectionInfo::Mysql(url) => Box::new(MysqlFlavour {
             url: url.clone(),
             circumstances: Default::default(),
         }),
-        ConnectionInfo::Postgres(url) => Box::new(PostgresFlavour(url.clone())),
+        ConnectionInfo::Postgres(url) => Box::new(PostgresFlavour {
+            url: url.clone(),
+            schemas: schemas.to_owned(),
+        }),
         ConnectionInfo::Sqlite { file_path, db_name } => Box::new(SqliteFlavour {
             file_path: file_path.clone(),
             attached_name: db_name.clone(),
         }),
-        ConnectionInfo::Mssql(url) => Box::new(MssqlFlavour(url.clone())),
+        ConnectionInfo::Mssql(url) => Box::new(MssqlFlavour {
+            url: url.clone(),
+            schemas: schemas.to_owned(),
+        }),
         ConnectionInfo::InMemorySqlite { .. } => unreachable!("SqlFlavour for in-memory SQLite"),
     }
 }
@@ -92,6 +101,12 @@ pub(crate) trait SqlFlavour:
         connection: &Connection,
     ) -> ConnectorResult<SqlSchema>;
 
+    /// The database schema of the connection, on connectors supporting `@@schema`. Tables and enums
+    /// in this schema are described without an explicit schema.
+    fn connection_schema_name(&self) -> Option<&str> {
+        None
+    }
+
     /// Table to store applied migrations, the name part.
     fn imperative_migrations_table_name(&self) -> &'static str {
         "_prisma_migrations"
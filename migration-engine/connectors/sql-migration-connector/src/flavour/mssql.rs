@@ -10,11 +10,24 @@ use sql_schema_describer::{DescriberErrorKind, SqlSchema, SqlSchemaDescriberBack
 use std::str::FromStr;
 
 #[derive(Debug)]
-pub(crate) struct MssqlFlavour(pub(crate) MssqlUrl);
+pub(crate) struct MssqlFlavour {
+    pub(crate) url: MssqlUrl,
+    /// The schemas of the `schemas` datasource property, see `@@schema`.
+    pub(crate) schemas: Vec<String>,
+}
 
 impl MssqlFlavour {
     pub(crate) fn schema_name(&self) -> &str {
-        self.0.schema()
+        self.url.schema()
+    }
+
+    /// The schemas used by the datamodel, other than the schema of the connection.
+    fn other_schemas(&self) -> Vec<String> {
+        self.schemas
+            .iter()
+            .filter(|schema| schema.as_str() != self.schema_name())
+            .cloned()
+            .collect()
     }
 
     /// Get the url as a JDBC string, extract the database name, and re-encode the string.
@@ -69,7 +82,7 @@ impl SqlFlavour for MssqlFlavour {
 
     async fn describe_schema<'a>(&'a self, connection: &Connection) -> ConnectorResult<SqlSchema> {
         sql_schema_describer::mssql::SqlSchemaDescriber::new(connection.quaint().clone())
-            .describe(connection.connection_info().schema_name())
+            .describe_schemas(connection.connection_info().schema_name(), &self.other_schemas())
             .await
             .map_err(|err| match err.into_kind() {
                 DescriberErrorKind::QuaintError(err) => {
@@ -86,7 +99,11 @@ impl SqlFlavour for MssqlFlavour {
     }
 
     async fn reset(&self, connection: &Connection) -> ConnectorResult<()> {
-        let schema_name = connection.connection_info().schema_name();
+        let schema_names = std::iter::once(connection.connection_info().schema_name().to_owned())
+            .chain(self.other_schemas())
+            .map(|schema_name| format!("'{}'", schema_name))
+            .collect::<Vec<_>>()
+            .join(", ");
         let drop_fks = format!(
             r#"
             DECLARE @stmt NVARCHAR(max)
@@ -97,11 +114,11 @@ impl SqlFlavour for MssqlFlavour {
             SELECT @stmt = ISNULL(@stmt + @n, '') +
                 'ALTER TABLE [' + SCHEMA_NAME(schema_id) + '].[' + OBJECT_NAME(parent_object_id) + '] DROP CONSTRAINT [' + name + ']'
             FROM sys.foreign_keys
-            WHERE SCHEMA_NAME(schema_id) = '{0}'
+            WHERE SCHEMA_NAME(schema_id) IN ({0})
 
             EXEC SP_EXECUTESQL @stmt
             "#,
-            schema_name
+            schema_names
         );
 
         let drop_tables = format!(
@@ -114,11 +131,11 @@ impl SqlFlavour for MssqlFlavour {
             SELECT @stmt = ISNULL(@stmt + @n, '') +
                 'DROP TABLE [' + SCHEMA_NAME(schema_id) + '].[' + name + ']'
             FROM sys.tables
-            WHERE SCHEMA_NAME(schema_id) = '{0}'
+            WHERE SCHEMA_NAME(schema_id) IN ({0})
 
             EXEC SP_EXECUTESQL @stmt
             "#,
-            schema_name
+            schema_names
         );
 
         connection.raw_cmd(&drop_fks).await?;
@@ -161,6 +178,10 @@ impl SqlFlavour for MssqlFlavour {
         Ok(())
     }
 
+    fn connection_schema_name(&self) -> Option<&str> {
+        Some(self.schema_name())
+    }
+
     fn sql_family(&self) -> SqlFamily {
         SqlFamily::Mssql
     }
@@ -175,7 +196,7 @@ impl SqlFlavour for MssqlFlavour {
 
         connection.raw_cmd(&create_database).await?;
 
-        let mut jdbc_string: JdbcString = self.0.connection_string().parse().unwrap();
+        let mut jdbc_string: JdbcString = self.url.connection_string().parse().unwrap();
         jdbc_string
             .properties_mut()
             .insert("database".into(), database_name.clone());
@@ -8,11 +8,24 @@ use url::Url;
 use user_facing_errors::{common::DatabaseDoesNotExist, migration_engine, UserFacingError};
 
 #[derive(Debug)]
-pub(crate) struct PostgresFlavour(pub(crate) PostgresUrl);
+pub(crate) struct PostgresFlavour {
+    pub(crate) url: PostgresUrl,
+    /// The schemas of the `schemas` datasource property, see `@@schema`.
+    pub(crate) schemas: Vec<String>,
+}
 
 impl PostgresFlavour {
     pub(crate) fn schema_name(&self) -> &str {
-        self.0.schema()
+        self.url.schema()
+    }
+
+    /// The schemas used by the datamodel, other than the schema of the connection.
+    fn other_schemas(&self) -> Vec<String> {
+        self.schemas
+            .iter()
+            .filter(|schema| schema.as_str() != self.schema_name())
+            .cloned()
+            .collect()
     }
 }
 
@@ -20,7 +33,7 @@ impl PostgresFlavour {
 impl SqlFlavour for PostgresFlavour {
     async fn create_database(&self, database_str: &str) -> ConnectorResult<String> {
         let mut url = Url::parse(database_str).map_err(|err| ConnectorError::url_parse_error(err, database_str))?;
-        let db_name = self.0.dbname();
+        let db_name = self.url.dbname();
 
         strip_schema_param_from_url(&mut url);
 
@@ -76,7 +89,7 @@ impl SqlFlavour for PostgresFlavour {
 
     async fn describe_schema<'a>(&'a self, connection: &Connection) -> ConnectorResult<SqlSchema> {
         sql_schema_describer::postgres::SqlSchemaDescriber::new(connection.quaint().clone())
-            .describe(connection.connection_info().schema_name())
+            .describe_schemas(connection.connection_info().schema_name(), &self.other_schemas())
             .await
             .map_err(|err| match err.into_kind() {
                 DescriberErrorKind::QuaintError(err) => {
@@ -131,8 +144,8 @@ impl SqlFlavour for PostgresFlavour {
 
         strip_schema_param_from_url(&mut url);
         let conn = create_postgres_admin_conn(url.clone()).await?;
-        let schema = self.0.schema();
-        let db_name = self.0.dbname();
+        let schema = self.url.schema();
+        let db_name = self.url.dbname();
 
         let query = format!("CREATE DATABASE \"{}\"", db_name);
         conn.raw_cmd(&query).await.ok();
@@ -158,6 +171,12 @@ impl SqlFlavour for PostgresFlavour {
             .raw_cmd(&format!("DROP SCHEMA \"{}\" CASCADE", schema_name))
             .await?;
 
+        for other_schema in self.other_schemas() {
+            connection
+                .raw_cmd(&format!("DROP SCHEMA IF EXISTS \"{}\" CASCADE", other_schema))
+                .await?;
+        }
+
         connection
             .raw_cmd(&format!("CREATE SCHEMA \"{}\"", schema_name))
             .await?;
@@ -165,6 +184,10 @@ impl SqlFlavour for PostgresFlavour {
         Ok(())
     }
 
+    fn connection_schema_name(&self) -> Option<&str> {
+        Some(self.schema_name())
+    }
+
     fn sql_family(&self) -> SqlFamily {
         SqlFamily::Postgres
     }
@@ -181,7 +204,7 @@ impl SqlFlavour for PostgresFlavour {
 
         connection.raw_cmd(&create_database).await?;
 
-        let mut temporary_database_url = self.0.url().clone();
+        let mut temporary_database_url = self.url.url().clone();
         temporary_database_url.set_path(&format!("/{}", database_name));
         let temporary_database_url = temporary_database_url.to_string();
 
@@ -38,8 +38,14 @@ pub struct SqlMigrationConnector {
 impl SqlMigrationConnector {
     /// Construct and initialize the SQL migration connector.
     pub async fn new(database_str: &str) -> ConnectorResult<Self> {
+        Self::new_with_schemas(database_str, Vec::new()).await
+    }
+
+    /// Construct and initialize the SQL migration connector for a datamodel spread over the given
+    /// database schemas, as listed in the `schemas` property of the datasource.
+    pub async fn new_with_schemas(database_str: &str, schemas: Vec<String>) -> ConnectorResult<Self> {
         let connection = connect(database_str).await?;
-        let flavour = flavour::from_connection_info(connection.connection_info());
+        let flavour = flavour::from_connection_info(connection.connection_info(), &schemas);
 
         flavour.ensure_connection_validity(&connection).await?;
 
@@ -50,7 +56,7 @@ impl SqlMigrationConnector {
     pub async fn create_database(database_str: &str) -> ConnectorResult<String> {
         let connection_info =
             ConnectionInfo::from_url(database_str).map_err(|err| ConnectorError::url_parse_error(err, database_str))?;
-        let flavour = flavour::from_connection_info(&connection_info);
+        let flavour = flavour::from_connection_info(&connection_info, &[]);
         flavour.create_database(database_str).await
     }
 
@@ -58,7 +64,7 @@ impl SqlMigrationConnector {
     pub async fn drop_database(database_str: &str) -> ConnectorResult<()> {
         let connection_info =
             ConnectionInfo::from_url(database_str).map_err(|err| ConnectorError::url_parse_error(err, database_str))?;
-        let flavour = flavour::from_connection_info(&connection_info);
+        let flavour = flavour::from_connection_info(&connection_info, &[]);
 
         flavour.drop_database(database_str).await
     }
@@ -68,7 +74,7 @@ impl SqlMigrationConnector {
         let connection_info =
             ConnectionInfo::from_url(database_str).map_err(|err| ConnectorError::url_parse_error(err, database_str))?;
 
-        let flavour = flavour::from_connection_info(&connection_info);
+        let flavour = flavour::from_connection_info(&connection_info, &[]);
 
         flavour.qe_setup(database_str).await
     }
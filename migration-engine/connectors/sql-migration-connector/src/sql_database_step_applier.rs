@@ -1,6 +1,6 @@
 use crate::{
     pair::Pair,
-    sql_migration::{CreateTable, DropTable, SqlMigration, SqlMigrationStep},
+    sql_migration::{CreateSchema, CreateTable, DropTable, SqlMigration, SqlMigrationStep},
     SqlFlavour, SqlMigrationConnector,
 };
 use migration_connector::{
@@ -145,6 +145,7 @@ fn render_raw_sql(
     match step {
         SqlMigrationStep::AlterEnum(alter_enum) => renderer.render_alter_enum(alter_enum, &schemas),
         SqlMigrationStep::RedefineTables(redefine_tables) => renderer.render_redefine_tables(redefine_tables, &schemas),
+        SqlMigrationStep::CreateSchema(CreateSchema { name }) => vec![renderer.render_create_schema(name)],
         SqlMigrationStep::CreateEnum(create_enum) => {
             renderer.render_create_enum(&schemas.next().enum_walker_at(create_enum.enum_index))
         }
@@ -154,10 +155,10 @@ fn render_raw_sql(
         SqlMigrationStep::CreateTable(CreateTable { table_index }) => {
             let table = schemas.next().table_walker_at(*table_index);
 
-            vec![renderer.render_create_table(&table)]
+            renderer.render_create_table(&table)
         }
         SqlMigrationStep::DropTable(DropTable { table_index }) => {
-            renderer.render_drop_table(schemas.previous().table_walker_at(*table_index).name())
+            renderer.render_drop_table(&schemas.previous().table_walker_at(*table_index))
         }
         SqlMigrationStep::RedefineIndex { table, index } => {
             renderer.render_drop_and_recreate_index(schemas.tables(table).indexes(index).as_ref())
@@ -120,7 +120,10 @@ impl SqlMigrationConnector {
                                 self.flavour
                                     .check_drop_and_recreate_column(&columns, changes, &mut plan, step_index)
                             }
-                            TableChange::AddPrimaryKey { .. } => (),
+                            TableChange::AddPrimaryKey { .. }
+                            | TableChange::AddCheckConstraint { .. }
+                            | TableChange::DropCheckConstraint { .. }
+                            | TableChange::SetComment => (),
                         }
                     }
                 }
@@ -76,14 +76,34 @@ impl DestructiveChangeCheckerFlavour for MysqlFlavour {
         };
     }
 
+    /// Columns are only dropped and recreated on MySQL when they start or stop being generated.
     fn check_drop_and_recreate_column(
         &self,
-        _columns: &Pair<ColumnWalker<'_>>,
+        columns: &Pair<ColumnWalker<'_>>,
         _changes: &ColumnChanges,
-        _plan: &mut DestructiveCheckPlan,
-        _step_index: usize,
+        plan: &mut DestructiveCheckPlan,
+        step_index: usize,
     ) {
-        panic!("check_drop_and_recreate_column on MySQL")
+        let next = columns.next();
+
+        if next.generated().is_none() && next.arity().is_required() && next.default().is_none() {
+            plan.push_unexecutable(
+                UnexecutableStepCheck::DropAndRecreateRequiredColumn {
+                    column: columns.previous().name().to_owned(),
+                    table: columns.previous().table().name().to_owned(),
+                },
+                step_index,
+            )
+        } else if next.generated().is_some() && columns.previous().generated().is_none() {
+            // The values of a generated column are computed again, only non-generated values get lost.
+            plan.push_warning(
+                SqlMigrationWarningCheck::DropAndRecreateColumn {
+                    column: columns.previous().name().to_owned(),
+                    table: columns.previous().table().name().to_owned(),
+                },
+                step_index,
+            )
+        }
     }
 }
 
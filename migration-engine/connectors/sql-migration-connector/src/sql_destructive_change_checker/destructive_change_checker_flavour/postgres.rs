@@ -79,6 +79,21 @@ impl DestructiveChangeCheckerFlavour for PostgresFlavour {
         plan: &mut DestructiveCheckPlan,
         step_index: usize,
     ) {
+        // The database computes the values of the recreated column, only the previous values can be lost.
+        if columns.next().generated().is_some() {
+            if columns.previous().generated().is_none() {
+                plan.push_warning(
+                    SqlMigrationWarningCheck::DropAndRecreateColumn {
+                        column: columns.previous().name().to_owned(),
+                        table: columns.previous().table().name().to_owned(),
+                    },
+                    step_index,
+                )
+            }
+
+            return;
+        }
+
         // Unexecutable drop and recreate.
         if changes.arity_changed()
             && columns.previous().arity().is_nullable()
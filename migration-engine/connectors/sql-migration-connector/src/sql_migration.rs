@@ -31,6 +31,7 @@ impl DatabaseMigrationMarker for SqlMigration {
 
 #[derive(Debug)]
 pub(crate) enum SqlMigrationStep {
+    CreateSchema(CreateSchema),
     AddForeignKey(AddForeignKey),
     CreateTable(CreateTable),
     AlterTable(AlterTable),
@@ -63,6 +64,7 @@ impl Serialize for SqlMigrationStep {
 impl SqlMigrationStep {
     pub(crate) fn description(&self) -> &'static str {
         match self {
+            SqlMigrationStep::CreateSchema(_) => "CreateSchema",
             SqlMigrationStep::AddForeignKey(_) => "AddForeignKey",
             SqlMigrationStep::CreateTable(_) => "CreateTable",
             SqlMigrationStep::AlterTable(_) => "AlterTable",
@@ -80,6 +82,12 @@ impl SqlMigrationStep {
     }
 }
 
+/// Create a database schema used by `@@schema` models or enums.
+#[derive(Debug)]
+pub(crate) struct CreateSchema {
+    pub name: String,
+}
+
 #[derive(Debug)]
 pub(crate) struct CreateTable {
     pub table_index: usize,
@@ -112,6 +120,16 @@ pub(crate) enum TableChange {
     AddPrimaryKey {
         columns: Vec<String>,
     },
+    AddCheckConstraint {
+        /// The index of the check constraint in the next table.
+        check_constraint_index: usize,
+    },
+    DropCheckConstraint {
+        /// The index of the check constraint in the previous table.
+        check_constraint_index: usize,
+    },
+    /// Set the comment of the table to the comment of the next table, or remove it.
+    SetComment,
 }
 
 #[derive(Debug)]
@@ -20,13 +20,13 @@ use crate::{
     pair::Pair,
     sql_migration::{AlterEnum, AlterTable, RedefineTable},
 };
-use common::Quoted;
+use common::{Quoted, SQL_INDENTATION};
 use sql_schema_describer::{
     walkers::EnumWalker,
     walkers::ForeignKeyWalker,
     walkers::IndexWalker,
     walkers::{ColumnWalker, TableWalker},
-    ColumnTypeFamily, DefaultValue, SqlSchema,
+    CheckConstraint, ColumnTypeFamily, DefaultValue, SortOrder, SqlSchema,
 };
 use std::borrow::Cow;
 
@@ -49,14 +49,76 @@ pub(crate) trait SqlRenderer {
 
     fn render_alter_table(&self, alter_table: &AlterTable, schemas: &Pair<&SqlSchema>) -> Vec<String>;
 
+    /// Render a check constraint definition, for CREATE TABLE and ALTER TABLE ... ADD.
+    fn render_check_constraint(&self, check_constraint: &CheckConstraint) -> String {
+        let expression = &check_constraint.expression;
+
+        match &check_constraint.name {
+            Some(name) => format!("CONSTRAINT {} CHECK ({})", self.quote(name), expression),
+            None => format!("CHECK ({})", expression),
+        }
+    }
+
+    /// Render the `CONSTRAINT` clause naming the primary key of a table, or nothing if the
+    /// database should pick the name.
+    fn render_primary_key_constraint_name(&self, table: &TableWalker<'_>) -> String {
+        table
+            .primary_key()
+            .and_then(|pk| pk.constraint_name.as_deref())
+            .map(|name| format!("CONSTRAINT {} ", self.quote(name)))
+            .unwrap_or_default()
+    }
+
+    /// Render the check constraints of a table as the trailing items of its CREATE TABLE statement.
+    fn render_table_check_constraints(&self, table: &TableWalker<'_>) -> String {
+        table
+            .check_constraints()
+            .iter()
+            .map(|check_constraint| self.render_check_constraint(check_constraint))
+            .map(|check_constraint| format!(",\n{}{}", SQL_INDENTATION, check_constraint))
+            .collect()
+    }
+
     /// Render a `CreateEnum` step.
     fn render_create_enum(&self, create_enum: &EnumWalker<'_>) -> Vec<String>;
 
+    /// Render a `CreateSchema` step.
+    fn render_create_schema(&self, schema_name: &str) -> String {
+        format!("CREATE SCHEMA IF NOT EXISTS {}", self.quote(schema_name))
+    }
+
     fn render_create_index(&self, index: &IndexWalker<'_>) -> String;
 
+    /// Render the column list of an index, with the prefix lengths and sort orders of the columns.
+    fn render_index_columns(&self, index: &IndexWalker<'_>) -> String {
+        index
+            .column_names()
+            .iter()
+            .enumerate()
+            .map(|(idx, column_name)| {
+                let options = index.column_options(idx);
+                let length = options.length.map(|length| format!("({})", length)).unwrap_or_default();
+                let sort_order = match options.sort_order {
+                    SortOrder::Asc => "",
+                    SortOrder::Desc => " DESC",
+                };
+
+                format!("{}{}{}", self.quote(column_name), length, sort_order)
+            })
+            .join(", ")
+    }
+
+    /// Render the `WHERE` clause of a partial index, or nothing.
+    fn render_index_predicate(&self, index: &IndexWalker<'_>) -> String {
+        index
+            .predicate()
+            .map(|predicate| format!(" WHERE {}", predicate))
+            .unwrap_or_default()
+    }
+
     /// Render a table creation step.
-    fn render_create_table(&self, table: &TableWalker<'_>) -> String {
-        self.render_create_table_as(table, table.name())
+    fn render_create_table(&self, table: &TableWalker<'_>) -> Vec<String> {
+        vec![self.render_create_table_as(table, table.name())]
     }
 
     /// Render a table creation with the provided table name.
@@ -76,8 +138,8 @@ pub(crate) trait SqlRenderer {
     fn render_drop_index(&self, index: &IndexWalker<'_>) -> String;
 
     /// Render a `DropTable` step.
-    fn render_drop_table(&self, table_name: &str) -> Vec<String> {
-        vec![format!("DROP TABLE {}", self.quote(&table_name))]
+    fn render_drop_table(&self, table: &TableWalker<'_>) -> Vec<String> {
+        vec![format!("DROP TABLE {}", self.quote(table.name()))]
     }
 
     /// Render a `RedefineTables` step.
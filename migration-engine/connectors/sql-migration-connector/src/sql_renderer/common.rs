@@ -60,6 +60,18 @@ pub(crate) fn render_nullability(column: &ColumnWalker<'_>) -> &'static str {
     }
 }
 
+/// The clause making a column generated, e.g. ` GENERATED ALWAYS AS (price * quantity) STORED`.
+pub(crate) fn render_generation(column: &ColumnWalker<'_>) -> String {
+    column
+        .generated()
+        .map(|generated| {
+            let storage = if generated.stored { "STORED" } else { "VIRTUAL" };
+
+            format!(" GENERATED ALWAYS AS ({}) {}", generated.expression, storage)
+        })
+        .unwrap_or_default()
+}
+
 pub(crate) fn render_on_delete(on_delete: &ForeignKeyAction) -> &'static str {
     match on_delete {
         ForeignKeyAction::NoAction => "",
@@ -31,12 +31,73 @@ impl<'a> Display for QuotedWithSchema<'a> {
 }
 
 impl MssqlFlavour {
-    fn quote_with_schema<'a>(&'a self, name: &'a str) -> QuotedWithSchema<'a> {
+    /// Quote the name of the table, qualified with the schema the table lives in.
+    fn quote_table<'a>(&'a self, table: &TableWalker<'a>) -> QuotedWithSchema<'a> {
         QuotedWithSchema {
-            schema_name: self.schema_name(),
-            name,
+            schema_name: self.table_schema_name(table),
+            name: table.name(),
         }
     }
+
+    fn table_schema_name<'a>(&'a self, table: &TableWalker<'a>) -> &'a str {
+        table.schema_name().unwrap_or_else(|| self.schema_name())
+    }
+
+    fn render_rename_table_in_schema(&self, schema_name: &str, name: &str, new_name: &str) -> String {
+        let with_schema = format!("{}.{}", schema_name, name);
+
+        format!(
+            "EXEC SP_RENAME N{}, N{}",
+            Quoted::Single(with_schema),
+            Quoted::Single(new_name),
+        )
+    }
+
+    /// Render the statement taking the `MS_Description` extended property, where SQL Server keeps
+    /// comments, from the previous to the next comment. Table comments have no column name.
+    fn render_comment_change(
+        &self,
+        table: &TableWalker<'_>,
+        column_name: Option<&str>,
+        comments: Pair<Option<&str>>,
+    ) -> Option<String> {
+        let (procedure, comment) = match (comments.previous(), comments.next()) {
+            (None, None) => return None,
+            (None, Some(comment)) => ("sp_addextendedproperty", Some(comment)),
+            (Some(_), Some(comment)) => ("sp_updateextendedproperty", Some(comment)),
+            (Some(_), None) => ("sp_dropextendedproperty", None),
+        };
+
+        let mut statement = format!("EXEC {} N'MS_Description'", procedure);
+
+        if let Some(comment) = comment {
+            write!(statement, ", N'{}'", escape_string_literal(comment)).unwrap();
+        }
+
+        write!(
+            statement,
+            ", N'SCHEMA', N'{}', N'TABLE', N'{}'",
+            escape_string_literal(self.table_schema_name(table)),
+            escape_string_literal(table.name()),
+        )
+        .unwrap();
+
+        if let Some(column_name) = column_name {
+            write!(statement, ", N'COLUMN', N'{}'", escape_string_literal(column_name)).unwrap();
+        }
+
+        Some(statement)
+    }
+
+    /// Render the statements adding the comments of a new table and its columns.
+    fn render_table_comments(&self, table: &TableWalker<'_>) -> Vec<String> {
+        let table_comment = self.render_comment_change(table, None, Pair::new(None, table.comment()));
+        let column_comments = table.columns().filter_map(|column| {
+            self.render_comment_change(table, Some(column.name()), Pair::new(None, column.comment()))
+        });
+
+        table_comment.into_iter().chain(column_comments).collect()
+    }
 }
 
 impl SqlRenderer for MssqlFlavour {
@@ -63,7 +124,7 @@ impl SqlRenderer for MssqlFlavour {
 
         let default = column
             .default()
-            .filter(|default| !matches!(default.kind(), DefaultKind::DBGENERATED(_)))
+            .filter(|default| !matches!(default.kind(), DefaultKind::DBGENERATED(expression) if expression.is_empty()))
             .map(|default| {
                 let constraint_name = format!("DF__{}__{}", column.table().name(), column.name());
 
@@ -91,7 +152,7 @@ impl SqlRenderer for MssqlFlavour {
 
         format!(
             " REFERENCES {}({}) {} ON UPDATE CASCADE",
-            self.quote_with_schema(&foreign_key.referenced_table().name()),
+            self.quote_table(&foreign_key.referenced_table()),
             cols,
             render_on_delete(&foreign_key.on_delete_action()),
         )
@@ -110,7 +171,9 @@ impl SqlRenderer for MssqlFlavour {
             (DefaultKind::NOW, ColumnTypeFamily::DateTime) => "CURRENT_TIMESTAMP".into(),
             (DefaultKind::NOW, _) => unreachable!("NOW default on non-datetime column"),
             (DefaultKind::VALUE(val), ColumnTypeFamily::DateTime) => format!("'{}'", val).into(),
-            (DefaultKind::VALUE(PrismaValue::String(val)), ColumnTypeFamily::Json) => format!("'{}'", val).into(),
+            (DefaultKind::VALUE(PrismaValue::String(val)), ColumnTypeFamily::Json) => {
+                format!("'{}'", escape_string_literal(&val)).into()
+            }
             (DefaultKind::VALUE(PrismaValue::Boolean(val)), ColumnTypeFamily::Boolean) => {
                 Cow::from(if *val { "1" } else { "0" })
             }
@@ -122,7 +185,7 @@ impl SqlRenderer for MssqlFlavour {
     fn render_alter_index(&self, indexes: Pair<&IndexWalker<'_>>) -> Vec<String> {
         let index_with_table = format!(
             "{}.{}.{}",
-            self.schema_name(),
+            self.table_schema_name(&indexes.previous().table()),
             indexes.previous().table().name(),
             indexes.previous().name()
         );
@@ -138,27 +201,41 @@ impl SqlRenderer for MssqlFlavour {
         unreachable!("render_create_enum on Microsoft SQL Server")
     }
 
+    fn render_create_schema(&self, schema_name: &str) -> String {
+        format!(
+            "IF NOT EXISTS (SELECT * FROM sys.schemas WHERE name = {name}) EXEC('CREATE SCHEMA {schema}')",
+            name = Quoted::Single(escape_string_literal(schema_name)),
+            schema = self.quote(schema_name),
+        )
+    }
+
     fn render_create_index(&self, index: &IndexWalker<'_>) -> String {
         let index_type = match index.index_type() {
             IndexType::Unique => "UNIQUE ",
             IndexType::Normal => "",
+            IndexType::Fulltext => unreachable!("render_create_index with a full-text index on Microsoft SQL Server"),
         };
 
         let index_name = index.name().replace('.', "_");
         let index_name = self.quote(&index_name);
-        let table_reference = self.quote_with_schema(index.table().name()).to_string();
-
-        let columns = index.columns().map(|c| self.quote(c.name()));
+        let table_reference = self.quote_table(&index.table()).to_string();
 
         format!(
-            "CREATE {index_type}INDEX {index_name} ON {table_reference}({columns})",
+            "CREATE {index_type}INDEX {index_name} ON {table_reference}({columns}){predicate}",
             index_type = index_type,
             index_name = index_name,
             table_reference = table_reference,
-            columns = columns.join(", "),
+            columns = self.render_index_columns(index),
+            predicate = self.render_index_predicate(index),
         )
     }
 
+    fn render_create_table(&self, table: &TableWalker<'_>) -> Vec<String> {
+        std::iter::once(self.render_create_table_as(table, table.name()))
+            .chain(self.render_table_comments(table))
+            .collect()
+    }
+
     fn render_create_table_as(&self, table: &TableWalker<'_>, table_name: &str) -> String {
         let columns: String = table
             .columns()
@@ -168,7 +245,10 @@ impl SqlRenderer for MssqlFlavour {
         let primary_columns = table.primary_key_column_names();
 
         let primary_key = if let Some(primary_columns) = primary_columns.as_ref().filter(|cols| !cols.is_empty()) {
-            let index_name = format!("PK__{}__{}", table.name(), primary_columns.iter().join("_"));
+            let index_name = table
+                .primary_key()
+                .and_then(|pk| pk.constraint_name.clone())
+                .unwrap_or_else(|| format!("PK__{}__{}", table.name(), primary_columns.iter().join("_")));
             let column_names = primary_columns.iter().map(|col| self.quote(&col)).join(",");
 
             format!(
@@ -180,19 +260,19 @@ impl SqlRenderer for MssqlFlavour {
             String::new()
         };
 
-        let constraints = table
-            .indexes()
-            .filter(|index| index.index_type().is_unique())
-            .collect::<Vec<_>>();
+        let constraints = table.indexes().filter(is_unique_constraint).collect::<Vec<_>>();
 
         let constraints = if !constraints.is_empty() {
             let constraints = constraints
                 .iter()
                 .map(|index| {
                     let name = index.name().replace('.', "_");
-                    let columns = index.columns().map(|col| self.quote(col.name()));
 
-                    format!("CONSTRAINT {} UNIQUE ({})", self.quote(&name), columns.join(","))
+                    format!(
+                        "CONSTRAINT {} UNIQUE ({})",
+                        self.quote(&name),
+                        self.render_index_columns(index)
+                    )
                 })
                 .join(",\n    ");
 
@@ -204,12 +284,16 @@ impl SqlRenderer for MssqlFlavour {
         formatdoc!(
             r#"
             CREATE TABLE {table_name} (
-                {columns}{primary_key}{constraints}
+                {columns}{primary_key}{constraints}{check_constraints}
             )"#,
-            table_name = self.quote_with_schema(table_name),
+            table_name = QuotedWithSchema {
+                schema_name: self.table_schema_name(table),
+                name: table_name,
+            },
             columns = columns,
             primary_key = primary_key,
             constraints = constraints,
+            check_constraints = self.render_table_check_constraints(table),
         )
     }
 
@@ -220,23 +304,24 @@ impl SqlRenderer for MssqlFlavour {
     fn render_drop_foreign_key(&self, foreign_key: &ForeignKeyWalker<'_>) -> String {
         format!(
             "ALTER TABLE {table} DROP CONSTRAINT {constraint_name}",
-            table = self.quote_with_schema(foreign_key.table().name()),
+            table = self.quote_table(&foreign_key.table()),
             constraint_name = Quoted::mssql_ident(foreign_key.constraint_name().unwrap()),
         )
     }
 
     fn render_drop_index(&self, index: &IndexWalker<'_>) -> String {
         match index.index_type() {
-            IndexType::Normal => format!(
+            IndexType::Normal | IndexType::Unique if !is_unique_constraint(index) => format!(
                 "DROP INDEX {} ON {}",
                 self.quote(index.name()),
-                self.quote_with_schema(index.table().name())
+                self.quote_table(&index.table())
             ),
             IndexType::Unique => format!(
                 "ALTER TABLE {} DROP CONSTRAINT {}",
-                self.quote_with_schema(index.table().name()),
+                self.quote_table(&index.table()),
                 self.quote(index.name()),
             ),
+            IndexType::Fulltext => unreachable!("render_drop_index with a full-text index on Microsoft SQL Server"),
         }
     }
 
@@ -300,7 +385,7 @@ impl SqlRenderer for MssqlFlavour {
                     AND OBJECT_NAME(PARENT_OBJECT_ID) = '{table}'
                     AND SCHEMA_NAME(SCHEMA_ID) = '{schema}'
                 EXEC sp_executesql @SQL
-            "#, table = tables.previous().name(), schema = self.schema_name()});
+            "#, table = tables.previous().name(), schema = self.table_schema_name(tables.previous())});
 
             // Create the new table.
             result.push(self.render_create_table_as(tables.next(), &temporary_table_name));
@@ -311,7 +396,10 @@ impl SqlRenderer for MssqlFlavour {
             if needs_autoincrement {
                 result.push(format!(
                     r#"SET IDENTITY_INSERT {} ON"#,
-                    self.quote_with_schema(&temporary_table_name)
+                    QuotedWithSchema {
+                        schema_name: self.table_schema_name(tables.next()),
+                        name: &temporary_table_name,
+                    }
                 ));
             }
 
@@ -320,23 +408,36 @@ impl SqlRenderer for MssqlFlavour {
                 IF EXISTS(SELECT * FROM {table})
                     EXEC('INSERT INTO {tmp_table} ({columns}) SELECT {columns} FROM {table} WITH (holdlock tablockx)')"#,
                 columns = columns.join(","),
-                table = self.quote_with_schema(tables.previous().name()),
-                tmp_table = self.quote_with_schema(&temporary_table_name),
+                table = self.quote_table(tables.previous()),
+                tmp_table = QuotedWithSchema {
+                    schema_name: self.table_schema_name(tables.next()),
+                    name: &temporary_table_name,
+                },
             });
 
             // When done copying, disallow identity inserts again if needed.
             if needs_autoincrement {
                 result.push(format!(
                     r#"SET IDENTITY_INSERT {} OFF"#,
-                    self.quote_with_schema(&temporary_table_name)
+                    QuotedWithSchema {
+                        schema_name: self.table_schema_name(tables.next()),
+                        name: &temporary_table_name,
+                    }
                 ));
             }
 
             // Drop the old, now empty table.
-            result.extend(self.render_drop_table(tables.previous().name()));
+            result.extend(self.render_drop_table(tables.previous()));
 
             // Rename the temporary table with the name defined in the migration.
-            result.push(self.render_rename_table(&temporary_table_name, tables.next().name()));
+            result.push(self.render_rename_table_in_schema(
+                self.table_schema_name(tables.next()),
+                &temporary_table_name,
+                tables.next().name(),
+            ));
+
+            // The comments were dropped with the old table.
+            result.extend(self.render_table_comments(tables.next()));
 
             // Recreating all foreign keys pointing to this table
             for fk in tables.next().referencing_foreign_keys() {
@@ -344,7 +445,7 @@ impl SqlRenderer for MssqlFlavour {
             }
 
             // Then the indices...
-            for index in tables.next().indexes().filter(|i| !i.index_type().is_unique()) {
+            for index in tables.next().indexes().filter(|i| !is_unique_constraint(i)) {
                 result.push(self.render_create_index(&index));
             }
         }
@@ -355,13 +456,7 @@ impl SqlRenderer for MssqlFlavour {
     }
 
     fn render_rename_table(&self, name: &str, new_name: &str) -> String {
-        let with_schema = format!("{}.{}", self.schema_name(), name);
-
-        format!(
-            "EXEC SP_RENAME N{}, N{}",
-            Quoted::Single(with_schema),
-            Quoted::Single(new_name),
-        )
+        self.render_rename_table_in_schema(self.schema_name(), name, new_name)
     }
 
     fn render_add_foreign_key(&self, foreign_key: &ForeignKeyWalker<'_>) -> String {
@@ -370,7 +465,7 @@ impl SqlRenderer for MssqlFlavour {
         write!(
             add_constraint,
             "ALTER TABLE {table} ADD ",
-            table = self.quote_with_schema(foreign_key.table().name())
+            table = self.quote_table(&foreign_key.table())
         )
         .unwrap();
 
@@ -402,11 +497,17 @@ impl SqlRenderer for MssqlFlavour {
         add_constraint
     }
 
-    fn render_drop_table(&self, table_name: &str) -> Vec<String> {
-        vec![format!("DROP TABLE {}", self.quote_with_schema(&table_name))]
+    fn render_drop_table(&self, table: &TableWalker<'_>) -> Vec<String> {
+        vec![format!("DROP TABLE {}", self.quote_table(table))]
     }
 }
 
+/// Unique indexes are created as `UNIQUE` constraints, except filtered ones, which can only be
+/// created with `CREATE UNIQUE INDEX`.
+fn is_unique_constraint(index: &IndexWalker<'_>) -> bool {
+    index.index_type().is_unique() && index.predicate().is_none()
+}
+
 fn render_column_type(column: &ColumnWalker<'_>) -> Cow<'static, str> {
     if !column.column_type().full_data_type.is_empty() {
         return column.column_type().full_data_type.clone().into();
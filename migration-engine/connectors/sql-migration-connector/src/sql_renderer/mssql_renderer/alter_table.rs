@@ -24,9 +24,11 @@ pub(crate) fn create_statements(
         changes,
         drop_constraints: BTreeSet::new(),
         add_constraints: BTreeSet::new(),
+        add_check_constraints: Vec::new(),
         add_columns: Vec::new(),
         drop_columns: Vec::new(),
         column_mods: Vec::new(),
+        comments: Vec::new(),
     };
 
     constructor.into_statements()
@@ -38,9 +40,11 @@ struct AlterTableConstructor<'a> {
     changes: &'a [TableChange],
     drop_constraints: BTreeSet<String>,
     add_constraints: BTreeSet<String>,
+    add_check_constraints: Vec<String>,
     add_columns: Vec<String>,
     drop_columns: Vec<String>,
     column_mods: Vec<String>,
+    comments: Vec<String>,
 }
 
 impl<'a> AlterTableConstructor<'a> {
@@ -53,6 +57,12 @@ impl<'a> AlterTableConstructor<'a> {
                 TableChange::AddPrimaryKey { columns } => {
                     self.add_primary_key(&columns);
                 }
+                TableChange::AddCheckConstraint { check_constraint_index } => {
+                    self.add_check_constraint(*check_constraint_index);
+                }
+                TableChange::DropCheckConstraint { check_constraint_index } => {
+                    self.drop_check_constraint(*check_constraint_index);
+                }
                 TableChange::AddColumn(AddColumn { column_index }) => {
                     self.add_column(*column_index);
                 }
@@ -69,6 +79,9 @@ impl<'a> AlterTableConstructor<'a> {
                 }) => {
                     self.alter_column(*column_index, &changes);
                 }
+                TableChange::SetComment => {
+                    self.set_comment();
+                }
             };
         }
 
@@ -78,7 +91,7 @@ impl<'a> AlterTableConstructor<'a> {
         if !self.drop_constraints.is_empty() {
             statements.push(format!(
                 "ALTER TABLE {} DROP CONSTRAINT {}",
-                self.renderer.quote_with_schema(self.tables.previous().name()),
+                self.renderer.quote_table(self.tables.previous()),
                 self.drop_constraints.iter().join(",\n"),
             ));
         }
@@ -90,7 +103,7 @@ impl<'a> AlterTableConstructor<'a> {
         if !self.drop_columns.is_empty() {
             statements.push(format!(
                 "ALTER TABLE {} DROP COLUMN {}",
-                self.renderer.quote_with_schema(self.tables.previous().name()),
+                self.renderer.quote_table(self.tables.previous()),
                 self.drop_columns.join(",\n"),
             ));
         }
@@ -98,7 +111,7 @@ impl<'a> AlterTableConstructor<'a> {
         if !self.add_constraints.is_empty() {
             statements.push(format!(
                 "ALTER TABLE {} ADD {}",
-                self.renderer.quote_with_schema(self.tables.previous().name()),
+                self.renderer.quote_table(self.tables.previous()),
                 self.add_constraints.iter().join(", ")
             ));
         }
@@ -106,11 +119,23 @@ impl<'a> AlterTableConstructor<'a> {
         if !self.add_columns.is_empty() {
             statements.push(format!(
                 "ALTER TABLE {} ADD {}",
-                self.renderer.quote_with_schema(self.tables.previous().name()),
+                self.renderer.quote_table(self.tables.previous()),
                 self.add_columns.join(",\n"),
             ));
         }
 
+        // Check constraints can reference the added columns.
+        if !self.add_check_constraints.is_empty() {
+            statements.push(format!(
+                "ALTER TABLE {} ADD {}",
+                self.renderer.quote_table(self.tables.previous()),
+                self.add_check_constraints.join(", ")
+            ));
+        }
+
+        // Comments are set on the columns as they are after all other changes.
+        statements.extend(self.comments);
+
         statements
     }
 
@@ -134,17 +159,62 @@ impl<'a> AlterTableConstructor<'a> {
             quoted_columns.push(format!("{}", self.renderer.quote(colname)));
         }
 
+        let constraint_name = match self
+            .tables
+            .next()
+            .primary_key()
+            .and_then(|pk| pk.constraint_name.as_ref())
+        {
+            Some(name) => format!("{}", self.renderer.quote(name)),
+            None => format!("PK__{}__{}", self.tables.next().name(), non_quoted_columns.join("__")),
+        };
+
         self.add_constraints.insert(format!(
-            "CONSTRAINT PK__{}__{} PRIMARY KEY ({})",
-            self.tables.next().name(),
-            non_quoted_columns.join("__"),
+            "CONSTRAINT {} PRIMARY KEY ({})",
+            constraint_name,
             quoted_columns.join(","),
         ));
     }
 
+    fn add_check_constraint(&mut self, check_constraint_index: usize) {
+        let check_constraint = self.tables.next().check_constraint_at(check_constraint_index);
+
+        self.add_check_constraints
+            .push(self.renderer.render_check_constraint(check_constraint));
+    }
+
+    fn drop_check_constraint(&mut self, check_constraint_index: usize) {
+        let constraint = self
+            .tables
+            .previous()
+            .check_constraint_at(check_constraint_index)
+            .name
+            .as_deref()
+            .expect("Missing constraint name in DropCheckConstraint on MSSQL");
+
+        self.drop_constraints
+            .insert(format!("{}", self.renderer.quote(constraint)));
+    }
+
+    fn set_comment(&mut self) {
+        let comments = self.tables.as_ref().map(|table| table.comment());
+
+        self.comments
+            .extend(self.renderer.render_comment_change(self.tables.next(), None, comments));
+    }
+
     fn add_column(&mut self, column_index: usize) {
         let column = self.tables.next().column_at(column_index);
         self.add_columns.push(self.renderer.render_column(&column));
+        self.add_column_comment(&column);
+    }
+
+    fn add_column_comment(&mut self, column: &ColumnWalker<'_>) {
+        self.comments.extend(self.renderer.render_comment_change(
+            self.tables.next(),
+            Some(column.name()),
+            Pair::new(None, column.comment()),
+        ));
     }
 
     fn drop_column(&mut self, column_index: usize) {
@@ -162,6 +232,7 @@ impl<'a> AlterTableConstructor<'a> {
             .push(format!("{}", self.renderer.quote(columns.previous().name())));
 
         self.add_columns.push(self.renderer.render_column(columns.next()));
+        self.add_column_comment(columns.next());
     }
 
     fn alter_column(&mut self, columns: Pair<usize>, changes: &ColumnChanges) {
@@ -186,6 +257,13 @@ impl<'a> AlterTableConstructor<'a> {
                         default = default,
                     ));
                 }
+                MsSqlAlterColumn::SetComment => {
+                    self.comments.extend(self.renderer.render_comment_change(
+                        self.tables.next(),
+                        Some(columns.next().name()),
+                        columns.as_ref().map(|column| column.comment()),
+                    ));
+                }
                 MsSqlAlterColumn::Modify => {
                     let nullability = if columns.next().arity().is_required() {
                         "NOT NULL"
@@ -212,7 +290,7 @@ impl<'a> AlterTableConstructor<'a> {
 
                     self.column_mods.push(format!(
                         "ALTER TABLE {table} ALTER COLUMN {column_name} {column_type} {nullability}",
-                        table = self.renderer.quote_with_schema(self.tables.previous().name()),
+                        table = self.renderer.quote_table(self.tables.previous()),
                         column_name = self.renderer.quote(&columns.next().name()),
                         column_type = super::render_column_type(columns.next()),
                         nullability = nullability,
@@ -257,6 +335,7 @@ enum MsSqlAlterColumn {
     DropDefault { constraint_name: String },
     SetDefault(DefaultValue),
     Modify,
+    SetComment,
 }
 
 fn expand_alter_column(columns: &Pair<ColumnWalker<'_>>, column_changes: &ColumnChanges) -> Vec<MsSqlAlterColumn> {
@@ -280,9 +359,13 @@ fn expand_alter_column(columns: &Pair<ColumnWalker<'_>>, column_changes: &Column
         if let Some(next_default) = columns.next().default() {
             changes.push(MsSqlAlterColumn::SetDefault(next_default.clone()));
         }
-    } else {
+    } else if !column_changes.only_comment_changed() {
         changes.push(MsSqlAlterColumn::Modify);
     }
 
+    if column_changes.comment_changed() {
+        changes.push(MsSqlAlterColumn::SetComment);
+    }
+
     changes
 }
@@ -1,6 +1,6 @@
 use super::{
     common::SQL_INDENTATION,
-    common::{render_nullability, render_on_delete, Quoted},
+    common::{render_generation, render_nullability, render_on_delete, Quoted},
     IteratorJoin, SqlRenderer,
 };
 use crate::{
@@ -73,6 +73,24 @@ impl SqlRenderer for MysqlFlavour {
                     "ADD PRIMARY KEY ({})",
                     columns.iter().map(|colname| self.quote(colname)).join(", ")
                 )),
+                TableChange::AddCheckConstraint { check_constraint_index } => {
+                    let check_constraint = tables.next().check_constraint_at(*check_constraint_index);
+
+                    lines.push(format!("ADD {}", self.render_check_constraint(check_constraint)));
+                }
+                TableChange::DropCheckConstraint { check_constraint_index } => {
+                    let name = tables
+                        .previous()
+                        .check_constraint_at(*check_constraint_index)
+                        .name
+                        .as_deref()
+                        .expect("Missing constraint name for DROP CHECK on MySQL.");
+
+                    // MySQL only has `DROP CONSTRAINT` since 8.0.19, MariaDB never had `DROP CHECK`.
+                    let keyword = if self.is_mariadb() { "CONSTRAINT" } else { "CHECK" };
+
+                    lines.push(format!("DROP {} {}", keyword, self.quote(name)));
+                }
                 TableChange::AddColumn(AddColumn { column_index }) => {
                     let column = tables.next().column_at(*column_index);
                     let col_sql = self.render_column(&column);
@@ -104,7 +122,19 @@ impl SqlRenderer for MysqlFlavour {
                         )),
                     };
                 }
-                TableChange::DropAndRecreateColumn { .. } => unreachable!("DropAndRecreateColumn on MySQL"),
+                TableChange::DropAndRecreateColumn {
+                    column_index,
+                    changes: _,
+                } => {
+                    let columns = tables.columns(column_index);
+
+                    lines.push(format!("DROP COLUMN {}", self.quote(columns.previous().name())));
+                    lines.push(format!("ADD COLUMN {}", self.render_column(columns.next())));
+                }
+                TableChange::SetComment => lines.push(format!(
+                    "COMMENT = {}",
+                    Quoted::mysql_string(escape_string_literal(tables.next().comment().unwrap_or("")))
+                )),
             };
         }
 
@@ -122,11 +152,13 @@ impl SqlRenderer for MysqlFlavour {
     fn render_column(&self, column: &ColumnWalker<'_>) -> String {
         let column_name = self.quote(column.name());
         let tpe_str = render_column_type(&column);
+        let generation_str = render_generation(&column);
         let nullability_str = render_nullability(&column);
         let default_str = column
             .default()
             .filter(|default| {
-                !matches!(default.kind(), DefaultKind::DBGENERATED(_) | DefaultKind::SEQUENCE(_))
+                !matches!(default.kind(), DefaultKind::DBGENERATED(expression) if expression.is_empty())
+                    && !matches!(default.kind(), DefaultKind::SEQUENCE(_))
                     // We do not want to render JSON defaults because they are not supported by MySQL.
                     && !matches!(column.column_type_family(), ColumnTypeFamily::Json)
                     // We do not want to render binary defaults because they are not supported by MySQL.
@@ -140,22 +172,23 @@ impl SqlRenderer for MysqlFlavour {
             })
             .unwrap_or_else(String::new);
         let foreign_key = column.table().foreign_key_for_column(column.name());
-        let auto_increment_str = if column.is_autoincrement() {
+        let auto_increment_str = if column.is_autoincrement() && foreign_key.is_none() {
             " AUTO_INCREMENT"
         } else {
             ""
         };
 
-        match foreign_key {
-            Some(_) => format!(
-                "{}{} {}{}{}",
-                SQL_INDENTATION, column_name, tpe_str, nullability_str, default_str
-            ),
-            None => format!(
-                "{}{} {}{}{}{}",
-                SQL_INDENTATION, column_name, tpe_str, nullability_str, default_str, auto_increment_str
-            ),
-        }
+        format!(
+            "{}{} {}{}{}{}{}{}",
+            SQL_INDENTATION,
+            column_name,
+            tpe_str,
+            generation_str,
+            nullability_str,
+            default_str,
+            auto_increment_str,
+            render_column_comment(column),
+        )
     }
 
     fn render_references(&self, foreign_key: &ForeignKeyWalker<'_>) -> String {
@@ -175,7 +208,7 @@ impl SqlRenderer for MysqlFlavour {
 
     fn render_default<'a>(&self, default: &'a DefaultValue, family: &ColumnTypeFamily) -> Cow<'a, str> {
         match (default.kind(), family) {
-            (DefaultKind::DBGENERATED(val), _) => val.as_str().into(),
+            (DefaultKind::DBGENERATED(val), _) => render_expression_default(val),
             (DefaultKind::VALUE(PrismaValue::String(val)), ColumnTypeFamily::String)
             | (DefaultKind::VALUE(PrismaValue::Enum(val)), ColumnTypeFamily::Enum(_)) => {
                 format!("'{}'", escape_string_literal(&val)).into()
@@ -202,18 +235,17 @@ impl SqlRenderer for MysqlFlavour {
         let index_type = match index.index_type() {
             IndexType::Unique => "UNIQUE ",
             IndexType::Normal => "",
+            IndexType::Fulltext => "FULLTEXT ",
         };
         let index_name = self.quote(&name);
         let table_reference = self.quote(&index.table().name());
 
-        let columns = index.columns().map(|c| self.quote(c.name()));
-
         format!(
             "CREATE {index_type}INDEX {index_name} ON {table_reference}({columns})",
             index_type = index_type,
             index_name = index_name,
             table_reference = table_reference,
-            columns = columns.join(", ")
+            columns = self.render_index_columns(index),
         )
     }
 
@@ -233,7 +265,11 @@ impl SqlRenderer for MysqlFlavour {
             let indices: String = table
                 .indexes()
                 .map(|index| {
-                    let tpe = if index.index_type().is_unique() { "UNIQUE " } else { "" };
+                    let tpe = match index.index_type() {
+                        IndexType::Unique => "UNIQUE ",
+                        IndexType::Normal => "",
+                        IndexType::Fulltext => "FULLTEXT ",
+                    };
                     let index_name = if index.name().len() > MYSQL_IDENTIFIER_SIZE_LIMIT {
                         &index.name()[0..MYSQL_IDENTIFIER_SIZE_LIMIT]
                     } else {
@@ -244,7 +280,7 @@ impl SqlRenderer for MysqlFlavour {
                         "{}INDEX {}({})",
                         tpe,
                         self.quote(&index_name),
-                        index.columns().map(|col| self.quote(col.name())).join(", ")
+                        self.render_index_columns(&index)
                     )
                 })
                 .join(",\n");
@@ -254,12 +290,19 @@ impl SqlRenderer for MysqlFlavour {
             String::new()
         };
 
+        let comment = table
+            .comment()
+            .map(|comment| format!(" COMMENT={}", Quoted::mysql_string(escape_string_literal(comment))))
+            .unwrap_or_default();
+
         format!(
-            "CREATE TABLE {} (\n{columns}{indexes}{primary_key}\n) DEFAULT CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci",
+            "CREATE TABLE {} (\n{columns}{indexes}{primary_key}{check_constraints}\n) DEFAULT CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci{comment}",
             table_name = self.quote(table_name),
             columns = columns,
             indexes = indexes,
             primary_key = primary_key,
+            check_constraints = self.render_table_check_constraints(table),
+            comment = comment,
         )
     }
 
@@ -287,8 +330,8 @@ impl SqlRenderer for MysqlFlavour {
         mysql_drop_index(&index.table().name(), &index.name())
     }
 
-    fn render_drop_table(&self, table_name: &str) -> Vec<String> {
-        vec![format!("DROP TABLE {}", self.quote(&table_name))]
+    fn render_drop_table(&self, table: &TableWalker<'_>) -> Vec<String> {
+        vec![format!("DROP TABLE {}", self.quote(table.name()))]
     }
 
     fn render_redefine_tables(&self, _names: &[RedefineTable], _schemas: &Pair<&SqlSchema>) -> Vec<String> {
@@ -328,9 +371,10 @@ fn render_mysql_modify(
         .unwrap_or_else(String::new);
 
     format!(
-        "MODIFY {column_name} {column_type}{nullability}{default}{sequence}",
+        "MODIFY {column_name} {column_type}{generation}{nullability}{default}{sequence}{comment}",
         column_name = Quoted::mysql_ident(&next_column.name()),
         column_type = column_type,
+        generation = render_generation(next_column),
         nullability = if next_column.arity().is_required() {
             " NOT NULL"
         } else {
@@ -342,9 +386,18 @@ fn render_mysql_modify(
         } else {
             ""
         },
+        // MODIFY removes the comment of the column if it is not repeated.
+        comment = render_column_comment(next_column),
     )
 }
 
+fn render_column_comment(column: &ColumnWalker<'_>) -> String {
+    column
+        .comment()
+        .map(|comment| format!(" COMMENT {}", Quoted::mysql_string(escape_string_literal(comment))))
+        .unwrap_or_default()
+}
+
 pub(crate) fn render_column_type(column: &ColumnWalker<'_>) -> Cow<'static, str> {
     if !column.column_type().full_data_type.is_empty() {
         return column.column_type().full_data_type.clone().into();
@@ -377,6 +430,32 @@ pub(crate) fn render_column_type(column: &ColumnWalker<'_>) -> Cow<'static, str>
     }
 }
 
+/// Expressions in defaults have to be wrapped in parentheses, but MySQL returns them without
+/// when describing the column. Literals and `CURRENT_TIMESTAMP` or `NOW()`, the only expressions
+/// MySQL 5.7 supports in defaults, are the exception.
+fn render_expression_default(expression: &str) -> Cow<'_, str> {
+    let lowercase = expression.trim().to_lowercase();
+    let is_current_timestamp = lowercase.starts_with("current_timestamp") || lowercase.starts_with("now(");
+
+    if is_current_timestamp || is_literal(&lowercase) {
+        expression.into()
+    } else {
+        format!("({})", expression).into()
+    }
+}
+
+/// Whether the (lowercase) expression is a single string, number, boolean or `NULL` literal.
+fn is_literal(expression: &str) -> bool {
+    let is_string = expression.len() >= 2
+        && expression.starts_with('\'')
+        && expression.ends_with('\'')
+        && !expression[1..expression.len() - 1].replace("''", "").contains('\'');
+    let is_number = expression.starts_with(|c: char| c.is_ascii_digit() || matches!(c, '-' | '+' | '.'))
+        && expression.parse::<f64>().is_ok();
+
+    is_string || is_number || matches!(expression, "null" | "true" | "false")
+}
+
 fn escape_string_literal(s: &str) -> Cow<'_, str> {
     static STRING_LITERAL_CHARACTER_TO_ESCAPE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"'"#).unwrap());
 
@@ -24,7 +24,7 @@ impl SqlRenderer for PostgresFlavour {
 
         format!(
             "ALTER TABLE {table} ADD {constraint_clause}FOREIGN KEY({columns}){references}",
-            table = self.quote(foreign_key.table().name()),
+            table = quote_table(&foreign_key.table()),
             constraint_clause = constraint_clause,
             columns = foreign_key
                 .constrained_column_names()
@@ -43,7 +43,7 @@ impl SqlRenderer for PostgresFlavour {
                 .map(|created_value| {
                     format!(
                         "ALTER TYPE {enum_name} ADD VALUE {value}",
-                        enum_name = Quoted::postgres_ident(schemas.enums(&alter_enum.index).previous().name()),
+                        enum_name = quote_enum(schemas.enums(&alter_enum.index).previous()),
                         value = Quoted::postgres_string(created_value)
                     )
                 })
@@ -65,7 +65,7 @@ impl SqlRenderer for PostgresFlavour {
         {
             let create_new_enum = format!(
                 "CREATE TYPE {enum_name} AS ENUM ({variants})",
-                enum_name = Quoted::postgres_ident(&tmp_name),
+                enum_name = quote_with_schema(enums.next().schema_name(), &tmp_name),
                 variants = enums.next().values().iter().map(Quoted::postgres_string).join(", ")
             );
 
@@ -81,10 +81,11 @@ impl SqlRenderer for PostgresFlavour {
                     "ALTER TABLE {schema_name}.{table_name} \
                             ALTER COLUMN {column_name} TYPE {tmp_name} \
                                 USING ({column_name}::text::{tmp_name})",
-                    schema_name = Quoted::postgres_ident(self.schema_name()),
+                    schema_name =
+                        Quoted::postgres_ident(column.table().schema_name().unwrap_or_else(|| self.schema_name())),
                     table_name = Quoted::postgres_ident(column.table().name()),
                     column_name = Quoted::postgres_ident(column.name()),
-                    tmp_name = Quoted::postgres_ident(&tmp_name),
+                    tmp_name = quote_with_schema(enums.next().schema_name(), &tmp_name),
                 );
 
                 stmts.push(sql);
@@ -95,7 +96,7 @@ impl SqlRenderer for PostgresFlavour {
         {
             let sql = format!(
                 "ALTER TYPE {enum_name} RENAME TO {tmp_old_name}",
-                enum_name = Quoted::postgres_ident(enums.previous().name()),
+                enum_name = quote_enum(enums.previous()),
                 tmp_old_name = Quoted::postgres_ident(&tmp_old_name)
             );
 
@@ -106,7 +107,7 @@ impl SqlRenderer for PostgresFlavour {
         {
             let sql = format!(
                 "ALTER TYPE {tmp_name} RENAME TO {enum_name}",
-                tmp_name = Quoted::postgres_ident(&tmp_name),
+                tmp_name = quote_with_schema(enums.next().schema_name(), &tmp_name),
                 enum_name = Quoted::postgres_ident(enums.next().name())
             );
 
@@ -117,7 +118,7 @@ impl SqlRenderer for PostgresFlavour {
         {
             let sql = format!(
                 "DROP TYPE {tmp_old_name}",
-                tmp_old_name = Quoted::postgres_ident(&tmp_old_name),
+                tmp_old_name = quote_with_schema(enums.previous().schema_name(), &tmp_old_name),
             );
 
             stmts.push(sql)
@@ -131,7 +132,7 @@ impl SqlRenderer for PostgresFlavour {
     fn render_alter_index(&self, indexes: Pair<&IndexWalker<'_>>) -> Vec<String> {
         vec![format!(
             "ALTER INDEX {} RENAME TO {}",
-            self.quote(indexes.previous().name()),
+            quote_with_schema(indexes.previous().table().schema_name(), indexes.previous().name()),
             self.quote(indexes.next().name())
         )]
     }
@@ -158,14 +159,36 @@ impl SqlRenderer for PostgresFlavour {
                     )
                 )),
                 TableChange::AddPrimaryKey { columns } => lines.push(format!(
-                    "ADD PRIMARY KEY ({})",
+                    "ADD {}PRIMARY KEY ({})",
+                    self.render_primary_key_constraint_name(tables.next()),
                     columns.iter().map(|colname| self.quote(colname)).join(", ")
                 )),
+                TableChange::AddCheckConstraint { check_constraint_index } => {
+                    let check_constraint = tables.next().check_constraint_at(*check_constraint_index);
+
+                    lines.push(format!("ADD {}", self.render_check_constraint(check_constraint)));
+                }
+                TableChange::DropCheckConstraint { check_constraint_index } => lines.push(format!(
+                    "DROP CONSTRAINT {}",
+                    self.quote(
+                        tables
+                            .previous()
+                            .check_constraint_at(*check_constraint_index)
+                            .name
+                            .as_deref()
+                            .expect("Missing constraint name for DROP CONSTRAINT on Postgres.")
+                    )
+                )),
                 TableChange::AddColumn(AddColumn { column_index }) => {
                     let column = tables.next().column_at(*column_index);
                     let col_sql = self.render_column(&column);
 
+                    before_statements.extend(render_create_named_sequence(&column));
                     lines.push(format!("ADD COLUMN {}", col_sql));
+
+                    if column.comment().is_some() {
+                        after_statements.push(render_column_comment(&column));
+                    }
                 }
                 TableChange::DropColumn(DropColumn { index }) => {
                     let name = self.quote(tables.previous().column_at(*index).name());
@@ -197,45 +220,47 @@ impl SqlRenderer for PostgresFlavour {
                     lines.push(format!("DROP COLUMN {}", name));
 
                     let col_sql = self.render_column(columns.next());
+                    before_statements.extend(render_create_named_sequence(columns.next()));
                     lines.push(format!("ADD COLUMN {}", col_sql));
+
+                    if columns.next().comment().is_some() {
+                        after_statements.push(render_column_comment(columns.next()));
+                    }
                 }
+                TableChange::SetComment => after_statements.push(render_table_comment(tables.next())),
             };
         }
 
-        if lines.is_empty() {
-            return Vec::new();
-        }
-
-        let alter_table = format!(
-            "ALTER TABLE {} {}",
-            self.quote(tables.previous().name()),
-            lines.join(",\n")
-        );
+        // Comment changes do not need an ALTER TABLE statement.
+        let alter_table = Some(lines)
+            .filter(|lines| !lines.is_empty())
+            .map(|lines| format!("ALTER TABLE {} {}", quote_table(tables.previous()), lines.join(",\n")));
 
         before_statements
             .into_iter()
-            .chain(std::iter::once(alter_table))
+            .chain(alter_table)
             .chain(after_statements.into_iter())
             .collect()
     }
 
     fn render_column(&self, column: &ColumnWalker<'_>) -> String {
         let column_name = self.quote(column.name());
-        let tpe_str = render_column_type(column.column_type());
+        let tpe_str = render_column_type(column.column_type(), column.schema());
+        let generation_str = render_generation(&column);
         let nullability_str = render_nullability(&column);
         let default_str = column
             .default()
-            .filter(|default| !matches!(default.kind(), DefaultKind::DBGENERATED(_)))
+            .filter(|default| !matches!(default.kind(), DefaultKind::DBGENERATED(expression) if expression.is_empty()))
             .map(|default| format!(" DEFAULT {}", self.render_default(default, column.column_type_family())))
             .unwrap_or_else(String::new);
-        let is_serial = column.is_autoincrement();
+        let is_serial = column.is_autoincrement() && named_sequence(column).is_none();
 
         if is_serial {
             format!("{} SERIAL", column_name)
         } else {
             format!(
-                "{}{} {}{}{}",
-                SQL_INDENTATION, column_name, tpe_str, nullability_str, default_str
+                "{}{} {}{}{}{}",
+                SQL_INDENTATION, column_name, tpe_str, generation_str, nullability_str, default_str
             )
         }
     }
@@ -249,7 +274,7 @@ impl SqlRenderer for PostgresFlavour {
 
         format!(
             "REFERENCES {}({}) {} ON UPDATE CASCADE",
-            self.quote(&foreign_key.referenced_table().name()),
+            quote_table(&foreign_key.referenced_table()),
             referenced_columns,
             render_on_delete(&foreign_key.on_delete_action())
         )
@@ -267,17 +292,25 @@ impl SqlRenderer for PostgresFlavour {
             }
             (DefaultKind::NOW, ColumnTypeFamily::DateTime) => "CURRENT_TIMESTAMP".into(),
             (DefaultKind::NOW, _) => unreachable!("NOW default on non-datetime column"),
+            (DefaultKind::VALUE(PrismaValue::List(values)), _) => render_array_literal(values).into(),
             (DefaultKind::VALUE(val), ColumnTypeFamily::DateTime) => format!("'{}'", val).into(),
-            (DefaultKind::VALUE(PrismaValue::String(val)), ColumnTypeFamily::Json) => format!("'{}'", val).into(),
+            (DefaultKind::VALUE(PrismaValue::String(val)), ColumnTypeFamily::Json) => {
+                format!("E'{}'", escape_string_literal(&val)).into()
+            }
             (DefaultKind::VALUE(val), _) => val.to_string().into(),
+            (DefaultKind::SEQUENCE(sequence_name), _) if !sequence_name.is_empty() => format!(
+                "nextval({})",
+                Quoted::postgres_string(Quoted::postgres_ident(sequence_name))
+            )
+            .into(),
             (DefaultKind::SEQUENCE(_), _) => "".into(),
         }
     }
 
     fn render_create_enum(&self, enm: &EnumWalker<'_>) -> Vec<String> {
         let sql = format!(
-            r#"CREATE TYPE "{enum_name}" AS ENUM ({variants})"#,
-            enum_name = enm.name(),
+            "CREATE TYPE {enum_name} AS ENUM ({variants})",
+            enum_name = quote_enum(enm),
             variants = enm.values().iter().map(Quoted::postgres_string).join(", "),
         );
 
@@ -285,24 +318,66 @@ impl SqlRenderer for PostgresFlavour {
     }
 
     fn render_create_index(&self, index: &IndexWalker<'_>) -> String {
+        let index_name = self.quote(index.name());
+        let table_reference = quote_table(&index.table());
+
+        // Full-text indexes are GIN indexes over one `to_tsvector` expression per column, so that
+        // the `to_tsvector('simple', column) @@ ...` conditions of the query engine can use them.
+        if let IndexType::Fulltext = index.index_type() {
+            let expressions = index
+                .columns()
+                .map(|c| format!("to_tsvector('simple', {})", self.quote(c.name())));
+
+            return format!(
+                "CREATE INDEX {index_name} ON {table_reference} USING GIN ({expressions})",
+                index_name = index_name,
+                table_reference = table_reference,
+                expressions = expressions.join(", ")
+            );
+        }
+
         let index_type = match index.index_type() {
             IndexType::Unique => "UNIQUE ",
-            IndexType::Normal => "",
+            IndexType::Normal | IndexType::Fulltext => "",
         };
 
-        let index_name = self.quote(index.name());
-        let table_reference = self.quote(index.table().name());
-        let columns = index.columns().map(|c| self.quote(c.name()));
+        let algorithm = match index.algorithm() {
+            None => "",
+            Some(IndexAlgorithm::Hash) => " USING HASH",
+            Some(IndexAlgorithm::Gin) => " USING GIN",
+            Some(IndexAlgorithm::Gist) => " USING GIST",
+            Some(IndexAlgorithm::Brin) => " USING BRIN",
+        };
 
         format!(
-            "CREATE {index_type}INDEX {index_name} ON {table_reference}({columns})",
+            "CREATE {index_type}INDEX {index_name} ON {table_reference}{algorithm}({columns}){predicate}",
             index_type = index_type,
             index_name = index_name,
             table_reference = table_reference,
-            columns = columns.join(", ")
+            algorithm = algorithm,
+            columns = self.render_index_columns(index),
+            predicate = self.render_index_predicate(index),
         )
     }
 
+    fn render_create_table(&self, table: &TableWalker<'_>) -> Vec<String> {
+        let table_comment = table.comment().map(|_| render_table_comment(table));
+        let column_comments = table
+            .columns()
+            .filter(|column| column.comment().is_some())
+            .map(|column| render_column_comment(&column));
+
+        let sequences = table
+            .columns()
+            .filter_map(|column| render_create_named_sequence(&column));
+
+        sequences
+            .chain(std::iter::once(self.render_create_table_as(table, table.name())))
+            .chain(table_comment)
+            .chain(column_comments)
+            .collect()
+    }
+
     fn render_create_table_as(&self, table: &TableWalker<'_>, table_name: &str) -> String {
         let columns: String = table.columns().map(|column| self.render_column(&column)).join(",\n");
 
@@ -313,24 +388,27 @@ impl SqlRenderer for PostgresFlavour {
             .map(|col| self.quote(col))
             .join(",");
         let pk = if !pk_column_names.is_empty() {
-            format!(",\n\n{}PRIMARY KEY ({})", SQL_INDENTATION, pk_column_names)
+            format!(
+                ",\n\n{}{}PRIMARY KEY ({})",
+                SQL_INDENTATION,
+                self.render_primary_key_constraint_name(table),
+                pk_column_names
+            )
         } else {
             String::new()
         };
 
         format!(
-            "CREATE TABLE {table_name} (\n{columns}{primary_key}\n)",
-            table_name = self.quote(table_name),
+            "CREATE TABLE {table_name} (\n{columns}{primary_key}{check_constraints}\n)",
+            table_name = quote_with_schema(table.schema_name(), table_name),
             columns = columns,
             primary_key = pk,
+            check_constraints = self.render_table_check_constraints(table),
         )
     }
 
     fn render_drop_enum(&self, dropped_enum: &EnumWalker<'_>) -> Vec<String> {
-        let sql = format!(
-            "DROP TYPE {enum_name}",
-            enum_name = Quoted::postgres_ident(dropped_enum.name()),
-        );
+        let sql = format!("DROP TYPE {enum_name}", enum_name = quote_enum(dropped_enum));
 
         vec![sql]
     }
@@ -338,17 +416,20 @@ impl SqlRenderer for PostgresFlavour {
     fn render_drop_foreign_key(&self, foreign_key: &ForeignKeyWalker<'_>) -> String {
         format!(
             "ALTER TABLE {table} DROP CONSTRAINT {constraint_name}",
-            table = self.quote(foreign_key.table().name()),
+            table = quote_table(&foreign_key.table()),
             constraint_name = Quoted::postgres_ident(foreign_key.constraint_name().unwrap()),
         )
     }
 
     fn render_drop_index(&self, index: &IndexWalker<'_>) -> String {
-        format!("DROP INDEX {}", self.quote(index.name()))
+        format!(
+            "DROP INDEX {}",
+            quote_with_schema(index.table().schema_name(), index.name())
+        )
     }
 
-    fn render_drop_table(&self, table_name: &str) -> Vec<String> {
-        vec![format!("DROP TABLE {}", self.quote(&table_name))]
+    fn render_drop_table(&self, table: &TableWalker<'_>) -> Vec<String> {
+        vec![format!("DROP TABLE {}", quote_table(table))]
     }
 
     fn render_redefine_tables(&self, _names: &[RedefineTable], _schemas: &Pair<&SqlSchema>) -> Vec<String> {
@@ -364,7 +445,87 @@ impl SqlRenderer for PostgresFlavour {
     }
 }
 
-pub(crate) fn render_column_type(t: &ColumnType) -> String {
+/// Quote a table, qualified with its schema if it does not live in the schema of the connection.
+fn quote_table(table: &TableWalker<'_>) -> String {
+    quote_with_schema(table.schema_name(), table.name())
+}
+
+/// Quote an enum, qualified with its schema if it does not live in the schema of the connection.
+fn quote_enum(r#enum: &EnumWalker<'_>) -> String {
+    quote_with_schema(r#enum.schema_name(), r#enum.name())
+}
+
+/// Set the comment of the table with `COMMENT ON TABLE`, or remove it.
+fn render_table_comment(table: &TableWalker<'_>) -> String {
+    format!(
+        "COMMENT ON TABLE {} IS {}",
+        quote_table(table),
+        render_comment(table.comment())
+    )
+}
+
+/// Set the comment of the column with `COMMENT ON COLUMN`, or remove it.
+fn render_column_comment(column: &ColumnWalker<'_>) -> String {
+    format!(
+        "COMMENT ON COLUMN {}.{} IS {}",
+        quote_table(&column.table()),
+        Quoted::postgres_ident(column.name()),
+        render_comment(column.comment())
+    )
+}
+
+/// The sequence of a `sequence("name")` default.
+fn named_sequence<'a>(column: &ColumnWalker<'a>) -> Option<&'a str> {
+    match column.default().map(|default| default.kind()) {
+        Some(DefaultKind::SEQUENCE(sequence_name)) if !sequence_name.is_empty() => Some(sequence_name),
+        _ => None,
+    }
+}
+
+/// Named sequences are not owned by a column, they are created on first use.
+fn render_create_named_sequence(column: &ColumnWalker<'_>) -> Option<String> {
+    named_sequence(column).map(|sequence_name| {
+        format!(
+            "CREATE SEQUENCE IF NOT EXISTS {}",
+            Quoted::postgres_ident(sequence_name)
+        )
+    })
+}
+
+/// Renders a list default as an array literal, e.g. `E'{1,2,3}'` or `E'{"a","b"}'`.
+fn render_array_literal(values: &[PrismaValue]) -> String {
+    let elements = values
+        .iter()
+        .map(|value| match value {
+            PrismaValue::String(val) | PrismaValue::Enum(val) => {
+                format!("\"{}\"", val.replace('\\', "\\\\").replace('"', "\\\""))
+            }
+            PrismaValue::DateTime(val) => format!("\"{}\"", val.to_rfc3339()),
+            val => val.to_string(),
+        })
+        .join(",");
+
+    format!("E'{{{}}}'", escape_string_literal(&elements))
+}
+
+fn render_comment(comment: Option<&str>) -> String {
+    comment
+        .map(|comment| format!("E'{}'", escape_string_literal(comment)))
+        .unwrap_or_else(|| "NULL".to_owned())
+}
+
+fn quote_with_schema(schema_name: Option<&str>, name: &str) -> String {
+    match schema_name {
+        Some(schema_name) => format!(
+            "{}.{}",
+            Quoted::postgres_ident(schema_name),
+            Quoted::postgres_ident(name)
+        ),
+        None => Quoted::postgres_ident(name).to_string(),
+    }
+}
+
+pub(crate) fn render_column_type(t: &ColumnType, schema: &SqlSchema) -> String {
     let array = match t.arity {
         ColumnArity::List => "[]",
         _ => "",
@@ -382,7 +543,11 @@ pub(crate) fn render_column_type(t: &ColumnType) -> String {
         ColumnTypeFamily::Int => format!("INTEGER{}", array),
         ColumnTypeFamily::BigInt => format!("BIGINT{}", array),
         ColumnTypeFamily::String => format!("TEXT{}", array),
-        ColumnTypeFamily::Enum(name) => format!("{}{}", Quoted::postgres_ident(name), array),
+        ColumnTypeFamily::Enum(name) => {
+            let enum_schema = schema.get_enum(name).and_then(|r#enum| r#enum.schema.as_deref());
+
+            format!("{}{}", quote_with_schema(enum_schema, name), array)
+        }
         ColumnTypeFamily::Json => format!("JSONB{}", array),
         ColumnTypeFamily::Binary => format!("BYTEA{}", array),
         ColumnTypeFamily::Uuid => unimplemented!("Uuid not handled yet"),
@@ -424,17 +589,21 @@ fn render_alter_column(
                     }
                 }
             }
-            PostgresAlterColumn::SetDefault(new_default) => clauses.push(format!(
-                "{} SET DEFAULT {}",
-                &alter_column_prefix,
-                renderer.render_default(&new_default, columns.next().column_type_family())
-            )),
+            PostgresAlterColumn::SetDefault(new_default) => {
+                before_statements.extend(render_create_named_sequence(columns.next()));
+
+                clauses.push(format!(
+                    "{} SET DEFAULT {}",
+                    &alter_column_prefix,
+                    renderer.render_default(&new_default, columns.next().column_type_family())
+                ))
+            }
             PostgresAlterColumn::DropNotNull => clauses.push(format!("{} DROP NOT NULL", &alter_column_prefix)),
             PostgresAlterColumn::SetNotNull => clauses.push(format!("{} SET NOT NULL", &alter_column_prefix)),
             PostgresAlterColumn::SetType(ty) => clauses.push(format!(
                 "{} SET DATA TYPE {}",
                 &alter_column_prefix,
-                render_column_type(&ty)
+                render_column_type(&ty, columns.next().schema())
             )),
             PostgresAlterColumn::AddSequence => {
                 // We imitate the sequence that would be automatically created on a `SERIAL` column.
@@ -448,22 +617,33 @@ fn render_alter_column(
                 )
                 .to_lowercase();
 
-                before_statements.push(format!("CREATE SEQUENCE {}", Quoted::postgres_ident(&sequence_name)));
+                // The sequence lives in the schema of its table.
+                let table_schema = columns.next().table().schema_name();
+                let sequence_reference = match table_schema {
+                    Some(_) => quote_with_schema(table_schema, &sequence_name),
+                    None => sequence_name.clone(),
+                };
+
+                before_statements.push(format!(
+                    "CREATE SEQUENCE {}",
+                    quote_with_schema(table_schema, &sequence_name)
+                ));
 
                 clauses.push(format!(
                     "{prefix} SET DEFAULT {default}",
                     prefix = alter_column_prefix,
-                    default = format_args!("nextval({})", Quoted::postgres_string(&sequence_name))
+                    default = format_args!("nextval({})", Quoted::postgres_string(&sequence_reference))
                 ));
 
                 after_statements.push(format!(
                     "ALTER SEQUENCE {sequence_name} OWNED BY {schema_name}.{table_name}.{column_name}",
-                    sequence_name = Quoted::postgres_ident(sequence_name),
-                    schema_name = Quoted::postgres_ident(renderer.0.schema()),
+                    sequence_name = quote_with_schema(table_schema, &sequence_name),
+                    schema_name = Quoted::postgres_ident(table_schema.unwrap_or_else(|| renderer.schema_name())),
                     table_name = table_name,
                     column_name = column_name,
                 ));
             }
+            PostgresAlterColumn::SetComment => after_statements.push(render_column_comment(columns.next())),
         }
     }
 }
@@ -501,12 +681,18 @@ fn expand_alter_column(columns: &Pair<ColumnWalker<'_>>, column_changes: &Column
                 if columns.previous().is_autoincrement() {
                     // The sequence should be dropped.
                     changes.push(PostgresAlterColumn::DropDefault)
+                } else if named_sequence(columns.next()).is_some() {
+                    // The named sequence is used as is.
+                    let next_default = columns.next().default().expect("named sequence default");
+                    changes.push(PostgresAlterColumn::SetDefault(next_default.clone()))
                 } else {
                     // The sequence should be created.
                     changes.push(PostgresAlterColumn::AddSequence)
                 }
             }
             ColumnChange::Renaming => unreachable!("column renaming"),
+            ColumnChange::Generation => unreachable!("generation changes drop and recreate the column"),
+            ColumnChange::Comment => changes.push(PostgresAlterColumn::SetComment),
         }
     }
 
@@ -528,4 +714,6 @@ enum PostgresAlterColumn {
     SetNotNull,
     /// Add an auto-incrementing sequence as a default on the column.
     AddSequence,
+    /// Set or remove the comment of the column, with a separate `COMMENT ON` statement.
+    SetComment,
 }
@@ -23,17 +23,18 @@ impl SqlRenderer for SqliteFlavour {
         let index_type = match index.index_type() {
             IndexType::Unique => "UNIQUE ",
             IndexType::Normal => "",
+            IndexType::Fulltext => return render_create_fulltext_index(index),
         };
         let index_name = self.quote(index.name());
         let table_reference = self.quote(index.table().name());
-        let columns = index.columns().map(|c| self.quote(c.name()));
 
         format!(
-            "CREATE {index_type}INDEX {index_name} ON {table_reference}({columns})",
+            "CREATE {index_type}INDEX {index_name} ON {table_reference}({columns}){predicate}",
             index_type = index_type,
             index_name = index_name,
             table_reference = table_reference,
-            columns = columns.join(", ")
+            columns = self.render_index_columns(index),
+            predicate = self.render_index_predicate(index),
         )
     }
 
@@ -43,7 +44,8 @@ impl SqlRenderer for SqliteFlavour {
         let nullability_str = render_nullability(&column);
         let default_str = column
             .default()
-            .filter(|default| !matches!(default.kind(), DefaultKind::DBGENERATED(_) | DefaultKind::SEQUENCE(_)))
+            .filter(|default| !matches!(default.kind(), DefaultKind::DBGENERATED(expression) if expression.is_empty()))
+            .filter(|default| !matches!(default.kind(), DefaultKind::SEQUENCE(_)))
             .map(|default| format!(" DEFAULT {}", self.render_default(default, column.column_type_family())))
             .unwrap_or_else(String::new);
         let auto_increment_str = if column.is_autoincrement() && column.is_single_primary_key() {
@@ -80,7 +82,9 @@ impl SqlRenderer for SqliteFlavour {
 
     fn render_default<'a>(&self, default: &'a DefaultValue, family: &ColumnTypeFamily) -> Cow<'a, str> {
         match (default.kind(), family) {
-            (DefaultKind::DBGENERATED(val), _) => val.as_str().into(),
+            // Expressions have to be wrapped in parentheses, literals may be.
+            (DefaultKind::DBGENERATED(val), _) if val.is_empty() => "".into(),
+            (DefaultKind::DBGENERATED(val), _) => format!("({})", val).into(),
             (DefaultKind::VALUE(PrismaValue::String(val)), ColumnTypeFamily::String)
             | (DefaultKind::VALUE(PrismaValue::Enum(val)), ColumnTypeFamily::Enum(_)) => {
                 format!("'{}'", escape_quotes(&val)).into()
@@ -122,11 +126,14 @@ impl SqlRenderer for SqliteFlavour {
                         column_definition = col_sql,
                     ));
                 }
+                TableChange::AddCheckConstraint { .. } => unreachable!("AddCheckConstraint on SQLite"),
                 TableChange::AddPrimaryKey { .. } => unreachable!("AddPrimaryKey on SQLite"),
                 TableChange::AlterColumn(_) => unreachable!("AlterColumn on SQLite"),
                 TableChange::DropAndRecreateColumn { .. } => unreachable!("DropAndRecreateColumn on SQLite"),
+                TableChange::DropCheckConstraint { .. } => unreachable!("DropCheckConstraint on SQLite"),
                 TableChange::DropColumn(_) => unreachable!("DropColumn on SQLite"),
                 TableChange::DropPrimaryKey { .. } => unreachable!("DropPrimaryKey on SQLite"),
+                TableChange::SetComment => unreachable!("SetComment on SQLite"),
             };
         }
 
@@ -186,11 +193,12 @@ impl SqlRenderer for SqliteFlavour {
         };
 
         format!(
-            "CREATE TABLE {table_name} (\n{columns}{foreign_keys}{primary_key}\n)",
+            "CREATE TABLE {table_name} (\n{columns}{foreign_keys}{primary_key}{check_constraints}\n)",
             table_name = self.quote(table_name),
             columns = columns,
             foreign_keys = foreign_keys,
             primary_key = primary_key,
+            check_constraints = self.render_table_check_constraints(table),
         )
     }
 
@@ -203,19 +211,23 @@ impl SqlRenderer for SqliteFlavour {
     }
 
     fn render_drop_index(&self, index: &IndexWalker<'_>) -> String {
-        format!("DROP INDEX {}", self.quote(index.name()))
+        match index.index_type() {
+            IndexType::Fulltext => render_drop_fulltext_index(index, false),
+            _ => format!("DROP INDEX {}", self.quote(index.name())),
+        }
     }
 
-    fn render_drop_table(&self, table_name: &str) -> Vec<String> {
+    fn render_drop_table(&self, table: &TableWalker<'_>) -> Vec<String> {
         // Turning off the pragma is safe, because schema validation would forbid foreign keys
         // to a non-existent model. There appears to be no other way to deal with cyclic
         // dependencies in the dropping order of tables in the presence of foreign key
         // constraints on SQLite.
-        vec![
-            "PRAGMA foreign_keys=off".to_string(),
-            format!("DROP TABLE {}", self.quote(&table_name)),
-            "PRAGMA foreign_keys=on".to_string(),
-        ]
+        let mut result = vec!["PRAGMA foreign_keys=off".to_string()];
+        result.extend(render_drop_remaining_fulltext_indexes(table));
+        result.push(format!("DROP TABLE {}", self.quote(table.name())));
+        result.push("PRAGMA foreign_keys=on".to_string());
+
+        result
     }
 
     fn render_redefine_tables(&self, tables: &[RedefineTable], schemas: &Pair<&SqlSchema>) -> Vec<String> {
@@ -232,6 +244,7 @@ impl SqlRenderer for SqliteFlavour {
 
             copy_current_table_into_new_table(&mut result, redefine_table, &tables, &temporary_table_name, self);
 
+            result.extend(render_drop_remaining_fulltext_indexes(tables.previous()));
             result.push(format!(r#"DROP TABLE "{}""#, tables.previous().name()));
 
             result.push(format!(
@@ -273,6 +286,107 @@ fn render_column_type(t: &ColumnType) -> &'static str {
     }
 }
 
+/// A full-text index is an FTS5 table with the name of the index. As an external content table,
+/// it reads the values from the indexed table instead of storing a copy, and triggers on the
+/// indexed table keep it up to date. The `rebuild` command indexes the existing rows.
+fn render_create_fulltext_index(index: &IndexWalker<'_>) -> String {
+    let fts_table = Quoted::sqlite_ident(index.name());
+    let table = Quoted::sqlite_ident(index.table().name());
+    let columns = index
+        .columns()
+        .map(|column| Quoted::sqlite_ident(column.name()))
+        .join(", ");
+    let values = |row: &str| {
+        index
+            .columns()
+            .map(|column| format!("{}.{}", row, Quoted::sqlite_ident(column.name())))
+            .join(", ")
+    };
+
+    let insert = format!(
+        "INSERT INTO {fts_table}(rowid, {columns}) VALUES (new.rowid, {values});",
+        fts_table = fts_table,
+        columns = columns,
+        values = values("new"),
+    );
+    let delete = format!(
+        "INSERT INTO {fts_table}({fts_table}, rowid, {columns}) VALUES ('delete', old.rowid, {values});",
+        fts_table = fts_table,
+        columns = columns,
+        values = values("old"),
+    );
+
+    let statements = vec![
+        format!(
+            "CREATE VIRTUAL TABLE {} USING fts5({}, content='{}')",
+            fts_table,
+            columns,
+            escape_quotes(index.table().name())
+        ),
+        format!(
+            "CREATE TRIGGER {} AFTER INSERT ON {} BEGIN {} END",
+            fulltext_index_trigger(index.name(), "insert"),
+            table,
+            insert
+        ),
+        format!(
+            "CREATE TRIGGER {} AFTER DELETE ON {} BEGIN {} END",
+            fulltext_index_trigger(index.name(), "delete"),
+            table,
+            delete
+        ),
+        format!(
+            "CREATE TRIGGER {} AFTER UPDATE ON {} BEGIN {} {} END",
+            fulltext_index_trigger(index.name(), "update"),
+            table,
+            delete,
+            insert
+        ),
+        format!(
+            "INSERT INTO {fts_table}({fts_table}) VALUES ('rebuild')",
+            fts_table = fts_table
+        ),
+    ];
+
+    statements.join(";\n")
+}
+
+/// Drops the triggers and the FTS5 table of a full-text index. With `if_exists`, the index may
+/// already have been dropped.
+fn render_drop_fulltext_index(index: &IndexWalker<'_>, if_exists: bool) -> String {
+    let if_exists = if if_exists { "IF EXISTS " } else { "" };
+
+    ["insert", "delete", "update"]
+        .iter()
+        .map(|event| {
+            format!(
+                "DROP TRIGGER {}{}",
+                if_exists,
+                fulltext_index_trigger(index.name(), event)
+            )
+        })
+        .chain(std::iter::once(format!(
+            "DROP TABLE {}{}",
+            if_exists,
+            Quoted::sqlite_ident(index.name())
+        )))
+        .join(";\n")
+}
+
+/// The FTS5 tables of full-text indexes are not dropped with the indexed table. The indexes that
+/// were dropped or changed on the table are already gone at this point.
+fn render_drop_remaining_fulltext_indexes(table: &TableWalker<'_>) -> Vec<String> {
+    table
+        .indexes()
+        .filter(|index| index.index_type() == &IndexType::Fulltext)
+        .map(|index| render_drop_fulltext_index(&index, true))
+        .collect()
+}
+
+fn fulltext_index_trigger(index_name: &str, event: &str) -> Quoted<String> {
+    Quoted::sqlite_ident(format!("{}_{}", index_name, event))
+}
+
 fn escape_quotes(s: &str) -> Cow<'_, str> {
     static STRING_LITERAL_CHARACTER_TO_ESCAPE_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"'"#).unwrap());
 
@@ -2,10 +2,11 @@ mod sql_schema_calculator_flavour;
 
 pub(super) use sql_schema_calculator_flavour::SqlSchemaCalculatorFlavour;
 
-use crate::{flavour::SqlFlavour, sql_renderer::IteratorJoin};
+use crate::flavour::SqlFlavour;
 use datamodel::{
     walkers::{walk_models, walk_relations, ModelWalker, ScalarFieldWalker, TypeWalker},
-    Datamodel, DefaultValue, FieldArity, IndexDefinition, IndexType, ScalarType, ValueGenerator, ValueGeneratorFn,
+    Datamodel, DefaultValue, FieldArity, IndexAlgorithm, IndexDefinition, IndexType, ScalarType, SortOrder,
+    ValueGenerator, ValueGeneratorFn,
 };
 use prisma_value::PrismaValue;
 use sql_schema_describer::{self as sql, ColumnArity};
@@ -42,22 +43,38 @@ impl<'a> SqlSchemaCalculator<'a> {
             tables,
             enums,
             sequences,
+            views: Vec::new(),
         }
     }
 
+    /// Tables in the schema of the connection are described without a schema.
+    fn table_schema(&self, schema: Option<&str>) -> Option<String> {
+        schema
+            .filter(|schema| Some(*schema) != self.flavour.connection_schema_name())
+            .map(String::from)
+    }
+
+    /// Doc comments are stored as comments in the database, if it supports them.
+    fn comment(&self, documentation: Option<&str>) -> Option<String> {
+        documentation.filter(|_| self.flavour.supports_comments()).map(String::from)
+    }
+
     fn calculate_model_tables<'iter>(&'iter self) -> impl Iterator<Item = (ModelWalker<'a>, sql::Table)> + 'iter {
-        walk_models(self.data_model).map(move |model| {
+        // Views are managed by the user, migrations never create or alter them.
+        walk_models(self.data_model).filter(|model| !model.is_view()).map(move |model| {
             let columns = model
                 .scalar_fields()
                 .flat_map(|f| match f.field_type() {
                     TypeWalker::Base(_) => {
-                        let has_auto_increment_default = matches!(f.default_value(), Some(DefaultValue::Expression(ValueGenerator { generator: ValueGeneratorFn::Autoincrement, .. })));
+                        let has_auto_increment_default = matches!(f.default_value(), Some(DefaultValue::Expression(ValueGenerator { generator: ValueGeneratorFn::Autoincrement, .. })) | Some(DefaultValue::Expression(ValueGenerator { generator: ValueGeneratorFn::Sequence, .. })));
 
                         Some(sql::Column {
                             name: f.db_name().to_owned(),
                             tpe: column_type(&f),
                             default: migration_value_new(&f),
                             auto_increment: has_auto_increment_default || self.flavour.field_is_implicit_autoincrement_primary_key(&f),
+                            generated: generated_column(&f),
+                            comment: self.comment(f.documentation()),
                         })
                     },
                     TypeWalker::Enum(r#enum) => {
@@ -67,18 +84,32 @@ impl<'a> SqlSchemaCalculator<'a> {
                             tpe: self.flavour.enum_column_type(&f,  enum_db_name),
                             default: migration_value_new(&f),
                             auto_increment: false,
+                            generated: generated_column(&f),
+                            comment: self.comment(f.documentation()),
                         })
                     }
                     TypeWalker::NativeType(scalar_type, native_type_instance) =>{
-                        let has_auto_increment_default = matches!(f.default_value(), Some(DefaultValue::Expression(ValueGenerator { generator: ValueGeneratorFn::Autoincrement, .. })));
+                        let has_auto_increment_default = matches!(f.default_value(), Some(DefaultValue::Expression(ValueGenerator { generator: ValueGeneratorFn::Autoincrement, .. })) | Some(DefaultValue::Expression(ValueGenerator { generator: ValueGeneratorFn::Sequence, .. })));
 
                         Some(sql::Column {
                             name: f.db_name().to_owned(),
                             tpe: self.flavour.column_type_for_native_type(&f, scalar_type, native_type_instance),
                             default: migration_value_new(&f),
-                            auto_increment: has_auto_increment_default || self.flavour.field_is_implicit_autoincrement_primary_key(&f)
+                            auto_increment: has_auto_increment_default || self.flavour.field_is_implicit_autoincrement_primary_key(&f),
+                            generated: generated_column(&f),
+                            comment: self.comment(f.documentation()),
                         })
                     } ,
+                    TypeWalker::CompositeType(_) => {
+                        Some(sql::Column {
+                            name: f.db_name().to_owned(),
+                            tpe: composite_column_type(&f),
+                            default: None,
+                            auto_increment: false,
+                            generated: None,
+                            comment: self.comment(f.documentation()),
+                        })
+                    }
                     _ => None,
                 })
                 .collect();
@@ -89,15 +120,21 @@ impl<'a> SqlSchemaCalculator<'a> {
                     .map(|field| field.db_name().to_owned())
                     .collect(),
                 sequence: None,
-                constraint_name: None,
+                constraint_name: model.id_constraint_name().map(String::from),
             }).filter(|pk| !pk.columns.is_empty());
 
             // TODO: HERE
             let single_field_indexes = model.scalar_fields().filter(|f| f.is_unique()).map(|f| {
                 sql::Index {
-                    name: self.flavour.single_field_index_name(model.db_name(), f.db_name()),
+                    name: f
+                        .unique_constraint_name()
+                        .map(String::from)
+                        .unwrap_or_else(|| self.flavour.single_field_index_name(model.db_name(), f.db_name())),
                     columns: vec![f.db_name().to_owned()],
                     tpe: sql::IndexType::Unique,
+                    column_options: Vec::new(),
+                    algorithm: None,
+                    predicate: None,
                 }
             });
 
@@ -111,16 +148,11 @@ impl<'a> SqlSchemaCalculator<'a> {
                 let index_type = match index_definition.tpe {
                     IndexType::Unique => sql::IndexType::Unique,
                     IndexType::Normal => sql::IndexType::Normal,
+                    IndexType::Fulltext => sql::IndexType::Fulltext,
                 };
 
-                let index_name = index_definition.name.clone().unwrap_or_else(|| {
-                    format!(
-                        "{table}.{fields}_{qualifier}",
-                        table = &model.db_name(),
-                        fields = referenced_fields.iter().map(|field| field.db_name()).join("_"),
-                        qualifier = if index_type.is_unique() { "unique" } else { "index" },
-                    )
-                });
+                // `map` sets the name of the constraint in the database, `name` sets both.
+                let index_name = model.index_database_name(index_definition);
 
                 sql::Index {
                     name: index_name,
@@ -131,15 +163,54 @@ impl<'a> SqlSchemaCalculator<'a> {
                         .map(|field| field.db_name().to_owned())
                         .collect(),
                     tpe: index_type,
+                    column_options: index_definition
+                        .field_options
+                        .iter()
+                        .map(|options| sql::IndexColumnOptions {
+                            sort_order: match options.sort_order {
+                                SortOrder::Asc => sql::SortOrder::Asc,
+                                SortOrder::Desc => sql::SortOrder::Desc,
+                            },
+                            length: options.length,
+                        })
+                        .collect(),
+                    // B-tree is the default, the describer does not report it.
+                    algorithm: index_definition.algorithm.and_then(|algorithm| match algorithm {
+                        IndexAlgorithm::BTree => None,
+                        IndexAlgorithm::Hash => Some(sql::IndexAlgorithm::Hash),
+                        IndexAlgorithm::Gin => Some(sql::IndexAlgorithm::Gin),
+                        IndexAlgorithm::Gist => Some(sql::IndexAlgorithm::Gist),
+                        IndexAlgorithm::Brin => Some(sql::IndexAlgorithm::Brin),
+                    }),
+                    predicate: index_definition.predicate.clone(),
                 }
             });
 
+            // Unnamed check constraints get the same default names MySQL would give them.
+            let check_constraints = model
+                .check_constraints()
+                .iter()
+                .enumerate()
+                .map(|(idx, check_constraint)| sql::CheckConstraint {
+                    name: Some(
+                        check_constraint
+                            .name
+                            .clone()
+                            .unwrap_or_else(|| format!("{}_chk_{}", model.database_name(), idx + 1)),
+                    ),
+                    expression: check_constraint.expression.clone(),
+                })
+                .collect();
+
             let table = sql::Table {
                 name: model.database_name().to_owned(),
+                schema: self.table_schema(model.schema()),
                 columns,
                 indices: single_field_indexes.chain(multiple_field_indexes).collect(),
                 primary_key,
                 foreign_keys: Vec::new(),
+                check_constraints,
+                comment: self.comment(model.documentation()),
             };
 
             (model, table)
@@ -162,9 +233,10 @@ impl<'a> SqlSchemaCalculator<'a> {
             // Foreign key
             {
                 let fk = sql::ForeignKey {
-                    constraint_name: None,
+                    constraint_name: relation_field.constraint_name().map(String::from),
                     columns: fk_columns,
                     referenced_table: relation_field.referenced_model().database_name().to_owned(),
+                    referenced_schema: self.table_schema(relation_field.referenced_model().schema()),
                     referenced_columns: relation_field.referenced_columns().map(String::from).collect(),
                     on_update_action: sql::ForeignKeyAction::Cascade,
                     on_delete_action: match column_arity(relation_field.arity()) {
@@ -194,6 +266,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                         constraint_name: None,
                         columns: vec![m2m.model_a_column().into()],
                         referenced_table: model_a.db_name().into(),
+                        referenced_schema: self.table_schema(model_a.schema()),
                         referenced_columns: vec![model_a_id.db_name().into()],
                         on_update_action: self.flavour.m2m_foreign_key_action(&model_a, &model_b),
                         on_delete_action: self.flavour.m2m_foreign_key_action(&model_a, &model_b),
@@ -202,6 +275,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                         constraint_name: None,
                         columns: vec![m2m.model_b_column().into()],
                         referenced_table: model_b.db_name().into(),
+                        referenced_schema: self.table_schema(model_b.schema()),
                         referenced_columns: vec![model_b_id.db_name().into()],
                         on_update_action: self.flavour.m2m_foreign_key_action(&model_a, &model_b),
                         on_delete_action: self.flavour.m2m_foreign_key_action(&model_a, &model_b),
@@ -213,11 +287,17 @@ impl<'a> SqlSchemaCalculator<'a> {
                         name: format!("{}_AB_unique", &table_name),
                         columns: vec![m2m.model_a_column().into(), m2m.model_b_column().into()],
                         tpe: sql::IndexType::Unique,
+                        column_options: Vec::new(),
+                        algorithm: None,
+                        predicate: None,
                     },
                     sql::Index {
                         name: format!("{}_B_index", &table_name),
                         columns: vec![m2m.model_b_column().into()],
                         tpe: sql::IndexType::Normal,
+                        column_options: Vec::new(),
+                        algorithm: None,
+                        predicate: None,
                     },
                 ];
 
@@ -227,21 +307,28 @@ impl<'a> SqlSchemaCalculator<'a> {
                         tpe: column_type(&model_a_id),
                         default: None,
                         auto_increment: false,
+                        generated: None,
+                        comment: None,
                     },
                     sql::Column {
                         name: m2m.model_b_column().into(),
                         tpe: column_type(&model_b_id),
                         default: None,
                         auto_increment: false,
+                        generated: None,
+                        comment: None,
                     },
                 ];
 
                 sql::Table {
                     name: table_name,
+                    schema: self.table_schema(model_a.schema()),
                     columns,
                     indices: indexes,
                     primary_key: None,
                     foreign_keys,
+                    check_constraints: Vec::new(),
+                    comment: None,
                 }
             })
     }
@@ -251,39 +338,67 @@ fn migration_value_new(field: &ScalarFieldWalker<'_>) -> Option<sql_schema_descr
     let value = match &field.default_value()? {
         datamodel::DefaultValue::Single(s) => match field.field_type() {
             TypeWalker::Enum(inum) => {
-                let corresponding_value = inum
-                    .r#enum
-                    .values()
-                    .find(|val| val.name.as_str() == s.to_string())
-                    .expect("could not find enum value");
+                let enum_value_db_name = |value: &PrismaValue| {
+                    let corresponding_value = inum
+                        .r#enum
+                        .values()
+                        .find(|val| val.name.as_str() == value.to_string())
+                        .expect("could not find enum value");
+
+                    PrismaValue::Enum(corresponding_value.final_database_name().to_owned())
+                };
 
-                PrismaValue::Enum(corresponding_value.final_database_name().to_owned())
+                match s {
+                    PrismaValue::List(values) => PrismaValue::List(values.iter().map(enum_value_db_name).collect()),
+                    value => enum_value_db_name(value),
+                }
             }
             _ => s.clone(),
         },
         datamodel::DefaultValue::Expression(expression) if expression.name == "now" && expression.args.is_empty() => {
             return Some(sql_schema_describer::DefaultValue::now())
         }
-        datamodel::DefaultValue::Expression(expression)
-            if expression.name == "dbgenerated" && expression.args.is_empty() =>
-        {
-            return Some(sql_schema_describer::DefaultValue::db_generated(String::new()))
+        datamodel::DefaultValue::Expression(expression) if expression.name == "dbgenerated" => {
+            // Without an expression, the default is managed outside of migrations.
+            let expression = expression.string_argument().unwrap_or_default();
+
+            return Some(sql_schema_describer::DefaultValue::db_generated(expression))
         }
         datamodel::DefaultValue::Expression(expression)
             if expression.name == "autoincrement" && expression.args.is_empty() =>
         {
             return Some(sql_schema_describer::DefaultValue::sequence(String::new()))
         }
+        datamodel::DefaultValue::Expression(expression) if expression.name == "sequence" => {
+            return expression.string_argument().map(sql_schema_describer::DefaultValue::sequence)
+        }
         datamodel::DefaultValue::Expression(_) => return None,
     };
 
     Some(sql_schema_describer::DefaultValue::value(value))
 }
 
+fn generated_column(field: &ScalarFieldWalker<'_>) -> Option<sql::GeneratedColumn> {
+    field.computed().map(|computed| sql::GeneratedColumn {
+        expression: computed.expression.clone(),
+        stored: computed.stored,
+    })
+}
+
 fn column_type(field: &ScalarFieldWalker<'_>) -> sql::ColumnType {
     column_type_for_scalar_type(&scalar_type_for_field(field), column_arity(field.arity()))
 }
 
+/// Composite types are stored as JSON, lists of them as a single nullable JSON array.
+fn composite_column_type(field: &ScalarFieldWalker<'_>) -> sql::ColumnType {
+    let arity = match field.arity() {
+        FieldArity::Required => sql::ColumnArity::Required,
+        FieldArity::Optional | FieldArity::List => sql::ColumnArity::Nullable,
+    };
+
+    column_type_for_scalar_type(&ScalarType::Json, arity)
+}
+
 fn scalar_type_for_field(field: &ScalarFieldWalker<'_>) -> ScalarType {
     match field.field_type() {
         TypeWalker::Base(ref scalar) => *scalar,
@@ -324,7 +439,7 @@ fn add_one_to_one_relation_unique_index(table: &mut sql::Table, column_names: &[
     if table
         .indices
         .iter()
-        .any(|index| index.columns == column_names && index.tpe.is_unique())
+        .any(|index| index.columns == column_names && index.tpe.is_unique() && index.predicate.is_none())
     {
         return;
     }
@@ -334,6 +449,9 @@ fn add_one_to_one_relation_unique_index(table: &mut sql::Table, column_names: &[
         name: format!("{}_{}_unique", table.name, columns_suffix),
         columns: column_names.to_owned(),
         tpe: sql::IndexType::Unique,
+        column_options: Vec::new(),
+        algorithm: None,
+        predicate: None,
     };
 
     table.indices.push(index);
@@ -26,6 +26,11 @@ pub(crate) trait SqlSchemaCalculatorFlavour {
         false
     }
 
+    /// Whether the database can store comments on tables and columns.
+    fn supports_comments(&self) -> bool {
+        true
+    }
+
     fn m2m_foreign_key_action(&self, _model_a: &ModelWalker<'_>, _model_b: &ModelWalker<'_>) -> sql::ForeignKeyAction {
         sql::ForeignKeyAction::Cascade
     }
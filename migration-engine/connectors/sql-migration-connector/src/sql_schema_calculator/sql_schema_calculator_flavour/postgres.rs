@@ -10,6 +10,10 @@ impl SqlSchemaCalculatorFlavour for PostgresFlavour {
             .enums()
             .map(|r#enum| sql::Enum {
                 name: r#enum.final_database_name().to_owned(),
+                schema: r#enum
+                    .schema
+                    .clone()
+                    .filter(|schema| schema.as_str() != self.schema_name()),
                 values: r#enum.database_values(),
             })
             .collect()
@@ -17,4 +17,8 @@ impl SqlSchemaCalculatorFlavour for SqliteFlavour {
     fn field_is_implicit_autoincrement_primary_key(&self, field: &ScalarFieldWalker<'_>) -> bool {
         field.is_id() && field.field_type().is_int()
     }
+
+    fn supports_comments(&self) -> bool {
+        false
+    }
 }
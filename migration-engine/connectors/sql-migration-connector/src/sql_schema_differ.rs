@@ -1,5 +1,6 @@
 mod column;
 mod enums;
+mod expression;
 mod index;
 mod sql_schema_differ_flavour;
 mod table;
@@ -10,8 +11,9 @@ pub(crate) use sql_schema_differ_flavour::SqlSchemaDifferFlavour;
 use crate::{
     pair::Pair,
     sql_migration::{
-        self, AddColumn, AddForeignKey, AlterColumn, AlterEnum, AlterTable, CreateEnum, CreateIndex, CreateTable,
-        DropColumn, DropEnum, DropForeignKey, DropIndex, DropTable, RedefineTable, SqlMigrationStep, TableChange,
+        self, AddColumn, AddForeignKey, AlterColumn, AlterEnum, AlterTable, CreateEnum, CreateIndex, CreateSchema,
+        CreateTable, DropColumn, DropEnum, DropForeignKey, DropIndex, DropTable, RedefineTable, SqlMigrationStep,
+        TableChange,
     },
     SqlFlavour, SqlSchema, MIGRATION_TABLE_NAME,
 };
@@ -42,14 +44,16 @@ pub(crate) fn calculate_steps(schemas: Pair<&SqlSchema>, flavour: &dyn SqlFlavou
     let redefine_tables = differ.redefine_tables(&tables_to_redefine);
     let add_foreign_keys = differ.add_foreign_keys(&tables_to_redefine);
     let create_enums = differ.create_enums();
+    let create_schemas = differ.create_schemas();
 
     let redefine_tables = Some(redefine_tables)
         .filter(|tables| !tables.is_empty())
         .map(SqlMigrationStep::RedefineTables);
 
-    create_enums
+    create_schemas
         .into_iter()
-        .map(SqlMigrationStep::CreateEnum)
+        .map(SqlMigrationStep::CreateSchema)
+        .chain(create_enums.into_iter().map(SqlMigrationStep::CreateEnum))
         .chain(differ.alter_enums().into_iter().map(SqlMigrationStep::AlterEnum))
         .chain(drop_indexes.into_iter().map(SqlMigrationStep::DropIndex))
         .chain(drop_foreign_keys.into_iter().map(SqlMigrationStep::DropForeignKey))
@@ -158,12 +162,14 @@ impl<'schema> SqlSchemaDiffer<'schema> {
             .filter(move |tables| !tables_to_redefine.contains(tables.next().name()))
             .filter_map(|differ| {
                 // Order matters.
-                let changes: Vec<TableChange> = SqlSchemaDiffer::drop_primary_key(&differ)
-                    .into_iter()
+                let changes: Vec<TableChange> = SqlSchemaDiffer::drop_check_constraints(&differ)
+                    .chain(SqlSchemaDiffer::drop_primary_key(&differ))
                     .chain(SqlSchemaDiffer::drop_columns(&differ))
                     .chain(SqlSchemaDiffer::add_columns(&differ))
                     .chain(SqlSchemaDiffer::alter_columns(&differ))
                     .chain(SqlSchemaDiffer::add_primary_key(&differ))
+                    .chain(SqlSchemaDiffer::add_check_constraints(&differ))
+                    .chain(SqlSchemaDiffer::set_comment(&differ))
                     .collect();
 
                 Some(changes)
@@ -175,6 +181,22 @@ impl<'schema> SqlSchemaDiffer<'schema> {
             })
     }
 
+    fn drop_check_constraints<'a>(differ: &'a TableDiffer<'schema>) -> impl Iterator<Item = TableChange> + 'a {
+        differ
+            .dropped_check_constraints()
+            .map(|check_constraint_index| TableChange::DropCheckConstraint { check_constraint_index })
+    }
+
+    fn add_check_constraints<'a>(differ: &'a TableDiffer<'schema>) -> impl Iterator<Item = TableChange> + 'a {
+        differ
+            .created_check_constraints()
+            .map(|check_constraint_index| TableChange::AddCheckConstraint { check_constraint_index })
+    }
+
+    fn set_comment(differ: &TableDiffer<'schema>) -> Option<TableChange> {
+        Some(TableChange::SetComment).filter(|_| differ.comment_changed())
+    }
+
     fn drop_columns<'a>(differ: &'a TableDiffer<'schema>) -> impl Iterator<Item = TableChange> + 'a {
         differ.dropped_columns().map(|column| {
             let change = DropColumn {
@@ -205,6 +227,11 @@ impl<'schema> SqlSchemaDiffer<'schema> {
 
             let column_index = Pair::new(column_differ.previous.column_index(), column_differ.next.column_index());
 
+            // Columns can not be altered to start or stop being generated.
+            if changes.generation_changed() {
+                return Some(TableChange::DropAndRecreateColumn { column_index, changes });
+            }
+
             match type_change {
                 Some(ColumnTypeChange::NotCastable) => {
                     Some(TableChange::DropAndRecreateColumn { column_index, changes })
@@ -434,10 +461,7 @@ impl<'schema> SqlSchemaDiffer<'schema> {
                 self.schemas
                     .next()
                     .table_walkers()
-                    .find(move |next_table| {
-                        self.flavour
-                            .table_names_match(Pair::new(previous_table.name(), next_table.name()))
-                    })
+                    .find(move |next_table| self.tables_match(&previous_table, next_table))
                     .map(move |next_table| TableDiffer {
                         flavour: self.flavour,
                         tables: Pair::new(previous_table, next_table),
@@ -465,19 +489,17 @@ impl<'schema> SqlSchemaDiffer<'schema> {
 
     fn created_tables(&self) -> impl Iterator<Item = TableWalker<'_>> {
         self.next_tables().filter(move |next_table| {
-            !self.previous_tables().any(|previous_table| {
-                self.flavour
-                    .table_names_match(Pair::new(previous_table.name(), next_table.name()))
-            })
+            !self
+                .previous_tables()
+                .any(|previous_table| self.tables_match(&previous_table, next_table))
         })
     }
 
     fn dropped_tables<'a>(&'a self) -> impl Iterator<Item = TableWalker<'schema>> + 'a {
         self.previous_tables().filter(move |previous_table| {
-            !self.next_tables().any(|next_table| {
-                self.flavour
-                    .table_names_match(Pair::new(previous_table.name(), next_table.name()))
-            })
+            !self
+                .next_tables()
+                .any(|next_table| self.tables_match(previous_table, &next_table))
         })
     }
 
@@ -495,6 +517,36 @@ impl<'schema> SqlSchemaDiffer<'schema> {
             .filter(move |table| !self.table_is_ignored(&table.name()))
     }
 
+    /// Tables are the same if they have the same name in the same database schema.
+    fn tables_match(&self, previous: &TableWalker<'_>, next: &TableWalker<'_>) -> bool {
+        self.flavour.table_names_match(Pair::new(previous.name(), next.name()))
+            && previous.schema_name() == next.schema_name()
+    }
+
+    /// The database schemas of `@@schema` tables and enums that do not exist in the previous schema.
+    fn create_schemas(&self) -> Vec<CreateSchema> {
+        let previous_schemas: HashSet<&str> = self
+            .previous_tables()
+            .filter_map(|table| table.schema_name())
+            .chain(self.previous_enums().filter_map(|r#enum| r#enum.schema_name()))
+            .collect();
+
+        let mut created_schemas: Vec<&str> = self
+            .next_tables()
+            .filter_map(|table| table.schema_name())
+            .chain(self.next_enums().filter_map(|r#enum| r#enum.schema_name()))
+            .filter(|schema_name| !previous_schemas.contains(schema_name))
+            .collect();
+
+        created_schemas.sort_unstable();
+        created_schemas.dedup();
+
+        created_schemas
+            .into_iter()
+            .map(|name| CreateSchema { name: name.to_owned() })
+            .collect()
+    }
+
     fn table_is_ignored(&self, table_name: &str) -> bool {
         table_name == MIGRATION_TABLE_NAME
             || table_name == "_prisma_migrations"
@@ -557,7 +609,8 @@ fn push_foreign_keys_from_created_tables<'a>(
 /// Compare two [ForeignKey](/sql-schema-describer/struct.ForeignKey.html)s and return whether they
 /// should be considered equivalent for schema diffing purposes.
 fn foreign_keys_match(previous: &ForeignKeyWalker<'_>, next: &ForeignKeyWalker<'_>) -> bool {
-    let references_same_table = previous.referenced_table().name() == next.referenced_table().name();
+    let references_same_table = previous.referenced_table().name() == next.referenced_table().name()
+        && previous.referenced_schema_name() == next.referenced_schema_name();
     let references_same_column_count = previous.referenced_columns_count() == next.referenced_columns_count();
     let constrains_same_column_count = previous.constrained_columns().count() == next.constrained_columns().count();
     let constrains_same_columns =
@@ -575,13 +628,20 @@ fn foreign_keys_match(previous: &ForeignKeyWalker<'_>, next: &ForeignKeyWalker<'
         .zip(next.referenced_column_names())
         .all(|(previous, next)| previous == next);
 
+    // A foreign key that was renamed has to be dropped and recreated.
+    let same_constraint_name = match (previous.constraint_name(), next.constraint_name()) {
+        (Some(previous_name), Some(next_name)) => previous_name == next_name,
+        _ => true,
+    };
+
     references_same_table
         && references_same_column_count
         && constrains_same_column_count
         && constrains_same_columns
         && references_same_columns
+        && same_constraint_name
 }
 
 fn enums_match(previous: &EnumWalker<'_>, next: &EnumWalker<'_>) -> bool {
-    previous.name() == next.name()
+    previous.name() == next.name() && previous.schema_name() == next.schema_name()
 }
@@ -1,3 +1,4 @@
+use super::expression::expressions_match;
 use crate::flavour::SqlFlavour;
 use enumflags2::BitFlags;
 use prisma_value::PrismaValue;
@@ -35,6 +36,14 @@ impl<'a> ColumnDiffer<'a> {
             changes |= ColumnChange::Sequence;
         };
 
+        if self.generation_changed() {
+            changes |= ColumnChange::Generation;
+        };
+
+        if self.previous.comment() != self.next.comment() {
+            changes |= ColumnChange::Comment;
+        };
+
         (ColumnChanges { changes }, column_type_change)
     }
 
@@ -46,6 +55,19 @@ impl<'a> ColumnDiffer<'a> {
         self.previous.is_autoincrement() != self.next.is_autoincrement()
     }
 
+    /// The databases return generation expressions rewritten, e.g. `((price * quantity) * 2)` on
+    /// Postgres and `` `price` * `quantity` * 2 `` on MySQL, so they are compared normalized, see
+    /// `expressions_match`.
+    fn generation_changed(&self) -> bool {
+        match (self.previous.generated(), self.next.generated()) {
+            (Some(previous), Some(next)) => {
+                previous.stored != next.stored || !expressions_match(&previous.expression, &next.expression)
+            }
+            (None, None) => false,
+            (_, _) => true,
+        }
+    }
+
     fn column_type_change(&self) -> Option<ColumnTypeChange> {
         match (self.previous.column_type_family(), self.next.column_type_family()) {
             (_, _) if self.arity_changed() => self.flavour.column_type_change(self),
@@ -98,11 +120,20 @@ impl<'a> ColumnDiffer<'a> {
             (Some(DefaultKind::NOW), None) => false,
             (Some(DefaultKind::NOW), Some(DefaultKind::VALUE(_))) => false,
 
+            // An empty `dbgenerated()` leaves the default to the database.
+            (_, Some(DefaultKind::DBGENERATED(next))) if next.is_empty() => true,
+            (Some(DefaultKind::DBGENERATED(prev)), Some(DefaultKind::DBGENERATED(next))) => {
+                dbgenerated_defaults_match(prev, next)
+            }
+            (_, Some(DefaultKind::DBGENERATED(_))) => false,
+
             (Some(DefaultKind::DBGENERATED(_)), Some(DefaultKind::VALUE(_))) => false,
             (Some(DefaultKind::DBGENERATED(_)), Some(DefaultKind::NOW)) => false,
             (Some(DefaultKind::DBGENERATED(_)), None) => false,
 
             (Some(DefaultKind::SEQUENCE(_)), None) => true, // sequences are dropped separately
+            // `autoincrement()` matches any sequence, `sequence("name")` only the named one.
+            (Some(DefaultKind::SEQUENCE(prev)), Some(DefaultKind::SEQUENCE(next))) => next.is_empty() || prev == next,
             (Some(DefaultKind::SEQUENCE(_)), Some(DefaultKind::VALUE(_))) => false,
             (Some(DefaultKind::SEQUENCE(_)), Some(DefaultKind::NOW)) => false,
 
@@ -110,14 +141,112 @@ impl<'a> ColumnDiffer<'a> {
             (None, Some(DefaultKind::VALUE(_))) => false,
             (None, Some(DefaultKind::NOW)) => false,
 
-            // We can never migrate to @dbgenerated
-            (_, Some(DefaultKind::DBGENERATED(_))) => true,
             // Sequence migrations are handled separately.
             (_, Some(DefaultKind::SEQUENCE(_))) => true,
         }
     }
 }
 
+/// Postgres adds casts to the literals of default expressions, e.g. `'x'` comes back as `'x'::text`.
+fn dbgenerated_defaults_match(previous: &str, next: &str) -> bool {
+    unparenthesize(&strip_casts(previous)) == unparenthesize(&strip_casts(next))
+}
+
+/// The databases may add or remove parentheses around default expressions, e.g. MySQL requires
+/// them but does not return them.
+fn unparenthesize(expression: &str) -> &str {
+    let mut expression = expression.trim();
+
+    while expression.starts_with('(') && closing_parenthesis(expression) == Some(expression.len() - 1) {
+        expression = expression[1..expression.len() - 1].trim();
+    }
+
+    expression
+}
+
+/// Removes the charset introducers MySQL adds to string literals, e.g. `_utf8mb4'#'`.
+pub(super) fn strip_charset_introducers(expression: &str) -> String {
+    let mut stripped = String::with_capacity(expression.len());
+    let mut rest = expression;
+
+    while let Some(idx) = rest.find('_') {
+        let previous_char = rest[..idx].chars().last().or_else(|| stripped.chars().last());
+        let starts_word = !matches!(previous_char, Some(c) if c.is_ascii_alphanumeric() || c == '_');
+        let charset_len = rest[idx + 1..]
+            .find(|c: char| !c.is_ascii_alphanumeric())
+            .unwrap_or(rest.len() - idx - 1);
+        let is_introducer = starts_word && charset_len > 0 && rest[idx + 1 + charset_len..].starts_with('\'');
+
+        stripped.push_str(&rest[..idx]);
+
+        if is_introducer {
+            rest = &rest[idx + 1 + charset_len..];
+        } else {
+            stripped.push('_');
+            rest = &rest[idx + 1..];
+        }
+    }
+
+    stripped.push_str(rest);
+    stripped
+}
+
+/// The type names Postgres renders with more than one word.
+const MULTI_WORD_TYPE_SUFFIXES: &[&str] = &[" varying", " precision", " without time zone", " with time zone"];
+
+/// Removes the `::type` casts Postgres adds to literals and expressions, e.g. `'x'::text` or
+/// `'a'::character varying(10)`.
+pub(super) fn strip_casts(expression: &str) -> String {
+    let mut stripped = String::with_capacity(expression.len());
+    let mut rest = expression;
+
+    while let Some(idx) = rest.find("::") {
+        stripped.push_str(&rest[..idx]);
+        rest = &rest[idx + 2..];
+
+        let name_len = rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(rest.len());
+        rest = &rest[name_len..];
+
+        if let Some(suffix) = MULTI_WORD_TYPE_SUFFIXES.iter().find(|suffix| rest.starts_with(*suffix)) {
+            rest = &rest[suffix.len()..];
+        }
+
+        // Type modifiers, e.g. `(10)` or `(65,30)`.
+        if rest.starts_with('(') {
+            if let Some(end) = rest.find(')') {
+                if rest[1..end].chars().all(|c| c.is_ascii_digit() || c == ',') {
+                    rest = &rest[end + 1..];
+                }
+            }
+        }
+
+        while rest.starts_with("[]") {
+            rest = &rest[2..];
+        }
+    }
+
+    stripped.push_str(rest);
+    stripped
+}
+
+/// The position of the parenthesis closing the one the expression starts with.
+fn closing_parenthesis(expression: &str) -> Option<usize> {
+    let mut depth = 0;
+
+    for (idx, c) in expression.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 1 => return Some(idx),
+            ')' => depth -= 1,
+            _ => (),
+        }
+    }
+
+    None
+}
+
 fn json_defaults_match(previous: &str, next: &str) -> bool {
     serde_json::from_str::<serde_json::Value>(previous)
         .and_then(|previous| serde_json::from_str::<serde_json::Value>(next).map(|next| (previous, next)))
@@ -133,6 +262,8 @@ pub(crate) enum ColumnChange {
     Default = 0b0100,
     TypeChanged = 0b1000,
     Sequence = 0b0010000,
+    Generation = 0b0100000,
+    Comment = 0b1000000,
 }
 
 // This should be pub(crate), but SqlMigration is exported, so it has to be
@@ -164,6 +295,18 @@ impl ColumnChanges {
         self.changes.contains(ColumnChange::Default)
     }
 
+    pub(crate) fn generation_changed(&self) -> bool {
+        self.changes.contains(ColumnChange::Generation)
+    }
+
+    pub(crate) fn comment_changed(&self) -> bool {
+        self.changes.contains(ColumnChange::Comment)
+    }
+
+    pub(crate) fn only_comment_changed(&self) -> bool {
+        self.changes == BitFlags::from(ColumnChange::Comment)
+    }
+
     pub(crate) fn only_default_changed(&self) -> bool {
         self.changes == BitFlags::from(ColumnChange::Default)
     }
@@ -183,3 +326,63 @@ pub enum ColumnTypeChange {
     RiskyCast,
     NotCastable,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generation_expressions_are_compared_as_normalized_by_the_databases() {
+        let same = &[
+            ("price * quantity", "(price * quantity)"),
+            ("price * quantity * 2", "((price * quantity) * 2)"),
+            ("(price + quantity) * 2", "((price + quantity) * 2)"),
+            ("price * quantity", "`price` * `quantity`"),
+            ("\"unitPrice\" * quantity", "(\"unitPrice\" * quantity)"),
+            ("CONCAT(first, ' ', last)", "concat(first, ' '::text, last)"),
+            ("code || 'x'", "((code)::character varying(10) || 'x'::text)"),
+            ("CONCAT('#', id)", "concat(_utf8mb4\\'#\\',`id`)"),
+            ("first_name", "`first_name`"),
+        ];
+
+        for (written, described) in same {
+            assert!(expressions_match(written, described), "{} vs {}", written, described);
+        }
+
+        let different = &[
+            ("price * quantity", "(price + quantity)"),
+            ("(price + quantity) * 2", "price + quantity * 2"),
+            ("price - (quantity - 2)", "((price - quantity) - 2)"),
+            ("CONCAT(first, ' A')", "concat(first, ' a'::text)"),
+            ("code || 'X'", "((code)::text || 'x'::text)"),
+        ];
+
+        for (written, described) in different {
+            assert!(!expressions_match(written, described), "{} vs {}", written, described);
+        }
+    }
+
+    #[test]
+    fn dbgenerated_defaults_are_compared_without_casts_and_outer_parentheses() {
+        let same = &[
+            ("'x'", "'x'::text"),
+            ("'draft'", "'draft'::character varying"),
+            ("'a'", "('a'::character varying(10))"),
+            ("'{}'", "'{}'::text[]"),
+            ("nextval('cat_ids')", "nextval('cat_ids'::regclass)"),
+            ("random()", "(random())"),
+        ];
+
+        for (written, described) in same {
+            assert!(
+                dbgenerated_defaults_match(written, described),
+                "{} vs {}",
+                written,
+                described
+            );
+        }
+
+        assert!(!dbgenerated_defaults_match("'x'", "'y'::text"));
+        assert!(!dbgenerated_defaults_match("random()", "now()"));
+    }
+}
@@ -0,0 +1,381 @@
+//! The comparison of the SQL expressions the databases return rewritten: the expressions of check
+//! constraints and generated columns, and the predicates of partial indexes. They are compared in a
+//! normalized form that undoes the known rewrites:
+//!
+//! - Quoted identifiers and keywords are lowercased and unquoted, e.g. `` `price` `` or `[price]`.
+//! - Casts and charset introducers are removed, e.g. `'a'::text` or `_utf8mb4'a'`.
+//! - Parentheses are removed where the precedence of the operators makes them redundant, e.g.
+//!   `((price > 0) AND (price < 100))` on Postgres or `([price]>(0))` on SQL Server.
+//! - `IN` lists are sorted, and the forms Postgres and SQL Server rewrite them to are turned back
+//!   into `IN`, e.g. `status = ANY (ARRAY['a', 'b'])` or `[status]='b' OR [status]='a'`.
+//!
+//! String literals are compared as they are.
+use super::column::{strip_casts, strip_charset_introducers};
+
+/// Whether the two expressions are the same, once normalized.
+pub(super) fn expressions_match(previous: &str, next: &str) -> bool {
+    normalize_expression(previous) == normalize_expression(next)
+}
+
+fn normalize_expression(expression: &str) -> String {
+    // MySQL escapes the quotes of string literals.
+    let expression = strip_charset_introducers(&strip_casts(expression).replace("\\'", "'"));
+    let mut tokens = tokenize(&expression).into_iter();
+
+    render(&canonicalize(parse(&mut tokens)))
+}
+
+/// A token, or the tokens within a pair of parentheses.
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    Token(String),
+    Group(Vec<Node>),
+}
+
+impl Node {
+    fn token(&self) -> Option<&str> {
+        match self {
+            Node::Token(token) => Some(token),
+            Node::Group(_) => None,
+        }
+    }
+}
+
+/// The two character operators, the others are a single character.
+const TWO_CHARACTER_OPERATORS: &[&str] = &["<=", ">=", "<>", "!=", "||", "~~"];
+
+fn tokenize(expression: &str) -> Vec<String> {
+    let mut tokens: Vec<String> = Vec::new();
+    let mut chars = expression.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => (),
+            '\'' => {
+                let mut literal = String::from(c);
+
+                while let Some(c) = chars.next() {
+                    literal.push(c);
+
+                    if c == '\'' {
+                        // A doubled quote is an escaped quote within the literal.
+                        match chars.peek() {
+                            Some('\'') => literal.push(chars.next().unwrap()),
+                            _ => break,
+                        }
+                    }
+                }
+
+                tokens.push(literal);
+            }
+            '"' | '`' | '[' if !(c == '[' && tokens.last().map(String::as_str) == Some("array")) => {
+                let closing = if c == '[' { ']' } else { c };
+                let identifier: String = chars.by_ref().take_while(|c| *c != closing).collect();
+
+                tokens.push(identifier.to_lowercase());
+            }
+            c if c.is_alphanumeric() || c == '_' || c == '.' => {
+                let mut word = c.to_lowercase().collect::<String>();
+
+                while let Some(c) = chars
+                    .peek()
+                    .copied()
+                    .filter(|c| c.is_alphanumeric() || *c == '_' || *c == '.')
+                {
+                    word.extend(c.to_lowercase());
+                    chars.next();
+                }
+
+                tokens.push(word);
+            }
+            c => {
+                let mut operator = String::from(c);
+
+                if let Some(next) = chars.peek() {
+                    let two_characters = format!("{}{}", c, next);
+
+                    if TWO_CHARACTER_OPERATORS.contains(&two_characters.as_str()) {
+                        operator = two_characters;
+                        chars.next();
+                    }
+                }
+
+                // Postgres returns `!=` as `<>` and `LIKE` as `~~`.
+                let operator = match operator.as_str() {
+                    "!=" => String::from("<>"),
+                    "~~" => String::from("like"),
+                    _ => operator,
+                };
+
+                tokens.push(operator);
+            }
+        }
+    }
+
+    tokens
+}
+
+fn parse(tokens: &mut impl Iterator<Item = String>) -> Vec<Node> {
+    let mut nodes = Vec::new();
+
+    while let Some(token) = tokens.next() {
+        match token.as_str() {
+            "(" => nodes.push(Node::Group(parse(tokens))),
+            ")" => break,
+            _ => nodes.push(Node::Token(token)),
+        }
+    }
+
+    nodes
+}
+
+/// The precedence of the operator, from the loosest to the tightest binding.
+fn precedence(token: &str) -> Option<u8> {
+    match token {
+        "or" => Some(1),
+        "and" => Some(2),
+        "not" => Some(3),
+        "=" | "<>" | "<" | ">" | "<=" | ">=" | "in" | "like" | "is" | "between" => Some(4),
+        "+" | "-" | "||" => Some(5),
+        "*" | "/" | "%" => Some(6),
+        _ => None,
+    }
+}
+
+/// The precedence of a node without operators, e.g. a literal or a function call.
+const ATOM_PRECEDENCE: u8 = u8::MAX;
+
+/// The loosest binding operator on the top level of the nodes.
+fn loosest_operator(nodes: &[Node]) -> Option<(&str, u8)> {
+    nodes
+        .iter()
+        .filter_map(|node| node.token().and_then(|token| precedence(token).map(|p| (token, p))))
+        .min_by_key(|(_, precedence)| *precedence)
+}
+
+fn is_literal(node: &Node) -> bool {
+    node.token()
+        .map(|token| token.starts_with('\'') || token.starts_with(|c: char| c.is_ascii_digit()))
+        .unwrap_or(false)
+}
+
+fn is_identifier(node: &Node) -> bool {
+    node.token()
+        .map(|token| precedence(token).is_none() && token.starts_with(|c: char| c.is_alphabetic() || c == '_'))
+        .unwrap_or(false)
+}
+
+fn canonicalize(nodes: Vec<Node>) -> Vec<Node> {
+    let mut nodes: Vec<Node> = nodes
+        .into_iter()
+        .map(|node| match node {
+            Node::Group(nodes) => Node::Group(canonicalize(nodes)),
+            token => token,
+        })
+        .collect();
+
+    rewrite_any_arrays(&mut nodes);
+    remove_redundant_parentheses(&mut nodes);
+    rewrite_equality_chain(&mut nodes);
+    sort_in_lists(&mut nodes);
+
+    nodes
+}
+
+/// `x = ANY (ARRAY[a, b])` becomes `x IN (a, b)`.
+fn rewrite_any_arrays(nodes: &mut Vec<Node>) {
+    let mut idx = 0;
+
+    while idx + 2 < nodes.len() {
+        let items = match (&nodes[idx], &nodes[idx + 1], &nodes[idx + 2]) {
+            (Node::Token(eq), Node::Token(any), Node::Group(array)) if eq == "=" && any == "any" => {
+                match array.as_slice() {
+                    [Node::Token(keyword), Node::Token(open), items @ .., Node::Token(close)]
+                        if keyword == "array" && open == "[" && close == "]" =>
+                    {
+                        Some(items.to_vec())
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+
+        if let Some(items) = items {
+            nodes.splice(idx..idx + 3, vec![Node::Token(String::from("in")), Node::Group(items)]);
+        }
+
+        idx += 1;
+    }
+}
+
+/// Removes the parentheses that don't change the meaning of the expression.
+fn remove_redundant_parentheses(nodes: &mut Vec<Node>) {
+    let mut idx = 0;
+
+    while idx < nodes.len() {
+        let group = match &nodes[idx] {
+            Node::Group(group) => group,
+            Node::Token(_) => {
+                idx += 1;
+                continue;
+            }
+        };
+
+        let left = idx.checked_sub(1).map(|idx| &nodes[idx]);
+        let right = nodes.get(idx + 1);
+
+        // The arguments of function calls and `IN` lists.
+        if matches!(left, Some(node) if is_identifier(node) || node.token() == Some("in")) {
+            idx += 1;
+            continue;
+        }
+
+        let left_operator = left
+            .and_then(Node::token)
+            .and_then(|token| precedence(token).map(|p| (token, p)));
+        let left_precedence = left_operator.map(|(_, p)| p).unwrap_or(0);
+        let right_precedence = right.and_then(Node::token).and_then(precedence).unwrap_or(0);
+        let (inner_operator, inner_precedence) = loosest_operator(group).unwrap_or(("", ATOM_PRECEDENCE));
+
+        // `a AND (b AND c)` is the same as `a AND b AND c`, `a - (b - c)` is not.
+        let associative = left_operator
+            .map(|(operator, _)| operator == inner_operator && matches!(operator, "and" | "or"))
+            .unwrap_or(false);
+        let redundant = (inner_precedence > left_precedence || associative) && inner_precedence >= right_precedence;
+
+        if redundant {
+            let group = group.clone();
+            let len = group.len();
+
+            nodes.splice(idx..idx + 1, group);
+            idx += len;
+        } else {
+            idx += 1;
+        }
+    }
+}
+
+/// `x = a OR x = b` becomes `x IN (a, b)`, as SQL Server returns `IN` conditions.
+fn rewrite_equality_chain(nodes: &mut Vec<Node>) {
+    if nodes.len() < 7 || nodes.len() % 4 != 3 {
+        return;
+    }
+
+    let column = &nodes[0];
+    let is_chain = is_identifier(column)
+        && nodes.chunks(4).all(|chunk| match chunk {
+            [identifier, eq, literal, rest @ ..] => {
+                identifier == column
+                    && eq.token() == Some("=")
+                    && is_literal(literal)
+                    && rest.iter().all(|node| node.token() == Some("or"))
+            }
+            _ => false,
+        });
+
+    if !is_chain {
+        return;
+    }
+
+    let mut items = Vec::new();
+
+    for chunk in nodes.chunks(4) {
+        if !items.is_empty() {
+            items.push(Node::Token(String::from(",")));
+        }
+
+        items.push(chunk[2].clone());
+    }
+
+    *nodes = vec![column.clone(), Node::Token(String::from("in")), Node::Group(items)];
+}
+
+/// The order of the values of `IN` lists doesn't matter.
+fn sort_in_lists(nodes: &mut [Node]) {
+    for idx in 1..nodes.len() {
+        if nodes[idx - 1].token() != Some("in") {
+            continue;
+        }
+
+        if let Node::Group(items) = &mut nodes[idx] {
+            let mut values: Vec<Vec<Node>> = items
+                .split(|node| node.token() == Some(","))
+                .map(|value| value.to_vec())
+                .collect();
+
+            values.sort_by_key(|value| render(value));
+
+            *items = values.join(&Node::Token(String::from(",")));
+        }
+    }
+}
+
+fn render(nodes: &[Node]) -> String {
+    let rendered: Vec<String> = nodes
+        .iter()
+        .map(|node| match node {
+            Node::Token(token) => token.clone(),
+            Node::Group(nodes) => format!("({})", render(nodes)),
+        })
+        .collect();
+
+    rendered.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conditions_are_compared_as_normalized_by_the_databases() {
+        let same = &[
+            ("price > 0", "(price > 0)"),
+            ("price > 0", "([price]>(0))"),
+            ("price > 0", "(`price` > 0)"),
+            ("price > 0 AND price < 100", "((price > 0) AND (price < 100))"),
+            ("price > 0 AND price < 100", "([price]>(0) AND [price]<(100))"),
+            ("a > 0 AND b > 0 AND c > 0", "(((a > 0) AND (b > 0)) AND (c > 0))"),
+            ("a > 0 OR b > 0 AND c > 0", "((a > 0) OR ((b > 0) AND (c > 0)))"),
+            ("price * quantity > 0", "((price * quantity) > 0)"),
+            ("name != ''", "((name)::text <> ''::text)"),
+            ("name LIKE 'a%'", "((name)::text ~~ 'a%'::text)"),
+            ("lower(name) <> 'x'", "(lower((name)::text) <> 'x'::text)"),
+            (
+                "status IN ('draft', 'published')",
+                "(status = ANY (ARRAY['draft'::text, 'published'::text]))",
+            ),
+            (
+                "status IN ('draft', 'published')",
+                "([status]='published' OR [status]='draft')",
+            ),
+            (
+                "status IN ('draft', 'published')",
+                "(`status` in (_utf8mb4\\'draft\\',_utf8mb4\\'published\\'))",
+            ),
+        ];
+
+        for (previous, next) in same {
+            assert!(
+                expressions_match(previous, next),
+                "{} ({}) vs {} ({})",
+                previous,
+                normalize_expression(previous),
+                next,
+                normalize_expression(next)
+            );
+        }
+
+        let different = &[
+            ("price > 0", "(price >= 100)"),
+            ("(a > 0 OR b > 0) AND c > 0", "a > 0 OR b > 0 AND c > 0"),
+            ("a - (b - c) > 0", "a - b - c > 0"),
+            ("status = 'Draft'", "status = 'draft'"),
+            ("status IN ('draft', 'published')", "status IN ('draft')"),
+        ];
+
+        for (previous, next) in different {
+            assert!(!expressions_match(previous, next), "{} vs {}", previous, next);
+        }
+    }
+}
@@ -8,7 +8,8 @@ use std::collections::HashSet;
 
 impl SqlSchemaDifferFlavour for MssqlFlavour {
     fn should_skip_index_for_new_table(&self, index: &IndexWalker<'_>) -> bool {
-        index.index_type().is_unique()
+        // Unique constraints are part of the CREATE TABLE statement, filtered unique indexes are not.
+        index.index_type().is_unique() && index.predicate().is_none()
     }
 
     fn should_recreate_the_primary_key_on_column_recreate(&self) -> bool {
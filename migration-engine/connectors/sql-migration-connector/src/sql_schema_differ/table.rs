@@ -1,8 +1,8 @@
-use super::column::ColumnDiffer;
+use super::{column::ColumnDiffer, expression::expressions_match};
 use crate::{flavour::SqlFlavour, pair::Pair};
 use sql_schema_describer::{
     walkers::{ColumnWalker, ForeignKeyWalker, IndexWalker, TableWalker},
-    PrimaryKey,
+    CheckConstraint, PrimaryKey,
 };
 
 pub(crate) struct TableDiffer<'a> {
@@ -81,11 +81,44 @@ impl<'schema> TableDiffer<'schema> {
         })
     }
 
+    /// The indexes of the check constraints in `next` that are not in `previous`.
+    pub(crate) fn created_check_constraints<'a>(&'a self) -> impl Iterator<Item = usize> + 'a {
+        let previous_check_constraints = self.previous().check_constraints();
+
+        self.next()
+            .check_constraints()
+            .iter()
+            .enumerate()
+            .filter(move |(_, next)| {
+                !previous_check_constraints
+                    .iter()
+                    .any(|previous| check_constraints_match(previous, next))
+            })
+            .map(|(idx, _)| idx)
+    }
+
+    /// The indexes of the check constraints in `previous` that are not in `next`.
+    pub(crate) fn dropped_check_constraints<'a>(&'a self) -> impl Iterator<Item = usize> + 'a {
+        let next_check_constraints = self.next().check_constraints();
+
+        self.previous()
+            .check_constraints()
+            .iter()
+            .enumerate()
+            .filter(move |(_, previous)| {
+                !next_check_constraints
+                    .iter()
+                    .any(|next| check_constraints_match(previous, next))
+            })
+            .map(|(idx, _)| idx)
+    }
+
     /// The primary key present in `next` but not `previous`, if applicable.
     pub(crate) fn created_primary_key(&self) -> Option<&'schema PrimaryKey> {
         match self.tables.as_ref().map(|t| t.primary_key()).as_tuple() {
             (None, Some(pk)) => Some(pk),
             (Some(previous_pk), Some(next_pk)) if previous_pk.columns != next_pk.columns => Some(next_pk),
+            (Some(previous_pk), Some(next_pk)) if !primary_key_names_match(previous_pk, next_pk) => Some(next_pk),
             (Some(previous_pk), Some(next_pk)) => {
                 if self.primary_key_column_changed(previous_pk) {
                     Some(next_pk)
@@ -102,6 +135,7 @@ impl<'schema> TableDiffer<'schema> {
         match self.tables.as_ref().map(|t| t.primary_key()).as_tuple() {
             (Some(pk), None) => Some(pk),
             (Some(previous_pk), Some(next_pk)) if previous_pk.columns != next_pk.columns => Some(previous_pk),
+            (Some(previous_pk), Some(next_pk)) if !primary_key_names_match(previous_pk, next_pk) => Some(previous_pk),
             (Some(previous_pk), Some(_next_pk)) => {
                 if self.primary_key_column_changed(previous_pk) {
                     Some(previous_pk)
@@ -114,6 +148,10 @@ impl<'schema> TableDiffer<'schema> {
     }
 
     /// Returns true if any of the columns of the primary key changed type.
+    pub(crate) fn comment_changed(&self) -> bool {
+        self.previous().comment() != self.next().comment()
+    }
+
     fn primary_key_column_changed(&self, previous_pk: &PrimaryKey) -> bool {
         self.column_pairs()
             .filter(|columns| {
@@ -163,6 +201,44 @@ pub(crate) fn columns_match(a: &ColumnWalker<'_>, b: &ColumnWalker<'_>) -> bool
 }
 
 /// Compare two SQL indexes and return whether they only differ by name.
+///
+/// The predicates of partial indexes are rewritten by the databases, so they are compared
+/// normalized, see `expressions_match`.
 fn indexes_match(first: &IndexWalker<'_>, second: &IndexWalker<'_>) -> bool {
-    first.column_names() == second.column_names() && first.index_type() == second.index_type()
+    let predicates_match = match (first.predicate(), second.predicate()) {
+        (None, None) => true,
+        (Some(first_predicate), Some(second_predicate)) => expressions_match(first_predicate, second_predicate),
+        _ => false,
+    };
+
+    first.column_names() == second.column_names()
+        && first.index_type() == second.index_type()
+        && (0..first.column_names().len()).all(|idx| first.column_options(idx) == second.column_options(idx))
+        && first.algorithm() == second.algorithm()
+        && predicates_match
+}
+
+/// A primary key that was renamed has to be dropped and recreated. Primary keys without a name,
+/// e.g. on MySQL and SQLite, never differ by name.
+fn primary_key_names_match(previous: &PrimaryKey, next: &PrimaryKey) -> bool {
+    match (&previous.constraint_name, &next.constraint_name) {
+        (Some(previous_name), Some(next_name)) => previous_name == next_name,
+        _ => true,
+    }
+}
+
+/// Check constraints match when they have the same name and the same expression. The databases
+/// rewrite the expressions we create them with, so they are compared normalized, see
+/// `expressions_match`. Unnamed constraints, which only exist in SQLite tables created outside
+/// of the migration engine, are compared by expression only.
+///
+/// The default names are positional (see the schema calculator), so removing an unnamed constraint
+/// recreates the unnamed constraints after it under their new names.
+fn check_constraints_match(previous: &CheckConstraint, next: &CheckConstraint) -> bool {
+    let names_match = match (&previous.name, &next.name) {
+        (Some(previous_name), Some(next_name)) => previous_name == next_name,
+        _ => true,
+    };
+
+    names_match && expressions_match(&previous.expression, &next.expression)
 }
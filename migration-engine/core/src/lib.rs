@@ -25,7 +25,7 @@ use datamodel::{
     dml::Datamodel,
     Configuration,
 };
-use migration_connector::ConnectorError;
+use migration_connector::{ConnectorError, MigrationConnector};
 use migration_engine::MigrationEngine;
 use sql_migration_connector::SqlMigrationConnector;
 use std::sync::Arc;
@@ -80,11 +80,11 @@ pub async fn migration_api(
                 u.query_pairs_mut().append_pair("statement_cache_size", "0");
             }
 
-            SqlMigrationConnector::new(u.as_str()).await?
+            SqlMigrationConnector::new_with_schemas(u.as_str(), source.schemas.clone()).await?
         }
         #[cfg(feature = "sql")]
         provider if [MYSQL_SOURCE_NAME, SQLITE_SOURCE_NAME, MSSQL_SOURCE_NAME].contains(&provider.as_str()) => {
-            SqlMigrationConnector::new(&source.url().value).await?
+            SqlMigrationConnector::new_with_schemas(&source.url().value, source.schemas.clone()).await?
         }
         x => unimplemented!("Connector {} is not supported yet", x),
     };
@@ -165,7 +165,15 @@ pub async fn qe_setup(prisma_schema: &str) -> CoreResult<()> {
         {
             // 1. creates schema & database
             SqlMigrationConnector::qe_setup(&source.url().value).await?;
-            SqlMigrationConnector::new(&source.url().value).await?
+            let connector =
+                SqlMigrationConnector::new_with_schemas(&source.url().value, source.schemas.clone()).await?;
+
+            // The additional schemas of `@@schema` models are not recreated by the setup above.
+            if !source.schemas.is_empty() {
+                connector.reset().await?;
+            }
+
+            connector
         }
         x => unimplemented!("Connector {} is not supported yet", x),
     };
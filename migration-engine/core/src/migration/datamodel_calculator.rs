@@ -209,6 +209,7 @@ fn apply_create_model(datamodel: &mut ast::SchemaAst, step: &steps::CreateModel)
         fields: vec![],
         attributes: vec![],
         commented_out: false,
+        is_view: false,
     };
 
     datamodel.tops.push(ast::Top::Model(model));
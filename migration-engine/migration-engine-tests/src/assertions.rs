@@ -220,6 +220,18 @@ impl<'a> TableAssertion<'a> {
         Ok(self)
     }
 
+    pub fn assert_comment(self, expected: Option<&str>) -> AssertionResult<Self> {
+        anyhow::ensure!(
+            self.0.comment.as_deref() == expected,
+            "Assertion failed: expected the comment on {} to be {:?}, found {:?}",
+            self.0.name,
+            expected,
+            self.0.comment,
+        );
+
+        Ok(self)
+    }
+
     pub fn assert_has_no_pk(self) -> AssertionResult<Self> {
         anyhow::ensure!(
             self.0.primary_key.is_none(),
@@ -298,6 +310,18 @@ impl<'a> ColumnAssertion<'a> {
         Ok(self)
     }
 
+    pub fn assert_comment(self, expected: Option<&str>) -> AssertionResult<Self> {
+        anyhow::ensure!(
+            self.0.comment.as_deref() == expected,
+            "Assertion failed: expected the comment on the `{}` column to be {:?}, found {:?}",
+            self.0.name,
+            expected,
+            self.0.comment,
+        );
+
+        Ok(self)
+    }
+
     pub fn assert_data_type(self, data_type: &str) -> AssertionResult<Self> {
         let found = &self.0.tpe.data_type;
 
@@ -61,6 +61,7 @@ pub struct TestApi {
     database: Quaint,
     api: MigrationApi<SqlMigrationConnector, SqlMigration>,
     tags: BitFlags<Tags>,
+    connection_string: String,
 }
 
 impl TestApi {
@@ -72,6 +73,20 @@ impl TestApi {
         &self.database
     }
 
+    /// A test API on the same database, for datamodels spread over the given database schemas, as
+    /// listed in the `schemas` property of the datasource.
+    pub async fn with_schemas(&self, schemas: &[&str]) -> anyhow::Result<TestApi> {
+        let schemas = schemas.iter().map(|schema| schema.to_string()).collect();
+        let connector = SqlMigrationConnector::new_with_schemas(&self.connection_string, schemas).await?;
+
+        Ok(TestApi {
+            database: connector.quaint().clone(),
+            api: test_api(connector).await,
+            tags: self.tags,
+            connection_string: self.connection_string.clone(),
+        })
+    }
+
     pub fn is_sqlite(&self) -> bool {
         self.tags.contains(Tags::Sqlite)
     }
@@ -117,6 +132,15 @@ impl TestApi {
         }
     }
 
+    /// The datasource of `datasource()`, with the given `schemas` property.
+    pub fn datasource_with_schemas(&self, schemas: &[&str]) -> String {
+        let datasource = self.datasource();
+        let (head, tail) = datasource.split_at(datasource.rfind('}').unwrap());
+        let schemas: Vec<String> = schemas.iter().map(|schema| format!("{:?}", schema)).collect();
+
+        format!("{}    schemas = [{}]\n        {}", head, schemas.join(", "), tail)
+    }
+
     /// Render a table name with the required prefixing for use with quaint query building.
     pub fn render_table_name<'a>(&'a self, table_name: &'a str) -> quaint::ast::Table<'a> {
         if self.is_sqlite() {
@@ -375,6 +399,7 @@ pub async fn mysql_8_test_api(args: TestAPIArgs) -> TestApi {
         database: connector.quaint().clone(),
         api: test_api(connector).await,
         tags: args.test_tag,
+        connection_string: url,
     }
 }
 
@@ -387,6 +412,7 @@ pub async fn mysql_5_6_test_api(args: TestAPIArgs) -> TestApi {
         database: connector.quaint().clone(),
         api: test_api(connector).await,
         tags: args.test_tag,
+        connection_string: url,
     }
 }
 
@@ -399,6 +425,7 @@ pub async fn mysql_test_api(args: TestAPIArgs) -> TestApi {
         database: connector.quaint().clone(),
         api: test_api(connector).await,
         tags: args.test_tag,
+        connection_string: url,
     }
 }
 
@@ -411,6 +438,7 @@ pub async fn mysql_mariadb_test_api(args: TestAPIArgs) -> TestApi {
         database: connector.quaint().clone(),
         api: test_api(connector).await,
         tags: args.test_tag,
+        connection_string: url,
     }
 }
 
@@ -423,6 +451,7 @@ pub async fn postgres9_test_api(args: TestAPIArgs) -> TestApi {
         database: connector.quaint().clone(),
         api: test_api(connector).await,
         tags: args.test_tag,
+        connection_string: url,
     }
 }
 
@@ -435,6 +464,7 @@ pub async fn postgres_test_api(args: TestAPIArgs) -> TestApi {
         database: connector.quaint().clone(),
         api: test_api(connector).await,
         tags: args.test_tag,
+        connection_string: url,
     }
 }
 
@@ -447,6 +477,7 @@ pub async fn postgres11_test_api(args: TestAPIArgs) -> TestApi {
         database: connector.quaint().clone(),
         api: test_api(connector).await,
         tags: args.test_tag,
+        connection_string: url,
     }
 }
 
@@ -458,6 +489,7 @@ pub async fn postgres12_test_api(args: TestAPIArgs) -> TestApi {
         database: connector.quaint().clone(),
         api: test_api(connector).await,
         tags: args.test_tag,
+        connection_string: url,
     }
 }
 
@@ -469,6 +501,7 @@ pub async fn postgres13_test_api(args: TestAPIArgs) -> TestApi {
         database: connector.quaint().clone(),
         api: test_api(connector).await,
         tags: args.test_tag,
+        connection_string: url,
     }
 }
 
@@ -480,6 +513,7 @@ pub async fn sqlite_test_api(args: TestAPIArgs) -> TestApi {
         database: connector.quaint().clone(),
         api: test_api(connector).await,
         tags: args.test_tag,
+        connection_string: sqlite_test_url(db_name),
     }
 }
 
@@ -505,6 +539,7 @@ async fn mssql_test_api(connection_string: String, args: TestAPIArgs) -> TestApi
         database: connector.quaint().clone(),
         api: test_api(connector).await,
         tags: args.test_tag,
+        connection_string,
     }
 }
 
@@ -866,6 +866,7 @@ async fn changing_a_relation_field_to_a_scalar_field_must_work(api: &TestApi) ->
                 },
                 columns: vec!["b".to_owned()],
                 referenced_table: "B".to_string(),
+                referenced_schema: None,
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::Cascade,
                 on_update_action: ForeignKeyAction::NoAction,
@@ -936,6 +937,7 @@ async fn changing_a_scalar_field_to_a_relation_field_must_work(api: &TestApi) {
             },
             columns: vec![column.name.clone()],
             referenced_table: "B".to_string(),
+            referenced_schema: None,
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
             on_update_action: ForeignKeyAction::NoAction,
@@ -1046,6 +1048,7 @@ async fn adding_an_inline_relation_must_result_in_a_foreign_key_in_the_model_tab
                 },
                 columns: vec![b_column.name.clone()],
                 referenced_table: "B".to_string(),
+                referenced_schema: None,
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::Cascade, // required relations can't set ON DELETE SET NULL
                 on_update_action: ForeignKeyAction::NoAction,
@@ -1059,6 +1062,7 @@ async fn adding_an_inline_relation_must_result_in_a_foreign_key_in_the_model_tab
                 },
                 columns: vec![c_column.name.clone()],
                 referenced_table: "C".to_string(),
+                referenced_schema: None,
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::SetNull,
                 on_update_action: ForeignKeyAction::NoAction,
@@ -1098,6 +1102,7 @@ async fn specifying_a_db_name_for_an_inline_relation_must_work(api: &TestApi) {
             },
             columns: vec![column.name.clone()],
             referenced_table: "B".to_string(),
+            referenced_schema: None,
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
             on_update_action: ForeignKeyAction::NoAction,
@@ -1133,6 +1138,7 @@ async fn adding_an_inline_relation_to_a_model_with_an_exotic_id_type(api: &TestA
             },
             columns: vec![column.name.clone()],
             referenced_table: "B".to_string(),
+            referenced_schema: None,
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
             on_update_action: ForeignKeyAction::NoAction,
@@ -1208,6 +1214,7 @@ async fn moving_an_inline_relation_to_the_other_side_must_work(api: &TestApi) ->
             },
             columns: vec!["b_id".to_string()],
             referenced_table: "B".to_string(),
+            referenced_schema: None,
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
             on_update_action: ForeignKeyAction::NoAction,
@@ -1238,6 +1245,7 @@ async fn moving_an_inline_relation_to_the_other_side_must_work(api: &TestApi) ->
             },
             columns: vec!["a_id".to_string()],
             referenced_table: "A".to_string(),
+            referenced_schema: None,
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
             on_update_action: ForeignKeyAction::NoAction,
@@ -1593,6 +1601,9 @@ async fn foreign_keys_of_inline_one_to_one_relations_have_a_unique_constraint(ap
         name: "Box_cat_id_unique".into(),
         columns: vec!["cat_id".into()],
         tpe: IndexType::Unique,
+        column_options: vec![],
+        algorithm: None,
+        predicate: None,
     }];
 
     assert_eq!(box_table.indices, expected_indexes);
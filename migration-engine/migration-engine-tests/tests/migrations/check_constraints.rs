@@ -0,0 +1,193 @@
+use migration_engine_tests::*;
+
+/// The names of the check constraints of the table, in the order the database returns them.
+async fn check_constraint_names(api: &TestApi, table: &str) -> anyhow::Result<Vec<String>> {
+    let schema = api.describe_database().await?;
+
+    Ok(schema
+        .table_bang(table)
+        .check_constraints
+        .iter()
+        .map(|check_constraint| check_constraint.name.clone().unwrap())
+        .collect())
+}
+
+#[test_each_connector(tags("postgres", "mysql_8", "mariadb", "mssql_2017", "mssql_2019", "sqlite"))]
+async fn check_constraints_can_be_created(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Product {
+            id     Int    @id
+            price  Int
+            status String
+
+            @@check("price > 0")
+            @@check("status IN ('draft', 'published')", name: "status_check")
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    let mut names = check_constraint_names(api, "Product").await?;
+    names.sort();
+
+    assert_eq!(names, &["Product_chk_1", "status_check"]);
+
+    // The databases store normalized expressions, e.g. `status = ANY (ARRAY[...])` on Postgres
+    // or `[status]='published' OR [status]='draft'` on SQL Server. These must match the
+    // expressions of the datamodel, so pushing it again must not recreate the constraints.
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mysql_8", "mariadb", "mssql_2017", "mssql_2019", "sqlite"))]
+async fn renamed_check_constraints_are_recreated(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Product {
+            id    Int @id
+            price Int
+
+            @@check("price > 0", name: "price_check")
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Product {
+            id    Int @id
+            price Int
+
+            @@check("price >= 100", name: "price_at_least_100")
+        }
+    "#;
+
+    api.schema_push(dm2)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+
+    assert_eq!(check_constraint_names(api, "Product").await?, &["price_at_least_100"]);
+
+    api.schema_push(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mysql_8", "mariadb", "mssql_2017", "mssql_2019", "sqlite"))]
+async fn changing_the_expression_of_a_check_constraint_recreates_it(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Product {
+            id    Int @id
+            price Int
+
+            @@check("price > 0", name: "price_check")
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Product {
+            id    Int @id
+            price Int
+
+            @@check("price >= 100", name: "price_check")
+        }
+    "#;
+
+    api.schema_push(dm2)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+
+    assert_eq!(check_constraint_names(api, "Product").await?, &["price_check"]);
+
+    let expression = api.describe_database().await?.table_bang("Product").check_constraints[0]
+        .expression
+        .clone();
+
+    assert!(expression.contains("100"), "{}", expression);
+
+    api.schema_push(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mysql_8", "mariadb", "mssql_2017", "mssql_2019", "sqlite"))]
+async fn removing_an_unnamed_check_constraint_keeps_the_others(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Product {
+            id       Int @id
+            price    Int
+            quantity Int
+
+            @@check("price > 0")
+            @@check("quantity > 0 AND quantity < 100")
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Product {
+            id       Int @id
+            price    Int
+            quantity Int
+
+            @@check("quantity > 0 AND quantity < 100")
+        }
+    "#;
+
+    api.schema_push(dm2)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+
+    let schema = api.describe_database().await?;
+    let check_constraints = &schema.table_bang("Product").check_constraints;
+
+    assert_eq!(check_constraints.len(), 1);
+    assert_eq!(check_constraints[0].name.as_deref(), Some("Product_chk_1"));
+    assert!(check_constraints[0].expression.contains("quantity"));
+
+    api.schema_push(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mysql_8", "mariadb", "mssql_2017", "mssql_2019", "sqlite"))]
+async fn check_constraints_can_be_dropped(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Product {
+            id    Int @id
+            price Int
+
+            @@check("price > 0", name: "price_check")
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Product {
+            id    Int @id
+            price Int
+        }
+    "#;
+
+    api.schema_push(dm2)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+
+    assert!(check_constraint_names(api, "Product").await?.is_empty());
+
+    api.schema_push(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
@@ -0,0 +1,216 @@
+use migration_engine_tests::*;
+
+async fn primary_key_name(api: &TestApi, table: &str) -> anyhow::Result<Option<String>> {
+    let schema = api.describe_database().await?;
+
+    Ok(schema
+        .table_bang(table)
+        .primary_key
+        .as_ref()
+        .and_then(|pk| pk.constraint_name.clone()))
+}
+
+async fn foreign_key_name(api: &TestApi, table: &str) -> anyhow::Result<Option<String>> {
+    let schema = api.describe_database().await?;
+
+    Ok(schema.table_bang(table).foreign_keys[0].constraint_name.clone())
+}
+
+async fn index_names(api: &TestApi, table: &str) -> anyhow::Result<Vec<String>> {
+    let schema = api.describe_database().await?;
+
+    Ok(schema
+        .table_bang(table)
+        .indices
+        .iter()
+        .map(|index| index.name.clone())
+        .collect())
+}
+
+fn user_with_id(api: &TestApi, id_attribute: &str) -> String {
+    format!(
+        r#"
+        {datasource}
+
+        model User {{
+            id   Int    {id_attribute}
+            name String
+        }}
+        "#,
+        datasource = api.datasource(),
+        id_attribute = id_attribute,
+    )
+}
+
+fn post_with_relation(api: &TestApi, relation_arguments: &str) -> String {
+    format!(
+        r#"
+        {datasource}
+
+        model User {{
+            id    Int    @id
+            posts Post[]
+        }}
+
+        model Post {{
+            id       Int  @id
+            authorId Int
+            author   User @relation({relation_arguments})
+        }}
+        "#,
+        datasource = api.datasource(),
+        relation_arguments = relation_arguments,
+    )
+}
+
+fn user_with_email(api: &TestApi, unique_attribute: &str) -> String {
+    format!(
+        r#"
+        {datasource}
+
+        model User {{
+            id    Int    @id
+            email String {unique_attribute}
+        }}
+        "#,
+        datasource = api.datasource(),
+        unique_attribute = unique_attribute,
+    )
+}
+
+#[test_each_connector(tags("postgres", "mssql_2017", "mssql_2019"))]
+async fn primary_key_names_can_be_added_changed_and_removed(api: &TestApi) -> TestResult {
+    let dm = user_with_id(api, "@id");
+    api.schema_push(&dm).send().await?.assert_green()?;
+
+    // Adding a name recreates the primary key.
+    let dm = user_with_id(api, r#"@id(map: "users_pk")"#);
+    api.schema_push(&dm)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+    assert_eq!(primary_key_name(api, "User").await?.as_deref(), Some("users_pk"));
+    api.schema_push(&dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    // Changing the name recreates it again.
+    let dm = user_with_id(api, r#"@id(map: "users_primary_key")"#);
+    api.schema_push(&dm)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+    assert_eq!(
+        primary_key_name(api, "User").await?.as_deref(),
+        Some("users_primary_key")
+    );
+    api.schema_push(&dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    // Without `map`, the database picks the name, so any name matches and the primary key is kept.
+    let dm = user_with_id(api, "@id");
+    api.schema_push(&dm).send().await?.assert_green()?.assert_no_steps()?;
+    assert_eq!(
+        primary_key_name(api, "User").await?.as_deref(),
+        Some("users_primary_key")
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mysql", "mssql_2017", "mssql_2019"))]
+async fn foreign_key_names_can_be_added_changed_and_removed(api: &TestApi) -> TestResult {
+    let dm = post_with_relation(api, "fields: [authorId], references: [id]");
+    api.schema_push(&dm).send().await?.assert_green()?;
+
+    // Adding a name recreates the foreign key.
+    let dm = post_with_relation(api, r#"fields: [authorId], references: [id], map: "posts_author_fk""#);
+    api.schema_push(&dm)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+    assert_eq!(foreign_key_name(api, "Post").await?.as_deref(), Some("posts_author_fk"));
+    api.schema_push(&dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    // Changing the name recreates it again.
+    let dm = post_with_relation(api, r#"fields: [authorId], references: [id], map: "posts_user_fk""#);
+    api.schema_push(&dm)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+    assert_eq!(foreign_key_name(api, "Post").await?.as_deref(), Some("posts_user_fk"));
+    api.schema_push(&dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    // Without `map`, the database picks the name, so any name matches and the foreign key is kept.
+    let dm = post_with_relation(api, "fields: [authorId], references: [id]");
+    api.schema_push(&dm).send().await?.assert_green()?.assert_no_steps()?;
+    assert_eq!(foreign_key_name(api, "Post").await?.as_deref(), Some("posts_user_fk"));
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn unique_constraint_names_can_be_added_changed_and_removed(api: &TestApi) -> TestResult {
+    let dm = user_with_email(api, "@unique");
+    api.schema_push(&dm).send().await?.assert_green()?;
+    let default_names = index_names(api, "User").await?;
+
+    // Unique constraints always have a name, so `map` renames them.
+    let dm = user_with_email(api, r#"@unique(map: "users_email_key")"#);
+    api.schema_push(&dm)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+    assert_eq!(index_names(api, "User").await?, &["users_email_key"]);
+    api.schema_push(&dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    let dm = user_with_email(api, r#"@unique(map: "users_email_unique")"#);
+    api.schema_push(&dm)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+    assert_eq!(index_names(api, "User").await?, &["users_email_unique"]);
+    api.schema_push(&dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    // Removing `map` renames the constraint back to its default name.
+    let dm = user_with_email(api, "@unique");
+    api.schema_push(&dm)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+    assert_eq!(index_names(api, "User").await?, default_names);
+    api.schema_push(&dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn unnamed_constraints_do_not_produce_steps(api: &TestApi) -> TestResult {
+    let dm = format!(
+        r#"
+        {datasource}
+
+        model User {{
+            id    Int    @id
+            email String @unique
+            posts Post[]
+        }}
+
+        model Post {{
+            id       Int  @id
+            authorId Int
+            author   User @relation(fields: [authorId], references: [id])
+        }}
+        "#,
+        datasource = api.datasource(),
+    );
+
+    api.schema_push(&dm).send().await?.assert_green()?;
+    api.schema_push(&dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
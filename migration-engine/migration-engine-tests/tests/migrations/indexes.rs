@@ -1,5 +1,5 @@
 use migration_engine_tests::sql::*;
-use sql_schema_describer::IndexType;
+use sql_schema_describer::{IndexAlgorithm, IndexType, SortOrder};
 
 #[test_each_connector]
 async fn index_on_compound_relation_fields_must_work(api: &TestApi) -> TestResult {
@@ -519,3 +519,188 @@ async fn new_index_with_same_name_as_index_from_dropped_table_works(api: &TestAp
 
     Ok(())
 }
+
+#[test_each_connector(tags("postgres", "mysql_8", "mssql_2017", "mssql_2019", "sqlite"))]
+async fn descending_indexes_are_idempotent(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Post {
+            id        Int      @id
+            title     String
+            createdAt DateTime
+
+            @@index([title, createdAt(Desc)])
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    let schema = api.describe_database().await?;
+    let index = &schema.table_bang("Post").indices[0];
+
+    assert_eq!(index.column_options(0).sort_order, SortOrder::Asc);
+    assert_eq!(index.column_options(1).sort_order, SortOrder::Desc);
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn indexes_with_prefix_lengths_are_idempotent(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Post {
+            id    Int    @id
+            title String
+            slug  String
+
+            @@index([title(100), slug])
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    let schema = api.describe_database().await?;
+    let index = &schema.table_bang("Post").indices[0];
+
+    assert_eq!(index.column_options(0).length, Some(100));
+    assert_eq!(index.column_options(1).length, None);
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn indexes_with_an_index_type_are_idempotent(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Post {
+            id   Int      @id
+            slug String
+            tags String[]
+
+            @@index([tags], type: Gin)
+            @@index([slug], type: Hash)
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    let schema = api.describe_database().await?;
+    let table = schema.table_walkers().find(|table| table.name() == "Post").unwrap();
+    let algorithms: Vec<_> = table.indexes().map(|index| index.algorithm()).collect();
+
+    assert!(algorithms.contains(&Some(IndexAlgorithm::Gin)));
+    assert!(algorithms.contains(&Some(IndexAlgorithm::Hash)));
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mssql_2017", "mssql_2019", "sqlite"))]
+async fn partial_indexes_are_idempotent(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Post {
+            id         Int       @id
+            slug       String
+            deleted_at DateTime?
+
+            @@unique([slug], where: "deleted_at IS NULL")
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    let schema = api.describe_database().await?;
+    let index = &schema.table_bang("Post").indices[0];
+
+    assert!(index.is_unique());
+    assert!(index.predicate.is_some());
+
+    // The databases normalize the predicate, e.g. to `(deleted_at IS NULL)` on Postgres and
+    // `([deleted_at] IS NULL)` on SQL Server, which must match the predicate of the datamodel.
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mssql_2017", "mssql_2019", "sqlite"))]
+async fn changing_the_predicate_of_a_partial_index_recreates_it(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Post {
+            id         Int       @id
+            slug       String
+            status     String
+            deleted_at DateTime?
+
+            @@unique([slug], name: "Post_slug_key", where: "deleted_at IS NULL")
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Post {
+            id         Int       @id
+            slug       String
+            status     String
+            deleted_at DateTime?
+
+            @@unique([slug], name: "Post_slug_key", where: "deleted_at IS NULL AND status = 'published'")
+        }
+    "#;
+
+    api.schema_push(dm2)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+
+    let schema = api.describe_database().await?;
+    let index = &schema.table_bang("Post").indices[0];
+
+    assert_eq!(index.name, "Post_slug_key");
+    assert!(index.predicate.as_deref().unwrap().contains("published"));
+
+    api.schema_push(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mysql", "sqlite"))]
+async fn fulltext_indexes_are_idempotent_and_can_be_dropped(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Post {
+            id    Int    @id
+            title String
+            body  String
+
+            @@fulltext([title, body])
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    let schema = api.describe_database().await?;
+    let index = &schema.table_bang("Post").indices[0];
+
+    assert_eq!(index.tpe, IndexType::Fulltext);
+    assert_eq!(index.columns, &["title", "body"]);
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    let dm = r#"
+        model Post {
+            id    Int    @id
+            title String
+            body  String
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+    api.assert_schema()
+        .await?
+        .assert_table("Post", |table| table.assert_indexes_count(0))?;
+
+    Ok(())
+}
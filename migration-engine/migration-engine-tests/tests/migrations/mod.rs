@@ -1,10 +1,13 @@
 mod apply_script_tests;
+mod check_constraints;
+mod constraint_names;
 mod enums;
 mod indexes;
 mod json;
 mod mariadb;
 mod mark_migration_applied_tests;
 mod mark_migration_rolled_back_tests;
+mod multi_schema;
 mod mysql;
 mod postgres;
 mod sql;
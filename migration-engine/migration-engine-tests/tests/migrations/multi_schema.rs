@@ -0,0 +1,121 @@
+use migration_engine_tests::*;
+
+#[test_each_connector(tags("postgres", "mssql_2017", "mssql_2019"))]
+async fn foreign_keys_can_reference_tables_in_other_schemas(api: &TestApi) -> TestResult {
+    let auth = format!("{}_auth", api.schema_name());
+    let api = api.with_schemas(&[auth.as_str()]).await?;
+    api.reset().send().await?;
+
+    let dm = format!(
+        r#"
+        {datasource}
+
+        model User {{
+            id    Int    @id
+            posts Post[]
+
+            @@schema("{auth}")
+        }}
+
+        model Post {{
+            id     Int  @id
+            userId Int
+            user   User @relation(fields: [userId], references: [id])
+        }}
+        "#,
+        datasource = api.datasource_with_schemas(&[auth.as_str()]),
+        auth = auth,
+    );
+
+    api.schema_push(&dm).send().await?.assert_green()?;
+
+    let schema = api.describe_database().await?;
+    let foreign_key = &schema.table_bang("Post").foreign_keys[0];
+
+    assert_eq!(foreign_key.referenced_table, "User");
+    assert_eq!(foreign_key.referenced_schema.as_deref(), Some(auth.as_str()));
+
+    // Check that the migration is idempotent.
+    api.schema_push(&dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mssql_2017", "mssql_2019"))]
+async fn foreign_keys_to_tables_of_the_same_name_in_other_schemas_are_told_apart(api: &TestApi) -> TestResult {
+    let auth = format!("{}_auth", api.schema_name());
+    let api = api.with_schemas(&[auth.as_str()]).await?;
+    api.reset().send().await?;
+
+    let dm1 = format!(
+        r#"
+        {datasource}
+
+        model User {{
+            id    Int    @id
+            posts Post[]
+        }}
+
+        model AuthUser {{
+            id Int @id
+
+            @@map("User")
+            @@schema("{auth}")
+        }}
+
+        model Post {{
+            id     Int  @id
+            userId Int
+            user   User @relation(fields: [userId], references: [id])
+        }}
+        "#,
+        datasource = api.datasource_with_schemas(&[auth.as_str()]),
+        auth = auth,
+    );
+
+    api.schema_push(&dm1).send().await?.assert_green()?;
+    api.schema_push(&dm1).send().await?.assert_green()?.assert_no_steps()?;
+
+    let dm2 = format!(
+        r#"
+        {datasource}
+
+        model User {{
+            id Int @id
+        }}
+
+        model AuthUser {{
+            id    Int    @id
+            posts Post[]
+
+            @@map("User")
+            @@schema("{auth}")
+        }}
+
+        model Post {{
+            id     Int      @id
+            userId Int
+            user   AuthUser @relation(fields: [userId], references: [id])
+        }}
+        "#,
+        datasource = api.datasource_with_schemas(&[auth.as_str()]),
+        auth = auth,
+    );
+
+    // Only the schema of the referenced table changes.
+    api.schema_push(&dm2)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+
+    let schema = api.describe_database().await?;
+    let foreign_key = &schema.table_bang("Post").foreign_keys[0];
+
+    assert_eq!(foreign_key.referenced_table, "User");
+    assert_eq!(foreign_key.referenced_schema.as_deref(), Some(auth.as_str()));
+
+    api.schema_push(&dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use migration_engine_tests::sql::*;
+use quaint::prelude::Queryable;
 use std::fmt::Write as _;
 
 /// We need to test this specifically for mysql, because foreign keys are indexes, and they are
@@ -286,3 +287,90 @@ async fn native_type_columns_can_be_created(api: &TestApi) -> TestResult {
 
     Ok(())
 }
+
+#[test_each_connector(tags("mysql_8"))]
+async fn changing_the_expression_of_a_computed_field_recreates_the_column(api: &TestApi) -> TestResult {
+    let dm = |expression: &str| {
+        format!(
+            r#"
+            {datasource}
+
+            model OrderItem {{
+                id       Int    @id
+                price    Int
+                quantity Int
+                total    Int    @computed("{expression}")
+                label    String @computed("CONCAT('#', id)", stored: false)
+            }}
+            "#,
+            datasource = api.datasource(),
+            expression = expression,
+        )
+    };
+
+    let dm1 = dm("price * quantity");
+    api.schema_push(&dm1).send().await?.assert_green()?;
+
+    // MySQL returns the expressions with quoted identifiers and charset introducers.
+    api.schema_push(&dm1).send().await?.assert_green()?.assert_no_steps()?;
+
+    let dm2 = dm("price + quantity");
+    api.schema_push(&dm2)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+
+    let schema = api.describe_database().await?;
+    let total = schema
+        .table_bang("OrderItem")
+        .column_bang("total")
+        .generated
+        .as_ref()
+        .unwrap();
+
+    assert!(
+        total.expression.contains("`price` + `quantity`"),
+        "{}",
+        total.expression
+    );
+
+    api.schema_push(&dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql_8"))]
+async fn dbgenerated_defaults_are_wrapped_in_parentheses(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id    Int    @id
+            token String @default(dbgenerated("uuid()")) @db.VarChar(36)
+            lives Int    @default(dbgenerated("3 + 6"))
+            name  String @default(dbgenerated("'Felix'")) @db.VarChar(20)
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+    api.insert("Cat").value("id", 1).result_raw().await?;
+
+    let row = api
+        .database()
+        .query_raw("SELECT token, lives, name FROM Cat", &[])
+        .await?
+        .into_single()?;
+
+    assert_eq!(
+        row.get("token")
+            .and_then(|token| token.to_string())
+            .map(|token| token.len()),
+        Some(36)
+    );
+    assert_eq!(row.get("lives").and_then(|lives| lives.as_i64()), Some(9));
+    assert_eq!(
+        row.get("name").and_then(|name| name.to_string()).as_deref(),
+        Some("Felix")
+    );
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use migration_engine_tests::*;
+use prisma_value::PrismaValue;
 use quaint::prelude::Queryable;
-use sql_schema_describer::{ColumnArity, ColumnTypeFamily};
+use sql_schema_describer::{ColumnArity, ColumnTypeFamily, DefaultValue};
 use std::fmt::Write;
 
 #[test_each_connector(tags("postgres"))]
@@ -163,3 +164,160 @@ async fn native_type_columns_can_be_created(api: &TestApi) -> TestResult {
 
     Ok(())
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn sequences_list_literals_and_expressions_can_be_used_as_defaults(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id     Int      @id @default(sequence("cat_ids"))
+            tags   String[] @default(["fluffy", "black and white"])
+            ages   Int[]    @default([1, 2])
+            toys   Int[]    @default([])
+            chance Float    @default(dbgenerated("random()"))
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table
+            .assert_column("id", |col| {
+                col.assert_auto_increments()?
+                    .assert_default(Some(DefaultValue::sequence("cat_ids")))
+            })?
+            .assert_column("tags", |col| {
+                col.assert_default_value(&PrismaValue::List(vec![
+                    PrismaValue::String("fluffy".into()),
+                    PrismaValue::String("black and white".into()),
+                ]))
+            })?
+            .assert_column("ages", |col| {
+                col.assert_default_value(&PrismaValue::List(vec![PrismaValue::Int(1), PrismaValue::Int(2)]))
+            })?
+            .assert_column("toys", |col| col.assert_default_value(&PrismaValue::List(vec![])))?
+            .assert_column("chance", |col| {
+                col.assert_default(Some(DefaultValue::db_generated("random()")))
+            })
+    })?;
+
+    // Check that the migration is idempotent.
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn views_are_neither_created_nor_dropped(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    let create_view = format!(
+        r#"CREATE VIEW "{0}"."CatNames" AS SELECT id, name FROM "{0}"."Cat""#,
+        api.schema_name()
+    );
+
+    api.database().raw_cmd(&create_view).await?;
+
+    let dm2 = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+
+        view CatNames {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    // The view is managed by the user, it is not created as a table.
+    api.schema_push(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    // Removing the view from the schema doesn't drop it.
+    api.schema_push(dm1).send().await?.assert_green()?.assert_no_steps()?;
+
+    let schema = api.describe_database().await?;
+
+    assert!(schema.get_table("CatNames").is_none());
+    assert!(schema.get_view("CatNames").is_some());
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn changing_the_expression_of_a_computed_field_recreates_the_column(api: &TestApi) -> TestResult {
+    // Generated columns only exist from Postgres 12 on.
+    let version = api.database().version().await?.unwrap_or_default();
+    let major_version: u32 = version
+        .trim_start_matches("PostgreSQL ")
+        .split(|c: char| !c.is_ascii_digit())
+        .next()
+        .and_then(|major| major.parse().ok())
+        .unwrap_or(0);
+
+    if major_version < 12 {
+        return Ok(());
+    }
+
+    let dm = |expression: &str| {
+        format!(
+            r#"
+            {datasource}
+
+            model OrderItem {{
+                id       Int @id
+                price    Int
+                quantity Int
+                total    Int @computed("{expression}")
+            }}
+            "#,
+            datasource = api.datasource(),
+            expression = expression,
+        )
+    };
+
+    let dm1 = dm("price * quantity * 2");
+    api.schema_push(&dm1).send().await?.assert_green()?;
+
+    // Postgres returns `((price * quantity) * 2)`.
+    api.schema_push(&dm1).send().await?.assert_green()?.assert_no_steps()?;
+
+    let dm2 = dm("price + quantity");
+    api.schema_push(&dm2)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+
+    let schema = api.describe_database().await?;
+    let total = schema.table_bang("OrderItem").column_bang("total");
+
+    assert_eq!(total.generated.as_ref().unwrap().expression, "(price + quantity)");
+
+    api.schema_push(&dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn dbgenerated_defaults_with_casts_added_by_postgres_are_idempotent(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id    Int    @id
+            label String @default(dbgenerated("concat('cat', '-', 'label')"))
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    // Postgres returns `concat('cat'::text, '-'::text, 'label'::text)`.
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
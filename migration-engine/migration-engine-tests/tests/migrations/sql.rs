@@ -389,3 +389,118 @@ async fn dropping_mutually_referencing_tables_works(api: &TestApi) -> TestResult
 
     Ok(())
 }
+
+#[test_each_connector(tags("postgres", "mysql", "mssql_2017", "mssql_2019"))]
+async fn documentation_is_round_tripped_as_table_and_column_comments(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        /// The cats of the shelter.
+        model Cat {
+            id Int @id
+            /// The name of the cat, if it's known.
+            name String
+            age Int
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table
+            .assert_comment(Some("The cats of the shelter."))?
+            .assert_column("name", |col| {
+                col.assert_comment(Some("The name of the cat, if it's known."))
+            })?
+            .assert_column("age", |col| col.assert_comment(None))
+    })?;
+
+    api.schema_push(dm1).send().await?.assert_green()?.assert_no_steps()?;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+            /// The name of the cat.
+            name String
+            /// The age of the cat, in years.
+            age Int
+            /// The favourite toy of the cat.
+            toy String?
+        }
+    "#;
+
+    api.schema_push(dm2).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table
+            .assert_comment(None)?
+            .assert_column("name", |col| col.assert_comment(Some("The name of the cat.")))?
+            .assert_column("age", |col| col.assert_comment(Some("The age of the cat, in years.")))?
+            .assert_column("toy", |col| col.assert_comment(Some("The favourite toy of the cat.")))
+    })?;
+
+    api.schema_push(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn ignored_models_and_fields_are_kept_in_the_database(api: &TestApi) -> TestResult {
+    // Introspection ignores tables without a unique criteria instead of leaving them out.
+    let dm1 = r#"
+        model Log {
+            message String
+            level   Int
+
+            @@ignore
+        }
+
+        model User {
+            id     Int     @id
+            secret String? @ignore
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Log", |table| table.assert_has_no_pk()?.assert_has_column("level"))?
+        .assert_table("User", |table| table.assert_has_column("secret"))?;
+
+    api.insert("Log")
+        .value("message", "started")
+        .value("level", 1)
+        .result_raw()
+        .await?;
+
+    api.schema_push(dm1).send().await?.assert_green()?.assert_no_steps()?;
+
+    let dm2 = r#"
+        model Log {
+            message String
+            level   Int
+
+            @@ignore
+        }
+
+        model User {
+            id     Int     @id
+            name   String?
+            secret String? @ignore
+        }
+    "#;
+
+    api.schema_push(dm2).send().await?.assert_green()?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Log", |table| table.assert_has_column("message"))?
+        .assert_table("User", |table| table.assert_has_column("secret"))?;
+
+    api.select("Log")
+        .column("message")
+        .send()
+        .await?
+        .assert_single_row(|row| row.assert_text_value("message", "started"))?;
+
+    Ok(())
+}
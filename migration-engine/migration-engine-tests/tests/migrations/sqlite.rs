@@ -1,4 +1,5 @@
 use migration_engine_tests::sql::*;
+use quaint::prelude::Queryable;
 
 #[test_each_connector(tags("sqlite"))]
 async fn sqlite_must_recreate_indexes(api: &TestApi) -> TestResult {
@@ -88,3 +89,102 @@ async fn creating_a_model_with_a_non_autoincrement_id_column_is_idempotent(api:
 
     Ok(())
 }
+
+#[test_each_connector(tags("sqlite"))]
+async fn fulltext_indexes_are_kept_up_to_date_and_recreated_with_the_table(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Post {
+            id    Int    @id
+            title String
+
+            @@fulltext([title])
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+    api.insert("Post")
+        .value("id", 1)
+        .value("title", "hello world")
+        .result_raw()
+        .await?;
+
+    // Making the column optional redefines the table.
+    let dm2 = r#"
+        model Post {
+            id    Int     @id
+            title String?
+
+            @@fulltext([title])
+        }
+    "#;
+
+    api.schema_push(dm2).send().await?.assert_green()?;
+    api.insert("Post")
+        .value("id", 2)
+        .value("title", "hello again")
+        .result_raw()
+        .await?;
+
+    let matches = r#"SELECT rowid FROM "Post.title_fulltext" WHERE "Post.title_fulltext" MATCH 'hello'"#;
+
+    assert_eq!(api.database().query_raw(matches, &[]).await?.len(), 2);
+
+    api.database()
+        .raw_cmd(r#"UPDATE "Post" SET title = 'goodbye' WHERE id = 1"#)
+        .await?;
+
+    assert_eq!(api.database().query_raw(matches, &[]).await?.len(), 1);
+
+    let dm3 = r#"
+        model Post {
+            id    Int     @id
+            title String?
+        }
+    "#;
+
+    api.schema_push(dm3).send().await?.assert_green()?;
+
+    let fts_tables = api
+        .database()
+        .query_raw(
+            r#"SELECT name FROM sqlite_master WHERE name LIKE 'Post.title_fulltext%'"#,
+            &[],
+        )
+        .await?;
+
+    assert!(fts_tables.is_empty(), "{:?}", fts_tables);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn dbgenerated_defaults_are_wrapped_in_parentheses(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id    Int    @id
+            token String @default(dbgenerated("lower(hex(randomblob(16)))"))
+            lives Int    @default(dbgenerated("3 + 6"))
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+    api.insert("Cat").value("id", 1).result_raw().await?;
+
+    let row = api
+        .database()
+        .query_raw(r#"SELECT token, lives FROM "Cat""#, &[])
+        .await?
+        .into_single()?;
+
+    assert_eq!(
+        row.get("token")
+            .and_then(|token| token.to_string())
+            .map(|token| token.len()),
+        Some(32)
+    );
+    assert_eq!(row.get("lives").and_then(|lives| lives.as_i64()), Some(9));
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
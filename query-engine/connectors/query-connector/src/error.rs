@@ -1,4 +1,4 @@
-use crate::filter::Filter;
+use crate::{filter::Filter, PoolState};
 use prisma_models::prelude::DomainError;
 use thiserror::Error;
 use user_facing_errors::{query_engine::DatabaseConstraint, KnownError};
@@ -30,6 +30,36 @@ impl ConnectorError {
                     column: column.clone(),
                 }))
             }
+            ErrorKind::PoolTimeout { state } => {
+                let unset = || String::from("not set");
+
+                Some(KnownError::new(user_facing_errors::query_engine::PoolTimeout {
+                    connection_limit: state
+                        .connection_limit
+                        .map(|limit| limit.to_string())
+                        .unwrap_or_else(unset),
+                    in_use: state.in_use.to_string(),
+                    pool_timeout: state
+                        .pool_timeout
+                        .map(|secs| format!("{}s", secs))
+                        .unwrap_or_else(unset),
+                }))
+            }
+            ErrorKind::VersionConflict {
+                model_name,
+                expected_version,
+            } => Some(KnownError::new(user_facing_errors::query_engine::VersionConflict {
+                model_name: model_name.clone(),
+                expected_version: expected_version.clone(),
+            })),
+            ErrorKind::NullCompositeUpdate { model_name, field_name } => {
+                Some(KnownError::new(user_facing_errors::query_engine::InputError {
+                    details: format!(
+                        "The composite field `{}` of a `{}` record is null, values within it can't be updated. Use `set` to write the whole composite instead.",
+                        field_name, model_name
+                    ),
+                }))
+            }
             ErrorKind::InvalidDatabaseUrl { details, url } => {
                 let details = user_facing_errors::quaint::invalid_url_description(url, details);
 
@@ -62,6 +92,23 @@ pub enum ErrorKind {
     #[error("Record does not exist.")]
     RecordDoesNotExist,
 
+    #[error(
+        "The record of the model {} was not found with the expected version {}.",
+        model_name,
+        expected_version
+    )]
+    VersionConflict {
+        model_name: String,
+        expected_version: String,
+    },
+
+    #[error(
+        "The composite field {} of a record of the model {} is null, values within it can't be updated.",
+        field_name,
+        model_name
+    )]
+    NullCompositeUpdate { model_name: String, field_name: String },
+
     #[error("Column '{}' does not exist.", column)]
     ColumnDoesNotExist { column: String },
 
@@ -136,6 +183,9 @@ pub enum ErrorKind {
 
     #[error("{}", details)]
     InvalidDatabaseUrl { details: String, url: String },
+
+    #[error("Timed out fetching a connection from the pool ({} connections in use).", state.in_use)]
+    PoolTimeout { state: PoolState },
 }
 
 impl From<DomainError> for ConnectorError {
@@ -39,6 +39,18 @@ impl<'conn, 'tx> ReadOperations for ConnectionLike<'conn, 'tx> {
         }
     }
 
+    async fn get_recursive_related_record_ids(
+        &self,
+        field: &RelationFieldRef,
+        filter: &Filter,
+        max_depth: usize,
+    ) -> crate::Result<Vec<(RecordProjection, usize)>> {
+        match self {
+            Self::Connection(c) => c.get_recursive_related_record_ids(field, filter, max_depth).await,
+            Self::Transaction(tx) => tx.get_recursive_related_record_ids(field, filter, max_depth).await,
+        }
+    }
+
     async fn aggregate_records(
         &self,
         model: &ModelRef,
@@ -105,7 +117,7 @@ impl<'conn, 'tx> WriteOperations for ConnectionLike<'conn, 'tx> {
         }
     }
 
-    async fn query_raw(&self, query: String, parameters: Vec<PrismaValue>) -> crate::Result<serde_json::Value> {
+    async fn query_raw(&self, query: String, parameters: Vec<PrismaValue>) -> crate::Result<RawQueryResult> {
         match self {
             Self::Connection(c) => c.query_raw(query, parameters).await,
             Self::Transaction(tx) => tx.query_raw(query, parameters).await,
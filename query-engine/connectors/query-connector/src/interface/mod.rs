@@ -1,11 +1,12 @@
 mod dispatch;
 pub use dispatch::*;
 
-use crate::{Filter, QueryArguments, WriteArgs};
+use crate::{Filter, QueryArguments, RawQueryResult, WriteArgs};
 use async_trait::async_trait;
 use dml::FieldArity;
 use prisma_models::*;
 use prisma_value::PrismaValue;
+use serde::Serialize;
 
 #[async_trait]
 pub trait Connector {
@@ -14,11 +15,39 @@ pub trait Connector {
 
     /// Returns name of the connector.
     fn name(&self) -> String;
+
+    /// Returns a snapshot of the connection pool, if the connector uses one.
+    fn pool_state(&self) -> Option<PoolState> {
+        None
+    }
+
+    /// Closes the connection pool, if the connector uses one. Connections in use are closed
+    /// once they are returned, getting a new connection fails afterwards.
+    fn close(&self) {}
+}
+
+/// A snapshot of the connection pool of a connector.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolState {
+    /// The configured maximum number of open connections, if set.
+    pub connection_limit: Option<usize>,
+    /// The configured time in seconds to wait for a free connection, if set.
+    pub pool_timeout: Option<u64>,
+    /// Connections currently checked out of the pool.
+    pub in_use: usize,
+    /// Connections checked out since the pool was created.
+    pub checkouts: u64,
+    /// Check outs that gave up waiting for a free connection.
+    pub timeouts: u64,
 }
 
 #[async_trait]
 pub trait Connection: ReadOperations + WriteOperations + Send + Sync {
     async fn start_transaction<'a>(&'a self) -> crate::Result<Box<dyn Transaction + 'a>>;
+
+    /// Runs a cheap query to check the database is reachable, returning the database version.
+    async fn health_check(&self) -> crate::Result<Option<String>>;
 }
 
 #[async_trait]
@@ -200,6 +229,18 @@ pub trait ReadOperations {
         from_record_ids: &[RecordProjection],
     ) -> crate::Result<Vec<(RecordProjection, RecordProjection)>>;
 
+    /// Traverses a self-relation recursively, starting from the records matching the filter.
+    ///
+    /// Returns the identifiers of all records reachable by repeatedly following `field`, for at
+    /// most `max_depth` steps, along with the depth at which each record was first reached.
+    /// Records are ordered by depth. The starting records themselves are not part of the result.
+    async fn get_recursive_related_record_ids(
+        &self,
+        field: &RelationFieldRef,
+        filter: &Filter,
+        max_depth: usize,
+    ) -> crate::Result<Vec<(RecordProjection, usize)>>;
+
     /// Aggregates records for a specific model based on the given selections.
     /// Whether or not the aggregations can be executed in a single query or
     /// requires multiple roundtrips to the underlying data source is at the
@@ -257,6 +298,6 @@ pub trait WriteOperations {
     /// Execute the raw query in the database as-is. The `parameters` are
     /// parameterized values for databases that support prepared statements.
     ///
-    /// Returns resulting rows as JSON.
-    async fn query_raw(&self, query: String, parameters: Vec<PrismaValue>) -> crate::Result<serde_json::Value>;
+    /// Returns the resulting rows together with the types of their columns.
+    async fn query_raw(&self, query: String, parameters: Vec<PrismaValue>) -> crate::Result<RawQueryResult>;
 }
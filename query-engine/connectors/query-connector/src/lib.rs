@@ -6,12 +6,14 @@ pub mod filter;
 mod compare;
 mod interface;
 mod query_arguments;
+mod raw;
 mod write_args;
 
 pub use compare::*;
 pub use filter::*;
 pub use interface::*;
 pub use query_arguments::*;
+pub use raw::*;
 pub use write_args::*;
 
 pub type Result<T> = std::result::Result<T, error::ConnectorError>;
@@ -11,6 +11,7 @@ pub struct SkipAndLimit {
 /// - `cursor`, `take`, `skip` page through the data.
 /// - `filter` scopes the data by defining conditions (akin to `WHERE` in SQL).
 /// - `order_by` defines the ordering of records, from most high to low precedence.
+/// - `order_by_relevance` orders by full-text search relevance, taking precedence over `order_by`.
 /// - `distinct` designates the fields on which the records should be distinct.
 /// - The `ignore_*` flags are a temporary bandaid to tell the connector to do not
 ///   include certain constraints when building queries, because the core is already
@@ -27,6 +28,7 @@ pub struct QueryArguments {
     pub skip: Option<i64>,
     pub filter: Option<Filter>,
     pub order_by: Vec<OrderBy>,
+    pub order_by_relevance: Option<OrderByRelevance>,
     pub distinct: Option<ModelProjection>,
     pub ignore_skip: bool,
     pub ignore_take: bool,
@@ -41,6 +43,7 @@ impl QueryArguments {
             skip: None,
             filter: None,
             order_by: vec![],
+            order_by_relevance: None,
             distinct: None,
             ignore_take: false,
             ignore_skip: false,
@@ -53,6 +56,7 @@ impl QueryArguments {
             && self.skip.is_none()
             && self.filter.is_none()
             && self.order_by.is_empty()
+            && self.order_by_relevance.is_none()
             && self.distinct.is_none()
     }
 
@@ -95,7 +99,9 @@ impl QueryArguments {
     }
 
     pub fn can_batch(&self) -> bool {
-        self.filter.as_ref().map(|filter| filter.can_batch()).unwrap_or(false) && self.cursor.is_none()
+        self.filter.as_ref().map(|filter| filter.can_batch()).unwrap_or(false)
+            && self.cursor.is_none()
+            && self.order_by_relevance.is_none()
     }
 
     pub fn batched(self) -> Vec<Self> {
@@ -106,6 +112,7 @@ impl QueryArguments {
                 let take = self.take;
                 let skip = self.skip;
                 let order_by = self.order_by;
+                let order_by_relevance = self.order_by_relevance;
                 let distinct = self.distinct;
                 let ignore_skip = self.ignore_skip;
                 let ignore_take = self.ignore_take;
@@ -120,6 +127,7 @@ impl QueryArguments {
                         skip,
                         filter: Some(filter),
                         order_by: order_by.clone(),
+                        order_by_relevance: order_by_relevance.clone(),
                         distinct: distinct.clone(),
                         ignore_skip,
                         ignore_take,
@@ -0,0 +1,174 @@
+use prisma_models::PrismaValue;
+use serde_json::{Map, Value};
+use std::fmt;
+
+/// The result of a raw query. Alongside the rows, it carries the type of each
+/// column as reported by the database, so values can be handed to clients
+/// without losing precision.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RawQueryResult {
+    /// The column names, in the order the database returned them.
+    pub columns: Vec<String>,
+
+    /// The type of each column. Same length and order as `columns`.
+    pub column_types: Vec<RawColumnType>,
+
+    /// The rows, each holding one value per column.
+    pub rows: Vec<Vec<PrismaValue>>,
+}
+
+impl RawQueryResult {
+    /// Position of the column with the given name, if present.
+    pub fn column_index(&self, name: &str) -> Option<usize> {
+        self.columns.iter().position(|column| column == name)
+    }
+
+    /// Converts the result into a list of JSON objects, one per row, keyed by
+    /// column name. This is the classic `queryRaw` response: numbers are
+    /// rendered as JSON numbers, even if that loses precision.
+    pub fn into_json(self) -> Value {
+        let columns = self.columns;
+        let column_types = self.column_types;
+
+        let rows = self
+            .rows
+            .into_iter()
+            .map(|row| {
+                let object: Map<String, Value> = row
+                    .into_iter()
+                    .enumerate()
+                    .map(|(idx, value)| (columns[idx].clone(), json_value(value, &column_types[idx], false)))
+                    .collect();
+
+                Value::Object(object)
+            })
+            .collect();
+
+        Value::Array(rows)
+    }
+
+    /// Converts the result into a JSON object holding the `columns`, their
+    /// `types` and the `rows` as lists of values. Values that can't be
+    /// represented as JSON numbers without losing precision (`bigint` and
+    /// `decimal`) are rendered as strings, `bytes` as base64.
+    pub fn into_typed_json(self) -> Value {
+        let column_types = self.column_types;
+
+        let rows = self
+            .rows
+            .into_iter()
+            .map(|row| {
+                let values = row
+                    .into_iter()
+                    .enumerate()
+                    .map(|(idx, value)| json_value(value, &column_types[idx], true))
+                    .collect();
+
+                Value::Array(values)
+            })
+            .collect();
+
+        let mut object = Map::new();
+
+        object.insert(
+            "columns".into(),
+            Value::Array(self.columns.into_iter().map(Value::String).collect()),
+        );
+
+        object.insert(
+            "types".into(),
+            Value::Array(column_types.iter().map(|tpe| Value::String(tpe.to_string())).collect()),
+        );
+
+        object.insert("rows".into(), Value::Array(rows));
+
+        Value::Object(object)
+    }
+}
+
+/// The type of a column in a raw query result.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RawColumnType {
+    BigInt,
+    Float,
+    Double,
+    Decimal,
+    String,
+    Enum,
+    Boolean,
+    Bytes,
+    Json,
+    Xml,
+    Uuid,
+    DateTime,
+    Date,
+    Time,
+    Array(Box<RawColumnType>),
+
+    /// The database didn't give enough information to determine the type,
+    /// e.g. for an empty result.
+    Unknown,
+}
+
+impl RawColumnType {
+    /// Whether the type is fully determined, including the element type of arrays.
+    pub fn is_known(&self) -> bool {
+        match self {
+            Self::Unknown => false,
+            Self::Array(inner) => inner.is_known(),
+            _ => true,
+        }
+    }
+}
+
+impl fmt::Display for RawColumnType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BigInt => write!(f, "bigint"),
+            Self::Float => write!(f, "float"),
+            Self::Double => write!(f, "double"),
+            Self::Decimal => write!(f, "decimal"),
+            Self::String => write!(f, "string"),
+            Self::Enum => write!(f, "enum"),
+            Self::Boolean => write!(f, "boolean"),
+            Self::Bytes => write!(f, "bytes"),
+            Self::Json => write!(f, "json"),
+            Self::Xml => write!(f, "xml"),
+            Self::Uuid => write!(f, "uuid"),
+            Self::DateTime => write!(f, "datetime"),
+            Self::Date => write!(f, "date"),
+            Self::Time => write!(f, "time"),
+            Self::Array(inner) => write!(f, "{}-array", inner),
+            Self::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+fn json_value(value: PrismaValue, column_type: &RawColumnType, lossless: bool) -> Value {
+    match value {
+        PrismaValue::Null => Value::Null,
+        PrismaValue::String(s) | PrismaValue::Enum(s) | PrismaValue::Xml(s) => Value::String(s),
+        PrismaValue::Boolean(b) => Value::Bool(b),
+        PrismaValue::Int(i) => Value::from(i),
+        PrismaValue::BigInt(i) if lossless => Value::String(i.to_string()),
+        PrismaValue::BigInt(i) => Value::from(i),
+        PrismaValue::Float(d) if lossless && column_type == &RawColumnType::Decimal => Value::String(d.to_string()),
+        PrismaValue::Float(d) => d.to_string().parse::<f64>().map(Value::from).unwrap_or(Value::Null),
+        PrismaValue::Uuid(uuid) => Value::String(uuid.to_hyphenated().to_string()),
+        PrismaValue::Json(s) => serde_json::from_str(&s).unwrap_or(Value::String(s)),
+        PrismaValue::Bytes(bytes) => Value::String(prisma_value::encode_bytes(&bytes)),
+        PrismaValue::DateTime(dt) => match column_type {
+            RawColumnType::Date => Value::String(dt.naive_local().date().to_string()),
+            RawColumnType::Time => Value::String(dt.naive_local().time().to_string()),
+            _ => Value::String(prisma_value::stringify_date(&dt)),
+        },
+        PrismaValue::List(values) => {
+            let inner = match column_type {
+                RawColumnType::Array(inner) => inner.as_ref(),
+                _ => &RawColumnType::Unknown,
+            };
+
+            Value::Array(values.into_iter().map(|v| json_value(v, inner, lossless)).collect())
+        }
+    }
+}
@@ -12,6 +12,10 @@ use std::{
 #[derive(Debug, PartialEq, Clone, Default)]
 pub struct WriteArgs {
     pub args: HashMap<DatasourceFieldName, WriteExpression>,
+
+    /// The `@version` the records to update are expected to have, see `update_version`.
+    /// Records that don't have it anymore when they are written are not updated.
+    pub expected_version: Option<PrismaValue>,
 }
 
 #[derive(Debug, PartialEq, Clone, Hash, Eq)]
@@ -61,6 +65,16 @@ pub enum WriteExpression {
 
     /// Divide field by value.
     Divide(PrismaValue),
+
+    /// Append value to a scalar list field. A list value appends all of its elements.
+    Push(PrismaValue),
+
+    /// Remove all occurrences of value from a scalar list field. A list value removes all of its elements.
+    Remove(PrismaValue),
+
+    /// Set the JSON values at the given paths within a composite type field, leaving the
+    /// rest of the composite value as is.
+    UpdateComposite(Vec<(Vec<String>, PrismaValue)>),
 }
 
 impl From<PrismaValue> for WriteExpression {
@@ -87,13 +101,17 @@ impl From<HashMap<DatasourceFieldName, PrismaValue>> for WriteArgs {
     fn from(args: HashMap<DatasourceFieldName, PrismaValue>) -> Self {
         Self {
             args: args.into_iter().map(|(k, v)| (k, WriteExpression::Value(v))).collect(),
+            expected_version: None,
         }
     }
 }
 
 impl From<HashMap<DatasourceFieldName, WriteExpression>> for WriteArgs {
     fn from(args: HashMap<DatasourceFieldName, WriteExpression>) -> Self {
-        Self { args }
+        Self {
+            args,
+            expected_version: None,
+        }
     }
 }
 
@@ -101,6 +119,7 @@ impl From<Vec<(DatasourceFieldName, PrismaValue)>> for WriteArgs {
     fn from(pairs: Vec<(DatasourceFieldName, PrismaValue)>) -> Self {
         Self {
             args: pairs.into_iter().map(|(k, v)| (k, WriteExpression::Value(v))).collect(),
+            expected_version: None,
         }
     }
 }
@@ -109,13 +128,14 @@ impl From<Vec<(DatasourceFieldName, WriteExpression)>> for WriteArgs {
     fn from(pairs: Vec<(DatasourceFieldName, WriteExpression)>) -> Self {
         Self {
             args: pairs.into_iter().collect(),
+            expected_version: None,
         }
     }
 }
 
 impl WriteArgs {
     pub fn new() -> Self {
-        Self { args: HashMap::new() }
+        Self::default()
     }
 
     pub fn insert<T, V>(&mut self, key: T, arg: V)
@@ -179,6 +199,31 @@ impl WriteArgs {
         }
     }
 
+    /// Increments the `@version` field of the model, if it has one.
+    /// Returns the version passed in the arguments, which is the version the record to update is expected to have,
+    /// and keeps it as `expected_version`. Fails with the write expression if the version is not set to a plain value.
+    pub fn update_version(&mut self, model: ModelRef) -> Result<Option<PrismaValue>, WriteExpression> {
+        let field = match model.fields().version().clone() {
+            Some(field) => field,
+            None => return Ok(None),
+        };
+
+        let expected_version = match self.args.remove(field.db_name()) {
+            Some(WriteExpression::Value(PrismaValue::Null)) | None => None,
+            Some(WriteExpression::Value(pv)) => Some(pv),
+            Some(expr) => return Err(expr),
+        };
+
+        if !self.args.is_empty() || expected_version.is_some() {
+            self.args
+                .insert((&field).into(), WriteExpression::Add(PrismaValue::Int(1)));
+        }
+
+        self.expected_version = expected_version.clone();
+
+        Ok(expected_version)
+    }
+
     pub fn as_record_projection(&self, model_projection: ModelProjection) -> Option<RecordProjection> {
         let pairs: Vec<_> = model_projection
             .scalar_fields()
@@ -1,9 +1,9 @@
-use super::transaction::SqlConnectorTransaction;
+use super::{pool::Checkout, transaction::SqlConnectorTransaction};
 use crate::{database::operations::*, QueryExt, SqlError};
 use async_trait::async_trait;
 use connector_interface::{
     self as connector, filter::Filter, AggregationRow, AggregationSelection, Connection, QueryArguments,
-    ReadOperations, RecordFilter, Transaction, WriteArgs, WriteOperations,
+    RawQueryResult, ReadOperations, RecordFilter, Transaction, WriteArgs, WriteOperations,
 };
 use prisma_models::prelude::*;
 use prisma_value::PrismaValue;
@@ -13,15 +13,22 @@ use std::future::Future;
 pub struct SqlConnection<C> {
     inner: C,
     connection_info: ConnectionInfo,
+    /// Keeps the connection counted as in use until it is returned to the pool.
+    _checkout: Checkout,
 }
 
 impl<C> SqlConnection<C>
 where
     C: QueryExt + Send + Sync + 'static,
 {
-    pub fn new(inner: C, connection_info: &ConnectionInfo) -> Self {
+    pub fn new(inner: C, connection_info: &ConnectionInfo, checkout: Checkout) -> Self {
         let connection_info = connection_info.clone();
-        Self { inner, connection_info }
+
+        Self {
+            inner,
+            connection_info,
+            _checkout: checkout,
+        }
     }
 
     async fn catch<O>(
@@ -49,6 +56,14 @@ where
         })
         .await
     }
+
+    async fn health_check(&self) -> connector::Result<Option<String>> {
+        self.catch(async move {
+            self.inner.query_raw("SELECT 1", &[]).await?;
+            Ok(self.inner.version().await?)
+        })
+        .await
+    }
 }
 
 #[async_trait]
@@ -62,8 +77,12 @@ where
         filter: &Filter,
         selected_fields: &ModelProjection,
     ) -> connector::Result<Option<SingleRecord>> {
-        self.catch(async move { read::get_single_record(&self.inner, model, filter, selected_fields).await })
-            .await
+        let sql_family = self.connection_info.sql_family();
+
+        self.catch(
+            async move { read::get_single_record(&self.inner, sql_family, model, filter, selected_fields).await },
+        )
+        .await
     }
 
     async fn get_many_records(
@@ -72,8 +91,12 @@ where
         query_arguments: QueryArguments,
         selected_fields: &ModelProjection,
     ) -> connector::Result<ManyRecords> {
-        self.catch(async move { read::get_many_records(&self.inner, model, query_arguments, selected_fields).await })
-            .await
+        let sql_family = self.connection_info.sql_family();
+
+        self.catch(async move {
+            read::get_many_records(&self.inner, sql_family, model, query_arguments, selected_fields).await
+        })
+        .await
     }
 
     async fn get_related_m2m_record_ids(
@@ -85,6 +108,20 @@ where
             .await
     }
 
+    async fn get_recursive_related_record_ids(
+        &self,
+        field: &RelationFieldRef,
+        filter: &Filter,
+        max_depth: usize,
+    ) -> connector::Result<Vec<(RecordProjection, usize)>> {
+        let sql_family = self.connection_info.sql_family();
+
+        self.catch(async move {
+            read::get_recursive_related_record_ids(&self.inner, sql_family, field, filter, max_depth).await
+        })
+        .await
+    }
+
     async fn aggregate_records(
         &self,
         model: &ModelRef,
@@ -92,8 +129,12 @@ where
         group_by: Vec<ScalarFieldRef>,
         query_arguments: QueryArguments,
     ) -> connector::Result<Vec<AggregationRow>> {
-        self.catch(async move { read::aggregate(&self.inner, model, selections, group_by, query_arguments).await })
-            .await
+        let sql_family = self.connection_info.sql_family();
+
+        self.catch(async move {
+            read::aggregate(&self.inner, sql_family, model, selections, group_by, query_arguments).await
+        })
+        .await
     }
 }
 
@@ -113,12 +154,16 @@ where
         record_filter: RecordFilter,
         args: WriteArgs,
     ) -> connector::Result<Vec<RecordProjection>> {
-        self.catch(async move { write::update_records(&self.inner, model, record_filter, args).await })
+        let sql_family = self.connection_info.sql_family();
+
+        self.catch(async move { write::update_records(&self.inner, sql_family, model, record_filter, args).await })
             .await
     }
 
     async fn delete_records(&self, model: &ModelRef, record_filter: RecordFilter) -> connector::Result<usize> {
-        self.catch(async move { write::delete_records(&self.inner, model, record_filter).await })
+        let sql_family = self.connection_info.sql_family();
+
+        self.catch(async move { write::delete_records(&self.inner, sql_family, model, record_filter).await })
             .await
     }
 
@@ -147,7 +192,7 @@ where
             .await
     }
 
-    async fn query_raw(&self, query: String, parameters: Vec<PrismaValue>) -> connector::Result<serde_json::Value> {
+    async fn query_raw(&self, query: String, parameters: Vec<PrismaValue>) -> connector::Result<RawQueryResult> {
         self.catch(async move { write::query_raw(&self.inner, query, parameters).await })
             .await
     }
@@ -1,6 +1,7 @@
 mod connection;
 mod mssql;
 mod mysql;
+mod pool;
 mod postgresql;
 mod sqlite;
 mod transaction;
@@ -9,7 +10,7 @@ pub(crate) mod operations;
 
 use async_trait::async_trait;
 use connector_interface::{error::ConnectorError, Connector};
-use datamodel::Datasource;
+use datamodel::{Datasource, PoolSettings};
 
 pub use mssql::*;
 pub use mysql::*;
@@ -18,7 +19,16 @@ pub use sqlite::*;
 
 #[async_trait]
 pub trait FromSource {
+    /// Creates a connector for the primary database of the datasource.
     async fn from_source(source: &Datasource) -> connector_interface::Result<Self>
+    where
+        Self: Connector + Sized,
+    {
+        Self::from_url(&source.url().value, &source.pool).await
+    }
+
+    /// Creates a connector for the database behind `url`, e.g. a read replica of the datasource.
+    async fn from_url(url: &str, pool_settings: &PoolSettings) -> connector_interface::Result<Self>
     where
         Self: Connector + Sized;
 }
@@ -1,25 +1,22 @@
-use super::connection::SqlConnection;
-use crate::{FromSource, SqlError};
+use super::{connection::SqlConnection, pool::ConnectionPool};
+use crate::FromSource;
 use async_trait::async_trait;
 use connector_interface::{
     self as connector,
     error::{ConnectorError, ErrorKind},
-    Connection, Connector,
+    Connection, Connector, PoolState,
 };
-use datamodel::Datasource;
-use quaint::{pooled::Quaint, prelude::ConnectionInfo};
-use std::time::Duration;
+use datamodel::PoolSettings;
+use quaint::prelude::ConnectionInfo;
 
 pub struct Mssql {
-    pool: Quaint,
+    pool: ConnectionPool,
     connection_info: ConnectionInfo,
 }
 
 #[async_trait]
 impl FromSource for Mssql {
-    async fn from_source(source: &Datasource) -> connector_interface::Result<Self> {
-        let database_str = &source.url().value;
-
+    async fn from_url(database_str: &str, pool_settings: &PoolSettings) -> connector_interface::Result<Self> {
         let connection_info = ConnectionInfo::from_url(database_str).map_err(|err| {
             ConnectorError::from_kind(ErrorKind::InvalidDatabaseUrl {
                 details: err.to_string(),
@@ -27,15 +24,10 @@ impl FromSource for Mssql {
             })
         })?;
 
-        let mut builder = Quaint::builder(database_str)
-            .map_err(SqlError::from)
+        let pool = ConnectionPool::new(database_str, pool_settings)
+            .await
             .map_err(|sql_error| sql_error.into_connector_error(&connection_info))?;
 
-        builder.max_idle_lifetime(Duration::from_secs(300));
-        builder.health_check_interval(Duration::from_secs(15));
-        builder.test_on_check_out(true);
-
-        let pool = builder.build();
         let connection_info = pool.connection_info().to_owned();
 
         Ok(Self { pool, connection_info })
@@ -46,14 +38,22 @@ impl FromSource for Mssql {
 impl Connector for Mssql {
     async fn get_connection<'a>(&'a self) -> connector::Result<Box<dyn Connection + 'static>> {
         super::catch(&self.connection_info, async move {
-            let conn = self.pool.check_out().await.map_err(SqlError::from)?;
-            let conn = SqlConnection::new(conn, &self.connection_info);
+            let (conn, checkout) = self.pool.check_out().await?;
+            let conn = SqlConnection::new(conn, &self.connection_info, checkout);
 
             Ok(Box::new(conn) as Box<dyn Connection>)
         })
         .await
     }
 
+    fn pool_state(&self) -> Option<PoolState> {
+        Some(self.pool.state())
+    }
+
+    fn close(&self) {
+        self.pool.close()
+    }
+
     fn name(&self) -> String {
         "mssql".to_owned()
     }
@@ -1,20 +1,22 @@
 use crate::{
     query_arguments_ext::QueryArgumentsExt,
     query_builder::{self, read},
-    QueryExt, SqlError,
+    QueryExt, SqlError, ToSqlRow,
 };
 use connector_interface::*;
+use datamodel::FieldArity;
 use futures::stream::{FuturesUnordered, StreamExt};
 use prisma_models::*;
-use quaint::ast::*;
+use quaint::{ast::*, connector::SqlFamily};
 
 pub async fn get_single_record(
     conn: &dyn QueryExt,
+    sql_family: SqlFamily,
     model: &ModelRef,
     filter: &Filter,
     selected_fields: &ModelProjection,
 ) -> crate::Result<Option<SingleRecord>> {
-    let query = read::get_records(&model, selected_fields.as_columns(), filter);
+    let query = read::get_records(sql_family, &model, selected_fields.as_columns(), filter);
     let field_names = selected_fields.db_names().map(String::from).collect();
     let idents: Vec<_> = selected_fields.type_identifiers_with_arities();
 
@@ -32,6 +34,7 @@ pub async fn get_single_record(
 
 pub async fn get_many_records(
     conn: &dyn QueryExt,
+    sql_family: SqlFamily,
     model: &ModelRef,
     mut query_arguments: QueryArguments,
     selected_fields: &ModelProjection,
@@ -56,7 +59,7 @@ pub async fn get_many_records(
         let mut futures = FuturesUnordered::new();
 
         for args in batches.into_iter() {
-            let query = read::get_records(model, selected_fields.as_columns(), args);
+            let query = read::get_records(sql_family, model, selected_fields.as_columns(), args);
             futures.push(conn.filter(query.into(), idents.as_slice()));
         }
 
@@ -70,7 +73,7 @@ pub async fn get_many_records(
             records.order_by(&order)
         }
     } else {
-        let query = read::get_records(model, selected_fields.as_columns(), query_arguments);
+        let query = read::get_records(sql_family, model, selected_fields.as_columns(), query_arguments);
 
         for item in conn.filter(query.into(), idents.as_slice()).await?.into_iter() {
             records.push(Record::from(item))
@@ -145,17 +148,48 @@ pub async fn get_related_m2m_record_ids(
         .collect())
 }
 
+pub async fn get_recursive_related_record_ids(
+    conn: &dyn QueryExt,
+    sql_family: SqlFamily,
+    field: &RelationFieldRef,
+    filter: &Filter,
+    max_depth: usize,
+) -> crate::Result<Vec<(RecordProjection, usize)>> {
+    let model_id = field.model().primary_identifier();
+    let (sql, params) = read::recursive_related_ids(sql_family, field, filter.clone(), max_depth)?;
+
+    let mut idents = model_id.type_identifiers_with_arities();
+    idents.push((TypeIdentifier::Int, FieldArity::Required));
+
+    let mut result = Vec::new();
+
+    for row in conn.query_raw(&sql, &params).await?.into_iter() {
+        let mut values = row.to_sql_row(&idents)?.values;
+
+        let depth = match values.pop() {
+            Some(PrismaValue::Int(depth)) => depth as usize,
+            _ => unreachable!("Traversal rows end with the depth of the record."),
+        };
+
+        let pairs: Vec<_> = model_id.scalar_fields().zip(values.into_iter()).collect();
+        result.push((RecordProjection::new(pairs), depth));
+    }
+
+    Ok(result)
+}
+
 pub async fn aggregate(
     conn: &dyn QueryExt,
+    sql_family: SqlFamily,
     model: &ModelRef,
     selections: Vec<AggregationSelection>,
     group_by: Vec<ScalarFieldRef>,
     query_arguments: QueryArguments,
 ) -> crate::Result<Vec<AggregationRow>> {
     if group_by.len() > 0 {
-        group_by_aggregate(conn, model, selections, group_by, query_arguments).await
+        group_by_aggregate(conn, sql_family, model, selections, group_by, query_arguments).await
     } else {
-        plain_aggregate(conn, model, selections, query_arguments)
+        plain_aggregate(conn, sql_family, model, selections, query_arguments)
             .await
             .map(|v| vec![v])
     }
@@ -163,11 +197,12 @@ pub async fn aggregate(
 
 async fn plain_aggregate(
     conn: &dyn QueryExt,
+    sql_family: SqlFamily,
     model: &ModelRef,
     selections: Vec<AggregationSelection>,
     query_arguments: QueryArguments,
 ) -> crate::Result<Vec<AggregationResult>> {
-    let query = read::aggregate(model, &selections, query_arguments);
+    let query = read::aggregate(sql_family, model, &selections, query_arguments);
 
     let idents: Vec<_> = selections
         .iter()
@@ -184,12 +219,13 @@ async fn plain_aggregate(
 
 async fn group_by_aggregate(
     conn: &dyn QueryExt,
+    sql_family: SqlFamily,
     model: &ModelRef,
     selections: Vec<AggregationSelection>,
     group_by: Vec<ScalarFieldRef>,
     query_arguments: QueryArguments,
 ) -> crate::Result<Vec<AggregationRow>> {
-    let query = read::group_by_aggregate(model, group_by, &selections, query_arguments);
+    let query = read::group_by_aggregate(sql_family, model, group_by, &selections, query_arguments);
 
     let idents: Vec<_> = selections
         .iter()
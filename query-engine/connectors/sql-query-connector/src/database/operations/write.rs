@@ -2,7 +2,7 @@ use crate::{error::SqlError, query_builder::write, QueryExt};
 use connector_interface::*;
 use prisma_models::*;
 use prisma_value::PrismaValue;
-use quaint::error::ErrorKind;
+use quaint::{connector::SqlFamily, error::ErrorKind};
 use std::{collections::HashMap, convert::TryFrom};
 use user_facing_errors::query_engine::DatabaseConstraint;
 
@@ -68,36 +68,81 @@ pub async fn create_record(conn: &dyn QueryExt, model: &ModelRef, args: WriteArg
 /// operation.
 pub async fn update_records(
     conn: &dyn QueryExt,
+    sql_family: SqlFamily,
     model: &ModelRef,
     record_filter: RecordFilter,
     args: WriteArgs,
 ) -> crate::Result<Vec<RecordProjection>> {
-    let ids = conn.filter_selectors(model, record_filter).await?;
+    let ids = conn.filter_selectors(model, record_filter, sql_family).await?;
     let id_args = pick_args(&model.primary_identifier(), &args);
 
     if ids.is_empty() {
         return Ok(vec![]);
     }
 
+    let expected_version = args.expected_version.clone();
     let updates = {
         let ids: Vec<&RecordProjection> = ids.iter().map(|id| &*id).collect();
-        write::update_many(model, ids.as_slice(), args)?
+
+        // Values within a `NULL` composite can't be updated, the update would silently do nothing.
+        let idents = model.primary_identifier().type_identifiers_with_arities();
+
+        for (field, composite_updates) in updated_composites(model, &args) {
+            for select in write::null_composites(sql_family, model, ids.as_slice(), &field, composite_updates) {
+                if !conn.filter(select, idents.as_slice()).await?.is_empty() {
+                    return Err(SqlError::NullCompositeUpdate {
+                        model_name: model.name.clone(),
+                        field_name: field.name.clone(),
+                    });
+                }
+            }
+        }
+
+        write::update_many(sql_family, model, ids.as_slice(), args)?
     };
 
+    let mut updated = 0;
     for update in updates {
-        conn.query(update).await?;
+        updated += conn.execute(update).await?;
+    }
+
+    // Records not updated changed their version since they were selected.
+    if let Some(expected_version) = expected_version {
+        if updated < ids.len() as u64 {
+            return Err(SqlError::VersionConflict {
+                model_name: model.name.clone(),
+                expected_version: expected_version.to_string(),
+            });
+        }
     }
 
     Ok(merge_write_args(ids, id_args))
 }
 
+/// The composite fields having values within them updated by `args`, with their updates.
+fn updated_composites<'a>(
+    model: &ModelRef,
+    args: &'a WriteArgs,
+) -> Vec<(ScalarFieldRef, &'a [(Vec<String>, PrismaValue)])> {
+    model
+        .fields()
+        .scalar()
+        .into_iter()
+        .filter_map(|field| match args.get_field_value(field.db_name()) {
+            Some(WriteExpression::UpdateComposite(updates)) => Some((field, updates.as_slice())),
+            _ => None,
+        })
+        .collect()
+}
+
 /// Delete multiple records in `conn`, defined in the `Filter`. Result is the number of items deleted.
 pub async fn delete_records(
     conn: &dyn QueryExt,
+    sql_family: SqlFamily,
     model: &ModelRef,
     record_filter: RecordFilter,
 ) -> crate::Result<usize> {
-    let ids = conn.filter_selectors(model, record_filter).await?;
+    let ids = conn.filter_selectors(model, record_filter, sql_family).await?;
     let ids: Vec<&RecordProjection> = ids.iter().map(|id| &*id).collect();
     let count = ids.len();
 
@@ -147,15 +192,14 @@ pub async fn execute_raw(conn: &dyn QueryExt, query: String, parameters: Vec<Pri
     Ok(value)
 }
 
-/// Execute a plain SQL query with the given parameters, returning the answer as
-/// a JSON `Value`.
+/// Execute a plain SQL query with the given parameters, returning the rows
+/// together with the types of their columns.
 pub async fn query_raw(
     conn: &dyn QueryExt,
     query: String,
     parameters: Vec<PrismaValue>,
-) -> crate::Result<serde_json::Value> {
-    let value = conn.raw_json(query, parameters).await?;
-    Ok(value)
+) -> crate::Result<RawQueryResult> {
+    conn.raw_result(query, parameters).await
 }
 
 /// Picks all arguments out of `args` that are updating a value for a field
@@ -210,5 +254,9 @@ fn apply_expression(val: PrismaValue, expr: WriteExpression) -> PrismaValue {
         WriteExpression::Substract(rhs) => val - rhs,
         WriteExpression::Multiply(rhs) => val * rhs,
         WriteExpression::Divide(rhs) => val / rhs,
+        WriteExpression::Push(_) | WriteExpression::Remove(_) => {
+            unreachable!("Scalar lists can't be part of an identifier.")
+        }
+        WriteExpression::UpdateComposite(_) => unreachable!("Composite types can't be part of an identifier."),
     }
 }
@@ -0,0 +1,204 @@
+use crate::SqlError;
+use connector_interface::PoolState;
+use datamodel::PoolSettings;
+use quaint::{
+    error::ErrorKind as QuaintKind,
+    pooled::{PooledConnection, Quaint},
+    prelude::ConnectionInfo,
+};
+use std::{
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Arc, RwLock, Weak,
+    },
+    time::Duration,
+};
+
+/// Idle connections are closed after this time, unless configured otherwise.
+const DEFAULT_IDLE_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// How often the pool is topped up to the configured number of idle connections.
+const MIN_IDLE_INTERVAL: Duration = Duration::from_secs(15);
+
+/// The connection pool of a SQL connector.
+/// Wraps the quaint pool to apply the datasource pool settings and to keep track of its usage.
+pub struct ConnectionPool {
+    /// The quaint pool, `None` once the pool is closed.
+    inner: RwLock<Option<Arc<Quaint>>>,
+    connection_info: ConnectionInfo,
+    connection_limit: Option<usize>,
+    pool_timeout: Option<u64>,
+    metrics: Arc<PoolMetrics>,
+}
+
+#[derive(Default)]
+struct PoolMetrics {
+    in_use: AtomicUsize,
+    checkouts: AtomicU64,
+    timeouts: AtomicU64,
+}
+
+/// Counts a connection as in use for as long as the guard is alive.
+pub struct Checkout {
+    metrics: Arc<PoolMetrics>,
+}
+
+impl Drop for Checkout {
+    fn drop(&mut self) {
+        self.metrics.in_use.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+impl ConnectionPool {
+    /// Builds a pool for the database behind `url`. Settings missing from `settings` are taken
+    /// from the connection string, and fall back to the quaint defaults after that.
+    pub async fn new(url: &str, settings: &PoolSettings) -> Result<Self, SqlError> {
+        let mut builder = Quaint::builder(url)?;
+
+        let idle_timeout = settings.idle_timeout.map(Duration::from_secs);
+        builder.max_idle_lifetime(idle_timeout.unwrap_or(DEFAULT_IDLE_TIMEOUT));
+        builder.health_check_interval(Duration::from_secs(15));
+        builder.test_on_check_out(true);
+
+        if let Some(connection_limit) = settings.connection_limit {
+            builder.connection_limit(connection_limit);
+        }
+
+        if let Some(pool_timeout) = settings.pool_timeout {
+            builder.pool_timeout(Duration::from_secs(pool_timeout));
+        }
+
+        if let Some(max_lifetime) = settings.max_connection_lifetime {
+            builder.max_lifetime(Duration::from_secs(max_lifetime));
+        }
+
+        let inner = Arc::new(builder.build());
+
+        let pool = Self {
+            connection_info: inner.connection_info().to_owned(),
+            inner: RwLock::new(Some(inner)),
+            connection_limit: settings.connection_limit.or_else(|| url_param(url, "connection_limit")),
+            pool_timeout: settings.pool_timeout.or_else(|| url_param(url, "pool_timeout")),
+            metrics: Arc::new(PoolMetrics::default()),
+        };
+
+        let min_idle = settings.min_idle_connections.unwrap_or(0);
+
+        if min_idle > 0 {
+            open_idle_connections(&pool.quaint()?, min_idle).await?;
+            pool.keep_idle_connections(min_idle);
+        }
+
+        Ok(pool)
+    }
+
+    pub fn connection_info(&self) -> &ConnectionInfo {
+        &self.connection_info
+    }
+
+    /// Checks out a connection. Running out of time waiting for a free connection
+    /// results in a `PoolTimeout` error carrying the current state of the pool.
+    pub async fn check_out(&self) -> Result<(PooledConnection, Checkout), SqlError> {
+        match self.quaint()?.check_out().await {
+            Ok(conn) => {
+                self.metrics.checkouts.fetch_add(1, Ordering::Relaxed);
+                self.metrics.in_use.fetch_add(1, Ordering::Relaxed);
+
+                let checkout = Checkout {
+                    metrics: Arc::clone(&self.metrics),
+                };
+
+                Ok((conn, checkout))
+            }
+            Err(err) if matches!(err.kind(), QuaintKind::Timeout(..)) => {
+                self.metrics.timeouts.fetch_add(1, Ordering::Relaxed);
+                Err(SqlError::PoolTimeout(self.state()))
+            }
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    pub fn state(&self) -> PoolState {
+        PoolState {
+            connection_limit: self.connection_limit,
+            pool_timeout: self.pool_timeout,
+            in_use: self.metrics.in_use.load(Ordering::Relaxed),
+            checkouts: self.metrics.checkouts.load(Ordering::Relaxed),
+            timeouts: self.metrics.timeouts.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Closes the pool. Idle connections are closed right away, connections in use are closed
+    /// when they are returned. Checking out a connection fails from now on.
+    pub fn close(&self) {
+        self.inner.write().expect("Pool lock poisoned").take();
+    }
+
+    fn quaint(&self) -> Result<Arc<Quaint>, SqlError> {
+        self.inner
+            .read()
+            .expect("Pool lock poisoned")
+            .clone()
+            .ok_or(SqlError::PoolClosed)
+    }
+
+    /// Periodically tops the pool up to `min_idle` idle connections, as idle connections are
+    /// closed after the idle timeout and broken ones are discarded. Connections in use count
+    /// against the connection limit, so the pool is never grown beyond it. The task ends when
+    /// the pool is closed or dropped.
+    fn keep_idle_connections(&self, min_idle: usize) {
+        let inner: Weak<Quaint> = match self.inner.read().expect("Pool lock poisoned").as_ref() {
+            Some(inner) => Arc::downgrade(inner),
+            None => return,
+        };
+        let metrics = Arc::clone(&self.metrics);
+        let connection_limit = self.connection_limit;
+
+        tokio::spawn(async move {
+            loop {
+                tokio::time::delay_for(MIN_IDLE_INTERVAL).await;
+
+                let inner = match inner.upgrade() {
+                    Some(inner) => inner,
+                    None => break,
+                };
+
+                let in_use = metrics.in_use.load(Ordering::Relaxed);
+                let count = match connection_limit {
+                    Some(limit) => min_idle.min(limit.saturating_sub(in_use)),
+                    None => min_idle,
+                };
+
+                // A failing database surfaces on the next check out, there is nothing to report here.
+                open_idle_connections(&inner, count).await.ok();
+            }
+        });
+    }
+}
+
+/// Makes sure at least `count` connections are open, reusing idle ones before opening new ones.
+/// The connections are returned to the pool right away. Bypasses the pool metrics, as these
+/// connections are never used for queries.
+async fn open_idle_connections(pool: &Quaint, count: usize) -> Result<(), SqlError> {
+    let mut connections = Vec::with_capacity(count);
+
+    for _ in 0..count {
+        connections.push(pool.check_out().await?);
+    }
+
+    Ok(())
+}
+
+/// Reads a numeric parameter from the query string of a connection string.
+fn url_param<T: std::str::FromStr>(url: &str, name: &str) -> Option<T> {
+    let params = url.splitn(2, '?').nth(1)?;
+
+    params
+        .split('&')
+        .filter_map(|param| {
+            let mut splitted = param.splitn(2, '=');
+            Some((splitted.next()?, splitted.next()?))
+        })
+        .find(|(key, _)| *key == name)
+        .and_then(|(_, value)| value.parse().ok())
+}
@@ -1,17 +1,17 @@
-use super::connection::SqlConnection;
+use super::{connection::SqlConnection, pool::ConnectionPool};
 use crate::{FromSource, SqlError};
 use async_trait::async_trait;
 use connector_interface::{
     self as connector,
     error::{ConnectorError, ErrorKind},
-    Connection, Connector,
+    Connection, Connector, PoolState,
 };
-use datamodel::Datasource;
-use quaint::{connector::SqliteParams, error::ErrorKind as QuaintKind, pooled::Quaint, prelude::ConnectionInfo};
-use std::{convert::TryFrom, time::Duration};
+use datamodel::PoolSettings;
+use quaint::{connector::SqliteParams, error::ErrorKind as QuaintKind, prelude::ConnectionInfo};
+use std::convert::TryFrom;
 
 pub struct Sqlite {
-    pool: Quaint,
+    pool: ConnectionPool,
     file_path: String,
 }
 
@@ -27,13 +27,11 @@ impl Sqlite {
 
 #[async_trait]
 impl FromSource for Sqlite {
-    async fn from_source(source: &Datasource) -> connector_interface::Result<Sqlite> {
-        let database_str = &source.url().value;
-
+    async fn from_url(database_str: &str, pool_settings: &PoolSettings) -> connector_interface::Result<Sqlite> {
         let connection_info = ConnectionInfo::from_url(database_str)
             .map_err(|err| ConnectorError::from_kind(ErrorKind::ConnectionError(err.into())))?;
 
-        let params = SqliteParams::try_from(database_str.as_str())
+        let params = SqliteParams::try_from(database_str)
             .map_err(SqlError::from)
             .map_err(|sql_error| sql_error.into_connector_error(&connection_info))?;
 
@@ -61,16 +59,10 @@ impl FromSource for Sqlite {
             format!("{}?{}", url, params.join("&"))
         };
 
-        let mut builder = Quaint::builder(url_with_db.as_str())
-            .map_err(SqlError::from)
+        let pool = ConnectionPool::new(url_with_db.as_str(), pool_settings)
+            .await
             .map_err(|sql_error| sql_error.into_connector_error(&connection_info))?;
 
-        builder.max_idle_lifetime(Duration::from_secs(300));
-        builder.health_check_interval(Duration::from_secs(15));
-        builder.test_on_check_out(true);
-
-        let pool = builder.build();
-
         Ok(Sqlite { pool, file_path })
     }
 }
@@ -87,14 +79,22 @@ fn invalid_file_path_error(file_path: &str, connection_info: &ConnectionInfo) ->
 impl Connector for Sqlite {
     async fn get_connection<'a>(&'a self) -> connector::Result<Box<dyn Connection + 'static>> {
         super::catch(&self.connection_info(), async move {
-            let conn = self.pool.check_out().await.map_err(SqlError::from)?;
-            let conn = SqlConnection::new(conn, self.connection_info());
+            let (conn, checkout) = self.pool.check_out().await?;
+            let conn = SqlConnection::new(conn, self.connection_info(), checkout);
 
             Ok(Box::new(conn) as Box<dyn Connection>)
         })
         .await
     }
 
+    fn pool_state(&self) -> Option<PoolState> {
+        Some(self.pool.state())
+    }
+
+    fn close(&self) {
+        self.pool.close()
+    }
+
     fn name(&self) -> String {
         "sqlite".to_owned()
     }
@@ -2,8 +2,8 @@ use crate::database::operations::*;
 use crate::SqlError;
 use async_trait::async_trait;
 use connector_interface::{
-    self as connector, filter::Filter, AggregationRow, AggregationSelection, QueryArguments, ReadOperations,
-    RecordFilter, Transaction, WriteArgs, WriteOperations,
+    self as connector, filter::Filter, AggregationRow, AggregationSelection, QueryArguments, RawQueryResult,
+    ReadOperations, RecordFilter, Transaction, WriteArgs, WriteOperations,
 };
 use prisma_models::prelude::*;
 use prisma_value::PrismaValue;
@@ -55,8 +55,12 @@ impl<'tx> ReadOperations for SqlConnectorTransaction<'tx> {
         filter: &Filter,
         selected_fields: &ModelProjection,
     ) -> connector::Result<Option<SingleRecord>> {
-        self.catch(async move { read::get_single_record(&self.inner, model, filter, selected_fields).await })
-            .await
+        let sql_family = self.connection_info.sql_family();
+
+        self.catch(
+            async move { read::get_single_record(&self.inner, sql_family, model, filter, selected_fields).await },
+        )
+        .await
     }
 
     async fn get_many_records(
@@ -65,8 +69,12 @@ impl<'tx> ReadOperations for SqlConnectorTransaction<'tx> {
         query_arguments: QueryArguments,
         selected_fields: &ModelProjection,
     ) -> connector::Result<ManyRecords> {
-        self.catch(async move { read::get_many_records(&self.inner, model, query_arguments, selected_fields).await })
-            .await
+        let sql_family = self.connection_info.sql_family();
+
+        self.catch(async move {
+            read::get_many_records(&self.inner, sql_family, model, query_arguments, selected_fields).await
+        })
+        .await
     }
 
     async fn get_related_m2m_record_ids(
@@ -78,6 +86,20 @@ impl<'tx> ReadOperations for SqlConnectorTransaction<'tx> {
             .await
     }
 
+    async fn get_recursive_related_record_ids(
+        &self,
+        field: &RelationFieldRef,
+        filter: &Filter,
+        max_depth: usize,
+    ) -> connector::Result<Vec<(RecordProjection, usize)>> {
+        let sql_family = self.connection_info.sql_family();
+
+        self.catch(async move {
+            read::get_recursive_related_record_ids(&self.inner, sql_family, field, filter, max_depth).await
+        })
+        .await
+    }
+
     async fn aggregate_records(
         &self,
         model: &ModelRef,
@@ -85,8 +107,12 @@ impl<'tx> ReadOperations for SqlConnectorTransaction<'tx> {
         group_by: Vec<ScalarFieldRef>,
         query_arguments: QueryArguments,
     ) -> connector::Result<Vec<AggregationRow>> {
-        self.catch(async move { read::aggregate(&self.inner, model, selections, group_by, query_arguments).await })
-            .await
+        let sql_family = self.connection_info.sql_family();
+
+        self.catch(async move {
+            read::aggregate(&self.inner, sql_family, model, selections, group_by, query_arguments).await
+        })
+        .await
     }
 }
 
@@ -103,12 +129,16 @@ impl<'tx> WriteOperations for SqlConnectorTransaction<'tx> {
         record_filter: RecordFilter,
         args: WriteArgs,
     ) -> connector::Result<Vec<RecordProjection>> {
-        self.catch(async move { write::update_records(&self.inner, model, record_filter, args).await })
+        let sql_family = self.connection_info.sql_family();
+
+        self.catch(async move { write::update_records(&self.inner, sql_family, model, record_filter, args).await })
             .await
     }
 
     async fn delete_records(&self, model: &ModelRef, record_filter: RecordFilter) -> connector::Result<usize> {
-        self.catch(async move { write::delete_records(&self.inner, model, record_filter).await })
+        let sql_family = self.connection_info.sql_family();
+
+        self.catch(async move { write::delete_records(&self.inner, sql_family, model, record_filter).await })
             .await
     }
 
@@ -137,7 +167,7 @@ impl<'tx> WriteOperations for SqlConnectorTransaction<'tx> {
             .await
     }
 
-    async fn query_raw(&self, query: String, parameters: Vec<PrismaValue>) -> connector::Result<serde_json::Value> {
+    async fn query_raw(&self, query: String, parameters: Vec<PrismaValue>) -> connector::Result<RawQueryResult> {
         self.catch(async move { write::query_raw(&self.inner, query, parameters).await })
             .await
     }
@@ -1,4 +1,4 @@
-use connector_interface::{error::*, Filter};
+use connector_interface::{error::*, Filter, PoolState};
 use prisma_models::prelude::DomainError;
 use quaint::error::ErrorKind as QuaintKind;
 use std::{any::Any, string::FromUtf8Error};
@@ -52,6 +52,23 @@ pub enum SqlError {
     #[error("Record does not exist.")]
     RecordDoesNotExist,
 
+    #[error(
+        "The record of the model {} was not found with the expected version {}.",
+        model_name,
+        expected_version
+    )]
+    VersionConflict {
+        model_name: String,
+        expected_version: String,
+    },
+
+    #[error(
+        "The composite field {} of a record of the model {} is null, values within it can't be updated.",
+        field_name,
+        model_name
+    )]
+    NullCompositeUpdate { model_name: String, field_name: String },
+
     #[error("Table {} does not exist", _0)]
     TableDoesNotExist(String),
 
@@ -107,6 +124,12 @@ pub enum SqlError {
 
     #[error("Database error. error code: {}, error message: {}", code, message)]
     RawError { code: String, message: String },
+
+    #[error("Timed out fetching a connection from the pool ({} connections in use).", _0.in_use)]
+    PoolTimeout(PoolState),
+
+    #[error("The connection pool has been closed.")]
+    PoolClosed,
 }
 
 impl SqlError {
@@ -127,6 +150,16 @@ impl SqlError {
                 ConnectorError::from_kind(ErrorKind::ForeignKeyConstraintViolation { constraint })
             }
             SqlError::RecordDoesNotExist => ConnectorError::from_kind(ErrorKind::RecordDoesNotExist),
+            SqlError::VersionConflict {
+                model_name,
+                expected_version,
+            } => ConnectorError::from_kind(ErrorKind::VersionConflict {
+                model_name,
+                expected_version,
+            }),
+            SqlError::NullCompositeUpdate { model_name, field_name } => {
+                ConnectorError::from_kind(ErrorKind::NullCompositeUpdate { model_name, field_name })
+            }
             SqlError::TableDoesNotExist(table) => ConnectorError::from_kind(ErrorKind::TableDoesNotExist { table }),
             SqlError::ColumnDoesNotExist(column) => ConnectorError::from_kind(ErrorKind::ColumnDoesNotExist { column }),
             SqlError::ConnectionError(e) => ConnectorError {
@@ -180,6 +213,10 @@ impl SqlError {
                 )),
                 kind: ErrorKind::RawError { code, message },
             },
+            SqlError::PoolTimeout(state) => ConnectorError::from_kind(ErrorKind::PoolTimeout { state }),
+            SqlError::PoolClosed => ConnectorError::from_kind(ErrorKind::ConnectionError(anyhow::anyhow!(
+                "The connection pool has been closed."
+            ))),
         }
     }
 }
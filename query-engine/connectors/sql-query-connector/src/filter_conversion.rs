@@ -1,6 +1,7 @@
+use crate::{full_text, json};
 use connector_interface::filter::*;
 use prisma_models::prelude::*;
-use quaint::ast::*;
+use quaint::{ast::*, connector::SqlFamily};
 
 #[derive(Clone, Copy, Debug)]
 /// A distinction in aliasing to separate the parent table and the joined data
@@ -59,8 +60,9 @@ pub trait AliasedCondition {
     /// alias if provided, otherwise using the fully qualified path.
     ///
     /// Alias should be used only when nesting, making the top level queries
-    /// more explicit.
-    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static>;
+    /// more explicit. Conditions without a common syntax, e.g. full-text search,
+    /// are built for the given SQL family.
+    fn aliased_cond(self, alias: Option<Alias>, sql_family: SqlFamily) -> ConditionTree<'static>;
 }
 
 trait AliasedSelect {
@@ -69,20 +71,20 @@ trait AliasedSelect {
     ///
     /// Alias should be used only when nesting, making the top level queries
     /// more explicit.
-    fn aliased_sel(self, alias: Option<Alias>) -> Select<'static>;
+    fn aliased_sel(self, alias: Option<Alias>, sql_family: SqlFamily) -> Select<'static>;
 }
 
 impl AliasedCondition for Filter {
     /// Conversion from a `Filter` to a query condition tree. Aliased when in a nested `SELECT`.
-    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
+    fn aliased_cond(self, alias: Option<Alias>, sql_family: SqlFamily) -> ConditionTree<'static> {
         match self {
             Filter::And(mut filters) => match filters.len() {
                 n if n == 0 => ConditionTree::NoCondition,
-                n if n == 1 => filters.pop().unwrap().aliased_cond(alias),
+                n if n == 1 => filters.pop().unwrap().aliased_cond(alias, sql_family),
                 _ => {
                     let exprs = filters
                         .into_iter()
-                        .map(|f| f.aliased_cond(alias))
+                        .map(|f| f.aliased_cond(alias, sql_family))
                         .map(Expression::from)
                         .collect();
 
@@ -91,11 +93,11 @@ impl AliasedCondition for Filter {
             },
             Filter::Or(mut filters) => match filters.len() {
                 n if n == 0 => ConditionTree::NegativeCondition,
-                n if n == 1 => filters.pop().unwrap().aliased_cond(alias),
+                n if n == 1 => filters.pop().unwrap().aliased_cond(alias, sql_family),
                 _ => {
                     let exprs = filters
                         .into_iter()
-                        .map(|f| f.aliased_cond(alias))
+                        .map(|f| f.aliased_cond(alias, sql_family))
                         .map(Expression::from)
                         .collect();
 
@@ -104,20 +106,20 @@ impl AliasedCondition for Filter {
             },
             Filter::Not(mut filters) => match filters.len() {
                 n if n == 0 => ConditionTree::NoCondition,
-                n if n == 1 => filters.pop().unwrap().aliased_cond(alias).not(),
+                n if n == 1 => filters.pop().unwrap().aliased_cond(alias, sql_family).not(),
                 _ => {
                     let exprs = filters
                         .into_iter()
-                        .map(|f| f.aliased_cond(alias).not())
+                        .map(|f| f.aliased_cond(alias, sql_family).not())
                         .map(Expression::from)
                         .collect();
 
                     ConditionTree::And(exprs)
                 }
             },
-            Filter::Scalar(filter) => filter.aliased_cond(alias),
-            Filter::OneRelationIsNull(filter) => filter.aliased_cond(alias),
-            Filter::Relation(filter) => filter.aliased_cond(alias),
+            Filter::Scalar(filter) => filter.aliased_cond(alias, sql_family),
+            Filter::OneRelationIsNull(filter) => filter.aliased_cond(alias, sql_family),
+            Filter::Relation(filter) => filter.aliased_cond(alias, sql_family),
             Filter::BoolFilter(b) => {
                 if b {
                     ConditionTree::NoCondition
@@ -133,7 +135,7 @@ impl AliasedCondition for Filter {
 
 impl AliasedCondition for ScalarFilter {
     /// Conversion from a `ScalarFilter` to a query condition tree. Aliased when in a nested `SELECT`.
-    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
+    fn aliased_cond(self, alias: Option<Alias>, sql_family: SqlFamily) -> ConditionTree<'static> {
         match (alias, self.projection) {
             (Some(alias), ScalarProjection::Single(field)) => {
                 let comparable: Expression = match self.mode {
@@ -141,7 +143,7 @@ impl AliasedCondition for ScalarFilter {
                     QueryMode::Insensitive => lower(field.as_column().table(alias.to_string(None))).into(),
                 };
 
-                convert_scalar_filter(comparable, self.condition, self.mode, &[field])
+                convert_scalar_filter(comparable, self.condition, self.mode, &[field], Some(alias), sql_family)
             }
             (Some(alias), ScalarProjection::Compound(fields)) => {
                 let columns: Vec<Column<'static>> = fields
@@ -150,7 +152,14 @@ impl AliasedCondition for ScalarFilter {
                     .map(|field| field.as_column().table(alias.to_string(None)))
                     .collect();
 
-                convert_scalar_filter(Row::from(columns), self.condition, self.mode, &fields)
+                convert_scalar_filter(
+                    Row::from(columns),
+                    self.condition,
+                    self.mode,
+                    &fields,
+                    Some(alias),
+                    sql_family,
+                )
             }
             (None, ScalarProjection::Single(field)) => {
                 let comparable: Expression = match self.mode {
@@ -158,12 +167,12 @@ impl AliasedCondition for ScalarFilter {
                     QueryMode::Insensitive => lower(field.as_column()).into(),
                 };
 
-                convert_scalar_filter(comparable, self.condition, self.mode, &[field])
+                convert_scalar_filter(comparable, self.condition, self.mode, &[field], None, sql_family)
             }
             (None, ScalarProjection::Compound(fields)) => {
                 let columns: Vec<Column<'static>> = fields.clone().into_iter().map(|field| field.as_column()).collect();
 
-                convert_scalar_filter(Row::from(columns), self.condition, self.mode, &fields)
+                convert_scalar_filter(Row::from(columns), self.condition, self.mode, &fields, None, sql_family)
             }
         }
     }
@@ -171,7 +180,7 @@ impl AliasedCondition for ScalarFilter {
 
 impl AliasedCondition for RelationFilter {
     /// Conversion from a `RelationFilter` to a query condition tree. Aliased when in a nested `SELECT`.
-    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
+    fn aliased_cond(self, alias: Option<Alias>, sql_family: SqlFamily) -> ConditionTree<'static> {
         let ids = self.field.model().primary_identifier().as_columns();
         let columns: Vec<Column<'static>> = match alias {
             Some(alias) => ids.map(|c| c.table(alias.to_string(None))).collect(),
@@ -179,7 +188,7 @@ impl AliasedCondition for RelationFilter {
         };
 
         let condition = self.condition;
-        let sub_select = self.aliased_sel(alias.map(|a| a.inc(AliasMode::Table)));
+        let sub_select = self.aliased_sel(alias.map(|a| a.inc(AliasMode::Table)), sql_family);
 
         let comparison = match condition {
             RelationCondition::AtLeastOneRelatedRecord => Row::from(columns).in_selection(sub_select),
@@ -194,7 +203,7 @@ impl AliasedCondition for RelationFilter {
 
 impl AliasedSelect for RelationFilter {
     /// The subselect part of the `RelationFilter` `ConditionTree`.
-    fn aliased_sel<'a>(self, alias: Option<Alias>) -> Select<'static> {
+    fn aliased_sel<'a>(self, alias: Option<Alias>, sql_family: SqlFamily) -> Select<'static> {
         let alias = alias.unwrap_or_default();
         let condition = self.condition;
 
@@ -222,7 +231,7 @@ impl AliasedSelect for RelationFilter {
 
         let nested_conditions = self
             .nested_filter
-            .aliased_cond(Some(alias.flip(AliasMode::Join)))
+            .aliased_cond(Some(alias.flip(AliasMode::Join)), sql_family)
             .invert_if(condition.invert_of_subselect());
 
         let conditions = selected_identifier
@@ -243,7 +252,7 @@ impl AliasedSelect for RelationFilter {
 
 impl AliasedCondition for OneRelationIsNullFilter {
     /// Conversion from a `OneRelationIsNullFilter` to a query condition tree. Aliased when in a nested `SELECT`.
-    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
+    fn aliased_cond(self, alias: Option<Alias>, _sql_family: SqlFamily) -> ConditionTree<'static> {
         let alias = alias.map(|a| a.to_string(None));
 
         let condition = if self.field.relation_is_inlined_in_parent() {
@@ -296,14 +305,31 @@ impl AliasedCondition for OneRelationIsNullFilter {
 }
 
 fn convert_scalar_filter(
-    comparable: impl Comparable<'static>,
+    comparable: impl Comparable<'static> + Into<Expression<'static>>,
     cond: ScalarCondition,
     mode: QueryMode,
     fields: &[ScalarFieldRef],
+    alias: Option<Alias>,
+    sql_family: SqlFamily,
 ) -> ConditionTree<'static> {
-    match mode {
-        QueryMode::Default => default_scalar_filter(comparable, cond, fields),
-        QueryMode::Insensitive => insensitive_scalar_filter(comparable, cond, fields),
+    match (cond, mode) {
+        // Full-text search is case-insensitive on its own.
+        (ScalarCondition::Search(value), _) => {
+            let query = format!("{}", value);
+            ConditionTree::single(full_text::search(
+                sql_family,
+                fields,
+                alias,
+                vec![comparable.into()],
+                query,
+            ))
+        }
+        (ScalarCondition::JsonContains(value), _) => {
+            let value = convert_value(fields, value).into();
+            ConditionTree::single(json::contains(sql_family, comparable.into(), value))
+        }
+        (cond, QueryMode::Default) => default_scalar_filter(comparable, cond, fields),
+        (cond, QueryMode::Insensitive) => insensitive_scalar_filter(comparable, cond, fields),
     }
 }
 
@@ -353,6 +379,8 @@ fn default_scalar_filter(
             }
             _ => comparable.not_in_selection(convert_values(fields, values)),
         },
+        ScalarCondition::Search(_) => unreachable!("Search conditions are built by `convert_scalar_filter`."),
+        ScalarCondition::JsonContains(_) => unreachable!("JSON conditions are built by `convert_scalar_filter`."),
     };
 
     ConditionTree::single(condition)
@@ -424,6 +452,8 @@ fn insensitive_scalar_filter(
                     .collect::<Vec<_>>(),
             ),
         },
+        ScalarCondition::Search(_) => unreachable!("Search conditions are built by `convert_scalar_filter`."),
+        ScalarCondition::JsonContains(_) => unreachable!("JSON conditions are built by `convert_scalar_filter`."),
     };
 
     ConditionTree::single(condition)
@@ -0,0 +1,151 @@
+//! Full-text search conditions and relevance orderings, built for the SQL family of the
+//! connector:
+//!
+//! - Postgres: `to_tsvector('simple', <column>) @@ to_tsquery('simple', <query>)`, ranked with
+//!   `ts_rank` over the concatenated columns.
+//! - MySQL: `MATCH (<columns>) AGAINST (<query> IN BOOLEAN MODE)`, used for ranking as well.
+//!   MySQL requires a full-text index covering exactly the matched columns.
+//! - SQLite: the FTS5 table of a full-text index covering the columns, restricted to them with
+//!   a column filter: `rowid IN (SELECT rowid FROM <index> WHERE <index> MATCH '{<columns>} : (<query>)')`,
+//!   ranked with the `rank` of the matching row. Without such an index, the query fails on the
+//!   missing FTS5 table, as MySQL fails on the missing index.
+//!
+//! Other databases don't have the `FullTextSearch` capability, so the query schema never
+//! produces search conditions or relevance orderings for them.
+use crate::{functions, Alias};
+use prisma_models::prelude::*;
+use quaint::{ast::*, connector::SqlFamily};
+
+/// The condition matching the `columns` of the `fields` against the search `query`. The
+/// `alias` is the one of the table of the fields in a nested `SELECT`, if any.
+pub(crate) fn search<'a>(
+    sql_family: SqlFamily,
+    fields: &[ScalarFieldRef],
+    alias: Option<Alias>,
+    columns: Vec<Expression<'a>>,
+    query: String,
+) -> Expression<'a> {
+    match sql_family {
+        SqlFamily::Postgres => functions::infix(
+            functions::call("to_tsvector", vec![configuration(), concatenated(columns)]),
+            "@@",
+            functions::call("to_tsquery", vec![configuration(), Value::text(query).into()]),
+        ),
+        SqlFamily::Mysql => match_against(columns, query),
+        SqlFamily::Sqlite => {
+            let fts_table = fts_table_name(fields);
+            let matches = Select::from_table(fts_table.clone())
+                .column("rowid")
+                .so_that(fts_match(fts_table, fields, query));
+
+            rowid(fields, alias).in_selection(matches).into()
+        }
+        _ => unreachable!("Full-text search is only supported on PostgreSQL, MySQL and SQLite."),
+    }
+}
+
+/// The relevance of the `columns` of the `fields` for the search `query`, for ordering.
+pub(crate) fn relevance<'a>(
+    sql_family: SqlFamily,
+    fields: &[ScalarFieldRef],
+    columns: Vec<Expression<'a>>,
+    query: String,
+) -> Expression<'a> {
+    match sql_family {
+        SqlFamily::Postgres => functions::call(
+            "ts_rank",
+            vec![
+                functions::call("to_tsvector", vec![configuration(), concatenated(columns)]),
+                functions::call("to_tsquery", vec![configuration(), Value::text(query).into()]),
+            ],
+        ),
+        SqlFamily::Mysql => match_against(columns, query),
+        // The rank is lower for better matches, and NULL for rows that don't match.
+        SqlFamily::Sqlite => {
+            let fts_table = fts_table_name(fields);
+            let relevance = functions::infix(functions::keyword("0"), "-", functions::keyword("rank"));
+            let conditions =
+                fts_match(fts_table.clone(), fields, query).and(Column::from("rowid").equals(rowid(fields, None)));
+
+            Select::from_table(fts_table)
+                .value(relevance)
+                .so_that(conditions)
+                .into()
+        }
+        _ => unreachable!("Full-text search is only supported on PostgreSQL, MySQL and SQLite."),
+    }
+}
+
+/// The FTS5 table of the full-text index covering all of the `fields`. The table of an index
+/// has the name of the index.
+fn fts_table_name(fields: &[ScalarFieldRef]) -> String {
+    let model = fields.first().expect("Full-text search without fields").model();
+
+    let index = model.indexes().iter().find(|index| {
+        index.typ == IndexType::Fulltext
+            && fields
+                .iter()
+                .all(|field| index.fields().iter().any(|indexed| indexed.name == field.name))
+    });
+
+    match index {
+        Some(index) => index.db_name.clone(),
+        // The name the index would have by default.
+        None => format!(
+            "{}.{}_fulltext",
+            model.db_name(),
+            fields.iter().map(|field| field.db_name()).collect::<Vec<_>>().join("_")
+        ),
+    }
+}
+
+/// `<fts_table> MATCH '{"<column>" ...} : (<query>)'`, matching only the columns of the `fields`.
+fn fts_match(fts_table: String, fields: &[ScalarFieldRef], query: String) -> ConditionTree<'static> {
+    let columns: Vec<String> = fields
+        .iter()
+        .map(|field| format!("\"{}\"", field.db_name().replace('"', "\"\"")))
+        .collect();
+    let query = format!("{{{}}} : ({})", columns.join(" "), query);
+
+    ConditionTree::single(Column::from(fts_table).compare_raw("MATCH", Value::text(query)))
+}
+
+/// The `rowid` column of the table of the `fields`, the FTS5 tables index the rows by it.
+fn rowid(fields: &[ScalarFieldRef], alias: Option<Alias>) -> Column<'static> {
+    let column = Column::from("rowid");
+    let model = fields.first().expect("Full-text search without fields").model();
+
+    match alias {
+        Some(alias) => column.table(alias.to_string(None)),
+        None => column.table(model.as_table()),
+    }
+}
+
+/// The Postgres text search configuration. `simple` doesn't stem words or drop stop words, so
+/// it works the same for all languages.
+fn configuration<'a>() -> Expression<'a> {
+    Value::text("simple").raw().into()
+}
+
+/// The columns joined with spaces, so several columns can be searched as one document.
+fn concatenated(mut columns: Vec<Expression<'_>>) -> Expression<'_> {
+    if columns.len() == 1 {
+        return columns.pop().unwrap();
+    }
+
+    let mut args = vec![Value::text(" ").raw().into()];
+    args.extend(columns);
+
+    functions::call("concat_ws", args)
+}
+
+/// `MATCH (<columns>) AGAINST (<query> IN BOOLEAN MODE)`.
+fn match_against(columns: Vec<Expression<'_>>, query: String) -> Expression<'_> {
+    let against = functions::infix(Value::text(query).into(), "IN BOOLEAN", functions::keyword("MODE"));
+
+    functions::infix(
+        functions::call("MATCH", columns),
+        "AGAINST",
+        Row::from(vec![against]).into(),
+    )
+}
@@ -0,0 +1,70 @@
+//! Calls of database functions quaint has no expression for, e.g. `array_append`, `jsonb_set`
+//! or `MATCH ... AGAINST`.
+//!
+//! quaint only has expressions for a fixed set of functions, so other calls are built from raw
+//! comparisons, which quaint renders as `left operator right`. The function name and its
+//! opening parenthesis are a single operator, as some databases don't allow whitespace in
+//! between, e.g. MySQL for `CAST`. The arguments stay regular expressions, so values are still
+//! passed as parameters of the query.
+use quaint::ast::*;
+use std::borrow::Cow;
+
+/// A call of the function `name` with the given arguments, rendered as `name( arg , ... )`.
+pub(crate) fn call<'a>(name: &'static str, args: Vec<Expression<'a>>) -> Expression<'a> {
+    let mut args = args.into_iter();
+    let opening = format!("{}(", name);
+
+    let call = match args.next() {
+        Some(first) => {
+            let call = infix(keyword(""), opening, first);
+            args.fold(call, |call, arg| infix(call, ",", arg))
+        }
+        None => infix(keyword(""), opening, keyword("")),
+    };
+
+    infix(call, ")", keyword(""))
+}
+
+/// A keyword or function name rendered verbatim.
+pub(crate) fn keyword<'a>(keyword: &'static str) -> Expression<'a> {
+    Value::Enum(Some(keyword.into())).raw().into()
+}
+
+/// `left` followed by `right`, with the given operator or keyword in between.
+pub(crate) fn infix<'a>(
+    left: Expression<'a>,
+    operator: impl Into<Cow<'static, str>>,
+    right: Expression<'a>,
+) -> Expression<'a> {
+    let operator: Cow<'a, str> = operator.into();
+    left.compare_raw(operator, right).into()
+}
+
+/// `CAST(expr AS type)`.
+pub(crate) fn cast<'a>(expr: Expression<'a>, typ: &'static str) -> Expression<'a> {
+    call("CAST", vec![infix(expr, "AS", keyword(typ))])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quaint::visitor::{self, Visitor};
+
+    #[test]
+    fn function_names_are_directly_followed_by_the_parenthesis() {
+        let select = Select::default().value(call(
+            "JSON_SET",
+            vec![keyword("doc"), cast(Value::text("1").into(), "JSON")],
+        ));
+        let (sql, params) = visitor::Mysql::build(select).unwrap();
+
+        assert!(sql.contains("JSON_SET( doc"), "{}", sql);
+        assert!(sql.contains("CAST( ? AS JSON )"), "{}", sql);
+        assert_eq!(vec![Value::text("1")], params);
+
+        let select = Select::default().value(call("JSON_ARRAY", vec![]));
+        let (sql, _) = visitor::Mysql::build(select).unwrap();
+
+        assert!(sql.contains("JSON_ARRAY("), "{}", sql);
+    }
+}
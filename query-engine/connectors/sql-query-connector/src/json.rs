@@ -0,0 +1,71 @@
+//! JSON functions for composite type fields, which are stored as JSON documents:
+//!
+//! - Postgres: `jsonb` columns, with the `@>`, `||` and `#>>` operators and `jsonb_set`.
+//! - MySQL: `JSON` columns, with `JSON_CONTAINS`, `JSON_MERGE_PRESERVE`, `JSON_SET` and `JSON_EXTRACT`.
+//!
+//! Values are passed as parameters and cast to JSON in the statement.
+use crate::functions::{self, call, cast};
+use quaint::{ast::*, connector::SqlFamily};
+
+/// The condition that the document in `column` contains the document `value`.
+pub(crate) fn contains<'a>(sql_family: SqlFamily, column: Expression<'a>, value: Expression<'a>) -> Expression<'a> {
+    match sql_family {
+        SqlFamily::Mysql => call("JSON_CONTAINS", vec![column, value]),
+        _ => functions::infix(column, "@>", cast(value, "jsonb")),
+    }
+}
+
+/// The array in `column` with the elements of the array `value` appended. A `NULL` column is
+/// treated as an empty array.
+pub(crate) fn append<'a>(sql_family: SqlFamily, column: Expression<'a>, value: Expression<'a>) -> Expression<'a> {
+    match sql_family {
+        SqlFamily::Mysql => {
+            let array = call("COALESCE", vec![column, call("JSON_ARRAY", vec![])]);
+            call("JSON_MERGE_PRESERVE", vec![array, cast(value, "JSON")])
+        }
+        _ => {
+            let array = call("COALESCE", vec![column, cast(Value::text("[]").raw().into(), "jsonb")]);
+            functions::infix(array, "||", cast(value, "jsonb"))
+        }
+    }
+}
+
+/// The document in `document` with the value at `path` replaced by `value`. The value of a
+/// `NULL` document stays `NULL`.
+pub(crate) fn set<'a>(
+    sql_family: SqlFamily,
+    document: Expression<'a>,
+    path: &[String],
+    value: Expression<'a>,
+) -> Expression<'a> {
+    match sql_family {
+        SqlFamily::Mysql => {
+            let path = Value::text(format!("$.{}", path.join("."))).into();
+            call("JSON_SET", vec![document, path, cast(value, "JSON")])
+        }
+        _ => {
+            // The path is made of field names, so it can be inlined as a text array literal.
+            let path = Value::text(format!("{{{}}}", path.join(","))).raw().into();
+            call("jsonb_set", vec![document, path, cast(value, "jsonb")])
+        }
+    }
+}
+
+/// The condition that the value at `path` within the document in `document` is `null` or missing.
+pub(crate) fn is_null<'a>(sql_family: SqlFamily, document: Expression<'a>, path: &[String]) -> Expression<'a> {
+    match sql_family {
+        SqlFamily::Mysql => {
+            let path = Value::text(format!("$.{}", path.join("."))).into();
+            let tpe = call("JSON_TYPE", vec![call("JSON_EXTRACT", vec![document, path])]);
+
+            call("COALESCE", vec![tpe, Value::text("NULL").into()])
+                .equals(Value::text("NULL"))
+                .into()
+        }
+        _ => {
+            // `#>>` extracts the value as text, so a JSON `null` is `NULL` as well.
+            let path = Value::text(format!("{{{}}}", path.join(","))).raw().into();
+            functions::infix(document, "#>>", path).is_null().into()
+        }
+    }
+}
@@ -2,6 +2,9 @@ mod cursor_condition;
 mod database;
 mod error;
 mod filter_conversion;
+mod full_text;
+mod functions;
+mod json;
 mod ordering;
 mod query_arguments_ext;
 mod query_builder;
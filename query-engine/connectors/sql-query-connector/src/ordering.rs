@@ -1,13 +1,26 @@
-use crate::query_arguments_ext::QueryArgumentsExt;
+use crate::{full_text, query_arguments_ext::QueryArgumentsExt};
 use connector_interface::QueryArguments;
 use prisma_models::*;
-use quaint::ast::*;
+use quaint::{ast::*, connector::SqlFamily};
 
 /// Builds all expressions for an `ORDER BY` clause based on the query arguments.
-pub fn build(query_arguments: &QueryArguments) -> Vec<OrderDefinition<'static>> {
+pub fn build(query_arguments: &QueryArguments, sql_family: SqlFamily) -> Vec<OrderDefinition<'static>> {
     let needs_reversed_order = query_arguments.needs_reversed_order();
 
-    query_arguments.order_by.iter().fold(vec![], |mut acc, next_order_by| {
+    let initial = match &query_arguments.order_by_relevance {
+        Some(relevance) => {
+            let columns = relevance.fields.iter().map(|field| field.as_column().into()).collect();
+            let rank = full_text::relevance(sql_family, &relevance.fields, columns, relevance.search.clone());
+
+            match (relevance.sort_order, needs_reversed_order) {
+                (SortOrder::Ascending, true) | (SortOrder::Descending, false) => vec![rank.descend()],
+                (SortOrder::Descending, true) | (SortOrder::Ascending, false) => vec![rank.ascend()],
+            }
+        }
+        None => vec![],
+    };
+
+    query_arguments.order_by.iter().fold(initial, |mut acc, next_order_by| {
         match (next_order_by.sort_order, needs_reversed_order) {
             (SortOrder::Ascending, true) => acc.push(next_order_by.field.as_column().descend()),
             (SortOrder::Descending, true) => acc.push(next_order_by.field.as_column().ascend()),
@@ -2,42 +2,46 @@ use crate::{cursor_condition, filter_conversion::AliasedCondition, ordering};
 use connector_interface::{filter::Filter, AggregationSelection, QueryArguments};
 use itertools::Itertools;
 use prisma_models::*;
-use quaint::ast::*;
+use quaint::{
+    ast::*,
+    connector::SqlFamily,
+    visitor::{self, Visitor},
+};
 
 pub trait SelectDefinition {
-    fn into_select(self, _: &ModelRef) -> Select<'static>;
+    fn into_select(self, _: &ModelRef, _: SqlFamily) -> Select<'static>;
 }
 
 impl SelectDefinition for Filter {
-    fn into_select(self, model: &ModelRef) -> Select<'static> {
+    fn into_select(self, model: &ModelRef, sql_family: SqlFamily) -> Select<'static> {
         let args = QueryArguments::from((model.clone(), self));
-        args.into_select(model)
+        args.into_select(model, sql_family)
     }
 }
 
 impl SelectDefinition for &Filter {
-    fn into_select(self, model: &ModelRef) -> Select<'static> {
-        self.clone().into_select(model)
+    fn into_select(self, model: &ModelRef, sql_family: SqlFamily) -> Select<'static> {
+        self.clone().into_select(model, sql_family)
     }
 }
 
 impl SelectDefinition for Select<'static> {
-    fn into_select(self, _: &ModelRef) -> Select<'static> {
+    fn into_select(self, _: &ModelRef, _: SqlFamily) -> Select<'static> {
         self
     }
 }
 
 impl SelectDefinition for QueryArguments {
-    fn into_select(self, model: &ModelRef) -> Select<'static> {
+    fn into_select(self, model: &ModelRef, sql_family: SqlFamily) -> Select<'static> {
         let (table_opt, cursor_condition) = cursor_condition::build(&self, &model);
-        let orderings = ordering::build(&self);
+        let orderings = ordering::build(&self, sql_family);
 
         let limit = if self.ignore_take { None } else { self.take_abs() };
         let skip = if self.ignore_skip { 0 } else { self.skip.unwrap_or(0) };
 
         let filter: ConditionTree = self
             .filter
-            .map(|f| f.aliased_cond(None))
+            .map(|f| f.aliased_cond(None, sql_family))
             .unwrap_or(ConditionTree::NoCondition);
 
         let conditions = match (filter, cursor_condition) {
@@ -65,11 +69,16 @@ impl SelectDefinition for QueryArguments {
     }
 }
 
-pub fn get_records<T>(model: &ModelRef, columns: impl Iterator<Item = Column<'static>>, query: T) -> Select<'static>
+pub fn get_records<T>(
+    sql_family: SqlFamily,
+    model: &ModelRef,
+    columns: impl Iterator<Item = Column<'static>>,
+    query: T,
+) -> Select<'static>
 where
     T: SelectDefinition,
 {
-    columns.fold(query.into_select(model), |acc, col| acc.column(col))
+    columns.fold(query.into_select(model, sql_family), |acc, col| acc.column(col))
 }
 
 /// Generates a query of the form:
@@ -98,9 +107,14 @@ where
 /// ```
 /// Important note: Do not use the AsColumn trait here as we need to construct column references that are relative,
 /// not absolute - e.g. `SELECT "field" FROM (...)` NOT `SELECT "full"."path"."to"."field" FROM (...)`.
-pub fn aggregate(model: &ModelRef, selections: &[AggregationSelection], args: QueryArguments) -> Select<'static> {
+pub fn aggregate(
+    sql_family: SqlFamily,
+    model: &ModelRef,
+    selections: &[AggregationSelection],
+    args: QueryArguments,
+) -> Select<'static> {
     let columns = extract_columns(model, &selections);
-    let sub_query = get_records(model, columns.into_iter(), args);
+    let sub_query = get_records(sql_family, model, columns.into_iter(), args);
     let sub_table = Table::from(sub_query).alias("sub");
 
     selections
@@ -139,12 +153,13 @@ pub fn aggregate(model: &ModelRef, selections: &[AggregationSelection], args: Qu
 }
 
 pub fn group_by_aggregate(
+    sql_family: SqlFamily,
     model: &ModelRef,
     group_by: Vec<ScalarFieldRef>,
     selections: &[AggregationSelection],
     args: QueryArguments,
 ) -> Select<'static> {
-    let base_query: Select = args.into_select(model);
+    let base_query: Select = args.into_select(model, sql_family);
 
     let select_query = selections.iter().fold(base_query, |select, next_op| match next_op {
         AggregationSelection::Field(field) => select.column(field.as_column()),
@@ -205,3 +220,105 @@ fn extract_columns(model: &ModelRef, selections: &[AggregationSelection]) -> Vec
 
     fields.as_columns().collect()
 }
+
+/// Name of the common table expression holding the records reached by a recursive traversal.
+const TRAVERSAL_TABLE: &str = "prisma_traversal";
+
+/// Generates a query traversing a self-relation recursively, of the form:
+/// ```sql
+/// WITH RECURSIVE prisma_traversal (id_0, link_0, depth) AS (
+///     SELECT prisma_start.*, 0 FROM (
+///         SELECT `Category`.`id` AS `id_0`, `Category`.`id` AS `link_0` FROM `Category` WHERE `Category`.`id` = ?
+///     ) AS prisma_start
+///     UNION ALL
+///     SELECT prisma_next.`id`, prisma_next.`id`, prisma_traversal.depth + 1
+///     FROM `Category` AS prisma_next
+///     INNER JOIN prisma_traversal ON prisma_next.`parentId` = prisma_traversal.link_0
+///     WHERE prisma_traversal.depth < 10
+/// )
+/// SELECT id_0, MIN(depth) FROM prisma_traversal WHERE depth > 0 GROUP BY id_0 ORDER BY MIN(depth), id_0
+/// ```
+/// The `link` columns of a reached record hold the values the next records of the traversal are joined on.
+/// The query AST has no support for common table expressions, so only the starting select is built with it.
+pub fn recursive_related_ids(
+    sql_family: SqlFamily,
+    field: &RelationFieldRef,
+    filter: Filter,
+    max_depth: usize,
+) -> crate::Result<(String, Vec<Value<'static>>)> {
+    let model = field.model();
+    let id_columns: Vec<Column<'static>> = model.primary_identifier().as_columns().collect();
+    let link_columns: Vec<Column<'static>> = field.linking_fields().as_columns().collect();
+    let next_columns: Vec<Column<'static>> = field.related_field().linking_fields().as_columns().collect();
+
+    let id_names: Vec<String> = (0..id_columns.len()).map(|i| format!("id_{}", i)).collect();
+    let link_names: Vec<String> = (0..link_columns.len()).map(|i| format!("link_{}", i)).collect();
+
+    let start_columns = id_columns
+        .iter()
+        .zip(id_names.iter())
+        .chain(link_columns.iter().zip(link_names.iter()))
+        .map(|(column, name)| column.clone().alias(name.clone()));
+
+    let start = Select::from_table(model.as_table())
+        .columns(start_columns)
+        .so_that(filter.aliased_cond(None, sql_family));
+
+    let (start, params) = match sql_family {
+        SqlFamily::Postgres => visitor::Postgres::build(start)?,
+        SqlFamily::Mysql => visitor::Mysql::build(start)?,
+        SqlFamily::Sqlite => visitor::Sqlite::build(start)?,
+        SqlFamily::Mssql => visitor::Mssql::build(start)?,
+    };
+
+    let quote = |name: &str| match sql_family {
+        SqlFamily::Mysql => format!("`{}`", name.replace('`', "``")),
+        SqlFamily::Mssql => format!("[{}]", name.replace(']', "]]")),
+        _ => format!("\"{}\"", name.replace('"', "\"\"")),
+    };
+
+    let table = format!("{}.{}", quote(&model.db_schema_name()), quote(model.db_name()));
+
+    let next = id_columns
+        .iter()
+        .chain(link_columns.iter())
+        .map(|column| format!("prisma_next.{}", quote(&column.name)))
+        .join(", ");
+
+    let join = next_columns
+        .iter()
+        .zip(link_names.iter())
+        .map(|(column, link)| format!("prisma_next.{} = {}.{}", quote(&column.name), TRAVERSAL_TABLE, link))
+        .join(" AND ");
+
+    let ids = id_names.join(", ");
+    let columns = id_names.iter().chain(link_names.iter()).join(", ");
+
+    // SQL Server has no `RECURSIVE` keyword, and limits recursion to 100 levels unless told otherwise.
+    let (with, options) = match sql_family {
+        SqlFamily::Mssql => ("WITH", " OPTION (MAXRECURSION 0)"),
+        _ => ("WITH RECURSIVE", ""),
+    };
+
+    let sql = format!(
+        "{with} {cte} ({columns}, depth) AS (\
+         SELECT prisma_start.*, 0 FROM ({start}) AS prisma_start \
+         UNION ALL \
+         SELECT {next}, {cte}.depth + 1 FROM {table} AS prisma_next \
+         INNER JOIN {cte} ON {join} \
+         WHERE {cte}.depth < {max_depth}) \
+         SELECT {ids}, MIN(depth) FROM {cte} WHERE depth > 0 GROUP BY {ids} ORDER BY MIN(depth), {ids}{options}",
+        with = with,
+        cte = TRAVERSAL_TABLE,
+        columns = columns,
+        start = start,
+        next = next,
+        table = table,
+        join = join,
+        max_depth = max_depth,
+        ids = ids,
+        options = options,
+    );
+
+    Ok((sql, params))
+}
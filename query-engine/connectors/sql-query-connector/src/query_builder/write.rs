@@ -1,6 +1,7 @@
+use crate::{functions, json};
 use connector_interface::{DatasourceFieldName, WriteArgs, WriteExpression};
 use prisma_models::*;
-use quaint::ast::*;
+use quaint::{ast::*, connector::SqlFamily};
 use std::convert::TryInto;
 
 /// `INSERT` a new record to the database. Resulting an `INSERT` ast and an
@@ -33,13 +34,25 @@ pub fn create_record(model: &ModelRef, mut args: WriteArgs) -> (Insert<'static>,
     )
 }
 
-pub fn update_many(model: &ModelRef, ids: &[&RecordProjection], args: WriteArgs) -> crate::Result<Vec<Query<'static>>> {
+pub fn update_many(
+    sql_family: SqlFamily,
+    model: &ModelRef,
+    ids: &[&RecordProjection],
+    args: WriteArgs,
+) -> crate::Result<Vec<Query<'static>>> {
     if args.args.is_empty() || ids.is_empty() {
         return Ok(Vec::new());
     }
 
     let scalar_fields = model.fields().scalar();
 
+    // Only records still having the expected version are updated, the version could have changed
+    // since the records to update were selected.
+    let version_check = match (model.fields().version(), args.expected_version) {
+        (Some(field), Some(expected_version)) => Some(field.as_column().equals(field.value(expected_version))),
+        _ => None,
+    };
+
     let query = args
         .args
         .into_iter()
@@ -72,17 +85,91 @@ pub fn update_many(model: &ModelRef, ids: &[&RecordProjection], args: WriteArgs)
                     let e: Expression<'_> = Column::from(name.clone()).into();
                     e / field.value(rhs).into()
                 }
+
+                // Composite type fields are stored as JSON, lists of composites as JSON arrays.
+                WriteExpression::Push(value) if field.is_composite() => {
+                    json::append(sql_family, Column::from(name.clone()).into(), field.value(value).into())
+                }
+
+                // Updates within `NULL` composites are rejected beforehand, see `null_composites`.
+                WriteExpression::UpdateComposite(sets) => sets
+                    .into_iter()
+                    .fold(Column::from(name.clone()).into(), |e: Expression<'_>, (path, value)| {
+                        json::set(sql_family, e, &path, field.value(value).into())
+                    }),
+
+                WriteExpression::Push(PrismaValue::List(values)) => {
+                    let e: Expression<'_> = Column::from(name.clone()).into();
+                    functions::call("array_cat", vec![e, field.value(PrismaValue::List(values)).into()])
+                }
+
+                WriteExpression::Push(value) => {
+                    let e: Expression<'_> = Column::from(name.clone()).into();
+                    functions::call("array_append", vec![e, field.value(value).into()])
+                }
+
+                WriteExpression::Remove(value) => {
+                    let values = match value {
+                        PrismaValue::List(values) => values,
+                        value => vec![value],
+                    };
+
+                    values
+                        .into_iter()
+                        .fold(Column::from(name.clone()).into(), |e: Expression<'_>, value| {
+                            functions::call("array_remove", vec![e, field.value(value).into()])
+                        })
+                }
             };
 
             acc.set(name, value)
         });
 
     let columns: Vec<_> = model.primary_identifier().as_columns().collect();
-    let result: Vec<Query> = super::chunked_conditions(&columns, ids, |conditions| query.clone().so_that(conditions));
+    let result: Vec<Query> = super::chunked_conditions(&columns, ids, |conditions| match version_check.clone() {
+        Some(version_check) => query.clone().so_that(conditions.and(version_check)),
+        None => query.clone().so_that(conditions),
+    });
 
     Ok(result)
 }
 
+/// Selects the ids of the records in `ids` where the composite `field`, or a composite within it
+/// that `updates` change values in, is `NULL`. Values within such a composite can't be updated,
+/// the whole composite has to be set instead.
+pub fn null_composites(
+    sql_family: SqlFamily,
+    model: &ModelRef,
+    ids: &[&RecordProjection],
+    field: &ScalarFieldRef,
+    updates: &[(Vec<String>, PrismaValue)],
+) -> Vec<Query<'static>> {
+    // Every proper prefix of an update path is a composite that is updated, not set.
+    let mut paths: Vec<&[String]> = updates
+        .iter()
+        .flat_map(|(path, _)| (0..path.len()).map(move |len| &path[..len]))
+        .collect();
+
+    paths.sort();
+    paths.dedup();
+
+    let nulls: Vec<Expression<'static>> = paths
+        .into_iter()
+        .map(|path| match path {
+            [] => field.as_column().is_null().into(),
+            path => json::is_null(sql_family, field.as_column().into(), path),
+        })
+        .collect();
+
+    let columns: Vec<_> = model.primary_identifier().as_columns().collect();
+
+    super::chunked_conditions(&columns, ids, |conditions| {
+        Select::from_table(model.as_table())
+            .columns(columns.clone())
+            .so_that(conditions.and(ConditionTree::Or(nulls.clone())))
+    })
+}
+
 pub fn delete_many(model: &ModelRef, ids: &[&RecordProjection]) -> Vec<Query<'static>> {
     let columns: Vec<_> = model.primary_identifier().as_columns().collect();
 
@@ -1,16 +1,15 @@
-use crate::{error::*, AliasedCondition, SqlRow, ToSqlRow};
+use crate::{error::*, raw_column_type, raw_value_to_prisma_value, AliasedCondition, SqlRow, ToSqlRow};
 use async_trait::async_trait;
-use connector_interface::{filter::Filter, RecordFilter};
+use connector_interface::{filter::Filter, RawColumnType, RawQueryResult, RecordFilter};
 use datamodel::FieldArity;
 use futures::future::FutureExt;
 use prisma_models::*;
 use quaint::{
     ast::*,
-    connector::{self, Queryable},
+    connector::{self, Queryable, SqlFamily},
     pooled::PooledConnection,
 };
 
-use serde_json::{Map, Value};
 use std::{convert::TryFrom, panic::AssertUnwindSafe};
 
 impl<'t> QueryExt for connector::Transaction<'t> {}
@@ -32,31 +31,52 @@ pub trait QueryExt: Queryable + Send + Sync {
         Ok(sql_rows)
     }
 
-    /// Execute a singular SQL query in the database, returning an arbitrary
-    /// JSON `Value` as a result.
-    async fn raw_json<'a>(
-        &'a self,
-        q: String,
-        params: Vec<PrismaValue>,
-    ) -> std::result::Result<Value, crate::error::RawError> {
+    /// Execute a singular SQL query in the database, returning the rows
+    /// together with the types of their columns. The columns of an empty
+    /// result are of unknown type.
+    async fn raw_result<'a>(&'a self, q: String, params: Vec<PrismaValue>) -> crate::Result<RawQueryResult> {
         let params: Vec<_> = params.into_iter().map(convert_lossy).collect();
-        let result_set = AssertUnwindSafe(self.query_raw(&q, &params)).catch_unwind().await??;
+        let result_set = AssertUnwindSafe(self.query_raw(&q, &params))
+            .catch_unwind()
+            .await
+            .map_err(RawError::from)?
+            .map_err(RawError::from)?;
 
         let columns: Vec<String> = result_set.columns().iter().map(ToString::to_string).collect();
-        let mut result = Vec::new();
+        let mut column_types = vec![RawColumnType::Unknown; columns.len()];
+        let mut typed_columns = vec![false; columns.len()];
+        let mut rows = Vec::new();
 
         for row in result_set.into_iter() {
-            let mut object = Map::new();
-
-            for (idx, p_value) in row.into_iter().enumerate() {
-                let column_name: String = columns[idx].clone();
-                object.insert(column_name, Value::from(p_value));
+            let values: Vec<Value<'_>> = row.into_iter().collect();
+
+            // Quaint doesn't expose the column types of the statement, but decodes the values
+            // into the variants of their column types, nulls included. Expressions without a
+            // declared type decode nulls as integers on SQLite though, so a column is only typed
+            // for good by a non-null value, and the type of a null is kept until one is found.
+            for (idx, value) in values.iter().enumerate() {
+                if typed_columns[idx] {
+                    continue;
+                }
+
+                let column_type = raw_column_type(value);
+                typed_columns[idx] = !value.is_null() && column_type.is_known();
+                column_types[idx] = column_type;
             }
 
-            result.push(Value::Object(object));
+            rows.push(
+                values
+                    .into_iter()
+                    .map(raw_value_to_prisma_value)
+                    .collect::<crate::Result<Vec<_>>>()?,
+            );
         }
 
-        Ok(Value::Array(result))
+        Ok(RawQueryResult {
+            columns,
+            column_types,
+            rows,
+        })
     }
 
     /// Execute a singular SQL query in the database, returning the number of
@@ -104,27 +124,38 @@ pub trait QueryExt: Queryable + Send + Sync {
         &self,
         model: &ModelRef,
         record_filter: RecordFilter,
+        sql_family: SqlFamily,
     ) -> crate::Result<Vec<RecordProjection>> {
         if let Some(selectors) = record_filter.selectors {
             Ok(selectors)
         } else {
-            self.filter_ids(model, record_filter.filter).await
+            self.filter_ids(model, record_filter.filter, sql_family).await
         }
     }
 
     /// Read the all columns as a (primary) identifier.
-    async fn filter_ids(&self, model: &ModelRef, filter: Filter) -> crate::Result<Vec<RecordProjection>> {
+    async fn filter_ids(
+        &self,
+        model: &ModelRef,
+        filter: Filter,
+        sql_family: SqlFamily,
+    ) -> crate::Result<Vec<RecordProjection>> {
         let model_id = model.primary_identifier();
         let id_cols: Vec<Column<'static>> = model_id.as_columns().collect();
 
         let select = Select::from_table(model.as_table())
             .columns(id_cols)
-            .so_that(filter.aliased_cond(None));
+            .so_that(filter.aliased_cond(None, sql_family));
 
-        self.select_ids(select, model_id).await
+        self.select_ids(select, model_id, sql_family).await
     }
 
-    async fn select_ids(&self, select: Select<'_>, model_id: ModelProjection) -> crate::Result<Vec<RecordProjection>> {
+    async fn select_ids(
+        &self,
+        select: Select<'_>,
+        model_id: ModelProjection,
+        sql_family: SqlFamily,
+    ) -> crate::Result<Vec<RecordProjection>> {
         let idents: Vec<_> = model_id
             .fields()
             .into_iter()
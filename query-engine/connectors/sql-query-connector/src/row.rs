@@ -1,7 +1,7 @@
 use crate::error::SqlError;
 use bigdecimal::{BigDecimal, FromPrimitive};
 use chrono::{DateTime, NaiveDate, Utc};
-use connector_interface::{AggregationResult, AggregationSelection};
+use connector_interface::{AggregationResult, AggregationSelection, RawColumnType};
 use datamodel::FieldArity;
 use prisma_models::{PrismaValue, Record, TypeIdentifier};
 use quaint::{
@@ -306,6 +306,52 @@ pub fn row_value_to_prisma_value(p_value: Value, type_identifier: &TypeIdentifie
     })
 }
 
+/// The type of a column in a raw query result, derived from the variant the
+/// driver decoded its value into. Quaint reads all integers as 64-bit values.
+pub fn raw_column_type(value: &Value) -> RawColumnType {
+    match value {
+        Value::Integer(_) => RawColumnType::BigInt,
+        Value::Float(_) => RawColumnType::Float,
+        Value::Double(_) => RawColumnType::Double,
+        Value::Numeric(_) => RawColumnType::Decimal,
+        Value::Text(_) | Value::Char(_) => RawColumnType::String,
+        Value::Enum(_) => RawColumnType::Enum,
+        Value::Boolean(_) => RawColumnType::Boolean,
+        Value::Bytes(_) => RawColumnType::Bytes,
+        Value::Json(_) => RawColumnType::Json,
+        Value::Xml(_) => RawColumnType::Xml,
+        Value::Uuid(_) => RawColumnType::Uuid,
+        Value::DateTime(_) => RawColumnType::DateTime,
+        Value::Date(_) => RawColumnType::Date,
+        Value::Time(_) => RawColumnType::Time,
+        Value::Array(values) => {
+            let inner = values
+                .as_ref()
+                .and_then(|values| values.first())
+                .map(raw_column_type)
+                .unwrap_or(RawColumnType::Unknown);
+
+            RawColumnType::Array(Box::new(inner))
+        }
+    }
+}
+
+/// Converts a value of a raw query result. Unlike the plain conversion, integers
+/// are kept as `BigInt` and bytes aren't interpreted as strings.
+pub fn raw_value_to_prisma_value(value: Value) -> crate::Result<PrismaValue> {
+    Ok(match value {
+        Value::Integer(Some(i)) => PrismaValue::BigInt(i),
+        Value::Bytes(Some(bytes)) => PrismaValue::Bytes(bytes.into_owned()),
+        Value::Array(Some(values)) => PrismaValue::List(
+            values
+                .into_iter()
+                .map(raw_value_to_prisma_value)
+                .collect::<crate::Result<Vec<_>>>()?,
+        ),
+        other => PrismaValue::try_from(other)?,
+    })
+}
+
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub enum SqlId {
     String(String),
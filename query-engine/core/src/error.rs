@@ -35,6 +35,9 @@ pub enum CoreError {
 
     #[error("{}", _0)]
     InterpreterError(InterpreterError),
+
+    #[error("The transaction was rolled back because the query engine is shutting down.")]
+    TransactionAborted,
 }
 
 impl From<QueryGraphBuilderError> for CoreError {
@@ -134,6 +137,28 @@ impl From<CoreError> for user_facing_errors::Error {
                 details,
             })
             .into(),
+            CoreError::QueryGraphBuilderError(QueryGraphBuilderError::VersionConflict {
+                model_name,
+                expected_version,
+            })
+            | CoreError::InterpreterError(InterpreterError::QueryGraphBuilderError(
+                QueryGraphBuilderError::VersionConflict {
+                    model_name,
+                    expected_version,
+                },
+            )) => user_facing_errors::KnownError::new(user_facing_errors::query_engine::VersionConflict {
+                model_name,
+                expected_version,
+            })
+            .into(),
+            CoreError::QueryGraphBuilderError(QueryGraphBuilderError::QueryLimitExceeded(violation)) => {
+                user_facing_errors::KnownError::new(user_facing_errors::query_engine::QueryLimitExceeded {
+                    limit: violation.limit.to_string(),
+                    value: violation.value.to_string(),
+                    max: violation.max.to_string(),
+                })
+                .into()
+            }
             CoreError::QueryGraphBuilderError(QueryGraphBuilderError::InputError(details)) => {
                 user_facing_errors::KnownError::new(user_facing_errors::query_engine::InputError { details }).into()
             }
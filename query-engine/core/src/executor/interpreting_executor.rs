@@ -1,17 +1,44 @@
-use super::{pipeline::QueryPipeline, QueryExecutor};
-use crate::{Operation, QueryGraphBuilder, QueryInterpreter, QuerySchemaRef, ResponseData};
+use super::{pipeline::QueryPipeline, stream, QueryExecutor, RecordStream};
+use crate::{
+    CoreError, InterpreterError, IrSerializer, Operation, QueryGraph, QueryGraphBuilder, QueryInterpreter,
+    QuerySchemaRef, ResponseData,
+};
 use async_trait::async_trait;
 use connector::{Connection, ConnectionLike, Connector};
-use futures::future;
+use futures::{
+    channel::oneshot,
+    future::{self, Either, FutureExt, Shared},
+};
+use std::{
+    future::Future,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+};
+
+/// Resolves once the open transactions are to be rolled back.
+type AbortSignal = Shared<oneshot::Receiver<()>>;
 
 /// Central query executor and main entry point into the query core.
 pub struct InterpretingExecutor<C> {
     /// The loaded connector
     connector: C,
 
+    /// Connectors to read replicas of the primary database.
+    /// Read-only operations are distributed among them in round-robin order.
+    replicas: Vec<C>,
+
+    /// Index of the replica the next read-only operation is routed to.
+    next_replica: AtomicUsize,
+
     /// Flag that forces individual operations to run in a transaction.
     /// Does _not_ force batches to use transactions.
     force_transactions: bool,
+
+    /// Fires the abort signal of all transactions, see `abort_transactions`.
+    abort_tx: Mutex<Option<oneshot::Sender<()>>>,
+    abort_rx: AbortSignal,
 }
 
 impl<C> InterpretingExecutor<C>
@@ -19,27 +46,122 @@ where
     C: Connector + Send + Sync,
 {
     pub fn new(connector: C, force_transactions: bool) -> Self {
+        let (abort_tx, abort_rx) = oneshot::channel();
+
         InterpretingExecutor {
             connector,
+            replicas: Vec::new(),
+            next_replica: AtomicUsize::new(0),
             force_transactions,
+            abort_tx: Mutex::new(Some(abort_tx)),
+            abort_rx: abort_rx.shared(),
+        }
+    }
+
+    /// Routes read-only operations to the given read replicas instead of the primary connector.
+    pub fn with_replicas(mut self, replicas: Vec<C>) -> Self {
+        self.replicas = replicas;
+        self
+    }
+
+    /// Acquires a replica connection for a query graph that doesn't write and doesn't run in a
+    /// transaction, unless `force_primary` is set. Returns `None` if the graph runs on the primary.
+    async fn replica_connection_for(
+        &self,
+        query_graph: &QueryGraph,
+        force_primary: bool,
+    ) -> Option<Box<dyn Connection>> {
+        let use_primary =
+            force_primary || self.force_transactions || query_graph.needs_transaction() || !query_graph.is_read_only();
+
+        if use_primary {
+            None
+        } else {
+            self.replica_connection().await
+        }
+    }
+
+    /// Acquires a connection from the next replica in line. Returns `None` if no replica hands
+    /// out a connection.
+    async fn replica_connection(&self) -> Option<Box<dyn Connection>> {
+        if self.replicas.is_empty() {
+            return None;
+        }
+
+        let start = self.next_replica.fetch_add(1, Ordering::Relaxed);
+
+        for offset in 0..self.replicas.len() {
+            let replica = &self.replicas[(start + offset) % self.replicas.len()];
+
+            match replica.get_connection().await {
+                Ok(conn) => return Some(conn),
+                Err(err) => warn!("Read replica unavailable, trying the next one: {}", err),
+            }
+        }
+
+        warn!("No read replica available, falling back to the primary connector.");
+        None
+    }
+
+    /// Acquires a connection from the next replica in line, falling back to the primary
+    /// if no replica hands out a connection. With `use_primary` set, the primary is used directly.
+    async fn read_connection(&self, use_primary: bool) -> crate::Result<Box<dyn Connection>> {
+        if !use_primary {
+            if let Some(conn) = self.replica_connection().await {
+                return Ok(conn);
+            }
+        }
+
+        Ok(self.connector.get_connection().await?)
+    }
+
+    /// Runs the operation again on the primary if it failed on a read replica with an error of
+    /// the database, e.g. because the replica lost its connection or lags behind a migration.
+    /// Only read-only operations run on replicas, so running them a second time is safe.
+    async fn retry_on_primary(
+        &self,
+        result: crate::Result<ResponseData>,
+        operation: Operation,
+        query_schema: QuerySchemaRef,
+    ) -> crate::Result<ResponseData> {
+        match result {
+            Err(err) if is_database_error(&err) => {
+                warn!(
+                    "Read replica failed to execute the operation, retrying on the primary: {}",
+                    err
+                );
+
+                let (query_graph, serializer) = QueryGraphBuilder::new(query_schema).build(operation)?;
+                let conn = self.connector.get_connection().await?;
+
+                Self::execute_single_operation(
+                    query_graph,
+                    serializer,
+                    conn,
+                    self.force_transactions,
+                    self.abort_rx.clone(),
+                )
+                .await
+            }
+            result => result,
         }
     }
 
     /// Async wrapper for executing an individual operation to allow code sharing with `execute_batch`.
     async fn execute_single_operation(
-        operation: Operation,
+        query_graph: QueryGraph,
+        serializer: IrSerializer,
         conn: Box<dyn Connection>,
         force_transactions: bool,
-        query_schema: QuerySchemaRef,
+        abort: AbortSignal,
     ) -> crate::Result<ResponseData> {
-        // Parse, validate, and extract query graph from query document.
-        let (query_graph, serializer) = QueryGraphBuilder::new(query_schema).build(operation)?;
         let is_transactional = force_transactions || query_graph.needs_transaction();
 
         if is_transactional {
             let tx = conn.start_transaction().await?;
             let interpreter = QueryInterpreter::new(ConnectionLike::Transaction(tx.as_ref()));
-            let result = QueryPipeline::new(query_graph, interpreter, serializer).execute().await;
+            let pipeline = QueryPipeline::new(query_graph, interpreter, serializer).execute();
+            let result = abortable(pipeline, abort).await;
 
             if result.is_ok() {
                 tx.commit().await?;
@@ -76,6 +198,7 @@ where
         operations: Vec<Operation>,
         transactional: bool,
         query_schema: QuerySchemaRef,
+        force_primary: bool,
     ) -> crate::Result<Vec<crate::Result<ResponseData>>> {
         if transactional {
             let queries = operations
@@ -89,7 +212,8 @@ where
 
             for (query, info) in queries {
                 let interpreter = QueryInterpreter::new(ConnectionLike::Transaction(tx.as_ref()));
-                let result = QueryPipeline::new(query, interpreter, info).execute().await;
+                let pipeline = QueryPipeline::new(query, interpreter, info).execute();
+                let result = abortable(pipeline, self.abort_rx.clone()).await;
 
                 if result.is_err() {
                     tx.rollback().await?;
@@ -102,34 +226,156 @@ where
             Ok(results)
         } else {
             let mut futures = Vec::with_capacity(operations.len());
+            // The operations that run on a read replica, to retry them on the primary if they fail.
+            let mut replica_operations = Vec::with_capacity(operations.len());
 
             for operation in operations {
-                let conn = self.connector.get_connection().await?;
-                futures.push(tokio::spawn(Self::execute_single_operation(
-                    operation,
-                    conn,
-                    self.force_transactions,
-                    query_schema.clone(),
-                )));
+                // Parse, validate, and extract query graph from query document.
+                match QueryGraphBuilder::new(query_schema.clone()).build(operation.clone()) {
+                    Ok((query_graph, serializer)) => {
+                        let conn = match self.replica_connection_for(&query_graph, force_primary).await {
+                            Some(conn) => {
+                                replica_operations.push(Some(operation));
+                                conn
+                            }
+                            None => {
+                                replica_operations.push(None);
+                                self.connector.get_connection().await?
+                            }
+                        };
+
+                        futures.push(tokio::spawn(Self::execute_single_operation(
+                            query_graph,
+                            serializer,
+                            conn,
+                            self.force_transactions,
+                            self.abort_rx.clone(),
+                        )));
+                    }
+                    Err(err) => {
+                        replica_operations.push(None);
+                        futures.push(tokio::spawn(future::ready(Err(crate::CoreError::from(err)))));
+                    }
+                }
             }
 
-            let responses: Vec<_> = future::join_all(futures)
-                .await
-                .into_iter()
-                .map(|res| res.expect("IO Error in tokio::spawn"))
-                .collect();
+            let results = future::join_all(futures).await;
+            let mut responses = Vec::with_capacity(results.len());
+
+            for (result, replica_operation) in results.into_iter().zip(replica_operations) {
+                let result = result.expect("IO Error in tokio::spawn");
+
+                let response = match replica_operation {
+                    Some(operation) => self.retry_on_primary(result, operation, query_schema.clone()).await,
+                    None => result,
+                };
+
+                responses.push(response);
+            }
 
             Ok(responses)
         }
     }
 
     /// Executes a single operation. Execution will be inside of a transaction or not depending on the needs of the query.
-    async fn execute(&self, operation: Operation, query_schema: QuerySchemaRef) -> crate::Result<ResponseData> {
-        let conn = self.connector.get_connection().await?;
-        Self::execute_single_operation(operation, conn, self.force_transactions, query_schema.clone()).await
+    async fn execute(
+        &self,
+        operation: Operation,
+        query_schema: QuerySchemaRef,
+        force_primary: bool,
+    ) -> crate::Result<ResponseData> {
+        // Parse, validate, and extract query graph from query document.
+        let (query_graph, serializer) = QueryGraphBuilder::new(query_schema.clone()).build(operation.clone())?;
+
+        match self.replica_connection_for(&query_graph, force_primary).await {
+            Some(conn) => {
+                let result = Self::execute_single_operation(
+                    query_graph,
+                    serializer,
+                    conn,
+                    self.force_transactions,
+                    self.abort_rx.clone(),
+                )
+                .await;
+
+                self.retry_on_primary(result, operation, query_schema).await
+            }
+            None => {
+                let conn = self.connector.get_connection().await?;
+
+                Self::execute_single_operation(
+                    query_graph,
+                    serializer,
+                    conn,
+                    self.force_transactions,
+                    self.abort_rx.clone(),
+                )
+                .await
+            }
+        }
+    }
+
+    /// Streams the records of a flat `findMany` operation. The stream owns its connection,
+    /// which is taken from a read replica unless `force_primary` is set. Records may already have
+    /// been sent when a query fails, so streams fall back to the primary only if no replica hands
+    /// out a connection.
+    async fn execute_stream(
+        &self,
+        operation: Operation,
+        query_schema: QuerySchemaRef,
+        force_primary: bool,
+    ) -> crate::Result<RecordStream> {
+        let (query, serializer) = QueryGraphBuilder::new(query_schema).build_stream(operation)?;
+        let conn = self.read_connection(force_primary).await?;
+
+        Ok(stream::stream_records(conn, query, serializer))
     }
 
     fn primary_connector(&self) -> &dyn Connector {
         &self.connector
     }
+
+    fn abort_transactions(&self) {
+        if let Some(abort_tx) = self.abort_tx.lock().expect("Abort lock poisoned").take() {
+            let _ = abort_tx.send(());
+        }
+    }
+
+    fn close(&self) {
+        self.connector.close();
+
+        for replica in self.replicas.iter() {
+            replica.close();
+        }
+    }
+}
+
+/// Whether the error was raised by the database, as opposed to the query graph construction.
+fn is_database_error(err: &CoreError) -> bool {
+    match err {
+        CoreError::ConnectorError(_) => true,
+        CoreError::InterpreterError(err) => is_database_interpreter_error(err),
+        _ => false,
+    }
+}
+
+fn is_database_interpreter_error(err: &InterpreterError) -> bool {
+    match err {
+        InterpreterError::ConnectorError(_) => true,
+        InterpreterError::InterpretationError(_, Some(cause)) => is_database_interpreter_error(cause),
+        _ => false,
+    }
+}
+
+/// Resolves with the result of `fut`, or fails if the transaction it runs in is aborted first.
+/// The caller rolls back the transaction in both cases, if the result is an error.
+async fn abortable<T>(fut: impl Future<Output = crate::Result<T>>, abort: AbortSignal) -> crate::Result<T> {
+    futures::pin_mut!(fut);
+
+    match future::select(fut, abort).await {
+        Either::Left((result, _)) => result,
+        Either::Right((Ok(()), _)) => Err(CoreError::TransactionAborted),
+        // The executor was dropped, which leaves nothing to abort.
+        Either::Right((Err(_), fut)) => fut.await,
+    }
 }
@@ -7,8 +7,10 @@
 //! - Define low level execution of queries. This is considered an implementation detail of the modules used by the executors.
 mod interpreting_executor;
 mod pipeline;
+mod stream;
 
 pub use interpreting_executor::*;
+pub use stream::{RecordStream, STREAM_BATCH_SIZE};
 
 use crate::{query_document::Operation, response_ir::ResponseData, schema::QuerySchemaRef};
 use async_trait::async_trait;
@@ -17,7 +19,13 @@ use connector::Connector;
 #[async_trait]
 pub trait QueryExecutor {
     /// Executes a single operation and returns its result.
-    async fn execute(&self, operation: Operation, query_schema: QuerySchemaRef) -> crate::Result<ResponseData>;
+    /// Read-only operations may be routed to a read replica, unless `force_primary` is set.
+    async fn execute(
+        &self,
+        operation: Operation,
+        query_schema: QuerySchemaRef,
+        force_primary: bool,
+    ) -> crate::Result<ResponseData>;
 
     // Executes a batch of operations as either a fanout of individual operations (non-transactional), or in series (transactional).
     async fn execute_batch(
@@ -25,7 +33,24 @@ pub trait QueryExecutor {
         operations: Vec<Operation>,
         transactional: bool,
         query_schema: QuerySchemaRef,
+        force_primary: bool,
     ) -> crate::Result<Vec<crate::Result<ResponseData>>>;
 
+    /// Executes a `findMany` operation selecting only scalar fields, yielding the serialized
+    /// records one by one instead of materializing the whole result.
+    async fn execute_stream(
+        &self,
+        operation: Operation,
+        query_schema: QuerySchemaRef,
+        force_primary: bool,
+    ) -> crate::Result<RecordStream>;
+
     fn primary_connector(&self) -> &dyn Connector;
+
+    /// Rolls back all open transactions, failing the operations running in them.
+    /// Operations starting a transaction afterwards are rolled back right away.
+    fn abort_transactions(&self);
+
+    /// Closes the connection pools of the primary connector and all read replicas.
+    fn close(&self);
 }
@@ -0,0 +1,174 @@
+use crate::{ExpressionResult, IrSerializer, Item, ManyRecordsQuery, QueryResult, RecordSelection};
+use connector::{Connection, Filter, ScalarCompare};
+use futures::stream::{self, BoxStream, StreamExt, TryStreamExt};
+use prisma_models::{Field, ModelProjection, OrderBy, RecordProjection, SortOrder};
+
+/// Number of records read from the database at a time while streaming.
+pub const STREAM_BATCH_SIZE: i64 = 1000;
+
+/// A stream of serialized records, one item per record.
+pub type RecordStream = BoxStream<'static, crate::Result<Item>>;
+
+struct StreamState {
+    conn: Box<dyn Connection>,
+    query: ManyRecordsQuery,
+    serializer: IrSerializer,
+
+    /// Matches the records after the last record of the previous batch, which the next batch continues with.
+    after: Option<Filter>,
+
+    /// The number of records left to read, if the query has a `take`.
+    remaining: Option<i64>,
+
+    done: bool,
+}
+
+/// Streams the records of a flat `findMany` query, holding at most `STREAM_BATCH_SIZE` records in memory.
+///
+/// Quaint reads result sets as a whole, so instead of a single statement the records are read in batches
+/// with keyset pagination: each batch continues after the order-by values of the last record of the
+/// previous one, so it doesn't matter if that record is deleted in between. The primary identifier is
+/// appended to the ordering to make it total, so every record is read exactly once. Batches are separate
+/// statements, so writes committed while streaming may become visible.
+pub(crate) fn stream_records(
+    conn: Box<dyn Connection>,
+    mut query: ManyRecordsQuery,
+    serializer: IrSerializer,
+) -> RecordStream {
+    for field in query.model.primary_identifier() {
+        if let Field::Scalar(sf) = field {
+            if !query
+                .args
+                .order_by
+                .iter()
+                .any(|order_by| order_by.field.name == sf.name)
+            {
+                query.args.order_by.push(sf.into());
+            }
+        }
+    }
+
+    // The order-by values of the last record of a batch are needed for the next one.
+    query.selected_fields = query.selected_fields.clone().merge(order_fields(&query.args.order_by));
+
+    let state = StreamState {
+        conn,
+        remaining: query.args.take,
+        query,
+        serializer,
+        after: None,
+        done: false,
+    };
+
+    stream::try_unfold(state, next_batch)
+        .map_ok(|items| stream::iter(items.into_iter().map(Ok)))
+        .try_flatten()
+        .boxed()
+}
+
+async fn next_batch(mut state: StreamState) -> crate::Result<Option<(Vec<Item>, StreamState)>> {
+    if state.done || state.remaining == Some(0) {
+        return Ok(None);
+    }
+
+    let batch_size = state
+        .remaining
+        .map(|remaining| remaining.min(STREAM_BATCH_SIZE))
+        .unwrap_or(STREAM_BATCH_SIZE);
+
+    let mut args = state.query.args.clone();
+    args.take = Some(batch_size);
+
+    // A `cursor` and `skip` of the query only apply to the first batch.
+    if let Some(after) = state.after.take() {
+        args.filter = Some(match args.filter.take() {
+            Some(filter) => Filter::and(vec![filter, after]),
+            None => after,
+        });
+        args.cursor = None;
+        args.skip = None;
+    }
+
+    let records = state
+        .conn
+        .get_many_records(&state.query.model, args.clone(), &state.query.selected_fields)
+        .await?;
+
+    let count = records.records.len() as i64;
+
+    if count == 0 {
+        return Ok(None);
+    }
+
+    let model_id = state.query.model.primary_identifier();
+
+    state.done = count < batch_size;
+    state.remaining = state.remaining.map(|remaining| remaining - count);
+    state.after = match records.records.last() {
+        Some(record) => {
+            let order_by = &state.query.args.order_by;
+            let values = record.projection(&records.field_names, &order_fields(order_by))?;
+
+            Some(records_after(order_by, values))
+        }
+        None => None,
+    };
+
+    let selection = RecordSelection {
+        name: state.query.name.clone(),
+        fields: state.query.selection_order.clone(),
+        composite_selections: state.query.composite_selections.clone(),
+        scalars: records,
+        nested: vec![],
+        query_arguments: args,
+        model_id,
+    };
+
+    let response = state
+        .serializer
+        .serialize(ExpressionResult::Query(QueryResult::RecordSelection(selection)))?;
+
+    let items = response
+        .data
+        .into_list()
+        .map(|list| list.into_iter().collect())
+        .unwrap_or_default();
+
+    Ok(Some((items, state)))
+}
+
+/// The fields the records are ordered by.
+fn order_fields(order_by: &[OrderBy]) -> ModelProjection {
+    ModelProjection::new(
+        order_by
+            .iter()
+            .map(|order_by| Field::Scalar(order_by.field.clone()))
+            .collect(),
+    )
+}
+
+/// Matches the records coming after the record with the given order-by `values` in the total ordering
+/// `order_by`, e.g. `a > 1 OR (a = 1 AND b < 2)` for `a ASC, b DESC`. Order-by fields are required,
+/// as `NULL` values can't be compared.
+fn records_after(order_by: &[OrderBy], values: RecordProjection) -> Filter {
+    let values: Vec<_> = values.pairs.into_iter().map(|(_, value)| value).collect();
+
+    let conditions = (0..order_by.len())
+        .map(|n| {
+            let mut conditions: Vec<Filter> = order_by[..n]
+                .iter()
+                .zip(values.iter())
+                .map(|(previous, value)| previous.field.equals(value.clone()))
+                .collect();
+
+            conditions.push(match order_by[n].sort_order {
+                SortOrder::Ascending => order_by[n].field.greater_than(values[n].clone()),
+                SortOrder::Descending => order_by[n].field.less_than(values[n].clone()),
+            });
+
+            Filter::and(conditions)
+        })
+        .collect();
+
+    Filter::or(conditions)
+}
@@ -1,9 +1,12 @@
 use super::*;
 use crate::{interpreter::InterpretationResult, query_ast::*, result_ast::*};
-use connector::{self, ConnectionLike, QueryArguments, ReadOperations};
+use connector::{
+    self, AggregationResult, AggregationSelection, ConnectionLike, IdFilter, QueryArguments, ReadOperations,
+};
 use futures::future::{BoxFuture, FutureExt};
 use inmemory_record_processor::InMemoryRecordProcessor;
-use prisma_models::ManyRecords;
+use prisma_models::{ManyRecords, PrismaValue, RecordProjection};
+use std::collections::HashMap;
 
 pub fn execute<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
@@ -16,6 +19,8 @@ pub fn execute<'a, 'b>(
             ReadQuery::ManyRecordsQuery(q) => read_many(tx, q).await,
             ReadQuery::RelatedRecordsQuery(q) => read_related(tx, q, parent_result).await,
             ReadQuery::AggregateRecordsQuery(q) => aggregate(tx, q).await,
+            ReadQuery::ConnectionQuery(q) => read_connection(tx, q).await,
+            ReadQuery::TraversalQuery(q) => read_traversal(tx, q).await,
         }
     };
 
@@ -41,6 +46,7 @@ fn read_one<'conn, 'tx>(
                 Ok(QueryResult::RecordSelection(RecordSelection {
                     name: query.name,
                     fields: query.selection_order,
+                    composite_selections: query.composite_selections,
                     scalars: records,
                     nested,
                     model_id,
@@ -51,6 +57,7 @@ fn read_one<'conn, 'tx>(
             None => Ok(QueryResult::RecordSelection(RecordSelection {
                 name: query.name,
                 fields: query.selection_order,
+                composite_selections: query.composite_selections,
                 model_id,
                 scalars: ManyRecords::default(),
                 nested: vec![],
@@ -73,24 +80,14 @@ fn read_many<'a, 'b>(
     mut query: ManyRecordsQuery,
 ) -> BoxFuture<'a, InterpretationResult<QueryResult>> {
     let fut = async move {
-        let scalars = if query.args.requires_inmemory_processing() {
-            let processor = InMemoryRecordProcessor::new_from_query_args(&mut query.args);
-            let scalars = tx
-                .get_many_records(&query.model, query.args.clone(), &query.selected_fields)
-                .await?;
-
-            processor.apply(scalars)
-        } else {
-            tx.get_many_records(&query.model, query.args.clone(), &query.selected_fields)
-                .await?
-        };
-
+        let scalars = read_many_scalars(tx, &mut query).await?;
         let model_id = query.model.primary_identifier();
         let nested: Vec<QueryResult> = process_nested(tx, query.nested, Some(&scalars)).await?;
 
         Ok(QueryResult::RecordSelection(RecordSelection {
             name: query.name,
             fields: query.selection_order,
+            composite_selections: query.composite_selections,
             query_arguments: query.args,
             model_id,
             scalars,
@@ -101,6 +98,177 @@ fn read_many<'a, 'b>(
     fut.boxed()
 }
 
+/// Reads the scalars of a set of records, without resolving nested queries.
+async fn read_many_scalars<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    query: &mut ManyRecordsQuery,
+) -> InterpretationResult<ManyRecords> {
+    let scalars = if query.args.requires_inmemory_processing() {
+        let processor = InMemoryRecordProcessor::new_from_query_args(&mut query.args);
+        let scalars = tx
+            .get_many_records(&query.model, query.args.clone(), &query.selected_fields)
+            .await?;
+
+        processor.apply(scalars)
+    } else {
+        tx.get_many_records(&query.model, query.args.clone(), &query.selected_fields)
+            .await?
+    };
+
+    Ok(scalars)
+}
+
+/// Queries a page of records as a connection.
+/// The records query takes one record more than the page size. If it is returned, there is another
+/// page in the direction of reading. It is dropped before nested queries are resolved.
+fn read_connection<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    query: ConnectionQuery,
+) -> BoxFuture<'a, InterpretationResult<QueryResult>> {
+    let fut = async move {
+        let mut records = query.records;
+        let has_cursor = records.args.cursor.is_some();
+
+        let total_count = if query.selection.has_total_count() {
+            Some(count_records(tx, &records.args).await?)
+        } else {
+            None
+        };
+
+        let mut scalars = read_many_scalars(tx, &mut records).await?;
+        let has_more = match query.page_size {
+            Some(page_size) if scalars.records.len() > page_size => {
+                if query.backwards {
+                    let excess = scalars.records.len() - page_size;
+                    scalars.records.drain(..excess);
+                } else {
+                    scalars.records.truncate(page_size);
+                }
+
+                true
+            }
+            _ => false,
+        };
+
+        let model_id = records.model.primary_identifier();
+        let cursors: Vec<String> = scalars.projections(&model_id)?.iter().map(encode_cursor).collect();
+
+        let page_info = PageInfo {
+            has_next_page: if query.backwards { has_cursor } else { has_more },
+            has_previous_page: if query.backwards { has_more } else { has_cursor },
+            start_cursor: cursors.first().cloned(),
+            end_cursor: cursors.last().cloned(),
+        };
+
+        let nested: Vec<QueryResult> = process_nested(tx, records.nested, Some(&scalars)).await?;
+
+        Ok(QueryResult::RecordConnection(RecordConnection {
+            records: RecordSelection {
+                name: query.name,
+                fields: records.selection_order,
+                composite_selections: records.composite_selections,
+                query_arguments: records.args,
+                model_id,
+                scalars,
+                nested,
+            },
+            cursors,
+            page_info,
+            total_count,
+            selection: query.selection,
+        }))
+    };
+
+    fut.boxed()
+}
+
+/// Queries the records reached by traversing a self-relation recursively.
+/// The connector determines the reached records and their depths, which are then read like any
+/// other set of records and put back into the order of the traversal.
+fn read_traversal<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    query: TraversalQuery,
+) -> BoxFuture<'a, InterpretationResult<QueryResult>> {
+    let fut = async move {
+        let mut records = query.records;
+        let model_id = records.model.primary_identifier();
+
+        let reached = tx
+            .get_recursive_related_record_ids(&query.field, &query.filter, query.max_depth)
+            .await?;
+
+        let mut scalars = if reached.is_empty() {
+            ManyRecords::new(records.selected_fields.db_names().map(String::from).collect())
+        } else {
+            let ids: Vec<RecordProjection> = reached.iter().map(|(id, _)| id.clone()).collect();
+            records.args.filter = Some(ids.filter());
+
+            read_many_scalars(tx, &mut records).await?
+        };
+
+        let positions: HashMap<RecordProjection, usize> = reached
+            .iter()
+            .enumerate()
+            .map(|(position, (id, _))| (id.clone(), position))
+            .collect();
+
+        let projections = scalars.projections(&model_id)?;
+        let mut ordered: Vec<_> = projections
+            .into_iter()
+            .zip(scalars.records.drain(..))
+            .filter_map(|(id, record)| positions.get(&id).map(|position| (*position, record)))
+            .collect();
+
+        ordered.sort_by_key(|(position, _)| *position);
+
+        let depths = ordered.iter().map(|(position, _)| reached[*position].1).collect();
+        scalars.records = ordered.into_iter().map(|(_, record)| record).collect();
+
+        let nested: Vec<QueryResult> = process_nested(tx, records.nested, Some(&scalars)).await?;
+
+        Ok(QueryResult::RecordTraversal(RecordTraversal {
+            records: RecordSelection {
+                name: query.name,
+                fields: records.selection_order,
+                composite_selections: records.composite_selections,
+                query_arguments: records.args,
+                model_id,
+                scalars,
+                nested,
+            },
+            depths,
+            selection: query.selection,
+        }))
+    };
+
+    fut.boxed()
+}
+
+/// Counts the records matching the filter of the given arguments, disregarding pagination.
+async fn count_records<'a, 'b>(tx: &'a ConnectionLike<'a, 'b>, args: &QueryArguments) -> InterpretationResult<usize> {
+    let count_args = QueryArguments {
+        filter: args.filter.clone(),
+        ..QueryArguments::new(args.model.clone())
+    };
+
+    let selection = vec![AggregationSelection::Count {
+        all: true,
+        fields: vec![],
+    }];
+
+    let rows = tx.aggregate_records(&args.model, selection, vec![], count_args).await?;
+    let count = rows
+        .into_iter()
+        .flatten()
+        .find_map(|result| match result {
+            AggregationResult::Count(None, PrismaValue::Int(count)) => Some(count as usize),
+            _ => None,
+        })
+        .unwrap_or(0);
+
+    Ok(count)
+}
+
 /// Queries related records for a set of parent IDs.
 fn read_related<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
@@ -134,6 +302,7 @@ fn read_related<'a, 'b>(
         Ok(QueryResult::RecordSelection(RecordSelection {
             name: query.name,
             fields: query.selection_order,
+            composite_selections: query.composite_selections,
             query_arguments: query.args,
             model_id,
             scalars,
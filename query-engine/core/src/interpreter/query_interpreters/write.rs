@@ -1,9 +1,10 @@
 use crate::{
     interpreter::{InterpretationResult, InterpreterError},
     query_ast::*,
-    QueryResult,
+    QueryResult, RecordSelection,
 };
-use connector::{ConnectionLike, WriteOperations};
+use connector::{ConnectionLike, QueryArguments, RawQueryResult, WriteOperations};
+use prisma_models::{ManyRecords, Record};
 use prisma_value::PrismaValue;
 
 pub async fn execute<'a, 'b>(
@@ -19,7 +20,7 @@ pub async fn execute<'a, 'b>(
         WriteQuery::ConnectRecords(q) => connect(tx, q).await,
         WriteQuery::DisconnectRecords(q) => disconnect(tx, q).await,
         WriteQuery::ExecuteRaw(rq) => execute_raw(tx, rq.query, rq.parameters).await,
-        WriteQuery::QueryRaw(rq) => query_raw(tx, rq.query, rq.parameters).await,
+        WriteQuery::QueryRaw(rq) => query_raw(tx, rq.query, rq.parameters, rq.mode).await,
     }
 }
 
@@ -27,9 +28,59 @@ async fn query_raw<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
     query: String,
     parameters: Vec<PrismaValue>,
+    mode: RawQueryMode,
 ) -> InterpretationResult<QueryResult> {
     let res = tx.query_raw(query, parameters).await?;
-    Ok(QueryResult::Json(res))
+
+    match mode {
+        RawQueryMode::Json => Ok(QueryResult::Json(res.into_json())),
+        RawQueryMode::Typed => Ok(QueryResult::Json(res.into_typed_json())),
+        RawQueryMode::Model(selection) => Ok(QueryResult::RecordSelection(raw_record_selection(res, selection)?)),
+    }
+}
+
+/// Maps the columns of a raw query result onto the selected fields of the
+/// model by their database names. All selected fields need a matching column.
+fn raw_record_selection(result: RawQueryResult, selection: RawModelSelection) -> InterpretationResult<RecordSelection> {
+    let field_names = selection.selected_fields.db_names().collect::<Vec<_>>();
+
+    let indexes = field_names
+        .iter()
+        .map(|name| {
+            result.column_index(name).ok_or_else(|| {
+                InterpreterError::InterpretationError(
+                    format!(
+                        "The raw query result for model `{}` is missing the column `{}`.",
+                        selection.model.name, name
+                    ),
+                    None,
+                )
+            })
+        })
+        .collect::<InterpretationResult<Vec<_>>>()?;
+
+    let records = result
+        .rows
+        .into_iter()
+        .map(|mut row| {
+            let values = indexes
+                .iter()
+                .map(|idx| std::mem::replace(&mut row[*idx], PrismaValue::Null))
+                .collect();
+
+            Record::new(values)
+        })
+        .collect();
+
+    Ok(RecordSelection {
+        name: selection.name,
+        fields: selection.selection_order,
+        composite_selections: selection.composite_selections,
+        scalars: ManyRecords { records, field_names },
+        nested: vec![],
+        query_arguments: QueryArguments::new(selection.model.clone()),
+        model_id: selection.model.primary_identifier(),
+    })
 }
 
 async fn execute_raw<'a, 'b>(
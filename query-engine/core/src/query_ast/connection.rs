@@ -0,0 +1,119 @@
+//! Relay-style connection reads.
+use super::ManyRecordsQuery;
+use prisma_models::{ModelRef, PrismaValue, RecordProjection, TypeIdentifier};
+use std::str::FromStr;
+
+/// Reads a page of records and returns it as a connection of edges, along with
+/// information about the surrounding pages and the total number of records.
+#[derive(Debug, Clone)]
+pub struct ConnectionQuery {
+    pub name: String,
+    pub alias: Option<String>,
+
+    /// Reads the records of the page. If the page size is limited, one record more than
+    /// the page size is taken, which tells if there are more records beyond the page.
+    pub records: ManyRecordsQuery,
+
+    /// Maximum number of records in the page.
+    pub page_size: Option<usize>,
+
+    /// Indicates that the page is read backwards, i.e. with `last` and `before`.
+    pub backwards: bool,
+
+    /// Selection on the connection object.
+    pub selection: ConnectionSelection,
+}
+
+/// Selected fields of a connection object, in the order of the incoming query.
+/// The first element of each pair is the key the field is serialized under, i.e. its alias or name.
+#[derive(Debug, Clone, Default)]
+pub struct ConnectionSelection {
+    pub fields: Vec<(String, ConnectionField)>,
+}
+
+impl ConnectionSelection {
+    pub fn has_total_count(&self) -> bool {
+        self.fields
+            .iter()
+            .any(|(_, field)| matches!(field, ConnectionField::TotalCount))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum ConnectionField {
+    /// Selected edge fields.
+    Edges(Vec<(String, EdgeField)>),
+
+    /// Selection order of the node fields.
+    Nodes(Vec<String>),
+
+    /// Selected page info fields, as (key, field name) pairs.
+    PageInfo(Vec<(String, String)>),
+
+    TotalCount,
+}
+
+#[derive(Debug, Clone)]
+pub enum EdgeField {
+    Cursor,
+
+    /// Selection order of the node fields.
+    Node(Vec<String>),
+}
+
+/// Encodes the primary identifier of a record into an opaque cursor.
+pub fn encode_cursor(id: &RecordProjection) -> String {
+    let values: Vec<PrismaValue> = id.values().collect();
+    let json = serde_json::to_vec(&values).expect("Record identifiers are always serializable.");
+
+    base64::encode_config(json, base64::URL_SAFE_NO_PAD)
+}
+
+/// Decodes a cursor created by `encode_cursor` into a primary identifier of the given model.
+/// Returns `None` if the cursor is malformed or doesn't match the identifier of the model.
+pub fn decode_cursor(model: &ModelRef, cursor: &str) -> Option<RecordProjection> {
+    let bytes = base64::decode_config(cursor, base64::URL_SAFE_NO_PAD).ok()?;
+    let values: Vec<serde_json::Value> = serde_json::from_slice(&bytes).ok()?;
+    let fields: Vec<_> = model.primary_identifier().scalar_fields().collect();
+
+    if fields.len() != values.len() {
+        return None;
+    }
+
+    let pairs = fields
+        .into_iter()
+        .zip(values)
+        .map(|(field, value)| decode_value(&field.type_identifier, value).map(|value| (field, value)))
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(RecordProjection::new(pairs))
+}
+
+fn decode_value(type_identifier: &TypeIdentifier, value: serde_json::Value) -> Option<PrismaValue> {
+    use serde_json::Value;
+
+    let value = match (type_identifier, value) {
+        (TypeIdentifier::String, Value::String(s)) => PrismaValue::String(s),
+        (TypeIdentifier::Enum(_), Value::String(s)) => PrismaValue::Enum(s),
+        (TypeIdentifier::Json, Value::String(s)) => PrismaValue::Json(s),
+        (TypeIdentifier::Xml, Value::String(s)) => PrismaValue::Xml(s),
+        (TypeIdentifier::Int, Value::Number(n)) => PrismaValue::Int(n.as_i64()?),
+        (TypeIdentifier::BigInt, Value::Number(n)) => PrismaValue::BigInt(n.as_i64()?),
+        (TypeIdentifier::BigInt, Value::String(s)) => PrismaValue::BigInt(s.parse().ok()?),
+        (TypeIdentifier::Float, Value::Number(n)) | (TypeIdentifier::Decimal, Value::Number(n)) => {
+            PrismaValue::Float(FromStr::from_str(&n.to_string()).ok()?)
+        }
+        (TypeIdentifier::Float, Value::String(s)) | (TypeIdentifier::Decimal, Value::String(s)) => {
+            PrismaValue::Float(FromStr::from_str(&s).ok()?)
+        }
+        (TypeIdentifier::Boolean, Value::Bool(b)) => PrismaValue::Boolean(b),
+        (TypeIdentifier::UUID, Value::String(s)) => PrismaValue::Uuid(s.parse().ok()?),
+        (TypeIdentifier::DateTime, Value::String(s)) => {
+            PrismaValue::DateTime(chrono::DateTime::parse_from_rfc3339(&s).ok()?)
+        }
+        (TypeIdentifier::Bytes, Value::String(s)) => PrismaValue::Bytes(prisma_value::decode_bytes(&s).ok()?),
+        _ => return None,
+    };
+
+    Some(value)
+}
@@ -1,7 +1,11 @@
+mod connection;
 mod read;
+mod traversal;
 mod write;
 
+pub use connection::*;
 pub use read::*;
+pub use traversal::*;
 pub use write::*;
 
 use connector::filter::Filter;
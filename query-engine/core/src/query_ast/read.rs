@@ -1,8 +1,8 @@
 //! Prisma read query AST
-use super::FilteredQuery;
+use super::{ConnectionQuery, FilteredQuery, TraversalQuery};
 use connector::{filter::Filter, AggregationSelection, QueryArguments};
 use prisma_models::prelude::*;
-use std::fmt::Display;
+use std::{collections::HashMap, fmt::Display};
 
 #[derive(Debug, Clone)]
 pub enum ReadQuery {
@@ -10,6 +10,8 @@ pub enum ReadQuery {
     ManyRecordsQuery(ManyRecordsQuery),
     RelatedRecordsQuery(RelatedRecordsQuery),
     AggregateRecordsQuery(AggregateRecordsQuery),
+    ConnectionQuery(ConnectionQuery),
+    TraversalQuery(TraversalQuery),
 }
 
 impl ReadQuery {
@@ -19,6 +21,8 @@ impl ReadQuery {
             ReadQuery::ManyRecordsQuery(x) => &x.name,
             ReadQuery::RelatedRecordsQuery(x) => &x.name,
             ReadQuery::AggregateRecordsQuery(x) => &x.name,
+            ReadQuery::ConnectionQuery(x) => &x.name,
+            ReadQuery::TraversalQuery(x) => &x.name,
         }
     }
 
@@ -30,6 +34,8 @@ impl ReadQuery {
             ReadQuery::ManyRecordsQuery(x) => x.selected_fields.contains_all_db_names(db_names),
             ReadQuery::RelatedRecordsQuery(x) => x.selected_fields.contains_all_db_names(db_names),
             ReadQuery::AggregateRecordsQuery(_x) => false,
+            ReadQuery::ConnectionQuery(_x) => false,
+            ReadQuery::TraversalQuery(_x) => false,
         }
     }
 
@@ -39,6 +45,8 @@ impl ReadQuery {
             ReadQuery::ManyRecordsQuery(x) => x.model.clone(),
             ReadQuery::RelatedRecordsQuery(x) => x.parent_field.related_field().model(),
             ReadQuery::AggregateRecordsQuery(x) => x.model.clone(),
+            ReadQuery::ConnectionQuery(x) => x.records.model.clone(),
+            ReadQuery::TraversalQuery(x) => x.records.model.clone(),
         }
     }
 }
@@ -88,6 +96,16 @@ impl Display for ReadQuery {
                 q.selected_fields.names().collect::<Vec<_>>()
             ),
             Self::AggregateRecordsQuery(q) => write!(f, "AggregateRecordsQuery: {}", q.name),
+            Self::ConnectionQuery(q) => write!(
+                f,
+                "ConnectionQuery(name: '{}', model: {}, args: {:?}, page size: {:?})",
+                q.name, q.records.model.name, q.records.args, q.page_size
+            ),
+            Self::TraversalQuery(q) => write!(
+                f,
+                "TraversalQuery(name: '{}', model: {}, field: {}, filter: {:?}, max depth: {})",
+                q.name, q.records.model.name, q.field.name, q.filter, q.max_depth
+            ),
         }
     }
 }
@@ -101,6 +119,7 @@ pub struct RecordQuery {
     pub selected_fields: ModelProjection,
     pub nested: Vec<ReadQuery>,
     pub selection_order: Vec<String>,
+    pub composite_selections: CompositeSelections,
 }
 
 #[derive(Debug, Clone)]
@@ -112,6 +131,7 @@ pub struct ManyRecordsQuery {
     pub selected_fields: ModelProjection,
     pub nested: Vec<ReadQuery>,
     pub selection_order: Vec<String>,
+    pub composite_selections: CompositeSelections,
 }
 
 #[derive(Debug, Clone)]
@@ -123,12 +143,27 @@ pub struct RelatedRecordsQuery {
     pub selected_fields: ModelProjection,
     pub nested: Vec<ReadQuery>,
     pub selection_order: Vec<String>,
+    pub composite_selections: CompositeSelections,
 
     /// Fields and values of the parent to satisfy the relation query without
     /// relying on the parent result passed by the interpreter.
     pub parent_projections: Option<Vec<RecordProjection>>,
 }
 
+/// The selections within composite type fields, by the name of the field.
+pub type CompositeSelections = HashMap<String, CompositeSelection>;
+
+/// Composite types are read as a whole, so the fields selected within them are only used to
+/// shape the response.
+#[derive(Debug, Clone, Default)]
+pub struct CompositeSelection {
+    /// Ordered list of the selected field names.
+    pub fields: Vec<String>,
+
+    /// Selections within nested composite type fields.
+    pub nested: CompositeSelections,
+}
+
 #[derive(Debug, Clone)]
 pub struct AggregateRecordsQuery {
     pub name: String,
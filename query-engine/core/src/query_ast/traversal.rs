@@ -0,0 +1,35 @@
+//! Recursive reads along self-relations.
+use super::ManyRecordsQuery;
+use connector::filter::Filter;
+use prisma_models::RelationFieldRef;
+
+/// Reads all records reachable from a starting record by repeatedly following a self-relation,
+/// e.g. all descendants or all ancestors in a tree. Each record is returned with the depth it was reached at.
+#[derive(Debug, Clone)]
+pub struct TraversalQuery {
+    pub name: String,
+    pub alias: Option<String>,
+
+    /// Self-relation field to follow.
+    pub field: RelationFieldRef,
+
+    /// Filter selecting the starting record.
+    pub filter: Filter,
+
+    /// Maximum number of steps taken from the starting record.
+    pub max_depth: usize,
+
+    /// Reads the reached records. Its filter is set to the reached records on execution.
+    pub records: ManyRecordsQuery,
+
+    /// Selected fields of the traversal objects, as (key, field) pairs in the order of the incoming query.
+    pub selection: Vec<(String, TraversalField)>,
+}
+
+#[derive(Debug, Clone)]
+pub enum TraversalField {
+    Depth,
+
+    /// Selection order of the node fields.
+    Node(Vec<String>),
+}
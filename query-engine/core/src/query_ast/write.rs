@@ -1,5 +1,5 @@
 //! Write query AST
-use super::FilteredQuery;
+use super::{CompositeSelections, FilteredQuery};
 use connector::{filter::Filter, DatasourceFieldName, RecordFilter, WriteArgs};
 use prisma_models::prelude::*;
 use std::sync::Arc;
@@ -170,6 +170,30 @@ pub struct DisconnectRecords {
 pub struct RawQuery {
     pub query: String,
     pub parameters: Vec<PrismaValue>,
+    pub mode: RawQueryMode,
+}
+
+/// Determines the shape of the result of a raw query.
+#[derive(Debug, Clone)]
+pub enum RawQueryMode {
+    /// A list of JSON objects, keyed by column name.
+    Json,
+
+    /// The columns, their types and the rows as lists of values.
+    Typed,
+
+    /// Records of a model, read from the columns matching the database names
+    /// of its scalar fields.
+    Model(RawModelSelection),
+}
+
+#[derive(Debug, Clone)]
+pub struct RawModelSelection {
+    pub name: String,
+    pub model: ModelRef,
+    pub selected_fields: ModelProjection,
+    pub selection_order: Vec<String>,
+    pub composite_selections: CompositeSelections,
 }
 
 impl FilteredQuery for UpdateRecord {
@@ -58,6 +58,7 @@ pub enum QueryParserErrorKind {
     ArgumentNotFoundError,
     FieldCountError(FieldCountError),
     ValueParseError(String),
+    ValueValidationError(String),
     ValueTypeMismatchError { have: QueryValue, want: InputType },
     InputUnionParseError { parsing_errors: Vec<QueryParserError> },
 }
@@ -71,6 +72,7 @@ impl Display for QueryParserErrorKind {
             Self::ArgumentNotFoundError => write!(f, "Argument does not exist on enclosing type."),
             Self::FieldCountError(err) => write!(f, "{}", err),
             Self::ValueParseError(reason) => write!(f, "Error parsing value: {}.", reason),
+            Self::ValueValidationError(reason) => write!(f, "Invalid value: {}.", reason),
             Self::InputUnionParseError { parsing_errors } => write!(
                 f,
                 "Unable to match input value to any allowed input type for the field. Parse errors: [{}]",
@@ -37,6 +37,13 @@ impl Operation {
         }
     }
 
+    pub fn selection_mut(&mut self) -> &mut Selection {
+        match self {
+            Self::Read(s) => s,
+            Self::Write(s) => s,
+        }
+    }
+
     pub fn name(&self) -> &str {
         match self {
             Self::Read(s) => s.name(),
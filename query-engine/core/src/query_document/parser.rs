@@ -3,6 +3,7 @@ use crate::schema::*;
 use bigdecimal::{BigDecimal, ToPrimitive};
 use chrono::prelude::*;
 use indexmap::IndexMap;
+use prisma_models::dml::FieldValidation;
 use prisma_value::PrismaValue;
 use std::{borrow::Borrow, collections::HashSet, convert::TryFrom, str::FromStr, sync::Arc};
 use uuid::Uuid;
@@ -388,6 +389,71 @@ impl QueryDocumentParser {
         }
     }
 
+    /// Checks a parsed input value against the validations of its field.
+    /// Update operation objects are checked on the value they `set`. Arithmetic operations, like
+    /// `increment`, are rejected on fields with a `@range`, as their result is only known to the database.
+    fn validate_input_value(
+        path: &QueryPath,
+        value: &ParsedInputValue,
+        validations: &[FieldValidation],
+    ) -> QueryParserResult<()> {
+        if validations.is_empty() {
+            return Ok(());
+        }
+
+        match value {
+            ParsedInputValue::Single(value) => validations
+                .iter()
+                .try_for_each(|validation| Self::check_validation(path, value, validation)),
+            ParsedInputValue::Map(map) => match map.get("set") {
+                Some(value) => Self::validate_input_value(&path.add("set".to_owned()), value, validations),
+                None => match validations
+                    .iter()
+                    .find(|validation| matches!(validation, FieldValidation::Range { .. }))
+                {
+                    Some(range) => match map.keys().next() {
+                        Some(operation) => Err(QueryParserError {
+                            path: path.add(operation.clone()),
+                            error_kind: QueryParserErrorKind::ValueValidationError(format!(
+                                "Expected {}, `{}` can't be checked against it, use `set` instead",
+                                range, operation
+                            )),
+                        }),
+                        None => Ok(()),
+                    },
+                    None => Ok(()),
+                },
+            },
+            _ => Ok(()),
+        }
+    }
+
+    fn check_validation(path: &QueryPath, value: &PrismaValue, validation: &FieldValidation) -> QueryParserResult<()> {
+        let valid = match (validation, value) {
+            (_, PrismaValue::Null) => true,
+            (FieldValidation::Length { min, max }, PrismaValue::String(s)) => {
+                let length = s.chars().count();
+                min.map_or(true, |min| length >= min) && max.map_or(true, |max| length <= max)
+            }
+            (FieldValidation::Range { min, max }, value) => match numeric_value(value) {
+                Some(n) => min.map_or(true, |min| n >= min) && max.map_or(true, |max| n <= max),
+                None => true,
+            },
+            (FieldValidation::Regex { pattern }, PrismaValue::String(s)) => pattern.is_match(s),
+            (FieldValidation::Email, PrismaValue::String(s)) => is_email(s),
+            _ => true,
+        };
+
+        if valid {
+            Ok(())
+        } else {
+            Err(QueryParserError {
+                path: path.clone(),
+                error_kind: QueryParserErrorKind::ValueValidationError(format!("Expected {}", validation)),
+            })
+        }
+    }
+
     /// Parses and validates an input object recursively.
     pub fn parse_input_object(
         parent_path: QueryPath,
@@ -448,6 +514,7 @@ impl QueryDocumentParser {
 
                 let path = path.add(field.name.clone());
                 let parsed = Self::parse_input_value(path.clone(), v, &field.field_types)?;
+                Self::validate_input_value(&path, &parsed, &field.validations)?;
 
                 Ok((k, parsed))
             })
@@ -497,3 +564,27 @@ impl<'a, T: std::cmp::Eq + std::hash::Hash> Diff<'a, T> {
         Diff { left, right, equal }
     }
 }
+
+fn numeric_value(value: &PrismaValue) -> Option<f64> {
+    match value {
+        PrismaValue::Int(i) | PrismaValue::BigInt(i) => Some(*i as f64),
+        PrismaValue::Float(f) => f.to_f64(),
+        _ => None,
+    }
+}
+
+/// A deliberately loose check: a non-empty local part and a dotted domain, separated by a single `@`.
+fn is_email(s: &str) -> bool {
+    let mut parts = s.splitn(2, '@');
+
+    match (parts.next(), parts.next()) {
+        (Some(local), Some(domain)) => {
+            !local.is_empty()
+                && !domain.contains('@')
+                && !s.chars().any(char::is_whitespace)
+                && domain.split('.').count() > 1
+                && domain.split('.').all(|label| !label.is_empty())
+        }
+        _ => false,
+    }
+}
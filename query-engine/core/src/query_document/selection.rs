@@ -105,6 +105,14 @@ impl Selection {
         &self.arguments
     }
 
+    pub fn arguments_mut(&mut self) -> &mut [(String, QueryValue)] {
+        &mut self.arguments
+    }
+
+    pub fn remove_argument(&mut self, idx: usize) -> (String, QueryValue) {
+        self.arguments.remove(idx)
+    }
+
     pub fn pop_argument(&mut self) -> Option<(String, QueryValue)> {
         self.arguments.pop()
     }
@@ -113,6 +121,10 @@ impl Selection {
         &self.nested_selections
     }
 
+    pub fn nested_selections_mut(&mut self) -> &mut [Self] {
+        &mut self.nested_selections
+    }
+
     pub fn name(&self) -> &str {
         &self.name
     }
@@ -8,7 +8,8 @@ pub use formatters::*;
 pub use transformers::*;
 
 use crate::{
-    interpreter::ExpressionResult, FilteredQuery, ManyRecordsQuery, Query, QueryGraphBuilderResult, ReadQuery,
+    interpreter::ExpressionResult, CompositeSelections, FilteredQuery, ManyRecordsQuery, Query,
+    QueryGraphBuilderResult, ReadQuery,
 };
 use connector::{IdFilter, QueryArguments};
 use guard::*;
@@ -315,6 +316,17 @@ impl QueryGraph {
         self.needs_transaction
     }
 
+    /// If true, none of the queries in the graph writes data, which allows the graph
+    /// to be executed against a read replica.
+    pub fn is_read_only(&self) -> bool {
+        self.graph.node_indices().all(|ix| {
+            !matches!(
+                self.graph.node_weight(ix).unwrap().borrow(),
+                Some(Node::Query(Query::Write(_)))
+            )
+        })
+    }
+
     /// Returns a reference to the content of `node`, if the content is still present.
     pub fn node_content(&self, node: &NodeRef) -> Option<&Node> {
         self.graph.node_weight(node.node_ix).unwrap().borrow()
@@ -718,6 +730,7 @@ impl QueryGraph {
                 selected_fields: ModelProjection::union(identifiers),
                 nested: vec![],
                 selection_order: vec![],
+                composite_selections: CompositeSelections::new(),
             });
 
             let query = Query::Read(read_query);
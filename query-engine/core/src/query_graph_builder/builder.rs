@@ -1,5 +1,5 @@
 use super::*;
-use crate::{query_document::*, query_graph::*, schema::*, IrSerializer};
+use crate::{query_document::*, query_graph::*, schema::*, IrSerializer, ManyRecordsQuery, ReadQuery};
 use prisma_value::PrismaValue;
 
 // TODO: Think about if this is really necessary here, or if the whole code should move into
@@ -50,16 +50,75 @@ impl QueryGraphBuilder {
         }
     }
 
+    /// Maps a read operation to a `findMany` query whose records can be streamed.
+    /// Only `findMany` queries selecting scalar fields and reading forward can be streamed.
+    pub fn build_stream(self, operation: Operation) -> QueryGraphBuilderResult<(ManyRecordsQuery, IrSerializer)> {
+        let selection = match operation {
+            Operation::Read(selection) => selection,
+            Operation::Write(_) => return Err(stream_error("Only read operations can be streamed.")),
+        };
+
+        self.query_schema.limits.check_selection(&selection)?;
+
+        let mut selections = vec![selection];
+        let mut parsed_object =
+            QueryDocumentParser::parse_object(QueryPath::default(), &selections, &self.query_schema.query())?;
+
+        let field_pair = parsed_object.fields.pop().unwrap();
+        self.query_schema.limits.check_field(&field_pair)?;
+
+        let serializer = Self::derive_serializer(&selections.pop().unwrap(), &field_pair.schema_field);
+
+        let model = match field_pair.schema_field.query_info.as_ref() {
+            Some(QueryInfo {
+                tag: QueryTag::FindMany,
+                model: Some(model),
+            }) => model.clone(),
+            _ => return Err(stream_error("Only findMany queries can be streamed.")),
+        };
+
+        let query = match read::find_many(field_pair.parsed_field, model)? {
+            ReadQuery::ManyRecordsQuery(query) => query,
+            _ => unreachable!(),
+        };
+
+        if !query.nested.is_empty() {
+            return Err(stream_error("Streaming only supports selecting scalar fields."));
+        }
+
+        if query.args.take.map(|take| take < 0).unwrap_or(false) {
+            return Err(stream_error("Streaming doesn't support a negative `take`."));
+        }
+
+        if query.args.distinct.is_some() {
+            return Err(stream_error("Streaming doesn't support `distinct`."));
+        }
+
+        if query.args.order_by_relevance.is_some() {
+            return Err(stream_error("Streaming doesn't support ordering by `_relevance`."));
+        }
+
+        // Batches continue after the order-by values of the previous batch, `NULL`s can't be compared.
+        if query.args.order_by.iter().any(|order_by| !order_by.field.is_required) {
+            return Err(stream_error("Streaming doesn't support ordering by optional fields."));
+        }
+
+        Ok((query, serializer))
+    }
+
     fn build_internal(
         &self,
         selection: Selection,
         root_object: &ObjectTypeStrongRef, // Either the query or mutation object.
     ) -> QueryGraphBuilderResult<(QueryGraph, IrSerializer)> {
+        self.query_schema.limits.check_selection(&selection)?;
+
         let mut selections = vec![selection];
         let mut parsed_object = QueryDocumentParser::parse_object(QueryPath::default(), &selections, root_object)?;
 
         // Because we're processing root objects, there can only be one query / mutation.
         let field_pair = parsed_object.fields.pop().unwrap();
+        self.query_schema.limits.check_field(&field_pair)?;
         let serializer = Self::derive_serializer(&selections.pop().unwrap(), &field_pair.schema_field);
 
         if field_pair.schema_field.query_info.is_some() {
@@ -82,6 +141,8 @@ impl QueryGraphBuilder {
             (QueryTag::FindOne, Some(m)) => read::find_one(parsed_field, m).map(Into::into),
             (QueryTag::FindFirst, Some(m)) => read::find_first(parsed_field, m).map(Into::into),
             (QueryTag::FindMany, Some(m)) => read::find_many(parsed_field, m).map(Into::into),
+            (QueryTag::FindManyConnection, Some(m)) => read::find_connection(parsed_field, m).map(Into::into),
+            (QueryTag::FindManyRecursive, Some(m)) => read::find_recursive(parsed_field, m).map(Into::into),
             (QueryTag::Aggregate, Some(m)) => read::aggregate(parsed_field, m).map(Into::into),
             (QueryTag::GroupBy, Some(m)) => read::group_by(parsed_field, m).map(Into::into),
             (QueryTag::CreateOne, Some(m)) => QueryGraph::root(|g| write::create_record(g, m, parsed_field)),
@@ -91,7 +152,7 @@ impl QueryGraphBuilder {
             (QueryTag::DeleteOne, Some(m)) => QueryGraph::root(|g| write::delete_record(g, m, parsed_field)),
             (QueryTag::DeleteMany, Some(m)) => QueryGraph::root(|g| write::delete_many_records(g, m, parsed_field)),
             (QueryTag::ExecuteRaw, _) => QueryGraph::root(|g| write::execute_raw(g, parsed_field)),
-            (QueryTag::QueryRaw, _) => QueryGraph::root(|g| write::query_raw(g, parsed_field)),
+            (QueryTag::QueryRaw, m) => QueryGraph::root(|g| write::query_raw(g, parsed_field, m)),
             _ => unreachable!("Query builder dispatching failed."),
         }?;
 
@@ -112,3 +173,7 @@ impl QueryGraphBuilder {
         }
     }
 }
+
+fn stream_error(message: &str) -> QueryGraphBuilderError {
+    QueryGraphBuilderError::InputError(message.to_owned())
+}
@@ -1,4 +1,4 @@
-use crate::{QueryGraphError, QueryParserError};
+use crate::{QueryGraphError, QueryLimitViolation, QueryParserError};
 use prisma_models::{DomainError, RelationFieldRef};
 
 #[derive(Debug)]
@@ -39,7 +39,16 @@ pub enum QueryGraphBuilderError {
 
     RecordNotFound(String),
 
+    /// An update with an expected `@version` didn't find the record in that version.
+    VersionConflict {
+        model_name: String,
+        expected_version: String,
+    },
+
     QueryGraphError(QueryGraphError),
+
+    /// The query exceeds one of the configured `QueryLimits`.
+    QueryLimitExceeded(QueryLimitViolation),
 }
 
 #[derive(Debug)]
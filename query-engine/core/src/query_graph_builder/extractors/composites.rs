@@ -0,0 +1,206 @@
+//! Composite types are stored as JSON. Values of composite type fields are converted into their
+//! JSON representation here, for writes as well as for filters matching by JSON containment.
+//!
+//! Values that JSON has no type for are stored as strings: date times in RFC 3339 format in UTC
+//! with millisecond precision, and bytes base64 encoded. Enums are stored with their database
+//! names.
+use crate::{query_document::*, QueryGraphBuilderError, QueryGraphBuilderResult};
+use bigdecimal::ToPrimitive;
+use chrono::{SecondsFormat, Utc};
+use prisma_models::{InternalCompositeType, InternalCompositeTypeField, InternalDataModelRef, PrismaValue};
+use serde_json::{Map, Value};
+use std::convert::TryInto;
+
+/// Converts the input for a complete composite value into JSON. Fields that are left out are
+/// written explicitly as `null`, or as an empty list for list fields, so that filters can match
+/// on them.
+pub(crate) fn composite_value_json(
+    input: ParsedInputValue,
+    composite_type: &InternalCompositeType,
+    internal_data_model: &InternalDataModelRef,
+) -> QueryGraphBuilderResult<Value> {
+    let mut input_map: ParsedInputMap = match input {
+        ParsedInputValue::Single(PrismaValue::Null) => return Ok(Value::Null),
+        input => input.try_into()?,
+    };
+
+    let mut object = Map::with_capacity(composite_type.fields.len());
+
+    for field in composite_type.fields.iter() {
+        let value = match input_map.remove(&field.name) {
+            Some(value) => field_value_json(value, field, internal_data_model)?,
+            None if field.is_list() => Value::Array(Vec::new()),
+            None => Value::Null,
+        };
+
+        object.insert(field.name.clone(), value);
+    }
+
+    Ok(Value::Object(object))
+}
+
+/// Converts the input for a list of composite values into a JSON array.
+pub(crate) fn composite_list_json(
+    input: ParsedInputValue,
+    composite_type: &InternalCompositeType,
+    internal_data_model: &InternalDataModelRef,
+) -> QueryGraphBuilderResult<Value> {
+    let values = match input {
+        ParsedInputValue::List(values) => values,
+        single => vec![single],
+    };
+
+    let values = values
+        .into_iter()
+        .map(|value| composite_value_json(value, composite_type, internal_data_model))
+        .collect::<QueryGraphBuilderResult<Vec<_>>>()?;
+
+    Ok(Value::Array(values))
+}
+
+/// Converts a composite filter object into the JSON object that matching values contain. Only the
+/// given fields are part of the object.
+pub(crate) fn composite_where_json(
+    input: ParsedInputMap,
+    composite_type: &InternalCompositeType,
+    internal_data_model: &InternalDataModelRef,
+) -> QueryGraphBuilderResult<Value> {
+    let object = input
+        .into_iter()
+        .map(|(name, value)| {
+            let field = find_field(composite_type, &name)?;
+
+            let value = match field.composite_type {
+                Some(ref nested_name) if !field.is_list() => match value {
+                    ParsedInputValue::Single(PrismaValue::Null) => Value::Null,
+                    value => {
+                        let nested_type = find_composite_type(internal_data_model, nested_name)?;
+                        composite_where_json(value.try_into()?, nested_type, internal_data_model)?
+                    }
+                },
+                _ => field_value_json(value, field, internal_data_model)?,
+            };
+
+            Ok((name, value))
+        })
+        .collect::<QueryGraphBuilderResult<Map<String, Value>>>()?;
+
+    Ok(Value::Object(object))
+}
+
+/// Converts the input of an `update` within a composite type field into the JSON values to set,
+/// each with the path of the field it is set at.
+pub(crate) fn composite_updates(
+    input: ParsedInputMap,
+    composite_type: &InternalCompositeType,
+    internal_data_model: &InternalDataModelRef,
+) -> QueryGraphBuilderResult<Vec<(Vec<String>, PrismaValue)>> {
+    let mut updates = Vec::with_capacity(input.len());
+    collect_composite_updates(input, composite_type, internal_data_model, &[], &mut updates)?;
+
+    Ok(updates)
+}
+
+fn collect_composite_updates(
+    input: ParsedInputMap,
+    composite_type: &InternalCompositeType,
+    internal_data_model: &InternalDataModelRef,
+    parent_path: &[String],
+    updates: &mut Vec<(Vec<String>, PrismaValue)>,
+) -> QueryGraphBuilderResult<()> {
+    for (name, value) in input {
+        let field = find_field(composite_type, &name)?;
+        let mut path = parent_path.to_vec();
+        path.push(name);
+
+        match field.composite_type {
+            // Nested single composites are updated with an envelope of exactly one of `set` and `update`.
+            Some(ref nested_name) if !field.is_list() => {
+                let nested_type = find_composite_type(internal_data_model, nested_name)?;
+                let envelope: ParsedInputMap = value.try_into()?;
+                let (operation, value) = envelope.into_iter().next().unwrap();
+
+                match operation.as_str() {
+                    "set" => {
+                        let json = composite_value_json(value, nested_type, internal_data_model)?;
+                        updates.push((path, PrismaValue::Json(json.to_string())));
+                    }
+                    "update" => {
+                        collect_composite_updates(value.try_into()?, nested_type, internal_data_model, &path, updates)?
+                    }
+                    _ => unreachable!("Invalid composite update operation"),
+                }
+            }
+            _ => {
+                let json = field_value_json(value, field, internal_data_model)?;
+                updates.push((path, PrismaValue::Json(json.to_string())));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn field_value_json(
+    value: ParsedInputValue,
+    field: &InternalCompositeTypeField,
+    internal_data_model: &InternalDataModelRef,
+) -> QueryGraphBuilderResult<Value> {
+    match field.composite_type {
+        Some(ref name) => {
+            let composite_type = find_composite_type(internal_data_model, name)?;
+
+            if field.is_list() {
+                composite_list_json(value, composite_type, internal_data_model)
+            } else {
+                composite_value_json(value, composite_type, internal_data_model)
+            }
+        }
+        None => {
+            let value: PrismaValue = value.try_into()?;
+            scalar_json(value)
+        }
+    }
+}
+
+fn scalar_json(value: PrismaValue) -> QueryGraphBuilderResult<Value> {
+    let json = match value {
+        PrismaValue::String(s) | PrismaValue::Enum(s) | PrismaValue::Xml(s) => Value::String(s),
+        PrismaValue::Boolean(b) => Value::Bool(b),
+        PrismaValue::Int(i) | PrismaValue::BigInt(i) => Value::from(i),
+        PrismaValue::Float(f) => Value::from(f.to_f64().unwrap()),
+        PrismaValue::Uuid(u) => Value::String(u.to_string()),
+        PrismaValue::DateTime(dt) => Value::String(dt.with_timezone(&Utc).to_rfc3339_opts(SecondsFormat::Millis, true)),
+        PrismaValue::Bytes(b) => Value::String(prisma_value::encode_bytes(&b)),
+        PrismaValue::Json(s) => serde_json::from_str(&s)
+            .map_err(|err| QueryGraphBuilderError::InputError(format!("Invalid JSON value: {}", err)))?,
+        PrismaValue::List(values) => Value::Array(
+            values
+                .into_iter()
+                .map(scalar_json)
+                .collect::<QueryGraphBuilderResult<Vec<_>>>()?,
+        ),
+        PrismaValue::Null => Value::Null,
+    };
+
+    Ok(json)
+}
+
+fn find_field<'a>(
+    composite_type: &'a InternalCompositeType,
+    name: &str,
+) -> QueryGraphBuilderResult<&'a InternalCompositeTypeField> {
+    composite_type.find_field(name).ok_or_else(|| {
+        QueryGraphBuilderError::InputError(format!(
+            "Field '{}' not found on composite type '{}'.",
+            name, composite_type.name
+        ))
+    })
+}
+
+fn find_composite_type<'a>(
+    internal_data_model: &'a InternalDataModelRef,
+    name: &str,
+) -> QueryGraphBuilderResult<&'a InternalCompositeType> {
+    Ok(internal_data_model.find_composite_type(name)?)
+}
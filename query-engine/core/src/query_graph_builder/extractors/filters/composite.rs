@@ -0,0 +1,54 @@
+use super::super::composite_where_json;
+use crate::{ParsedInputMap, ParsedInputValue, QueryGraphBuilderResult};
+use connector::{Filter, ScalarCompare};
+use prisma_models::{PrismaValue, ScalarFieldRef};
+use serde_json::json;
+use std::convert::TryInto;
+
+/// Composite type filters match by JSON containment: a value matches if it contains the JSON
+/// built from the filter. Unset values don't contain anything, so negated filters include them
+/// explicitly.
+pub fn parse(filter_key: &str, field: &ScalarFieldRef, input: ParsedInputValue) -> QueryGraphBuilderResult<Filter> {
+    let filter = match (filter_key, input) {
+        ("is", ParsedInputValue::Single(PrismaValue::Null)) => field.equals(PrismaValue::Null),
+        ("isNot", ParsedInputValue::Single(PrismaValue::Null)) => field.not_equals(PrismaValue::Null),
+
+        ("is", input) => field.json_contains(json_value(where_json(field, input.try_into()?)?)),
+        ("isNot", input) => unless_contains(field, where_json(field, input.try_into()?)?),
+
+        ("some", input) => field.json_contains(json_value(json!([where_json(field, input.try_into()?)?]))),
+        ("none", input) => unless_contains(field, json!([where_json(field, input.try_into()?)?])),
+
+        // Any element of a list contains the empty object.
+        ("isEmpty", input) => {
+            let is_empty: bool = input.try_into()?;
+
+            if is_empty {
+                unless_contains(field, json!([{}]))
+            } else {
+                field.json_contains(json_value(json!([{}])))
+            }
+        }
+
+        (key, _) => unreachable!("Invalid composite filter: {}", key),
+    };
+
+    Ok(filter)
+}
+
+fn where_json(field: &ScalarFieldRef, input: ParsedInputMap) -> QueryGraphBuilderResult<serde_json::Value> {
+    let composite_type = field.composite_type().unwrap();
+    composite_where_json(input, &composite_type, &field.internal_data_model())
+}
+
+/// Matches values that are unset or don't contain the given JSON.
+fn unless_contains(field: &ScalarFieldRef, json: serde_json::Value) -> Filter {
+    Filter::or(vec![
+        field.equals(PrismaValue::Null),
+        Filter::not(vec![field.json_contains(json_value(json))]),
+    ])
+}
+
+fn json_value(json: serde_json::Value) -> PrismaValue {
+    PrismaValue::Json(json.to_string())
+}
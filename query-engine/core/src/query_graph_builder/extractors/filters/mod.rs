@@ -1,3 +1,4 @@
+mod composite;
 mod filter_grouping;
 mod relation;
 mod scalar;
@@ -113,6 +114,7 @@ pub fn extract_filter(value_map: ParsedInputMap, model: &ModelRef) -> QueryGraph
                     Err(_) => {
                         let filters = match model.fields().find_from_all(&key)? {
                             Field::Relation(rf) => extract_relation_filters(rf, value),
+                            Field::Scalar(sf) if sf.is_composite() => extract_composite_filters(sf, value),
                             Field::Scalar(sf) => extract_scalar_filters(sf, value),
                         }?;
 
@@ -169,6 +171,23 @@ fn extract_scalar_filters(field: &ScalarFieldRef, value: ParsedInputValue) -> Qu
     }
 }
 
+/// Field is the composite type field the filter is refering to and `value` is the passed filter.
+/// `value` is either `null` (for shorthand null-equality notation) or a filter object.
+fn extract_composite_filters(field: &ScalarFieldRef, value: ParsedInputValue) -> QueryGraphBuilderResult<Vec<Filter>> {
+    match value {
+        ParsedInputValue::Single(PrismaValue::Null) => Ok(vec![field.equals(PrismaValue::Null)]),
+        ParsedInputValue::Map(filter_map) => filter_map
+            .into_iter()
+            .map(|(k, v)| composite::parse(&k, field, v))
+            .collect::<QueryGraphBuilderResult<Vec<_>>>(),
+
+        x => Err(QueryGraphBuilderError::InputError(format!(
+            "Invalid composite filter input: {:?}",
+            x
+        ))),
+    }
+}
+
 /// Field is the field the filter is refering to and `value` is the passed filter. E.g. `where: { <field>: <value> }.
 /// `value` can be either a filter object (for shorthand filter notation) or an object (full filter syntax).
 fn extract_relation_filters(field: &RelationFieldRef, value: ParsedInputValue) -> QueryGraphBuilderResult<Vec<Filter>> {
@@ -63,6 +63,7 @@ pub fn parse(
         "contains" => field.contains(as_prisma_value(input)?),
         "startsWith" => field.starts_with(as_prisma_value(input)?),
         "endsWith" => field.ends_with(as_prisma_value(input)?),
+        "search" => field.search(as_prisma_value(input)?),
 
         "lt" if reverse => field.greater_than_or_equals(as_prisma_value(input)?),
         "gt" if reverse => field.less_than_or_equals(as_prisma_value(input)?),
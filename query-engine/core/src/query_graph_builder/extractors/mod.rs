@@ -1,7 +1,9 @@
+mod composites;
 mod filters;
 mod query_arguments;
 mod utils;
 
+pub(crate) use composites::*;
 pub use filters::*;
 pub use query_arguments::*;
 
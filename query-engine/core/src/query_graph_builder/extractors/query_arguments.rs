@@ -5,9 +5,10 @@ use crate::{
 };
 use connector::QueryArguments;
 use prisma_models::{
-    Field, ModelProjection, ModelRef, OrderBy, PrismaValue, RecordProjection, ScalarFieldRef, SortOrder,
+    Field, ModelProjection, ModelRef, OrderBy, OrderByRelevance, PrismaValue, RecordProjection, ScalarFieldRef,
+    SortOrder,
 };
-use std::convert::{identity, TryInto};
+use std::convert::TryInto;
 
 /// Expects the caller to know that it is structurally guaranteed that query arguments can be extracted,
 /// e.g. that the query schema guarantees that required fields are present.
@@ -33,10 +34,15 @@ pub fn extract_query_args(arguments: Vec<ParsedArgument>, model: &ModelRef) -> Q
                         ..res
                     }),
 
-                    "orderBy" => Ok(QueryArguments {
-                        order_by: extract_order_by(model, arg.value)?,
-                        ..res
-                    }),
+                    "orderBy" => {
+                        let (order_by, order_by_relevance) = extract_order_by(model, arg.value)?;
+
+                        Ok(QueryArguments {
+                            order_by,
+                            order_by_relevance,
+                            ..res
+                        })
+                    }
 
                     "distinct" => Ok(QueryArguments {
                         distinct: Some(extract_distinct(arg.value)?),
@@ -62,61 +68,80 @@ pub fn extract_query_args(arguments: Vec<ParsedArgument>, model: &ModelRef) -> Q
         },
     )?;
 
-    Ok(finalize_arguments(query_args, model))
+    finalize_arguments(query_args, model)
 }
 
-/// Extracts order by conditions in order of appearance, as defined in
-fn extract_order_by(model: &ModelRef, value: ParsedInputValue) -> QueryGraphBuilderResult<Vec<OrderBy>> {
-    match value {
+/// Extracts order by conditions in order of appearance, as defined in the query.
+/// A `_relevance` ordering is extracted separately, as it always takes precedence.
+fn extract_order_by(
+    model: &ModelRef,
+    value: ParsedInputValue,
+) -> QueryGraphBuilderResult<(Vec<OrderBy>, Option<OrderByRelevance>)> {
+    let objects: Vec<ParsedInputMap> = match value {
         ParsedInputValue::List(list) => list
             .into_iter()
-            .map(|list_value| {
-                let object: ParsedInputMap = list_value.try_into()?;
-
-                match object.into_iter().next() {
-                    None => Ok(None),
-                    Some((field_name, sort_order)) => {
-                        let field = model.fields().find_from_scalar(&field_name)?;
-                        let value: PrismaValue = sort_order.try_into()?;
-                        let sort_order = match value.into_string().unwrap().to_lowercase().as_str() {
-                            "asc" => SortOrder::Ascending,
-                            "desc" => SortOrder::Descending,
-                            _ => unreachable!(),
-                        };
-
-                        Ok(Some(OrderBy::new(field, sort_order)))
-                    }
-                }
-            })
-            .collect::<QueryGraphBuilderResult<Vec<_>>>()
-            .map(|results| results.into_iter().filter_map(identity).collect()),
+            .map(|list_value| Ok(list_value.try_into()?))
+            .collect::<QueryGraphBuilderResult<Vec<_>>>()?,
 
-        ParsedInputValue::Map(map) => Ok(match process_order_object(model, map)? {
-            Some(order) => vec![order],
-            None => vec![],
-        }),
+        ParsedInputValue::Map(map) => vec![map],
 
         _ => unreachable!(),
-    }
-}
+    };
 
-fn process_order_object(model: &ModelRef, object: ParsedInputMap) -> QueryGraphBuilderResult<Option<OrderBy>> {
-    // let object: ParsedInputMap = list_value.try_into()?;
-
-    match object.into_iter().next() {
-        None => Ok(None),
-        Some((field_name, sort_order)) => {
-            let field = model.fields().find_from_scalar(&field_name)?;
-            let value: PrismaValue = sort_order.try_into()?;
-            let sort_order = match value.into_string().unwrap().to_lowercase().as_str() {
-                "asc" => SortOrder::Ascending,
-                "desc" => SortOrder::Descending,
-                _ => unreachable!(),
-            };
-
-            Ok(Some(OrderBy::new(field, sort_order)))
+    let mut order_by = vec![];
+    let mut order_by_relevance = None;
+
+    for object in objects {
+        match object.into_iter().next() {
+            None => (),
+            Some((field_name, value)) if field_name == "_relevance" => {
+                if order_by_relevance.is_some() {
+                    return Err(QueryGraphBuilderError::InputError(
+                        "Only one `_relevance` ordering can be used per query.".to_owned(),
+                    ));
+                }
+
+                order_by_relevance = Some(extract_order_by_relevance(value)?);
+            }
+            Some((field_name, sort_order)) => {
+                let field = model.fields().find_from_scalar(&field_name)?;
+                order_by.push(OrderBy::new(field, extract_sort_order(sort_order)?));
+            }
         }
     }
+
+    Ok((order_by, order_by_relevance))
+}
+
+fn extract_order_by_relevance(value: ParsedInputValue) -> QueryGraphBuilderResult<OrderByRelevance> {
+    let mut object: ParsedInputMap = value.try_into()?;
+
+    let fields = match object.remove("fields").unwrap() {
+        ParsedInputValue::List(list) => list
+            .into_iter()
+            .map(|element| Ok(element.try_into()?))
+            .collect::<QueryGraphBuilderResult<Vec<ScalarFieldRef>>>()?,
+        single => vec![single.try_into()?],
+    };
+
+    let search: PrismaValue = object.remove("search").unwrap().try_into()?;
+    let sort_order = extract_sort_order(object.remove("sort").unwrap())?;
+
+    Ok(OrderByRelevance {
+        fields,
+        search: search.into_string().unwrap(),
+        sort_order,
+    })
+}
+
+fn extract_sort_order(value: ParsedInputValue) -> QueryGraphBuilderResult<SortOrder> {
+    let value: PrismaValue = value.try_into()?;
+
+    Ok(match value.into_string().unwrap().to_lowercase().as_str() {
+        "asc" => SortOrder::Ascending,
+        "desc" => SortOrder::Descending,
+        _ => unreachable!(),
+    })
 }
 
 fn extract_distinct(value: ParsedInputValue) -> QueryGraphBuilderResult<ModelProjection> {
@@ -194,8 +219,18 @@ fn extract_compound_cursor_field(
     Ok(pairs)
 }
 
-/// Runs final transformations on the QueryArguments.
-fn finalize_arguments(mut args: QueryArguments, model: &ModelRef) -> QueryArguments {
+/// Runs final transformations and checks on the QueryArguments.
+pub(crate) fn finalize_arguments(
+    mut args: QueryArguments,
+    model: &ModelRef,
+) -> QueryGraphBuilderResult<QueryArguments> {
+    // Relevance can't be compared against a cursor record, which cursor pagination relies on.
+    if args.cursor.is_some() && args.order_by_relevance.is_some() {
+        return Err(QueryGraphBuilderError::InputError(
+            "Ordering by `_relevance` can't be combined with cursor-based pagination.".to_owned(),
+        ));
+    }
+
     // Check if the query requires an implicit ordering added to the arguments.
     // An implicit ordering is convenient for deterministic results for take and skip, for cursor it's _required_
     // as a cursor needs a direction to page. We simply take the primary identifier as a default order-by.
@@ -213,5 +248,5 @@ fn finalize_arguments(mut args: QueryArguments, model: &ModelRef) -> QueryArgume
         args.order_by.extend(order_bys);
     }
 
-    args
+    Ok(args)
 }
@@ -0,0 +1,214 @@
+use super::*;
+use crate::{query_document::*, schema::QueryTag};
+use prisma_models::ModelRef;
+use prisma_value::PrismaValue;
+use std::fmt;
+
+/// The number of records a list field is assumed to return if the query doesn't say how many
+/// records to take. Used to estimate the cost of a query.
+pub const ESTIMATED_LIST_SIZE: u64 = 100;
+
+/// Limits on the shape and size of queries, checked before a query is executed.
+/// No limits are enforced by default.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct QueryLimits {
+    /// Maximum depth of the selection. The top-level field is at depth one,
+    /// e.g. `findManyUser { posts { id } }` has a depth of three.
+    pub max_selection_depth: Option<u64>,
+
+    /// Maximum depth of nested writes. A write without nested writes is at depth zero,
+    /// e.g. creating a user with posts has a depth of one.
+    pub max_nested_write_depth: Option<u64>,
+
+    /// Maximum number of operations in a batch.
+    pub max_batch_size: Option<u64>,
+
+    /// Maximum number of records a single `take`, `first` or `last` argument can ask for.
+    pub max_take: Option<u64>,
+
+    /// Maximum estimated cost of a query: the number of records it may read, multiplied along
+    /// nested relations. Lists without a `take` are assumed to hold `ESTIMATED_LIST_SIZE` records.
+    pub max_cost: Option<u64>,
+}
+
+/// The limit a query exceeded.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum QueryLimit {
+    SelectionDepth,
+    NestedWriteDepth,
+    BatchSize,
+    Take,
+    Cost,
+}
+
+impl fmt::Display for QueryLimit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SelectionDepth => write!(f, "selection depth"),
+            Self::NestedWriteDepth => write!(f, "nested write depth"),
+            Self::BatchSize => write!(f, "batch size"),
+            Self::Take => write!(f, "take"),
+            Self::Cost => write!(f, "estimated cost"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct QueryLimitViolation {
+    pub limit: QueryLimit,
+    pub value: u64,
+    pub max: u64,
+}
+
+impl QueryLimits {
+    /// Checks the number of operations of a batch.
+    pub fn check_batch_size(&self, size: usize) -> QueryGraphBuilderResult<()> {
+        check(QueryLimit::BatchSize, size as u64, self.max_batch_size)
+    }
+
+    /// Checks the depth of a selection, before it's parsed.
+    pub(crate) fn check_selection(&self, selection: &Selection) -> QueryGraphBuilderResult<()> {
+        match self.max_selection_depth {
+            Some(max) => check(QueryLimit::SelectionDepth, selection_depth(selection), Some(max)),
+            None => Ok(()),
+        }
+    }
+
+    /// Checks the `take` arguments, the estimated cost and the nested writes of a parsed top-level field.
+    pub(crate) fn check_field(&self, field_pair: &FieldPair) -> QueryGraphBuilderResult<()> {
+        if let Some(max) = self.max_take {
+            check(QueryLimit::Take, max_take(field_pair), Some(max))?;
+        }
+
+        if let Some(max) = self.max_cost {
+            check(QueryLimit::Cost, cost(field_pair, 1), Some(max))?;
+        }
+
+        if let Some(max) = self.max_nested_write_depth {
+            let query_info = field_pair.schema_field.query_info.as_ref();
+
+            let depth = match query_info.map(|info| (&info.tag, info.model.as_ref())) {
+                Some((QueryTag::CreateOne, Some(model)))
+                | Some((QueryTag::UpdateOne, Some(model)))
+                | Some((QueryTag::UpdateMany, Some(model)))
+                | Some((QueryTag::UpsertOne, Some(model))) => field_pair
+                    .parsed_field
+                    .arguments
+                    .iter()
+                    .filter(|arg| arg.name != "where")
+                    .map(|arg| nested_write_depth(model, &arg.value))
+                    .max()
+                    .unwrap_or(0),
+                _ => 0,
+            };
+
+            check(QueryLimit::NestedWriteDepth, depth, Some(max))?;
+        }
+
+        Ok(())
+    }
+}
+
+fn check(limit: QueryLimit, value: u64, max: Option<u64>) -> QueryGraphBuilderResult<()> {
+    match max {
+        Some(max) if value > max => Err(QueryGraphBuilderError::QueryLimitExceeded(QueryLimitViolation {
+            limit,
+            value,
+            max,
+        })),
+        _ => Ok(()),
+    }
+}
+
+fn selection_depth(selection: &Selection) -> u64 {
+    1 + selection
+        .nested_selections()
+        .iter()
+        .map(selection_depth)
+        .max()
+        .unwrap_or(0)
+}
+
+/// The number of records the field asks for with `take`, `first` or `last`, if any.
+fn page_size(field: &ParsedField) -> Option<u64> {
+    field
+        .arguments
+        .iter()
+        .filter(|arg| matches!(arg.name.as_str(), "take" | "first" | "last"))
+        .filter_map(|arg| match arg.value {
+            ParsedInputValue::Single(PrismaValue::Int(i)) => Some(i.checked_abs().unwrap_or(i64::MAX) as u64),
+            _ => None,
+        })
+        .max()
+}
+
+fn max_take(field_pair: &FieldPair) -> u64 {
+    let nested = field_pair
+        .parsed_field
+        .nested_fields
+        .iter()
+        .flat_map(|nested| nested.fields.iter())
+        .map(max_take);
+
+    page_size(&field_pair.parsed_field)
+        .into_iter()
+        .chain(nested)
+        .max()
+        .unwrap_or(0)
+}
+
+/// The estimated number of records read for the field and its nested fields,
+/// if the field is read `multiplier` times.
+fn cost(field_pair: &FieldPair, multiplier: u64) -> u64 {
+    let nested = match field_pair.parsed_field.nested_fields {
+        Some(ref nested) => nested,
+        None => return 0,
+    };
+
+    // Composite values are read as part of their record.
+    let is_composite = field_pair
+        .schema_field
+        .field_type
+        .as_object_type()
+        .map_or(false, |obj| obj.is_composite());
+
+    if is_composite {
+        return 0;
+    }
+
+    let records = if field_pair.schema_field.field_type.is_list() {
+        page_size(&field_pair.parsed_field).unwrap_or(ESTIMATED_LIST_SIZE)
+    } else {
+        1
+    };
+
+    let records = multiplier.saturating_mul(records);
+
+    nested
+        .fields
+        .iter()
+        .fold(records, |total, field| total.saturating_add(cost(field, records)))
+}
+
+/// The depth of the nested writes in a write argument: every relation field written through
+/// adds a level. Filters under `where` keys are skipped, other keys, like the nested write
+/// operations, are looked through.
+fn nested_write_depth(model: &ModelRef, value: &ParsedInputValue) -> u64 {
+    match value {
+        ParsedInputValue::List(values) => values
+            .iter()
+            .map(|value| nested_write_depth(model, value))
+            .max()
+            .unwrap_or(0),
+        ParsedInputValue::Map(map) => map
+            .iter()
+            .filter(|(name, _)| name.as_str() != "where")
+            .map(|(name, value)| match model.fields().find_from_relation_fields(name) {
+                Ok(rf) => 1 + nested_write_depth(&rf.related_model(), value),
+                Err(_) => nested_write_depth(model, value),
+            })
+            .max()
+            .unwrap_or(0),
+        _ => 0,
+    }
+}
@@ -4,6 +4,7 @@
 mod builder;
 mod error;
 mod extractors;
+mod limits;
 mod read;
 
 pub mod write;
@@ -11,6 +12,7 @@ pub mod write;
 pub use builder::*;
 pub use error::*;
 pub use extractors::*;
+pub use limits::*;
 pub use read::*;
 
 /// Query graph builder sub-result type.
@@ -21,6 +21,12 @@ pub fn group_by(mut field: ParsedField, model: ModelRef) -> QueryGraphBuilderRes
         .map(|field| resolve_query(field, &model))
         .collect::<QueryGraphBuilderResult<_>>()?;
 
+    if args.order_by_relevance.is_some() {
+        return Err(QueryGraphBuilderError::InputError(
+            "Ordering by `_relevance` is not supported for groupBy queries.".to_owned(),
+        ));
+    }
+
     verify_selections(&selectors, &group_by).and_then(|_| verify_orderings(&args.order_by, &group_by))?;
 
     Ok(ReadQuery::AggregateRecordsQuery(AggregateRecordsQuery {
@@ -0,0 +1,191 @@
+use super::*;
+use crate::{
+    query_document::*, ConnectionField, ConnectionQuery, ConnectionSelection, EdgeField, ManyRecordsQuery, ReadQuery,
+};
+use prisma_models::ModelRef;
+use std::{collections::HashSet, convert::TryInto};
+
+/// Builds a connection query from a parsed incoming connection query field.
+/// `first`/`after` page forwards, `last`/`before` page backwards from the cursor or the end of the records.
+pub fn find_connection(field: ParsedField, model: ModelRef) -> QueryGraphBuilderResult<ReadQuery> {
+    let name = field.name;
+    let alias = field.alias;
+    let mut arguments = field.arguments;
+
+    let first = page_size_arg(&mut arguments, "first")?;
+    let last = page_size_arg(&mut arguments, "last")?;
+    let after = cursor_arg(&mut arguments, "after", &model)?;
+    let before = cursor_arg(&mut arguments, "before", &model)?;
+
+    if first.is_some() && last.is_some() {
+        return Err(QueryGraphBuilderError::InputError(
+            "The `first` and `last` arguments can not be used together.".to_owned(),
+        ));
+    }
+
+    if after.is_some() && before.is_some() {
+        return Err(QueryGraphBuilderError::InputError(
+            "The `after` and `before` arguments can not be used together.".to_owned(),
+        ));
+    }
+
+    if (first.is_some() && before.is_some()) || (last.is_some() && after.is_some()) {
+        return Err(QueryGraphBuilderError::InputError(
+            "The `first` argument can only be combined with `after`, and `last` only with `before`.".to_owned(),
+        ));
+    }
+
+    if before.is_some() && last.is_none() {
+        return Err(QueryGraphBuilderError::InputError(
+            "The `before` argument requires the `last` argument.".to_owned(),
+        ));
+    }
+
+    let backwards = last.is_some();
+    let page_size = first.or(last);
+
+    let mut args = extractors::extract_query_args(arguments, &model)?;
+    args.skip = after.as_ref().or_else(|| before.as_ref()).map(|_| 1);
+    args.cursor = after.or(before);
+
+    // Probe for one more record than requested to find out if there is another page.
+    args.take = page_size.map(|size| {
+        let take = size as i64 + 1;
+
+        if backwards {
+            -take
+        } else {
+            take
+        }
+    });
+
+    let args = extractors::finalize_arguments(args, &model)?;
+
+    let (selection, node_fields) = collect_connection_selection(field.nested_fields.unwrap().fields);
+    let selection_order = utils::collect_selection_order(&node_fields);
+    let selected_fields = utils::collect_selected_fields(&node_fields, &model);
+    let composite_selections = utils::collect_composite_selections(&node_fields, &model);
+    let nested = utils::collect_nested_queries(node_fields, &model)?;
+    let selected_fields = utils::merge_relation_selections(selected_fields, None, &nested);
+    let selected_fields = utils::merge_cursor_fields(selected_fields, &args.cursor);
+
+    let records = ManyRecordsQuery {
+        name: name.clone(),
+        alias: alias.clone(),
+        model,
+        args,
+        selected_fields,
+        nested,
+        selection_order,
+        composite_selections,
+    };
+
+    Ok(ReadQuery::ConnectionQuery(ConnectionQuery {
+        name,
+        alias,
+        records,
+        page_size,
+        backwards,
+        selection,
+    }))
+}
+
+fn page_size_arg(arguments: &mut Vec<ParsedArgument>, name: &str) -> QueryGraphBuilderResult<Option<usize>> {
+    let size: Option<i64> = match arguments.lookup(name) {
+        Some(arg) => arg.value.try_into()?,
+        None => None,
+    };
+
+    match size {
+        Some(size) if size < 0 => Err(QueryGraphBuilderError::InputError(format!(
+            "The `{}` argument must not be negative.",
+            name
+        ))),
+        size => Ok(size.map(|size| size as usize)),
+    }
+}
+
+fn cursor_arg(
+    arguments: &mut Vec<ParsedArgument>,
+    name: &str,
+    model: &ModelRef,
+) -> QueryGraphBuilderResult<Option<prisma_models::RecordProjection>> {
+    let cursor: Option<String> = match arguments.lookup(name) {
+        Some(arg) => arg.value.try_into()?,
+        None => None,
+    };
+
+    cursor
+        .map(|cursor| {
+            crate::decode_cursor(model, &cursor).ok_or_else(|| {
+                QueryGraphBuilderError::InputError(format!(
+                    "The `{}` argument is not a valid cursor for model {}.",
+                    name, model.name
+                ))
+            })
+        })
+        .transpose()
+}
+
+/// Collects the selection on the connection object, and the union of the fields selected
+/// on the nodes of `edges` and `nodes`. The first selection of a node field wins.
+fn collect_connection_selection(fields: Vec<FieldPair>) -> (ConnectionSelection, Vec<FieldPair>) {
+    let mut selection = ConnectionSelection::default();
+    let mut node_fields: Vec<FieldPair> = vec![];
+    let mut node_keys: HashSet<String> = HashSet::new();
+
+    let mut add_node_fields = |fields: Vec<FieldPair>| {
+        let order = utils::collect_selection_order(&fields);
+
+        for (pair, key) in fields.into_iter().zip(order.iter()) {
+            if node_keys.insert(key.clone()) {
+                node_fields.push(pair);
+            }
+        }
+
+        order
+    };
+
+    for pair in fields {
+        let field = pair.parsed_field;
+        let key = field.alias.clone().unwrap_or_else(|| field.name.clone());
+        let nested = field.nested_fields.map(|nested| nested.fields).unwrap_or_default();
+
+        let connection_field = match field.name.as_str() {
+            "edges" => ConnectionField::Edges(
+                nested
+                    .into_iter()
+                    .map(|edge_pair| {
+                        let edge_field = edge_pair.parsed_field;
+                        let edge_key = edge_field.alias.clone().unwrap_or_else(|| edge_field.name.clone());
+
+                        match edge_field.name.as_str() {
+                            "node" => {
+                                let node_fields = edge_field.nested_fields.map(|n| n.fields).unwrap_or_default();
+                                (edge_key, EdgeField::Node(add_node_fields(node_fields)))
+                            }
+                            _ => (edge_key, EdgeField::Cursor),
+                        }
+                    })
+                    .collect(),
+            ),
+            "nodes" => ConnectionField::Nodes(add_node_fields(nested)),
+            "pageInfo" => ConnectionField::PageInfo(
+                nested
+                    .into_iter()
+                    .map(|info_pair| {
+                        let info_field = info_pair.parsed_field;
+                        let info_key = info_field.alias.clone().unwrap_or_else(|| info_field.name.clone());
+
+                        (info_key, info_field.name)
+                    })
+                    .collect(),
+            ),
+            _ => ConnectionField::TotalCount,
+        };
+
+        selection.fields.push((key, connection_field));
+    }
+
+    (selection, node_fields)
+}
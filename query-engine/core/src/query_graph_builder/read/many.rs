@@ -9,6 +9,7 @@ pub fn find_many(field: ParsedField, model: ModelRef) -> QueryGraphBuilderResult
     let nested_fields = field.nested_fields.unwrap().fields;
     let selection_order: Vec<String> = utils::collect_selection_order(&nested_fields);
     let selected_fields = utils::collect_selected_fields(&nested_fields, &model);
+    let composite_selections = utils::collect_composite_selections(&nested_fields, &model);
     let nested = utils::collect_nested_queries(nested_fields, &model)?;
     let model = model;
 
@@ -23,5 +24,6 @@ pub fn find_many(field: ParsedField, model: ModelRef) -> QueryGraphBuilderResult
         selected_fields,
         nested,
         selection_order,
+        composite_selections,
     }))
 }
@@ -1,17 +1,23 @@
 //! Unwraps in this module are safe because of query validation that ensures conformity to the query schema.
 
 mod aggregations;
+mod connection;
 mod first;
 mod many;
 mod one;
 mod related;
+mod traversal;
 mod utils;
 
 pub use aggregations::*;
+pub use connection::*;
 pub use first::*;
 pub use many::*;
 pub use one::*;
 pub use related::*;
+pub use traversal::*;
+
+pub(crate) use utils::collect_composite_selections;
 
 use super::*;
 use crate::{Query, QueryGraph, ReadQuery};
@@ -19,6 +19,7 @@ pub fn find_one(mut field: ParsedField, model: ModelRef) -> QueryGraphBuilderRes
     let nested_fields = field.nested_fields.unwrap().fields;
     let selection_order: Vec<String> = utils::collect_selection_order(&nested_fields);
     let selected_fields = utils::collect_selected_fields(&nested_fields, &model);
+    let composite_selections = utils::collect_composite_selections(&nested_fields, &model);
     let nested = utils::collect_nested_queries(nested_fields, &model)?;
     let selected_fields = utils::merge_relation_selections(selected_fields, None, &nested);
 
@@ -30,5 +31,6 @@ pub fn find_one(mut field: ParsedField, model: ModelRef) -> QueryGraphBuilderRes
         selected_fields,
         nested,
         selection_order,
+        composite_selections,
     }))
 }
@@ -0,0 +1,95 @@
+use super::*;
+use crate::{query_document::*, ManyRecordsQuery, ReadQuery, TraversalField, TraversalQuery};
+use connector::QueryArguments;
+use prisma_models::ModelRef;
+use std::{collections::HashSet, convert::TryInto};
+
+/// Maximum depth of a recursive traversal. Also used if no `maxDepth` is given,
+/// which guards against endless traversals of cyclic data.
+pub const MAX_TRAVERSAL_DEPTH: usize = 100;
+
+/// Builds a traversal query from a parsed incoming recursive query field.
+pub fn find_recursive(mut field: ParsedField, model: ModelRef) -> QueryGraphBuilderResult<ReadQuery> {
+    let where_arg: ParsedInputMap = field.arguments.lookup("where").unwrap().value.try_into()?;
+    let filter = extractors::extract_unique_filter(where_arg, &model)?;
+
+    let relation: Option<String> = field.arguments.lookup("relation").unwrap().value.try_into()?;
+    let relation_field = model.fields().find_from_relation_fields(&relation.unwrap())?;
+
+    let max_depth: Option<i64> = match field.arguments.lookup("maxDepth") {
+        Some(arg) => arg.value.try_into()?,
+        None => None,
+    };
+
+    let max_depth = match max_depth {
+        Some(depth) if depth < 1 || depth as usize > MAX_TRAVERSAL_DEPTH => {
+            return Err(QueryGraphBuilderError::InputError(format!(
+                "The `maxDepth` argument must be between 1 and {}.",
+                MAX_TRAVERSAL_DEPTH
+            )))
+        }
+        Some(depth) => depth as usize,
+        None => MAX_TRAVERSAL_DEPTH,
+    };
+
+    let (selection, node_fields) = collect_traversal_selection(field.nested_fields.unwrap().fields);
+    let selection_order = utils::collect_selection_order(&node_fields);
+    let selected_fields = utils::collect_selected_fields(&node_fields, &model);
+    let composite_selections = utils::collect_composite_selections(&node_fields, &model);
+    let nested = utils::collect_nested_queries(node_fields, &model)?;
+    let selected_fields = utils::merge_relation_selections(selected_fields, None, &nested);
+
+    let records = ManyRecordsQuery {
+        name: field.name.clone(),
+        alias: field.alias.clone(),
+        model: model.clone(),
+        args: QueryArguments::new(model),
+        selected_fields,
+        nested,
+        selection_order,
+        composite_selections,
+    };
+
+    Ok(ReadQuery::TraversalQuery(TraversalQuery {
+        name: field.name,
+        alias: field.alias,
+        field: relation_field,
+        filter,
+        max_depth,
+        records,
+        selection,
+    }))
+}
+
+/// Collects the selection on the traversal objects, and the union of the fields selected on their nodes.
+/// The first selection of a node field wins.
+fn collect_traversal_selection(fields: Vec<FieldPair>) -> (Vec<(String, TraversalField)>, Vec<FieldPair>) {
+    let mut selection = vec![];
+    let mut node_fields: Vec<FieldPair> = vec![];
+    let mut node_keys: HashSet<String> = HashSet::new();
+
+    for pair in fields {
+        let field = pair.parsed_field;
+        let key = field.alias.clone().unwrap_or_else(|| field.name.clone());
+
+        let traversal_field = match field.name.as_str() {
+            "node" => {
+                let fields = field.nested_fields.map(|nested| nested.fields).unwrap_or_default();
+                let order = utils::collect_selection_order(&fields);
+
+                for (pair, key) in fields.into_iter().zip(order.iter()) {
+                    if node_keys.insert(key.clone()) {
+                        node_fields.push(pair);
+                    }
+                }
+
+                TraversalField::Node(order)
+            }
+            _ => TraversalField::Depth,
+        };
+
+        selection.push((key, traversal_field));
+    }
+
+    (selection, node_fields)
+}
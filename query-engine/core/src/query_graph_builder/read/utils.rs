@@ -1,6 +1,8 @@
 use super::*;
-use crate::{FieldPair, ReadQuery};
-use prisma_models::{Field, ModelProjection, ModelRef, RecordProjection, RelationFieldRef};
+use crate::{CompositeSelection, CompositeSelections, FieldPair, ReadQuery};
+use prisma_models::{
+    Field, InternalCompositeType, InternalDataModelRef, ModelProjection, ModelRef, RecordProjection, RelationFieldRef,
+};
 use std::sync::Arc;
 
 pub fn collect_selection_order(from: &[FieldPair]) -> Vec<String> {
@@ -35,6 +37,49 @@ pub fn collect_selected_fields(from: &[FieldPair], model: &ModelRef) -> ModelPro
     model_id.merge(selected_projection)
 }
 
+/// Collects the selections within the composite type fields of the model.
+/// Unwraps are safe due to query validation.
+pub fn collect_composite_selections(from: &[FieldPair], model: &ModelRef) -> CompositeSelections {
+    let internal_data_model = model.internal_data_model();
+
+    from.iter()
+        .filter_map(|pair| {
+            let field = model.fields().find_from_scalar(&pair.parsed_field.name).ok()?;
+            let composite_type = field.composite_type()?;
+            let selection = collect_composite_selection(pair, &composite_type, &internal_data_model);
+
+            Some((field.name.clone(), selection))
+        })
+        .collect()
+}
+
+fn collect_composite_selection(
+    pair: &FieldPair,
+    composite_type: &InternalCompositeType,
+    internal_data_model: &InternalDataModelRef,
+) -> CompositeSelection {
+    let nested_fields = &pair.parsed_field.nested_fields.as_ref().unwrap().fields;
+
+    let nested = nested_fields
+        .iter()
+        .filter_map(|nested_pair| {
+            let field = composite_type.find_field(&nested_pair.parsed_field.name)?;
+            let nested_type = internal_data_model
+                .find_composite_type(field.composite_type.as_ref()?)
+                .unwrap();
+
+            let selection = collect_composite_selection(nested_pair, nested_type, internal_data_model);
+
+            Some((field.name.clone(), selection))
+        })
+        .collect();
+
+    CompositeSelection {
+        fields: collect_selection_order(nested_fields),
+        nested,
+    }
+}
+
 pub fn collect_nested_queries(from: Vec<FieldPair>, model: &ModelRef) -> QueryGraphBuilderResult<Vec<ReadQuery>> {
     from.into_iter()
         .filter_map(|pair| {
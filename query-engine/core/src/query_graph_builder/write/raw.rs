@@ -1,25 +1,27 @@
 use super::*;
 use crate::{query_ast::*, query_graph::QueryGraph, ArgumentListLookup, ParsedField};
+use prisma_models::{Field, ModelProjection, ModelRef};
 use prisma_value::PrismaValue;
 use std::convert::TryInto;
 
 pub fn execute_raw(graph: &mut QueryGraph, field: ParsedField) -> QueryGraphBuilderResult<()> {
-    let raw_query = Query::Write(WriteQuery::ExecuteRaw(raw_query(field)?));
+    let raw_query = Query::Write(WriteQuery::ExecuteRaw(raw_query(field, None)?));
 
     graph.create_node(raw_query);
     Ok(())
 }
 
-pub fn query_raw(graph: &mut QueryGraph, field: ParsedField) -> QueryGraphBuilderResult<()> {
-    let raw_query = Query::Write(WriteQuery::QueryRaw(raw_query(field)?));
+pub fn query_raw(graph: &mut QueryGraph, field: ParsedField, model: Option<ModelRef>) -> QueryGraphBuilderResult<()> {
+    let raw_query = Query::Write(WriteQuery::QueryRaw(raw_query(field, model)?));
 
     graph.create_node(raw_query);
     Ok(())
 }
 
-fn raw_query(mut field: ParsedField) -> QueryGraphBuilderResult<RawQuery> {
+fn raw_query(mut field: ParsedField, model: Option<ModelRef>) -> QueryGraphBuilderResult<RawQuery> {
     let query_arg = field.arguments.lookup("query").unwrap().value;
     let parameters_arg = field.arguments.lookup("parameters");
+    let typed_arg = field.arguments.lookup("typed");
 
     let query_value: PrismaValue = query_arg.try_into()?;
     let parameters: Vec<PrismaValue> = match parameters_arg {
@@ -30,8 +32,57 @@ fn raw_query(mut field: ParsedField) -> QueryGraphBuilderResult<RawQuery> {
         None => vec![],
     };
 
+    let typed = match typed_arg {
+        Some(parsed) => {
+            let val: PrismaValue = parsed.value.try_into()?;
+            val == PrismaValue::Boolean(true)
+        }
+        None => false,
+    };
+
+    let mode = match model {
+        Some(model) => RawQueryMode::Model(raw_model_selection(field, model)?),
+        None if typed => RawQueryMode::Typed,
+        None => RawQueryMode::Json,
+    };
+
     Ok(RawQuery {
         query: query_value.into_string().unwrap(),
         parameters,
+        mode,
+    })
+}
+
+/// The records of a raw query are read from its columns alone, so only scalar
+/// fields can be selected. Only the selected fields need a column, the primary
+/// identifier isn't required.
+fn raw_model_selection(field: ParsedField, model: ModelRef) -> QueryGraphBuilderResult<RawModelSelection> {
+    let nested_fields = field.nested_fields.unwrap().fields;
+    let composite_selections = collect_composite_selections(&nested_fields, &model);
+    let mut selected_fields = Vec::with_capacity(nested_fields.len());
+    let mut selection_order = Vec::with_capacity(nested_fields.len());
+
+    for pair in nested_fields {
+        let selected = pair.parsed_field;
+
+        match model.fields().find_from_scalar(&selected.name) {
+            Ok(sf) => selected_fields.push(Field::from(sf)),
+            Err(_) => {
+                return Err(QueryGraphBuilderError::InputError(format!(
+                    "Raw queries mapped onto model `{}` can only select scalar fields, but `{}` was selected.",
+                    model.name, selected.name
+                )))
+            }
+        }
+
+        selection_order.push(selected.alias.unwrap_or(selected.name));
+    }
+
+    Ok(RawModelSelection {
+        name: field.name,
+        model,
+        selected_fields: ModelProjection::new(selected_fields),
+        selection_order,
+        composite_selections,
     })
 }
@@ -3,10 +3,10 @@ use crate::query_graph_builder::write::write_args_parser::*;
 use crate::{
     query_ast::*,
     query_graph::{Node, NodeRef, QueryGraph, QueryGraphDependency},
-    ArgumentListLookup, ParsedField, ParsedInputMap,
+    ArgumentListLookup, ParsedField, ParsedInputMap, ParsedInputValue,
 };
-use connector::{Filter, IdFilter};
-use prisma_models::ModelRef;
+use connector::{Filter, IdFilter, ScalarCompare, WriteArgs};
+use prisma_models::{ModelRef, PrismaValue};
 use std::{convert::TryInto, sync::Arc};
 
 /// Creates an update record query and adds it to the query graph, together with it's nested queries and companion read query.
@@ -18,6 +18,7 @@ pub fn update_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedF
     // "data"
     let data_argument = field.arguments.lookup("data").unwrap();
     let data_map: ParsedInputMap = data_argument.value.try_into()?;
+    let expected_version = expected_version(&model, &data_map);
 
     let update_node = update_record_node(graph, filter, Arc::clone(&model), data_map)?;
 
@@ -31,9 +32,10 @@ pub fn update_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedF
         QueryGraphDependency::ParentProjection(
             model.primary_identifier(),
             Box::new(move |mut read_node, mut parent_ids| {
-                let parent_id = match parent_ids.pop() {
-                    Some(pid) => Ok(pid),
-                    None => Err(QueryGraphBuilderError::RecordNotFound(
+                let parent_id = match (parent_ids.pop(), expected_version) {
+                    (Some(pid), _) => Ok(pid),
+                    (None, Some(expected_version)) => Err(version_conflict(&model, expected_version)),
+                    (None, None) => Err(QueryGraphBuilderError::RecordNotFound(
                         "Record to update not found.".to_string(),
                     )),
                 }?;
@@ -70,6 +72,7 @@ pub fn update_many_records(
     let mut args = update_args.args;
     args.update_datetimes(Arc::clone(&model));
 
+    let filter = with_version_check(filter, &model, &mut args)?;
     let record_filter = filter.into();
     let update_many = WriteQuery::UpdateManyRecords(UpdateManyRecords {
         model,
@@ -98,7 +101,7 @@ where
 
     args.update_datetimes(Arc::clone(&model));
 
-    let filter = filter.into();
+    let filter = with_version_check(filter.into(), &model, &mut args)?;
     let record_filter = filter.into();
     let ur = UpdateRecord {
         model,
@@ -113,3 +116,47 @@ where
 
     Ok(node)
 }
+
+/// Optimistic concurrency control: Increments the `@version` of the model and,
+/// if the update expects a version, only updates records still having it.
+/// The connector checks the version again when writing the records, as they can change in between.
+fn with_version_check(filter: Filter, model: &ModelRef, args: &mut WriteArgs) -> QueryGraphBuilderResult<Filter> {
+    let expected_version = args.update_version(Arc::clone(model)).map_err(|_| {
+        QueryGraphBuilderError::InputError(format!(
+            "The version field of the model `{}` is incremented by every update, it can only be set to the version the record is expected to have.",
+            model.name
+        ))
+    })?;
+
+    match (expected_version, model.fields().version()) {
+        (Some(expected_version), Some(version_field)) => {
+            Ok(Filter::and(vec![filter, version_field.equals(expected_version)]))
+        }
+        _ => Ok(filter),
+    }
+}
+
+/// The version the update in `data_map` expects the record to have,
+/// if the model has a `@version` field and the data sets it.
+pub(crate) fn expected_version(model: &ModelRef, data_map: &ParsedInputMap) -> Option<PrismaValue> {
+    let field = model.fields().version().clone()?;
+
+    let value = match data_map.get(&field.name)? {
+        ParsedInputValue::Map(map) => map.get("set")?,
+        value => value,
+    };
+
+    match value {
+        ParsedInputValue::Single(PrismaValue::Null) => None,
+        ParsedInputValue::Single(pv) => Some(pv.clone()),
+        _ => None,
+    }
+}
+
+/// Error for an update that didn't find the record in the version it expected.
+pub(crate) fn version_conflict(model: &ModelRef, expected_version: PrismaValue) -> QueryGraphBuilderError {
+    QueryGraphBuilderError::VersionConflict {
+        model_name: model.name.clone(),
+        expected_version: expected_version.to_string(),
+    }
+}
@@ -22,8 +22,11 @@ pub fn upsert_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedF
     let read_parent_records = utils::read_ids_infallible(model.clone(), model_id.clone(), filter.clone());
     let read_parent_records_node = graph.create_node(read_parent_records);
 
+    let update_map: ParsedInputMap = update_argument.value.try_into()?;
+    let expected_version = update::expected_version(&model, &update_map);
+
     let create_node = create::create_record_node(graph, Arc::clone(&model), create_argument.value.try_into()?)?;
-    let update_node = update::update_record_node(graph, filter, Arc::clone(&model), update_argument.value.try_into()?)?;
+    let update_node = update::update_record_node(graph, filter, Arc::clone(&model), update_map)?;
 
     let read_query = read::find_one(field, Arc::clone(&model))?;
     let read_node_create = graph.create_node(Query::Read(read_query.clone()));
@@ -52,15 +55,18 @@ pub fn upsert_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedF
 
     graph.create_edge(&if_node, &update_node, QueryGraphDependency::Then)?;
     graph.create_edge(&if_node, &create_node, QueryGraphDependency::Else)?;
+    let update_model = Arc::clone(&model);
+
     graph.create_edge(
         &update_node,
         &read_node_update,
         QueryGraphDependency::ParentProjection(
             model_id.clone(),
             Box::new(move |mut read_node_update, mut parent_ids| {
-                let parent_id = match parent_ids.pop() {
-                    Some(pid) => Ok(pid),
-                    None => Err(QueryGraphBuilderError::AssertionError(
+                let parent_id = match (parent_ids.pop(), expected_version) {
+                    (Some(pid), _) => Ok(pid),
+                    (None, Some(expected_version)) => Err(update::version_conflict(&update_model, expected_version)),
+                    (None, None) => Err(QueryGraphBuilderError::AssertionError(
                         "Expected a valid parent ID to be present for create follow-up for upsert query.".to_string(),
                     )),
                 }?;
@@ -41,6 +41,7 @@ where
         selected_fields,
         nested: vec![],
         selection_order: vec![],
+        composite_selections: CompositeSelections::new(),
     });
 
     Query::Read(read_query)
@@ -114,6 +115,7 @@ where
         selected_fields,
         nested: vec![],
         selection_order: vec![],
+        composite_selections: CompositeSelections::new(),
     })));
 
     graph.create_edge(
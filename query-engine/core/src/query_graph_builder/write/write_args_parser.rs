@@ -20,14 +20,63 @@ impl WriteArgsParser {
                 let field = model.fields().find_from_all(&k).unwrap();
 
                 match field {
+                    // Composite type fields are always written with an envelope of exactly one operation.
+                    Field::Scalar(sf) if sf.is_composite() => {
+                        let composite_type = sf.composite_type().unwrap();
+                        let internal_data_model = model.internal_data_model();
+                        let map: ParsedInputMap = v.try_into()?;
+                        let (operation, value) = map.into_iter().next().unwrap();
+
+                        let expr: WriteExpression = match operation.as_str() {
+                            "set" if sf.is_list => {
+                                let json =
+                                    extractors::composite_list_json(value, &composite_type, &internal_data_model)?;
+                                WriteExpression::Value(PrismaValue::Json(json.to_string()))
+                            }
+                            "set" => {
+                                match extractors::composite_value_json(value, &composite_type, &internal_data_model)? {
+                                    serde_json::Value::Null => WriteExpression::Value(PrismaValue::Null),
+                                    json => WriteExpression::Value(PrismaValue::Json(json.to_string())),
+                                }
+                            }
+                            "update" => WriteExpression::UpdateComposite(extractors::composite_updates(
+                                value.try_into()?,
+                                &composite_type,
+                                &internal_data_model,
+                            )?),
+                            "push" => {
+                                let json =
+                                    extractors::composite_list_json(value, &composite_type, &internal_data_model)?;
+                                WriteExpression::Push(PrismaValue::Json(json.to_string()))
+                            }
+                            _ => unreachable!("Invalid composite update operation"),
+                        };
+
+                        args.args.insert(sf, expr)
+                    }
+
                     Field::Scalar(sf) if sf.is_list => {
-                        let set_value: PrismaValue = match v {
-                            ParsedInputValue::List(_) => v.try_into()?,
-                            ParsedInputValue::Map(mut map) => map.remove("set").unwrap().try_into()?,
+                        let expr: WriteExpression = match v {
+                            ParsedInputValue::List(_) => WriteExpression::Value(v.try_into()?),
+                            ParsedInputValue::Map(map) => {
+                                let (operation, value) = map.into_iter().next().unwrap();
+                                let value: PrismaValue = value.try_into()?;
+
+                                match operation.as_str() {
+                                    "set" => WriteExpression::Value(value),
+                                    "push" => WriteExpression::Push(value),
+                                    "remove" => WriteExpression::Remove(value),
+                                    "unset" if value == PrismaValue::Boolean(true) => {
+                                        WriteExpression::Value(PrismaValue::Null)
+                                    }
+                                    "unset" => return Ok(args),
+                                    _ => unreachable!("Invalid scalar list update operation"),
+                                }
+                            }
                             _ => unreachable!(),
                         };
 
-                        args.args.insert(sf, set_value)
+                        args.args.insert(sf, expr)
                     }
 
                     Field::Scalar(sf) => {
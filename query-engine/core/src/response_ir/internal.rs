@@ -1,13 +1,15 @@
 use super::*;
 use crate::{
     schema::{IntoArc, ObjectTypeStrongRef, OutputType, OutputTypeRef, ScalarType},
-    CoreError, DatabaseEnumType, EnumType, OutputFieldRef, QueryResult, RecordAggregations, RecordSelection,
+    CompositeSelection, ConnectionField, CoreError, DatabaseEnumType, EdgeField, EnumType, OutputFieldRef, QueryResult,
+    RecordAggregations, RecordConnection, RecordSelection, RecordTraversal, TraversalField,
 };
 use bigdecimal::ToPrimitive;
+use chrono::DateTime;
 use connector::AggregationResult;
 use indexmap::IndexMap;
 use prisma_models::{PrismaValue, RecordProjection};
-use std::{borrow::Borrow, collections::HashMap};
+use std::{borrow::Borrow, collections::HashMap, convert::TryFrom};
 
 /// A grouping of items to their parent record.
 /// The item implicitly holds the information of the type of item contained.
@@ -41,6 +43,8 @@ pub fn serialize_internal(
     match result {
         QueryResult::RecordSelection(rs) => serialize_record_selection(rs, field, &field.field_type, is_list),
         QueryResult::RecordAggregations(ras) => serialize_aggregations(field, ras),
+        QueryResult::RecordConnection(connection) => serialize_connection(field, connection),
+        QueryResult::RecordTraversal(traversal) => serialize_traversal(field, traversal),
 
         QueryResult::Count(c) => {
             // Todo needs a real implementation or needs to move to RecordAggregation
@@ -175,6 +179,160 @@ fn find_nested_aggregate_output_field(
     nested_object_type.find_field(nested_field_name).unwrap()
 }
 
+fn serialize_connection(
+    output_field: &OutputFieldRef,
+    connection: RecordConnection,
+) -> crate::Result<CheckedItemsWithParents> {
+    let connection_type = match output_field.field_type.borrow() {
+        OutputType::Object(obj) => obj.into_arc(),
+        _ => unreachable!("Connection output must be an object."),
+    };
+
+    let nodes_field = connection_type.find_field("nodes").unwrap();
+    let node_type = match nodes_field.field_type.borrow() {
+        OutputType::List(inner) => match inner.borrow() {
+            OutputType::Object(obj) => obj.into_arc(),
+            _ => unreachable!("Connection nodes must be objects."),
+        },
+        _ => unreachable!("Connection nodes must be a list."),
+    };
+
+    // All records are serialized with the union of the fields selected on `edges` and `nodes`,
+    // then narrowed down to the selection of each.
+    let nodes = serialize_objects(connection.records, node_type)?
+        .remove(&None)
+        .unwrap_or_default();
+
+    let cursors = connection.cursors;
+    let page_info = connection.page_info;
+    let mut map: Map = IndexMap::with_capacity(connection.selection.fields.len());
+
+    for (key, field) in connection.selection.fields {
+        let item = match field {
+            ConnectionField::Edges(edge_fields) => {
+                let edges = nodes
+                    .iter()
+                    .zip(cursors.iter())
+                    .map(|(node, cursor)| {
+                        let edge = edge_fields
+                            .iter()
+                            .map(|(edge_key, edge_field)| {
+                                let item = match edge_field {
+                                    EdgeField::Cursor => Item::Value(PrismaValue::String(cursor.clone())),
+                                    EdgeField::Node(order) => select_fields(node, order),
+                                };
+
+                                (edge_key.clone(), item)
+                            })
+                            .collect();
+
+                        Item::Map(edge)
+                    })
+                    .collect();
+
+                Item::list(edges)
+            }
+
+            ConnectionField::Nodes(order) => Item::list(nodes.iter().map(|node| select_fields(node, &order)).collect()),
+
+            ConnectionField::PageInfo(info_fields) => {
+                let info = info_fields
+                    .into_iter()
+                    .map(|(info_key, name)| {
+                        let value = match name.as_str() {
+                            "hasNextPage" => PrismaValue::Boolean(page_info.has_next_page),
+                            "hasPreviousPage" => PrismaValue::Boolean(page_info.has_previous_page),
+                            "startCursor" => cursor_value(&page_info.start_cursor),
+                            _ => cursor_value(&page_info.end_cursor),
+                        };
+
+                        (info_key, Item::Value(value))
+                    })
+                    .collect();
+
+                Item::Map(info)
+            }
+
+            ConnectionField::TotalCount => Item::Value(PrismaValue::Int(connection.total_count.unwrap_or(0) as i64)),
+        };
+
+        map.insert(key, item);
+    }
+
+    let mut result = CheckedItemsWithParents::new();
+    result.insert(None, Item::Map(map));
+
+    Ok(result)
+}
+
+fn serialize_traversal(
+    output_field: &OutputFieldRef,
+    traversal: RecordTraversal,
+) -> crate::Result<CheckedItemsWithParents> {
+    let traversal_type = match output_field.field_type.borrow() {
+        OutputType::List(inner) => match inner.borrow() {
+            OutputType::Object(obj) => obj.into_arc(),
+            _ => unreachable!("Traversal output must be a list of objects."),
+        },
+        _ => unreachable!("Traversal output must be a list."),
+    };
+
+    let node_field = traversal_type.find_field("node").unwrap();
+    let node_type = match node_field.field_type.borrow() {
+        OutputType::Object(obj) => obj.into_arc(),
+        _ => unreachable!("Traversal nodes must be objects."),
+    };
+
+    // All records are serialized with the union of the fields selected on the nodes,
+    // then narrowed down to the selection of each.
+    let nodes = serialize_objects(traversal.records, node_type)?
+        .remove(&None)
+        .unwrap_or_default();
+
+    let items = nodes
+        .iter()
+        .zip(traversal.depths.iter())
+        .map(|(node, depth)| {
+            let map = traversal
+                .selection
+                .iter()
+                .map(|(key, field)| {
+                    let item = match field {
+                        TraversalField::Depth => Item::Value(PrismaValue::Int(*depth as i64)),
+                        TraversalField::Node(order) => select_fields(node, order),
+                    };
+
+                    (key.clone(), item)
+                })
+                .collect();
+
+            Item::Map(map)
+        })
+        .collect();
+
+    let mut result = CheckedItemsWithParents::new();
+    result.insert(None, Item::list(items));
+
+    Ok(result)
+}
+
+/// Narrows a serialized object down to the given fields, in the given order.
+fn select_fields(object: &Item, fields: &[String]) -> Item {
+    match object {
+        Item::Map(map) => Item::Map(
+            fields
+                .iter()
+                .filter_map(|field| map.get(field).map(|item| (field.clone(), item.clone())))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+fn cursor_value(cursor: &Option<String>) -> PrismaValue {
+    cursor.clone().map(PrismaValue::String).unwrap_or(PrismaValue::Null)
+}
+
 fn coerce_non_numeric(value: PrismaValue, output: &OutputType) -> PrismaValue {
     match (value, output.borrow()) {
         (PrismaValue::Int(x), OutputType::Scalar(ScalarType::String)) if x == 0 => PrismaValue::Null,
@@ -280,7 +438,13 @@ fn serialize_objects(
     // Write all fields, nested and list fields unordered into a map, afterwards order all into the final order.
     // If nothing is written to the object, write null instead.
     for record in result.scalars.records.into_iter() {
-        let record_id = Some(record.projection(&scalar_db_field_names, &result.model_id)?);
+        // The record ID is only needed to look up nested items. Records read by raw queries
+        // have no nested items and may not contain the ID fields.
+        let record_id = if nested_mapping.is_empty() {
+            None
+        } else {
+            Some(record.projection(&scalar_db_field_names, &result.model_id)?)
+        };
 
         if !object_mapping.contains_key(&record.parent_id) {
             object_mapping.insert(record.parent_id.clone(), Vec::new());
@@ -293,7 +457,12 @@ fn serialize_objects(
         for (val, scalar_field_name) in values.into_iter().zip(field_names.iter()) {
             let field = typ.find_field(scalar_field_name).unwrap();
 
-            if !field.field_type.is_object() {
+            if let Some(selection) = result.composite_selections.get(scalar_field_name) {
+                object.insert(
+                    scalar_field_name.to_owned(),
+                    serialize_composite(&field, val, selection)?,
+                );
+            } else if !field.field_type.is_object() {
                 object.insert(scalar_field_name.to_owned(), serialize_scalar(&field, val)?);
             }
         }
@@ -421,6 +590,128 @@ fn serialize_scalar(field: &OutputFieldRef, value: PrismaValue) -> crate::Result
     }
 }
 
+/// Composite type fields are stored as JSON and read as a whole. The JSON is serialized into
+/// objects of the composite output type, narrowed down to the selected fields.
+fn serialize_composite(
+    field: &OutputFieldRef,
+    value: PrismaValue,
+    selection: &CompositeSelection,
+) -> crate::Result<Item> {
+    let json = match value {
+        PrismaValue::Null => serde_json::Value::Null,
+        PrismaValue::Json(s) | PrismaValue::String(s) => serde_json::from_str(&s).map_err(|err| {
+            CoreError::SerializationError(format!("Unable to parse composite value of '{}': {}", field.name, err))
+        })?,
+        value => {
+            return Err(CoreError::SerializationError(format!(
+                "Attempted to serialize non-JSON value '{}' as composite field '{}'",
+                value, field.name
+            )))
+        }
+    };
+
+    serialize_composite_value(json, &field.field_type, field.is_required, Some(selection))
+}
+
+fn serialize_composite_value(
+    value: serde_json::Value,
+    typ: &OutputTypeRef,
+    is_required: bool,
+    selection: Option<&CompositeSelection>,
+) -> crate::Result<Item> {
+    match (value, typ.borrow()) {
+        (serde_json::Value::Null, OutputType::List(_)) => Ok(Item::list(Vec::new())),
+        (serde_json::Value::Null, _) if !is_required => Ok(Item::Value(PrismaValue::Null)),
+        (serde_json::Value::Null, _) => Err(CoreError::SerializationError(
+            "Required field of a composite type returned null".to_owned(),
+        )),
+
+        (serde_json::Value::Array(values), OutputType::List(inner)) => {
+            let items = values
+                .into_iter()
+                .map(|value| serialize_composite_value(value, inner, true, selection))
+                .collect::<crate::Result<Vec<Item>>>()?;
+
+            Ok(Item::list(items))
+        }
+
+        (serde_json::Value::Object(mut object), OutputType::Object(obj)) => {
+            let obj = obj.into_arc();
+            let selection = selection.expect("Composite objects must have a selection");
+
+            let map = selection
+                .fields
+                .iter()
+                .map(|field_name| {
+                    let field = obj.find_field(field_name).unwrap();
+                    let value = object.remove(field_name).unwrap_or(serde_json::Value::Null);
+                    let item = serialize_composite_value(
+                        value,
+                        &field.field_type,
+                        field.is_required,
+                        selection.nested.get(field_name),
+                    )?;
+
+                    Ok((field_name.clone(), item))
+                })
+                .collect::<crate::Result<Map>>()?;
+
+            Ok(Item::Map(map))
+        }
+
+        (serde_json::Value::String(s), OutputType::Enum(et)) => match et.borrow() {
+            EnumType::Database(ref db) => convert_enum(PrismaValue::Enum(s), db),
+            _ => unreachable!(),
+        },
+
+        (value, OutputType::Scalar(st)) => Ok(Item::Value(convert_composite_scalar(value, st)?)),
+
+        (value, ot) => Err(CoreError::SerializationError(format!(
+            "Attempted to serialize composite value '{}' with incompatible type '{:?}'",
+            value, ot
+        ))),
+    }
+}
+
+/// Values that JSON has no type for are stored as strings: date times in RFC 3339 format and
+/// bytes base64 encoded.
+fn convert_composite_scalar(value: serde_json::Value, st: &ScalarType) -> Result<PrismaValue, CoreError> {
+    let converted = match (st, value) {
+        (ScalarType::Json, value) => PrismaValue::Json(value.to_string()),
+        (ScalarType::Decimal, serde_json::Value::Number(n)) => PrismaValue::String(n.to_string()),
+        (ScalarType::DateTime, serde_json::Value::String(s)) => match DateTime::parse_from_rfc3339(&s) {
+            Ok(dt) => PrismaValue::DateTime(dt),
+            Err(_) => {
+                return Err(CoreError::SerializationError(format!(
+                    "Attempted to serialize string '{}' as DateTime",
+                    s
+                )))
+            }
+        },
+        (ScalarType::Bytes, serde_json::Value::String(s)) => match prisma_value::decode_bytes(&s) {
+            Ok(bytes) => PrismaValue::Bytes(bytes),
+            Err(_) => {
+                return Err(CoreError::SerializationError(format!(
+                    "Attempted to serialize string '{}' as Bytes",
+                    s
+                )))
+            }
+        },
+        (st, value) => {
+            let value = PrismaValue::try_from(value.clone()).map_err(|_| {
+                CoreError::SerializationError(format!(
+                    "Attempted to serialize composite value '{}' with incompatible type '{:?}'",
+                    value, st
+                ))
+            })?;
+
+            convert_prisma_value(value, st)?
+        }
+    };
+
+    Ok(converted)
+}
+
 fn convert_prisma_value(value: PrismaValue, st: &ScalarType) -> Result<PrismaValue, CoreError> {
     let item_value = match (st, value) {
         (ScalarType::String, PrismaValue::String(s)) => PrismaValue::String(s),
@@ -430,13 +721,16 @@ fn convert_prisma_value(value: PrismaValue, st: &ScalarType) -> Result<PrismaVal
 
         (ScalarType::Int, PrismaValue::Float(f)) => PrismaValue::Int(f.to_i64().unwrap()),
         (ScalarType::Int, PrismaValue::Int(i)) => PrismaValue::Int(i),
+        (ScalarType::Int, PrismaValue::BigInt(i)) => PrismaValue::Int(i),
 
         (ScalarType::Float, PrismaValue::Float(f)) => PrismaValue::Float(f),
         (ScalarType::Float, PrismaValue::Int(i)) => {
             PrismaValue::Int(i.to_i64().expect("Unable to convert BigDecimal to i64."))
         }
+        (ScalarType::Float, PrismaValue::BigInt(i)) => PrismaValue::Int(i),
 
         (ScalarType::Decimal, PrismaValue::Int(i)) => PrismaValue::String(i.to_string()),
+        (ScalarType::Decimal, PrismaValue::BigInt(i)) => PrismaValue::String(i.to_string()),
         (ScalarType::Decimal, PrismaValue::Float(f)) => PrismaValue::String(f.to_string()),
 
         (ScalarType::BigInt, PrismaValue::BigInt(i)) => PrismaValue::BigInt(i),
@@ -444,9 +738,20 @@ fn convert_prisma_value(value: PrismaValue, st: &ScalarType) -> Result<PrismaVal
         (ScalarType::BigInt, PrismaValue::Float(f)) => PrismaValue::BigInt(f.to_i64().unwrap()),
 
         (ScalarType::Boolean, PrismaValue::Boolean(b)) => PrismaValue::Boolean(b),
+        (ScalarType::Boolean, PrismaValue::Int(i)) => PrismaValue::Boolean(i != 0),
+        (ScalarType::Boolean, PrismaValue::BigInt(i)) => PrismaValue::Boolean(i != 0),
         (ScalarType::Int, PrismaValue::Boolean(b)) => PrismaValue::Int(b as i64),
         (ScalarType::DateTime, PrismaValue::DateTime(dt)) => PrismaValue::DateTime(dt),
         (ScalarType::UUID, PrismaValue::Uuid(u)) => PrismaValue::Uuid(u),
+        (ScalarType::UUID, PrismaValue::String(s)) => match uuid::Uuid::parse_str(&s) {
+            Ok(u) => PrismaValue::Uuid(u),
+            Err(_) => {
+                return Err(CoreError::SerializationError(format!(
+                    "Attempted to serialize string '{}' as UUID",
+                    s
+                )))
+            }
+        },
         (ScalarType::Bytes, PrismaValue::Bytes(b)) => PrismaValue::Bytes(b),
 
         (ScalarType::Xml, PrismaValue::Xml(b)) => PrismaValue::Xml(b),
@@ -1,3 +1,4 @@
+use crate::{CompositeSelections, ConnectionSelection, TraversalField};
 use connector::{AggregationRow, QueryArguments};
 use prisma_models::{ManyRecords, ModelProjection, RecordProjection};
 
@@ -8,6 +9,8 @@ pub enum QueryResult {
     RecordSelection(RecordSelection),
     Json(serde_json::Value),
     RecordAggregations(RecordAggregations),
+    RecordConnection(RecordConnection),
+    RecordTraversal(RecordTraversal),
     Unit,
 }
 
@@ -20,6 +23,9 @@ pub struct RecordSelection {
     /// Holds an ordered list of selected field names for each contained record.
     pub fields: Vec<String>,
 
+    /// Selections within composite type fields, which are part of the scalar field results.
+    pub composite_selections: CompositeSelections,
+
     /// Scalar field results
     pub scalars: ManyRecords,
 
@@ -42,3 +48,40 @@ pub struct RecordAggregations {
     /// Actual aggregation results.
     pub results: Vec<AggregationRow>,
 }
+
+#[derive(Debug, Clone)]
+pub struct RecordConnection {
+    /// Records of the page.
+    pub records: RecordSelection,
+
+    /// Cursors of the records of the page, in the same order.
+    pub cursors: Vec<String>,
+
+    pub page_info: PageInfo,
+
+    /// Number of records matching the query filter, disregarding pagination. Only counted if selected.
+    pub total_count: Option<usize>,
+
+    /// Selection on the connection object as defined by the original incoming query.
+    pub selection: ConnectionSelection,
+}
+
+#[derive(Debug, Clone)]
+pub struct PageInfo {
+    pub has_next_page: bool,
+    pub has_previous_page: bool,
+    pub start_cursor: Option<String>,
+    pub end_cursor: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RecordTraversal {
+    /// Reached records, ordered by depth.
+    pub records: RecordSelection,
+
+    /// Depths the records have been reached at, in the same order.
+    pub depths: Vec<usize>,
+
+    /// Selection on the traversal objects as defined by the original incoming query.
+    pub selection: Vec<(String, TraversalField)>,
+}
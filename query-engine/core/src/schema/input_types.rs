@@ -88,9 +88,18 @@ pub struct InputField {
     /// Indicates if the presence of the field on the higher input objects
     /// is required, but doesn't state whether or not the input can be null.
     pub is_required: bool,
+
+    /// Constraints the value of the field is checked against while parsing, e.g. `@length`.
+    pub validations: Vec<dml::FieldValidation>,
 }
 
 impl InputField {
+    /// Sets the constraints the value of the field is checked against.
+    pub fn with_validations(mut self, validations: Vec<dml::FieldValidation>) -> Self {
+        self.validations = validations;
+        self
+    }
+
     /// Sets the field as optional (not required to be present on the input).
     pub fn optional(mut self) -> Self {
         self.is_required = false;
@@ -132,6 +132,11 @@ impl ObjectType {
         self.get_fields().iter().find(|f| &f.name == name).cloned()
     }
 
+    /// Composite types share the namespace of the models, but don't map to a model.
+    pub fn is_composite(&self) -> bool {
+        self.model.is_none() && self.identifier.namespace() == MODEL_NAMESPACE
+    }
+
     /// True if fields are empty, false otherwise.
     pub fn is_empty(&self) -> bool {
         self.get_fields().is_empty()
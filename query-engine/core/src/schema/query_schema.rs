@@ -1,4 +1,5 @@
 use super::*;
+use crate::QueryLimits;
 use fmt::Debug;
 use prisma_models::{InternalDataModelRef, ModelRef};
 use std::{borrow::Borrow, fmt};
@@ -28,6 +29,9 @@ pub struct QuerySchema {
     output_object_types: Vec<ObjectTypeStrongRef>,
 
     pub internal_data_model: InternalDataModelRef,
+
+    /// Limits on the shape and size of queries against the schema.
+    pub limits: QueryLimits,
 }
 
 impl QuerySchema {
@@ -44,9 +48,15 @@ impl QuerySchema {
             input_object_types,
             output_object_types,
             internal_data_model,
+            limits: QueryLimits::default(),
         }
     }
 
+    pub fn with_limits(mut self, limits: QueryLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
     pub fn find_mutation_field<T>(&self, name: T) -> Option<OutputFieldRef>
     where
         T: Into<String>,
@@ -91,6 +101,8 @@ pub enum QueryTag {
     FindOne,
     FindFirst,
     FindMany,
+    FindManyConnection,
+    FindManyRecursive,
     CreateOne,
     UpdateOne,
     UpdateMany,
@@ -109,6 +121,8 @@ impl fmt::Display for QueryTag {
             Self::FindOne => "findOne",
             Self::FindFirst => "findFirst",
             Self::FindMany => "findMany",
+            Self::FindManyConnection => "findManyConnection",
+            Self::FindManyRecursive => "findManyRecursive",
             Self::CreateOne => "createOne",
             Self::UpdateOne => "updateOne",
             Self::UpdateMany => "updateMany",
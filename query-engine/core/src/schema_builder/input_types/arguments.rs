@@ -122,6 +122,58 @@ pub(crate) fn many_records_arguments(
     args
 }
 
+/// Builds the arguments of a connection query: the filter and ordering of the records,
+/// and the relay-style pagination arguments `first`/`after` and `last`/`before`.
+pub(crate) fn connection_arguments(ctx: &mut BuilderContext, model: &ModelRef) -> Vec<InputField> {
+    vec![
+        where_argument(ctx, &model),
+        order_by_argument(ctx, &model),
+        input_field("first", InputType::int(), None).optional(),
+        input_field("after", InputType::string(), None).optional(),
+        input_field("last", InputType::int(), None).optional(),
+        input_field("before", InputType::string(), None).optional(),
+    ]
+}
+
+/// Builds the arguments of a recursive traversal query: the starting record, the self-relation to follow
+/// and the maximum depth. Returns `None` if the model has no self-relations that can be traversed.
+pub(crate) fn traversal_arguments(ctx: &mut BuilderContext, model: &ModelRef) -> Option<Vec<InputField>> {
+    let field_names: Vec<String> = traversable_fields(model)
+        .into_iter()
+        .map(|rf| rf.name.clone())
+        .collect();
+
+    if field_names.is_empty() {
+        return None;
+    }
+
+    where_unique_argument(ctx, model).map(|where_arg| {
+        let enum_type = Arc::new(string_enum_type(
+            format!("{}SelfRelationField", capitalize(&model.name)),
+            field_names,
+        ));
+
+        vec![
+            where_arg,
+            input_field("relation", InputType::Enum(enum_type), None),
+            input_field("maxDepth", InputType::int(), None).optional(),
+        ]
+    })
+}
+
+/// Self-relation fields of the model that are stored inline, i.e. with the foreign key on the model itself.
+pub(crate) fn traversable_fields(model: &ModelRef) -> Vec<RelationFieldRef> {
+    model
+        .fields()
+        .relation()
+        .into_iter()
+        .filter(|rf| {
+            let relation = rf.relation();
+            relation.is_self_relation() && relation.is_inline_relation()
+        })
+        .collect()
+}
+
 // Builds "orderBy" argument.
 pub(crate) fn order_by_argument(ctx: &mut BuilderContext, model: &ModelRef) -> InputField {
     let order_object_type = InputType::object(order_by_object_type(ctx, model));
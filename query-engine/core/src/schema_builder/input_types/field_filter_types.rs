@@ -10,6 +10,7 @@ pub(crate) fn get_field_filter_types(ctx: &mut BuilderContext, field: &ModelFiel
             types.extend(mto1_relation_filter_shorthand_types(ctx, rf));
             types
         }
+        ModelField::Scalar(sf) if sf.is_composite() => composite_objects::composite_filter_types(ctx, sf),
         ModelField::Scalar(sf) if field.is_list() => vec![InputType::object(scalar_list_filter_type(ctx, sf))],
         ModelField::Scalar(sf) => {
             let mut types = vec![InputType::object(full_scalar_filter_type(ctx, sf, false))];
@@ -104,6 +105,7 @@ fn full_scalar_filter_type(ctx: &mut BuilderContext, sf: &ScalarFieldRef, nested
             .chain(inclusion_filters(sf))
             .chain(alphanumeric_filters(sf))
             .chain(string_filters(sf))
+            .chain(search_filter(ctx, sf, nested))
             .chain(query_mode_field(ctx, nested))
             .collect(),
 
@@ -185,6 +187,20 @@ fn string_filters(sf: &ScalarFieldRef) -> impl Iterator<Item = InputField> {
     .into_iter()
 }
 
+fn search_filter(ctx: &BuilderContext, sf: &ScalarFieldRef, nested: bool) -> impl Iterator<Item = InputField> {
+    // Full-text search is limited to the topmost filter level of String fields, on connectors supporting it.
+    let fields = if !nested
+        && sf.type_identifier == TypeIdentifier::String
+        && ctx.capabilities.contains(ConnectorCapability::FullTextSearch)
+    {
+        vec![input_field("search", InputType::string(), None).optional()]
+    } else {
+        vec![]
+    };
+
+    fields.into_iter()
+}
+
 fn query_mode_field(ctx: &BuilderContext, nested: bool) -> impl Iterator<Item = InputField> {
     // Limit query mode field to the topmost filter level.
     // Only build mode field for connectors with insensitive filter support.
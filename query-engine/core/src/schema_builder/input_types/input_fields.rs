@@ -124,61 +124,67 @@ pub(crate) fn nested_update_input_field(ctx: &mut BuilderContext, field: &Relati
     input_field("update", update_types, None).optional()
 }
 
-/// Builds scalar input fields using the mapper and the given, prefiltered, scalar fields.
-/// The mapper is responsible for mapping the fields to input types.
-pub(crate) fn scalar_input_fields<T, F>(
+/// Builds scalar input fields using the mappers and the given, prefiltered, scalar fields.
+/// The mappers are responsible for mapping the fields to input types.
+pub(crate) fn scalar_input_fields<F, G>(
     ctx: &mut BuilderContext,
-    model_name: String,
-    input_object_name: T,
     prefiltered_fields: Vec<ScalarFieldRef>,
     non_list_field_mapper: F,
+    list_field_mapper: G,
     with_defaults: bool,
 ) -> Vec<InputField>
 where
-    T: Into<String>,
     F: Fn(&mut BuilderContext, ScalarFieldRef, Option<DefaultValue>) -> InputField,
+    G: Fn(&mut BuilderContext, ScalarFieldRef) -> InputField,
 {
-    let input_object_name = input_object_name.into();
     let mut non_list_fields: Vec<InputField> = prefiltered_fields
         .iter()
         .filter(|f| !f.is_list)
         .map(|f| {
             let default = if with_defaults { f.default_value.clone() } else { None };
-            non_list_field_mapper(ctx, f.clone(), default)
+            non_list_field_mapper(ctx, f.clone(), default).with_validations(f.validations.clone())
         })
         .collect();
 
     let mut list_fields: Vec<InputField> = prefiltered_fields
         .into_iter()
         .filter(|f| f.is_list)
-        .map(|f| {
-            let name = f.name.clone();
-            let list_input_type = map_scalar_input_type(&f);
-            let set_object_ident = Identifier::new(
-                format!("{}{}{}Input", model_name, input_object_name, f.name),
-                PRISMA_NAMESPACE,
-            );
-
-            let input_object = match ctx.get_input_type(&set_object_ident) {
-                Some(t) => t,
-                None => {
-                    let set_fields = vec![input_field("set", list_input_type.clone(), None)];
-                    let input_object = Arc::new(input_object_type(set_object_ident.clone(), set_fields));
-
-                    ctx.cache_input_type(set_object_ident, input_object.clone());
-                    Arc::downgrade(&input_object)
-                }
-            };
-
-            let set_input_type = InputType::object(input_object);
-            input_field(name, vec![set_input_type, list_input_type], None).optional()
-        })
+        .map(|f| list_field_mapper(ctx, f))
         .collect();
 
     non_list_fields.append(&mut list_fields);
     non_list_fields
 }
 
+/// Builds the input field for a scalar list field, accepting either the list
+/// or a "<Model><input_object_name><field>Input" object wrapping it in `set`.
+pub(crate) fn scalar_list_set_input_field(
+    ctx: &mut BuilderContext,
+    model_name: &str,
+    input_object_name: &str,
+    field: ScalarFieldRef,
+) -> InputField {
+    let list_input_type = map_scalar_input_type(&field);
+    let set_object_ident = Identifier::new(
+        format!("{}{}{}Input", model_name, input_object_name, field.name),
+        PRISMA_NAMESPACE,
+    );
+
+    let input_object = match ctx.get_input_type(&set_object_ident) {
+        Some(t) => t,
+        None => {
+            let set_fields = vec![input_field("set", list_input_type.clone(), None)];
+            let input_object = Arc::new(input_object_type(set_object_ident.clone(), set_fields));
+
+            ctx.cache_input_type(set_object_ident, input_object.clone());
+            Arc::downgrade(&input_object)
+        }
+    };
+
+    let set_input_type = InputType::object(input_object);
+    input_field(field.name.clone(), vec![set_input_type, list_input_type], None).optional()
+}
+
 fn where_input_field<T>(ctx: &mut BuilderContext, name: T, field: &RelationFieldRef) -> InputField
 where
     T: Into<String>,
@@ -6,8 +6,9 @@ mod objects;
 
 use super::*;
 use crate::schema::*;
+use datamodel_connector::ConnectorCapability;
 use objects::*;
-use prisma_models::{RelationFieldRef, ScalarFieldRef};
+use prisma_models::{InternalEnum, RelationFieldRef, ScalarFieldRef};
 
 /// Builds "<Model>OrderByInput" object types.
 pub(crate) fn order_by_object_type(ctx: &mut BuilderContext, model: &ModelRef) -> InputObjectTypeWeakRef {
@@ -22,19 +23,94 @@ pub(crate) fn order_by_object_type(ctx: &mut BuilderContext, model: &ModelRef) -
     let input_object = Arc::new(input_object);
     ctx.cache_input_type(ident, input_object.clone());
 
-    let fields = model
+    let mut fields: Vec<_> = model
         .fields()
         .scalar()
         .iter()
+        .filter(|sf| !sf.is_composite())
         .map(|sf| input_field(sf.name.clone(), InputType::Enum(enum_type.clone()), None).optional())
         .collect();
 
+    fields.extend(order_by_relevance_field(ctx, model, &enum_type));
+
     input_object.set_fields(fields);
     Arc::downgrade(&input_object)
 }
 
+/// Builds the `_relevance` order by field, ordering by full-text search relevance over String fields.
+fn order_by_relevance_field(
+    ctx: &mut BuilderContext,
+    model: &ModelRef,
+    sort_order: &EnumTypeRef,
+) -> Option<InputField> {
+    let has_search_fields = model
+        .fields()
+        .scalar()
+        .iter()
+        .any(|sf| sf.type_identifier == TypeIdentifier::String && !sf.is_list);
+
+    if !has_search_fields || !ctx.capabilities.contains(ConnectorCapability::FullTextSearch) {
+        return None;
+    }
+
+    let object_type = InputType::object(order_by_relevance_object_type(ctx, model, sort_order));
+    Some(input_field("_relevance", object_type, None).optional())
+}
+
+/// Builds "<Model>OrderByRelevanceInput" object types.
+fn order_by_relevance_object_type(
+    ctx: &mut BuilderContext,
+    model: &ModelRef,
+    sort_order: &EnumTypeRef,
+) -> InputObjectTypeWeakRef {
+    let ident = Identifier::new(format!("{}OrderByRelevanceInput", model.name), PRISMA_NAMESPACE);
+    return_cached_input!(ctx, &ident);
+
+    let field_enum_type = InputType::Enum(Arc::new(EnumType::FieldRef(FieldRefEnumType {
+        name: format!("{}OrderByRelevanceFieldEnum", capitalize(&model.name)),
+        values: model
+            .fields()
+            .scalar()
+            .into_iter()
+            .filter(|sf| sf.type_identifier == TypeIdentifier::String && !sf.is_list)
+            .map(|sf| (sf.name.clone(), sf))
+            .collect(),
+    })));
+
+    let input_object = Arc::new(input_object_type(
+        ident.clone(),
+        vec![
+            input_field(
+                "fields",
+                vec![InputType::list(field_enum_type.clone()), field_enum_type],
+                None,
+            ),
+            input_field("search", InputType::string(), None),
+            input_field("sort", InputType::Enum(sort_order.clone()), None),
+        ],
+    ));
+
+    ctx.cache_input_type(ident, input_object.clone());
+    Arc::downgrade(&input_object)
+}
+
 fn map_scalar_input_type(field: &ScalarFieldRef) -> InputType {
-    let typ = match field.type_identifier {
+    let typ = map_scalar_element_input_type(field);
+
+    if field.is_list {
+        InputType::list(typ)
+    } else {
+        typ
+    }
+}
+
+/// Maps the type of a single value of the field, regardless of it being a list.
+fn map_scalar_element_input_type(field: &ScalarFieldRef) -> InputType {
+    map_type_identifier_input_type(&field.type_identifier, field.internal_enum.as_ref())
+}
+
+fn map_type_identifier_input_type(type_identifier: &TypeIdentifier, internal_enum: Option<&InternalEnum>) -> InputType {
+    match type_identifier {
         TypeIdentifier::String => InputType::string(),
         TypeIdentifier::Int => InputType::int(),
         TypeIdentifier::Float => InputType::float(),
@@ -43,24 +119,16 @@ fn map_scalar_input_type(field: &ScalarFieldRef) -> InputType {
         TypeIdentifier::UUID => InputType::uuid(),
         TypeIdentifier::DateTime => InputType::date_time(),
         TypeIdentifier::Json => InputType::json(),
-        TypeIdentifier::Enum(_) => map_enum_input_type(&field),
+        TypeIdentifier::Enum(_) => map_enum_input_type(internal_enum),
         TypeIdentifier::Xml => InputType::xml(),
         TypeIdentifier::Bytes => InputType::bytes(),
         TypeIdentifier::BigInt => InputType::bigint(),
-    };
-
-    if field.is_list {
-        InputType::list(typ)
-    } else {
-        typ
     }
 }
 
-fn map_enum_input_type(field: &ScalarFieldRef) -> InputType {
-    let internal_enum = field
-        .internal_enum
-        .as_ref()
-        .expect("A field with TypeIdentifier Enum must always have an associated internal enum.");
+fn map_enum_input_type(internal_enum: Option<&InternalEnum>) -> InputType {
+    let internal_enum =
+        internal_enum.expect("A field with TypeIdentifier Enum must always have an associated internal enum.");
 
     let et: EnumType = internal_enum.clone().into();
     et.into()
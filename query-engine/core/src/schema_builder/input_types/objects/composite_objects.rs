@@ -0,0 +1,278 @@
+use super::*;
+use prisma_models::{InternalCompositeType, InternalCompositeTypeField};
+
+/// Builds the create input field of a composite type field, an envelope with `set`.
+pub(crate) fn composite_create_input_field(ctx: &mut BuilderContext, field: &ScalarFieldRef) -> InputField {
+    let composite_type = field.composite_type().unwrap();
+
+    let (name, set_type) = if field.is_list {
+        let create_type = create_object_type(ctx, &composite_type);
+        (
+            format!("{}ListCreateEnvelopeInput", composite_type.name),
+            list_union_object_type(create_type, true),
+        )
+    } else {
+        let nullable = if field.is_required { "" } else { "Nullable" };
+        (
+            format!("{}{}CreateEnvelopeInput", nullable, composite_type.name),
+            vec![InputType::object(create_object_type(ctx, &composite_type))],
+        )
+    };
+
+    let ident = Identifier::new(name, PRISMA_NAMESPACE);
+
+    let envelope = match ctx.get_input_type(&ident) {
+        Some(t) => t,
+        None => {
+            let set_field = input_field("set", set_type, None).nullable_if(!field.is_list && !field.is_required);
+            let envelope = Arc::new(input_object_type(ident.clone(), vec![set_field]));
+
+            ctx.cache_input_type(ident, envelope.clone());
+            Arc::downgrade(&envelope)
+        }
+    };
+
+    input_field(field.name.clone(), InputType::object(envelope), None).optional_if(!field.is_required || field.is_list)
+}
+
+/// Builds the update input field of a composite type field, an envelope with exactly one of
+/// `set` and `update`, or `set` and `push` for lists.
+pub(crate) fn composite_update_input_field(ctx: &mut BuilderContext, field: &ScalarFieldRef) -> InputField {
+    let composite_type = field.composite_type().unwrap();
+
+    let envelope = if field.is_list {
+        list_update_envelope_object_type(ctx, &composite_type)
+    } else {
+        update_envelope_object_type(ctx, &composite_type, field.is_required)
+    };
+
+    input_field(field.name.clone(), InputType::object(envelope), None).optional()
+}
+
+/// Builds the filter types of a composite type field: "<x>CompositeFilter" with `is` and `isNot`,
+/// or "<x>CompositeListFilter" with `some`, `none` and `isEmpty` for lists.
+pub(crate) fn composite_filter_types(ctx: &mut BuilderContext, field: &ScalarFieldRef) -> Vec<InputType> {
+    let composite_type = field.composite_type().unwrap();
+    let where_type = InputType::object(where_object_type(ctx, &composite_type));
+
+    if field.is_list {
+        let ident = Identifier::new(format!("{}CompositeListFilter", composite_type.name), PRISMA_NAMESPACE);
+
+        let filter_object = match ctx.get_input_type(&ident) {
+            Some(t) => t,
+            None => {
+                let fields = vec![
+                    input_field("some", where_type.clone(), None).optional(),
+                    input_field("none", where_type, None).optional(),
+                    input_field("isEmpty", InputType::boolean(), None).optional(),
+                ];
+
+                let filter_object = Arc::new(input_object_type(ident.clone(), fields));
+                ctx.cache_input_type(ident, filter_object.clone());
+                Arc::downgrade(&filter_object)
+            }
+        };
+
+        vec![InputType::object(filter_object)]
+    } else {
+        let nullable = if field.is_required { "" } else { "Nullable" };
+        let ident = Identifier::new(
+            format!("{}{}CompositeFilter", composite_type.name, nullable),
+            PRISMA_NAMESPACE,
+        );
+
+        let filter_object = match ctx.get_input_type(&ident) {
+            Some(t) => t,
+            None => {
+                let fields = vec![
+                    input_field("is", where_type.clone(), None)
+                        .optional()
+                        .nullable_if(!field.is_required),
+                    input_field("isNot", where_type, None)
+                        .optional()
+                        .nullable_if(!field.is_required),
+                ];
+
+                let filter_object = Arc::new(input_object_type(ident.clone(), fields));
+                ctx.cache_input_type(ident, filter_object.clone());
+                Arc::downgrade(&filter_object)
+            }
+        };
+
+        let mut types = vec![InputType::object(filter_object)];
+
+        if !field.is_required {
+            types.push(InputType::null()); // Null-equality shorthand
+        }
+
+        types
+    }
+}
+
+/// Builds "<x>CreateInput" input object types for complete composite values.
+fn create_object_type(ctx: &mut BuilderContext, composite_type: &InternalCompositeType) -> InputObjectTypeWeakRef {
+    let ident = Identifier::new(format!("{}CreateInput", composite_type.name), PRISMA_NAMESPACE);
+    return_cached_input!(ctx, &ident);
+
+    let input_object = Arc::new(init_input_object_type(ident.clone()));
+    ctx.cache_input_type(ident, input_object.clone());
+
+    let fields = composite_type
+        .fields
+        .iter()
+        .map(|f| {
+            let types = match nested_composite_type(ctx, f) {
+                Some(nested) => list_union_object_type(create_object_type(ctx, &nested), f.is_list()),
+                None => vec![map_composite_field_input_type(f)],
+            };
+
+            input_field(f.name.clone(), types, None)
+                .optional_if(!f.is_required())
+                .nullable_if(!f.is_required() && !f.is_list())
+        })
+        .collect();
+
+    input_object.set_fields(fields);
+    Arc::downgrade(&input_object)
+}
+
+/// Builds "<x>UpdateInput" input object types, setting only the given fields of composite values.
+fn update_object_type(ctx: &mut BuilderContext, composite_type: &InternalCompositeType) -> InputObjectTypeWeakRef {
+    let ident = Identifier::new(format!("{}UpdateInput", composite_type.name), PRISMA_NAMESPACE);
+    return_cached_input!(ctx, &ident);
+
+    let input_object = Arc::new(init_input_object_type(ident.clone()));
+    ctx.cache_input_type(ident, input_object.clone());
+
+    let fields = composite_type
+        .fields
+        .iter()
+        .map(|f| {
+            let types = match nested_composite_type(ctx, f) {
+                Some(nested) if f.is_list() => list_union_object_type(create_object_type(ctx, &nested), true),
+                Some(nested) => vec![InputType::object(update_envelope_object_type(
+                    ctx,
+                    &nested,
+                    f.is_required(),
+                ))],
+                None => vec![map_composite_field_input_type(f)],
+            };
+
+            input_field(f.name.clone(), types, None)
+                .optional()
+                .nullable_if(!f.is_required() && !f.is_list() && f.composite_type.is_none())
+        })
+        .collect();
+
+    input_object.set_fields(fields);
+    Arc::downgrade(&input_object)
+}
+
+/// Builds "<x>UpdateEnvelopeInput" input object types, with exactly one of `set` and `update`.
+fn update_envelope_object_type(
+    ctx: &mut BuilderContext,
+    composite_type: &InternalCompositeType,
+    is_required: bool,
+) -> InputObjectTypeWeakRef {
+    // Nullability is important for the `set` operation, so we need to
+    // construct and cache different objects to reflect that.
+    let nullable = if is_required { "" } else { "Nullable" };
+    let ident = Identifier::new(
+        format!("{}{}UpdateEnvelopeInput", nullable, composite_type.name),
+        PRISMA_NAMESPACE,
+    );
+    return_cached_input!(ctx, &ident);
+
+    let mut obj = init_input_object_type(ident.clone());
+    obj.require_exactly_one_field();
+
+    let obj = Arc::new(obj);
+    ctx.cache_input_type(ident, obj.clone());
+
+    let create_type = InputType::object(create_object_type(ctx, composite_type));
+    let update_type = InputType::object(update_object_type(ctx, composite_type));
+
+    obj.set_fields(vec![
+        input_field("set", create_type, None)
+            .optional()
+            .nullable_if(!is_required),
+        input_field("update", update_type, None).optional(),
+    ]);
+
+    Arc::downgrade(&obj)
+}
+
+/// Builds "<x>ListUpdateEnvelopeInput" input object types, with exactly one of `set` and `push`.
+fn list_update_envelope_object_type(
+    ctx: &mut BuilderContext,
+    composite_type: &InternalCompositeType,
+) -> InputObjectTypeWeakRef {
+    let ident = Identifier::new(
+        format!("{}ListUpdateEnvelopeInput", composite_type.name),
+        PRISMA_NAMESPACE,
+    );
+    return_cached_input!(ctx, &ident);
+
+    let mut obj = init_input_object_type(ident.clone());
+    obj.require_exactly_one_field();
+
+    let obj = Arc::new(obj);
+    ctx.cache_input_type(ident, obj.clone());
+
+    let create_type = create_object_type(ctx, composite_type);
+
+    obj.set_fields(vec![
+        input_field("set", list_union_object_type(create_type.clone(), true), None).optional(),
+        input_field("push", list_union_object_type(create_type, true), None).optional(),
+    ]);
+
+    Arc::downgrade(&obj)
+}
+
+/// Builds "<x>WhereInput" input object types. Only single values can be matched, scalar lists and
+/// composite lists within composite types are not filterable.
+fn where_object_type(ctx: &mut BuilderContext, composite_type: &InternalCompositeType) -> InputObjectTypeWeakRef {
+    let ident = Identifier::new(format!("{}WhereInput", composite_type.name), PRISMA_NAMESPACE);
+    return_cached_input!(ctx, &ident);
+
+    let input_object = Arc::new(init_input_object_type(ident.clone()));
+    ctx.cache_input_type(ident, input_object.clone());
+
+    let fields = composite_type
+        .fields
+        .iter()
+        .filter(|f| !f.is_list())
+        .map(|f| {
+            let typ = match nested_composite_type(ctx, f) {
+                Some(nested) => InputType::object(where_object_type(ctx, &nested)),
+                None => map_composite_field_input_type(f),
+            };
+
+            input_field(f.name.clone(), typ, None)
+                .optional()
+                .nullable_if(!f.is_required())
+        })
+        .collect();
+
+    input_object.set_fields(fields);
+    Arc::downgrade(&input_object)
+}
+
+fn nested_composite_type(ctx: &BuilderContext, field: &InternalCompositeTypeField) -> Option<InternalCompositeType> {
+    field.composite_type.as_ref().map(|name| {
+        ctx.internal_data_model
+            .find_composite_type(name)
+            .expect("Composite type of a field must exist")
+            .clone()
+    })
+}
+
+fn map_composite_field_input_type(field: &InternalCompositeTypeField) -> InputType {
+    let typ = map_type_identifier_input_type(&field.type_identifier, field.internal_enum.as_ref());
+
+    if field.is_list() {
+        InputType::list(typ)
+    } else {
+        typ
+    }
+}
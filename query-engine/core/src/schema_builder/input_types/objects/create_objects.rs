@@ -55,16 +55,25 @@ fn checked_create_input_type(
 
     let mut fields = input_fields::scalar_input_fields(
         ctx,
-        model.name.clone(),
-        "Create",
         scalar_fields,
-        |_, f: ScalarFieldRef, default: Option<DefaultValue>| {
+        |ctx, f: ScalarFieldRef, default: Option<DefaultValue>| {
+            if f.is_composite() {
+                return composite_objects::composite_create_input_field(ctx, &f);
+            }
+
             let typ = map_scalar_input_type(&f);
 
             input_field(f.name.clone(), typ, default)
                 .optional_if(!f.is_required || f.default_value.is_some() || f.is_created_at() || f.is_updated_at())
                 .nullable_if(!f.is_required)
         },
+        |ctx, f: ScalarFieldRef| {
+            if f.is_composite() {
+                composite_objects::composite_create_input_field(ctx, &f)
+            } else {
+                input_fields::scalar_list_set_input_field(ctx, &model.name, "Create", f)
+            }
+        },
         true,
     );
 
@@ -185,21 +194,30 @@ fn unchecked_create_input_type(
         .fields()
         .scalar()
         .into_iter()
-        .filter(|sf| !linking_fields.contains(sf))
+        .filter(|sf| !linking_fields.contains(sf) && !sf.is_computed())
         .collect();
 
     let mut fields = input_fields::scalar_input_fields(
         ctx,
-        model.name.clone(),
-        "Create",
         scalar_fields,
-        |_, f: ScalarFieldRef, default: Option<DefaultValue>| {
+        |ctx, f: ScalarFieldRef, default: Option<DefaultValue>| {
+            if f.is_composite() {
+                return composite_objects::composite_create_input_field(ctx, &f);
+            }
+
             let typ = map_scalar_input_type(&f);
 
             input_field(f.name.clone(), typ, default)
                 .optional_if(!f.is_required || f.default_value.is_some() || f.is_created_at() || f.is_updated_at())
                 .nullable_if(!f.is_required)
         },
+        |ctx, f: ScalarFieldRef| {
+            if f.is_composite() {
+                composite_objects::composite_create_input_field(ctx, &f)
+            } else {
+                input_fields::scalar_list_set_input_field(ctx, &model.name, "Create", f)
+            }
+        },
         true,
     );
 
@@ -85,14 +85,13 @@ fn unchecked_update_one_input_type(
 pub(super) fn scalar_input_fields_for_checked_update(ctx: &mut BuilderContext, model: &ModelRef) -> Vec<InputField> {
     input_fields::scalar_input_fields(
         ctx,
-        model.name.clone(),
-        "Update",
         model
             .fields()
             .scalar_writable()
             .filter(field_should_be_kept_for_update_input_type)
             .collect(),
         |ctx, f: ScalarFieldRef, default| non_list_scalar_update_field_mapper(ctx, &f, default),
+        |ctx, f: ScalarFieldRef| list_scalar_update_field_mapper(ctx, model, &f),
         false,
     )
 }
@@ -117,15 +116,14 @@ pub(super) fn scalar_input_fields_for_unchecked_update(
         .fields()
         .scalar()
         .into_iter()
-        .filter(|sf| !linking_fields.contains(sf))
+        .filter(|sf| !linking_fields.contains(sf) && !sf.is_computed())
         .collect();
 
     input_fields::scalar_input_fields(
         ctx,
-        model.name.clone(),
-        "Update",
         scalar_fields,
         |ctx, f: ScalarFieldRef, default| non_list_scalar_update_field_mapper(ctx, &f, default),
+        |ctx, f: ScalarFieldRef| list_scalar_update_field_mapper(ctx, model, &f),
         false,
     )
 }
@@ -135,6 +133,10 @@ fn non_list_scalar_update_field_mapper(
     field: &ScalarFieldRef,
     default: Option<DefaultValue>,
 ) -> InputField {
+    if field.is_composite() {
+        return composite_objects::composite_update_input_field(ctx, field);
+    }
+
     let base_update_type = match &field.type_identifier {
         TypeIdentifier::Float => InputType::object(operations_object_type(ctx, "Float", field, true)),
         TypeIdentifier::Decimal => InputType::object(operations_object_type(ctx, "Decimal", field, true)),
@@ -160,6 +162,49 @@ fn non_list_scalar_update_field_mapper(
     input_field.optional().nullable_if(!field.is_required)
 }
 
+/// Builds the input field for a scalar list field, accepting either the list or a
+/// "<Model>Update<field>Input" object with exactly one of `set`, `push`, `remove` and `unset`.
+fn list_scalar_update_field_mapper(ctx: &mut BuilderContext, model: &ModelRef, field: &ScalarFieldRef) -> InputField {
+    if field.is_composite() {
+        return composite_objects::composite_update_input_field(ctx, field);
+    }
+
+    let list_input_type = map_scalar_input_type(field);
+    let ident = Identifier::new(format!("{}Update{}Input", model.name, field.name), PRISMA_NAMESPACE);
+
+    let input_object = match ctx.get_input_type(&ident) {
+        Some(t) => t,
+        None => {
+            let mut obj = init_input_object_type(ident.clone());
+            obj.require_exactly_one_field();
+
+            let obj = Arc::new(obj);
+            ctx.cache_input_type(ident, obj.clone());
+
+            let element_type = map_scalar_element_input_type(field);
+            let mut fields = vec![
+                input_field("set", list_input_type.clone(), None).optional(),
+                input_field("push", vec![element_type.clone(), list_input_type.clone()], None).optional(),
+                input_field("remove", vec![element_type, list_input_type.clone()], None).optional(),
+            ];
+
+            if !field.is_required {
+                fields.push(input_field("unset", InputType::boolean(), None).optional());
+            }
+
+            obj.set_fields(fields);
+            Arc::downgrade(&obj)
+        }
+    };
+
+    input_field(
+        field.name.clone(),
+        vec![InputType::object(input_object), list_input_type],
+        None,
+    )
+    .optional()
+}
+
 fn operations_object_type(
     ctx: &mut BuilderContext,
     prefix: &str,
@@ -14,7 +14,12 @@ fn field_avg_output_type(field: &ScalarFieldRef) -> OutputType {
 }
 
 fn collect_non_list_fields(model: &ModelRef) -> Vec<ScalarFieldRef> {
-    model.fields().scalar().into_iter().filter(|f| !f.is_list).collect()
+    model
+        .fields()
+        .scalar()
+        .into_iter()
+        .filter(|f| !f.is_list && !f.is_composite())
+        .collect()
 }
 
 fn collect_numeric_fields(model: &ModelRef) -> Vec<ScalarFieldRef> {
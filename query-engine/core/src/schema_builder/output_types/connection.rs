@@ -0,0 +1,65 @@
+use super::*;
+
+/// Builds the connection object type for given model (e.g. UserConnection).
+pub(crate) fn connection_object_type(ctx: &mut BuilderContext, model: &ModelRef) -> ObjectTypeWeakRef {
+    let ident = Identifier::new(format!("{}Connection", capitalize(&model.name)), PRISMA_NAMESPACE);
+    return_cached_output!(ctx, &ident);
+
+    let model_object_type = output_objects::map_model_object_type(ctx, model);
+    let fields = vec![
+        field(
+            "edges",
+            vec![],
+            OutputType::list(OutputType::object(edge_object_type(ctx, model))),
+            None,
+        ),
+        field(
+            "nodes",
+            vec![],
+            OutputType::list(OutputType::object(model_object_type)),
+            None,
+        ),
+        field("pageInfo", vec![], OutputType::object(page_info_object_type(ctx)), None),
+        field("totalCount", vec![], OutputType::int(), None),
+    ];
+
+    let object = Arc::new(object_type(ident.clone(), fields, Some(ModelRef::clone(model))));
+    ctx.cache_output_type(ident, ObjectTypeStrongRef::clone(&object));
+
+    ObjectTypeStrongRef::downgrade(&object)
+}
+
+/// Builds the edge object type for given model (e.g. UserEdge), pairing a record with its cursor.
+fn edge_object_type(ctx: &mut BuilderContext, model: &ModelRef) -> ObjectTypeWeakRef {
+    let ident = Identifier::new(format!("{}Edge", capitalize(&model.name)), PRISMA_NAMESPACE);
+    return_cached_output!(ctx, &ident);
+
+    let model_object_type = output_objects::map_model_object_type(ctx, model);
+    let fields = vec![
+        field("cursor", vec![], OutputType::string(), None),
+        field("node", vec![], OutputType::object(model_object_type), None),
+    ];
+
+    let object = Arc::new(object_type(ident.clone(), fields, Some(ModelRef::clone(model))));
+    ctx.cache_output_type(ident, ObjectTypeStrongRef::clone(&object));
+
+    ObjectTypeStrongRef::downgrade(&object)
+}
+
+/// Builds the page info object type shared by all connections.
+fn page_info_object_type(ctx: &mut BuilderContext) -> ObjectTypeWeakRef {
+    let ident = Identifier::new("PageInfo".to_owned(), PRISMA_NAMESPACE);
+    return_cached_output!(ctx, &ident);
+
+    let fields = vec![
+        field("hasNextPage", vec![], OutputType::boolean(), None),
+        field("hasPreviousPage", vec![], OutputType::boolean(), None),
+        field("startCursor", vec![], OutputType::string(), None).optional(),
+        field("endCursor", vec![], OutputType::string(), None).optional(),
+    ];
+
+    let object = Arc::new(object_type(ident.clone(), fields, None));
+    ctx.cache_output_type(ident, ObjectTypeStrongRef::clone(&object));
+
+    ObjectTypeStrongRef::downgrade(&object)
+}
@@ -1,7 +1,9 @@
 pub(crate) mod aggregation;
+pub(crate) mod connection;
 pub(crate) mod mutation_type;
 pub(crate) mod output_objects;
 pub(crate) mod query_type;
+pub(crate) mod traversal;
 
 use super::*;
 use input_types::arguments;
@@ -7,6 +7,7 @@ pub(crate) fn build(ctx: &mut BuilderContext) -> (OutputType, ObjectTypeStrongRe
     let non_embedded_models = ctx.internal_data_model.non_embedded_models();
     let mut fields: Vec<OutputField> = non_embedded_models
         .into_iter()
+        .filter(|model| !model.is_view) // Views are read-only.
         .map(|model| {
             let mut vec = vec![create_item_field(ctx, &model)];
 
@@ -26,7 +27,11 @@ pub(crate) fn build(ctx: &mut BuilderContext) -> (OutputType, ObjectTypeStrongRe
 
     if ctx.enable_raw_queries {
         fields.push(create_execute_raw_field());
-        fields.push(create_query_raw_field());
+        fields.push(create_query_raw_field(ctx, None));
+
+        for model in ctx.internal_data_model.non_embedded_models() {
+            fields.push(create_query_raw_field(ctx, Some(&model)));
+        }
     }
 
     let ident = Identifier::new("Mutation".to_owned(), PRISMA_NAMESPACE);
@@ -95,24 +100,50 @@ fn create_execute_raw_field() -> OutputField {
     )
 }
 
-fn create_query_raw_field() -> OutputField {
-    field(
-        "queryRaw",
-        vec![
-            input_field("query", InputType::string(), None),
-            input_field(
-                "parameters",
-                InputType::json_list(),
-                Some(dml::DefaultValue::Single(PrismaValue::String("[]".into()))),
+/// Builds the `queryRaw` field, or, for a model, a field (e.g. queryRawUser)
+/// mapping the rows of the raw query onto records of that model.
+fn create_query_raw_field(ctx: &mut BuilderContext, model: Option<&ModelRef>) -> OutputField {
+    let mut args = vec![
+        input_field("query", InputType::string(), None),
+        input_field(
+            "parameters",
+            InputType::json_list(),
+            Some(dml::DefaultValue::Single(PrismaValue::String("[]".into()))),
+        )
+        .optional(),
+    ];
+
+    match model {
+        Some(model) => field(
+            format!("queryRaw{}", model.name),
+            args,
+            OutputType::list(OutputType::object(output_objects::map_model_object_type(ctx, &model))),
+            Some(QueryInfo {
+                tag: QueryTag::QueryRaw,
+                model: Some(Arc::clone(&model)),
+            }),
+        ),
+        None => {
+            args.push(
+                input_field(
+                    "typed",
+                    InputType::boolean(),
+                    Some(dml::DefaultValue::Single(PrismaValue::Boolean(false))),
+                )
+                .optional(),
+            );
+
+            field(
+                "queryRaw",
+                args,
+                OutputType::json(),
+                Some(QueryInfo {
+                    tag: QueryTag::QueryRaw,
+                    model: None,
+                }),
             )
-            .optional(),
-        ],
-        OutputType::json(),
-        Some(QueryInfo {
-            tag: QueryTag::QueryRaw,
-            model: None,
-        }),
-    )
+        }
+    }
 }
 
 /// Builds a create mutation field (e.g. createUser) for given model.
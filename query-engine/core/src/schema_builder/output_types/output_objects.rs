@@ -1,5 +1,5 @@
 use super::*;
-use prisma_models::ScalarFieldRef;
+use prisma_models::{InternalCompositeType, InternalEnum, ScalarFieldRef};
 
 /// Initializes model output object type cache on the context.
 /// This is a critical first step to ensure that all model output object types are present
@@ -61,6 +61,7 @@ pub(crate) fn map_field(ctx: &mut BuilderContext, model_field: &ModelField) -> O
 
 pub(crate) fn map_output_type(ctx: &mut BuilderContext, model_field: &ModelField) -> OutputType {
     match model_field {
+        ModelField::Scalar(sf) if sf.is_composite() => map_composite_output_type(ctx, sf),
         ModelField::Scalar(sf) => map_scalar_output_type(sf),
         ModelField::Relation(rf) => map_relation_output_type(ctx, rf),
     }
@@ -68,11 +69,32 @@ pub(crate) fn map_output_type(ctx: &mut BuilderContext, model_field: &ModelField
 
 pub(crate) fn map_scalar_output_type(field: &ScalarFieldRef) -> OutputType {
     let output_type = match field.type_identifier {
+        TypeIdentifier::Enum(_) => map_enum_field(field).into(),
+        ref type_identifier => map_type_identifier_output_type(type_identifier, None),
+    };
+
+    if field.is_list {
+        OutputType::list(output_type)
+    } else {
+        output_type
+    }
+}
+
+fn map_type_identifier_output_type(
+    type_identifier: &TypeIdentifier,
+    internal_enum: Option<&InternalEnum>,
+) -> OutputType {
+    match type_identifier {
         TypeIdentifier::String => OutputType::string(),
         TypeIdentifier::Float => OutputType::float(),
         TypeIdentifier::Decimal => OutputType::decimal(),
         TypeIdentifier::Boolean => OutputType::boolean(),
-        TypeIdentifier::Enum(_) => map_enum_field(field).into(),
+        TypeIdentifier::Enum(_) => {
+            let internal_enum = internal_enum
+                .expect("Invariant violation: Enum fields are expected to have an internal_enum associated with them.");
+
+            EnumType::from(internal_enum.clone()).into()
+        }
         TypeIdentifier::Json => OutputType::json(),
         TypeIdentifier::DateTime => OutputType::date_time(),
         TypeIdentifier::UUID => OutputType::uuid(),
@@ -80,15 +102,54 @@ pub(crate) fn map_scalar_output_type(field: &ScalarFieldRef) -> OutputType {
         TypeIdentifier::Xml => OutputType::xml(),
         TypeIdentifier::Bytes => OutputType::bytes(),
         TypeIdentifier::BigInt => OutputType::bigint(),
-    };
+    }
+}
+
+/// Maps a composite type field to the output object type of its composite type.
+pub(crate) fn map_composite_output_type(ctx: &mut BuilderContext, field: &ScalarFieldRef) -> OutputType {
+    let composite_type = field
+        .composite_type()
+        .expect("Invariant violation: map_composite_output_type can only be called on composite type fields.");
+
+    let object_type = OutputType::object(composite_object_type(ctx, &composite_type));
 
     if field.is_list {
-        OutputType::list(output_type)
+        OutputType::list(object_type)
     } else {
-        output_type
+        object_type
     }
 }
 
+/// Builds the output object type of a composite type. Composite types share the namespace of the
+/// models, but their object types don't have a model, as they are not queried on their own.
+fn composite_object_type(ctx: &mut BuilderContext, composite_type: &InternalCompositeType) -> ObjectTypeWeakRef {
+    let ident = Identifier::new(composite_type.name.clone(), MODEL_NAMESPACE);
+    return_cached_output!(ctx, &ident);
+
+    let object_type = Arc::new(ObjectType::new(ident.clone(), None));
+    ctx.cache_output_type(ident, object_type.clone());
+
+    let fields = composite_type
+        .fields
+        .iter()
+        .map(|f| {
+            let typ = match f.composite_type {
+                Some(ref name) => {
+                    let nested = ctx.internal_data_model.find_composite_type(name).unwrap().clone();
+                    OutputType::object(composite_object_type(ctx, &nested))
+                }
+                None => map_type_identifier_output_type(&f.type_identifier, f.internal_enum.as_ref()),
+            };
+
+            let typ = if f.is_list() { OutputType::list(typ) } else { typ };
+            field(f.name.clone(), vec![], typ, None).optional_if(!f.is_required())
+        })
+        .collect();
+
+    object_type.set_fields(fields);
+    Arc::downgrade(&object_type)
+}
+
 pub(crate) fn map_relation_output_type(ctx: &mut BuilderContext, field: &RelationFieldRef) -> OutputType {
     let related_model_obj = OutputType::object(map_model_object_type(ctx, &field.related_model()));
 
@@ -9,6 +9,7 @@ pub(crate) fn build(ctx: &mut BuilderContext) -> (OutputType, ObjectTypeStrongRe
             let mut vec = vec![
                 find_first_field(ctx, &model),
                 all_items_field(ctx, &model),
+                connection_field(ctx, &model),
                 plain_aggregation_field(ctx, &model),
             ];
 
@@ -17,6 +18,7 @@ pub(crate) fn build(ctx: &mut BuilderContext) -> (OutputType, ObjectTypeStrongRe
             }
 
             append_opt(&mut vec, find_one_field(ctx, &model));
+            append_opt(&mut vec, recursive_field(ctx, &model));
             vec
         })
         .flatten()
@@ -80,6 +82,36 @@ fn all_items_field(ctx: &mut BuilderContext, model: &ModelRef) -> OutputField {
     )
 }
 
+/// Builds a connection query field (e.g. "findManyUserConnection") for given model,
+/// reading a page of records along with the information to page further.
+fn connection_field(ctx: &mut BuilderContext, model: &ModelRef) -> OutputField {
+    field(
+        format!("findMany{}Connection", model.name),
+        arguments::connection_arguments(ctx, &model),
+        OutputType::object(connection::connection_object_type(ctx, &model)),
+        Some(QueryInfo {
+            model: Some(Arc::clone(&model)),
+            tag: QueryTag::FindManyConnection,
+        }),
+    )
+}
+
+/// Builds a recursive query field (e.g. "findManyCategoryRecursive") for given model,
+/// reading all records reachable from a record along a self-relation.
+fn recursive_field(ctx: &mut BuilderContext, model: &ModelRef) -> Option<OutputField> {
+    arguments::traversal_arguments(ctx, model).map(|args| {
+        field(
+            format!("findMany{}Recursive", model.name),
+            args,
+            OutputType::list(OutputType::object(traversal::traversal_object_type(ctx, &model))),
+            Some(QueryInfo {
+                model: Some(Arc::clone(&model)),
+                tag: QueryTag::FindManyRecursive,
+            }),
+        )
+    })
+}
+
 /// Builds an "aggregate" query field (e.g. "aggregateUser") for given model.
 fn plain_aggregation_field(ctx: &mut BuilderContext, model: &ModelRef) -> OutputField {
     field(
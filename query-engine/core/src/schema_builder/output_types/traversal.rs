@@ -0,0 +1,19 @@
+use super::*;
+
+/// Builds the traversal object type for given model (e.g. CategoryTraversal),
+/// pairing a record reached by a recursive query with the depth it was reached at.
+pub(crate) fn traversal_object_type(ctx: &mut BuilderContext, model: &ModelRef) -> ObjectTypeWeakRef {
+    let ident = Identifier::new(format!("{}Traversal", capitalize(&model.name)), PRISMA_NAMESPACE);
+    return_cached_output!(ctx, &ident);
+
+    let model_object_type = output_objects::map_model_object_type(ctx, model);
+    let fields = vec![
+        field("depth", vec![], OutputType::int(), None),
+        field("node", vec![], OutputType::object(model_object_type), None),
+    ];
+
+    let object = Arc::new(object_type(ident.clone(), fields, Some(ModelRef::clone(model))));
+    ctx.cache_output_type(ident, ObjectTypeStrongRef::clone(&object));
+
+    ObjectTypeStrongRef::downgrade(&object)
+}
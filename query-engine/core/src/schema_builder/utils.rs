@@ -71,6 +71,7 @@ where
         field_types: field_types.into(),
         default_value,
         is_required: true,
+        validations: Vec::new(),
     }
 }
 
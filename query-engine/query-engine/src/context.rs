@@ -1,7 +1,8 @@
-use crate::{exec_loader, PrismaError, PrismaResult};
+use crate::{exec_loader, request_handlers::PersistedQueries, PrismaError, PrismaResult};
+use connector::PoolState;
 use datamodel::{Configuration, Datamodel};
 use prisma_models::DatamodelConverter;
-use query_core::{schema::QuerySchemaRef, schema_builder, BuildMode, QueryExecutor};
+use query_core::{schema::QuerySchemaRef, schema_builder, BuildMode, QueryExecutor, QueryLimits};
 use std::{fmt, sync::Arc};
 
 /// Prisma request context containing all immutable state of the process.
@@ -13,6 +14,8 @@ pub struct PrismaContext {
     dm: Datamodel,
     /// Central query executor.
     pub executor: Box<dyn QueryExecutor + Send + Sync + 'static>,
+    /// Persisted queries, shared with the contexts replacing this one on reload.
+    persisted_queries: Arc<PersistedQueries>,
 }
 
 impl fmt::Debug for PrismaContext {
@@ -24,6 +27,8 @@ impl fmt::Debug for PrismaContext {
 pub struct ContextBuilder {
     legacy: bool,
     enable_raw_queries: bool,
+    persisted_queries: Arc<PersistedQueries>,
+    query_limits: QueryLimits,
     datamodel: Datamodel,
     config: Configuration,
 }
@@ -39,14 +44,39 @@ impl ContextBuilder {
         self
     }
 
+    pub fn persisted_queries(mut self, val: Arc<PersistedQueries>) -> Self {
+        self.persisted_queries = val;
+        self
+    }
+
+    pub fn query_limits(mut self, val: QueryLimits) -> Self {
+        self.query_limits = val;
+        self
+    }
+
     pub async fn build(self) -> PrismaResult<PrismaContext> {
-        PrismaContext::new(self.config, self.datamodel, self.legacy, self.enable_raw_queries).await
+        PrismaContext::new(
+            self.config,
+            self.datamodel,
+            self.legacy,
+            self.enable_raw_queries,
+            self.persisted_queries,
+            self.query_limits,
+        )
+        .await
     }
 }
 
 impl PrismaContext {
     /// Initializes a new Prisma context.
-    async fn new(config: Configuration, dm: Datamodel, legacy: bool, enable_raw_queries: bool) -> PrismaResult<Self> {
+    async fn new(
+        config: Configuration,
+        dm: Datamodel,
+        legacy: bool,
+        enable_raw_queries: bool,
+        persisted_queries: Arc<PersistedQueries>,
+        query_limits: QueryLimits,
+    ) -> PrismaResult<Self> {
         let template = DatamodelConverter::convert(&dm);
 
         // We only support one data source at the moment, so take the first one (default not exposed yet).
@@ -63,17 +93,21 @@ impl PrismaContext {
 
         // Construct query schema
         let build_mode = if legacy { BuildMode::Legacy } else { BuildMode::Modern };
-        let query_schema: QuerySchemaRef = Arc::new(schema_builder::build(
-            internal_data_model,
-            build_mode,
-            enable_raw_queries,
-            data_source.capabilities(),
-        ));
+        let query_schema: QuerySchemaRef = Arc::new(
+            schema_builder::build(
+                internal_data_model,
+                build_mode,
+                enable_raw_queries,
+                data_source.capabilities(),
+            )
+            .with_limits(query_limits),
+        );
 
         let context = Self {
             query_schema,
             dm,
             executor,
+            persisted_queries,
         };
 
         context.verify_connection().await?;
@@ -90,6 +124,8 @@ impl PrismaContext {
         ContextBuilder {
             legacy: false,
             enable_raw_queries: false,
+            persisted_queries: Arc::new(PersistedQueries::default()),
+            query_limits: QueryLimits::default(),
             datamodel,
             config,
         }
@@ -103,7 +139,22 @@ impl PrismaContext {
         &self.dm
     }
 
+    pub fn persisted_queries(&self) -> &PersistedQueries {
+        &self.persisted_queries
+    }
+
     pub fn primary_connector(&self) -> String {
         self.executor.primary_connector().name()
     }
+
+    /// Runs a health query against the primary database, returning the database version.
+    pub async fn health_check(&self) -> PrismaResult<Option<String>> {
+        let conn = self.executor.primary_connector().get_connection().await?;
+        Ok(conn.health_check().await?)
+    }
+
+    /// The state of the connection pool of the primary connector.
+    pub fn pool_state(&self) -> Option<PoolState> {
+        self.executor.primary_connector().pool_state()
+    }
 }
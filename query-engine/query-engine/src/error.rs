@@ -44,6 +44,12 @@ pub enum PrismaError {
 
     #[error("{}", _0)]
     FeatureError(String),
+
+    #[error("Persisted query `{}` not found, send the query along with its hash.", _0)]
+    PersistedQueryNotFound(String),
+
+    #[error("Only persisted queries are accepted, but query `{}` isn't persisted.", _0)]
+    PersistedQueryRequired(String),
 }
 
 impl From<PrismaError> for user_facing_errors::Error {
@@ -50,11 +50,12 @@ async fn sqlite(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecu
     trace!("Loading SQLite connector...");
 
     let sqlite = Sqlite::from_source(source).await?;
+    let replicas = replicas(source).await?;
     let path = PathBuf::from(sqlite.file_path());
     let db_name = path.file_stem().unwrap().to_str().unwrap().to_owned(); // Safe due to previous validations.
 
     trace!("Loaded SQLite connector.");
-    Ok((db_name, sql_executor(sqlite, false)))
+    Ok((db_name, sql_executor(sqlite, replicas, false)))
 }
 
 #[cfg(feature = "sql")]
@@ -63,6 +64,7 @@ async fn postgres(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExe
 
     let database_str = &source.url().value;
     let psql = PostgreSql::from_source(source).await?;
+    let replicas = replicas(source).await?;
 
     let url = Url::parse(database_str)?;
     let params: HashMap<String, String> = url.query_pairs().into_owned().collect();
@@ -78,7 +80,7 @@ async fn postgres(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExe
         .unwrap_or(false);
 
     trace!("Loaded Postgres connector.");
-    Ok((db_name, sql_executor(psql, force_transactions)))
+    Ok((db_name, sql_executor(psql, replicas, force_transactions)))
 }
 
 #[cfg(feature = "sql")]
@@ -86,6 +88,7 @@ async fn mysql(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecut
     trace!("Loading MySQL connector...");
 
     let mysql = Mysql::from_source(source).await?;
+    let replicas = replicas(source).await?;
     let database_str = &source.url().value;
 
     let url = Url::parse(database_str)?;
@@ -98,7 +101,7 @@ async fn mysql(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecut
     let db_name = db_name.next().expect(err_str).to_owned();
 
     trace!("Loaded MySQL connector.");
-    Ok((db_name, sql_executor(mysql, false)))
+    Ok((db_name, sql_executor(mysql, replicas, false)))
 }
 
 #[cfg(feature = "sql")]
@@ -106,6 +109,7 @@ async fn mssql(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecut
     trace!("Loading SQL Server connector...");
 
     let mssql = Mssql::from_source(source).await?;
+    let replicas = replicas(source).await?;
 
     let mut conn = JdbcString::from_str(&format!("jdbc:{}", &source.url().value))?;
     let db_name = conn
@@ -114,13 +118,36 @@ async fn mssql(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecut
         .unwrap_or_else(|| String::from("dbo"));
 
     trace!("Loaded SQL Server connector.");
-    Ok((db_name, sql_executor(mssql, false)))
+    Ok((db_name, sql_executor(mssql, replicas, false)))
 }
 
+/// Loads a connector for each read replica configured on the datasource.
 #[cfg(feature = "sql")]
-fn sql_executor<T>(connector: T, force_transactions: bool) -> Box<dyn QueryExecutor + Send + Sync + 'static>
+async fn replicas<T>(source: &Datasource) -> PrismaResult<Vec<T>>
+where
+    T: FromSource + Connector + Send + Sync + 'static,
+{
+    let mut replicas = Vec::with_capacity(source.read_replicas.len());
+
+    for replica in source.read_replicas.iter() {
+        replicas.push(T::from_url(&replica.value, &source.pool).await?);
+    }
+
+    if !replicas.is_empty() {
+        trace!("Loaded {} read replica connector(s).", replicas.len());
+    }
+
+    Ok(replicas)
+}
+
+#[cfg(feature = "sql")]
+fn sql_executor<T>(
+    connector: T,
+    replicas: Vec<T>,
+    force_transactions: bool,
+) -> Box<dyn QueryExecutor + Send + Sync + 'static>
 where
     T: Connector + Send + Sync + 'static,
 {
-    Box::new(InterpretingExecutor::new(connector, force_transactions))
+    Box::new(InterpretingExecutor::new(connector, force_transactions).with_replicas(replicas))
 }
@@ -1,8 +1,9 @@
-use crate::{error::PrismaError, PrismaResult};
+use crate::{error::PrismaError, request_handlers::PersistedQueries, PrismaResult};
 use datamodel::diagnostics::ValidatedConfiguration;
-use datamodel::Datamodel;
+use datamodel::{Datamodel, PoolSettings};
+use query_core::QueryLimits;
 use serde::Deserialize;
-use std::{ffi::OsStr, fs::File, io::Read};
+use std::{fs::File, io::Read};
 use structopt::StructOpt;
 
 #[derive(Debug, StructOpt, Clone)]
@@ -61,7 +62,7 @@ pub struct PrismaOpt {
     pub unix_path: Option<String>,
 
     /// Path to the Prisma datamodel file
-    #[structopt(long, env = "PRISMA_DML_PATH", parse(from_os_str = load_datamodel_file))]
+    #[structopt(long, env = "PRISMA_DML_PATH")]
     pub datamodel_path: Option<String>,
 
     /// Base64 encoded Prisma datamodel
@@ -80,14 +81,70 @@ pub struct PrismaOpt {
     #[structopt(long, short = "r")]
     pub enable_raw_queries: bool,
 
+    /// Path to a JSON file of persisted queries, mapping the SHA-256 hash of each GraphQL document to the document.
+    #[structopt(long, env = "PRISMA_PERSISTED_QUERIES_PATH")]
+    pub persisted_queries_path: Option<String>,
+
+    /// Rejects all queries that aren't persisted, and disables persisting queries from requests.
+    #[structopt(long)]
+    pub only_persisted_queries: bool,
+
+    /// Maximum depth of the selection of a query, the top-level field being at depth one.
+    #[structopt(long, env = "PRISMA_MAX_SELECTION_DEPTH")]
+    pub max_selection_depth: Option<u64>,
+
+    /// Maximum depth of nested writes, a write without nested writes being at depth zero.
+    #[structopt(long, env = "PRISMA_MAX_NESTED_WRITE_DEPTH")]
+    pub max_nested_write_depth: Option<u64>,
+
+    /// Maximum number of operations in a batch.
+    #[structopt(long, env = "PRISMA_MAX_BATCH_SIZE")]
+    pub max_batch_size: Option<u64>,
+
+    /// Maximum number of records a `take`, `first` or `last` argument can ask for.
+    #[structopt(long, env = "PRISMA_MAX_TAKE")]
+    pub max_take: Option<u64>,
+
+    /// Maximum estimated cost of a query, the number of records it may read.
+    #[structopt(long, env = "PRISMA_MAX_QUERY_COST")]
+    pub max_query_cost: Option<u64>,
+
     /// Enables the GraphQL playground
     #[structopt(long, short = "g")]
     pub enable_playground: bool,
 
+    /// Enables the `/reload` endpoint, reloading the datamodel file at `--datamodel-path` without a restart.
+    #[structopt(long)]
+    pub enable_reload_endpoint: bool,
+
     /// Enables server debug features.
     #[structopt(long = "debug", short = "d")]
     pub enable_debug_mode: bool,
 
+    /// Maximum number of open database connections, overriding the datasource setting.
+    #[structopt(long, env = "PRISMA_CONNECTION_LIMIT")]
+    pub connection_limit: Option<usize>,
+
+    /// Number of database connections opened on startup, overriding the datasource setting.
+    #[structopt(long, env = "PRISMA_MIN_IDLE_CONNECTIONS")]
+    pub min_idle_connections: Option<usize>,
+
+    /// Seconds to wait for a free database connection, overriding the datasource setting.
+    #[structopt(long, env = "PRISMA_POOL_TIMEOUT")]
+    pub pool_timeout: Option<u64>,
+
+    /// Seconds after which database connections are closed, overriding the datasource setting.
+    #[structopt(long, env = "PRISMA_MAX_CONNECTION_LIFETIME")]
+    pub max_connection_lifetime: Option<u64>,
+
+    /// Seconds after which unused database connections are closed, overriding the datasource setting.
+    #[structopt(long, env = "PRISMA_IDLE_TIMEOUT")]
+    pub idle_timeout: Option<u64>,
+
+    /// Seconds in-flight requests get to finish after a shutdown signal before they are aborted.
+    #[structopt(long, env = "PRISMA_SHUTDOWN_TIMEOUT", default_value = "30")]
+    pub shutdown_timeout: u64,
+
     /// Set the log format.
     #[structopt(long = "log-format", env = "RUST_LOG_FORMAT")]
     pub log_format: Option<String>,
@@ -106,31 +163,35 @@ struct SourceOverride {
 }
 
 impl PrismaOpt {
-    fn datamodel_str(&self) -> PrismaResult<&str> {
-        let res = self
-            .datamodel
-            .as_deref()
-            .or(self.datamodel_path.as_deref())
-            .ok_or_else(|| {
-                PrismaError::ConfigurationError(
-                    "Datamodel should be provided either as path or base64-encoded string.".into(),
-                )
-            })?;
-
-        Ok(res)
+    /// The datamodel string. A datamodel given as path is read from disk on every call,
+    /// so reloading the datamodel picks up changes to the file.
+    fn datamodel_str(&self) -> PrismaResult<String> {
+        match (self.datamodel.as_ref(), self.datamodel_path.as_ref()) {
+            (Some(datamodel), _) => Ok(datamodel.clone()),
+            (None, Some(path)) => load_datamodel_file(path),
+            (None, None) => Err(PrismaError::ConfigurationError(
+                "Datamodel should be provided either as path or base64-encoded string.".into(),
+            )),
+        }
+    }
+
+    /// Only a datamodel read from a file can change while the engine is running,
+    /// a datamodel given as string is fixed on startup.
+    pub(crate) fn datamodel_is_reloadable(&self) -> bool {
+        self.datamodel.is_none() && self.datamodel_path.is_some()
     }
 
     pub fn datamodel(&self, ignore_data_sources: bool) -> PrismaResult<Datamodel> {
         let datamodel_str = self.datamodel_str()?;
 
         let datamodel = if ignore_data_sources {
-            datamodel::parse_datamodel_and_ignore_datasource_urls(datamodel_str)
+            datamodel::parse_datamodel_and_ignore_datasource_urls(&datamodel_str)
         } else {
-            datamodel::parse_datamodel(datamodel_str)
+            datamodel::parse_datamodel(&datamodel_str)
         };
 
         match datamodel {
-            Err(errors) => Err(PrismaError::ConversionError(errors, datamodel_str.to_string())),
+            Err(errors) => Err(PrismaError::ConversionError(errors, datamodel_str)),
             _ => Ok(datamodel.unwrap().subject),
         }
     }
@@ -146,11 +207,57 @@ impl PrismaOpt {
         };
 
         let config_result = if ignore_env_errors {
-            datamodel::parse_configuration_and_ignore_datasource_urls(datamodel_str)
+            datamodel::parse_configuration_and_ignore_datasource_urls(&datamodel_str)
         } else {
-            datamodel::parse_configuration_with_url_overrides(datamodel_str, datasource_url_overrides)
+            datamodel::parse_configuration_with_url_overrides(&datamodel_str, datasource_url_overrides)
         };
-        config_result.map_err(|errors| PrismaError::ConversionError(errors, datamodel_str.to_string()))
+        let mut config = config_result.map_err(|errors| PrismaError::ConversionError(errors, datamodel_str))?;
+
+        for datasource in config.subject.datasources.iter_mut() {
+            self.override_pool_settings(&mut datasource.pool)?;
+        }
+
+        Ok(config)
+    }
+
+    /// Pool settings given on the command line take precedence over the ones in the datasource.
+    /// The merged settings are validated like the ones in the datasource.
+    fn override_pool_settings(&self, pool: &mut PoolSettings) -> PrismaResult<()> {
+        pool.connection_limit = self.connection_limit.or(pool.connection_limit);
+        pool.min_idle_connections = self.min_idle_connections.or(pool.min_idle_connections);
+        pool.pool_timeout = self.pool_timeout.or(pool.pool_timeout);
+        pool.max_connection_lifetime = self.max_connection_lifetime.or(pool.max_connection_lifetime);
+        pool.idle_timeout = self.idle_timeout.or(pool.idle_timeout);
+
+        if let (Some(limit), Some(min_idle)) = (pool.connection_limit, pool.min_idle_connections) {
+            if min_idle > limit {
+                return Err(PrismaError::ConfigurationError(format!(
+                    "The number of min idle connections ({}) must not be greater than the connection limit ({}).",
+                    min_idle, limit
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The persisted queries, loaded from the registry file if one is given.
+    pub(crate) fn persisted_queries(&self) -> PrismaResult<PersistedQueries> {
+        match self.persisted_queries_path.as_ref() {
+            Some(path) => PersistedQueries::load(path, self.only_persisted_queries),
+            None => Ok(PersistedQueries::new(self.only_persisted_queries)),
+        }
+    }
+
+    /// The limits on the shape and size of queries.
+    pub(crate) fn query_limits(&self) -> QueryLimits {
+        QueryLimits {
+            max_selection_depth: self.max_selection_depth,
+            max_nested_write_depth: self.max_nested_write_depth,
+            max_batch_size: self.max_batch_size,
+            max_take: self.max_take,
+            max_cost: self.max_query_cost,
+        }
     }
 
     /// Extract the log format from on the RUST_LOG_FORMAT env var.
@@ -181,12 +288,13 @@ fn parse_base64_string(s: &str) -> PrismaResult<String> {
     }
 }
 
-fn load_datamodel_file(path: &OsStr) -> String {
-    let mut f = File::open(path).unwrap_or_else(|_| panic!("Could not open datamodel file {:?}", path));
+fn load_datamodel_file(path: &str) -> PrismaResult<String> {
+    let mut f = File::open(path)
+        .map_err(|err| PrismaError::ConfigurationError(format!("Could not open datamodel file {:?}: {}", path, err)))?;
     let mut datamodel = String::new();
 
     f.read_to_string(&mut datamodel)
-        .unwrap_or_else(|_| panic!("Could not read datamodel file: {:?}", path));
+        .map_err(|err| PrismaError::ConfigurationError(format!("Could not read datamodel file {:?}: {}", path, err)))?;
 
-    datamodel
+    Ok(datamodel)
 }
@@ -1,25 +1,39 @@
-use super::{protocol_adapter::GraphQLProtocolAdapter, GQLResponse};
-use crate::{context::PrismaContext, PrismaResponse, PrismaResult};
+use super::{
+    persisted::{query_hash, PersistedQueries},
+    protocol_adapter::{GraphQLProtocolAdapter, Variables},
+    GQLResponse,
+};
+use crate::{context::PrismaContext, PrismaError, PrismaResponse, PrismaResult};
 use futures::FutureExt;
 use graphql_parser as gql;
 use indexmap::IndexMap;
-use query_core::{BatchDocument, CompactedDocument, Item, Operation, QueryDocument, QueryValue, ResponseData};
+use query_core::{
+    BatchDocument, CompactedDocument, CoreError, Item, Operation, QueryDocument, QueryValue, RecordStream, ResponseData,
+};
 use serde::{Deserialize, Serialize};
 use std::{collections::HashMap, panic::AssertUnwindSafe, sync::Arc};
 
+// `Multi` comes first, as every field of `SingleQuery` is optional.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase", untagged)]
 pub enum GraphQlBody {
-    Single(SingleQuery),
     Multi(MultiQuery),
+    Single(SingleQuery),
 }
 
+/// A single GraphQL query. Persisted queries are referenced by `query_hash`, the SHA-256 hash
+/// of the document. Sending both the query and its hash registers the query as persisted.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SingleQuery {
-    query: String,
+    #[serde(default)]
+    query: Option<String>,
+    #[serde(default)]
+    query_hash: Option<String>,
+    #[serde(default)]
     operation_name: Option<String>,
-    variables: HashMap<String, String>,
+    #[serde(default)]
+    variables: Variables,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -32,7 +46,8 @@ pub struct MultiQuery {
 impl From<String> for SingleQuery {
     fn from(query: String) -> Self {
         SingleQuery {
-            query,
+            query: Some(query),
+            query_hash: None,
             operation_name: None,
             variables: HashMap::new(),
         }
@@ -45,24 +60,45 @@ impl From<&str> for SingleQuery {
     }
 }
 
+impl SingleQuery {
+    fn into_operation(self, persisted: &PersistedQueries) -> PrismaResult<Operation> {
+        let template = match (self.query, self.query_hash) {
+            (query, Some(hash)) => persisted.template(&hash, query, self.operation_name)?,
+            (Some(query), None) if persisted.only_persisted() => {
+                persisted.template(&query_hash(&query), Some(query), self.operation_name)?
+            }
+            (Some(query), None) => {
+                let gql_doc = gql::parse_query(&query)?;
+                Arc::new(GraphQLProtocolAdapter::convert(gql_doc, self.operation_name)?)
+            }
+            (None, None) => {
+                return Err(PrismaError::InvocationError(
+                    "Either a query or the hash of a persisted query is required.".into(),
+                ))
+            }
+        };
+
+        template.fill(&self.variables)
+    }
+}
+
 impl GraphQlBody {
-    /// Convert a `GraphQlBody` into a `QueryDocument`.
-    pub(crate) fn into_doc(self) -> PrismaResult<QueryDocument> {
-        match self {
-            GraphQlBody::Single(body) => {
-                let gql_doc = gql::parse_query(&body.query)?;
-                let operation = GraphQLProtocolAdapter::convert(gql_doc, body.operation_name)?;
+    /// Convert a `GraphQlBody` into a `QueryDocument`, resolving persisted queries.
+    pub(crate) fn into_doc(self, cx: &PrismaContext) -> PrismaResult<QueryDocument> {
+        let persisted = cx.persisted_queries();
 
-                Ok(QueryDocument::Single(operation))
-            }
+        match self {
+            GraphQlBody::Single(body) => Ok(QueryDocument::Single(body.into_operation(persisted)?)),
             GraphQlBody::Multi(bodies) => {
+                cx.query_schema()
+                    .limits
+                    .check_batch_size(bodies.batch.len())
+                    .map_err(CoreError::from)?;
+
                 let operations: PrismaResult<Vec<Operation>> = bodies
                     .batch
                     .into_iter()
-                    .map(|body| {
-                        let gql_doc = gql::parse_query(&body.query)?;
-                        GraphQLProtocolAdapter::convert(gql_doc, body.operation_name)
-                    })
+                    .map(|body| body.into_operation(persisted))
                     .collect();
 
                 Ok(QueryDocument::Multi(BatchDocument::new(
@@ -75,23 +111,44 @@ impl GraphQlBody {
 }
 
 /// Handle a Graphql request.
-pub(crate) async fn handle(body: GraphQlBody, cx: Arc<PrismaContext>) -> PrismaResponse {
+/// If `force_primary` is set, read-only operations are not routed to read replicas.
+pub(crate) async fn handle(body: GraphQlBody, cx: Arc<PrismaContext>, force_primary: bool) -> PrismaResponse {
     debug!("Incoming GraphQL query: {:?}", body);
 
-    match body.into_doc() {
-        Ok(QueryDocument::Single(query)) => handle_single_query(query, cx.clone()).await,
+    match body.into_doc(&cx) {
+        Ok(QueryDocument::Single(query)) => handle_single_query(query, cx.clone(), force_primary).await,
         Ok(QueryDocument::Multi(batch)) => match batch.compact() {
-            BatchDocument::Multi(batch, transactional) => handle_batch(batch, transactional, &cx).await,
-            BatchDocument::Compact(compacted) => handle_compacted(compacted, &cx).await,
+            BatchDocument::Multi(batch, transactional) => handle_batch(batch, transactional, &cx, force_primary).await,
+            BatchDocument::Compact(compacted) => handle_compacted(compacted, &cx, force_primary).await,
         },
         Err(err) => PrismaResponse::Single(err.into()),
     }
 }
 
-async fn handle_single_query(query: Operation, ctx: Arc<PrismaContext>) -> PrismaResponse {
+/// Handle a GraphQL request streaming the records of a single `findMany` query.
+/// Errors occurring before the first record is read are returned directly.
+pub(crate) async fn handle_stream(
+    body: GraphQlBody,
+    cx: Arc<PrismaContext>,
+    force_primary: bool,
+) -> PrismaResult<RecordStream> {
+    debug!("Incoming GraphQL stream query: {:?}", body);
+
+    match body.into_doc(&cx)? {
+        QueryDocument::Single(operation) => Ok(cx
+            .executor
+            .execute_stream(operation, Arc::clone(cx.query_schema()), force_primary)
+            .await?),
+        QueryDocument::Multi(_) => Err(PrismaError::InvocationError(
+            "Batches can't be streamed, send a single findMany query instead.".into(),
+        )),
+    }
+}
+
+async fn handle_single_query(query: Operation, ctx: Arc<PrismaContext>, force_primary: bool) -> PrismaResponse {
     use user_facing_errors::Error;
 
-    let gql_response = match AssertUnwindSafe(handle_graphql_query(query, &*ctx))
+    let gql_response = match AssertUnwindSafe(handle_graphql_query(query, &*ctx, force_primary))
         .catch_unwind()
         .await
     {
@@ -107,13 +164,20 @@ async fn handle_single_query(query: Operation, ctx: Arc<PrismaContext>) -> Prism
     PrismaResponse::Single(gql_response)
 }
 
-async fn handle_batch(queries: Vec<Operation>, transactional: bool, ctx: &Arc<PrismaContext>) -> PrismaResponse {
+async fn handle_batch(
+    queries: Vec<Operation>,
+    transactional: bool,
+    ctx: &Arc<PrismaContext>,
+    force_primary: bool,
+) -> PrismaResponse {
     use user_facing_errors::Error;
 
-    match AssertUnwindSafe(
-        ctx.executor
-            .execute_batch(queries, transactional, ctx.query_schema().clone()),
-    )
+    match AssertUnwindSafe(ctx.executor.execute_batch(
+        queries,
+        transactional,
+        ctx.query_schema().clone(),
+        force_primary,
+    ))
     .catch_unwind()
     .await
     {
@@ -139,7 +203,11 @@ async fn handle_batch(queries: Vec<Operation>, transactional: bool, ctx: &Arc<Pr
     }
 }
 
-async fn handle_compacted(document: CompactedDocument, ctx: &Arc<PrismaContext>) -> PrismaResponse {
+async fn handle_compacted(
+    document: CompactedDocument,
+    ctx: &Arc<PrismaContext>,
+    force_primary: bool,
+) -> PrismaResponse {
     use user_facing_errors::Error;
 
     let plural_name = document.plural_name();
@@ -148,7 +216,7 @@ async fn handle_compacted(document: CompactedDocument, ctx: &Arc<PrismaContext>)
     let arguments = document.arguments;
     let nested_selection = document.nested_selection;
 
-    match AssertUnwindSafe(handle_graphql_query(document.operation, ctx))
+    match AssertUnwindSafe(handle_graphql_query(document.operation, ctx, force_primary))
         .catch_unwind()
         .await
     {
@@ -206,6 +274,13 @@ async fn handle_compacted(document: CompactedDocument, ctx: &Arc<PrismaContext>)
     }
 }
 
-async fn handle_graphql_query(query_doc: Operation, ctx: &PrismaContext) -> PrismaResult<ResponseData> {
-    Ok(ctx.executor.execute(query_doc, Arc::clone(ctx.query_schema())).await?)
+async fn handle_graphql_query(
+    query_doc: Operation,
+    ctx: &PrismaContext,
+    force_primary: bool,
+) -> PrismaResult<ResponseData> {
+    Ok(ctx
+        .executor
+        .execute(query_doc, Arc::clone(ctx.query_schema()), force_primary)
+        .await?)
 }
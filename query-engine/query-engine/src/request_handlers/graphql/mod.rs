@@ -1,9 +1,11 @@
 mod handler;
+mod persisted;
 mod protocol_adapter;
 mod response;
 mod schema_renderer;
 
 pub use handler::*;
+pub use persisted::*;
 pub use protocol_adapter::*;
 pub use response::*;
 pub use schema_renderer::*;
@@ -0,0 +1,208 @@
+use super::protocol_adapter::{GraphQLProtocolAdapter, OperationTemplate};
+use crate::{PrismaError, PrismaResult};
+use graphql_parser as gql;
+use sha2::{Digest, Sha256};
+use std::{
+    collections::HashMap,
+    fs,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, RwLock,
+    },
+};
+
+/// Maximum number of queries registered by clients. Registering another one evicts the least
+/// recently used of them.
+pub(crate) const MAX_REGISTERED_QUERIES: usize = 10_000;
+
+/// Registry of persisted queries: GraphQL documents known by their SHA-256 hash, so clients can send
+/// the hash instead of the document. A persisted document is parsed and converted once per operation
+/// name, requests only fill in their variables.
+///
+/// Documents are loaded from a registry file on startup, or registered by clients sending a document
+/// along with its hash. If only persisted queries are accepted, registering is disabled and any
+/// document not in the registry is rejected. Documents of the registry file are kept, registered
+/// ones are evicted once there are too many.
+#[derive(Debug, Default)]
+pub struct PersistedQueries {
+    queries: RwLock<HashMap<String, PersistedQuery>>,
+    only_persisted: bool,
+
+    /// Incremented on every use of a query, for finding the least recently used one.
+    clock: AtomicU64,
+}
+
+#[derive(Debug)]
+struct PersistedQuery {
+    document: String,
+
+    /// The converted operations of the document by operation name, added on first use.
+    templates: HashMap<Option<String>, Arc<OperationTemplate>>,
+
+    /// Whether the document was registered by a client, rather than loaded from the registry file.
+    registered: bool,
+
+    /// The clock of the last use.
+    last_used: AtomicU64,
+}
+
+impl PersistedQueries {
+    pub fn new(only_persisted: bool) -> Self {
+        Self {
+            queries: RwLock::new(HashMap::new()),
+            only_persisted,
+            clock: AtomicU64::new(0),
+        }
+    }
+
+    /// Loads the registry file, a JSON object mapping the hash of each document to the document.
+    pub fn load(path: &str, only_persisted: bool) -> PrismaResult<Self> {
+        let file = fs::read_to_string(path).map_err(|err| {
+            PrismaError::ConfigurationError(format!("Could not read persisted queries file {:?}: {}", path, err))
+        })?;
+
+        let documents: HashMap<String, String> = serde_json::from_str(&file).map_err(|err| {
+            PrismaError::ConfigurationError(format!("Invalid persisted queries file {:?}: {}", path, err))
+        })?;
+
+        let mut queries = HashMap::with_capacity(documents.len());
+
+        for (hash, document) in documents {
+            let hash = hash.to_lowercase();
+
+            if query_hash(&document) != hash {
+                return Err(PrismaError::ConfigurationError(format!(
+                    "The document of persisted query `{}` doesn't match its hash.",
+                    hash
+                )));
+            }
+
+            queries.insert(
+                hash,
+                PersistedQuery {
+                    document,
+                    templates: HashMap::new(),
+                    registered: false,
+                    last_used: AtomicU64::new(0),
+                },
+            );
+        }
+
+        Ok(Self {
+            queries: RwLock::new(queries),
+            only_persisted,
+            clock: AtomicU64::new(0),
+        })
+    }
+
+    /// Whether queries that aren't persisted are rejected.
+    pub fn only_persisted(&self) -> bool {
+        self.only_persisted
+    }
+
+    /// The number of persisted queries.
+    pub fn count(&self) -> usize {
+        self.queries.read().expect("Persisted queries lock poisoned").len()
+    }
+
+    /// The operation template of the persisted query with the given hash. If the document is given,
+    /// it is checked against the hash and persisted, unless only persisted queries are accepted.
+    pub(crate) fn template(
+        &self,
+        hash: &str,
+        document: Option<String>,
+        operation_name: Option<String>,
+    ) -> PrismaResult<Arc<OperationTemplate>> {
+        let hash = hash.to_lowercase();
+
+        if let Some(ref document) = document {
+            if query_hash(document) != hash {
+                return Err(PrismaError::QueryConversionError(format!(
+                    "The query hash `{}` doesn't match the SHA-256 hash of the query.",
+                    hash
+                )));
+            }
+        }
+
+        let persisted_document = {
+            let queries = self.queries.read().expect("Persisted queries lock poisoned");
+
+            match queries.get(&hash) {
+                Some(persisted) => {
+                    persisted.last_used.store(self.tick(), Ordering::Relaxed);
+
+                    match persisted.templates.get(&operation_name) {
+                        Some(template) => return Ok(Arc::clone(template)),
+                        None => Some(persisted.document.clone()),
+                    }
+                }
+                None if self.only_persisted => return Err(PrismaError::PersistedQueryRequired(hash)),
+                None => None,
+            }
+        };
+
+        let document = match persisted_document.or(document) {
+            Some(document) => document,
+            None => return Err(PrismaError::PersistedQueryNotFound(hash)),
+        };
+
+        // Converting happens outside of the lock, concurrent requests for the same
+        // document may convert it more than once.
+        let gql_doc = gql::parse_query(&document)?;
+        let template = Arc::new(GraphQLProtocolAdapter::convert(gql_doc, operation_name.clone())?);
+
+        let mut queries = self.queries.write().expect("Persisted queries lock poisoned");
+
+        match queries.get_mut(&hash) {
+            Some(persisted) => {
+                persisted.templates.insert(operation_name, Arc::clone(&template));
+            }
+            None => {
+                if queries.values().filter(|persisted| persisted.registered).count() >= MAX_REGISTERED_QUERIES {
+                    evict_least_recently_used(&mut queries);
+                }
+
+                let mut templates = HashMap::new();
+                templates.insert(operation_name, Arc::clone(&template));
+
+                queries.insert(
+                    hash,
+                    PersistedQuery {
+                        document,
+                        templates,
+                        registered: true,
+                        last_used: AtomicU64::new(self.tick()),
+                    },
+                );
+            }
+        }
+
+        Ok(template)
+    }
+
+    fn tick(&self) -> u64 {
+        self.clock.fetch_add(1, Ordering::Relaxed) + 1
+    }
+}
+
+/// Removes the least recently used query registered by a client.
+fn evict_least_recently_used(queries: &mut HashMap<String, PersistedQuery>) {
+    let least_recently_used = queries
+        .iter()
+        .filter(|(_, persisted)| persisted.registered)
+        .min_by_key(|(_, persisted)| persisted.last_used.load(Ordering::Relaxed))
+        .map(|(hash, _)| hash.clone());
+
+    if let Some(hash) = least_recently_used {
+        debug!("Evicting persisted query `{}`.", hash);
+        queries.remove(&hash);
+    }
+}
+
+/// The hex encoded SHA-256 hash of a GraphQL document, identifying it as persisted query.
+pub fn query_hash(document: &str) -> String {
+    Sha256::digest(document.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
@@ -1,10 +1,14 @@
 use crate::{error::PrismaError, PrismaResult};
 use bigdecimal::{BigDecimal, FromPrimitive};
 use graphql_parser::query::{
-    Definition, Document, OperationDefinition, Selection as GqlSelection, SelectionSet, Value,
+    Definition, Document, OperationDefinition, Selection as GqlSelection, SelectionSet, Type, Value, VariableDefinition,
 };
 use indexmap::IndexMap;
 use query_core::query_document::*;
+use std::collections::HashMap;
+
+/// The variable values of a request, by variable name.
+pub type Variables = HashMap<String, serde_json::Value>;
 
 /// Protocol adapter for GraphQL -> Query Document.
 ///
@@ -14,17 +18,17 @@ use query_core::query_document::*;
 /// - If the JSON payload specifies an operation name, only that specific operation is picked and the rest ignored.
 /// - Fields on the queries are mapped to `Field`s, including arguments.
 /// - Concrete values (e.g. in arguments) are mapped to `QueryValue`s.
+/// - Variables are left out of the converted operation and filled in from the request, see `OperationTemplate`.
 ///
 /// Currently unsupported features:
 /// - Fragments in any form.
-/// - Variables.
 /// - Subscription queries.
 /// - Query names are ignored
 pub struct GraphQLProtocolAdapter;
 
 impl GraphQLProtocolAdapter {
-    pub fn convert(gql_doc: Document<String>, operation: Option<String>) -> PrismaResult<Operation> {
-        let mut operations: Vec<Operation> = match operation {
+    pub fn convert(gql_doc: Document<String>, operation: Option<String>) -> PrismaResult<OperationTemplate> {
+        let mut operations: Vec<OperationTemplate> = match operation {
             Some(ref op) => gql_doc
                 .definitions
                 .into_iter()
@@ -38,19 +42,16 @@ impl GraphQLProtocolAdapter {
                 .definitions
                 .into_iter()
                 .map(Self::convert_definition)
-                .collect::<PrismaResult<Vec<Vec<Operation>>>>()
-                .map(|r| r.into_iter().flatten().collect::<Vec<Operation>>()),
+                .collect::<PrismaResult<Vec<Vec<OperationTemplate>>>>()
+                .map(|r| r.into_iter().flatten().collect::<Vec<OperationTemplate>>()),
         }?;
 
-        let operation = operations
+        operations
             .pop()
-            .ok_or_else(|| PrismaError::QueryConversionError("Document contained no operations.".into()))?
-            .dedup_selections();
-
-        Ok(operation)
+            .ok_or_else(|| PrismaError::QueryConversionError("Document contained no operations.".into()))
     }
 
-    fn convert_definition(def: Definition<String>) -> PrismaResult<Vec<Operation>> {
+    fn convert_definition(def: Definition<String>) -> PrismaResult<Vec<OperationTemplate>> {
         match def {
             Definition::Fragment(f) => Err(PrismaError::UnsupportedFeatureError(
                 "Fragment definition",
@@ -61,42 +62,99 @@ impl GraphQLProtocolAdapter {
                     "Subscription query",
                     format!("At position {}.", s.position),
                 )),
-                OperationDefinition::SelectionSet(s) => Self::convert_query(s),
-                OperationDefinition::Query(q) => Self::convert_query(q.selection_set),
-                OperationDefinition::Mutation(m) => Self::convert_mutation(m.selection_set),
+                OperationDefinition::SelectionSet(s) => Self::convert_operations(s, vec![], Operation::Read),
+                OperationDefinition::Query(q) => {
+                    Self::convert_operations(q.selection_set, q.variable_definitions, Operation::Read)
+                }
+                OperationDefinition::Mutation(m) => {
+                    Self::convert_operations(m.selection_set, m.variable_definitions, Operation::Write)
+                }
             },
         }
     }
 
-    fn convert_query(selection_set: SelectionSet<String>) -> PrismaResult<Vec<Operation>> {
-        Self::convert_selection_set(selection_set).map(|fields| fields.into_iter().map(Operation::Read).collect())
-    }
+    fn convert_operations(
+        selection_set: SelectionSet<String>,
+        variable_definitions: Vec<VariableDefinition<String>>,
+        operation: fn(Selection) -> Operation,
+    ) -> PrismaResult<Vec<OperationTemplate>> {
+        let definitions = variable_definitions
+            .into_iter()
+            .map(|def| {
+                Ok(VariableDef {
+                    required: matches!(def.var_type, Type::NonNullType(_)),
+                    default: def.default_value.map(Self::convert_constant).transpose()?,
+                    name: def.name,
+                })
+            })
+            .collect::<PrismaResult<Vec<_>>>()?;
+
+        Self::convert_selection_set(selection_set)?
+            .into_iter()
+            .map(|(selection, slots)| {
+                if let Some(slot) = slots
+                    .iter()
+                    .find(|slot| !definitions.iter().any(|def| def.name == slot.name))
+                {
+                    return Err(PrismaError::QueryConversionError(format!(
+                        "Variable '${}' is not defined by the operation.",
+                        slot.name
+                    )));
+                }
 
-    fn convert_mutation(selection_set: SelectionSet<String>) -> PrismaResult<Vec<Operation>> {
-        Self::convert_selection_set(selection_set).map(|fields| fields.into_iter().map(Operation::Write).collect())
+                Ok(OperationTemplate {
+                    operation: operation(selection),
+                    definitions: definitions.clone(),
+                    slots,
+                })
+            })
+            .collect()
     }
 
-    fn convert_selection_set(selection_set: SelectionSet<String>) -> PrismaResult<Vec<Selection>> {
+    /// Converts the fields of the selection set, along with the variables used in each field.
+    fn convert_selection_set(selection_set: SelectionSet<String>) -> PrismaResult<Vec<(Selection, Vec<VariableSlot>)>> {
         selection_set
             .items
             .into_iter()
             .map(|item| match item {
                 GqlSelection::Field(f) => {
-                    let arguments: Vec<(String, QueryValue)> = f
-                        .arguments
-                        .into_iter()
-                        .map(|(k, v)| Ok((k, Self::convert_value(v)?)))
-                        .collect::<PrismaResult<Vec<_>>>()?;
+                    let mut slots = Vec::new();
+                    let mut arguments = Vec::with_capacity(f.arguments.len());
+
+                    for (idx, (k, v)) in f.arguments.into_iter().enumerate() {
+                        let mut variables = Vec::new();
+                        arguments.push((k, Self::convert_value(v, &mut vec![], &mut variables)?));
+
+                        slots.extend(variables.into_iter().map(|(name, value)| VariableSlot {
+                            name,
+                            selections: vec![],
+                            argument: idx,
+                            value,
+                        }));
+                    }
+
+                    let mut nested_selections = Vec::new();
+
+                    for (idx, (nested, nested_slots)) in
+                        Self::convert_selection_set(f.selection_set)?.into_iter().enumerate()
+                    {
+                        nested_selections.push(nested);
+
+                        slots.extend(nested_slots.into_iter().map(|mut slot| {
+                            slot.selections.insert(0, idx);
+                            slot
+                        }));
+                    }
 
                     let mut builder = Selection::builder(f.name);
                     builder.set_arguments(arguments);
-                    builder.nested_selections(Self::convert_selection_set(f.selection_set)?);
+                    builder.nested_selections(nested_selections);
 
                     if let Some(alias) = f.alias {
                         builder.alias(alias);
                     };
 
-                    Ok(builder.build())
+                    Ok((builder.build(), slots))
                 }
 
                 GqlSelection::FragmentSpread(fs) => Err(PrismaError::UnsupportedFeatureError(
@@ -126,12 +184,32 @@ impl GraphQLProtocolAdapter {
         }
     }
 
-    fn convert_value(value: Value<String>) -> PrismaResult<QueryValue> {
+    /// Converts a value that can't contain variables, e.g. the default value of a variable.
+    fn convert_constant(value: Value<String>) -> PrismaResult<QueryValue> {
+        let mut variables = Vec::new();
+        let value = Self::convert_value(value, &mut vec![], &mut variables)?;
+
+        match variables.pop() {
+            Some((name, _)) => Err(PrismaError::QueryConversionError(format!(
+                "Variable '${}' can't be used in a constant value.",
+                name
+            ))),
+            None => Ok(value),
+        }
+    }
+
+    /// Converts the value at `path`, the indices into the lists and objects of an argument value.
+    /// Variables are converted to `null` and collected with their path.
+    fn convert_value(
+        value: Value<String>,
+        path: &mut Vec<usize>,
+        variables: &mut Vec<(String, Vec<usize>)>,
+    ) -> PrismaResult<QueryValue> {
         match value {
-            Value::Variable(name) => Err(PrismaError::UnsupportedFeatureError(
-                "Variable usage",
-                format!("Variable '{}'.", name),
-            )),
+            Value::Variable(name) => {
+                variables.push((name, path.clone()));
+                Ok(QueryValue::Null)
+            }
             Value::Int(i) => match i.as_i64() {
                 Some(i) => Ok(QueryValue::Int(i)),
                 None => Err(PrismaError::QueryConversionError(format!(
@@ -153,7 +231,13 @@ impl GraphQLProtocolAdapter {
             Value::List(values) => {
                 let values: Vec<QueryValue> = values
                     .into_iter()
-                    .map(Self::convert_value)
+                    .enumerate()
+                    .map(|(idx, v)| {
+                        path.push(idx);
+                        let value = Self::convert_value(v, path, variables);
+                        path.pop();
+                        value
+                    })
                     .collect::<PrismaResult<Vec<QueryValue>>>()?;
 
                 Ok(QueryValue::List(values))
@@ -161,11 +245,160 @@ impl GraphQLProtocolAdapter {
             Value::Object(map) => {
                 let values = map
                     .into_iter()
-                    .map(|(k, v)| Self::convert_value(v).map(|v| (k, v)))
+                    .enumerate()
+                    .map(|(idx, (k, v))| {
+                        path.push(idx);
+                        let value = Self::convert_value(v, path, variables).map(|v| (k, v));
+                        path.pop();
+                        value
+                    })
                     .collect::<PrismaResult<IndexMap<String, QueryValue>>>()?;
 
                 Ok(QueryValue::Object(values))
             }
         }
     }
+
+    /// Converts a JSON variable value. Strings are passed on as they are, the query parser
+    /// coerces them into enums, dates and the like.
+    fn convert_json_value(value: &serde_json::Value) -> PrismaResult<QueryValue> {
+        match value {
+            serde_json::Value::Null => Ok(QueryValue::Null),
+            serde_json::Value::Bool(b) => Ok(QueryValue::Boolean(*b)),
+            serde_json::Value::Number(n) => match n.as_i64() {
+                Some(i) => Ok(QueryValue::Int(i)),
+                None => n
+                    .to_string()
+                    .parse::<BigDecimal>()
+                    .map(QueryValue::Float)
+                    .map_err(|_| PrismaError::QueryConversionError(format!("Invalid variable number: {}", n))),
+            },
+            serde_json::Value::String(s) => Ok(QueryValue::String(s.clone())),
+            serde_json::Value::Array(values) => Ok(QueryValue::List(
+                values
+                    .iter()
+                    .map(Self::convert_json_value)
+                    .collect::<PrismaResult<Vec<_>>>()?,
+            )),
+            serde_json::Value::Object(map) => Ok(QueryValue::Object(
+                map.iter()
+                    .map(|(k, v)| Ok((k.clone(), Self::convert_json_value(v)?)))
+                    .collect::<PrismaResult<IndexMap<_, _>>>()?,
+            )),
+        }
+    }
+}
+
+/// An operation converted from a GraphQL document, without the values of its variables.
+///
+/// The positions of the variables in the operation are recorded instead, so a document only
+/// has to be parsed and converted once and each request fills its variables into a copy.
+#[derive(Debug, Clone)]
+pub struct OperationTemplate {
+    operation: Operation,
+    definitions: Vec<VariableDef>,
+    slots: Vec<VariableSlot>,
+}
+
+impl OperationTemplate {
+    /// The operation with the given variable values filled in. Arguments and object fields of
+    /// variables without a value or default are left out, list items become `null`.
+    pub fn fill(&self, variables: &Variables) -> PrismaResult<Operation> {
+        let mut values = HashMap::with_capacity(self.definitions.len());
+
+        for def in self.definitions.iter() {
+            let value = match (variables.get(&def.name), &def.default) {
+                (Some(value), _) => Some(GraphQLProtocolAdapter::convert_json_value(value)?),
+                (None, Some(default)) => Some(default.clone()),
+                (None, None) => None,
+            };
+
+            if def.required && matches!(value, None | Some(QueryValue::Null)) {
+                return Err(PrismaError::QueryConversionError(format!(
+                    "Variable '${}' is required, but no value was given.",
+                    def.name
+                )));
+            }
+
+            values.insert(def.name.as_str(), value);
+        }
+
+        let mut operation = self.operation.clone();
+        let mut omitted = Vec::new();
+
+        for slot in self.slots.iter() {
+            match &values[slot.name.as_str()] {
+                Some(value) => {
+                    let argument = &mut slot.selection(&mut operation).arguments_mut()[slot.argument].1;
+                    *value_at(argument, &slot.value) = value.clone();
+                }
+                None => omitted.push(slot),
+            }
+        }
+
+        // Removing a value shifts the indices of the values after it, so the last ones go first.
+        omitted.sort_by(|a, b| (&b.selections, b.argument, &b.value).cmp(&(&a.selections, a.argument, &a.value)));
+
+        for slot in omitted {
+            let selection = slot.selection(&mut operation);
+
+            match slot.value.split_last() {
+                None => {
+                    selection.remove_argument(slot.argument);
+                }
+                Some((idx, path)) => match value_at(&mut selection.arguments_mut()[slot.argument].1, path) {
+                    QueryValue::Object(values) => {
+                        values.shift_remove_index(*idx);
+                    }
+                    QueryValue::List(values) => values[*idx] = QueryValue::Null,
+                    _ => unreachable!("Variable path into a scalar value."),
+                },
+            }
+        }
+
+        Ok(operation.dedup_selections())
+    }
+}
+
+/// The value at the given indices into the lists and objects of `value`.
+fn value_at<'a>(mut value: &'a mut QueryValue, path: &[usize]) -> &'a mut QueryValue {
+    for idx in path {
+        value = match value {
+            QueryValue::List(values) => &mut values[*idx],
+            QueryValue::Object(values) => values.get_index_mut(*idx).unwrap().1,
+            _ => unreachable!("Variable path into a scalar value."),
+        };
+    }
+
+    value
+}
+
+#[derive(Debug, Clone)]
+struct VariableDef {
+    name: String,
+    required: bool,
+    default: Option<QueryValue>,
+}
+
+/// Where a variable is used: the indices of the nested selections leading to the field, the
+/// index of the argument, and the indices into the lists and objects of the argument value.
+#[derive(Debug, Clone)]
+struct VariableSlot {
+    name: String,
+    selections: Vec<usize>,
+    argument: usize,
+    value: Vec<usize>,
+}
+
+impl VariableSlot {
+    /// The selection of the argument the variable is used in.
+    fn selection<'a>(&self, operation: &'a mut Operation) -> &'a mut Selection {
+        let mut selection = operation.selection_mut();
+
+        for idx in self.selections.iter() {
+            selection = &mut selection.nested_selections_mut()[*idx];
+        }
+
+        selection
+    }
 }
@@ -2,36 +2,151 @@
 
 use crate::context::PrismaContext;
 use crate::dmmf;
+use crate::error::PrismaError;
 use crate::opt::PrismaOpt;
-use crate::request_handlers::graphql::{self, GraphQLSchemaRenderer, GraphQlBody};
+use crate::request_handlers::graphql::{self, GQLResponse, GraphQLSchemaRenderer, GraphQlBody, PersistedQueries};
 use crate::PrismaResult;
 use elapsed_middleware::ElapsedMiddleware;
+use shutdown::{Shutdown, ShutdownMiddleware};
 
+use futures::{
+    channel::mpsc,
+    future::{self, Either, FutureExt},
+    SinkExt, StreamExt, TryStreamExt,
+};
 use query_core::schema::QuerySchemaRenderer;
 use serde_json::json;
 use tide::http::{mime, StatusCode};
 use tide::{prelude::*, Body, Request, Response};
 use tide_server_timing::TimingMiddleware;
+use user_facing_errors::common::DatabaseOperationTimeout;
 
-use std::sync::Arc;
+use std::{
+    sync::{Arc, Mutex, RwLock, Weak},
+    time::{Duration, Instant},
+};
 
 mod elapsed_middleware;
+mod shutdown;
+
+/// Time the readiness check waits for the database to answer.
+const READINESS_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Time requests get to finish after their transactions were rolled back at the shutdown deadline.
+const ROLLBACK_GRACE_PERIOD: Duration = Duration::from_secs(5);
+
+/// Header forcing all operations of a request to run against the primary database.
+static FORCE_PRIMARY_HEADER: &str = "x-prisma-force-primary";
+
+/// Size of the chunks a streamed response body is written in.
+const STREAM_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Number of chunks buffered for a streamed response before reading from the database pauses.
+const STREAM_BUFFERED_CHUNKS: usize = 4;
 
 //// Shared application state.
 pub(crate) struct State {
-    cx: Arc<PrismaContext>,
+    /// The current context. Swapped out as a whole when the datamodel is reloaded.
+    cx: Arc<RwLock<Arc<PrismaContext>>>,
+    /// Contexts replaced by a reload, until the last request running on them is done.
+    retired: Arc<Mutex<Vec<Weak<PrismaContext>>>>,
+    opts: Arc<PrismaOpt>,
+    /// Persisted queries, kept when the datamodel is reloaded.
+    persisted_queries: Arc<PersistedQueries>,
+    shutdown: Arc<Shutdown>,
     enable_playground: bool,
     enable_debug_mode: bool,
 }
 
 impl State {
     /// Create a new instance of `State`.
-    fn new(cx: PrismaContext, enable_playground: bool, enable_debug_mode: bool) -> Self {
+    fn new(
+        cx: PrismaContext,
+        opts: PrismaOpt,
+        persisted_queries: Arc<PersistedQueries>,
+        shutdown: Arc<Shutdown>,
+    ) -> Self {
         Self {
-            cx: Arc::new(cx),
-            enable_playground,
-            enable_debug_mode,
+            cx: Arc::new(RwLock::new(Arc::new(cx))),
+            retired: Arc::new(Mutex::new(Vec::new())),
+            persisted_queries,
+            shutdown,
+            enable_playground: opts.enable_playground,
+            enable_debug_mode: opts.enable_debug_mode,
+            opts: Arc::new(opts),
+        }
+    }
+
+    /// The current context. A request keeps the context it started with,
+    /// even if the datamodel is reloaded while it is running.
+    fn cx(&self) -> Arc<PrismaContext> {
+        Arc::clone(&self.cx.read().expect("Context lock poisoned"))
+    }
+
+    /// Builds a new context from the datamodel and swaps it in for all new requests.
+    /// If building the context fails, e.g. because the datamodel is invalid,
+    /// the current context stays in place and the error is returned.
+    /// Fails as well if the datamodel is not read from a file, as it can't have changed then.
+    async fn reload(&self) -> PrismaResult<()> {
+        if !self.opts.datamodel_is_reloadable() {
+            return Err(PrismaError::ConfigurationError(
+                "The datamodel can only be reloaded if it is given as path, with `--datamodel-path` or `PRISMA_DML_PATH`."
+                    .into(),
+            ));
+        }
+
+        let cx = build_context(&self.opts, self.persisted_queries.clone()).await?;
+        let previous = std::mem::replace(&mut *self.cx.write().expect("Context lock poisoned"), Arc::new(cx));
+
+        let mut retired = self.retired.lock().expect("Context lock poisoned");
+        retired.retain(|cx| cx.strong_count() > 0);
+        retired.push(Arc::downgrade(&previous));
+
+        info!("Reloaded the datamodel.");
+        Ok(())
+    }
+
+    /// The current context and all replaced contexts still in use by a request.
+    fn contexts(&self) -> Vec<Arc<PrismaContext>> {
+        let retired = self.retired.lock().expect("Context lock poisoned");
+        let mut contexts: Vec<_> = retired.iter().filter_map(Weak::upgrade).collect();
+        contexts.push(self.cx());
+
+        contexts
+    }
+
+    /// Shuts the server down gracefully. New requests are answered with `503` right away,
+    /// in-flight requests and streams get `deadline` to finish. Transactions still open at the
+    /// deadline are rolled back explicitly, requests still running after that are cut off.
+    /// Finally, the connection pools are closed.
+    pub(crate) async fn shutdown(&self, deadline: Duration) {
+        info!(
+            "Shutting down, waiting for {} in-flight requests to finish.",
+            self.shutdown.in_flight()
+        );
+
+        if !self.shutdown.drain(deadline).await {
+            warn!(
+                "Shutdown deadline of {}s reached, rolling back the transactions of {} in-flight requests.",
+                deadline.as_secs(),
+                self.shutdown.in_flight()
+            );
+
+            for cx in self.contexts() {
+                cx.executor.abort_transactions();
+            }
+
+            if !self.shutdown.drain(ROLLBACK_GRACE_PERIOD).await {
+                warn!("Aborting {} in-flight requests.", self.shutdown.in_flight());
+                self.shutdown.abort().await;
+            }
+        }
+
+        for cx in self.contexts() {
+            cx.executor.close();
         }
+
+        info!("Closed the connection pools.");
     }
 }
 
@@ -39,6 +154,10 @@ impl Clone for State {
     fn clone(&self) -> Self {
         Self {
             cx: self.cx.clone(),
+            retired: self.retired.clone(),
+            opts: self.opts.clone(),
+            persisted_queries: self.persisted_queries.clone(),
+            shutdown: self.shutdown.clone(),
             enable_playground: self.enable_playground,
             enable_debug_mode: self.enable_debug_mode,
         }
@@ -47,40 +166,129 @@ impl Clone for State {
 
 /// Create a new server and listen.
 pub async fn listen(opts: PrismaOpt) -> PrismaResult<()> {
-    let config = opts
-        .configuration(false)?
-        .subject
-        .validate_that_one_datasource_is_provided()?;
+    let unix_path = opts.unix_path().cloned();
+    let address = format!("{}:{}", opts.host.as_str(), opts.port);
+    let shutdown_timeout = Duration::from_secs(opts.shutdown_timeout);
 
-    let datamodel = opts.datamodel(true)?;
-    let cx = PrismaContext::builder(config, datamodel)
-        .legacy(opts.legacy)
-        .enable_raw_queries(opts.enable_raw_queries)
-        .build()
-        .await?;
+    let app = build_server(opts).await?;
+    let state = app.state().clone();
+
+    #[cfg(unix)]
+    reload_on_sighup(state.clone())?;
+
+    // Start the Tide server and log the server details.
+    // NOTE: The `info!` statement is essential for the correct working of the client.
+    let mut listener = match unix_path {
+        Some(path) => app.bind(format!("http+unix://{}", path)).await?,
+        None => app.bind(address).await?,
+    };
+    info!("Started http server on {}", listener);
+
+    // Dropping the pending `accept` future once a shutdown signal arrives stops accepting
+    // new connections, requests on already accepted connections keep being served.
+    match future::select(listener.accept().boxed(), shutdown_signal().boxed()).await {
+        Either::Left((result, _)) => result?,
+        Either::Right((result, _)) => result?,
+    }
+
+    state.shutdown(shutdown_timeout).await;
+    info!("Shutdown complete.");
+
+    Ok(())
+}
+
+/// Builds the server with its state and all routes, without binding it.
+pub(crate) async fn build_server(opts: PrismaOpt) -> PrismaResult<tide::Server<State>> {
+    let persisted_queries = Arc::new(opts.persisted_queries()?);
+    info!("Loaded {} persisted queries.", persisted_queries.count());
+
+    let cx = build_context(&opts, persisted_queries.clone()).await?;
+    let enable_reload_endpoint = opts.enable_reload_endpoint;
 
-    let mut app = tide::with_state(State::new(cx, opts.enable_playground, opts.enable_debug_mode));
+    let shutdown = Arc::new(Shutdown::new());
+    let state = State::new(cx, opts, persisted_queries, shutdown.clone());
+
+    let mut app = tide::with_state(state);
+    app.with(ShutdownMiddleware::new(shutdown));
     app.with(ElapsedMiddleware::new());
 
-    if opts.enable_playground {
+    if app.state().enable_playground {
         app.with(TimingMiddleware::new());
     }
 
     app.at("/").post(graphql_handler);
     app.at("/").get(playground_handler);
+    app.at("/stream").post(stream_handler);
     app.at("/sdl").get(sdl_handler);
     app.at("/dmmf").get(dmmf_handler);
     app.at("/server_info").get(server_info_handler);
-    app.at("/status").get(|_| async move { Ok(json!({"status": "ok"})) });
+    app.at("/status").get(status_handler);
+    app.at("/ready").get(ready_handler);
+
+    if enable_reload_endpoint {
+        app.at("/reload").post(reload_handler);
+    }
+
+    Ok(app)
+}
+
+/// Resolves as soon as the process is asked to terminate.
+async fn shutdown_signal() -> PrismaResult<()> {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        let mut terminate = signal(SignalKind::terminate())?;
+        let mut interrupt = signal(SignalKind::interrupt())?;
+
+        future::select(terminate.recv().boxed(), interrupt.recv().boxed()).await;
+    }
+
+    #[cfg(not(unix))]
+    tokio::signal::ctrl_c().await?;
+
+    Ok(())
+}
+
+/// Builds the request context from the datamodel and configuration in `opts`.
+async fn build_context(opts: &PrismaOpt, persisted_queries: Arc<PersistedQueries>) -> PrismaResult<PrismaContext> {
+    let config = opts
+        .configuration(false)?
+        .subject
+        .validate_that_one_datasource_is_provided()?;
+
+    let datamodel = opts.datamodel(true)?;
+
+    PrismaContext::builder(config, datamodel)
+        .legacy(opts.legacy)
+        .enable_raw_queries(opts.enable_raw_queries)
+        .persisted_queries(persisted_queries)
+        .query_limits(opts.query_limits())
+        .build()
+        .await
+}
+
+/// Reloads the datamodel every time the process receives a SIGHUP.
+#[cfg(unix)]
+fn reload_on_sighup(state: State) -> PrismaResult<()> {
+    use tokio::signal::unix::{signal, SignalKind};
+
+    let mut hangups = signal(SignalKind::hangup())?;
+
+    async_std::task::spawn(async move {
+        while hangups.recv().await.is_some() {
+            info!("Received SIGHUP, reloading the datamodel.");
+
+            if let Err(err) = state.reload().await {
+                let err = user_facing_errors::Error::from(err);
+                error!(
+                    "Reloading the datamodel failed, keeping the current one. {}",
+                    err.message()
+                );
+            }
+        }
+    });
 
-    // Start the Tide server and log the server details.
-    // NOTE: The `info!` statement is essential for the correct working of the client.
-    let mut listener = match opts.unix_path() {
-        Some(path) => app.bind(format!("http+unix://{}", path)).await?,
-        None => app.bind(format!("{}:{}", opts.host.as_str(), opts.port)).await?,
-    };
-    info!("Started http server on {}", listener);
-    listener.accept().await?;
     Ok(())
 }
 
@@ -94,14 +302,98 @@ async fn graphql_handler(mut req: Request<State>) -> tide::Result {
         }
     }
 
+    // Read-your-writes: clients can opt out of read replica routing for individual requests.
+    let force_primary = req.header(FORCE_PRIMARY_HEADER).is_some();
+
     let body: GraphQlBody = req.body_json().await?;
-    let cx = req.state().cx.clone();
-    let result = graphql::handle(body, cx).await;
+    let cx = req.state().cx();
+    let result = graphql::handle(body, cx, force_primary).await;
     let mut res = Response::new(StatusCode::Ok);
     res.set_body(Body::from_json(&result)?);
     Ok(res)
 }
 
+/// Streams the records of a single `findMany` query as newline-delimited JSON, one record per line.
+/// Records are read from the database in batches while the body is written, so memory use is bounded
+/// regardless of the number of records. An error while streaming, or the stream being cut off on shutdown,
+/// ends the body with a line holding the error.
+async fn stream_handler(mut req: Request<State>) -> tide::Result {
+    let force_primary = req.header(FORCE_PRIMARY_HEADER).is_some();
+    let body: GraphQlBody = req.body_json().await?;
+    let cx = req.state().cx();
+
+    let mut records = match graphql::handle_stream(body, cx, force_primary).await {
+        Ok(records) => records,
+        Err(err) => {
+            let mut res = Response::new(StatusCode::Ok);
+            res.set_body(Body::from_json(&GQLResponse::from(err))?);
+            return Ok(res);
+        }
+    };
+
+    let (mut sender, receiver) = mpsc::channel::<std::io::Result<Vec<u8>>>(STREAM_BUFFERED_CHUNKS);
+
+    // Every sender has a slot of its own in the channel, so the error line of an aborted stream
+    // gets through even if the client doesn't keep up with the body.
+    let mut abort_sender = sender.clone();
+
+    // The body outlives the handler, so the stream counts as in flight on its own.
+    let in_flight = req.state().shutdown.track();
+    let aborted = req.state().shutdown.aborted();
+
+    let write_chunks = async move {
+        let mut chunk = Vec::with_capacity(STREAM_CHUNK_SIZE);
+
+        while let Some(record) = records.next().await {
+            let failed = match record {
+                Ok(item) => {
+                    serde_json::to_writer(&mut chunk, &item).expect("Serializing a record to JSON");
+                    false
+                }
+                Err(err) => {
+                    serde_json::to_writer(&mut chunk, &GQLResponse::from(err)).expect("Serializing an error to JSON");
+                    true
+                }
+            };
+
+            chunk.push(b'\n');
+
+            if failed || chunk.len() >= STREAM_CHUNK_SIZE {
+                let full = std::mem::replace(&mut chunk, Vec::with_capacity(STREAM_CHUNK_SIZE));
+
+                // Stop reading from the database once the client is gone.
+                if sender.send(Ok(full)).await.is_err() || failed {
+                    return;
+                }
+            }
+        }
+
+        if !chunk.is_empty() {
+            let _ = sender.send(Ok(chunk)).await;
+        }
+    };
+
+    async_std::task::spawn(async move {
+        // Chunks end with a complete line, so the error line follows the last record that was sent.
+        if let Either::Right(_) = future::select(write_chunks.boxed(), aborted).await {
+            let err = PrismaError::InvocationError(
+                "The stream was cut off because the query engine is shutting down.".into(),
+            );
+            let mut line = serde_json::to_vec(&GQLResponse::from(err)).expect("Serializing an error to JSON");
+            line.push(b'\n');
+
+            let _ = abort_sender.try_send(Ok(line));
+        }
+
+        drop(in_flight);
+    });
+
+    let mut res = Response::new(StatusCode::Ok);
+    res.set_body(Body::from_reader(receiver.into_async_read(), None));
+    res.set_content_type("application/x-ndjson");
+    Ok(res)
+}
+
 /// Expose the GraphQL playground if enabled.
 ///
 /// # Security
@@ -122,28 +414,94 @@ async fn playground_handler(req: Request<State>) -> tide::Result {
 /// Handler for the playground to work with the SDL-rendered query schema.
 /// Serves a raw SDL string created from the query schema.
 async fn sdl_handler(req: Request<State>) -> tide::Result<impl Into<Response>> {
-    let schema = Arc::clone(&req.state().cx.query_schema());
+    let schema = Arc::clone(&req.state().cx().query_schema());
     Ok(GraphQLSchemaRenderer::render(schema))
 }
 
 /// Renders the Data Model Meta Format.
 /// Only callable if prisma was initialized using a v2 data model.
 async fn dmmf_handler(req: Request<State>) -> tide::Result {
-    let result = dmmf::render_dmmf(req.state().cx.datamodel(), Arc::clone(req.state().cx.query_schema()));
+    let cx = req.state().cx();
+    let result = dmmf::render_dmmf(cx.datamodel(), Arc::clone(cx.query_schema()));
     let mut res = Response::new(StatusCode::Ok);
     res.set_body(Body::from_json(&result)?);
     Ok(res)
 }
 
+/// Reports that the server accepts traffic. While draining, the `ShutdownMiddleware` answers
+/// with `503` instead, so load balancers stop routing requests here.
+async fn status_handler(_: Request<State>) -> tide::Result {
+    Ok(json!({"status": "ok"}).into())
+}
+
+/// Readiness endpoint, checking that the primary database answers queries.
+/// Answers with `503` and the user-facing error if it doesn't, or doesn't do so in time.
+/// While draining, the `ShutdownMiddleware` answers with `503` before the database is asked.
+async fn ready_handler(req: Request<State>) -> tide::Result {
+    let cx = req.state().cx();
+    let start = Instant::now();
+    let result = async_std::future::timeout(READINESS_TIMEOUT, cx.health_check()).await;
+    let latency = start.elapsed();
+
+    let error = match result {
+        Ok(Ok(version)) => {
+            return Ok(json!({
+                "status": "ready",
+                "connector": cx.primary_connector(),
+                "version": version,
+                "latency_ms": latency.as_millis() as u64,
+            })
+            .into())
+        }
+        Ok(Err(err)) => user_facing_errors::Error::from(err),
+        Err(_) => user_facing_errors::KnownError::new(DatabaseOperationTimeout {
+            time: format!("{}s", READINESS_TIMEOUT.as_secs()),
+        })
+        .into(),
+    };
+
+    let mut res = Response::new(StatusCode::ServiceUnavailable);
+    res.set_body(json!({
+        "status": "unavailable",
+        "connector": cx.primary_connector(),
+        "latency_ms": latency.as_millis() as u64,
+        "error": error,
+    }));
+
+    Ok(res)
+}
+
 /// Simple status endpoint
 async fn server_info_handler(req: Request<State>) -> tide::Result<impl Into<Response>> {
+    let cx = req.state().cx();
+
     Ok(json!({
         "commit": env!("GIT_HASH"),
         "version": env!("CARGO_PKG_VERSION"),
-        "primary_connector": req.state().cx.primary_connector(),
+        "primary_connector": cx.primary_connector(),
+        "pool": cx.pool_state(),
     }))
 }
 
+/// Reloads the datamodel without restarting the server.
+/// Responds with the validation errors if the new datamodel can't be loaded.
+async fn reload_handler(req: Request<State>) -> tide::Result {
+    match req.state().reload().await {
+        Ok(()) => Ok(json!({"status": "reloaded"}).into()),
+        Err(err) => {
+            let err = user_facing_errors::Error::from(err);
+            error!(
+                "Reloading the datamodel failed, keeping the current one. {}",
+                err.message()
+            );
+
+            let mut res = Response::new(StatusCode::UnprocessableEntity);
+            res.set_body(Body::from_json(&err)?);
+            Ok(res)
+        }
+    }
+}
+
 /// Handle debug headers inside the main GraphQL endpoint.
 async fn handle_debug_headers(req: &Request<State>) -> tide::Result<Option<impl Into<Response>>> {
     /// Debug header that triggers a panic in the request thread.
@@ -0,0 +1,127 @@
+use futures::{
+    channel::oneshot,
+    future::{self, Either, FutureExt, Shared},
+};
+use serde_json::json;
+use tide::{http::StatusCode, Middleware, Next, Request, Response};
+
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+/// How often the number of in-flight requests is checked while draining.
+const DRAIN_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Keeps track of in-flight requests and coordinates the graceful shutdown of the server.
+pub(crate) struct Shutdown {
+    draining: AtomicBool,
+    in_flight: AtomicUsize,
+    abort_tx: Mutex<Option<oneshot::Sender<()>>>,
+    abort_rx: Shared<oneshot::Receiver<()>>,
+}
+
+impl Shutdown {
+    /// Creates a new `Shutdown`.
+    pub fn new() -> Self {
+        let (abort_tx, abort_rx) = oneshot::channel();
+
+        Self {
+            draining: AtomicBool::new(false),
+            in_flight: AtomicUsize::new(0),
+            abort_tx: Mutex::new(Some(abort_tx)),
+            abort_rx: abort_rx.shared(),
+        }
+    }
+
+    /// True once a shutdown has been initiated.
+    pub fn is_draining(&self) -> bool {
+        self.draining.load(Ordering::SeqCst)
+    }
+
+    /// Number of requests currently being handled.
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.load(Ordering::SeqCst)
+    }
+
+    /// Stops handing new requests to the handlers and waits for in-flight requests to finish,
+    /// for at most `deadline`. Returns whether all of them finished in time.
+    pub async fn drain(&self, deadline: Duration) -> bool {
+        self.draining.store(true, Ordering::SeqCst);
+
+        let start = Instant::now();
+
+        while self.in_flight() > 0 && start.elapsed() < deadline {
+            async_std::task::sleep(DRAIN_POLL_INTERVAL).await;
+        }
+
+        self.in_flight() == 0
+    }
+
+    /// Cuts off all in-flight requests and streams, and waits until they are gone.
+    pub async fn abort(&self) {
+        if let Some(abort_tx) = self.abort_tx.lock().expect("Shutdown lock poisoned").take() {
+            let _ = abort_tx.send(());
+        }
+
+        while self.in_flight() > 0 {
+            async_std::task::sleep(DRAIN_POLL_INTERVAL).await;
+        }
+    }
+
+    /// Resolves once in-flight requests are cut off.
+    pub fn aborted(&self) -> Shared<oneshot::Receiver<()>> {
+        self.abort_rx.clone()
+    }
+
+    /// Counts work as in flight until the returned guard is dropped, so draining waits for it.
+    pub fn track(self: &Arc<Self>) -> InFlight {
+        self.in_flight.fetch_add(1, Ordering::SeqCst);
+        InFlight(Arc::clone(self))
+    }
+}
+
+/// Counts a request as in flight for as long as the guard is alive.
+pub(crate) struct InFlight(Arc<Shutdown>);
+
+impl Drop for InFlight {
+    fn drop(&mut self) {
+        self.0.in_flight.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Middleware counting in-flight requests, aborting them if the shutdown deadline is reached.
+/// Requests arriving while the server drains are answered with `503` right away.
+#[derive(Clone)]
+pub(crate) struct ShutdownMiddleware {
+    shutdown: Arc<Shutdown>,
+}
+
+impl ShutdownMiddleware {
+    /// Creates a new `ShutdownMiddleware`.
+    pub fn new(shutdown: Arc<Shutdown>) -> Self {
+        Self { shutdown }
+    }
+}
+
+#[tide::utils::async_trait]
+impl<State: Clone + Send + Sync + 'static> Middleware<State> for ShutdownMiddleware {
+    async fn handle(&self, req: Request<State>, next: Next<'_, State>) -> tide::Result {
+        if self.shutdown.is_draining() {
+            let mut res = Response::new(StatusCode::ServiceUnavailable);
+            res.set_body(json!({"status": "draining"}));
+            return Ok(res);
+        }
+
+        let _in_flight = self.shutdown.track();
+        let abort = self.shutdown.aborted();
+
+        match future::select(next.run(req).boxed(), abort).await {
+            Either::Left((res, _)) => Ok(res),
+            Either::Right(_) => Ok(Response::new(StatusCode::ServiceUnavailable)),
+        }
+    }
+}
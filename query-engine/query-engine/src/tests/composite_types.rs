@@ -0,0 +1,296 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static USER: &str = indoc! {"
+    model User {
+        id        Int      @id
+        address   Address?
+        previous  Address[]
+    }
+
+    type Address {
+        street   String
+        zip      String?
+        location Location?
+    }
+
+    type Location {
+        lat Float
+        lng Float
+    }
+"};
+
+async fn create_users(query_engine: &QueryEngine) {
+    let mutations = vec![
+        r#"mutation { createOneUser(data: { id: 1, address: { set: { street: "Main St", zip: "10115", location: { lat: 52.5, lng: 13.4 } } } }) { id } }"#,
+        r#"mutation { createOneUser(data: { id: 2, address: { set: { street: "Side St" } }, previous: { set: [{ street: "Main St" }] } }) { id } }"#,
+        r#"mutation { createOneUser(data: { id: 3 }) { id } }"#,
+    ];
+
+    for mutation in mutations {
+        query_engine.request(mutation).await;
+    }
+}
+
+fn user_ids(result: &serde_json::Value) -> Vec<i64> {
+    result["data"]["findManyUser"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|user| user["id"].as_i64().unwrap())
+        .collect()
+}
+
+#[test_each_connector(tags("postgres", "mysql"))]
+async fn reads_selected_composite_fields(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&USER).await?;
+    create_users(&query_engine).await;
+
+    let query = indoc! {r#"
+        query {
+            findManyUser(orderBy: { id: asc }) {
+                id
+                address { street location { lat } }
+                previous { street zip }
+            }
+        }
+    "#};
+
+    assert_eq!(
+        json!({
+            "data": {
+                "findManyUser": [
+                    { "id": 1, "address": { "street": "Main St", "location": { "lat": 52.5 } }, "previous": [] },
+                    { "id": 2, "address": { "street": "Side St", "location": null }, "previous": [{ "street": "Main St", "zip": null }] },
+                    { "id": 3, "address": null, "previous": [] }
+                ]
+            }
+        }),
+        query_engine.request(query).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mysql"))]
+async fn filters_by_composite_fields(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&USER).await?;
+    create_users(&query_engine).await;
+
+    let query = indoc! {r#"
+        query {
+            findManyUser(where: { address: { is: { street: "Main St" } } }, orderBy: { id: asc }) { id }
+        }
+    "#};
+
+    assert_eq!(vec![1], user_ids(&query_engine.request(query).await));
+
+    let query = indoc! {r#"
+        query {
+            findManyUser(where: { address: { isNot: { street: "Main St" } } }, orderBy: { id: asc }) { id }
+        }
+    "#};
+
+    assert_eq!(vec![2, 3], user_ids(&query_engine.request(query).await));
+
+    let query = indoc! {r#"
+        query {
+            findManyUser(where: { previous: { some: { street: "Main St" } } }, orderBy: { id: asc }) { id }
+        }
+    "#};
+
+    assert_eq!(vec![2], user_ids(&query_engine.request(query).await));
+
+    let query = indoc! {r#"
+        query {
+            findManyUser(where: { previous: { isEmpty: true } }, orderBy: { id: asc }) { id }
+        }
+    "#};
+
+    assert_eq!(vec![1, 3], user_ids(&query_engine.request(query).await));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mysql"))]
+async fn updates_composite_fields(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&USER).await?;
+    create_users(&query_engine).await;
+
+    let mutation = indoc! {r#"
+        mutation {
+            updateOneUser(
+                where: { id: 1 }
+                data: {
+                    address: { update: { zip: "10117", location: { update: { lng: 13.3 } } } }
+                    previous: { push: { street: "Old St" } }
+                }
+            ) {
+                address { street zip location { lat lng } }
+                previous { street }
+            }
+        }
+    "#};
+
+    assert_eq!(
+        json!({
+            "data": {
+                "updateOneUser": {
+                    "address": { "street": "Main St", "zip": "10117", "location": { "lat": 52.5, "lng": 13.3 } },
+                    "previous": [{ "street": "Old St" }]
+                }
+            }
+        }),
+        query_engine.request(mutation).await
+    );
+
+    let mutation = indoc! {r#"
+        mutation {
+            updateOneUser(where: { id: 2 }, data: { address: { set: null } }) { address { street } }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "updateOneUser": { "address": null } } }),
+        query_engine.request(mutation).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mysql"))]
+async fn updates_within_null_composites_are_rejected(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&USER).await?;
+    create_users(&query_engine).await;
+
+    let mutations = vec![
+        r#"mutation { updateOneUser(where: { id: 3 }, data: { address: { update: { zip: "10117" } } }) { id } }"#,
+        r#"mutation { updateOneUser(where: { id: 2 }, data: { address: { update: { location: { update: { lng: 13.3 } } } } }) { id } }"#,
+        r#"mutation { updateManyUser(data: { address: { update: { zip: "10117" } } }) { count } }"#,
+    ];
+
+    for mutation in mutations {
+        let response = query_engine.request(mutation).await;
+
+        assert_eq!(
+            json!("P2019"),
+            response["errors"][0]["user_facing_error"]["error_code"],
+            "{}",
+            response
+        );
+    }
+
+    let query = indoc! {r#"
+        query {
+            findManyUser(orderBy: { id: asc }) { id address { zip location { lng } } }
+        }
+    "#};
+
+    assert_eq!(
+        json!({
+            "data": {
+                "findManyUser": [
+                    { "id": 1, "address": { "zip": "10115", "location": { "lng": 13.4 } } },
+                    { "id": 2, "address": { "zip": null, "location": null } },
+                    { "id": 3, "address": null }
+                ]
+            }
+        }),
+        query_engine.request(query).await
+    );
+
+    let mutation = indoc! {r#"
+        mutation {
+            updateOneUser(where: { id: 3 }, data: { address: { set: { street: "New St" } } }) { address { street } }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "updateOneUser": { "address": { "street": "New St" } } } }),
+        query_engine.request(mutation).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mysql"))]
+async fn composite_updates_can_be_combined_with_other_updates(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+
+    let datamodel = indoc! {"
+        model User {
+            id       Int       @id
+            name     String
+            address  Address?
+            previous Address[]
+        }
+
+        type Address {
+            street String
+            zip    String?
+        }
+    "};
+
+    let query_engine = api.create_engine(datamodel).await?;
+
+    for (id, name) in &[(1, "a"), (2, "a"), (3, "b")] {
+        let mutation = format!(
+            r#"mutation {{ createOneUser(data: {{ id: {}, name: "{}", address: {{ set: {{ street: "Main St" }} }} }}) {{ id }} }}"#,
+            id, name
+        );
+
+        query_engine.request(mutation).await;
+    }
+
+    // The values of the composite updates are parameters between the other assignments and
+    // the conditions.
+    let mutation = indoc! {r#"
+        mutation {
+            updateManyUser(
+                where: { name: "a", id: { gt: 1 } }
+                data: {
+                    name: "c"
+                    address: { update: { zip: "10117" } }
+                    previous: { push: [{ street: "Old St" }, { street: "Older St" }] }
+                }
+            ) { count }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "updateManyUser": { "count": 1 } } }),
+        query_engine.request(mutation).await
+    );
+
+    let query = indoc! {r#"
+        query {
+            findManyUser(orderBy: { id: asc }) { id name address { street zip } previous { street } }
+        }
+    "#};
+
+    assert_eq!(
+        json!({
+            "data": {
+                "findManyUser": [
+                    { "id": 1, "name": "a", "address": { "street": "Main St", "zip": null }, "previous": [] },
+                    {
+                        "id": 2,
+                        "name": "c",
+                        "address": { "street": "Main St", "zip": "10117" },
+                        "previous": [{ "street": "Old St" }, { "street": "Older St" }]
+                    },
+                    { "id": 3, "name": "b", "address": { "street": "Main St", "zip": null }, "previous": [] }
+                ]
+            }
+        }),
+        query_engine.request(query).await
+    );
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static SCHEMA: &str = indoc! {r#"
+    model OrderItem {
+        id       Int    @id
+        price    Int
+        quantity Int
+        total    Int    @computed("price * quantity")
+        label    String @computed("CONCAT('#', id)", stored: false)
+    }
+"#};
+
+#[test_each_connector(tags("mysql_8"))]
+async fn computed_fields_can_be_read(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&SCHEMA).await?;
+
+    let mutation = r#"mutation { createOneOrderItem(data: { id: 1, price: 3, quantity: 4 }) { total label } }"#;
+
+    assert_eq!(
+        json!({ "data": { "createOneOrderItem": { "total": 12, "label": "#1" } } }),
+        query_engine.request(mutation).await
+    );
+
+    let mutation = r#"mutation { updateOneOrderItem(where: { id: 1 }, data: { quantity: 5 }) { total } }"#;
+
+    assert_eq!(
+        json!({ "data": { "updateOneOrderItem": { "total": 15 } } }),
+        query_engine.request(mutation).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql_8"))]
+async fn computed_fields_can_not_be_written(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&SCHEMA).await?;
+
+    let mutations = vec![
+        r#"mutation { createOneOrderItem(data: { id: 1, price: 3, quantity: 4, total: 12 }) { id } }"#,
+        r#"mutation { updateManyOrderItem(data: { total: 12 }) { count } }"#,
+        r#"mutation { updateManyOrderItem(data: { label: { set: "#2" } }) { count } }"#,
+    ];
+
+    for mutation in mutations {
+        let response = query_engine.request(mutation).await;
+        assert!(response["errors"].is_array(), "Expected an error for `{}`", mutation);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,107 @@
+use super::test_api::*;
+use indoc::{formatdoc, indoc};
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static ITEM: &str = indoc! {"
+    model Item {
+        id   Int    @id
+        name String
+    }
+"};
+
+async fn create_items(query_engine: &QueryEngine, count: usize) {
+    for id in 1..=count {
+        let mutation = format!(
+            r#"mutation {{ createOneItem(data: {{ id: {}, name: "item{}" }}) {{ id }} }}"#,
+            id, id
+        );
+
+        query_engine.request(mutation).await;
+    }
+}
+
+fn node_ids(connection: &serde_json::Value) -> Vec<i64> {
+    connection["edges"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|edge| edge["node"]["id"].as_i64().unwrap())
+        .collect()
+}
+
+#[test_each_connector]
+async fn pages_forwards_with_first_and_after(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&ITEM).await?;
+    create_items(&query_engine, 5).await;
+
+    let query = indoc! {r#"
+        query {
+            findManyItemConnection(first: 2) {
+                edges { cursor node { id } }
+                pageInfo { hasNextPage hasPreviousPage endCursor }
+                totalCount
+            }
+        }
+    "#};
+
+    let res = query_engine.request(query).await;
+    let connection = &res["data"]["findManyItemConnection"];
+
+    assert_eq!(vec![1, 2], node_ids(connection));
+    assert_eq!(json!(true), connection["pageInfo"]["hasNextPage"]);
+    assert_eq!(json!(false), connection["pageInfo"]["hasPreviousPage"]);
+    assert_eq!(json!(5), connection["totalCount"]);
+    assert_eq!(connection["edges"][1]["cursor"], connection["pageInfo"]["endCursor"]);
+
+    let query = formatdoc! {r#"
+        query {{
+            findManyItemConnection(first: 2, after: "{}") {{
+                edges {{ node {{ id }} }}
+                pageInfo {{ hasNextPage hasPreviousPage }}
+            }}
+        }}
+    "#, connection["pageInfo"]["endCursor"].as_str().unwrap()};
+
+    let res = query_engine.request(query).await;
+    let connection = &res["data"]["findManyItemConnection"];
+
+    assert_eq!(vec![3, 4], node_ids(connection));
+    assert_eq!(json!(true), connection["pageInfo"]["hasNextPage"]);
+    assert_eq!(json!(true), connection["pageInfo"]["hasPreviousPage"]);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn pages_backwards_with_last(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&ITEM).await?;
+    create_items(&query_engine, 5).await;
+
+    let query = indoc! {r#"
+        query {
+            findManyItemConnection(last: 2, where: { id: { lt: 5 } }) {
+                nodes { id name }
+                pageInfo { hasNextPage hasPreviousPage }
+                totalCount
+            }
+        }
+    "#};
+
+    assert_eq!(
+        json!({
+            "data": {
+                "findManyItemConnection": {
+                    "nodes": [{ "id": 3, "name": "item3" }, { "id": 4, "name": "item4" }],
+                    "pageInfo": { "hasNextPage": false, "hasPreviousPage": true },
+                    "totalCount": 4
+                }
+            }
+        }),
+        query_engine.request(query).await
+    );
+
+    Ok(())
+}
@@ -0,0 +1,106 @@
+use super::test_api::*;
+use crate::{error::PrismaError, opt::PrismaOpt};
+use indoc::indoc;
+use serde_json::json;
+use std::time::Duration;
+use structopt::StructOpt;
+use test_macros::test_each_connector;
+
+static ITEM: &str = indoc! {"
+    model Item {
+        id   Int    @id
+        name String
+    }
+"};
+
+#[test_each_connector(tags("postgres"))]
+async fn waiting_too_long_for_a_connection_fails_with_a_pool_timeout(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api
+        .create_engine_with_properties(&["connectionLimit = 1", "poolTimeout = 1"], ITEM)
+        .await?;
+
+    // Holds the only connection for longer than the pool timeout.
+    let sleep = r#"mutation { queryRaw(query: "SELECT 1 AS slept FROM pg_sleep(3)") }"#;
+
+    let find_items = async {
+        async_std::task::sleep(Duration::from_millis(500)).await;
+        query_engine.request("query { findManyItem { id } }").await
+    };
+
+    let (_, response) = futures::join!(query_engine.request(sleep), find_items);
+
+    assert_eq!(json!("P2023"), response["errors"][0]["user_facing_error"]["error_code"]);
+
+    let state = query_engine.pool_state().unwrap();
+    assert_eq!(1, state.timeouts);
+    assert_eq!(0, state.in_use);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn idle_connections_are_not_counted_as_checkouts(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api
+        .create_engine_with_properties(&["connectionLimit = 4", "minIdleConnections = 2"], ITEM)
+        .await?;
+
+    let state = query_engine.pool_state().unwrap();
+    assert_eq!(0, state.checkouts);
+    assert_eq!(0, state.in_use);
+
+    query_engine.request("query { findManyItem { id } }").await;
+
+    assert_eq!(1, query_engine.pool_state().unwrap().checkouts);
+
+    Ok(())
+}
+
+#[test]
+fn pool_settings_from_the_command_line_are_validated_with_the_datasource() {
+    let datamodel = indoc! {r#"
+        datasource db {
+            provider        = "postgresql"
+            url             = "postgresql://localhost:5432/db"
+            connectionLimit = 4
+        }
+    "#};
+
+    let encoded_datamodel = base64::encode(datamodel);
+
+    let opt = PrismaOpt::from_iter(&[
+        "query-engine",
+        "--datamodel",
+        &encoded_datamodel,
+        "--min-idle-connections",
+        "2",
+    ]);
+    let pool = &opt.configuration(false).unwrap().subject.datasources[0].pool;
+    assert_eq!(Some(4), pool.connection_limit);
+    assert_eq!(Some(2), pool.min_idle_connections);
+
+    let opt = PrismaOpt::from_iter(&[
+        "query-engine",
+        "--datamodel",
+        &encoded_datamodel,
+        "--min-idle-connections",
+        "5",
+    ]);
+    assert!(matches!(
+        opt.configuration(false),
+        Err(PrismaError::ConfigurationError(_))
+    ));
+
+    let opt = PrismaOpt::from_iter(&[
+        "query-engine",
+        "--datamodel",
+        &encoded_datamodel,
+        "--connection-limit",
+        "1",
+        "--min-idle-connections",
+        "2",
+    ]);
+    assert!(matches!(
+        opt.configuration(false),
+        Err(PrismaError::ConfigurationError(_))
+    ));
+}
@@ -82,8 +82,22 @@ fn test_dmmf_cli_command(schema: &str) -> PrismaResult<()> {
         datamodel_path: None,
         enable_debug_mode: false,
         enable_raw_queries: false,
+        persisted_queries_path: None,
+        only_persisted_queries: false,
+        max_selection_depth: None,
+        max_nested_write_depth: None,
+        max_batch_size: None,
+        max_take: None,
+        max_query_cost: None,
         enable_playground: false,
+        enable_reload_endpoint: false,
+        shutdown_timeout: 30,
         legacy: false,
+        connection_limit: None,
+        min_idle_connections: None,
+        pool_timeout: None,
+        max_connection_lifetime: None,
+        idle_timeout: None,
         log_format: None,
         overwrite_datasources: None,
         port: 123,
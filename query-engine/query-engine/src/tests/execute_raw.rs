@@ -379,3 +379,164 @@ async fn parameter_escaping(api: &TestApi) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn typed_query_raw_returns_column_types(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let query = indoc! {r#"
+        mutation {
+            queryRaw(
+                query: "SELECT 9007199254740993::bigint AS big, 1.5::decimal AS dec, 'foo'::text AS str, NULL::timestamp AS dt",
+                typed: true
+            )
+        }
+    "#};
+
+    assert_eq!(
+        json!({
+            "data": {
+                "queryRaw": {
+                    "columns": ["big", "dec", "str", "dt"],
+                    "types": ["bigint", "decimal", "string", "datetime"],
+                    "rows": [["9007199254740993", "1.5", "foo", null]]
+                }
+            }
+        }),
+        query_engine.request(query).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn query_raw_mapped_onto_a_model(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let dt = DateTime::parse_from_rfc3339("1996-12-19T16:39:57+00:00")?;
+    let dt: DateTime<Utc> = dt.into();
+
+    let insert = Insert::single_into("Todo")
+        .value("id", "id1")
+        .value("title", "title1")
+        .value("dt", dt);
+
+    let (query, params) = api.to_sql_string(insert)?;
+    query_engine.request(execute_raw(&query, params)).await;
+
+    let (query, _) = api.to_sql_string(Select::from_table("Todo").value(asterisk()))?;
+
+    let mutation = format!(
+        r#"mutation {{ queryRawTodo(query: "{}") {{ id title dt }} }}"#,
+        query.replace("\"", "\\\"")
+    );
+
+    assert_eq!(
+        json!({
+            "data": {
+                "queryRawTodo": [
+                    {"id": "id1", "title": "title1", "dt": "1996-12-19T16:39:57+00:00"}
+                ]
+            }
+        }),
+        query_engine.request(mutation).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn query_raw_mapped_onto_a_model_requires_selected_columns(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let mutation = indoc! {r#"
+        mutation {
+            createOneTodo(data: { title: "title1" }) { id }
+        }
+    "#};
+
+    query_engine.request(mutation).await;
+
+    let (query, _) = api.to_sql_string(Select::from_table("Todo").column("title"))?;
+    let query = query.replace("\"", "\\\"");
+
+    // The primary key doesn't need a column if it isn't selected.
+    assert_eq!(
+        json!({ "data": { "queryRawTodo": [{ "title": "title1" }] } }),
+        query_engine
+            .request(format!(
+                r#"mutation {{ queryRawTodo(query: "{}") {{ title }} }}"#,
+                query
+            ))
+            .await
+    );
+
+    let result = query_engine
+        .request(format!(
+            r#"mutation {{ queryRawTodo(query: "{}") {{ id title }} }}"#,
+            query
+        ))
+        .await;
+    let message = result["errors"][0]["error"].as_str().unwrap_or_default();
+
+    assert!(message.contains("is missing the column `id`"), "{}", result);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn typed_query_raw_types_columns_by_their_first_non_null_value(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let query = indoc! {r#"
+        mutation {
+            queryRaw(query: "SELECT NULL AS str UNION ALL SELECT 'foo'", typed: true)
+        }
+    "#};
+
+    assert_eq!(
+        json!({
+            "data": {
+                "queryRaw": {
+                    "columns": ["str"],
+                    "types": ["string"],
+                    "rows": [[null], ["foo"]]
+                }
+            }
+        }),
+        query_engine.request(query).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn typed_query_raw_returns_unknown_types_for_empty_results(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let query = indoc! {r#"
+        mutation {
+            queryRaw(query: "SELECT 1 AS one WHERE 1 = 0", typed: true)
+        }
+    "#};
+
+    assert_eq!(
+        json!({
+            "data": {
+                "queryRaw": {
+                    "columns": ["one"],
+                    "types": ["unknown"],
+                    "rows": []
+                }
+            }
+        }),
+        query_engine.request(query).await
+    );
+
+    Ok(())
+}
@@ -0,0 +1,125 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static USER: &str = indoc! {r#"
+    model User {
+        id   Int    @id
+        name String @length(min: 2, max: 8) @regex("^[A-Z]")
+        age  Int?   @range(min: 0, max: 150)
+    }
+"#};
+
+fn assert_rejected(result: &serde_json::Value, expected: &str) {
+    let error = &result["errors"][0]["user_facing_error"];
+
+    assert_eq!(json!("P2009"), error["error_code"], "{}", result);
+    assert!(result.to_string().contains(expected), "{}", result);
+}
+
+#[test_each_connector]
+async fn length_is_enforced(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(USER).await?;
+
+    let result = query_engine
+        .request(r#"mutation { createOneUser(data: { id: 1, name: "A" }) { id } }"#)
+        .await;
+    assert_rejected(&result, "Expected a length between 2 and 8");
+
+    let result = query_engine
+        .request(r#"mutation { createOneUser(data: { id: 1, name: "Abcdefghi" }) { id } }"#)
+        .await;
+    assert_rejected(&result, "Expected a length between 2 and 8");
+
+    assert_eq!(
+        json!({ "data": { "createOneUser": { "id": 1 } } }),
+        query_engine
+            .request(r#"mutation { createOneUser(data: { id: 1, name: "Abcdefgh" }) { id } }"#)
+            .await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn range_is_enforced(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(USER).await?;
+
+    let result = query_engine
+        .request(r#"mutation { createOneUser(data: { id: 1, name: "Ada", age: -1 }) { id } }"#)
+        .await;
+    assert_rejected(&result, "Expected a value between 0 and 150");
+
+    query_engine
+        .request(r#"mutation { createOneUser(data: { id: 1, name: "Ada", age: 36 }) { id } }"#)
+        .await;
+
+    // Updates are checked on the value they set.
+    let result = query_engine
+        .request(r#"mutation { updateOneUser(where: { id: 1 }, data: { age: { set: 151 } }) { id } }"#)
+        .await;
+    assert_rejected(&result, "Expected a value between 0 and 150");
+
+    assert_eq!(
+        json!({ "data": { "updateOneUser": { "age": 150 } } }),
+        query_engine
+            .request(r#"mutation { updateOneUser(where: { id: 1 }, data: { age: { set: 150 } }) { age } }"#)
+            .await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn arithmetic_updates_of_ranged_fields_are_rejected(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(USER).await?;
+
+    query_engine
+        .request(r#"mutation { createOneUser(data: { id: 1, name: "Ada", age: 150 }) { id } }"#)
+        .await;
+
+    // The result of an increment is only known to the database, so it can't be checked against the range.
+    let result = query_engine
+        .request(r#"mutation { updateOneUser(where: { id: 1 }, data: { age: { increment: 1 } }) { id } }"#)
+        .await;
+    assert_rejected(&result, "`increment` can't be checked against it, use `set` instead");
+
+    let result = query_engine
+        .request(r#"mutation { updateManyUser(data: { age: { decrement: 151 } }) { count } }"#)
+        .await;
+    assert_rejected(&result, "`decrement` can't be checked against it, use `set` instead");
+
+    assert_eq!(
+        json!({ "data": { "findManyUser": [{ "age": 150 }] } }),
+        query_engine.request("query { findManyUser { age } }").await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn regex_is_enforced(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(USER).await?;
+
+    let result = query_engine
+        .request(r#"mutation { createOneUser(data: { id: 1, name: "ada" }) { id } }"#)
+        .await;
+    assert_rejected(&result, "Expected a value matching `^[A-Z]`");
+
+    query_engine
+        .request(r#"mutation { createOneUser(data: { id: 1, name: "Ada" }) { id } }"#)
+        .await;
+
+    let result = query_engine
+        .request(r#"mutation { updateOneUser(where: { id: 1 }, data: { name: "grace" }) { id } }"#)
+        .await;
+    assert_rejected(&result, "Expected a value matching `^[A-Z]`");
+
+    assert_eq!(
+        json!({ "data": { "findManyUser": [{ "name": "Ada" }] } }),
+        query_engine.request("query { findManyUser { name } }").await
+    );
+
+    Ok(())
+}
@@ -0,0 +1,172 @@
+use super::test_api::*;
+use indoc::indoc;
+use test_macros::test_each_connector;
+
+static POST: &str = indoc! {"
+    model Post {
+        id    Int    @id
+        title String
+        body  String
+
+        @@fulltext([title])
+        @@fulltext([title, body])
+    }
+"};
+
+async fn create_posts(query_engine: &QueryEngine) {
+    let posts = vec![
+        (1, "Learning Rust", "An introduction"),
+        (2, "Rust tips", "Rust traits, rust macros and rust lifetimes"),
+        (3, "Cooking pasta", "Boil the water first"),
+    ];
+
+    for (id, title, body) in posts {
+        let mutation = format!(
+            r#"mutation {{ createOnePost(data: {{ id: {}, title: "{}", body: "{}" }}) {{ id }} }}"#,
+            id, title, body
+        );
+
+        query_engine.request(mutation).await;
+    }
+}
+
+fn post_ids(result: &serde_json::Value) -> Vec<i64> {
+    result["data"]["findManyPost"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|post| post["id"].as_i64().unwrap())
+        .collect()
+}
+
+#[test_each_connector(tags("postgres", "mysql", "sqlite"))]
+async fn search_filter_matches_words(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&POST).await?;
+    create_posts(&query_engine).await;
+
+    let query = indoc! {r#"
+        query {
+            findManyPost(where: { title: { search: "rust" } }, orderBy: { id: asc }) { id }
+        }
+    "#};
+
+    let result = query_engine.request(query).await;
+    assert_eq!(vec![1, 2], post_ids(&result));
+
+    let query = indoc! {r#"
+        query {
+            findManyPost(where: { NOT: { title: { search: "rust" } } }) { id }
+        }
+    "#};
+
+    let result = query_engine.request(query).await;
+    assert_eq!(vec![3], post_ids(&result));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mysql", "sqlite"))]
+async fn search_filter_can_be_combined_with_other_filters(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&POST).await?;
+    create_posts(&query_engine).await;
+
+    // The search query is a parameter between the parameters of the other conditions.
+    let query = indoc! {r#"
+        query {
+            findManyPost(
+                where: { id: { gt: 1 }, title: { search: "rust" }, body: { contains: "traits" } },
+                orderBy: { id: asc }
+            ) { id }
+        }
+    "#};
+
+    let result = query_engine.request(query).await;
+    assert_eq!(vec![2], post_ids(&result));
+
+    let query = indoc! {r#"
+        query {
+            findManyPost(where: { OR: [{ title: { search: "pasta" } }, { id: 1 }] }, orderBy: { id: asc }) { id }
+        }
+    "#};
+
+    let result = query_engine.request(query).await;
+    assert_eq!(vec![1, 3], post_ids(&result));
+
+    Ok(())
+}
+
+// On SQLite, the full-text index is a separate table that has to follow the writes to the records.
+#[test_each_connector(tags("postgres", "mysql", "sqlite"))]
+async fn search_filter_matches_updated_records(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&POST).await?;
+    create_posts(&query_engine).await;
+
+    query_engine
+        .request(r#"mutation { updateOnePost(where: { id: 3 }, data: { title: "Rust pasta" }) { id } }"#)
+        .await;
+    query_engine
+        .request(r#"mutation { deleteOnePost(where: { id: 1 }) { id } }"#)
+        .await;
+
+    let query = indoc! {r#"
+        query {
+            findManyPost(where: { title: { search: "rust" } }, orderBy: { id: asc }) { id }
+        }
+    "#};
+
+    let result = query_engine.request(query).await;
+    assert_eq!(vec![2, 3], post_ids(&result));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mysql", "sqlite"))]
+async fn order_by_relevance(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&POST).await?;
+    create_posts(&query_engine).await;
+
+    let query = indoc! {r#"
+        query {
+            findManyPost(orderBy: { _relevance: { fields: [title, body], search: "rust", sort: desc } }) { id }
+        }
+    "#};
+
+    let result = query_engine.request(query).await;
+    let ids = post_ids(&result);
+
+    assert_eq!(Some(&2), ids.first());
+    assert_eq!(Some(&3), ids.last());
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mysql", "sqlite"))]
+async fn order_by_relevance_rejects_cursors(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&POST).await?;
+    create_posts(&query_engine).await;
+
+    let query = indoc! {r#"
+        query {
+            findManyPost(
+                cursor: { id: 1 },
+                orderBy: { _relevance: { fields: title, search: "rust", sort: asc } }
+            ) { id }
+        }
+    "#};
+
+    let result = query_engine.request(query).await;
+    let message = result["errors"][0]["error"].as_str().unwrap_or_default();
+
+    assert!(
+        message.contains("can't be combined with cursor-based pagination"),
+        "{}",
+        result
+    );
+
+    Ok(())
+}
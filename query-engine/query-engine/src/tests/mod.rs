@@ -1,4 +1,20 @@
+mod composite_types;
+mod computed_fields;
+mod connection;
+mod connection_pool;
 mod dmmf;
 mod errors;
 mod execute_raw;
+mod field_validations;
+mod full_text_search;
+mod multi_schema;
+mod optimistic_concurrency;
+mod persisted_queries;
+mod query_limits;
+mod read_replicas;
+mod scalar_lists;
+mod server;
+mod streaming;
 mod test_api;
+mod traversal;
+mod views;
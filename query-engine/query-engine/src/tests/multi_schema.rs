@@ -0,0 +1,126 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static SCHEMAS: &[&str] = &["auth", "billing"];
+
+static SCHEMA: &str = indoc! {r#"
+    model User {
+        id       Int       @id
+        name     String
+        invoices Invoice[]
+
+        @@map("users")
+        @@schema("auth")
+    }
+
+    model Invoice {
+        id     Int    @id
+        amount Int
+        status Status
+        userId Int
+        user   User   @relation(fields: [userId], references: [id])
+
+        @@schema("billing")
+    }
+
+    model Note {
+        id   Int    @id
+        text String
+    }
+
+    enum Status {
+        OPEN
+        PAID
+
+        @@schema("billing")
+    }
+"#};
+
+#[test_each_connector(tags("postgres"))]
+async fn models_in_different_schemas_can_be_related(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api.create_engine_with_schemas(SCHEMAS, &SCHEMA).await?;
+
+    let mutation = indoc! {r#"
+        mutation {
+            createOneUser(data: {
+                id: 1,
+                name: "Alice",
+                invoices: { create: [{ id: 1, amount: 100, status: OPEN }, { id: 2, amount: 50, status: PAID }] }
+            }) {
+                id
+            }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "createOneUser": { "id": 1 } } }),
+        query_engine.request(mutation).await
+    );
+
+    let query = indoc! {r#"
+        query {
+            findManyInvoice(where: { status: OPEN }) { amount user { name } }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "findManyInvoice": [{ "amount": 100, "user": { "name": "Alice" } }] } }),
+        query_engine.request(query).await
+    );
+
+    let query = indoc! {r#"
+        query {
+            findOneUser(where: { id: 1 }) { invoices(orderBy: { id: asc }) { id } }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "findOneUser": { "invoices": [{ "id": 1 }, { "id": 2 }] } } }),
+        query_engine.request(query).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn tables_are_created_in_their_schema(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api.create_engine_with_schemas(SCHEMAS, &SCHEMA).await?;
+
+    let mutations = vec![
+        r#"mutation { createOneUser(data: { id: 1, name: "Alice" }) { id } }"#,
+        r#"mutation { createOneNote(data: { id: 1, text: "hello" }) { id } }"#,
+    ];
+
+    for mutation in mutations {
+        query_engine.request(mutation).await;
+    }
+
+    let mutation = indoc! {r#"
+        mutation {
+            executeRaw(query: "UPDATE auth.users SET name = 'Bob'")
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "executeRaw": 1 } }),
+        query_engine.request(mutation).await
+    );
+
+    let query = indoc! {r#"
+        query {
+            findManyUser { name }
+            findManyNote { text }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "findManyUser": [{ "name": "Bob" }], "findManyNote": [{ "text": "hello" }] } }),
+        query_engine.request(query).await
+    );
+
+    Ok(())
+}
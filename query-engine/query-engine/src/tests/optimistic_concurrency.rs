@@ -0,0 +1,149 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static POST: &str = indoc! {"
+    model Post {
+        id      Int    @id
+        title   String
+        version Int    @version
+    }
+"};
+
+static FIND_POST: &str = "query { findOnePost(where: { id: 1 }) { title version } }";
+
+async fn create_post(query_engine: &QueryEngine) {
+    query_engine
+        .request(r#"mutation { createOnePost(data: { id: 1, title: "first", version: 1 }) { id } }"#)
+        .await;
+}
+
+#[test_each_connector]
+async fn updates_increment_the_version(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(POST).await?;
+    create_post(&query_engine).await;
+
+    assert_eq!(
+        json!({ "data": { "updateOnePost": { "title": "second", "version": 2 } } }),
+        query_engine
+            .request(r#"mutation { updateOnePost(where: { id: 1 }, data: { title: "second", version: 1 }) { title version } }"#)
+            .await
+    );
+
+    // Updates without an expected version aren't checked, but still increment the version.
+    assert_eq!(
+        json!({ "data": { "updateOnePost": { "title": "third", "version": 3 } } }),
+        query_engine
+            .request(r#"mutation { updateOnePost(where: { id: 1 }, data: { title: "third" }) { title version } }"#)
+            .await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn updates_with_a_stale_version_fail(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(POST).await?;
+    create_post(&query_engine).await;
+
+    query_engine
+        .request(r#"mutation { updateOnePost(where: { id: 1 }, data: { title: "second", version: 1 }) { id } }"#)
+        .await;
+
+    let result = query_engine
+        .request(r#"mutation { updateOnePost(where: { id: 1 }, data: { title: "stale", version: 1 }) { id } }"#)
+        .await;
+
+    let error = &result["errors"][0]["user_facing_error"];
+    assert_eq!(json!("P2024"), error["error_code"]);
+    assert_eq!(json!("Post"), error["meta"]["model_name"]);
+    assert_eq!(json!("1"), error["meta"]["expected_version"]);
+
+    assert_eq!(
+        json!({ "data": { "findOnePost": { "title": "second", "version": 2 } } }),
+        query_engine.request(FIND_POST).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn update_many_skips_records_with_a_stale_version(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(POST).await?;
+    create_post(&query_engine).await;
+
+    query_engine
+        .request(r#"mutation { updateOnePost(where: { id: 1 }, data: { title: "second", version: 1 }) { id } }"#)
+        .await;
+
+    assert_eq!(
+        json!({ "data": { "updateManyPost": { "count": 0 } } }),
+        query_engine
+            .request(r#"mutation { updateManyPost(data: { title: "stale", version: 1 }) { count } }"#)
+            .await
+    );
+
+    assert_eq!(
+        json!({ "data": { "updateManyPost": { "count": 1 } } }),
+        query_engine
+            .request(r#"mutation { updateManyPost(data: { title: "third", version: 2 }) { count } }"#)
+            .await
+    );
+
+    assert_eq!(
+        json!({ "data": { "findOnePost": { "title": "third", "version": 3 } } }),
+        query_engine.request(FIND_POST).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mysql"))]
+async fn only_one_of_interleaved_updates_with_the_same_version_succeeds(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(POST).await?;
+    create_post(&query_engine).await;
+
+    // All updates select the record in version 1 before any of them has written it.
+    let updates = (0..10).map(|i| {
+        query_engine.request(format!(
+            r#"mutation {{ updateOnePost(where: {{ id: 1 }}, data: {{ title: "update {}", version: 1 }}) {{ id }} }}"#,
+            i
+        ))
+    });
+    let results = futures::future::join_all(updates).await;
+
+    let (succeeded, failed): (Vec<_>, Vec<_>) = results.iter().partition(|result| result.get("errors").is_none());
+    assert_eq!(1, succeeded.len(), "{:?}", results);
+
+    for result in failed {
+        assert_eq!(json!("P2024"), result["errors"][0]["user_facing_error"]["error_code"]);
+    }
+
+    assert_eq!(
+        json!(2),
+        query_engine.request(FIND_POST).await["data"]["findOnePost"]["version"]
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn version_fields_can_only_be_set(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(POST).await?;
+    create_post(&query_engine).await;
+
+    let result = query_engine
+        .request(r#"mutation { updateOnePost(where: { id: 1 }, data: { title: "second", version: { increment: 5 } }) { id } }"#)
+        .await;
+
+    let error = &result["errors"][0]["user_facing_error"];
+    assert_eq!(json!("P2019"), error["error_code"]);
+
+    assert_eq!(
+        json!({ "data": { "findOnePost": { "title": "first", "version": 1 } } }),
+        query_engine.request(FIND_POST).await
+    );
+
+    Ok(())
+}
@@ -0,0 +1,171 @@
+use super::test_api::*;
+use crate::request_handlers::{query_hash, PersistedQueries, MAX_REGISTERED_QUERIES};
+use indoc::indoc;
+use serde_json::json;
+use std::sync::Arc;
+use test_macros::test_each_connector;
+
+static USER: &str = indoc! {"
+    model User {
+        id   Int    @id
+        name String
+    }
+"};
+
+static FIND_USERS: &str = "query ($ids: [Int!], $name: String = \"a\") { findManyUser(where: { id: { in: $ids }, name: $name }, orderBy: { id: asc }) { id } }";
+
+async fn create_users(query_engine: &QueryEngine) {
+    for (id, name) in &[(1, "a"), (2, "a"), (3, "b")] {
+        let mutation = format!(
+            r#"mutation {{ createOneUser(data: {{ id: {}, name: "{}" }}) {{ id }} }}"#,
+            id, name
+        );
+
+        query_engine.request(mutation).await;
+    }
+}
+
+#[test_each_connector]
+async fn variables_are_filled_in(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&USER).await?;
+    create_users(&query_engine).await;
+
+    let response = query_engine
+        .request_json(json!({ "query": FIND_USERS, "variables": { "ids": [1, 3] } }))
+        .await;
+
+    assert_eq!(json!({ "data": { "findManyUser": [{ "id": 1 }] } }), response);
+
+    let response = query_engine
+        .request_json(json!({ "query": FIND_USERS, "variables": { "ids": [1, 3], "name": "b" } }))
+        .await;
+
+    assert_eq!(json!({ "data": { "findManyUser": [{ "id": 3 }] } }), response);
+
+    let response = query_engine
+        .request_json(json!({ "query": "query ($id: Int!) { findOneUser(where: { id: $id }) { id } }" }))
+        .await;
+
+    assert!(response.to_string().contains("Variable '$id' is required"));
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn arguments_of_omitted_variables_are_left_out(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&USER).await?;
+    create_users(&query_engine).await;
+
+    let query = "query ($take: Int, $name: String) { findManyUser(take: $take, where: { name: $name }, orderBy: { id: asc }) { id } }";
+
+    let response = query_engine.request_json(json!({ "query": query })).await;
+
+    assert_eq!(
+        json!({ "data": { "findManyUser": [{ "id": 1 }, { "id": 2 }, { "id": 3 }] } }),
+        response
+    );
+
+    let response = query_engine
+        .request_json(json!({ "query": query, "variables": { "name": "a" } }))
+        .await;
+
+    assert_eq!(
+        json!({ "data": { "findManyUser": [{ "id": 1 }, { "id": 2 }] } }),
+        response
+    );
+
+    let response = query_engine
+        .request_json(json!({ "query": query, "variables": { "take": 1 } }))
+        .await;
+
+    assert_eq!(json!({ "data": { "findManyUser": [{ "id": 1 }] } }), response);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn persisted_queries_are_referenced_by_hash(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&USER).await?;
+    create_users(&query_engine).await;
+
+    let hash = query_hash(FIND_USERS);
+
+    let response = query_engine
+        .request_json(json!({ "queryHash": hash, "variables": { "ids": [1, 2] } }))
+        .await;
+
+    assert!(response.to_string().contains("not found"));
+
+    let response = query_engine
+        .request_json(json!({ "query": FIND_USERS, "queryHash": hash, "variables": { "ids": [1, 2] } }))
+        .await;
+
+    assert_eq!(
+        json!({ "data": { "findManyUser": [{ "id": 1 }, { "id": 2 }] } }),
+        response
+    );
+
+    let response = query_engine
+        .request_json(json!({ "queryHash": hash, "variables": { "ids": [2, 3], "name": "b" } }))
+        .await;
+
+    assert_eq!(json!({ "data": { "findManyUser": [{ "id": 3 }] } }), response);
+
+    let response = query_engine
+        .request_json(json!({ "query": "{ findManyUser { id } }", "queryHash": hash }))
+        .await;
+
+    assert!(response.to_string().contains("doesn't match the SHA-256 hash"));
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn only_persisted_queries_are_accepted(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api
+        .create_engine_with(&USER, |builder| {
+            builder.persisted_queries(Arc::new(PersistedQueries::new(true)))
+        })
+        .await?;
+
+    let hash = query_hash(FIND_USERS);
+
+    let response = query_engine.request_json(json!({ "query": FIND_USERS })).await;
+    assert!(response.to_string().contains("Only persisted queries are accepted"));
+
+    let response = query_engine
+        .request_json(json!({ "query": FIND_USERS, "queryHash": hash }))
+        .await;
+
+    assert!(response.to_string().contains("Only persisted queries are accepted"));
+
+    Ok(())
+}
+
+#[test]
+fn the_least_recently_used_registered_query_is_evicted() {
+    let persisted = PersistedQueries::new(false);
+    let documents: Vec<String> = (0..=MAX_REGISTERED_QUERIES)
+        .map(|take| format!("{{ findManyUser(take: {}) {{ id }} }}", take))
+        .collect();
+
+    for document in documents[..MAX_REGISTERED_QUERIES].iter() {
+        assert!(persisted
+            .template(&query_hash(document), Some(document.clone()), None)
+            .is_ok());
+    }
+
+    // Using the first query makes the second one the least recently used.
+    assert!(persisted.template(&query_hash(&documents[0]), None, None).is_ok());
+
+    let last = &documents[MAX_REGISTERED_QUERIES];
+    assert!(persisted.template(&query_hash(last), Some(last.clone()), None).is_ok());
+
+    assert_eq!(MAX_REGISTERED_QUERIES, persisted.count());
+    assert!(persisted.template(&query_hash(&documents[0]), None, None).is_ok());
+    assert!(persisted.template(&query_hash(&documents[1]), None, None).is_err());
+}
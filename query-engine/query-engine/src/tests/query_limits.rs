@@ -0,0 +1,141 @@
+use super::test_api::*;
+use indoc::indoc;
+use query_core::QueryLimits;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static BLOG: &str = indoc! {"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id       Int       @id
+        authorId Int
+        author   User      @relation(fields: [authorId], references: [id])
+        comments Comment[]
+    }
+
+    model Comment {
+        id     Int  @id
+        postId Int
+        post   Post @relation(fields: [postId], references: [id])
+    }
+"};
+
+async fn engine_with_limits(api: &TestApi, limits: QueryLimits) -> anyhow::Result<QueryEngine> {
+    api.create_engine_with(&BLOG, |builder| builder.query_limits(limits))
+        .await
+}
+
+fn error_code(response: &serde_json::Value) -> &serde_json::Value {
+    &response["errors"][0]["user_facing_error"]["error_code"]
+}
+
+#[test_each_connector]
+async fn limits_the_selection_depth(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let limits = QueryLimits {
+        max_selection_depth: Some(3),
+        ..Default::default()
+    };
+
+    let query_engine = engine_with_limits(api, limits).await?;
+
+    let response = query_engine.request("query { findManyUser { posts { id } } }").await;
+    assert_eq!(json!({ "data": { "findManyUser": [] } }), response);
+
+    let response = query_engine
+        .request("query { findManyUser { posts { comments { id } } } }")
+        .await;
+
+    assert_eq!(&json!("P2025"), error_code(&response));
+    assert!(response.to_string().contains("selection depth limit: 4"));
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn limits_take_and_the_estimated_cost(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let limits = QueryLimits {
+        max_take: Some(50),
+        max_cost: Some(1000),
+        ..Default::default()
+    };
+
+    let query_engine = engine_with_limits(api, limits).await?;
+
+    let response = query_engine.request("query { findManyUser(take: -51) { id } }").await;
+    assert_eq!(&json!("P2025"), error_code(&response));
+    assert!(response.to_string().contains("take limit: 51"));
+
+    // 10 users with 10 posts each.
+    let response = query_engine
+        .request("query { findManyUser(take: 10) { posts(take: 10) { id } } }")
+        .await;
+
+    assert_eq!(json!({ "data": { "findManyUser": [] } }), response);
+
+    // 10 users, with the default estimate of 100 posts each.
+    let response = query_engine
+        .request("query { findManyUser(take: 10) { posts { id } } }")
+        .await;
+
+    assert_eq!(&json!("P2025"), error_code(&response));
+    assert!(response.to_string().contains("estimated cost limit: 1010"));
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn limits_the_nested_write_depth(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let limits = QueryLimits {
+        max_nested_write_depth: Some(1),
+        ..Default::default()
+    };
+
+    let query_engine = engine_with_limits(api, limits).await?;
+
+    let response = query_engine
+        .request("mutation { createOneUser(data: { id: 1, posts: { create: { id: 1 } } }) { id } }")
+        .await;
+
+    assert_eq!(json!({ "data": { "createOneUser": { "id": 1 } } }), response);
+
+    let response = query_engine
+        .request(indoc! {r#"
+            mutation {
+                createOneUser(data: { id: 2, posts: { create: { id: 2, comments: { create: { id: 1 } } } } }) { id }
+            }
+        "#})
+        .await;
+
+    assert_eq!(&json!("P2025"), error_code(&response));
+    assert!(response.to_string().contains("nested write depth limit: 2"));
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn limits_the_batch_size(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let limits = QueryLimits {
+        max_batch_size: Some(2),
+        ..Default::default()
+    };
+
+    let query_engine = engine_with_limits(api, limits).await?;
+    let query = json!({ "query": "query { findManyUser { id } }", "variables": {} });
+
+    let response = query_engine
+        .request_json(json!({ "batch": [query.clone(), query.clone(), query], "transaction": false }))
+        .await;
+
+    assert_eq!(&json!("P2025"), error_code(&response));
+    assert!(response.to_string().contains("batch size limit: 3"));
+
+    Ok(())
+}
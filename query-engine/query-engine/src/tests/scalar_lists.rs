@@ -0,0 +1,129 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static POST: &str = indoc! {"
+    model Post {
+        id   Int      @id
+        tags String[]
+    }
+"};
+
+static FIND_POST: &str = "query { findOnePost(where: { id: 1 }) { tags } }";
+
+async fn create_post(query_engine: &QueryEngine) {
+    query_engine
+        .request(r#"mutation { createOnePost(data: { id: 1, tags: { set: ["a", "b", "a"] } }) { id } }"#)
+        .await;
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn push_appends_one_or_many_values(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(POST).await?;
+    create_post(&query_engine).await;
+
+    query_engine
+        .request(r#"mutation { updateOnePost(where: { id: 1 }, data: { tags: { push: "c" } }) { id } }"#)
+        .await;
+
+    query_engine
+        .request(r#"mutation { updateOnePost(where: { id: 1 }, data: { tags: { push: ["d", "e"] } }) { id } }"#)
+        .await;
+
+    assert_eq!(
+        json!({ "data": { "findOnePost": { "tags": ["a", "b", "a", "c", "d", "e"] } } }),
+        query_engine.request(FIND_POST).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn remove_removes_all_occurrences_of_one_or_many_values(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(POST).await?;
+    create_post(&query_engine).await;
+
+    query_engine
+        .request(r#"mutation { updateOnePost(where: { id: 1 }, data: { tags: { remove: "a" } }) { id } }"#)
+        .await;
+
+    assert_eq!(
+        json!({ "data": { "findOnePost": { "tags": ["b"] } } }),
+        query_engine.request(FIND_POST).await
+    );
+
+    query_engine
+        .request(r#"mutation { updateOnePost(where: { id: 1 }, data: { tags: { push: ["c", "d"] } }) { id } }"#)
+        .await;
+
+    query_engine
+        .request(r#"mutation { updateOnePost(where: { id: 1 }, data: { tags: { remove: ["b", "d", "x"] } }) { id } }"#)
+        .await;
+
+    assert_eq!(
+        json!({ "data": { "findOnePost": { "tags": ["c"] } } }),
+        query_engine.request(FIND_POST).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn push_and_remove_can_be_combined_with_other_updates(api: &TestApi) -> anyhow::Result<()> {
+    let datamodel = indoc! {"
+        model Post {
+            id    Int      @id
+            title String
+            tags  String[]
+            ids   Int[]
+        }
+    "};
+
+    let query_engine = api.create_engine(datamodel).await?;
+
+    query_engine
+        .request(r#"mutation { createOnePost(data: { id: 1, title: "a", tags: { set: ["a"] }, ids: { set: [1, 2] } }) { id } }"#)
+        .await;
+
+    let mutation = indoc! {r#"
+        mutation {
+            updateOnePost(where: { id: 1 }, data: { title: "b", tags: { push: "b" }, ids: { remove: 1 } }) {
+                title
+                tags
+                ids
+            }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "updateOnePost": { "title": "b", "tags": ["a", "b"], "ids": [2] } } }),
+        query_engine.request(mutation).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn unset_is_not_available_on_required_lists(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(POST).await?;
+    create_post(&query_engine).await;
+
+    // The datamodel has no optional lists, so lists can only be unset through `set: []`.
+    let result = query_engine
+        .request(r#"mutation { updateOnePost(where: { id: 1 }, data: { tags: { unset: true } }) { id } }"#)
+        .await;
+
+    assert_eq!(json!("P2009"), result["errors"][0]["user_facing_error"]["error_code"]);
+
+    query_engine
+        .request(r#"mutation { updateOnePost(where: { id: 1 }, data: { tags: { set: [] } }) { id } }"#)
+        .await;
+
+    assert_eq!(
+        json!({ "data": { "findOnePost": { "tags": [] } } }),
+        query_engine.request(FIND_POST).await
+    );
+
+    Ok(())
+}
@@ -0,0 +1,161 @@
+use super::test_api::*;
+use futures::StreamExt;
+use indoc::indoc;
+use quaint::ast::*;
+use query_core::STREAM_BATCH_SIZE;
+use std::collections::HashSet;
+use test_macros::test_each_connector;
+
+static ITEM: &str = indoc! {"
+    model Item {
+        id       Int     @id
+        category String
+        note     String?
+    }
+"};
+
+/// More than a single batch, so streams have to continue across batch boundaries.
+const ITEM_COUNT: i64 = 1100;
+
+/// Inserts the items with raw statements, as one mutation per item would be too slow.
+/// Items fall into seven categories, so ordering by `category` alone has ties.
+async fn create_items(api: &TestApi, query_engine: &QueryEngine) -> anyhow::Result<()> {
+    for chunk_start in (1..=ITEM_COUNT).step_by(100) {
+        let mut insert = Insert::multi_into("Item", &["id", "category"]);
+
+        for id in chunk_start..chunk_start + 100 {
+            insert = insert.values((id, format!("category{}", id % 7)));
+        }
+
+        let (query, params) = api.to_sql_string(insert)?;
+        let params: Vec<serde_json::Value> = params.into_iter().map(serde_json::Value::from).collect();
+        let params = serde_json::to_string(&params)?;
+
+        let mutation = format!(
+            r#"mutation {{ executeRaw(query: "{}", parameters: "{}") }}"#,
+            query.replace("\"", "\\\""),
+            params.replace("\"", "\\\"")
+        );
+
+        query_engine.request(mutation).await;
+    }
+
+    Ok(())
+}
+
+fn ids(records: &[serde_json::Value]) -> Vec<i64> {
+    records.iter().map(|record| record["id"].as_i64().unwrap()).collect()
+}
+
+#[test_each_connector]
+async fn streams_all_records_in_order(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&ITEM).await?;
+    create_items(api, &query_engine).await?;
+
+    let records = query_engine
+        .stream(r#"query { findManyItem(orderBy: { id: desc }) { id } }"#)
+        .await
+        .unwrap();
+
+    let expected: Vec<i64> = (1..=ITEM_COUNT).rev().collect();
+    assert_eq!(expected, ids(&records));
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn streams_with_skip_take_and_non_unique_ordering(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&ITEM).await?;
+    create_items(api, &query_engine).await?;
+
+    let records = query_engine
+        .stream(r#"query { findManyItem(orderBy: { category: asc }, skip: 10, take: 1050) { id category } }"#)
+        .await
+        .unwrap();
+
+    assert_eq!(1050, records.len());
+
+    let unique: HashSet<i64> = ids(&records).into_iter().collect();
+    assert_eq!(1050, unique.len());
+
+    let categories: Vec<&str> = records
+        .iter()
+        .map(|record| record["category"].as_str().unwrap())
+        .collect();
+    let mut sorted = categories.clone();
+    sorted.sort();
+    assert_eq!(sorted, categories);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn streams_continue_if_the_last_record_of_a_batch_is_deleted(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&ITEM).await?;
+    create_items(api, &query_engine).await?;
+
+    let mut records = query_engine
+        .open_stream(r#"query { findManyItem(orderBy: { id: asc }) { id } }"#)
+        .await
+        .unwrap();
+
+    let mut ids = Vec::new();
+
+    // The next batch is only read once the first one is consumed.
+    while ids.len() < STREAM_BATCH_SIZE as usize {
+        let record = serde_json::to_value(records.next().await.unwrap().unwrap())?;
+        ids.push(record["id"].as_i64().unwrap());
+    }
+
+    query_engine
+        .request(format!(
+            "mutation {{ deleteOneItem(where: {{ id: {} }}) {{ id }} }}",
+            STREAM_BATCH_SIZE
+        ))
+        .await;
+
+    while let Some(record) = records.next().await {
+        let record = serde_json::to_value(record.unwrap())?;
+        ids.push(record["id"].as_i64().unwrap());
+    }
+
+    let expected: Vec<i64> = (1..=ITEM_COUNT).collect();
+    assert_eq!(expected, ids);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn streaming_rejects_unsupported_queries(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&ITEM).await?;
+
+    let error = query_engine
+        .stream(r#"query { findManyItem(take: -5) { id } }"#)
+        .await
+        .unwrap_err();
+
+    let message = error["errors"][0].to_string();
+    assert!(message.contains("Streaming doesn't support a negative `take`."));
+
+    let error = query_engine
+        .stream(r#"query { findManyItem(orderBy: { note: asc }) { id } }"#)
+        .await
+        .unwrap_err();
+
+    let message = error["errors"][0].to_string();
+    assert!(message.contains("Streaming doesn't support ordering by optional fields."));
+
+    let error = query_engine
+        .stream(r#"mutation { deleteManyItem { count } }"#)
+        .await
+        .unwrap_err();
+
+    let message = error["errors"][0].to_string();
+    assert!(message.contains("Only read operations can be streamed."));
+
+    Ok(())
+}
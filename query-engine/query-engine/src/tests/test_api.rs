@@ -1,8 +1,10 @@
 use crate::{
-    context::PrismaContext,
-    request_handlers::{graphql, GraphQlBody, SingleQuery},
+    context::{ContextBuilder, PrismaContext},
+    request_handlers::{graphql, GQLResponse, GraphQlBody, SingleQuery},
     PrismaResponse,
 };
+use connector::PoolState;
+use futures::StreamExt;
 use migration_core::{
     api::{GenericApi, MigrationApi},
     commands::SchemaPushInput,
@@ -12,6 +14,7 @@ use quaint::{
     connector::ConnectionInfo,
     visitor::{self, Visitor},
 };
+use query_core::RecordStream;
 use sql_migration_connector::{SqlMigration, SqlMigrationConnector};
 use std::sync::Arc;
 use test_setup::*;
@@ -29,27 +32,158 @@ impl QueryEngine {
     pub async fn request(&self, body: impl Into<SingleQuery>) -> serde_json::Value {
         let body = GraphQlBody::Single(body.into());
         let cx = self.context.clone();
-        match graphql::handle(body, cx).await {
+        match graphql::handle(body, cx, false).await {
             PrismaResponse::Single(response) => serde_json::to_value(response).unwrap(),
             _ => unreachable!(),
         }
     }
+
+    /// Sends a request whose read-only operations must not be routed to a read replica.
+    pub async fn request_on_primary(&self, body: impl Into<SingleQuery>) -> serde_json::Value {
+        let body = GraphQlBody::Single(body.into());
+        let cx = self.context.clone();
+        match graphql::handle(body, cx, true).await {
+            PrismaResponse::Single(response) => serde_json::to_value(response).unwrap(),
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn pool_state(&self) -> Option<PoolState> {
+        self.context.pool_state()
+    }
+
+    /// Sends a request body given as JSON, e.g. to set the variables or the hash of a query.
+    pub async fn request_json(&self, body: serde_json::Value) -> serde_json::Value {
+        let body: GraphQlBody = serde_json::from_value(body).unwrap();
+        let cx = self.context.clone();
+        serde_json::to_value(graphql::handle(body, cx, false).await).unwrap()
+    }
+
+    /// Starts streaming the records of a `findMany` query. Returns the error response if the query fails.
+    pub async fn open_stream(&self, body: impl Into<SingleQuery>) -> Result<RecordStream, serde_json::Value> {
+        let body = GraphQlBody::Single(body.into());
+        let cx = self.context.clone();
+
+        graphql::handle_stream(body, cx, false)
+            .await
+            .map_err(|err| serde_json::to_value(GQLResponse::from(err)).unwrap())
+    }
+
+    /// Streams the records of a `findMany` query. Returns the error response if the query fails.
+    pub async fn stream(&self, body: impl Into<SingleQuery>) -> Result<Vec<serde_json::Value>, serde_json::Value> {
+        let mut records = self.open_stream(body).await?;
+        let mut result = Vec::new();
+
+        while let Some(record) = records.next().await {
+            match record {
+                Ok(item) => result.push(serde_json::to_value(item).unwrap()),
+                Err(err) => return Err(serde_json::to_value(GQLResponse::from(err)).unwrap()),
+            }
+        }
+
+        Ok(result)
+    }
 }
 
 pub struct TestApi {
     connection_info: ConnectionInfo,
+    url: String,
     migration_api: MigrationApi<SqlMigrationConnector, SqlMigration>,
     config: String,
 }
 
 impl TestApi {
     pub async fn create_engine(&self, datamodel: &str) -> anyhow::Result<QueryEngine> {
+        self.create_engine_with(datamodel, |builder| builder).await
+    }
+
+    /// Creates an engine, configuring the context with `configure`.
+    pub async fn create_engine_with(
+        &self,
+        datamodel: &str,
+        configure: impl FnOnce(ContextBuilder) -> ContextBuilder,
+    ) -> anyhow::Result<QueryEngine> {
         feature_flags::initialize(&[String::from("all")]).unwrap();
 
         let datamodel_string = format!("{}\n\n{}", self.config, datamodel);
         let dml = datamodel::parse_datamodel(&datamodel_string).unwrap().subject;
         let config = datamodel::parse_configuration(&datamodel_string).unwrap();
 
+        self.migration_api
+            .schema_push(&SchemaPushInput {
+                schema: datamodel_string,
+                force: true,
+                assume_empty: true,
+            })
+            .await?;
+
+        let builder = PrismaContext::builder(config.subject, dml).enable_raw_queries(true);
+        let context = configure(builder).build().await.unwrap();
+
+        Ok(QueryEngine {
+            context: Arc::new(context),
+        })
+    }
+
+    /// Creates an engine for a datamodel spread over the given database schemas, which are added
+    /// as the `schemas` property of the datasource.
+    pub async fn create_engine_with_schemas(&self, schemas: &[&str], datamodel: &str) -> anyhow::Result<QueryEngine> {
+        feature_flags::initialize(&[String::from("all")]).unwrap();
+
+        let schemas = schemas.iter().map(|schema| format!("{:?}", schema)).collect::<Vec<_>>();
+        let (datasource_head, datasource_tail) = self.config.split_at(self.config.find('}').unwrap());
+        let config = format!(
+            "{}    schemas = [{}]\n{}",
+            datasource_head,
+            schemas.join(", "),
+            datasource_tail
+        );
+
+        let datamodel_string = format!("{}\n\n{}", config, datamodel);
+        let dml = datamodel::parse_datamodel(&datamodel_string).unwrap().subject;
+        let config = datamodel::parse_configuration(&datamodel_string).unwrap();
+
+        migration_core::qe_setup(&datamodel_string).await?;
+
+        let context = PrismaContext::builder(config.subject, dml)
+            .enable_raw_queries(true)
+            .build()
+            .await
+            .unwrap();
+
+        Ok(QueryEngine {
+            context: Arc::new(context),
+        })
+    }
+
+    /// Creates an engine whose datasource routes reads to the given replica URLs, which are added
+    /// as the `readReplicas` property of the datasource. The replica databases must already exist.
+    pub async fn create_engine_with_replicas(&self, replicas: &[&str], datamodel: &str) -> anyhow::Result<QueryEngine> {
+        let replicas = replicas.iter().map(|url| format!("{:?}", url)).collect::<Vec<_>>();
+        let property = format!("readReplicas = [{}]", replicas.join(", "));
+
+        self.create_engine_with_properties(&[&property], datamodel).await
+    }
+
+    /// Creates an engine with additional properties on the datasource, e.g. the pool settings.
+    pub async fn create_engine_with_properties(
+        &self,
+        properties: &[&str],
+        datamodel: &str,
+    ) -> anyhow::Result<QueryEngine> {
+        feature_flags::initialize(&[String::from("all")]).unwrap();
+
+        let (datasource_head, datasource_tail) = self.config.split_at(self.config.find('}').unwrap());
+        let properties: String = properties
+            .iter()
+            .map(|property| format!("    {}\n", property))
+            .collect();
+        let config = format!("{}{}{}", datasource_head, properties, datasource_tail);
+
+        let datamodel_string = format!("{}\n\n{}", config, datamodel);
+        let dml = datamodel::parse_datamodel(&datamodel_string).unwrap().subject;
+        let config = datamodel::parse_configuration(&datamodel_string).unwrap();
+
         self.migration_api
             .schema_push(&SchemaPushInput {
                 schema: datamodel_string,
@@ -73,6 +207,15 @@ impl TestApi {
         &self.connection_info
     }
 
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// The datamodel with the datasource of the test in front of it.
+    pub fn datamodel_with_config(&self, datamodel: &str) -> String {
+        format!("{}\n\n{}", self.config, datamodel)
+    }
+
     pub fn to_sql_string<'a>(&'a self, query: impl Into<Query<'a>>) -> quaint::Result<(String, Vec<Value>)> {
         match self.connection_info() {
             ConnectionInfo::Postgres(..) => visitor::Postgres::build(query),
@@ -94,6 +237,7 @@ pub async fn mysql_8_test_api(args: TestAPIArgs) -> TestApi {
 
     TestApi {
         connection_info,
+        url,
         migration_api,
         config,
     }
@@ -110,6 +254,7 @@ pub async fn mysql_5_6_test_api(args: TestAPIArgs) -> TestApi {
 
     TestApi {
         connection_info,
+        url,
         migration_api,
         config,
     }
@@ -126,6 +271,7 @@ pub async fn mysql_test_api(args: TestAPIArgs) -> TestApi {
 
     TestApi {
         connection_info,
+        url,
         migration_api,
         config,
     }
@@ -142,6 +288,7 @@ pub async fn mysql_mariadb_test_api(args: TestAPIArgs) -> TestApi {
 
     TestApi {
         connection_info,
+        url,
         migration_api,
         config,
     }
@@ -160,6 +307,7 @@ pub async fn postgres9_test_api(args: TestAPIArgs) -> TestApi {
 
     TestApi {
         connection_info,
+        url,
         migration_api,
         config,
     }
@@ -178,6 +326,7 @@ pub async fn postgres_test_api(args: TestAPIArgs) -> TestApi {
 
     TestApi {
         connection_info,
+        url,
         migration_api,
         config,
     }
@@ -196,6 +345,7 @@ pub async fn postgres11_test_api(args: TestAPIArgs) -> TestApi {
 
     TestApi {
         connection_info,
+        url,
         migration_api,
         config,
     }
@@ -214,6 +364,7 @@ pub async fn postgres12_test_api(args: TestAPIArgs) -> TestApi {
 
     TestApi {
         connection_info,
+        url,
         migration_api,
         config,
     }
@@ -232,6 +383,7 @@ pub async fn postgres13_test_api(args: TestAPIArgs) -> TestApi {
 
     TestApi {
         connection_info,
+        url,
         migration_api,
         config,
     }
@@ -250,6 +402,7 @@ pub async fn sqlite_test_api(args: TestAPIArgs) -> TestApi {
 
     TestApi {
         connection_info,
+        url,
         migration_api,
         config,
     }
@@ -266,6 +419,7 @@ pub async fn mssql_2017_test_api(args: TestAPIArgs) -> TestApi {
 
     TestApi {
         connection_info,
+        url,
         migration_api,
         config,
     }
@@ -282,6 +436,7 @@ pub async fn mssql_2019_test_api(args: TestAPIArgs) -> TestApi {
 
     TestApi {
         connection_info,
+        url,
         migration_api,
         config,
     }
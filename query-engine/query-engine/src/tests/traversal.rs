@@ -0,0 +1,92 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static CATEGORY: &str = indoc! {"
+    model Category {
+        id       Int        @id
+        name     String
+        parentId Int?
+        parent   Category?  @relation(\"CategoryTree\", fields: [parentId], references: [id])
+        children Category[] @relation(\"CategoryTree\")
+    }
+"};
+
+/// Creates the tree 1 -> 2 -> 3 -> 4, with 5 as another child of 1.
+async fn create_tree(query_engine: &QueryEngine) {
+    for (id, parent) in &[(1, None), (2, Some(1)), (3, Some(2)), (4, Some(3)), (5, Some(1))] {
+        let parent = parent
+            .map(|parent| format!(r#", parent: {{ connect: {{ id: {} }} }}"#, parent))
+            .unwrap_or_default();
+
+        let mutation = format!(
+            r#"mutation {{ createOneCategory(data: {{ id: {}, name: "c{}"{} }}) {{ id }} }}"#,
+            id, id, parent
+        );
+
+        query_engine.request(mutation).await;
+    }
+}
+
+#[test_each_connector]
+async fn finds_descendants_with_depth(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&CATEGORY).await?;
+    create_tree(&query_engine).await;
+
+    let query = indoc! {r#"
+        query {
+            findManyCategoryRecursive(where: { id: 1 }, relation: children, maxDepth: 2) {
+                depth
+                node { id name }
+            }
+        }
+    "#};
+
+    assert_eq!(
+        json!({
+            "data": {
+                "findManyCategoryRecursive": [
+                    { "depth": 1, "node": { "id": 2, "name": "c2" } },
+                    { "depth": 1, "node": { "id": 5, "name": "c5" } },
+                    { "depth": 2, "node": { "id": 3, "name": "c3" } }
+                ]
+            }
+        }),
+        query_engine.request(query).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn finds_ancestors_with_depth(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&CATEGORY).await?;
+    create_tree(&query_engine).await;
+
+    let query = indoc! {r#"
+        query {
+            findManyCategoryRecursive(where: { id: 4 }, relation: parent) {
+                level: depth
+                node { id }
+            }
+        }
+    "#};
+
+    assert_eq!(
+        json!({
+            "data": {
+                "findManyCategoryRecursive": [
+                    { "level": 1, "node": { "id": 3 } },
+                    { "level": 2, "node": { "id": 2 } },
+                    { "level": 3, "node": { "id": 1 } }
+                ]
+            }
+        }),
+        query_engine.request(query).await
+    );
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static SCHEMA: &str = indoc! {r#"
+    model User {
+        id    Int    @id
+        name  String
+        email String
+
+        @@map("users")
+    }
+
+    view UserName {
+        id   Int    @unique
+        name String
+
+        @@map("user_names")
+    }
+"#};
+
+async fn create_view(query_engine: &QueryEngine) {
+    let mutations = vec![
+        r#"mutation { createOneUser(data: { id: 1, name: "Alice", email: "alice@example.com" }) { id } }"#,
+        r#"mutation { createOneUser(data: { id: 2, name: "Bob", email: "bob@example.com" }) { id } }"#,
+        r#"mutation { executeRaw(query: "CREATE VIEW user_names AS SELECT id, name FROM users") }"#,
+    ];
+
+    for mutation in mutations {
+        query_engine.request(mutation).await;
+    }
+}
+
+#[test_each_connector]
+async fn views_can_be_queried(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&SCHEMA).await?;
+    create_view(&query_engine).await;
+
+    let query = indoc! {r#"
+        query {
+            findManyUserName(where: { name: { startsWith: "A" } }, orderBy: { id: asc }) { id name }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "findManyUserName": [{ "id": 1, "name": "Alice" }] } }),
+        query_engine.request(query).await
+    );
+
+    let query = indoc! {r#"
+        query {
+            findOneUserName(where: { id: 2 }) { name }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "findOneUserName": { "name": "Bob" } } }),
+        query_engine.request(query).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn views_have_no_write_operations(api: &TestApi) -> anyhow::Result<()> {
+    feature_flags::initialize(&[String::from("all")]).unwrap();
+    let query_engine = api.create_engine(&SCHEMA).await?;
+    create_view(&query_engine).await;
+
+    let mutations = vec![
+        r#"mutation { createOneUserName(data: { id: 3, name: "Carol" }) { id } }"#,
+        r#"mutation { updateManyUserName(data: { name: "Carol" }) { count } }"#,
+        r#"mutation { deleteOneUserName(where: { id: 1 }) { id } }"#,
+    ];
+
+    for mutation in mutations {
+        let response = query_engine.request(mutation).await;
+        assert!(response["errors"].is_array(), "Expected an error for `{}`", mutation);
+    }
+
+    Ok(())
+}